@@ -910,6 +910,11 @@ pub struct CompositeSourceConfig {
 #[serde(deny_unknown_fields)]
 #[ts(export, rename = "TsCompositePaneStyle")]
 pub struct CompositePaneStyle {
+    /// Background for the pane's unchanged rows (RGB); defaults to the
+    /// theme's editor background
+    #[serde(default)]
+    #[ts(optional, type = "[number, number, number]")]
+    pub bg: Option<[u8; 3]>,
     /// Background color for added lines (RGB)
     /// Using [u8; 3] instead of (u8, u8, u8) for better rquickjs_serde compatibility
     #[serde(default, rename = "addBg")]
@@ -1324,6 +1329,30 @@ pub struct ScreenSize {
     pub height: u16,
 }
 
+/// One rendered screen cell. Colours are written as ratatui prints them:
+/// a name (`"Red"`, `"Reset"`), `"#RRGGBB"`, or a palette index.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RenderedCell {
+    /// Text drawn in the cell
+    pub symbol: String,
+    /// Foreground colour
+    pub fg: String,
+    /// Background colour
+    pub bg: String,
+}
+
+/// A rectangle of the rendered screen. Returned by
+/// `editor.getRenderedRegion()`; clipped to the screen.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RenderedRegion {
+    /// Text of each row, top to bottom
+    pub lines: Vec<String>,
+    /// Cells of each row, top to bottom
+    pub cells: Vec<Vec<RenderedCell>>,
+}
+
 impl EditorStateSnapshot {
     pub fn new() -> Self {
         Self {
@@ -3210,6 +3239,22 @@ pub enum PluginCommand {
         priority: i32,
     },
 
+    /// Set a gutter icon, a line indicator whose colour may be a theme key
+    /// (resolved against the theme when the icon is set)
+    SetGutterIcon {
+        buffer_id: BufferId,
+        /// Line number (0-indexed)
+        line: usize,
+        /// Namespace for grouping; cleared with `ClearLineIndicators`
+        namespace: String,
+        /// Glyph to display (e.g., "●", "▶")
+        glyph: String,
+        /// RGB array or theme key
+        color: OverlayColorSpec,
+        /// Priority for display when multiple icons share a line (higher wins)
+        priority: i32,
+    },
+
     /// Clear all line indicators for a specific namespace
     ClearLineIndicators {
         buffer_id: BufferId,
@@ -3771,6 +3816,10 @@ pub enum PluginCommand {
     ExecuteAction {
         /// Action name (e.g., "move_word_right", "move_line_end")
         action_name: String,
+        /// Payload for actions that carry data, as in `ActionSpec::args`
+        #[serde(default)]
+        #[ts(type = "Record<string, unknown>")]
+        args: std::collections::HashMap<String, serde_json::Value>,
     },
 
     /// Execute multiple actions in sequence, each with an optional repeat count
@@ -3811,6 +3860,21 @@ pub enum PluginCommand {
         request_id: u64,
     },
 
+    /// Read back a region of the screen as of the next render (async).
+    /// Only served when the plugin debug API is enabled.
+    GetRenderedRegion {
+        /// Left column
+        x: u16,
+        /// Top row
+        y: u16,
+        /// Width in cells
+        width: u16,
+        /// Height in rows
+        height: u16,
+        /// Callback ID for async response
+        callback_id: JsCallbackId,
+    },
+
     /// Get byte offset of the start of a line (async)
     /// Line is 0-indexed (0 = first line)
     GetLineStartPosition {
@@ -3939,6 +4003,29 @@ pub enum PluginCommand {
         buffer_id: Option<usize>,
     },
 
+    /// Show a bordered floating popup with plugin-supplied lines. `handle`
+    /// is allocated by the plugin runtime and names the popup in
+    /// `UpdatePopup` / `ClosePopup`.
+    ShowPopup {
+        handle: u64,
+        lines: Vec<String>,
+        anchor: PopupAnchor,
+        options: PopupOptions,
+    },
+
+    /// Replace the lines of a popup opened with `ShowPopup`. No-op if the
+    /// popup has been closed or dismissed.
+    UpdatePopup { handle: u64, lines: Vec<String> },
+
+    /// Close a popup opened with `ShowPopup`. No-op if it is already gone.
+    ClosePopup { handle: u64 },
+
+    /// Show the read-only output panel `id` in a split, rendering `html` (a
+    /// constrained HTML subset: headings, paragraphs, lists, code blocks,
+    /// bold and italic) into its rows. Showing an `id` that is already open
+    /// replaces its content.
+    ShowOutputPanel { id: String, html: String },
+
     /// Contribute (or replace, or clear) a set of menu rows for the
     /// LSP-Servers popup (the popup opened by clicking the LSP
     /// indicator). Each plugin owns its own slice keyed by
@@ -3986,6 +4073,24 @@ pub enum PluginCommand {
         scheme: String,
     },
 
+    /// Register a plugin text object. `i{key}` / `a{key}` after an
+    /// operator (and the `select_inside` / `select_around` actions) call
+    /// `handler_name` in `plugin_name`'s context to resolve the range.
+    RegisterTextObject {
+        plugin_name: String,
+        /// The single character naming the object (e.g. `f`).
+        key: char,
+        handler_name: String,
+    },
+
+    /// Result of a text-object handler call made for `request_id`:
+    /// the byte range it returned, or `None` if there is no such object
+    /// at the cursor.
+    TextObjectResolved {
+        request_id: u64,
+        range: Option<Range<usize>>,
+    },
+
     /// Mark the buffer backing `path` read-only. Resolved by path (not
     /// buffer id) so it is race-free when issued right after `openFile`:
     /// both are FIFO commands, so the buffer exists by the time this runs,
@@ -4681,6 +4786,49 @@ pub struct ActionPopupOptions {
     pub buffer_id: Option<usize>,
 }
 
+/// Where `showPopup` places a floating popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum PopupAnchor {
+    /// Just below a byte offset of the popup's buffer (above it when there
+    /// is no room below), following the text through edits and scrolling;
+    /// hidden while that text is out of view
+    Buffer { position: usize },
+    /// Top-left corner at a screen column and row
+    Screen { x: u16, y: u16 },
+}
+
+/// Options for showPopup
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct PopupOptions {
+    /// Title drawn in the top border
+    #[serde(default)]
+    #[ts(optional)]
+    pub title: Option<String>,
+    /// Width in columns including the border (default: fits the content)
+    #[serde(default)]
+    #[ts(optional)]
+    pub width: Option<u16>,
+    /// Maximum height in rows including the border (default: 15); longer
+    /// content scrolls
+    #[serde(default)]
+    #[ts(optional)]
+    pub max_height: Option<u16>,
+    /// Buffer whose popup stack holds the popup (default: the active
+    /// buffer). A buffer anchor is an offset into this buffer.
+    #[serde(default)]
+    #[ts(optional)]
+    pub buffer_id: Option<usize>,
+    /// Take keyboard focus, so arrow keys scroll the popup and Escape
+    /// closes it (default: false; the user can focus it with `popup_focus`)
+    #[serde(default)]
+    #[ts(optional)]
+    pub focus: Option<bool>,
+}
+
 /// Syntax highlight span for a buffer range
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -5327,6 +5475,8 @@ mod fromjs_impls {
         ActionSpec,
         ActionPopupAction,
         ActionPopupOptions,
+        PopupAnchor,
+        PopupOptions,
         LspMenuItem,
         ViewTokenWire,
         ViewTokenStyle,
@@ -5506,6 +5656,23 @@ mod fromjs_impls {
             assert_eq!(got.actions[0].id, "ok");
         }
 
+        #[test]
+        fn popup_anchor_decodes_buffer_and_screen_forms() {
+            let got: PopupAnchor = eval_as("({position: 12})");
+            assert_eq!(got, PopupAnchor::Buffer { position: 12 });
+            let got: PopupAnchor = eval_as("({x: 3, y: 4})");
+            assert_eq!(got, PopupAnchor::Screen { x: 3, y: 4 });
+        }
+
+        #[test]
+        fn popup_options_decodes_camel_case_fields() {
+            let got: PopupOptions = eval_as("({title: 'T', maxHeight: 5, bufferId: 2})");
+            assert_eq!(got.title.as_deref(), Some("T"));
+            assert_eq!(got.max_height, Some(5));
+            assert_eq!(got.buffer_id, Some(2));
+            assert_eq!(got.focus, None);
+        }
+
         #[test]
         fn view_token_wire_decodes_offset_and_kind() {
             // Using `Newline` (a unit variant) avoids the tuple-variant
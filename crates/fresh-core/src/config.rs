@@ -39,6 +39,10 @@ pub struct PluginConfig {
     #[serde(default, skip_serializing_if = "settings_is_empty")]
     #[schemars(extend("readOnly" = true))]
     pub settings: serde_json::Value,
+
+    /// Capabilities granted to this plugin (default: all granted)
+    #[serde(default, skip_serializing_if = "PluginPermissions::is_default")]
+    pub permissions: PluginPermissions,
 }
 
 impl Default for PluginConfig {
@@ -47,6 +51,7 @@ impl Default for PluginConfig {
             enabled: true,
             path: None,
             settings: serde_json::Value::Null,
+            permissions: PluginPermissions::default(),
         }
     }
 }
@@ -57,6 +62,82 @@ impl PluginConfig {
             enabled: true,
             path: Some(path),
             settings: serde_json::Value::Null,
+            permissions: PluginPermissions::default(),
+        }
+    }
+}
+
+/// Capabilities a plugin may use
+///
+/// Everything is granted by default; set a capability to `false` to deny
+/// it. A denied filesystem call logs a warning and returns `false`/`null`
+/// (an empty list for `readDir`); a denied process or network call throws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PluginPermissions {
+    /// Read files and list directories (`readFile`, `readFileBytes`,
+    /// `readDir`, `fileStat`)
+    #[serde(default = "default_true")]
+    pub fs_read: bool,
+
+    /// Write, create, move and remove files (`writeFile`, `createDir`,
+    /// `renamePath`, `copyPath`, `removePath`)
+    #[serde(default = "default_true")]
+    pub fs_write: bool,
+
+    /// Run processes (`spawnProcess`, `spawnHostProcess`,
+    /// `spawnBackgroundProcess`)
+    #[serde(default = "default_true")]
+    pub process_spawn: bool,
+
+    /// Make network requests (`httpFetch`)
+    #[serde(default = "default_true")]
+    pub network: bool,
+}
+
+impl Default for PluginPermissions {
+    fn default() -> Self {
+        Self {
+            fs_read: true,
+            fs_write: true,
+            process_spawn: true,
+            network: true,
+        }
+    }
+}
+
+impl PluginPermissions {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `capability` is granted.
+    pub fn allows(&self, capability: PluginCapability) -> bool {
+        match capability {
+            PluginCapability::FsRead => self.fs_read,
+            PluginCapability::FsWrite => self.fs_write,
+            PluginCapability::ProcessSpawn => self.process_spawn,
+            PluginCapability::Network => self.network,
+        }
+    }
+}
+
+/// One of the capabilities in [`PluginPermissions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginCapability {
+    FsRead,
+    FsWrite,
+    ProcessSpawn,
+    Network,
+}
+
+impl PluginCapability {
+    /// The config key of this capability.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluginCapability::FsRead => "fs_read",
+            PluginCapability::FsWrite => "fs_write",
+            PluginCapability::ProcessSpawn => "process_spawn",
+            PluginCapability::Network => "network",
         }
     }
 }
@@ -86,4 +167,19 @@ mod tests {
         assert!(c.enabled);
         assert_eq!(c.path.as_ref(), Some(&p));
     }
+
+    /// Permissions default to all granted; listing one capability only
+    /// revokes that one.
+    #[test]
+    fn permissions_default_to_granted() {
+        let c: PluginConfig = serde_json::from_str("{}").unwrap();
+        assert!(c.permissions.is_default());
+
+        let c: PluginConfig =
+            serde_json::from_str(r#"{"permissions": {"fs_write": false}}"#).unwrap();
+        assert!(!c.permissions.allows(PluginCapability::FsWrite));
+        assert!(c.permissions.allows(PluginCapability::FsRead));
+        assert!(c.permissions.allows(PluginCapability::ProcessSpawn));
+        assert!(c.permissions.allows(PluginCapability::Network));
+    }
 }
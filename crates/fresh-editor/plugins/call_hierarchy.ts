@@ -0,0 +1,223 @@
+/// <reference path="./lib/fresh.d.ts" />
+
+/**
+ * Call Hierarchy Plugin
+ *
+ * Asks the language server for the call hierarchy of the symbol under
+ * the cursor (`textDocument/prepareCallHierarchy`) and then for its
+ * incoming or outgoing calls. The result is shown as a small tree in the
+ * shared Utility Dock: the prepared item is the root and each caller /
+ * callee is a child. Selecting any node jumps to its name.
+ */
+
+import { Finder, DisplayEntry, getRelativePath } from "./lib/finder.ts";
+
+const editor = getEditor();
+
+type CallDirection = "incoming" | "outgoing";
+
+/** One node of the rendered call tree. Positions are 0-based (LSP). */
+interface CallNode {
+  name: string;
+  kind: number;
+  detail: string;
+  file: string;
+  line: number;
+  character: number;
+  /** 0 for the prepared item, 1 for its callers / callees. */
+  depth: number;
+  /** Number of call sites recorded in `fromRanges` (children only). */
+  callSites: number;
+}
+
+function getKindLabel(kind: number): string {
+  switch (kind) {
+    case 5:
+      return "class";
+    case 6:
+      return "method";
+    case 9:
+      return "construct";
+    case 12:
+      return "fn";
+    case 23:
+      return "struct";
+    default:
+      return "item";
+  }
+}
+
+/**
+ * Turn a raw `CallHierarchyItem` into a tree node, or null when the
+ * payload is missing the fields we need to navigate to it.
+ */
+function parseItem(raw: unknown, depth: number, callSites: number): CallNode | null {
+  if (typeof raw !== "object" || raw === null) return null;
+  const item = raw as Record<string, unknown>;
+  const name = typeof item.name === "string" ? item.name : "";
+  const uri = typeof item.uri === "string" ? item.uri : "";
+  if (!name || !uri) return null;
+
+  // `selectionRange` is the symbol name; fall back to the full range.
+  const range = (item.selectionRange ?? item.range) as
+    | { start?: { line?: number; character?: number } }
+    | undefined;
+  const start = range?.start ?? {};
+
+  return {
+    name,
+    kind: Number(item.kind) || 0,
+    detail: typeof item.detail === "string" ? item.detail : "",
+    file: editor.fileUriToPath(uri) || uri,
+    line: typeof start.line === "number" ? start.line : 0,
+    character: typeof start.character === "number" ? start.character : 0,
+    depth,
+    callSites,
+  };
+}
+
+/**
+ * Decode an `incomingCalls` / `outgoingCalls` response into a flat,
+ * depth-annotated tree rooted at the prepared item. Incoming calls carry
+ * the caller in `from`, outgoing calls carry the callee in `to`.
+ */
+function buildCallTree(
+  root: CallNode,
+  calls: unknown,
+  direction: CallDirection,
+): CallNode[] {
+  const tree: CallNode[] = [root];
+  if (!Array.isArray(calls)) return tree;
+
+  const key = direction === "incoming" ? "from" : "to";
+  for (const call of calls) {
+    if (typeof call !== "object" || call === null) continue;
+    const raw = call as Record<string, unknown>;
+    const sites = Array.isArray(raw.fromRanges) ? raw.fromRanges.length : 0;
+    const child = parseItem(raw[key], 1, sites);
+    if (child) tree.push(child);
+  }
+  return tree;
+}
+
+let currentDirection: CallDirection = "incoming";
+
+function format(node: CallNode): DisplayEntry {
+  let label: string;
+  if (node.depth === 0) {
+    label = `▾ [${getKindLabel(node.kind)}] ${node.name}`;
+  } else {
+    const arrow = currentDirection === "incoming" ? "←" : "→";
+    const sites = node.callSites > 1 ? ` (${node.callSites}×)` : "";
+    label = `    ${arrow} [${getKindLabel(node.kind)}] ${node.name}${sites}`;
+  }
+
+  const where = `${getRelativePath(editor, node.file)}:${node.line + 1}`;
+  return {
+    label,
+    description: node.detail ? `${node.detail}  ${where}` : where,
+    location: {
+      file: node.file,
+      line: node.line + 1,
+      column: node.character + 1,
+    },
+  };
+}
+
+const finder = new Finder<CallNode>(editor, {
+  id: "call_hierarchy",
+  format,
+  preview: false,
+  // Keep the tree order the server gave us rather than regrouping.
+  groupBy: "none",
+  useUtilityDock: true,
+});
+
+/**
+ * Prepare the call hierarchy at the cursor. Returns the first prepared
+ * item (servers may return several for overloaded symbols) plus the
+ * buffer's language, or null with a status message set.
+ */
+async function prepareAtCursor(): Promise<{ item: unknown; root: CallNode; language: string } | null> {
+  const bufferId = editor.getActiveBufferId();
+  const language = editor.getBufferInfo(bufferId)?.language;
+  const path = editor.getBufferPath(bufferId);
+  const cursor = editor.getPrimaryCursor();
+  if (!language || !path || !cursor || cursor.line === null) {
+    editor.setStatus(editor.t("status.no_item"));
+    return null;
+  }
+
+  // LSP positions count UTF-16 code units, which is exactly what a JS
+  // string's length measures.
+  const lineStart = await editor.getLineStartPosition(cursor.line);
+  const prefix =
+    lineStart === null ? "" : await editor.getBufferText(bufferId, lineStart, cursor.position);
+
+  let prepared: unknown;
+  try {
+    prepared = await editor.sendLspRequest(language, "textDocument/prepareCallHierarchy", {
+      textDocument: { uri: editor.pathToFileUri(path) },
+      position: { line: cursor.line, character: prefix.length },
+    });
+  } catch (err) {
+    const msg = err instanceof Error ? err.message : String(err);
+    editor.setStatus(editor.t("status.request_failed", { error: msg }));
+    return null;
+  }
+
+  const item = Array.isArray(prepared) ? prepared[0] : null;
+  const root = parseItem(item, 0, 0);
+  if (!root) {
+    editor.setStatus(editor.t("status.no_item"));
+    return null;
+  }
+  return { item, root, language };
+}
+
+async function showCalls(direction: CallDirection): Promise<void> {
+  const prepared = await prepareAtCursor();
+  if (!prepared) return;
+
+  const method =
+    direction === "incoming" ? "callHierarchy/incomingCalls" : "callHierarchy/outgoingCalls";
+  let calls: unknown;
+  try {
+    calls = await editor.sendLspRequest(prepared.language, method, { item: prepared.item });
+  } catch (err) {
+    const msg = err instanceof Error ? err.message : String(err);
+    editor.setStatus(editor.t("status.request_failed", { error: msg }));
+    return;
+  }
+
+  currentDirection = direction;
+  const tree = buildCallTree(prepared.root, calls, direction);
+  const title =
+    direction === "incoming"
+      ? editor.t("panel.incoming_title", { name: prepared.root.name })
+      : editor.t("panel.outgoing_title", { name: prepared.root.name });
+  finder.panel({ title, items: tree });
+}
+
+async function show_incoming_calls(): Promise<void> {
+  await showCalls("incoming");
+}
+registerHandler("show_incoming_calls", show_incoming_calls);
+
+async function show_outgoing_calls(): Promise<void> {
+  await showCalls("outgoing");
+}
+registerHandler("show_outgoing_calls", show_outgoing_calls);
+
+editor.registerCommand(
+  "%cmd.show_incoming_calls",
+  "%cmd.show_incoming_calls_desc",
+  "show_incoming_calls",
+);
+editor.registerCommand(
+  "%cmd.show_outgoing_calls",
+  "%cmd.show_outgoing_calls_desc",
+  "show_outgoing_calls",
+);
+
+editor.debug("Call hierarchy plugin loaded");
@@ -75,7 +75,7 @@ function getDiagnostics(): DiagnosticItem[] {
     ? diagnostics
     : diagnostics.filter((d) => uriToPath(d.uri).replace(/\\/g, "/") === activePath);
 
-  // Sort by file, then line, then severity
+  // Sort by file, then severity (errors first), then position
   filtered.sort((a, b) => {
     // File comparison
     if (a.uri !== b.uri) {
@@ -88,11 +88,13 @@ function getDiagnostics(): DiagnosticItem[] {
       }
       return a.uri < b.uri ? -1 : 1;
     }
-    // Line comparison
+    // Severity comparison
+    const severityDiff = (a.severity ?? 4) - (b.severity ?? 4);
+    if (severityDiff !== 0) return severityDiff;
+    // Position comparison
     const lineDiff = a.range.start.line - b.range.start.line;
     if (lineDiff !== 0) return lineDiff;
-    // Severity comparison
-    return (a.severity ?? 4) - (b.severity ?? 4);
+    return a.range.start.character - b.range.start.character;
   });
 
   // Convert to DiagnosticItem
@@ -0,0 +1,99 @@
+/// <reference path="./lib/fresh.d.ts" />
+
+/**
+ * Document Links Plugin
+ *
+ * Asks the language server for `textDocument/documentLink` when a file is
+ * opened, activated or saved, and paints each link as an underlined
+ * overlay carrying its target. The editor follows overlay URLs on
+ * Ctrl+Click and with the "Open Link" command, and falls back to plain
+ * URL / file-path detection where the server reports nothing. Links the
+ * server returns without a target are skipped rather than resolved.
+ */
+
+const editor = getEditor();
+
+const NAMESPACE = "lsp-document-links";
+
+interface LspPosition {
+  line: number;
+  character: number;
+}
+
+/**
+ * Byte offset of an LSP position. `character` counts UTF-16 code units,
+ * which is what JS string indexing uses, so slicing the line by it and
+ * measuring the UTF-8 length of the prefix gives the byte column.
+ */
+function toByteOffset(
+  lines: string[],
+  lineStarts: number[],
+  pos: LspPosition | undefined,
+): number | null {
+  if (!pos || pos.line < 0 || pos.line >= lines.length) return null;
+  const prefix = lines[pos.line].slice(0, pos.character);
+  return lineStarts[pos.line] + editor.utf8ByteLength(prefix);
+}
+
+async function refreshLinks(bufferId: number): Promise<void> {
+  const info = editor.getBufferInfo(bufferId);
+  if (!info || !info.language || !info.path) return;
+
+  let result: unknown;
+  try {
+    result = await editor.sendLspRequest(info.language, "textDocument/documentLink", {
+      textDocument: { uri: editor.pathToFileUri(info.path) },
+    });
+  } catch {
+    // No server for this language, or it doesn't provide document links.
+    return;
+  }
+
+  editor.clearNamespace(bufferId, NAMESPACE);
+  if (!Array.isArray(result) || result.length === 0) return;
+
+  const text = await editor.getBufferText(bufferId, 0, info.length);
+  const lines = text.split("\n");
+  const lineStarts: number[] = [];
+  let offset = 0;
+  for (const line of lines) {
+    lineStarts.push(offset);
+    offset += editor.utf8ByteLength(line) + 1;
+  }
+
+  for (const raw of result) {
+    if (typeof raw !== "object" || raw === null) continue;
+    const link = raw as {
+      target?: unknown;
+      range?: { start?: LspPosition; end?: LspPosition };
+    };
+    if (typeof link.target !== "string" || !link.target) continue;
+
+    const start = toByteOffset(lines, lineStarts, link.range?.start);
+    const end = toByteOffset(lines, lineStarts, link.range?.end);
+    if (start === null || end === null || end <= start) continue;
+
+    editor.addOverlay(bufferId, NAMESPACE, start, end, {
+      fg: "syntax.link",
+      underline: true,
+      url: link.target,
+    });
+  }
+}
+
+editor.on("after_file_open", (args) => {
+  refreshLinks(args.buffer_id);
+  return true;
+});
+
+editor.on("buffer_activated", (args) => {
+  refreshLinks(args.buffer_id);
+  return true;
+});
+
+editor.on("after_file_save", (args) => {
+  refreshLinks(args.buffer_id);
+  return true;
+});
+
+editor.debug("Document links plugin loaded");
@@ -610,6 +610,7 @@ registerHandler("git_blame_close", git_blame_close);
  */
 function on_git_blame_buffer_closed(data: { buffer_id: number }): void {
   blameInstances.delete(data.buffer_id);
+  blameAnnotations.delete(data.buffer_id);
 }
 registerHandler("on_git_blame_buffer_closed", on_git_blame_buffer_closed);
 editor.on("buffer_closed", on_git_blame_buffer_closed);
@@ -719,6 +720,175 @@ function git_blame_copy_hash() : void {
 }
 registerHandler("git_blame_copy_hash", git_blame_copy_hash);
 
+// =============================================================================
+// Blame Annotations (in-place)
+// =============================================================================
+
+/**
+ * Blame annotations for a regular file buffer: each visible line gets a
+ * `hash author date │` prefix rendered as virtual text, refreshed as the
+ * viewport scrolls. Only the visible lines are decorated, so large files
+ * stay cheap. Clicking an annotation shows the commit message.
+ */
+interface BlameAnnotation {
+  filePath: string;
+  repo: GitRepo;
+  lines: BlameLine[];   // Indexed by final line number - 1
+  generation: number;   // Bumped per render so stale async passes bail out
+}
+
+const ANNOTATION_PREFIX = "git-blame-annotation:";
+const ANNOTATION_AUTHOR_WIDTH = 14;
+
+// Annotated buffers, keyed by buffer id.
+const blameAnnotations: Map<number, BlameAnnotation> = new Map();
+
+// Parsed blame output per file path. Dropped when the file is saved.
+const blameCache: Map<string, BlameLine[]> = new Map();
+
+async function cachedBlame(repo: GitRepo, filePath: string): Promise<BlameLine[]> {
+  const cached = blameCache.get(filePath);
+  if (cached) return cached;
+  const lines = await fetchGitBlame(repo, filePath, null);
+  if (lines.length > 0) {
+    blameCache.set(filePath, lines);
+  }
+  return lines;
+}
+
+function formatAnnotation(line: BlameLine | undefined): string {
+  if (!line) {
+    return " ".repeat(7 + 1 + ANNOTATION_AUTHOR_WIDTH + 1 + 10) + " │ ";
+  }
+  const author = line.author.length > ANNOTATION_AUTHOR_WIDTH
+    ? line.author.slice(0, ANNOTATION_AUTHOR_WIDTH - 1) + "…"
+    : line.author.padEnd(ANNOTATION_AUTHOR_WIDTH);
+  const date = new Date(parseInt(line.authorTime, 10) * 1000).toISOString().slice(0, 10);
+  return `${line.shortHash} ${author} ${date} │ `;
+}
+
+/**
+ * Decorate the lines currently visible in the active split. Older
+ * annotations are replaced so only the viewport carries virtual text.
+ */
+async function renderAnnotations(bufferId: number): Promise<void> {
+  const ann = blameAnnotations.get(bufferId);
+  if (!ann || editor.getActiveBufferId() !== bufferId) return;
+  const viewport = editor.getViewport();
+  if (!viewport || viewport.topLine === null) return;
+
+  const generation = ++ann.generation;
+  const first = viewport.topLine;
+  const last = first + viewport.height;
+  const starts: Array<[number, number]> = [];
+  for (let line = first; line <= last; line++) {
+    const start = await editor.getLineStartPosition(line);
+    if (start === null) break;
+    starts.push([line, start]);
+  }
+  if (blameAnnotations.get(bufferId) !== ann || ann.generation !== generation) return;
+
+  editor.removeVirtualTextsByPrefix(bufferId, ANNOTATION_PREFIX);
+  for (const [line, start] of starts) {
+    editor.addVirtualTextStyled(
+      bufferId,
+      ANNOTATION_PREFIX + String(line),
+      start,
+      formatAnnotation(ann.lines[line]),
+      { fg: HEADER_FG_KEY, bg: HEADER_BG_KEY },
+      true, // before
+    );
+  }
+}
+
+/**
+ * Toggle blame annotations for the current file buffer.
+ */
+async function git_blame_toggle_annotations(): Promise<void> {
+  const bufferId = editor.getActiveBufferId();
+  if (blameAnnotations.delete(bufferId)) {
+    editor.removeVirtualTextsByPrefix(bufferId, ANNOTATION_PREFIX);
+    editor.setStatus(editor.t("status.annotations_off"));
+    return;
+  }
+
+  const filePath = editor.getBufferPath(bufferId);
+  if (!filePath || filePath === "") {
+    editor.setStatus(editor.t("status.no_file"));
+    return;
+  }
+
+  editor.setStatus(editor.t("status.loading"));
+  const repo = await resolveGitRepoForPath(editor, filePath);
+  const lines = repo ? await cachedBlame(repo, filePath) : [];
+  if (!repo || lines.length === 0) {
+    editor.setStatus(editor.t("status.no_blame_info"));
+    return;
+  }
+
+  const byLine: BlameLine[] = [];
+  for (const line of lines) {
+    byLine[line.finalLineNumber - 1] = line;
+  }
+  blameAnnotations.set(bufferId, { filePath, repo, lines: byLine, generation: 0 });
+  await renderAnnotations(bufferId);
+  editor.setStatus(editor.t("status.annotations_on"));
+}
+registerHandler("git_blame_toggle_annotations", git_blame_toggle_annotations);
+
+editor.on("viewport_changed", (args) => {
+  if (blameAnnotations.has(args.buffer_id)) {
+    void renderAnnotations(args.buffer_id);
+  }
+});
+
+editor.on("buffer_activated", (args) => {
+  if (blameAnnotations.has(args.buffer_id)) {
+    void renderAnnotations(args.buffer_id);
+  }
+});
+
+editor.on("after_file_save", async (args) => {
+  const ann = blameAnnotations.get(args.buffer_id);
+  const path = editor.getBufferPath(args.buffer_id);
+  if (path) blameCache.delete(path);
+  if (!ann) return;
+
+  const lines = await cachedBlame(ann.repo, ann.filePath);
+  if (blameAnnotations.get(args.buffer_id) !== ann) return;
+  ann.lines = [];
+  for (const line of lines) {
+    ann.lines[line.finalLineNumber - 1] = line;
+  }
+  await renderAnnotations(args.buffer_id);
+});
+
+/**
+ * Clicking at the start of an annotated line (where the annotation sits)
+ * shows the full commit message of that line's commit.
+ */
+editor.on("mouse_click", async (args) => {
+  if (args.buffer_id === null || args.buffer_row === null) return;
+  if (args.button !== "left" || args.buffer_col !== 0) return;
+  const ann = blameAnnotations.get(args.buffer_id);
+  const line = ann?.lines[args.buffer_row];
+  if (!ann || !line) return;
+
+  if (line.hash === "0000000000000000000000000000000000000000") {
+    editor.setStatus(editor.t("status.not_committed"));
+    return;
+  }
+  const result = await git(editor, ann.repo, ["log", "-1", "--format=%B", line.hash]);
+  const message = result.exit_code === 0
+    ? result.stdout.trim().split("\n").filter((l) => l.trim() !== "").join(" ⏎ ")
+    : line.summary;
+  editor.setStatus(editor.t("status.commit_message", {
+    hash: line.shortHash,
+    author: line.author,
+    message,
+  }));
+});
+
 // =============================================================================
 // Command Registration
 // =============================================================================
@@ -744,6 +914,13 @@ editor.registerCommand(
   null
 );
 
+editor.registerCommand(
+  "%cmd.git_blame_toggle_annotations",
+  "%cmd.git_blame_toggle_annotations_desc",
+  "git_blame_toggle_annotations",
+  null
+);
+
 // =============================================================================
 // Plugin Initialization
 // =============================================================================
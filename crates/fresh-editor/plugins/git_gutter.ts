@@ -1,4 +1,11 @@
 /// <reference path="./lib/fresh.d.ts" />
+import {
+  type GitRepo,
+  git,
+  repoRelativePath,
+  resolveGitRepoForPath,
+} from "./lib/git_repo.ts";
+
 const editor = getEditor();
 
 
@@ -6,13 +13,17 @@ const editor = getEditor();
  * Git Gutter Plugin
  *
  * Shows git diff indicators in the gutter for modified, added, and deleted lines.
- * Uses `git diff` to compare the current buffer content against the index (staged changes)
- * or HEAD if nothing is staged.
+ * Uses `git diff` to compare the file against HEAD. While the buffer has
+ * unsaved edits, its content is diffed against HEAD instead, debounced so a
+ * burst of typing costs one diff.
  *
  * Indicator symbols:
  * - │ (green): Added line
  * - │ (yellow): Modified line
  * - ▾ (red): Deleted line(s) below
+ *
+ * The hunk under the cursor can be staged (`git apply --cached`) or
+ * reverted in the buffer to its HEAD content.
  */
 
 // =============================================================================
@@ -22,6 +33,9 @@ const editor = getEditor();
 const NAMESPACE = "git-gutter";
 const PRIORITY = 10; // Lower than diagnostics
 
+// Coalesce a burst of edits into one re-diff of the buffer content.
+const EDIT_DEBOUNCE_MS = 300;
+
 // Colors (RGB)
 const COLORS = {
   added: [80, 250, 123] as [number, number, number],    // Green
@@ -56,6 +70,23 @@ interface BufferGitState {
   hunks: DiffHunk[];
   /** Whether we're currently updating */
   updating: boolean;
+  /** Set when an edit arrives mid-update, so the update runs again */
+  stale: boolean;
+  /** Bumped per edit; only the newest debounced update proceeds */
+  pendingToken: number;
+  /** Temp file holding the HEAD version, for diffing unsaved content */
+  headSnapshot: string | null;
+}
+
+function newBufferState(filePath: string): BufferGitState {
+  return {
+    filePath,
+    hunks: [],
+    updating: false,
+    stale: false,
+    pendingToken: 0,
+    headSnapshot: null,
+  };
 }
 
 // =============================================================================
@@ -225,6 +256,41 @@ async function getGitDiff(filePath: string): Promise<string> {
   return "";
 }
 
+/**
+ * Diff the buffer's unsaved content against HEAD. Both sides go through
+ * temp files so the output is ordinary `git diff` output; the HEAD side is
+ * fetched once and kept until the next save.
+ */
+async function getBufferDiff(bufferId: number, state: BufferGitState): Promise<string> {
+  const repo = await resolveGitRepoForPath(editor, state.filePath);
+  if (!repo) return "";
+  const tmpDir = editor.getTempDir();
+
+  if (!state.headSnapshot) {
+    const snapshot = editor.pathJoin(tmpDir, `fresh-git-gutter-${bufferId}-head`);
+    const rel = repoRelativePath(repo, state.filePath);
+    const result = await git(editor, repo, ["show", `HEAD:${rel}`], snapshot);
+    if (result.exit_code !== 0) return "";
+    state.headSnapshot = snapshot;
+  }
+
+  const content = await editor.getBufferText(bufferId, 0, editor.getBufferLength(bufferId));
+  const current = editor.pathJoin(tmpDir, `fresh-git-gutter-${bufferId}-buffer`);
+  editor.writeFile(editor.localPath(current), content);
+
+  const result = await git(editor, repo, [
+    "diff",
+    "--no-index",
+    "--no-color",
+    "--unified=0",
+    "--",
+    state.headSnapshot,
+    current,
+  ]);
+  // Exit code 0 = no differences, 1 = differences found, >1 = error
+  return result.exit_code <= 1 ? result.stdout : "";
+}
+
 // =============================================================================
 // Indicator Management
 // =============================================================================
@@ -234,7 +300,11 @@ async function getGitDiff(filePath: string): Promise<string> {
  */
 async function updateGitGutter(bufferId: number): Promise<void> {
   const state = bufferStates.get(bufferId);
-  if (!state || state.updating) return;
+  if (!state) return;
+  if (state.updating) {
+    state.stale = true;
+    return;
+  }
 
   state.updating = true;
 
@@ -256,7 +326,9 @@ async function updateGitGutter(bufferId: number): Promise<void> {
     editor.debug("Git Gutter: file is tracked, getting diff...");
 
     // Get diff
-    const diffOutput = await getGitDiff(state.filePath);
+    const diffOutput = editor.getBufferInfo(bufferId)?.modified
+      ? await getBufferDiff(bufferId, state)
+      : await getGitDiff(state.filePath);
     editor.debug(`Git Gutter: diff output length = ${diffOutput.length}`);
     if (diffOutput.length > 0 && diffOutput.length < 500) {
       editor.debug(`Git Gutter: diff = ${diffOutput.replace(/\n/g, "\\n")}`);
@@ -311,9 +383,26 @@ async function updateGitGutter(bufferId: number): Promise<void> {
     editor.setViewState(bufferId, "git_gutter_hunks", hunks);
   } finally {
     state.updating = false;
+    if (state.stale && bufferStates.get(bufferId) === state) {
+      state.stale = false;
+      void updateGitGutter(bufferId);
+    }
   }
 }
 
+/**
+ * Re-diff after an edit, once the buffer has been quiet for
+ * `EDIT_DEBOUNCE_MS`.
+ */
+async function scheduleUpdate(bufferId: number): Promise<void> {
+  const state = bufferStates.get(bufferId);
+  if (!state) return;
+  const myToken = ++state.pendingToken;
+  await editor.delay(EDIT_DEBOUNCE_MS);
+  if (myToken !== state.pendingToken || bufferStates.get(bufferId) !== state) return;
+  await updateGitGutter(bufferId);
+}
+
 
 // =============================================================================
 // Event Handlers
@@ -334,9 +423,9 @@ async function updateGitGutter(bufferId: number): Promise<void> {
  */
 
 
-// Note: Git diff compares the file on disk, not the in-memory buffer.
-// Line indicators automatically track position changes via byte-position markers.
-// A full re-diff happens on save. Unsaved changes are shown natively by the editor.
+// Note: Line indicators track position changes via byte-position markers
+// between updates. Edits schedule a debounced re-diff of the buffer content;
+// a save re-diffs the file on disk.
 
 /**
  * Handle buffer closed - cleanup state
@@ -361,11 +450,7 @@ function git_gutter_refresh() : void {
 
   // Ensure state exists
   if (!bufferStates.has(bufferId)) {
-    bufferStates.set(bufferId, {
-      filePath,
-      hunks: [],
-      updating: false,
-    });
+    bufferStates.set(bufferId, newBufferState(filePath));
   }
 
   // Force immediate update
@@ -377,13 +462,189 @@ function git_gutter_refresh() : void {
 }
 registerHandler("git_gutter_refresh", git_gutter_refresh);
 
+// =============================================================================
+// Hunk Staging / Reverting
+// =============================================================================
+
+/** A `--unified=0` hunk with the text of both sides. */
+interface RawHunk {
+  oldStart: number;
+  oldCount: number;
+  newStart: number;
+  newCount: number;
+  /** Hunk body lines as they appear in the diff: `+`, `-` and `\` markers */
+  body: string[];
+}
+
+/**
+ * Parse `--unified=0` diff output into hunks that keep their body, so a
+ * single hunk can be turned back into a patch or its old side restored.
+ */
+function parseRawHunks(diffOutput: string): RawHunk[] {
+  const hunks: RawHunk[] = [];
+  let current: RawHunk | null = null;
+  for (const line of diffOutput.split("\n")) {
+    const m = line.match(/^@@ -(\d+)(?:,(\d+))? \+(\d+)(?:,(\d+))? @@/);
+    if (m) {
+      current = {
+        oldStart: parseInt(m[1], 10),
+        oldCount: m[2] === undefined ? 1 : parseInt(m[2], 10),
+        newStart: parseInt(m[3], 10),
+        newCount: m[4] === undefined ? 1 : parseInt(m[4], 10),
+        body: [],
+      };
+      hunks.push(current);
+    } else if (current && (line.startsWith("+") || line.startsWith("-") || line.startsWith("\\"))) {
+      current.body.push(line);
+    }
+  }
+  return hunks;
+}
+
+/**
+ * The hunk covering 0-indexed editor `line`. A pure deletion is matched on
+ * the line its gutter marker sits on.
+ */
+function hunkAtLine(hunks: RawHunk[], line: number): RawHunk | null {
+  const lineNum = line + 1;
+  for (const hunk of hunks) {
+    if (hunk.newCount === 0) {
+      if (lineNum === Math.max(1, hunk.newStart)) return hunk;
+    } else if (lineNum >= hunk.newStart && lineNum < hunk.newStart + hunk.newCount) {
+      return hunk;
+    }
+  }
+  return null;
+}
+
+/**
+ * Resolve the active buffer's file, repo and the hunk under the cursor in
+ * `git diff <args>`. Reports why on the status bar and returns null when
+ * there is nothing to act on. The buffer must be saved, because hunks are
+ * computed from the file on disk.
+ */
+async function hunkAtCursor(
+  diffArgs: string[],
+): Promise<{ bufferId: number; repo: GitRepo; relPath: string; hunk: RawHunk } | null> {
+  const bufferId = editor.getActiveBufferId();
+  const filePath = editor.getBufferPath(bufferId);
+  if (!filePath || filePath === "") {
+    editor.setStatus(editor.t("status.no_file"));
+    return null;
+  }
+  if (editor.getBufferInfo(bufferId)?.modified) {
+    editor.setStatus(editor.t("status.save_first"));
+    return null;
+  }
+  const repo = await resolveGitRepoForPath(editor, filePath);
+  if (!repo) {
+    editor.setStatus(editor.t("status.no_hunk"));
+    return null;
+  }
+  const relPath = repoRelativePath(repo, filePath);
+  const result = await git(editor, repo, [
+    "diff",
+    ...diffArgs,
+    "--no-color",
+    "--unified=0",
+    "--",
+    relPath,
+  ]);
+  const hunk = result.exit_code <= 1
+    ? hunkAtLine(parseRawHunks(result.stdout), editor.getCursorLine())
+    : null;
+  if (!hunk) {
+    editor.setStatus(editor.t("status.no_hunk"));
+    return null;
+  }
+  return { bufferId, repo, relPath, hunk };
+}
+
+/**
+ * Stage the hunk under the cursor. Hunks are taken from the diff against
+ * the index so the patch applies cleanly even when part of the file is
+ * already staged; with nothing staged that is the diff against HEAD.
+ */
+async function git_gutter_stage_hunk(): Promise<void> {
+  const found = await hunkAtCursor([]);
+  if (!found) return;
+  const { bufferId, repo, relPath, hunk } = found;
+
+  const patch = [
+    `diff --git a/${relPath} b/${relPath}`,
+    `--- a/${relPath}`,
+    `+++ b/${relPath}`,
+    `@@ -${hunk.oldStart},${hunk.oldCount} +${hunk.newStart},${hunk.newCount} @@`,
+    ...hunk.body,
+    "",
+  ].join("\n");
+  const patchPath = editor.pathJoin(editor.getTempDir(), `fresh-stage-hunk-${Date.now()}.patch`);
+  editor.writeFile(editor.localPath(patchPath), patch);
+
+  const result = await git(editor, repo, ["apply", "--cached", "--unidiff-zero", patchPath]);
+  if (result.exit_code !== 0) {
+    editor.setStatus(editor.t("status.stage_failed", { error: result.stderr.trim() }));
+    return;
+  }
+  editor.setStatus(editor.t("status.hunk_staged", { line: String(hunk.newStart) }));
+  await updateGitGutter(bufferId);
+}
+registerHandler("git_gutter_stage_hunk", git_gutter_stage_hunk);
+
+/**
+ * Revert the hunk under the cursor in the buffer, replacing its lines with
+ * their HEAD content. The change is an ordinary buffer edit: it can be
+ * undone, and reaches disk when the buffer is saved.
+ */
+async function git_gutter_revert_hunk(): Promise<void> {
+  const found = await hunkAtCursor(["HEAD"]);
+  if (!found) return;
+  const { bufferId, hunk } = found;
+
+  // Rebuild the old side; a "\ No newline" marker after a removed line
+  // means that line had no trailing newline.
+  let oldText = "";
+  let lastWasOld = false;
+  for (const line of hunk.body) {
+    if (line.startsWith("-")) {
+      oldText += line.slice(1) + "\n";
+      lastWasOld = true;
+    } else if (line.startsWith("\\")) {
+      if (lastWasOld) oldText = oldText.slice(0, -1);
+    } else {
+      lastWasOld = false;
+    }
+  }
+
+  const length = editor.getBufferLength(bufferId);
+  let start: number;
+  let end: number;
+  if (hunk.newCount === 0) {
+    // Pure deletion: re-insert after line `newStart` (1-indexed).
+    start = hunk.newStart === 0
+      ? 0
+      : (await editor.getLineStartPosition(hunk.newStart)) ?? length;
+    end = start;
+  } else {
+    start = (await editor.getLineStartPosition(hunk.newStart - 1)) ?? length;
+    end = (await editor.getLineStartPosition(hunk.newStart - 1 + hunk.newCount)) ?? length;
+  }
+
+  if (end > start) {
+    editor.deleteRange(bufferId, start, end);
+  }
+  if (oldText.length > 0) {
+    editor.insertText(bufferId, start, oldText);
+  }
+  editor.setStatus(editor.t("status.hunk_reverted", { line: String(hunk.newStart) }));
+}
+registerHandler("git_gutter_revert_hunk", git_gutter_revert_hunk);
+
 // =============================================================================
 // Registration
 // =============================================================================
 
 // Register event handlers
-// Note: No need to register after-insert/after-delete hooks - indicators
-// automatically track position changes via byte-position markers in the editor.
 editor.on("after_file_open", (args) => {
   const bufferId = args.buffer_id;
   const filePath = args.path;
@@ -393,11 +654,7 @@ editor.on("after_file_open", (args) => {
   }
 
   // Initialize state for this buffer
-  bufferStates.set(bufferId, {
-    filePath,
-    hunks: [],
-    updating: false,
-  });
+  bufferStates.set(bufferId, newBufferState(filePath));
 
   // Update immediately (no debounce for file open)
   updateGitGutter(bufferId);
@@ -411,11 +668,7 @@ editor.on("buffer_activated", (args) => {
   if (!bufferStates.has(bufferId)) {
     const filePath = editor.getBufferPath(bufferId);
     if (filePath && filePath !== "") {
-      bufferStates.set(bufferId, {
-        filePath,
-        hunks: [],
-        updating: false,
-      });
+      bufferStates.set(bufferId, newBufferState(filePath));
       updateGitGutter(bufferId);
     }
   }
@@ -431,12 +684,10 @@ editor.on("after_file_save", (args) => {
   const state = bufferStates.get(bufferId);
   if (state) {
     state.filePath = args.path;
+    // HEAD may have moved since the snapshot was taken (e.g. a commit)
+    state.headSnapshot = null;
   } else {
-    bufferStates.set(bufferId, {
-      filePath: args.path,
-      hunks: [],
-      updating: false,
-    });
+    bufferStates.set(bufferId, newBufferState(args.path));
   }
 
   // Update immediately after save (no debounce)
@@ -444,6 +695,16 @@ editor.on("after_file_save", (args) => {
 
   return true;
 });
+editor.on("after_insert", (args) => {
+  if (!bufferStates.has(args.buffer_id)) return true;
+  scheduleUpdate(args.buffer_id).catch((e) => editor.error(`git-gutter: ${e}`));
+  return true;
+});
+editor.on("after_delete", (args) => {
+  if (!bufferStates.has(args.buffer_id)) return true;
+  scheduleUpdate(args.buffer_id).catch((e) => editor.error(`git-gutter: ${e}`));
+  return true;
+});
 editor.on("buffer_closed", (args) => {
   bufferStates.delete(args.buffer_id);
   return true;
@@ -457,15 +718,25 @@ editor.registerCommand(
   null
 );
 
+editor.registerCommand(
+  "%cmd.stage_hunk",
+  "%cmd.stage_hunk_desc",
+  "git_gutter_stage_hunk",
+  null
+);
+
+editor.registerCommand(
+  "%cmd.revert_hunk",
+  "%cmd.revert_hunk_desc",
+  "git_gutter_revert_hunk",
+  null
+);
+
 // Initialize for the current buffer
 const initBufferId = editor.getActiveBufferId();
 const initPath = editor.getBufferPath(initBufferId);
 if (initPath && initPath !== "") {
-  bufferStates.set(initBufferId, {
-    filePath: initPath,
-    hunks: [],
-    updating: false,
-  });
+  bufferStates.set(initBufferId, newBufferState(initPath));
   updateGitGutter(initBufferId);
 }
 
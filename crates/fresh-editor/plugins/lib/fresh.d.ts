@@ -33,6 +33,38 @@ declare function getEditor(): EditorAPI;
 * @param fn - The handler function
 */
 declare function registerHandler(name: string, fn: Function): void;
+/** Encodes strings as UTF-8 bytes (the standard `TextEncoder`) */
+declare class TextEncoder {
+	readonly encoding: "utf-8";
+	encode(input?: string): Uint8Array;
+}
+/**
+* Decodes bytes into a string (the standard `TextDecoder`).
+* Only the UTF-8 encoding is supported; other labels throw a `RangeError`.
+* With `fatal: true`, invalid input throws a `TypeError` instead of
+* decoding to U+FFFD.
+*/
+declare class TextDecoder {
+	constructor(label?: string, options?: {
+		fatal?: boolean;
+		ignoreBOM?: boolean;
+	});
+	readonly encoding: string;
+	readonly fatal: boolean;
+	readonly ignoreBOM: boolean;
+	decode(input?: Uint8Array | ArrayBuffer): string;
+}
+/**
+* Run `callback` once after `ms` milliseconds. `callback` may also be the
+* name of a global handler. Returns an id for `clearTimeout`.
+*/
+declare function setTimeout(callback: Function | string, ms?: number, ...args: unknown[]): number;
+/** Cancel a `setTimeout`; ids that already fired are ignored */
+declare function clearTimeout(id?: number): void;
+/** Run `callback` every `ms` milliseconds until `clearInterval` */
+declare function setInterval(callback: Function | string, ms?: number, ...args: unknown[]): number;
+/** Cancel a `setInterval` */
+declare function clearInterval(id?: number): void;
 /** Handle for a cancellable async operation */
 interface ProcessHandle<T> extends PromiseLike<T> {
 	/** Promise that resolves to the result when complete */
@@ -166,6 +198,11 @@ type TsCompositeSourceConfig = {
 	style: TsCompositePaneStyle | null;
 };
 type TsCompositePaneStyle = {
+	/**
+	* Background for the pane's unchanged rows (RGB); defaults to the
+	* theme's editor background
+	*/
+	bg?: [number, number, number];
 	/**
 	* Background color for added lines (RGB)
 	* Using [u8; 3] instead of (u8, u8, u8) for better rquickjs_serde compatibility
@@ -264,6 +301,30 @@ type ScreenSize = {
 	width: number;
 	height: number;
 };
+type RenderedRegion = {
+	/**
+	* Text of each row, top to bottom
+	*/
+	lines: Array<string>;
+	/**
+	* Cells of each row, top to bottom
+	*/
+	cells: Array<Array<RenderedCell>>;
+};
+type RenderedCell = {
+	/**
+	* Text drawn in the cell
+	*/
+	symbol: string;
+	/**
+	* Foreground colour
+	*/
+	fg: string;
+	/**
+	* Background colour
+	*/
+	bg: string;
+};
 type KeyEventPayload = {
 	/**
 	* Key name (e.g. `"a"`, `"escape"`, `"f1"`).
@@ -620,6 +681,37 @@ type ActionPopupOptions = {
 	*/
 	buffer_id?: number;
 };
+type PopupAnchor = {
+	position: number;
+} | {
+	x: number;
+	y: number;
+};
+type PopupOptions = {
+	/**
+	* Title drawn in the top border
+	*/
+	title?: string;
+	/**
+	* Width in columns including the border (default: fits the content)
+	*/
+	width?: number;
+	/**
+	* Maximum height in rows including the border (default: 15); longer
+	* content scrolls
+	*/
+	maxHeight?: number;
+	/**
+	* Buffer whose popup stack holds the popup (default: the active
+	* buffer). A buffer anchor is an offset into this buffer.
+	*/
+	bufferId?: number;
+	/**
+	* Take keyboard focus, so arrow keys scroll the popup and Escape
+	* closes it (default: false; the user can focus it with `popup_focus`)
+	*/
+	focus?: boolean;
+};
 type TsLspMenuItem = {
 	/**
 	* Stable identifier used as the `action_id` in the resulting
@@ -2204,13 +2296,27 @@ interface EditorAPI {
 	*/
 	unregisterCommand(name: string): boolean;
 	/**
+	* Register a text object named by the single character `key`
+	* 
+	* When the user triggers it (`d i {key}` in the vim keymap, or the
+	* `select_inside` / `select_around` actions), the `globalThis` function
+	* `handlerName` is called with `{ bufferId, position, around }` and
+	* returns (or resolves to) a `{ start, end }` byte range, or `null`
+	* if there is no such object at the cursor.
+	*/
+	registerTextObject(key: string, handlerName: string): boolean;
+	/**
 	* Set a context (for keybinding conditions)
 	*/
 	setContext(name: string, active: boolean): boolean;
 	/**
 	* Execute a built-in action
+	*
+	* `args` carries the payload of actions that take one, e.g.
+	* `{ char: "x" }` for `insert_char`. Returns false without sending
+	* anything when the name is empty or `args` is not an object.
 	*/
-	executeAction(actionName: string): boolean;
+	executeAction(actionName: string, args?: Record<string, unknown> | null): boolean;
 	/**
 	* Cancel the active prompt / overlay — the same teardown the
 	* Escape key triggers. Lets a plugin dismiss a prompt it opened
@@ -2283,6 +2389,14 @@ interface EditorAPI {
 	*/
 	getScreenSize(): ScreenSize;
 	/**
+	* Text and colours of a screen region as of the next render, for
+	* plugin self-tests. Clipped to the screen.
+	* 
+	* Only available in debug builds or when `FRESH_PLUGIN_DEBUG_API=1`
+	* is set; otherwise the promise rejects.
+	*/
+	getRenderedRegion(x: number, y: number, width: number, height: number): Promise<RenderedRegion>;
+	/**
 	* List every split with its active buffer and viewport.
 	* 
 	* Plugins that need to operate on every visible buffer
@@ -2561,6 +2675,11 @@ interface EditorAPI {
 	*/
 	readFile(path: string | LocalPath | WindowPath | AuthorityPath): string | null;
 	/**
+	* Read raw file bytes from the path's filesystem, for binary files that
+	* `readFile` cannot decode as UTF-8.
+	*/
+	readFileBytes(path: string | LocalPath | WindowPath | AuthorityPath): Uint8Array | null;
+	/**
 	* Write file contents to the path's filesystem. Parent directories are
 	* created as needed.
 	*/
@@ -3314,7 +3433,18 @@ interface EditorAPI {
 	*/
 	setSplitScroll(splitId: number, topByte: number): boolean;
 	/**
-	* Set the ratio of a split (0.0 to 1.0, 0.5 = equal)
+	* Resize the split that `split_id` lives in.
+	* 
+	* `split_id` is a leaf id (as returned by `getActiveSplitId`,
+	* `listSplits`, `BufferInfo.splits`, `createTerminal`); the editor
+	* resolves it to its parent split container and sets that container's
+	* ratio, moving the divider between this pane and its sibling. `ratio`
+	* is the fraction of space given to the container's FIRST child
+	* (0.0–1.0, 0.5 = equal), clamped to [0.1, 0.9]. A leaf with no parent
+	* container (the only pane) is a no-op.
+	* 
+	* Note: this is fire-and-forget — the returned bool only reports that
+	* the command was queued, not whether the resize succeeded.
 	*/
 	setSplitRatio(splitId: number, ratio: number): boolean;
 	/**
@@ -3359,6 +3489,15 @@ interface EditorAPI {
 	*/
 	clearLineIndicators(bufferId: number, namespace: string): boolean;
 	/**
+	* Set a gutter icon on a line. `color` is an RGB array or a theme key;
+	* when icons share a line the highest `priority` (default 0) shows
+	*/
+	setGutterIcon(bufferId: number, line: number, glyph: string, color: OverlayColorSpec, namespace: string, priority?: number): boolean;
+	/**
+	* Clear the gutter icons in a namespace
+	*/
+	clearGutterIcons(bufferId: number, namespace: string): boolean;
+	/**
 	* Enable or disable line numbers for a buffer
 	*/
 	setLineNumbers(bufferId: number, enabled: boolean): boolean;
@@ -3463,6 +3602,29 @@ interface EditorAPI {
 	*/
 	showActionPopup(opts: ActionPopupOptions): boolean;
 	/**
+	* Show a bordered floating popup with `lines` at `anchor`: a buffer
+	* position (`{position}`) or a screen cell (`{x, y}`). Returns a handle
+	* for `updatePopup` / `closePopup`. The user can dismiss the popup at
+	* any time; updating or closing it after that is a no-op.
+	*/
+	showPopup(lines: string[], anchor: PopupAnchor, options?: PopupOptions): number;
+	/**
+	* Replace the lines of a popup opened with `showPopup`
+	*/
+	updatePopup(handle: number, lines: string[]): boolean;
+	/**
+	* Close a popup opened with `showPopup`
+	*/
+	closePopup(handle: number): boolean;
+	/**
+	* Show the read-only output panel `id` in a split with `html` rendered
+	* into it. Supports a constrained subset: `<h1>`-`<h6>`, `<p>`,
+	* `<ul>`/`<ol>` with `<li>`, `<pre>`/`<code>`, `<b>`/`<strong>`,
+	* `<i>`/`<em>`, `<br>` and `<hr>`; other tags are dropped but their
+	* text is kept. Showing an `id` that is already open replaces its content.
+	*/
+	showOutputPanel(id: string, html: string): boolean;
+	/**
 	* Contribute (or replace, or clear) menu rows for the LSP-Servers
 	* popup. Pass an empty `items` to clear this plugin's slice for
 	* the given language. See `PluginCommand::SetLspMenuContributions`.
@@ -3536,9 +3698,11 @@ interface EditorAPI {
 	/**
 	* Set virtual buffer content (takes array of entry objects)
 	* 
-	* Note: entries should be TextPropertyEntry[] - uses manual parsing for HashMap support
+	* Note: entries should be TextPropertyEntry[] - uses manual parsing for HashMap support.
+	* Entries without a string `text` are skipped with a warning; an empty
+	* array clears the buffer.
 	*/
-	setVirtualBufferContent(bufferId: number, entriesArr: Record<string, unknown>[]): boolean;
+	setVirtualBufferContent(bufferId: number, entriesArr: TextPropertyEntry[]): boolean;
 	/**
 	* Get text properties at cursor position (returns JS array)
 	*/
@@ -1,6 +1,14 @@
 /// <reference path="./lib/fresh.d.ts" />
 
-import { Finder, FilterSource, defaultFuzzyFilter, DisplayEntry } from "./lib/finder.ts";
+import {
+  Finder,
+  FilterSource,
+  defaultFuzzyFilter,
+  DisplayEntry,
+  getRelativePath,
+  parseGrepOutput,
+} from "./lib/finder.ts";
+import { git, resolveGitRepo, toAbsInRepo } from "./lib/git_repo.ts";
 
 interface SymbolItem {
   name: string;
@@ -410,10 +418,168 @@ function parseSymbols(result: unknown): SymbolItem[] {
   return symbols;
 }
 
+// =============================================================================
+// Workspace symbols
+// =============================================================================
+
+/** A symbol anywhere in the project, from `workspace/symbol` or the grep
+ *  fallback. `line` and `column` are 1-indexed, like grep output. */
+interface WorkspaceSymbolItem {
+  name: string;
+  kind: number;
+  containerName: string;
+  file: string;
+  line: number;
+  column: number;
+}
+
+// Most results shown for one query.
+const MAX_WORKSPACE_SYMBOLS = 100;
+
+// Declaration keywords the grep fallback treats as introducing a symbol,
+// mapped to the LSP SymbolKind shown in the label.
+const DECLARATION_KINDS: Record<string, number> = {
+  class: 5,
+  def: 12,
+  enum: 10,
+  fn: 12,
+  func: 12,
+  function: 12,
+  interface: 11,
+  mod: 2,
+  struct: 23,
+  trait: 11,
+  type: 26,
+};
+
+/**
+ * Decode a `workspace/symbol` response. Servers answer with
+ * `SymbolInformation[]` (a full location) or `WorkspaceSymbol[]`, whose
+ * location may carry only a URI; those open at the top of the file.
+ */
+function parseWorkspaceSymbols(result: unknown): WorkspaceSymbolItem[] {
+  if (!Array.isArray(result)) return [];
+  const symbols: WorkspaceSymbolItem[] = [];
+  for (const item of result) {
+    if (typeof item !== "object" || item === null) continue;
+    const raw = item as Record<string, unknown>;
+    const name = String(raw.name ?? "");
+    const loc = raw.location as Record<string, unknown> | undefined;
+    if (!name || typeof loc !== "object" || loc === null || typeof loc.uri !== "string") {
+      continue;
+    }
+    const range = loc.range as Record<string, Record<string, unknown>> | undefined;
+    const start = range?.start;
+    symbols.push({
+      name,
+      kind: Number(raw.kind) || 0,
+      containerName: typeof raw.containerName === "string" ? raw.containerName : "",
+      file: editor.fileUriToPath(loc.uri),
+      line: (typeof start?.line === "number" ? start.line : 0) + 1,
+      column: (typeof start?.character === "number" ? start.character : 0) + 1,
+    });
+  }
+  return symbols;
+}
+
+/**
+ * Keep the symbols whose name fuzzy-matches `query`, best first. Servers
+ * differ in how loosely they match, so results are always re-filtered.
+ */
+function filterWorkspaceSymbols(
+  symbols: WorkspaceSymbolItem[],
+  query: string,
+): WorkspaceSymbolItem[] {
+  return defaultFuzzyFilter(
+    symbols,
+    query,
+    (sym) => ({ label: sym.name }),
+    MAX_WORKSPACE_SYMBOLS,
+  );
+}
+
+/**
+ * Fallback for buffers without a server (or a server without workspace
+ * symbols): `git grep` for declarations whose name contains `query`.
+ */
+async function grepWorkspaceSymbols(query: string): Promise<WorkspaceSymbolItem[]> {
+  const repo = await resolveGitRepo(editor);
+  if (!repo) return [];
+  const escaped = query.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+  const keywords = Object.keys(DECLARATION_KINDS).join("|");
+  const pattern = `(^|[^A-Za-z0-9_])(${keywords})[[:space:]]+[A-Za-z0-9_]*${escaped}`;
+  const result = await git(editor, repo, ["grep", "-n", "--column", "-I", "-i", "-E", "--", pattern]);
+  if (result.exit_code !== 0) return [];
+
+  const declaration = new RegExp(`\\b(${keywords})\\s+([A-Za-z0-9_]+)`);
+  const symbols: WorkspaceSymbolItem[] = [];
+  for (const match of parseGrepOutput(result.stdout, MAX_WORKSPACE_SYMBOLS * 5)) {
+    const decl = declaration.exec(match.content);
+    if (!decl) continue;
+    symbols.push({
+      name: decl[2],
+      kind: DECLARATION_KINDS[decl[1]] ?? 0,
+      containerName: "",
+      file: toAbsInRepo(editor, repo, match.file),
+      line: match.line,
+      column: match.content.indexOf(decl[2], decl.index) + 1,
+    });
+  }
+  return symbols;
+}
+
+async function searchWorkspaceSymbols(query: string): Promise<WorkspaceSymbolItem[]> {
+  const bufferId = editor.getActiveBufferId();
+  const language = bufferId === null ? undefined : editor.getBufferInfo(bufferId)?.language;
+  if (language) {
+    try {
+      const result = await editor.sendLspRequest(language, "workspace/symbol", { query });
+      const symbols = parseWorkspaceSymbols(result);
+      if (symbols.length > 0) return filterWorkspaceSymbols(symbols, query);
+    } catch (e) {
+      const msg = e instanceof Error ? e.message : String(e);
+      editor.debug(`workspace/symbol failed, grepping instead: ${msg}`);
+    }
+  }
+  return filterWorkspaceSymbols(await grepWorkspaceSymbols(query), query);
+}
+
+const workspaceFinder = new Finder<WorkspaceSymbolItem>(editor, {
+  id: "lsp_workspace_symbols",
+  format: (sym) => ({
+    label: `[${getKindLabel(sym.kind)}] ${sym.name}`,
+    description: sym.containerName
+      ? `${sym.containerName} · ${getRelativePath(editor, sym.file)}:${sym.line}`
+      : `${getRelativePath(editor, sym.file)}:${sym.line}`,
+    location: { file: sym.file, line: sym.line, column: sym.column },
+  }),
+  preview: false,
+  maxResults: MAX_WORKSPACE_SYMBOLS,
+});
+
+function goto_workspace_symbol(): void {
+  workspaceFinder.prompt({
+    title: "Go to workspace symbol: ",
+    source: {
+      mode: "search",
+      search: searchWorkspaceSymbols,
+      debounceMs: 150,
+      minQueryLength: 1,
+    },
+  });
+}
+registerHandler("goto_workspace_symbol", goto_workspace_symbol);
+
 editor.registerCommand(
   "%cmd.goto_lsp_symbol",
   "%cmd.goto_lsp_symbol_desc",
   "goto_lsp_symbol",
 );
 
+editor.registerCommand(
+  "%cmd.goto_workspace_symbol",
+  "%cmd.goto_workspace_symbol_desc",
+  "goto_workspace_symbol",
+);
+
 editor.debug("LSP navigation plugin loaded");
@@ -0,0 +1,516 @@
+/// <reference path="./lib/fresh.d.ts" />
+
+/**
+ * Outline Plugin
+ *
+ * A persistent sidebar showing the symbol tree of the current document,
+ * docked to the right of the editor the way the file explorer docks to
+ * the left. The tree comes from the language server
+ * (`textDocument/documentSymbol`); buffers without a server show an
+ * empty outline.
+ *
+ * The row of the innermost symbol enclosing the cursor is highlighted
+ * and follows the cursor as it moves. Clicking a row (or pressing Enter
+ * on it) jumps to the symbol; Space / Tab collapses and expands it.
+ */
+
+const editor = getEditor();
+
+/** One node of the symbol tree. Lines are 0-based (LSP). */
+interface OutlineNode {
+  name: string;
+  kind: number;
+  /** Full extent of the symbol, used for cursor sync. */
+  startLine: number;
+  endLine: number;
+  /** Position of the symbol's name, where a jump lands. */
+  nameLine: number;
+  nameCharacter: number;
+  children: OutlineNode[];
+  /** Stable identity across refreshes, used to remember collapsed nodes. */
+  key: string;
+}
+
+/** Rows above the first symbol (the title line). */
+const HEADER_ROWS = 1;
+
+const ACTIVE_STYLE: Partial<OverlayOptions> = {
+  bg: "ui.selection_bg",
+  extendToLineEnd: true,
+};
+const TITLE_STYLE: Partial<OverlayOptions> = { fg: "syntax.keyword", bold: true };
+
+interface OutlineState {
+  bufferId: number;
+  splitId: number;
+  /** Split and buffer whose symbols are shown. */
+  sourceSplitId: number;
+  sourceBufferId: number;
+  sourcePath: string;
+  roots: OutlineNode[];
+  /** Visible rows in display order; row `i` is buffer line `i + HEADER_ROWS`. */
+  rows: { node: OutlineNode; depth: number }[];
+  collapsed: Set<string>;
+  /** Key of the highlighted node, if the cursor is inside any symbol. */
+  activeKey: string | null;
+  /** Bumped per refresh so a slow, superseded response is dropped. */
+  generation: number;
+}
+
+let outline: OutlineState | null = null;
+
+function getKindLabel(kind: number): string {
+  switch (kind) {
+    case 2:
+      return "mod";
+    case 3:
+      return "ns";
+    case 5:
+      return "class";
+    case 6:
+      return "method";
+    case 7:
+      return "prop";
+    case 8:
+      return "field";
+    case 9:
+      return "construct";
+    case 10:
+      return "enum";
+    case 11:
+      return "iface";
+    case 12:
+      return "fn";
+    case 13:
+      return "var";
+    case 14:
+      return "const";
+    case 19:
+      return "obj";
+    case 22:
+      return "enum-mem";
+    case 23:
+      return "struct";
+    default:
+      return "item";
+  }
+}
+
+// =============================================================================
+// Tree construction
+// =============================================================================
+
+type RawRange = { start?: { line?: number; character?: number }; end?: { line?: number } };
+
+function rangeOf(raw: unknown): { startLine: number; endLine: number; character: number } | null {
+  if (typeof raw !== "object" || raw === null) return null;
+  const range = raw as RawRange;
+  const startLine = typeof range.start?.line === "number" ? range.start.line : 0;
+  return {
+    startLine,
+    endLine: typeof range.end?.line === "number" ? range.end.line : startLine,
+    character: typeof range.start?.character === "number" ? range.start.character : 0,
+  };
+}
+
+/**
+ * Give every node a key built from its ancestors' keys, its kind and
+ * name, and a counter for same-named siblings (overloads), so collapsed
+ * state survives a refresh even when the symbol moved.
+ */
+function assignKeys(nodes: OutlineNode[], parentKey: string): void {
+  const seen = new Map<string, number>();
+  for (const node of nodes) {
+    const base = `${parentKey}/${node.kind}:${node.name}`;
+    const n = seen.get(base) ?? 0;
+    seen.set(base, n + 1);
+    node.key = n === 0 ? base : `${base}#${n}`;
+    assignKeys(node.children, node.key);
+  }
+}
+
+/**
+ * Nest a flat list by range containment: a node becomes the child of the
+ * closest earlier node whose range encloses it.
+ */
+function nestByContainment(flat: OutlineNode[]): OutlineNode[] {
+  flat.sort((a, b) => a.startLine - b.startLine || b.endLine - a.endLine);
+  const roots: OutlineNode[] = [];
+  const stack: OutlineNode[] = [];
+  for (const node of flat) {
+    while (stack.length > 0 && stack[stack.length - 1].endLine < node.endLine) {
+      stack.pop();
+    }
+    const parent = stack[stack.length - 1];
+    (parent ? parent.children : roots).push(node);
+    stack.push(node);
+  }
+  return roots;
+}
+
+/**
+ * Decode a `textDocument/documentSymbol` response into a tree. Servers
+ * answer with either hierarchical `DocumentSymbol`s (children inline,
+ * `selectionRange` for the name) or flat `SymbolInformation`s (a single
+ * `location`), which are nested by range containment.
+ */
+function parseDocumentSymbols(result: unknown): OutlineNode[] {
+  if (!Array.isArray(result)) return [];
+
+  const flat: OutlineNode[] = [];
+  const decode = (items: unknown[]): OutlineNode[] => {
+    const nodes: OutlineNode[] = [];
+    for (const item of items) {
+      if (typeof item !== "object" || item === null) continue;
+      const raw = item as Record<string, unknown>;
+      const name = typeof raw.name === "string" ? raw.name : "";
+      if (!name) continue;
+
+      if (typeof raw.location === "object" && raw.location !== null) {
+        const range = rangeOf((raw.location as Record<string, unknown>).range);
+        if (!range) continue;
+        flat.push({
+          name,
+          kind: Number(raw.kind) || 0,
+          startLine: range.startLine,
+          endLine: range.endLine,
+          nameLine: range.startLine,
+          nameCharacter: range.character,
+          children: [],
+          key: "",
+        });
+        continue;
+      }
+
+      const range = rangeOf(raw.range);
+      if (!range) continue;
+      const selection = rangeOf(raw.selectionRange) ?? range;
+      nodes.push({
+        name,
+        kind: Number(raw.kind) || 0,
+        startLine: range.startLine,
+        endLine: range.endLine,
+        nameLine: selection.startLine,
+        nameCharacter: selection.character,
+        children: Array.isArray(raw.children) ? decode(raw.children) : [],
+        key: "",
+      });
+    }
+    nodes.sort((a, b) => a.startLine - b.startLine);
+    return nodes;
+  };
+
+  const tree = decode(result);
+  const roots = flat.length > 0 ? nestByContainment(flat) : tree;
+  assignKeys(roots, "");
+  return roots;
+}
+
+/** The innermost node whose range contains `line`, or null. */
+function findEnclosing(nodes: OutlineNode[], line: number): OutlineNode | null {
+  for (const node of nodes) {
+    if (line >= node.startLine && line <= node.endLine) {
+      return findEnclosing(node.children, line) ?? node;
+    }
+  }
+  return null;
+}
+
+/**
+ * The node to highlight for `line`: the innermost enclosing symbol, or
+ * its outermost collapsed ancestor when it is hidden inside a folded one.
+ */
+function visibleEnclosing(
+  nodes: OutlineNode[],
+  line: number,
+  collapsed: Set<string>,
+): OutlineNode | null {
+  for (const node of nodes) {
+    if (line >= node.startLine && line <= node.endLine) {
+      if (collapsed.has(node.key)) return node;
+      return visibleEnclosing(node.children, line, collapsed) ?? node;
+    }
+  }
+  return null;
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+function flattenRows(
+  nodes: OutlineNode[],
+  depth: number,
+  collapsed: Set<string>,
+  out: { node: OutlineNode; depth: number }[],
+): void {
+  for (const node of nodes) {
+    out.push({ node, depth });
+    if (!collapsed.has(node.key)) {
+      flattenRows(node.children, depth + 1, collapsed, out);
+    }
+  }
+}
+
+function buildEntries(state: OutlineState): TextPropertyEntry[] {
+  const title = getFileName(state.sourcePath);
+  const entries: TextPropertyEntry[] = [
+    { text: `${editor.t("panel.title", { file: title })}\n`, style: TITLE_STYLE },
+  ];
+
+  if (state.rows.length === 0) {
+    entries.push({ text: `  ${editor.t("panel.empty")}\n` });
+    return entries;
+  }
+
+  for (const { node, depth } of state.rows) {
+    const active = node.key === state.activeKey;
+    const glyph =
+      node.children.length === 0 ? " " : state.collapsed.has(node.key) ? "▸" : "▾";
+    const marker = active ? "›" : " ";
+    entries.push({
+      text: `${marker} ${"  ".repeat(depth)}${glyph} [${getKindLabel(node.kind)}] ${node.name}\n`,
+      properties: { outlineKey: node.key },
+      style: active ? ACTIVE_STYLE : undefined,
+    });
+  }
+  return entries;
+}
+
+function getFileName(path: string): string {
+  const slash = path.lastIndexOf("/");
+  return slash >= 0 ? path.slice(slash + 1) : path;
+}
+
+function render(): void {
+  if (!outline) return;
+  outline.rows = [];
+  flattenRows(outline.roots, 0, outline.collapsed, outline.rows);
+  editor.setVirtualBufferContent(outline.bufferId, buildEntries(outline));
+}
+
+/** Move the highlight to the symbol enclosing `line` (0-based). */
+function syncToLine(line: number): void {
+  if (!outline) return;
+  const node = visibleEnclosing(outline.roots, line, outline.collapsed);
+  const key = node ? node.key : null;
+  if (key === outline.activeKey) return;
+  outline.activeKey = key;
+  render();
+
+  const row = outline.rows.findIndex((r) => r.node.key === key);
+  if (row >= 0) {
+    editor.scrollBufferToLine(outline.bufferId, row + HEADER_ROWS);
+  }
+}
+
+// =============================================================================
+// Loading symbols
+// =============================================================================
+
+async function loadTree(bufferId: number, path: string): Promise<OutlineNode[]> {
+  const language = editor.getBufferInfo(bufferId)?.language;
+  if (!language) return [];
+  try {
+    const result = await editor.sendLspRequest(language, "textDocument/documentSymbol", {
+      textDocument: { uri: editor.pathToFileUri(path) },
+    });
+    return Array.isArray(result) ? parseDocumentSymbols(result) : [];
+  } catch (err) {
+    // No server for this language (or it failed): nothing to show.
+    const msg = err instanceof Error ? err.message : String(err);
+    editor.debug(`Outline: documentSymbol failed: ${msg}`);
+    return [];
+  }
+}
+
+/**
+ * Rebuild the tree for the source buffer and re-render, highlighting the
+ * symbol at `cursorLine` (0-based) or, when not given, at the source
+ * buffer's cursor if it is the active one.
+ */
+async function refresh(cursorLine: number | null = null): Promise<void> {
+  if (!outline) return;
+  const generation = ++outline.generation;
+  const { sourceBufferId, sourcePath } = outline;
+
+  const roots = await loadTree(sourceBufferId, sourcePath);
+  if (!outline || outline.generation !== generation) return;
+
+  outline.roots = roots;
+  outline.activeKey = null;
+  render();
+
+  if (cursorLine === null && editor.getActiveBufferId() === sourceBufferId) {
+    cursorLine = editor.getPrimaryCursor()?.line ?? null;
+  }
+  if (cursorLine !== null) syncToLine(cursorLine);
+}
+
+// =============================================================================
+// Opening and closing
+// =============================================================================
+
+editor.defineMode(
+  "outline",
+  [
+    ["Return", "outline_jump"],
+    ["Space", "outline_toggle_node"],
+    ["Tab", "outline_toggle_node"],
+    ["q", "outline_close"],
+    ["Escape", "outline_close"],
+  ],
+  true, // read-only
+);
+
+async function openOutline(): Promise<void> {
+  const sourceBufferId = editor.getActiveBufferId();
+  const sourcePath = editor.getBufferPath(sourceBufferId);
+  if (!sourcePath) {
+    editor.setStatus(editor.t("status.no_file"));
+    return;
+  }
+  const sourceSplitId = editor.getActiveSplitId();
+  const cursorLine = editor.getPrimaryCursor()?.line ?? null;
+
+  outline = {
+    bufferId: 0,
+    splitId: 0,
+    sourceSplitId,
+    sourceBufferId,
+    sourcePath,
+    roots: [],
+    rows: [],
+    collapsed: new Set<string>(),
+    activeKey: null,
+    generation: 0,
+  };
+
+  const result = await editor.createVirtualBufferInSplit({
+    name: "*Outline*",
+    mode: "outline",
+    readOnly: true,
+    entries: buildEntries(outline),
+    // The source keeps most of the width.
+    ratio: 0.7,
+    direction: "vertical",
+    panelId: "outline",
+    showLineNumbers: false,
+    showCursors: true,
+    editingDisabled: true,
+  });
+  outline.bufferId = result.bufferId;
+  outline.splitId = result.splitId ?? editor.getActiveSplitId();
+
+  // The outline is a companion to the code: keep typing in the source.
+  editor.focusSplit(sourceSplitId);
+  await refresh(cursorLine);
+}
+
+function closeOutline(): void {
+  if (!outline) return;
+  const { bufferId, splitId, sourceSplitId } = outline;
+  outline = null;
+  editor.closeBuffer(bufferId);
+  if (splitId !== sourceSplitId) {
+    editor.closeSplit(splitId);
+  }
+  editor.focusSplit(sourceSplitId);
+}
+
+async function toggle_outline(): Promise<void> {
+  if (outline) {
+    closeOutline();
+  } else {
+    await openOutline();
+  }
+}
+registerHandler("toggle_outline", toggle_outline);
+
+function outline_close(): void {
+  closeOutline();
+}
+registerHandler("outline_close", outline_close);
+
+// =============================================================================
+// Interaction
+// =============================================================================
+
+function nodeAtOutlineCursor(): OutlineNode | null {
+  if (!outline) return null;
+  for (const props of editor.getTextPropertiesAtCursor(outline.bufferId)) {
+    const key = props.outlineKey;
+    const row = outline.rows.find((r) => r.node.key === key);
+    if (row) return row.node;
+  }
+  return null;
+}
+
+function jumpTo(node: OutlineNode): void {
+  if (!outline) return;
+  editor.openFileInSplit(
+    outline.sourceSplitId,
+    outline.sourcePath,
+    node.nameLine + 1,
+    node.nameCharacter + 1,
+  );
+}
+
+function outline_jump(): void {
+  const node = nodeAtOutlineCursor();
+  if (node) jumpTo(node);
+}
+registerHandler("outline_jump", outline_jump);
+
+function outline_toggle_node(): void {
+  const node = nodeAtOutlineCursor();
+  if (!outline || !node || node.children.length === 0) return;
+  if (outline.collapsed.has(node.key)) {
+    outline.collapsed.delete(node.key);
+  } else {
+    outline.collapsed.add(node.key);
+  }
+  render();
+}
+registerHandler("outline_toggle_node", outline_toggle_node);
+
+editor.on("mouse_click", (args) => {
+  if (!outline || args.buffer_id !== outline.bufferId || args.buffer_row === null) return;
+  if (args.button !== "left") return;
+  const row = outline.rows[args.buffer_row - HEADER_ROWS];
+  if (row) jumpTo(row.node);
+});
+
+editor.on("cursor_moved", (args) => {
+  if (!outline || args.buffer_id !== outline.sourceBufferId) return;
+  syncToLine(args.line - 1);
+});
+
+editor.on("buffer_activated", (args) => {
+  if (!outline || args.buffer_id === outline.bufferId) return;
+  if (args.buffer_id === outline.sourceBufferId) return;
+  const path = editor.getBufferPath(args.buffer_id);
+  if (!path) return;
+
+  outline.sourceBufferId = args.buffer_id;
+  outline.sourcePath = path;
+  outline.sourceSplitId = editor.getActiveSplitId();
+  outline.collapsed.clear();
+  void refresh();
+});
+
+editor.on("after_file_save", (args) => {
+  if (outline && args.buffer_id === outline.sourceBufferId) {
+    void refresh();
+  }
+});
+
+editor.on("buffer_closed", (args) => {
+  if (outline && args.buffer_id === outline.bufferId) {
+    outline = null;
+  }
+});
+
+editor.registerCommand("%cmd.toggle_outline", "%cmd.toggle_outline_desc", "toggle_outline");
+
+editor.debug("Outline plugin loaded");
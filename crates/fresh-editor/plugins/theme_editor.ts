@@ -2186,6 +2186,7 @@ function createDefaultTheme(): Record<string, unknown> {
       fg: [212, 212, 212],
       cursor: [82, 139, 255],
       inactive_cursor: [100, 100, 100],
+      secondary_cursor: [90, 90, 120],
       selection_bg: [38, 79, 120],
       current_line_bg: [40, 40, 40],
       line_number_fg: [100, 100, 100],
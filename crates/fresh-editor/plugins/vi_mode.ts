@@ -1447,6 +1447,11 @@ function vi_matching_bracket() : void {
 }
 registerHandler("vi_matching_bracket", vi_matching_bracket);
 
+function vi_goto_file() : void {
+  editor.executeAction("goto_file_under_cursor");
+}
+registerHandler("vi_goto_file", vi_goto_file);
+
 function vi_paragraph_up() : void {
   executeWithCount("move_to_paragraph_up");
 }
@@ -3422,6 +3427,7 @@ editor.defineMode("vi-normal", [
   ["$", "vi_line_end"],
   ["^", "vi_first_non_blank"],
   ["g g", "vi_doc_start"],
+  ["g f", "vi_goto_file"],
   ["G", "vi_doc_end"],
   ["C-f", "vi_page_down"],
   ["C-b", "vi_page_up"],
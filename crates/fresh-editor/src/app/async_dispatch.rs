@@ -152,10 +152,11 @@ impl Editor {
                 }
                 AsyncMessage::LspError {
                     language,
+                    server_name,
                     error,
                     stderr_log_path,
                 } => {
-                    self.handle_lsp_error(language, error, stderr_log_path);
+                    self.handle_lsp_error(language, server_name, error, stderr_log_path);
                 }
                 AsyncMessage::LspCompletion { request_id, items } => {
                     if let Err(e) = self.handle_completion_response(request_id, items) {
@@ -577,12 +578,17 @@ impl Editor {
     fn handle_lsp_error(
         &mut self,
         language: String,
+        server_name: String,
         error: String,
         stderr_log_path: Option<std::path::PathBuf>,
     ) {
-        tracing::error!("LSP error for {}: {}", language, error);
-        self.active_window_mut().status_message =
-            Some(format!("LSP error ({}): {}", language, error));
+        tracing::error!("LSP error for {} ({}): {}", language, server_name, error);
+
+        // Remembered even when the status-bar message is suppressed below,
+        // so the LSP status popup can still show why the server is down.
+        self.active_window_mut()
+            .lsp_last_errors
+            .insert((language.clone(), server_name.clone()), error.clone());
 
         // Get server command from config for the hook
         let server_command = self
@@ -605,6 +611,18 @@ impl Editor {
         }
         .to_string();
 
+        // A missing executable won't fix itself between file opens: warn
+        // once and keep later failures out of the status bar.
+        let show_status = error_type != "not_found"
+            || self
+                .active_window_mut()
+                .lsp
+                .mark_binary_missing(&language, &server_name);
+        if show_status {
+            self.active_window_mut().status_message =
+                Some(format!("LSP error ({}): {}", language, error));
+        }
+
         // Fire the LspServerError hook for plugins
         self.plugin_manager.read().unwrap().run_hook(
             "lsp_server_error",
@@ -626,10 +644,12 @@ impl Editor {
                     Ok(buffer_id) => {
                         self.active_window_mut()
                             .mark_buffer_read_only(buffer_id, true);
-                        self.active_window_mut().status_message = Some(format!(
-                            "LSP error ({}): {} - See stderr log",
-                            language, error
-                        ));
+                        if show_status {
+                            self.active_window_mut().status_message = Some(format!(
+                                "LSP error ({}): {} - See stderr log",
+                                language, error
+                            ));
+                        }
                     }
                     Err(e) => {
                         tracing::error!("Failed to open LSP stderr log: {}", e);
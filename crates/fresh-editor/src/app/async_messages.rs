@@ -135,11 +135,23 @@ impl Editor {
     }
 
     /// Materialise the merged push + pull view (positions mapped to the buffer's
-    /// current version) and rebuild the overlays from it.
+    /// current version) and rebuild the overlays from it. While the buffer is
+    /// being edited the overlay update is left to
+    /// [`Self::check_diagnostic_overlay_timer`].
     fn merge_and_apply_diagnostics(&mut self, uri: &str) {
         let merged = self.active_window_mut().recompute_merged_diagnostics(uri);
 
-        if let Some((buffer_id, updated)) = self.apply_diagnostics_to_buffer(uri, &merged) {
+        let debounced = self
+            .active_window()
+            .diagnostic_overlay_debounce
+            .get(uri)
+            .is_some_and(|ready_at| Instant::now() < *ready_at);
+        if debounced {
+            tracing::debug!(
+                "Deferring diagnostic overlays for {} (buffer being edited)",
+                uri
+            );
+        } else if let Some((buffer_id, updated)) = self.apply_diagnostics_to_buffer(uri, &merged) {
             if updated {
                 tracing::info!(
                     "Applied {} diagnostics to buffer {:?} (overlays updated)",
@@ -168,6 +180,31 @@ impl Editor {
         );
     }
 
+    /// Apply the diagnostics held back by an edit burst once their debounce
+    /// window has passed. Returns true if any overlays changed.
+    pub fn check_diagnostic_overlay_timer(&mut self) -> bool {
+        let now = Instant::now();
+        let due: Vec<String> = self
+            .active_window()
+            .diagnostic_overlay_debounce
+            .iter()
+            .filter(|(_, ready_at)| now >= **ready_at)
+            .map(|(uri, _)| uri.clone())
+            .collect();
+
+        let mut updated = false;
+        for uri in due {
+            self.active_window_mut()
+                .diagnostic_overlay_debounce
+                .remove(&uri);
+            let merged = self.active_window_mut().recompute_merged_diagnostics(&uri);
+            if let Some((_, changed)) = self.apply_diagnostics_to_buffer(&uri, &merged) {
+                updated |= changed;
+            }
+        }
+        updated
+    }
+
     /// Handle LSP diagnostics (push model — publishDiagnostics from flycheck/cargo)
     pub(super) fn handle_lsp_diagnostics(
         &mut self,
@@ -1956,6 +1993,91 @@ fn apply_semantic_token_edits(
 mod tests {
     use super::*;
 
+    fn token(
+        delta_line: u32,
+        delta_start: u32,
+        length: u32,
+        token_type: u32,
+        token_modifiers_bitset: u32,
+    ) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type,
+            token_modifiers_bitset,
+        }
+    }
+
+    #[test]
+    fn semantic_token_payload_decodes_to_byte_ranges() {
+        use lsp_types::{SemanticTokenModifier, SemanticTokenType};
+
+        // "é" is two UTF-8 bytes but one UTF-16 code unit, so the LSP
+        // columns after it differ from byte columns.
+        let buffer = Buffer::from_str(
+            "let é = foo();\nfn bar() {}\n",
+            1024 * 1024,
+            std::sync::Arc::new(crate::model::filesystem::StdFileSystem),
+        );
+        let legend = SemanticTokensLegend {
+            token_types: vec![SemanticTokenType::VARIABLE, SemanticTokenType::FUNCTION],
+            token_modifiers: vec![
+                SemanticTokenModifier::DECLARATION,
+                SemanticTokenModifier::READONLY,
+            ],
+        };
+        let data = [
+            token(0, 4, 1, 0, 0b11), // `é`: variable, declaration + readonly
+            token(0, 4, 3, 1, 0),    // `foo`: same line, start relative to `é`
+            token(1, 3, 3, 1, 0b01), // `bar`: next line, absolute start
+            token(0, 4, 1, 7, 0),    // `)`: type index outside the legend
+        ];
+
+        let decoded = decode_semantic_token_data(&buffer, &legend, &data, 0);
+
+        assert_eq!(decoded.raw.len(), data.len() * 5);
+        let spans: Vec<_> = decoded
+            .spans
+            .iter()
+            .map(|s| (s.range.clone(), s.token_type.as_str(), s.modifiers.clone()))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (
+                    4..6,
+                    "variable",
+                    vec!["declaration".to_string(), "readonly".to_string()]
+                ),
+                (9..12, "function", vec![]),
+                (19..22, "function", vec!["declaration".to_string()]),
+                (23..24, "unknown", vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn semantic_token_payload_with_base_line_offsets_range_results() {
+        let buffer = Buffer::from_str(
+            "a\nb\nc\n",
+            1024 * 1024,
+            std::sync::Arc::new(crate::model::filesystem::StdFileSystem),
+        );
+        let legend = SemanticTokensLegend {
+            token_types: vec![lsp_types::SemanticTokenType::VARIABLE],
+            token_modifiers: vec![],
+        };
+        // Range responses are decoded relative to the first requested line.
+        let raw = [0, 0, 1, 0, 0];
+        let spans = decode_semantic_token_raw_data(&buffer, &legend, &raw, 2);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].range, 4..5);
+
+        // A payload that isn't a whole number of 5-tuples is rejected.
+        assert!(decode_semantic_token_raw_data(&buffer, &legend, &[0, 0, 1], 0).is_empty());
+    }
+
     #[test]
     fn semantic_token_delta_edits_apply() {
         let base = vec![0, 0, 2, 0, 0, 0, 3, 4, 1, 0];
@@ -1,7 +1,8 @@
 //! Bookmark orchestrators.
 //!
-//! Pure window-state mutations (`set_bookmark`, `clear_bookmark`,
-//! `list_bookmarks`) live on `impl Window`. The cross-cutting
+//! Pure window-state mutations (`set_bookmark`, `clear_bookmark`) live on
+//! `impl Window`. The bookmarks panel needs the theme to build its popup,
+//! so `show_bookmarks_panel` is on `impl Editor`. The cross-cutting
 //! `jump_to_bookmark` stays on `impl Editor` because its body needs
 //! orchestration helpers (`set_active_buffer`,
 //! `apply_event_to_active_buffer`, `ensure_active_cursor_visible_for_navigation`)
@@ -12,7 +13,7 @@
 
 use rust_i18n::t;
 
-use crate::model::event::Event;
+use crate::model::event::{BufferId, Event};
 
 use super::Editor;
 
@@ -21,11 +22,20 @@ impl crate::app::window::Window {
     pub fn set_bookmark(&mut self, key: char) {
         let buffer_id = self.active_buffer();
         let position = self.active_cursors().primary().position;
+        let file_path = if crate::app::bookmarks::is_file_mark_key(key) {
+            self.buffer_metadata
+                .get(&buffer_id)
+                .and_then(|meta| meta.file_path())
+                .cloned()
+        } else {
+            None
+        };
         self.bookmarks.set(
             key,
             crate::app::bookmarks::Bookmark {
                 buffer_id,
                 position,
+                file_path,
             },
         );
         self.set_status_message(t!("bookmark.set", key = key).to_string());
@@ -33,57 +43,118 @@ impl crate::app::window::Window {
 
     /// Clear a bookmark by key.
     pub fn clear_bookmark(&mut self, key: char) {
-        if self.bookmarks.remove(key) {
+        let buffer_id = self.active_buffer();
+        if self.bookmarks.remove(key, buffer_id) {
             self.set_status_message(t!("bookmark.cleared", key = key).to_string());
         } else {
             self.set_status_message(t!("bookmark.not_set", key = key).to_string());
         }
     }
+}
+
+/// Longest snippet of the bookmarked line shown in the bookmarks panel.
+const BOOKMARK_SNIPPET_CHARS: usize = 40;
 
-    /// Show the list of bookmarks in the status bar. Bookmarks pointing
-    /// at buffers that no longer exist render as "unknown".
-    pub fn list_bookmarks(&mut self) {
-        if self.bookmarks.is_empty() {
+impl Editor {
+    /// Open the bookmarks panel: one row per bookmark showing its register,
+    /// file, line and a snippet of the line. Enter jumps to the selected
+    /// bookmark. Bookmarks whose buffer is gone are listed as such (or by
+    /// file, for file marks).
+    pub(super) fn show_bookmarks_panel(&mut self) {
+        use crate::view::popup::{Popup, PopupKind, PopupListItem, PopupPosition, PopupResolver};
+
+        let mut bookmark_list: Vec<(char, crate::app::bookmarks::Bookmark)> =
+            self.active_window().bookmarks.iter().collect();
+        if bookmark_list.is_empty() {
             self.set_status_message(t!("bookmark.none_set").to_string());
             return;
         }
+        bookmark_list.sort_by_key(|(k, bm)| (*k, bm.buffer_id.0));
 
-        let mut bookmark_list: Vec<(char, crate::app::bookmarks::Bookmark)> =
-            self.bookmarks.iter().collect();
-        bookmark_list.sort_by_key(|(k, _)| *k);
-
-        let list_str: String = bookmark_list
+        let root = self.working_dir().to_path_buf();
+        let window = self.active_window();
+        let items: Vec<PopupListItem> = bookmark_list
             .iter()
-            .map(|(k, bm)| {
-                let buffer_name = self
-                    .buffer_metadata
-                    .get(&bm.buffer_id)
-                    .map(|m| m.display_name.as_str())
-                    .unwrap_or("unknown");
-                format!("'{}': {} @ {}", k, buffer_name, bm.position)
+            .map(|(key, bm)| {
+                let label = match window.buffers.get(&bm.buffer_id) {
+                    Some(state) => {
+                        let name = window
+                            .buffer_metadata
+                            .get(&bm.buffer_id)
+                            .map(|meta| match meta.file_path() {
+                                Some(path) => path
+                                    .strip_prefix(&root)
+                                    .unwrap_or(path)
+                                    .display()
+                                    .to_string(),
+                                None => meta.display_name.clone(),
+                            })
+                            .unwrap_or_default();
+                        let position = bm.position.min(state.buffer.len());
+                        let (line, _) = state.buffer.position_to_line_col(position);
+                        let snippet: String = state
+                            .buffer
+                            .get_line(line)
+                            .map(|bytes| String::from_utf8_lossy(&bytes).trim().to_string())
+                            .unwrap_or_default()
+                            .chars()
+                            .take(BOOKMARK_SNIPPET_CHARS)
+                            .collect();
+                        format!("'{}'  {}:{}  {}", key, name, line + 1, snippet)
+                    }
+                    None => match &bm.file_path {
+                        Some(path) => format!(
+                            "'{}'  {}",
+                            key,
+                            path.strip_prefix(&root).unwrap_or(path).display()
+                        ),
+                        None => format!("'{}'  {}", key, t!("bookmark.panel_buffer_gone")),
+                    },
+                };
+                // Local marks are only reachable from their own buffer, so
+                // the row carries the buffer too.
+                PopupListItem::new(label).with_data(format!("{}:{}", key, bm.buffer_id.0))
             })
-            .collect::<Vec<_>>()
-            .join(", ");
+            .collect();
 
-        self.set_status_message(t!("bookmark.list", list = list_str).to_string());
+        let mut popup = Popup::list(items, &self.theme.read().unwrap());
+        popup.kind = PopupKind::Action;
+        popup.title = Some(t!("bookmark.panel_title").to_string());
+        popup.position = PopupPosition::Centered;
+        popup.width = 80;
+        popup.max_height = 15;
+        popup.resolver = PopupResolver::Bookmarks;
+        // Explicitly invoked, so the choice is expected right away.
+        popup.focused = true;
+
+        let buffer_id = self.active_buffer();
+        if let Some(state) = self.active_window_mut().buffers.get_mut(&buffer_id) {
+            state.popups.show_or_replace(popup);
+        }
     }
-}
 
-impl Editor {
-    /// Jump to a bookmark.
+    /// Jump to a bookmark. Lowercase keys look up the active buffer's own
+    /// mark; other keys are window-wide.
+    pub(super) fn jump_to_bookmark(&mut self, key: char) {
+        let buffer_id = self.active_buffer();
+        self.jump_to_bookmark_in(key, buffer_id);
+    }
+
+    /// Jump to the bookmark under `key` as seen from `buffer_id`.
     ///
     /// Stays on `impl Editor` because the body fires plugin hooks
     /// (`apply_event_to_active_buffer`) and orchestrates cross-cutting
     /// state (active-buffer switch, viewport recentering). Moving it
     /// to `impl Window` waits for plugin-hook firing to be available
     /// from `Window`.
-    pub(super) fn jump_to_bookmark(&mut self, key: char) {
-        let Some(bookmark) = self.active_window_mut().bookmarks.get(key) else {
+    pub(super) fn jump_to_bookmark_in(&mut self, key: char, buffer_id: BufferId) {
+        let Some(bookmark) = self.active_window_mut().bookmarks.get(key, buffer_id) else {
             self.set_status_message(t!("bookmark.not_set", key = key).to_string());
             return;
         };
 
-        // Switch to the buffer if needed, or forget the bookmark if it's gone.
+        // Switch to the buffer if needed. A file mark whose buffer was
+        // closed reopens its file; any other bookmark is forgotten.
         if bookmark.buffer_id != self.active_buffer() {
             if self
                 .windows
@@ -93,9 +164,22 @@ impl Editor {
                 .contains_key(&bookmark.buffer_id)
             {
                 self.set_active_buffer(bookmark.buffer_id);
+            } else if let Some(path) = bookmark.file_path.clone() {
+                match self.open_file(&path) {
+                    Ok(new_id) => self
+                        .active_window_mut()
+                        .bookmarks
+                        .rebind_buffer(bookmark.buffer_id, new_id),
+                    Err(e) => {
+                        self.set_status_message(
+                            t!("file.error_opening", error = e.to_string()).to_string(),
+                        );
+                        return;
+                    }
+                }
             } else {
                 self.set_status_message(t!("bookmark.buffer_gone", key = key).to_string());
-                self.active_window_mut().bookmarks.remove(key);
+                self.active_window_mut().bookmarks.remove(key, buffer_id);
                 return;
             }
         }
@@ -121,8 +205,9 @@ impl Editor {
         // Bookmarks can point anywhere in the file; the viewport must scroll
         // to follow the jump even when the bookmark target is in the same
         // buffer that's already visible (#1689).
+        let scroll_on_jump = self.config.editor.scroll_on_jump;
         self.active_window_mut()
-            .ensure_active_cursor_visible_for_navigation(true);
+            .ensure_active_cursor_visible_for_navigation(scroll_on_jump);
         self.set_status_message(t!("bookmark.jumped", key = key).to_string());
     }
 }
@@ -1,52 +1,190 @@
 //! Self-contained bookmark storage.
 //!
 //! A bookmark remembers a `(buffer_id, byte_position)` under a single-char
-//! register. The state is deliberately minimal: lookup, insert, remove,
-//! iterate. Cross-cutting work — checking that a bookmark's target buffer
-//! still exists, jumping the cursor, rendering a list — lives on `Editor`.
+//! register. Registers come in two scopes, Vim-style:
+//!
+//! - lowercase letters are local marks: each buffer has its own `a`..`z`,
+//!   and jumping to one stays in the current buffer;
+//! - uppercase letters (and digits) are global marks: one per window,
+//!   jumping to one switches to its buffer. Uppercase marks also remember
+//!   the file, so jumping reopens it if its buffer was closed.
+//!
+//! The state is deliberately minimal: lookup, insert, remove, iterate.
+//! Cross-cutting work — checking that a bookmark's target buffer still
+//! exists, jumping the cursor, rendering a list — lives on `Editor`.
+//!
+//! Persisted bookmarks also carry a [`LineAnchor`] — a hash of the
+//! bookmarked line — so a bookmark restored into a file that changed while
+//! it was closed can follow its line to where it moved.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use crate::model::event::BufferId;
 
 /// A bookmark: a position within a specific buffer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Bookmark {
     pub buffer_id: BufferId,
     pub position: usize,
+    /// The buffer's file, kept for global marks so they can reopen it
+    /// after the buffer is closed.
+    pub file_path: Option<PathBuf>,
+}
+
+/// True for registers that are local to a buffer (lowercase letters).
+pub(crate) fn is_local_key(key: char) -> bool {
+    key.is_ascii_lowercase()
+}
+
+/// True for registers that only exist once per window (uppercase letters).
+/// These remember their file and reopen it on jump.
+pub(crate) fn is_file_mark_key(key: char) -> bool {
+    key.is_ascii_uppercase()
 }
 
-/// Owner of the register -> bookmark map.
+/// True for characters usable as a bookmark register.
+pub(crate) fn is_bookmark_key(key: char) -> bool {
+    key.is_ascii_alphanumeric()
+}
+
+/// Owner of the register -> bookmark maps.
 #[derive(Debug, Default)]
 pub(crate) struct BookmarkState {
+    /// Global marks (digits and uppercase letters).
     bookmarks: HashMap<char, Bookmark>,
+    /// Local marks (lowercase letters), one set per buffer.
+    local: HashMap<(BufferId, char), Bookmark>,
 }
 
 impl BookmarkState {
-    /// Store (or replace) a bookmark under `key`.
+    /// Store (or replace) a bookmark under `key`. Local keys are stored
+    /// against `bookmark.buffer_id`.
     pub(crate) fn set(&mut self, key: char, bookmark: Bookmark) {
-        self.bookmarks.insert(key, bookmark);
+        if is_local_key(key) {
+            self.local.insert((bookmark.buffer_id, key), bookmark);
+        } else {
+            self.bookmarks.insert(key, bookmark);
+        }
+    }
+
+    /// Retrieve the bookmark under `key` as seen from `buffer_id`: local
+    /// keys only find that buffer's mark.
+    pub(crate) fn get(&self, key: char, buffer_id: BufferId) -> Option<Bookmark> {
+        if is_local_key(key) {
+            self.local.get(&(buffer_id, key)).cloned()
+        } else {
+            self.bookmarks.get(&key).cloned()
+        }
     }
 
-    /// Retrieve the bookmark under `key`, if any.
-    pub(crate) fn get(&self, key: char) -> Option<Bookmark> {
-        self.bookmarks.get(&key).copied()
+    /// Remove the bookmark under `key` as seen from `buffer_id`. Returns
+    /// `true` if one existed.
+    pub(crate) fn remove(&mut self, key: char, buffer_id: BufferId) -> bool {
+        if is_local_key(key) {
+            self.local.remove(&(buffer_id, key)).is_some()
+        } else {
+            self.bookmarks.remove(&key).is_some()
+        }
     }
 
-    /// Remove the bookmark under `key`. Returns `true` if one existed.
-    pub(crate) fn remove(&mut self, key: char) -> bool {
-        self.bookmarks.remove(&key).is_some()
+    /// Point global marks held by `old` at `new`, e.g. after a closed
+    /// file was reopened for a jump.
+    pub(crate) fn rebind_buffer(&mut self, old: BufferId, new: BufferId) {
+        for bookmark in self.bookmarks.values_mut() {
+            if bookmark.buffer_id == old {
+                bookmark.buffer_id = new;
+            }
+        }
     }
 
     /// True when no bookmarks are set.
     pub(crate) fn is_empty(&self) -> bool {
-        self.bookmarks.is_empty()
+        self.bookmarks.is_empty() && self.local.is_empty()
     }
 
-    /// Iterate over all (key, bookmark) pairs — used by serialization and
-    /// the `list_bookmarks` orchestrator on `Editor`.
+    /// Iterate over all (key, bookmark) pairs, global and local — used by
+    /// serialization and the bookmarks panel on `Editor`.
     pub(crate) fn iter(&self) -> impl Iterator<Item = (char, Bookmark)> + '_ {
-        self.bookmarks.iter().map(|(k, v)| (*k, *v))
+        self.bookmarks
+            .iter()
+            .map(|(k, v)| (*k, v.clone()))
+            .chain(self.local.iter().map(|((_, k), v)| (*k, v.clone())))
+    }
+}
+
+/// Where a bookmark sits by content rather than by offset: the hash of its
+/// line's trimmed text and the byte column within that line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LineAnchor {
+    pub hash: String,
+    pub column: usize,
+}
+
+/// Stable hash of a line's trimmed text, or `None` for a blank line (blank
+/// lines are too common to identify anything).
+fn line_hash(line: &str) -> Option<String> {
+    use sha2::{Digest, Sha256};
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(trimmed.as_bytes());
+    Some(format!("{:x}", hasher.finalize())[..16].to_string())
+}
+
+/// Byte offsets at which each line of `text` starts.
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// The line (index and text, without its newline) containing `position`.
+fn line_at(text: &str, starts: &[usize], position: usize) -> (usize, &str) {
+    let index = starts.partition_point(|&s| s <= position).saturating_sub(1);
+    let start = starts[index];
+    let end = starts.get(index + 1).map_or(text.len(), |&next| next - 1);
+    (index, text[start..end].trim_end_matches('\r'))
+}
+
+/// Anchor for a bookmark at `position` in `text`. `None` when the position
+/// is on a blank line.
+pub(crate) fn anchor_at(text: &str, position: usize) -> Option<LineAnchor> {
+    let position = position.min(text.len());
+    let starts = line_starts(text);
+    let (index, line) = line_at(text, &starts, position);
+    Some(LineAnchor {
+        hash: line_hash(line)?,
+        column: position - starts[index],
+    })
+}
+
+/// Resolve a persisted bookmark against the file's current `text`. If the
+/// line at `position` no longer matches the anchor, the bookmark moves to
+/// the matching line nearest its old one (keeping its column); if no line
+/// matches, it stays at `position`, clamped to the text.
+pub(crate) fn relocate(text: &str, position: usize, anchor: &LineAnchor) -> usize {
+    let position = position.min(text.len());
+    let starts = line_starts(text);
+    let (old_index, line) = line_at(text, &starts, position);
+    if line_hash(line).as_ref() == Some(&anchor.hash) {
+        return position;
+    }
+
+    let best = (0..starts.len())
+        .filter(|&i| {
+            let (_, line) = line_at(text, &starts, starts[i]);
+            line_hash(line).as_ref() == Some(&anchor.hash)
+        })
+        .min_by_key(|&i| i.abs_diff(old_index));
+    match best {
+        Some(i) => {
+            let (_, line) = line_at(text, &starts, starts[i]);
+            starts[i] + anchor.column.min(line.len())
+        }
+        None => position,
     }
 }
 
@@ -58,6 +196,7 @@ mod tests {
         Bookmark {
             buffer_id: BufferId(buffer),
             position: pos,
+            file_path: None,
         }
     }
 
@@ -65,7 +204,7 @@ mod tests {
     fn default_is_empty() {
         let s = BookmarkState::default();
         assert!(s.is_empty());
-        assert_eq!(s.get('a'), None);
+        assert_eq!(s.get('a', BufferId(1)), None);
     }
 
     #[test]
@@ -73,7 +212,7 @@ mod tests {
         let mut s = BookmarkState::default();
         s.set('1', bm(7, 42));
         assert!(!s.is_empty());
-        assert_eq!(s.get('1'), Some(bm(7, 42)));
+        assert_eq!(s.get('1', BufferId(1)), Some(bm(7, 42)));
     }
 
     #[test]
@@ -81,21 +220,21 @@ mod tests {
         let mut s = BookmarkState::default();
         s.set('1', bm(7, 42));
         s.set('1', bm(3, 99));
-        assert_eq!(s.get('1'), Some(bm(3, 99)));
+        assert_eq!(s.get('1', BufferId(1)), Some(bm(3, 99)));
     }
 
     #[test]
     fn remove_returns_true_when_bookmark_existed() {
         let mut s = BookmarkState::default();
         s.set('1', bm(7, 42));
-        assert!(s.remove('1'));
-        assert_eq!(s.get('1'), None);
+        assert!(s.remove('1', BufferId(1)));
+        assert_eq!(s.get('1', BufferId(1)), None);
     }
 
     #[test]
     fn remove_returns_false_when_no_bookmark() {
         let mut s = BookmarkState::default();
-        assert!(!s.remove('1'));
+        assert!(!s.remove('1', BufferId(1)));
     }
 
     #[test]
@@ -107,4 +246,70 @@ mod tests {
         seen.sort_by_key(|(k, _)| *k);
         assert_eq!(seen, vec![('a', bm(1, 10)), ('b', bm(2, 20))]);
     }
+
+    #[test]
+    fn lowercase_marks_are_per_buffer_and_uppercase_are_global() {
+        let mut s = BookmarkState::default();
+        s.set('a', bm(1, 10));
+        s.set('a', bm(2, 20));
+        s.set('A', bm(1, 30));
+
+        assert_eq!(s.get('a', BufferId(1)), Some(bm(1, 10)));
+        assert_eq!(s.get('a', BufferId(2)), Some(bm(2, 20)));
+        assert_eq!(s.get('a', BufferId(3)), None);
+        assert_eq!(s.get('A', BufferId(2)), Some(bm(1, 30)));
+
+        assert!(s.remove('a', BufferId(2)));
+        assert_eq!(s.get('a', BufferId(1)), Some(bm(1, 10)));
+
+        s.rebind_buffer(BufferId(1), BufferId(9));
+        assert_eq!(s.get('A', BufferId(2)), Some(bm(9, 30)));
+        // Local marks belong to their buffer and are not rebound.
+        assert_eq!(s.get('a', BufferId(1)), Some(bm(1, 10)));
+    }
+
+    #[test]
+    fn anchor_follows_line_that_moved() {
+        let before = "fn a() {}\nfn target() {\n    body();\n}\n";
+        let position = before.find("target").unwrap();
+        let anchor = anchor_at(before, position).unwrap();
+        assert_eq!(anchor.column, 3);
+
+        // Unchanged text keeps the position as-is.
+        assert_eq!(relocate(before, position, &anchor), position);
+
+        // Two lines inserted above: the bookmark follows `fn target`.
+        let after = "// header\n// more\nfn a() {}\nfn target() {\n    body();\n}\n";
+        assert_eq!(
+            relocate(after, position, &anchor),
+            after.find("target").unwrap()
+        );
+    }
+
+    #[test]
+    fn relocate_prefers_nearest_match_and_falls_back_to_offset() {
+        let text = "dup\nother\nother\nother\ndup\n";
+        let anchor = LineAnchor {
+            hash: line_hash("dup").unwrap(),
+            column: 1,
+        };
+        // Old position on line 3 (0-based): line 4 is nearer than line 0.
+        let line3 = text.match_indices('\n').nth(2).unwrap().0 + 1;
+        assert_eq!(
+            relocate(text, line3, &anchor),
+            text.rfind("dup").unwrap() + 1
+        );
+
+        // The line is gone entirely: keep the (clamped) offset.
+        let gone = LineAnchor {
+            hash: line_hash("missing").unwrap(),
+            column: 0,
+        };
+        assert_eq!(relocate(text, 999, &gone), text.len());
+    }
+
+    #[test]
+    fn blank_lines_are_not_anchored() {
+        assert_eq!(anchor_at("a\n\nb", 2), None);
+    }
 }
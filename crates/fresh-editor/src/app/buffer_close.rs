@@ -90,7 +90,7 @@ impl Editor {
 
         // Complete any --wait tracking for this buffer
         if let Some((wait_id, _)) = self.active_window_mut().wait_tracking.remove(&id) {
-            self.active_window_mut().completed_waits.push(wait_id);
+            self.complete_wait(wait_id);
         }
 
         // Save file state before closing (for per-file session persistence)
@@ -182,6 +182,12 @@ impl Editor {
 
         self.purge_buffer_state(id);
 
+        // A diff against disk owns its hidden snapshot buffer: free both.
+        if let Some(snapshot) = self.active_window_mut().disk_snapshots.remove(&id) {
+            self.active_window_mut().close_composite_buffer(id);
+            self.purge_buffer_state(snapshot);
+        }
+
         if closing_active {
             if created_empty_buffer && self.config.file_explorer.auto_open_on_last_buffer_close {
                 self.focus_file_explorer();
@@ -1250,8 +1256,9 @@ impl Editor {
                 // Position-history entries can land anywhere in the buffer;
                 // the viewport must scroll to the restored cursor or the user
                 // sees the same page after Ctrl+- / Ctrl+= (#1689).
+                let scroll_on_jump = self.config.editor.scroll_on_jump;
                 self.active_window_mut()
-                    .ensure_active_cursor_visible_for_navigation(true);
+                    .ensure_active_cursor_visible_for_navigation(scroll_on_jump);
             }
         }
 
@@ -1306,8 +1313,9 @@ impl Editor {
                 // Position-history entries can land anywhere in the buffer;
                 // the viewport must scroll to the restored cursor or the user
                 // sees the same page after Ctrl+- / Ctrl+= (#1689).
+                let scroll_on_jump = self.config.editor.scroll_on_jump;
                 self.active_window_mut()
-                    .ensure_active_cursor_visible_for_navigation(true);
+                    .ensure_active_cursor_visible_for_navigation(scroll_on_jump);
             }
         }
 
@@ -0,0 +1,237 @@
+//! Compare buffers side by side.
+//!
+//! `CompareBuffers` prompts for the left buffer, then the right one, and
+//! opens a side-by-side composite diff of their current contents.
+//! `DiffAgainstDisk` diffs the active buffer against its file on disk,
+//! loaded into a hidden read-only buffer for the left pane that is freed
+//! when the diff is closed. Both diffs are
+//! computed in-editor with `line_diff::changed_runs` — no git involved —
+//! so unsaved changes and unnamed buffers can be compared too.
+//! `fresh --diff LEFT RIGHT` opens both files and starts in their diff.
+
+use std::path::Path;
+
+use rust_i18n::t;
+
+use crate::input::commands::Suggestion;
+use crate::model::composite_buffer::{
+    CompositeLayout, DiffHunk, LineAlignment, PaneStyle, SourcePane,
+};
+use crate::model::event::BufferId;
+use crate::model::line_diff::changed_runs;
+use crate::view::prompt::PromptType;
+
+use super::Editor;
+
+impl Editor {
+    /// Prompt for a buffer to compare. With `first` unset this picks the
+    /// left side; otherwise it picks the buffer to compare `first` with.
+    pub(super) fn start_compare_buffers_prompt(&mut self, first: Option<BufferId>) {
+        let window = self.active_window();
+        let mut candidates: Vec<(BufferId, String)> = window
+            .buffer_metadata
+            .iter()
+            .filter(|(id, meta)| {
+                !meta.is_virtual() && !meta.hidden_from_tabs && window.buffers.contains_key(id)
+            })
+            .map(|(id, meta)| (*id, meta.display_name.clone()))
+            .collect();
+        candidates.sort_by_key(|(id, _)| id.0);
+
+        if candidates.is_empty() {
+            self.set_status_message(t!("compare.no_buffers").to_string());
+            return;
+        }
+
+        // Preselect the active buffer for the left side and some other
+        // buffer for the right side.
+        let active = self.active_buffer();
+        let selected = match first {
+            None => candidates.iter().position(|(id, _)| *id == active),
+            Some(first) => candidates
+                .iter()
+                .position(|(id, _)| *id != first && *id == active)
+                .or_else(|| candidates.iter().position(|(id, _)| *id != first)),
+        };
+
+        let suggestions: Vec<Suggestion> = candidates
+            .into_iter()
+            .map(|(id, name)| Suggestion {
+                description_spans: None,
+                text: name,
+                description: None,
+                value: Some(id.0.to_string()),
+                disabled: false,
+                keybinding: None,
+                source: None,
+            })
+            .collect();
+
+        let message = match first {
+            None => t!("compare.prompt_first").to_string(),
+            Some(first) => {
+                let name = self.compare_buffer_name(first);
+                t!("compare.prompt_second", name = name).to_string()
+            }
+        };
+        self.start_prompt_with_suggestions(
+            message,
+            PromptType::CompareBuffers { first },
+            suggestions,
+        );
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            prompt.selected_suggestion = selected.or(Some(0));
+        }
+    }
+
+    /// Open a side-by-side diff of `left` against `right`. Comparing a
+    /// buffer with itself does nothing.
+    pub(crate) fn compare_buffers(&mut self, left: BufferId, right: BufferId) {
+        if left == right {
+            self.set_status_message(t!("compare.same_buffer").to_string());
+            return;
+        }
+        let text_of = |editor: &Self, id: BufferId| {
+            editor
+                .active_window()
+                .buffers
+                .get(&id)
+                .and_then(|state| state.buffer.to_string())
+        };
+        let (Some(left_text), Some(right_text)) = (text_of(self, left), text_of(self, right))
+        else {
+            self.set_status_message(t!("compare.not_loaded").to_string());
+            return;
+        };
+
+        let left_name = self.compare_buffer_name(left);
+        let right_name = self.compare_buffer_name(right);
+        let title = t!("compare.title", left = &left_name, right = &right_name).to_string();
+        self.open_side_by_side_diff(
+            title,
+            (left, left_name, &left_text),
+            (right, right_name, &right_text),
+        );
+    }
+
+    /// Open the files at `left` and `right` and show their side-by-side
+    /// diff, as `fresh --diff` does at startup.
+    pub fn diff_files(&mut self, left: &Path, right: &Path) -> anyhow::Result<()> {
+        let left_id = self.open_file(left)?;
+        let right_id = self.open_file(right)?;
+        self.compare_buffers(left_id, right_id);
+        Ok(())
+    }
+
+    /// Diff the active buffer against the saved file. A buffer whose file
+    /// does not exist on disk (or that has no file) diffs against nothing,
+    /// so every line shows as added.
+    pub(super) fn diff_against_disk(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(current) = self.active_state().buffer.to_string() else {
+            self.set_status_message(t!("compare.not_loaded").to_string());
+            return;
+        };
+        let path = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|p| p.to_path_buf());
+        let on_disk = match &path {
+            Some(path) => match self.authority().filesystem.read_file(path) {
+                Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(e) => {
+                    self.set_status_message(
+                        t!("file.error_opening", error = e.to_string()).to_string(),
+                    );
+                    return;
+                }
+            },
+            None => String::new(),
+        };
+
+        let name = self.compare_buffer_name(buffer_id);
+        let disk_label = t!("compare.on_disk", name = &name).to_string();
+        let disk_id = self.active_window_mut().create_virtual_buffer_detached(
+            disk_label.clone(),
+            "text".to_string(),
+            true,
+        );
+        if let Some(meta) = self.active_window_mut().buffer_metadata.get_mut(&disk_id) {
+            meta.hidden_from_tabs = true;
+        }
+        if let Err(e) = self.set_virtual_buffer_content(
+            disk_id,
+            vec![crate::primitives::text_property::TextPropertyEntry::text(
+                &on_disk,
+            )],
+        ) {
+            tracing::warn!("Failed to fill on-disk buffer for diff: {}", e);
+            return;
+        }
+
+        let title = t!("compare.disk_title", name = &name).to_string();
+        let composite_id = self.open_side_by_side_diff(
+            title,
+            (disk_id, disk_label, &on_disk),
+            (buffer_id, name, &current),
+        );
+        self.active_window_mut()
+            .disk_snapshots
+            .insert(composite_id, disk_id);
+    }
+
+    /// Open a composite showing `left` and `right` (buffer, pane label,
+    /// text) side by side, aligned on their unchanged lines, and report
+    /// how many regions differ. Returns the composite's id.
+    fn open_side_by_side_diff(
+        &mut self,
+        title: String,
+        left: (BufferId, String, &str),
+        right: (BufferId, String, &str),
+    ) -> BufferId {
+        let (left_id, left_label, left_text) = left;
+        let (right_id, right_label, right_text) = right;
+        let runs = changed_runs(left_text.as_bytes(), right_text.as_bytes());
+        let hunks: Vec<DiffHunk> = runs
+            .iter()
+            .map(|run| DiffHunk::new(run.old_start, run.old_count, run.new_start, run.new_count))
+            .collect();
+        let alignment = LineAlignment::from_hunks(
+            &hunks,
+            left_text.split('\n').count(),
+            right_text.split('\n').count(),
+        );
+
+        let sources = vec![
+            SourcePane::new(left_id, left_label, false).with_style(PaneStyle::old_diff()),
+            SourcePane::new(right_id, right_label, false).with_style(PaneStyle::new_diff()),
+        ];
+        let layout = CompositeLayout::SideBySide {
+            ratios: vec![0.5, 0.5],
+            show_separator: true,
+        };
+        let composite_id =
+            self.create_composite_buffer(title, "diff-view".to_string(), layout, sources);
+        self.active_window_mut()
+            .set_composite_alignment(composite_id, alignment);
+        self.set_active_buffer(composite_id);
+
+        let message = if hunks.is_empty() {
+            t!("compare.identical").to_string()
+        } else {
+            t!("compare.differences", count = hunks.len()).to_string()
+        };
+        self.set_status_message(message);
+        composite_id
+    }
+
+    fn compare_buffer_name(&self, id: BufferId) -> String {
+        self.active_window()
+            .buffer_metadata
+            .get(&id)
+            .map(|meta| meta.display_name.clone())
+            .unwrap_or_else(|| format!("Buffer {:?}", id))
+    }
+}
@@ -0,0 +1,531 @@
+//! Ctrl+Click / Ctrl+hover to follow links in editor buffers.
+//!
+//! A link is either an overlay carrying a URL — the `document_links` plugin
+//! paints one per LSP `textDocument/documentLink` result, and other plugins
+//! (markdown) do the same — or, as a fallback, text that looks like a URL
+//! (`https://…`, `file://…`) or a file path with an optional `:line[:col]`
+//! suffix. URLs open in the system browser; paths (and `file://` URLs) open
+//! in Fresh, jumping to the encoded location.
+//!
+//! Ctrl+hover underlines the link under the pointer so the user can see what
+//! a click would follow; the `open_link_at_cursor` action follows the link
+//! under the primary cursor from the keyboard. Path detection reuses the
+//! terminal's [`path_link`] parser; relative paths resolve against the
+//! buffer's directory, then Fresh's working directory, through the editor's
+//! filesystem so it works on remote hosts too.
+//!
+//! [`path_link`]: crate::services::terminal::path_link
+
+use crate::app::window::{BufferLinkHover, Window};
+use crate::app::Editor;
+use crate::model::event::BufferId;
+use crate::primitives::path_utils::expand_tilde;
+use crate::services::terminal::path_link;
+use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use anyhow::Result as AnyhowResult;
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rust_i18n::t;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Overlay namespace for the Ctrl+hover underline.
+const HOVER_NAMESPACE: &str = "buffer-link-hover";
+
+/// Schemes recognised by the textual URL fallback.
+const URL_SCHEMES: &[&str] = &["https://", "http://", "file://", "ftp://"];
+
+/// What a link in a buffer points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LinkTarget {
+    /// Opened in the system browser, or in Fresh for `file://` URLs.
+    Url(String),
+    /// A file path as written, not yet resolved. Line/column are 1-based.
+    Path {
+        path: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+}
+
+/// A link detected within one line of buffer text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BufferLink {
+    /// Character range within the line covered by the link.
+    pub range: Range<usize>,
+    pub target: LinkTarget,
+}
+
+/// Find every URL and path-like link in `line`, in order.
+///
+/// URLs are found first so their path-shaped tails (`example.com/docs`) aren't
+/// reported again as paths. Paths are only textual candidates; callers check
+/// that they resolve before treating them as clickable.
+pub(crate) fn find_links(line: &str) -> Vec<BufferLink> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut links = find_urls(&chars);
+
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let token_end = (i..chars.len())
+            .find(|&j| chars[j].is_whitespace())
+            .unwrap_or(chars.len());
+        let inside_url = links
+            .iter()
+            .any(|l| l.range.start < token_end && i < l.range.end);
+        if !inside_url {
+            if let Some(found) = path_link::detect_link_at(line, i) {
+                links.push(BufferLink {
+                    range: found.range,
+                    target: LinkTarget::Path {
+                        path: found.path,
+                        line: found.line,
+                        column: found.column,
+                    },
+                });
+            }
+        }
+        i = token_end;
+    }
+
+    links.sort_by_key(|l| l.range.start);
+    links
+}
+
+/// The link covering character column `col` of `line`, if any.
+pub(crate) fn detect_buffer_link_at(line: &str, col: usize) -> Option<BufferLink> {
+    find_links(line)
+        .into_iter()
+        .find(|l| l.range.contains(&col))
+}
+
+fn find_urls(chars: &[char]) -> Vec<BufferLink> {
+    let mut urls = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let at_boundary = i == 0 || !chars[i - 1].is_alphanumeric();
+        let scheme = URL_SCHEMES
+            .iter()
+            .find(|s| at_boundary && starts_with_at(chars, i, s));
+        let Some(scheme) = scheme else {
+            i += 1;
+            continue;
+        };
+
+        let body_start = i + scheme.len();
+        let mut end = body_start;
+        while end < chars.len()
+            && !chars[end].is_whitespace()
+            && !matches!(chars[end], '<' | '>' | '"' | '\'' | '`')
+        {
+            end += 1;
+        }
+        // Peel sentence punctuation and closers that don't belong to the
+        // URL — a `)` is kept only when it balances a `(` inside the URL,
+        // as in Wikipedia-style `…/Foo_(bar)`.
+        while end > body_start {
+            let c = chars[end - 1];
+            let unbalanced = |open: char, close: char| {
+                let s = &chars[i..end];
+                s.iter().filter(|&&x| x == close).count() > s.iter().filter(|&&x| x == open).count()
+            };
+            let strip = match c {
+                '.' | ',' | ';' | ':' | '!' | '?' => true,
+                ')' => unbalanced('(', ')'),
+                ']' => unbalanced('[', ']'),
+                '}' => unbalanced('{', '}'),
+                _ => false,
+            };
+            if !strip {
+                break;
+            }
+            end -= 1;
+        }
+
+        if end > body_start {
+            urls.push(BufferLink {
+                range: i..end,
+                target: LinkTarget::Url(chars[i..end].iter().collect()),
+            });
+        }
+        i = end.max(i + 1);
+    }
+    urls
+}
+
+fn starts_with_at(chars: &[char], at: usize, needle: &str) -> bool {
+    let mut j = at;
+    for c in needle.chars() {
+        if chars.get(j).map(|x| x.to_ascii_lowercase()) != Some(c) {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Split a `file://` URL into a local path and the line/column encoded in
+/// its fragment (`#L12`, `#L12,3`, `#12:3`), as servers commonly emit for
+/// document links.
+fn file_url_location(url: &str) -> Option<(PathBuf, Option<usize>, Option<usize>)> {
+    let (base, fragment) = match url.split_once('#') {
+        Some((base, fragment)) => (base, Some(fragment)),
+        None => (url, None),
+    };
+    let path = fresh_core::file_uri::file_uri_to_path(base)?;
+    let (line, column) = fragment
+        .map(|f| {
+            let f = f.trim_start_matches('L');
+            let mut parts = f.splitn(2, [',', ':']);
+            let line = parts.next().and_then(|l| l.parse().ok());
+            let column = parts
+                .next()
+                .and_then(|c| c.trim_start_matches('C').parse().ok());
+            (line, column)
+        })
+        .unwrap_or((None, None));
+    Some((path, line, column))
+}
+
+impl Window {
+    /// The editor buffer and byte offset under a screen cell, skipping
+    /// terminal and composite buffers (which have their own link handling or
+    /// none at all) and clicks in the gutter.
+    fn buffer_byte_at_screen(&self, col: u16, row: u16) -> Option<(BufferId, usize)> {
+        let (split_id, buffer_id, content_rect) =
+            self.layout_cache
+                .split_areas
+                .iter()
+                .find_map(|(sid, bid, rect, _, _, _)| {
+                    (col >= rect.x
+                        && col < rect.x + rect.width
+                        && row >= rect.y
+                        && row < rect.y + rect.height)
+                        .then_some((*sid, *bid, *rect))
+                })?;
+        if self.is_terminal_buffer(buffer_id) || self.is_composite_buffer(buffer_id) {
+            return None;
+        }
+
+        let state = self.buffers.get(&buffer_id)?;
+        let gutter_width = state.margins.left_total_width() as u16;
+        let cached_mappings = self.layout_cache.view_line_mappings.get(&split_id).cloned();
+        let (fallback, compose_width) = self
+            .buffers
+            .splits()
+            .and_then(|(_, vs)| vs.get(&split_id))
+            .map(|vs| (vs.viewport.top_byte, vs.compose_width))
+            .unwrap_or((0, None));
+
+        let byte_pos = crate::app::click_geometry::screen_to_buffer_position(
+            col,
+            row,
+            content_rect,
+            gutter_width,
+            &cached_mappings,
+            fallback,
+            false,
+            compose_width,
+        )?;
+        Some((buffer_id, byte_pos))
+    }
+
+    /// The link covering `byte_pos` in `buffer_id`, as a byte range plus its
+    /// target. An overlay carrying a URL wins over textual detection.
+    pub(crate) fn buffer_link_at_byte(
+        &self,
+        buffer_id: BufferId,
+        byte_pos: usize,
+    ) -> Option<(Range<usize>, LinkTarget)> {
+        let state = self.buffers.get(&buffer_id)?;
+
+        if let Some((range, url)) = state
+            .overlays
+            .at_position(byte_pos, &state.marker_list)
+            .into_iter()
+            .find_map(|o| Some((o.range(&state.marker_list), o.url.clone()?)))
+        {
+            return Some((range, LinkTarget::Url(url)));
+        }
+
+        let pos = crate::model::buffer_position::byte_to_2d(&state.buffer, byte_pos);
+        let line_bytes = state.buffer.get_line(pos.line)?;
+        let line = String::from_utf8_lossy(&line_bytes);
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let char_col = line
+            .char_indices()
+            .take_while(|(b, _)| *b < pos.column)
+            .count();
+
+        let link = detect_buffer_link_at(line, char_col)?;
+        let line_start = byte_pos - pos.column;
+        let byte_of = |c: usize| line.char_indices().nth(c).map_or(line.len(), |(b, _)| b);
+        let range = line_start + byte_of(link.range.start)..line_start + byte_of(link.range.end);
+        Some((range, link.target))
+    }
+
+    /// Directory of the buffer's file, for resolving relative link paths.
+    fn buffer_dir(&self, buffer_id: BufferId) -> Option<PathBuf> {
+        self.buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .and_then(|p| p.parent())
+            .map(Path::to_path_buf)
+    }
+}
+
+impl Editor {
+    /// If this is a Ctrl+Left-click over a followable link in an editor
+    /// buffer, follow it and return `Some(Ok(true))` (event handled).
+    /// Returns `None` to let normal mouse handling proceed.
+    pub(crate) fn try_open_buffer_link(
+        &mut self,
+        col: u16,
+        row: u16,
+        mouse_event: MouseEvent,
+    ) -> Option<AnyhowResult<bool>> {
+        if !matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
+            || !mouse_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            return None;
+        }
+        // A popup drawn over the buffer owns the click.
+        if self.is_mouse_over_any_popup(col, row) {
+            return None;
+        }
+
+        let (buffer_id, byte_pos) = self.active_window().buffer_byte_at_screen(col, row)?;
+        let (_, target) = self
+            .active_window()
+            .buffer_link_at_byte(buffer_id, byte_pos)?;
+        if !self.buffer_link_followable(buffer_id, &target) {
+            return None;
+        }
+
+        self.set_buffer_link_hover(None);
+        Some(self.follow_buffer_link(buffer_id, target).map(|()| true))
+    }
+
+    /// Update the Ctrl+hover link underline for editor buffers.
+    ///
+    /// Returns true if the underlined span changed (a re-render is needed).
+    pub(crate) fn update_buffer_link_hover(
+        &mut self,
+        col: u16,
+        row: u16,
+        modifiers: KeyModifiers,
+    ) -> bool {
+        let new_hover = if modifiers.contains(KeyModifiers::CONTROL)
+            && !self.is_mouse_over_any_popup(col, row)
+        {
+            self.active_window()
+                .buffer_byte_at_screen(col, row)
+                .and_then(|(buffer_id, byte_pos)| {
+                    let (range, target) = self
+                        .active_window()
+                        .buffer_link_at_byte(buffer_id, byte_pos)?;
+                    self.buffer_link_followable(buffer_id, &target)
+                        .then_some(BufferLinkHover { buffer_id, range })
+                })
+        } else {
+            None
+        };
+        if self.active_window().buffer_link_hover == new_hover {
+            return false;
+        }
+        self.set_buffer_link_hover(new_hover);
+        true
+    }
+
+    /// Follow the link under the primary cursor (`open_link_at_cursor`).
+    pub(crate) fn open_link_at_cursor(&mut self) -> AnyhowResult<()> {
+        let buffer_id = self.active_buffer();
+        let byte_pos = self.active_cursors().primary().position;
+        let target = self
+            .active_window()
+            .buffer_link_at_byte(buffer_id, byte_pos)
+            .map(|(_, target)| target);
+        match target {
+            Some(target) => self.follow_buffer_link(buffer_id, target),
+            None => {
+                self.set_status_message(t!("link.none_at_cursor").to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Swap the hover underline overlay from the previous link to `hover`.
+    fn set_buffer_link_hover(&mut self, hover: Option<BufferLinkHover>) {
+        let ns = OverlayNamespace::from_string(HOVER_NAMESPACE.to_string());
+        let win = self.active_window_mut();
+        if let Some(old) = win.buffer_link_hover.take() {
+            if let Some(state) = win.buffers.get_mut(&old.buffer_id) {
+                state.overlays.clear_namespace(&ns, &mut state.marker_list);
+            }
+        }
+        if let Some(new) = &hover {
+            if let Some(state) = win.buffers.get_mut(&new.buffer_id) {
+                let style = ratatui::style::Style::default()
+                    .add_modifier(ratatui::style::Modifier::UNDERLINED);
+                let overlay = Overlay::with_namespace(
+                    &mut state.marker_list,
+                    new.range.clone(),
+                    OverlayFace::Style { style },
+                    ns,
+                )
+                .with_priority_value(20);
+                state.overlays.add(overlay);
+            }
+        }
+        win.buffer_link_hover = hover;
+    }
+
+    /// Whether clicking `target` would do something: URLs always can, paths
+    /// only when they resolve to an existing file — otherwise the underline
+    /// would promise a link that clicking can't honor.
+    fn buffer_link_followable(&self, buffer_id: BufferId, target: &LinkTarget) -> bool {
+        match target {
+            LinkTarget::Url(_) => true,
+            LinkTarget::Path { path, .. } => {
+                self.resolve_buffer_link_path(buffer_id, path).is_some()
+            }
+        }
+    }
+
+    fn follow_buffer_link(&mut self, buffer_id: BufferId, target: LinkTarget) -> AnyhowResult<()> {
+        match target {
+            LinkTarget::Url(url) => {
+                if let Some((path, line, column)) = file_url_location(&url) {
+                    return self.handle_open_file_at_location(path, line, column);
+                }
+                #[cfg(feature = "runtime")]
+                if let Err(e) = open::that(&url) {
+                    self.set_status_message(
+                        t!("link.open_failed", error = e.to_string()).to_string(),
+                    );
+                    return Ok(());
+                }
+                self.set_status_message(t!("link.opening", url = url).to_string());
+                Ok(())
+            }
+            LinkTarget::Path { path, line, column } => {
+                match self.resolve_buffer_link_path(buffer_id, &path) {
+                    Some(resolved) => self.handle_open_file_at_location(resolved, line, column),
+                    None => {
+                        self.set_status_message(t!("link.file_not_found", path = path).to_string());
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolve a path written in a buffer to an existing file: as-is if
+    /// absolute (after `~` expansion), else against the buffer's directory,
+    /// then Fresh's working directory.
+    fn resolve_buffer_link_path(&self, buffer_id: BufferId, raw: &str) -> Option<PathBuf> {
+        let expanded = expand_tilde(raw);
+
+        let candidates: Vec<PathBuf> = if expanded.is_absolute() {
+            vec![expanded]
+        } else {
+            let mut v = Vec::new();
+            if let Some(dir) = self.active_window().buffer_dir(buffer_id) {
+                v.push(dir.join(&expanded));
+            }
+            v.push(self.working_dir().join(&expanded));
+            v
+        };
+
+        let fs = &self.authority().filesystem;
+        candidates
+            .into_iter()
+            .find(|p| fs.is_file(p).unwrap_or(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(p: &str, line: Option<usize>, column: Option<usize>) -> LinkTarget {
+        LinkTarget::Path {
+            path: p.to_string(),
+            line,
+            column,
+        }
+    }
+
+    /// A URL and a `path:line` reference on the same line are both found,
+    /// with the URL's trailing comma and the prose around them excluded.
+    #[test]
+    fn finds_url_and_path_line_reference() {
+        let line = "see https://example.com/docs, and path.rs:10 for details";
+        let links = find_links(line);
+
+        assert_eq!(links.len(), 2, "{links:?}");
+        assert_eq!(
+            links[0].target,
+            LinkTarget::Url("https://example.com/docs".to_string())
+        );
+        assert_eq!(&line[links[0].range.clone()], "https://example.com/docs");
+        assert_eq!(links[1].target, path("path.rs", Some(10), None));
+        assert_eq!(&line[links[1].range.clone()], "path.rs:10");
+    }
+
+    /// Lookup by column picks the link under the column and nothing else.
+    #[test]
+    fn detect_at_column() {
+        let line = "see https://example.com/docs, and path.rs:10 for details";
+        assert!(matches!(
+            detect_buffer_link_at(line, 10).map(|l| l.target),
+            Some(LinkTarget::Url(_))
+        ));
+        assert_eq!(
+            detect_buffer_link_at(line, 36).map(|l| l.target),
+            Some(path("path.rs", Some(10), None))
+        );
+        assert_eq!(detect_buffer_link_at(line, 1), None);
+        assert_eq!(detect_buffer_link_at(line, 50), None);
+    }
+
+    /// A `)` that balances a `(` inside the URL stays; a wrapping one goes.
+    #[test]
+    fn url_parentheses_are_balanced() {
+        let line = "(https://en.wikipedia.org/wiki/Rust_(language)).";
+        let links = find_links(line);
+        assert_eq!(links.len(), 1, "{links:?}");
+        assert_eq!(
+            links[0].target,
+            LinkTarget::Url("https://en.wikipedia.org/wiki/Rust_(language)".to_string())
+        );
+    }
+
+    /// A scheme glued to a preceding word is not a URL start.
+    #[test]
+    fn scheme_requires_word_boundary() {
+        let links = find_links("xhttps://example.com");
+        assert!(links
+            .iter()
+            .all(|l| !matches!(l.target, LinkTarget::Url(_))));
+    }
+
+    #[test]
+    fn file_url_fragment_carries_location() {
+        let (p, line, column) = file_url_location("file:///tmp/src/lib.rs#L12,3").unwrap();
+        assert_eq!(
+            Some(p),
+            fresh_core::file_uri::file_uri_to_path("file:///tmp/src/lib.rs")
+        );
+        assert_eq!((line, column), (Some(12), Some(3)));
+
+        let (_, line, column) = file_url_location("file:///tmp/src/lib.rs").unwrap();
+        assert_eq!((line, column), (None, None));
+
+        assert!(file_url_location("https://example.com").is_none());
+    }
+}
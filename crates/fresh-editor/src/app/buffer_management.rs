@@ -13,6 +13,7 @@ use std::collections::HashSet;
 use std::path::Path;
 use std::sync::Arc;
 
+use crate::config::ScrollOnJump;
 use crate::model::event::{BufferId, Event, LeafId};
 use crate::state::EditorState;
 
@@ -440,8 +441,14 @@ impl Editor {
             // for live-preview jumps (Quick Open `:N`, Goto Line prompt) the
             // suggestion/prompt popup overlays the bottom of the screen,
             // obscuring the very line the user is navigating to. Recentering
-            // puts the target in the middle so it stays visible.
-            self.apply_event_to_active_buffer(&Event::Recenter);
+            // puts the target in the middle so it stays visible. Users who
+            // picked another `scroll_on_jump` placement get that instead.
+            match self.config.editor.scroll_on_jump {
+                ScrollOnJump::Center => self.apply_event_to_active_buffer(&Event::Recenter),
+                alignment => self
+                    .active_window_mut()
+                    .ensure_active_cursor_visible_for_navigation(alignment),
+            }
         }
     }
 
@@ -647,6 +654,41 @@ impl Editor {
         buffer_id
     }
 
+    /// Create a scratch buffer for quick notes: an unnamed, file-less buffer
+    /// titled `*scratch*` (then `*scratch 2*`, `*scratch 3*`, … while earlier
+    /// ones are still open). Like any new buffer it needs no path, and closing
+    /// it with content goes through the usual unsaved-changes prompt.
+    ///
+    /// `language` selects the syntax used for highlighting, as typed in the
+    /// "Set Language" prompt; `None` leaves the buffer as plain text.
+    pub fn open_scratch_buffer(&mut self, language: Option<&str>) -> BufferId {
+        let taken: HashSet<String> = self
+            .active_window()
+            .buffer_metadata
+            .values()
+            .map(|m| m.display_name.clone())
+            .collect();
+        let display_name = (1..)
+            .map(|n| match n {
+                1 => "*scratch*".to_string(),
+                n => format!("*scratch {}*", n),
+            })
+            .find(|name| !taken.contains(name))
+            .expect("unbounded range always yields a free name");
+
+        let buffer_id = self.new_buffer();
+        if let Some(metadata) = self.active_window_mut().buffer_metadata.get_mut(&buffer_id) {
+            metadata.display_name = display_name.clone();
+        }
+        self.set_status_message(t!("buffer.scratch_opened", name = display_name).to_string());
+
+        if let Some(language) = language {
+            self.handle_set_language(language);
+        }
+
+        buffer_id
+    }
+
     /// Get the current mouse hover state for testing
     /// Returns Some((byte_position, screen_x, screen_y)) if hovering over text
     pub fn get_mouse_hover_state(&self) -> Option<(usize, u16, u16)> {
@@ -0,0 +1,133 @@
+//! MRU buffer switcher (`BufferSwitcher`, Ctrl+Tab).
+//!
+//! Lists the active split's tabs most-recently-focused first in a centered
+//! action popup, with the previously focused buffer preselected — so a
+//! single Ctrl+Tab tap flips between the last two buffers. While Ctrl is
+//! held, further Tab taps move the selection down (Shift+Tab moves it
+//! back up) and releasing Ctrl switches to the selected buffer.
+//!
+//! Key releases only arrive when the terminal reports event types
+//! (`keyboard_report_event_types`), and a bare Ctrl release only with
+//! `keyboard_report_all_keys_as_escape_codes` too. Without them the
+//! switcher behaves like any other action popup: Enter switches, Escape
+//! cancels.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, ModifierKeyCode};
+use rust_i18n::t;
+
+use crate::model::event::BufferId;
+use crate::view::popup::{Popup, PopupKind, PopupListItem, PopupPosition, PopupResolver};
+
+use super::Editor;
+
+impl Editor {
+    /// Open the MRU buffer switcher over the active split's tabs.
+    pub(super) fn show_buffer_switcher(&mut self) {
+        let active_split = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .map(|(mgr, _)| mgr)
+            .expect("active window must have a populated split layout")
+            .active_split();
+        let ordered = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .map(|(_, vs)| vs)
+            .expect("active window must have a populated split layout")
+            .get(&active_split)
+            .map(|vs| vs.buffer_tabs_by_recent_focus())
+            .unwrap_or_default();
+        if ordered.len() < 2 {
+            self.set_status_message(t!("buffer_switcher.no_other_buffers").to_string());
+            return;
+        }
+
+        let root = self.working_dir().to_path_buf();
+        let window = self.active_window();
+        let items: Vec<PopupListItem> = ordered
+            .iter()
+            .map(|&buffer_id| {
+                let meta = window.buffer_metadata.get(&buffer_id);
+                let name = meta
+                    .map(|m| m.display_name.clone())
+                    .unwrap_or_else(|| format!("Buffer {:?}", buffer_id));
+                let modified = window
+                    .buffers
+                    .get(&buffer_id)
+                    .is_some_and(|state| state.buffer.is_modified());
+                let label = if modified {
+                    format!("{} [+]", name)
+                } else {
+                    name
+                };
+                let item = PopupListItem::new(label).with_data(buffer_id.0.to_string());
+                match meta.and_then(|m| m.file_path()) {
+                    Some(path) => item.with_detail(
+                        path.strip_prefix(&root)
+                            .unwrap_or(path)
+                            .display()
+                            .to_string(),
+                    ),
+                    None => item,
+                }
+            })
+            .collect();
+
+        let mut popup = Popup::list(items, &self.theme.read().unwrap());
+        popup.kind = PopupKind::Action;
+        popup.title = Some(t!("buffer_switcher.title").to_string());
+        popup.position = PopupPosition::Centered;
+        popup.width = 60;
+        popup.max_height = 15;
+        popup.resolver = PopupResolver::BufferSwitcher;
+        popup.focused = true;
+        // Row 0 is the buffer being left; preselect the one focused before it.
+        popup.select_index(1);
+
+        let buffer_id = self.active_buffer();
+        if let Some(state) = self.active_window_mut().buffers.get_mut(&buffer_id) {
+            state.popups.show_or_replace(popup);
+        }
+    }
+
+    /// Switch to the buffer picked in the switcher, if it is still open.
+    pub(super) fn switch_from_buffer_switcher(&mut self, buffer_id: BufferId) {
+        if buffer_id == self.active_buffer() {
+            return;
+        }
+        if self.active_window().buffers.contains_key(&buffer_id) {
+            self.set_active_buffer(buffer_id);
+        } else {
+            self.set_status_message(t!("status.previous_tab_closed").to_string());
+        }
+    }
+
+    /// Handle a key-release event. Releasing Ctrl while the buffer switcher
+    /// is open commits its selection. Returns true when the release changed
+    /// anything (and so needs a render).
+    pub fn handle_key_release(&mut self, key_event: KeyEvent) -> bool {
+        let switcher_open = self
+            .active_state()
+            .popups
+            .top()
+            .is_some_and(|p| p.resolver == PopupResolver::BufferSwitcher);
+        if !switcher_open {
+            return false;
+        }
+        // Either Ctrl itself is reported, or Tab comes up after Ctrl did.
+        // Other releases (e.g. the Enter that ran the palette command) are
+        // not a commit.
+        let ctrl_released = match key_event.code {
+            KeyCode::Modifier(ModifierKeyCode::LeftControl | ModifierKeyCode::RightControl) => true,
+            KeyCode::Tab | KeyCode::BackTab => !key_event.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+        if !ctrl_released {
+            return false;
+        }
+        let _ = self.handle_popup_confirm();
+        true
+    }
+}
@@ -615,6 +615,8 @@ impl Editor {
         // The file explorer has a 1-line border at top and bottom
         let relative_row = row.saturating_sub(explorer_area.y + 1); // +1 for top border
 
+        // Entry under the press, armed as a drag-to-move source below
+        let mut pressed_path = None;
         if let Some(explorer) = self.file_explorer_mut().as_mut() {
             let display_nodes = explorer.get_display_nodes();
             let scroll_offset = explorer.get_scroll_offset();
@@ -629,6 +631,9 @@ impl Editor {
                 // Check if it's a file or directory
                 let node = explorer.tree().get_node(node_id);
                 if let Some(node) = node {
+                    if node_id != explorer.tree().root_id() {
+                        pressed_path = Some(node.entry.path.clone());
+                    }
                     if node.is_dir() {
                         // Toggle expand/collapse using the existing method
                         self.file_explorer_toggle_expand();
@@ -668,6 +673,10 @@ impl Editor {
             }
         }
 
+        if let Some(path) = pressed_path {
+            self.start_file_explorer_entry_drag(path, col, row);
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,153 @@
+//! Go-to-definition without a language server.
+//!
+//! When `lsp_goto_definition` finds no server to ask, the identifier under
+//! the cursor is first looked up with the buffer's tree-sitter locals query
+//! (parameters and local bindings, scope-aware). Names the locals query
+//! doesn't track — functions, types, anything in another file — fall back
+//! to a project-wide search for a line that looks like a declaration of the
+//! symbol (`fn name`, `class name`, `def name`, …), preferring a hit in the
+//! current file.
+
+use crate::app::Editor;
+use rust_i18n::t;
+
+/// Cap on declaration matches collected by the project search.
+#[cfg(feature = "plugins")]
+const MAX_DEFINITION_MATCHES: usize = 50;
+
+/// Regex matching a line that declares `symbol` in one of the common
+/// languages: a declaration keyword (or a Go method receiver, or
+/// `#define`) followed by the name as a whole word.
+pub(crate) fn definition_search_pattern(symbol: &str) -> String {
+    const KEYWORDS: &str = "fn|struct|enum|union|trait|type|mod|const|static|let|var|val|\
+                            class|interface|def|function|func|macro_rules!";
+    format!(
+        r"(?:\b(?:{KEYWORDS})\s+|\bfunc\s*\([^)]*\)\s*|#define\s+){}\b",
+        regex::escape(symbol)
+    )
+}
+
+impl Editor {
+    /// Jump to the definition of the identifier under the cursor without
+    /// LSP: the tree-sitter locals query first, then a project search.
+    /// With `in_split`, the jump happens in another split.
+    pub(crate) fn goto_definition_without_lsp(&mut self, in_split: bool) {
+        let cursor_pos = self.active_cursors().primary().position;
+
+        let local = {
+            let state = self.active_state_mut();
+            state
+                .reference_highlighter
+                .find_local_definition(&state.buffer, cursor_pos)
+        };
+        if let Some(range) = local {
+            if in_split {
+                self.focus_split_for_definition();
+            }
+            let scroll_on_jump = self.config.editor.scroll_on_jump;
+            self.active_window_mut().jump_active_cursor_to(
+                range.start,
+                super::navigation::JumpOptions::navigation(scroll_on_jump),
+            );
+            let buffer_id = self.active_buffer();
+            self.reveal_range(buffer_id, range, scroll_on_jump);
+            self.set_status_message(t!("goto_definition.local").to_string());
+            return;
+        }
+
+        let symbol = {
+            let state = self.active_state_mut();
+            let range = state
+                .reference_highlighter
+                .get_word_at_position(&state.buffer, cursor_pos);
+            range.map(|r| state.get_text_range(r.start, r.end))
+        };
+        let Some(symbol) = symbol else {
+            self.set_status_message(t!("lsp.no_definition").to_string());
+            return;
+        };
+
+        #[cfg(feature = "plugins")]
+        if self.goto_definition_by_search(&symbol, in_split) {
+            return;
+        }
+
+        self.set_status_message(t!("goto_definition.not_found", symbol = symbol).to_string());
+    }
+
+    /// Search the project for a declaration of `symbol` and open the best
+    /// hit. Returns false when nothing matched.
+    #[cfg(feature = "plugins")]
+    fn goto_definition_by_search(&mut self, symbol: &str, in_split: bool) -> bool {
+        let pattern = definition_search_pattern(symbol);
+        let matches = match self.grep_project(&pattern, false, true, MAX_DEFINITION_MATCHES, false)
+        {
+            Ok(matches) => matches,
+            Err(e) => {
+                tracing::warn!("Definition search for '{}' failed: {}", symbol, e);
+                return false;
+            }
+        };
+
+        let current_file = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|p| p.to_string_lossy().into_owned());
+        let Some(best) = matches
+            .iter()
+            .find(|m| Some(&m.file) == current_file.as_ref())
+            .or_else(|| matches.first())
+        else {
+            return false;
+        };
+
+        // The match starts at the keyword; land on the name itself.
+        let keyword_col = best.column.saturating_sub(1);
+        let name_col = best
+            .context
+            .get(keyword_col..)
+            .and_then(|rest| rest.find(symbol))
+            .map_or(keyword_col, |offset| keyword_col + offset);
+
+        let path = std::path::PathBuf::from(&best.file);
+        let (line, total) = (best.line, matches.len());
+        if in_split {
+            self.focus_split_for_definition();
+        }
+        if let Err(e) = self.handle_open_file_at_location(path, Some(line), Some(name_col + 1)) {
+            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            return true;
+        }
+        self.set_status_message(
+            t!(
+                "goto_definition.by_search",
+                symbol = symbol,
+                line = line,
+                count = total
+            )
+            .to_string(),
+        );
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn definition_pattern_matches_declarations_only() {
+        let re = regex::Regex::new(&definition_search_pattern("parse")).unwrap();
+
+        assert!(re.is_match("pub fn parse(input: &str) {"));
+        assert!(re.is_match("def parse(self):"));
+        assert!(re.is_match("export function parse() {"));
+        assert!(re.is_match("func (p *Parser) parse() error {"));
+        assert!(re.is_match("#define parse(x) x"));
+
+        assert!(!re.is_match("let x = parse(input);"));
+        assert!(!re.is_match("fn parse_all() {}"));
+        assert!(!re.is_match("fn reparse() {}"));
+    }
+}
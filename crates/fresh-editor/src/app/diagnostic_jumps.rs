@@ -20,38 +20,39 @@ impl Editor {
         let cursor = *self.active_cursors().primary();
         let state = self.active_state_mut();
 
-        // Get all diagnostic overlay positions
-        let mut diagnostic_positions: Vec<usize> = state
+        // Get all diagnostic overlay ranges
+        let mut diagnostic_ranges: Vec<std::ops::Range<usize>> = state
             .overlays
             .all()
             .iter()
             .filter_map(|overlay| {
                 // Only consider LSP diagnostics (those in the diagnostic namespace)
                 if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                    Some(overlay.range(&state.marker_list).start)
+                    Some(overlay.range(&state.marker_list))
                 } else {
                     None
                 }
             })
             .collect();
 
-        if diagnostic_positions.is_empty() {
+        if diagnostic_ranges.is_empty() {
             self.set_status_message(t!("diagnostics.none").to_string());
             return;
         }
 
-        // Sort positions
-        diagnostic_positions.sort_unstable();
-        diagnostic_positions.dedup();
+        // Sort by start position
+        diagnostic_ranges.sort_unstable_by_key(|range| (range.start, range.end));
+        diagnostic_ranges.dedup_by_key(|range| range.start);
 
         // Find next diagnostic after cursor position
-        let next_pos = diagnostic_positions
+        let next = diagnostic_ranges
             .iter()
-            .find(|&&pos| pos > cursor_pos)
-            .or_else(|| diagnostic_positions.first()) // Wrap around
-            .copied();
+            .find(|range| range.start > cursor_pos)
+            .or_else(|| diagnostic_ranges.first()) // Wrap around
+            .cloned();
 
-        if let Some(new_pos) = next_pos {
+        if let Some(target) = next {
+            let new_pos = target.start;
             let event = Event::MoveCursor {
                 cursor_id,
                 old_position: cursor.position,
@@ -64,9 +65,11 @@ impl Editor {
             self.active_event_log_mut().append(event.clone());
             self.apply_event_to_active_buffer(&event);
             // Diagnostics can be on any line; the viewport must scroll so the
-            // user actually sees the error after pressing F8 (#1689).
-            self.active_window_mut()
-                .ensure_active_cursor_visible_for_navigation(true);
+            // user actually sees the error after pressing F8 (#1689), and as
+            // much of the flagged range as fits.
+            let scroll_on_jump = self.config.editor.scroll_on_jump;
+            let buffer_id = self.active_buffer();
+            self.reveal_range(buffer_id, target, scroll_on_jump);
 
             // Show diagnostic message in status bar
             let state = self.active_state();
@@ -91,39 +94,40 @@ impl Editor {
         let cursor = *self.active_cursors().primary();
         let state = self.active_state_mut();
 
-        // Get all diagnostic overlay positions
-        let mut diagnostic_positions: Vec<usize> = state
+        // Get all diagnostic overlay ranges
+        let mut diagnostic_ranges: Vec<std::ops::Range<usize>> = state
             .overlays
             .all()
             .iter()
             .filter_map(|overlay| {
                 // Only consider LSP diagnostics (those in the diagnostic namespace)
                 if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                    Some(overlay.range(&state.marker_list).start)
+                    Some(overlay.range(&state.marker_list))
                 } else {
                     None
                 }
             })
             .collect();
 
-        if diagnostic_positions.is_empty() {
+        if diagnostic_ranges.is_empty() {
             self.set_status_message(t!("diagnostics.none").to_string());
             return;
         }
 
-        // Sort positions
-        diagnostic_positions.sort_unstable();
-        diagnostic_positions.dedup();
+        // Sort by start position
+        diagnostic_ranges.sort_unstable_by_key(|range| (range.start, range.end));
+        diagnostic_ranges.dedup_by_key(|range| range.start);
 
         // Find previous diagnostic before cursor position
-        let prev_pos = diagnostic_positions
+        let prev = diagnostic_ranges
             .iter()
             .rev()
-            .find(|&&pos| pos < cursor_pos)
-            .or_else(|| diagnostic_positions.last()) // Wrap around
-            .copied();
+            .find(|range| range.start < cursor_pos)
+            .or_else(|| diagnostic_ranges.last()) // Wrap around
+            .cloned();
 
-        if let Some(new_pos) = prev_pos {
+        if let Some(target) = prev {
+            let new_pos = target.start;
             let event = Event::MoveCursor {
                 cursor_id,
                 old_position: cursor.position,
@@ -136,9 +140,11 @@ impl Editor {
             self.active_event_log_mut().append(event.clone());
             self.apply_event_to_active_buffer(&event);
             // Diagnostics can be on any line; the viewport must scroll so the
-            // user actually sees the error after pressing F8 (#1689).
-            self.active_window_mut()
-                .ensure_active_cursor_visible_for_navigation(true);
+            // user actually sees the error after pressing F8 (#1689), and as
+            // much of the flagged range as fits.
+            let scroll_on_jump = self.config.editor.scroll_on_jump;
+            let buffer_id = self.active_buffer();
+            self.reveal_range(buffer_id, target, scroll_on_jump);
 
             // Show diagnostic message in status bar
             let state = self.active_state();
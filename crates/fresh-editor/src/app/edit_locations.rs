@@ -0,0 +1,228 @@
+//! Last-edit locations.
+//!
+//! Every buffer modification in a file-backed (or unnamed) buffer records
+//! where the primary cursor ended up, across all buffers of the window.
+//! `GotoLastEdit` jumps to the most recent location; pressing it again
+//! walks further back and wraps around to the newest. Edits close to the
+//! previous one (typing a word, deleting a few characters) update that
+//! entry instead of adding a new one.
+//!
+//! Unlike the position history (`NavigateBack` / `NavigateForward`), which
+//! follows cursor navigation, this only ever sees edits.
+
+use rust_i18n::t;
+
+use crate::model::event::{BufferId, Event};
+
+use super::Editor;
+
+/// Edits within this many bytes of the newest entry update it in place.
+const COALESCE_DISTANCE: usize = 50;
+
+/// Maximum number of edit locations kept.
+const MAX_EDIT_LOCATIONS: usize = 100;
+
+/// Recent edit locations, oldest first.
+#[derive(Debug, Default)]
+pub(crate) struct EditLocations {
+    entries: Vec<(BufferId, usize)>,
+    /// Index of the entry the last `GotoLastEdit` jumped to. Reset by
+    /// every new edit so the next jump starts from the newest again.
+    cycle: Option<usize>,
+}
+
+impl EditLocations {
+    /// Record an edit that left the cursor at `position` in `buffer_id`.
+    pub(crate) fn record(&mut self, buffer_id: BufferId, position: usize) {
+        self.cycle = None;
+        if let Some(last) = self.entries.last_mut() {
+            if last.0 == buffer_id && last.1.abs_diff(position) <= COALESCE_DISTANCE {
+                last.1 = position;
+                return;
+            }
+        }
+        self.entries.push((buffer_id, position));
+        if self.entries.len() > MAX_EDIT_LOCATIONS {
+            self.entries.remove(0);
+        }
+    }
+
+    /// Shift the entries of `buffer_id` past an edit at `at` that removed
+    /// `deleted` bytes and inserted `inserted` bytes.
+    pub(crate) fn adjust_for_edit(
+        &mut self,
+        buffer_id: BufferId,
+        at: usize,
+        deleted: usize,
+        inserted: usize,
+    ) {
+        for (buffer, position) in self.entries.iter_mut() {
+            if *buffer != buffer_id || *position <= at {
+                continue;
+            }
+            *position = if *position < at + deleted {
+                at
+            } else {
+                *position - deleted + inserted
+            };
+        }
+    }
+
+    /// Forget every entry in `buffer_id`.
+    pub(crate) fn remove_buffer(&mut self, buffer_id: BufferId) {
+        self.entries.retain(|(buffer, _)| *buffer != buffer_id);
+        self.cycle = None;
+    }
+
+    /// The next location to jump to: the newest on the first call after an
+    /// edit, then each older one in turn, wrapping back to the newest.
+    /// `current` is skipped when it is where the first jump would land.
+    pub(crate) fn next_target(&mut self, current: (BufferId, usize)) -> Option<(BufferId, usize)> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let newest = self.entries.len() - 1;
+        let index = match self.cycle {
+            Some(0) => newest,
+            Some(i) => (i - 1).min(newest),
+            None if self.entries[newest] == current && newest > 0 => newest - 1,
+            None => newest,
+        };
+        self.cycle = Some(index);
+        Some(self.entries[index])
+    }
+}
+
+impl Editor {
+    /// Record the edit `event` just applied to the active buffer.
+    pub(super) fn record_edit_location(&mut self, event: &Event) {
+        let buffer_id = self.active_buffer();
+        let tracked = self
+            .active_window()
+            .buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|meta| !meta.is_virtual());
+        if !tracked {
+            return;
+        }
+        let position = self.active_cursors().primary().position;
+        let locations = &mut self.active_window_mut().edit_locations;
+        adjust_for_event(locations, buffer_id, event);
+        locations.record(buffer_id, position);
+    }
+
+    /// Jump to the most recent edit location; repeat to go further back.
+    pub(super) fn goto_last_edit(&mut self) {
+        let current = (
+            self.active_buffer(),
+            self.active_cursors().primary().position,
+        );
+        let target = loop {
+            let Some((buffer_id, position)) =
+                self.active_window_mut().edit_locations.next_target(current)
+            else {
+                self.set_status_message(t!("edit_location.none").to_string());
+                return;
+            };
+            if self.active_window().buffers.contains_key(&buffer_id) {
+                break (buffer_id, position);
+            }
+            // The buffer was closed since; drop its entries and retry.
+            self.active_window_mut()
+                .edit_locations
+                .remove_buffer(buffer_id);
+        };
+
+        let (buffer_id, position) = target;
+        if buffer_id != self.active_buffer() {
+            self.set_active_buffer(buffer_id);
+        }
+        let cursor = *self.active_cursors().primary();
+        let cursor_id = self.active_cursors().primary_id();
+        let new_position = position.min(self.active_state().buffer.len());
+        let event = Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: None,
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+        let scroll_on_jump = self.config.editor.scroll_on_jump;
+        self.active_window_mut()
+            .ensure_active_cursor_visible_for_navigation(scroll_on_jump);
+    }
+}
+
+/// Shift `locations` for the inserts and deletes in `event`.
+fn adjust_for_event(locations: &mut EditLocations, buffer_id: BufferId, event: &Event) {
+    match event {
+        Event::Insert { position, text, .. } => {
+            locations.adjust_for_edit(buffer_id, *position, 0, text.len())
+        }
+        Event::Delete { range, .. } => {
+            locations.adjust_for_edit(buffer_id, range.start, range.len(), 0)
+        }
+        Event::Batch { events, .. } => {
+            for e in events {
+                adjust_for_event(locations, buffer_id, e);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearby_edits_coalesce_and_distant_ones_stack() {
+        let mut locations = EditLocations::default();
+        locations.record(BufferId(1), 10);
+        locations.record(BufferId(1), 14);
+        locations.record(BufferId(1), 500);
+        locations.record(BufferId(2), 3);
+        assert_eq!(
+            locations.entries,
+            vec![(BufferId(1), 14), (BufferId(1), 500), (BufferId(2), 3)]
+        );
+    }
+
+    #[test]
+    fn next_target_cycles_from_newest_and_wraps() {
+        let mut locations = EditLocations::default();
+        locations.record(BufferId(1), 0);
+        locations.record(BufferId(2), 0);
+        locations.record(BufferId(1), 200);
+
+        let elsewhere = (BufferId(3), 0);
+        assert_eq!(locations.next_target(elsewhere), Some((BufferId(1), 200)));
+        assert_eq!(locations.next_target(elsewhere), Some((BufferId(2), 0)));
+        assert_eq!(locations.next_target(elsewhere), Some((BufferId(1), 0)));
+        assert_eq!(locations.next_target(elsewhere), Some((BufferId(1), 200)));
+
+        // A new edit restarts from the newest; standing on it skips it.
+        locations.record(BufferId(2), 100);
+        assert_eq!(
+            locations.next_target((BufferId(2), 100)),
+            Some((BufferId(1), 200))
+        );
+    }
+
+    #[test]
+    fn edits_earlier_in_the_buffer_shift_entries() {
+        let mut locations = EditLocations::default();
+        locations.record(BufferId(1), 100);
+        locations.record(BufferId(2), 100);
+        locations.adjust_for_edit(BufferId(1), 10, 0, 5);
+        locations.adjust_for_edit(BufferId(1), 90, 20, 0);
+        assert_eq!(
+            locations.entries,
+            vec![(BufferId(1), 90), (BufferId(2), 100)]
+        );
+    }
+}
@@ -309,7 +309,7 @@ impl Editor {
     /// spinner whenever the server stopped emitting `$/progress`
     /// (e.g. died externally — see #1941 issue 3).
     pub fn next_periodic_redraw_deadline(&self) -> Option<std::time::Instant> {
-        let lsp_progress_deadline = if self.active_window().has_active_lsp_progress() {
+        let lsp_progress_deadline = if self.active_window().has_lsp_activity() {
             // 100ms matches the spinner-glyph period in
             // `lsp_status::compose_lsp_status`.
             Some(std::time::Instant::now() + std::time::Duration::from_millis(100))
@@ -450,6 +450,21 @@ impl Editor {
         self.status_log_path = Some(path);
     }
 
+    /// Enable or disable workspace persistence (`--no-session` disables it)
+    pub fn set_workspace_enabled(&mut self, enabled: bool) {
+        self.workspace_enabled = enabled;
+    }
+
+    /// Make saving the stdin buffer write to `path` instead of stdout
+    pub fn set_stdin_output_path(&mut self, path: PathBuf) {
+        self.stdin_output_path = Some(path);
+    }
+
+    /// Take the stdin buffer bytes saved for stdout, if it was saved
+    pub fn take_stdout_output(&mut self) -> Option<Vec<u8>> {
+        self.stdout_output.take()
+    }
+
     /// Queue a new authority and restart the editor.
     ///
     /// Per the design decision in `docs/internal/AUTHORITY_DESIGN.md`,
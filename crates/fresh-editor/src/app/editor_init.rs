@@ -593,6 +593,9 @@ impl Editor {
             command_registry: parts.command_registry,
             quick_open_registry: parts.quick_open_registry,
             lsp_uri_schemes: std::collections::HashSet::new(),
+            plugin_text_objects: HashMap::new(),
+            pending_text_object: None,
+            next_text_object_request: 0,
             plugin_manager: parts.plugin_manager,
             recovery_service: parts.recovery_service,
             mouse_capture: parts.mouse_capture,
@@ -620,6 +623,7 @@ impl Editor {
             should_detach: false,
             session_mode: false,
             software_cursor_only: false,
+            deterministic_render: false,
             session_name: None,
             pending_escape_sequences: Vec::new(),
             restart_with_dir: None,
@@ -638,6 +642,8 @@ impl Editor {
             pending_plugin_actions: Vec::new(),
             #[cfg(feature = "plugins")]
             plugin_render_requested: false,
+            #[cfg(feature = "plugins")]
+            pending_rendered_regions: Vec::new(),
             full_redraw_requested: false,
             suppress_chrome_cells: false,
             suspend_requested: false,
@@ -646,6 +652,10 @@ impl Editor {
             plugin_global_dirty: HashMap::new(),
             warning_log: None,
             status_log_path: None,
+            workspace_enabled: true,
+            stdin_buffer: None,
+            stdin_output_path: None,
+            stdout_output: None,
             self_update_phase: crate::services::release_checker::SelfUpdatePhase::default(),
             self_update_terminal: None,
             self_update_output: None,
@@ -950,6 +960,7 @@ impl Editor {
 
         t.phase("theme_setup");
         let keybindings = Arc::new(RwLock::new(KeybindingResolver::new(&config)));
+        KeybindingResolver::warn_conflicts(&config);
         t.phase("keybindings");
 
         // Create an empty initial buffer
@@ -1470,6 +1481,8 @@ impl Editor {
         t.phase("editor_struct_assembly");
         // Apply clipboard configuration
         editor.clipboard.apply_config(&editor.config.clipboard);
+        // Start in normal mode when the modal `vim` keymap is active
+        editor.sync_vi_mode_with_keymap();
 
         // Seed splits/buffers for every persisted inactive window so they
         // render in preview surfaces (Orchestrator's WindowEmbed) before the
@@ -1784,6 +1797,7 @@ impl Editor {
                     .write()
                     .unwrap()
                     .reload_from_config(&self.config);
+                self.sync_vi_mode_with_keymap();
                 self.clipboard.apply_config(&self.config.clipboard);
                 {
                     let cfg = self.config.editor.clone();
@@ -150,6 +150,10 @@ impl Editor {
             _ => {}
         }
 
+        if event.modifies_buffer() {
+            self.record_edit_location(event);
+        }
+
         // 2. Adjust cursors in other splits that share the same buffer
         self.active_window_mut()
             .adjust_other_split_cursors_for_event(event);
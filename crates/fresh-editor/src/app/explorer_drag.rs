@@ -0,0 +1,105 @@
+//! File explorer drag-and-drop
+//!
+//! Pressing on an explorer entry arms a drag; releasing it over a
+//! directory moves the entry into that directory. Dropping onto a file,
+//! onto the directory the entry already lives in, or outside the explorer
+//! does nothing. An existing destination asks before overwriting, and the
+//! last move can be reverted with `FileExplorerUndoMove`.
+
+use super::types::ExplorerDragState;
+use super::Editor;
+use crate::view::prompt::PromptType;
+use rust_i18n::t;
+use std::path::PathBuf;
+
+impl Editor {
+    /// Arm a drag for the explorer entry at `source`, pressed at `(col, row)`.
+    pub(super) fn start_file_explorer_entry_drag(&mut self, source: PathBuf, col: u16, row: u16) {
+        self.active_window_mut().mouse_state.dragging_explorer_entry =
+            Some(ExplorerDragState::new(source, (col, row)));
+    }
+
+    /// Handle explorer entry drag - update position and compute drop target
+    pub(super) fn handle_file_explorer_entry_drag(&mut self, col: u16, row: u16) {
+        let is_dragging = match self
+            .active_window_mut()
+            .mouse_state
+            .dragging_explorer_entry
+            .as_mut()
+        {
+            Some(drag_state) => {
+                drag_state.current_position = (col, row);
+                drag_state.is_dragging()
+            }
+            None => return,
+        };
+
+        let drop_target = if is_dragging {
+            self.file_explorer_drop_target(col, row)
+        } else {
+            None
+        };
+        if let Some(drag_state) = self
+            .active_window_mut()
+            .mouse_state
+            .dragging_explorer_entry
+            .as_mut()
+        {
+            drag_state.drop_target = drop_target;
+        }
+    }
+
+    /// The directory shown on the explorer row under `(col, row)`, if any.
+    /// Rows showing files (and anything outside the tree) are not targets.
+    fn file_explorer_drop_target(&self, col: u16, row: u16) -> Option<PathBuf> {
+        let area = self.active_layout().file_explorer_area?;
+        // The tree starts below the top border/title row
+        if col < area.x
+            || col >= area.x + area.width
+            || row <= area.y
+            || row >= area.y + area.height
+        {
+            return None;
+        }
+        let explorer = self.file_explorer()?;
+        let index = (row - area.y - 1) as usize + explorer.get_scroll_offset();
+        let (node_id, _indent) = *explorer.get_display_nodes().get(index)?;
+        let node = explorer.tree().get_node(node_id)?;
+        node.is_dir().then(|| node.entry.path.clone())
+    }
+
+    /// Complete an explorer drag on mouse release.
+    pub(super) fn finish_file_explorer_entry_drag(&mut self, drag_state: ExplorerDragState) {
+        if !drag_state.is_dragging() {
+            return;
+        }
+        let Some(target_dir) = drag_state.drop_target else {
+            return;
+        };
+        let src = drag_state.source;
+        // Dropping into the directory the entry already lives in, or onto
+        // the dragged directory itself, is a no-op.
+        if src.parent() == Some(target_dir.as_path()) || target_dir == src {
+            return;
+        }
+        let Some(file_name) = src.file_name() else {
+            return;
+        };
+        let dst = target_dir.join(file_name);
+
+        if self.authority().filesystem.exists(&dst) {
+            let name =
+                super::file_explorer::truncate_name_for_prompt(&file_name.to_string_lossy(), 40);
+            let dir = target_dir
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            self.start_prompt(
+                t!("explorer.move_overwrite_confirm", name = &name, dir = &dir).to_string(),
+                PromptType::ConfirmExplorerMoveOverwrite { src, dst },
+            );
+        } else {
+            self.perform_file_explorer_move(src, dst);
+        }
+    }
+}
@@ -114,6 +114,7 @@ impl Editor {
             self.take_focus_for_file_explorer();
             self.set_status_message(t!("explorer.opened").to_string());
             self.active_window_mut().sync_file_explorer_to_active_file();
+            self.notify_file_explorer_focused();
         } else {
             self.active_window_mut().key_context = KeyContext::Normal;
             self.set_status_message(t!("explorer.closed").to_string());
@@ -142,6 +143,7 @@ impl Editor {
             self.take_focus_for_file_explorer();
             self.set_status_message(t!("explorer.focused").to_string());
             self.active_window_mut().sync_file_explorer_to_active_file();
+            self.notify_file_explorer_focused();
         } else {
             self.toggle_file_explorer();
         }
@@ -407,15 +409,16 @@ impl Editor {
     }
 
     pub fn file_explorer_refresh(&mut self) {
-        let (selected_id, node_name) = if let Some(explorer) = self.file_explorer() {
+        let (selected_id, node_name, node_path) = if let Some(explorer) = self.file_explorer() {
             if let Some(selected_id) = explorer.get_selected() {
-                let node_name = explorer
-                    .tree()
-                    .get_node(selected_id)
-                    .map(|n| n.entry.name.clone());
-                (Some(selected_id), node_name)
+                let node = explorer.tree().get_node(selected_id);
+                (
+                    Some(selected_id),
+                    node.map(|n| n.entry.name.clone()),
+                    node.map(|n| n.entry.path.clone()),
+                )
             } else {
-                (None, None)
+                (None, None, None)
             }
         } else {
             return;
@@ -445,6 +448,12 @@ impl Editor {
                     } else {
                         self.set_status_message(t!("explorer.refreshed_default").to_string());
                     }
+                    // A manual refresh means the disk may have changed
+                    // behind our back; let plugins rescan too.
+                    if let Some(path) = node_path {
+                        self.refresh_quick_open_path(&path);
+                        self.notify_file_explorer_change(&path);
+                    }
                 }
                 Err(e) => {
                     self.set_status_message(
@@ -493,6 +502,7 @@ impl Editor {
                                 self.set_status_message(
                                     t!("explorer.created_file", name = &filename).to_string(),
                                 );
+                                self.refresh_quick_open_path(&path_clone);
                                 self.notify_file_explorer_change(&path_clone);
 
                                 // Open the file in the buffer
@@ -559,6 +569,7 @@ impl Editor {
                                 self.set_status_message(
                                     t!("explorer.created_dir", name = &dirname_clone).to_string(),
                                 );
+                                self.refresh_quick_open_path(&path_clone);
                                 self.notify_file_explorer_change(&path_clone);
 
                                 let prompt = crate::view::prompt::Prompt::with_initial_text(
@@ -616,8 +627,17 @@ impl Editor {
                 .to_string_lossy()
                 .to_string();
             let type_str = if is_dir { "directory" } else { "file" };
+            let message = if self.config.file_explorer.delete_to_trash {
+                t!("explorer.delete_confirm", "type" = type_str, name = &name)
+            } else {
+                t!(
+                    "explorer.delete_permanent_confirm",
+                    "type" = type_str,
+                    name = &name
+                )
+            };
             self.start_prompt(
-                t!("explorer.delete_confirm", "type" = type_str, name = &name).to_string(),
+                message.to_string(),
                 PromptType::ConfirmDeleteFile { path, is_dir },
             );
         } else {
@@ -627,13 +647,21 @@ impl Editor {
             // about to be deleted. Include '…' when there are more than
             // fit in the minibuffer budget.
             let names = format_path_preview_for_prompt(&all_paths, 3);
-            self.start_prompt(
+            let message = if self.config.file_explorer.delete_to_trash {
                 t!(
                     "explorer.delete_multi_confirm",
                     count = count,
                     names = &names
                 )
-                .to_string(),
+            } else {
+                t!(
+                    "explorer.delete_multi_permanent_confirm",
+                    count = count,
+                    names = &names
+                )
+            };
+            self.start_prompt(
+                message.to_string(),
                 PromptType::ConfirmMultiDelete { paths: all_paths },
             );
         }
@@ -642,15 +670,25 @@ impl Editor {
     /// Perform the actual file explorer delete operation (called after prompt confirmation)
     /// For local files: moves to system trash/recycle bin
     /// For remote files: moves to ~/.local/share/fresh/trash/ on remote
-    pub fn perform_file_explorer_delete(&mut self, path: std::path::PathBuf, _is_dir: bool) {
+    /// With `file_explorer.delete_to_trash` off: removes the path permanently
+    pub fn perform_file_explorer_delete(&mut self, path: std::path::PathBuf, is_dir: bool) {
         let name = path
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
 
-        // For remote files, move to remote trash directory
-        // For local files, use system trash
-        let delete_result = if self
+        // Permanent delete when trash is turned off. Otherwise, for remote
+        // files, move to remote trash directory; for local files, use
+        // system trash
+        let to_trash = self.config.file_explorer.delete_to_trash;
+        let delete_result = if !to_trash {
+            let fs = &self.authority().filesystem;
+            if is_dir {
+                fs.remove_dir_all(&path)
+            } else {
+                fs.remove_file(&path)
+            }
+        } else if self
             .authority()
             .filesystem
             .remote_connection_info()
@@ -731,16 +769,25 @@ impl Editor {
                         }
                     }
                 }
-                self.set_status_message(t!("explorer.moved_to_trash", name = &name).to_string());
+                let message = if to_trash {
+                    t!("explorer.moved_to_trash", name = &name)
+                } else {
+                    t!("explorer.deleted", name = &name)
+                };
+                self.set_status_message(message.to_string());
+                self.refresh_quick_open_path(&path);
                 self.notify_file_explorer_change(&path);
 
                 // Ensure focus remains on file explorer
                 self.active_window_mut().key_context = KeyContext::FileExplorer;
             }
             Err(e) => {
-                self.set_status_message(
-                    t!("explorer.error_trash", error = e.to_string()).to_string(),
-                );
+                let message = if to_trash {
+                    t!("explorer.error_trash", error = e.to_string())
+                } else {
+                    t!("explorer.error_deleting", error = e.to_string())
+                };
+                self.set_status_message(message.to_string());
             }
         }
     }
@@ -887,6 +934,8 @@ impl Editor {
                     self.set_status_message(
                         t!("explorer.renamed", old = &original_name, new = &new_name).to_string(),
                     );
+                    self.refresh_quick_open_path(&original_path);
+                    self.refresh_quick_open_path(&new_path);
                     self.notify_file_explorer_change(&new_path);
                 }
                 Err(e) => {
@@ -1163,6 +1212,12 @@ impl Editor {
             }
         }
 
+        for (src, dst) in &succeeded {
+            if is_cut {
+                self.refresh_quick_open_path(src);
+            }
+            self.refresh_quick_open_path(dst);
+        }
         if !succeeded.is_empty() {
             let first_dst = succeeded[0].1.clone();
             let any_src = succeeded[0].0.clone();
@@ -1387,7 +1442,8 @@ impl Editor {
     /// `path` is one of the affected paths (destination for move/copy,
     /// the deleted path for delete, the new path for create/rename).
     /// Multi-target operations call this once per refresh, not once per
-    /// file.
+    /// file. A manual refresh and the explorer taking focus fire it too,
+    /// since the working tree may have changed outside the editor.
     pub(super) fn notify_file_explorer_change(&mut self, path: &Path) {
         self.plugin_manager.read().unwrap().run_hook(
             "after_file_explorer_change",
@@ -1397,6 +1453,18 @@ impl Editor {
         );
     }
 
+    /// Fire `after_file_explorer_change` for the explorer root when the
+    /// explorer is opened or focused, so git badges catch up with changes
+    /// made outside the editor.
+    fn notify_file_explorer_focused(&mut self) {
+        let root = self
+            .file_explorer()
+            .map(|explorer| explorer.tree().root_path().to_path_buf());
+        if let Some(root) = root {
+            self.notify_file_explorer_change(&root);
+        }
+    }
+
     pub fn perform_file_explorer_paste(&mut self, src: PathBuf, dst: PathBuf, is_cut: bool) {
         let name = dst
             .file_name()
@@ -1455,6 +1523,111 @@ impl Editor {
         }
     }
 
+    /// Move `src` to `dst` for a drag-and-drop in the explorer (directly,
+    /// or once the overwrite prompt is confirmed). Unlike cut/paste this
+    /// leaves the explorer clipboard alone, and it remembers the move so
+    /// `file_explorer_undo_move` can put the entry back.
+    pub fn perform_file_explorer_move(&mut self, src: PathBuf, dst: PathBuf) {
+        let name = dst
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let dir = dst
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        self.active_window_mut().key_context = KeyContext::FileExplorer;
+        match self.paste_one_fs_op(&src, &dst, true) {
+            PasteOpOutcome::Ok => {
+                self.relocate_buffers_for_rename(&src, &dst);
+                self.refresh_tree_after_paste(&src, &dst, true);
+                self.refresh_quick_open_path(&src);
+                self.refresh_quick_open_path(&dst);
+                let msg = match self.get_keybinding_for_action("file_explorer_undo_move") {
+                    Some(key) => t!(
+                        "explorer.moved_to_dir_undo_hint",
+                        name = &name,
+                        dir = &dir,
+                        key = &key
+                    ),
+                    None => t!("explorer.moved_to_dir", name = &name, dir = &dir),
+                };
+                self.set_status_message(msg.to_string());
+                self.active_window_mut().file_explorer_last_move = Some((src, dst));
+            }
+            PasteOpOutcome::SourceRemovalFailed {
+                dst: landed_dst,
+                err,
+            } => {
+                self.refresh_tree_after_paste(&src, &landed_dst, true);
+                self.set_status_message(
+                    t!(
+                        "explorer.move_source_removal_failed",
+                        name = &name,
+                        error = err.to_string()
+                    )
+                    .to_string(),
+                );
+            }
+            PasteOpOutcome::Failed(e) => {
+                self.set_status_message(
+                    t!("explorer.error_moving", error = e.to_string()).to_string(),
+                );
+            }
+        }
+    }
+
+    /// Revert the last drag-and-drop move by moving the entry back to where
+    /// it came from. Refuses if either side changed since the move.
+    pub fn file_explorer_undo_move(&mut self) {
+        let Some((from, to)) = self.active_window_mut().file_explorer_last_move.take() else {
+            self.set_status_message(t!("explorer.no_move_to_undo").to_string());
+            return;
+        };
+        let name = from
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let fs = &self.authority().filesystem;
+        if fs.exists(&from) || !fs.exists(&to) {
+            self.set_status_message(t!("explorer.undo_move_conflict", name = &name).to_string());
+            return;
+        }
+
+        self.active_window_mut().key_context = KeyContext::FileExplorer;
+        match self.paste_one_fs_op(&to, &from, true) {
+            PasteOpOutcome::Ok => {
+                self.relocate_buffers_for_rename(&to, &from);
+                self.refresh_tree_after_paste(&to, &from, true);
+                self.refresh_quick_open_path(&to);
+                self.refresh_quick_open_path(&from);
+                self.set_status_message(t!("explorer.move_undone", name = &name).to_string());
+            }
+            PasteOpOutcome::SourceRemovalFailed {
+                dst: landed_dst,
+                err,
+            } => {
+                self.refresh_tree_after_paste(&to, &landed_dst, true);
+                self.set_status_message(
+                    t!(
+                        "explorer.move_source_removal_failed",
+                        name = &name,
+                        error = err.to_string()
+                    )
+                    .to_string(),
+                );
+            }
+            PasteOpOutcome::Failed(e) => {
+                self.set_status_message(
+                    t!("explorer.error_moving", error = e.to_string()).to_string(),
+                );
+            }
+        }
+    }
+
     /// Duplicate the selected file/directory in-place, naming the new copy
     /// using the same `name copy[.ext]` convention as Paste's auto-rename.
     ///
@@ -1517,6 +1690,9 @@ impl Editor {
             }
         }
 
+        for (_, dst) in &succeeded {
+            self.refresh_quick_open_path(dst);
+        }
         if !succeeded.is_empty() {
             let (first_src, first_dst) = succeeded[0].clone();
             self.refresh_tree_after_paste(&first_src, &first_dst, false);
@@ -83,9 +83,10 @@ impl Editor {
         // Funnel through the navigation primitive so the cursor is guaranteed
         // visible in the viewport (#1689 — without this, jump_to_line_column
         // could land off-screen if a prior scroll set skip_ensure_visible).
+        let scroll_on_jump = self.config.editor.scroll_on_jump;
         self.active_window_mut().jump_active_cursor_to(
             clamped_position,
-            super::navigation::JumpOptions::navigation(),
+            super::navigation::JumpOptions::navigation(scroll_on_jump),
         );
     }
 
@@ -3,7 +3,8 @@
 //! CLI file arguments are queued and processed after the TUI starts so
 //! they go through the same code path as interactive opens (with proper
 //! encoding-prompt handling). Wait tracking lets the CLI block until
-//! a popup-based wait is dismissed.
+//! a popup-based wait is dismissed. A standalone `fresh --wait FILE`
+//! registers [`QUIT_WAIT_ID`], whose completion quits the editor.
 
 use std::path::PathBuf;
 
@@ -11,6 +12,11 @@ use rust_i18n::t;
 
 use super::Editor;
 
+/// Wait id queued by `fresh --wait`: completing it quits the editor
+/// instead of being reported to a waiting client. Daemon and nested
+/// `--wait` ids count up from 1, so they never reach this value.
+pub const QUIT_WAIT_ID: u64 = u64::MAX;
+
 impl Editor {
     /// Queue a file to be opened after the TUI starts.
     ///
@@ -130,6 +136,17 @@ impl Editor {
         processed_any
     }
 
+    /// Mark `wait_id` as completed: [`QUIT_WAIT_ID`] quits the editor
+    /// (prompting about other unsaved buffers, like any quit), any other id
+    /// is reported through `take_completed_waits`.
+    pub(crate) fn complete_wait(&mut self, wait_id: u64) {
+        if wait_id == QUIT_WAIT_ID {
+            self.quit();
+        } else {
+            self.active_window_mut().completed_waits.push(wait_id);
+        }
+    }
+
     /// Take and return completed wait IDs (for --wait support).
     pub fn take_completed_waits(&mut self) -> Vec<u64> {
         std::mem::take(&mut self.active_window_mut().completed_waits)
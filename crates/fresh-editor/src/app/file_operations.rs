@@ -83,6 +83,29 @@ impl Editor {
         }
     }
 
+    /// Save the piped-stdin buffer. With `--output PATH` it becomes an
+    /// ordinary file buffer saved to that path; otherwise its bytes are
+    /// kept for printing to stdout when the editor exits.
+    pub(crate) fn save_stdin_buffer(&mut self) {
+        if let Some(path) = self.stdin_output_path.clone() {
+            self.stdin_buffer = None;
+            self.perform_save_file_as(path);
+            return;
+        }
+        let state = self.active_state_mut();
+        let len = state.buffer.len();
+        match state.buffer.get_text_range_mut(0, len) {
+            Ok(bytes) => {
+                state.buffer.clear_modified();
+                self.stdout_output = Some(bytes);
+                self.set_status_message(t!("stdin.saved_to_stdout").to_string());
+            }
+            Err(e) => {
+                self.set_status_message(t!("file.save_failed", error = e.to_string()).to_string());
+            }
+        }
+    }
+
     /// Internal helper to finalize save state (mark as saved, notify LSP, etc.)
     pub(crate) fn finalize_save(&mut self, path: Option<PathBuf>) -> anyhow::Result<()> {
         let buffer_id = self.active_buffer();
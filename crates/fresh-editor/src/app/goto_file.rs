@@ -0,0 +1,442 @@
+//! Go to the file named under the cursor (`goto_file_under_cursor`, Vim's
+//! `gf`).
+//!
+//! Unlike link following ([`buffer_link`]), which only fires on tokens that
+//! already look like paths, this treats any run of file-name characters
+//! around the cursor as a file reference — `utils`, `config.h`,
+//! `../lib/foo.ts:12:3` — and searches for it: relative to the buffer's
+//! directory, then the project root and its common include directories,
+//! with the current file's extension and a few common ones appended when
+//! the token has none. One match opens directly (honoring a `:line[:col]`
+//! suffix); several open a picker.
+//!
+//! [`buffer_link`]: crate::app::buffer_link
+
+use crate::app::Editor;
+use crate::primitives::path_utils::expand_tilde;
+use anyhow::Result as AnyhowResult;
+use rust_i18n::t;
+use std::path::{Component, Path, PathBuf};
+
+/// Project-root subdirectories searched after the root itself, like Vim's
+/// `'path'` option.
+const INCLUDE_DIRS: &[&str] = &["src", "include", "lib"];
+
+/// Extensions tried, after the current file's own, when the token has none.
+const COMMON_EXTENSIONS: &[&str] = &[
+    "rs", "ts", "tsx", "js", "jsx", "py", "go", "c", "h", "cpp", "hpp", "md",
+];
+
+/// A file reference extracted from buffer text. Line/column are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FileToken {
+    pub path: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+/// Characters that can appear in a file reference. `:` is included so the
+/// token spans a `path:line:col` suffix; it is split on afterwards.
+fn is_file_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '\\' | '~' | '+' | '@' | ':')
+}
+
+/// Extract the file reference around character offset `col` in `line`.
+///
+/// The token is the run of file-name characters under (or just left of)
+/// the cursor. A trailing `:line` or `:line:col` is split off as the
+/// location; any remaining `:`-separated segments (`crate::module`) narrow
+/// to the one under the cursor.
+pub(crate) fn extract_file_token(line: &str, col: usize) -> Option<FileToken> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        return None;
+    }
+
+    // Like a word motion, a cursor just past the token still names it.
+    let mut anchor = col.min(chars.len() - 1);
+    if !is_file_name_char(chars[anchor]) {
+        if anchor == 0 || !is_file_name_char(chars[anchor - 1]) {
+            return None;
+        }
+        anchor -= 1;
+    }
+
+    let mut start = anchor;
+    while start > 0 && is_file_name_char(chars[start - 1]) {
+        start -= 1;
+    }
+    let mut end = anchor + 1;
+    while end < chars.len() && is_file_name_char(chars[end]) {
+        end += 1;
+    }
+    // Sentence punctuation after a reference isn't part of it.
+    while end > start + 1 && matches!(chars[end - 1], '.' | ',') {
+        end -= 1;
+    }
+    anchor = anchor.min(end - 1);
+
+    let mut segments: Vec<(usize, String)> = Vec::new();
+    let mut seg_start = start;
+    for i in start..=end {
+        if i == end || chars[i] == ':' {
+            segments.push((seg_start, chars[seg_start..i].iter().collect()));
+            seg_start = i + 1;
+        }
+    }
+    // A trailing `:` (as in compiler output `file:12:5:`) carries nothing.
+    while segments.len() > 1 && segments.last().is_some_and(|(_, s)| s.is_empty()) {
+        segments.pop();
+    }
+
+    // Peel up to two numeric segments off the end as `:line[:col]`.
+    let is_number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let mut numbers = Vec::new();
+    while numbers.len() < 2 && segments.len() > 1 && is_number(&segments.last().unwrap().1) {
+        numbers.push(segments.pop().unwrap().1.parse::<usize>().ok()?);
+    }
+    let (line_no, col_no) = match numbers.as_slice() {
+        [] => (None, None),
+        [line] => (Some(*line), None),
+        [column, line] => (Some(*line), Some(*column)),
+        _ => unreachable!(),
+    };
+
+    // Keep a Windows drive prefix (`C:\…`) together; otherwise pick the
+    // segment under the cursor, or the last one if the cursor is on the
+    // location suffix.
+    let path = match segments.as_slice() {
+        [(_, drive), (_, rest)]
+            if drive.len() == 1
+                && drive.chars().all(|c| c.is_ascii_alphabetic())
+                && rest.starts_with(['\\', '/']) =>
+        {
+            format!("{drive}:{rest}")
+        }
+        _ => segments
+            .iter()
+            .rev()
+            .find(|(s, _)| *s <= anchor)
+            .map(|(_, seg)| seg.clone())?,
+    };
+
+    if path.is_empty() || path.chars().all(|c| matches!(c, '.' | '/' | '\\' | '~')) {
+        return None;
+    }
+
+    Some(FileToken {
+        path,
+        line: line_no,
+        column: col_no,
+    })
+}
+
+/// Lexically normalize `path` (drop `.`, fold `..`) so the same file
+/// reached from two search directories compares equal.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Every path `raw` could refer to, most likely first: the buffer's
+/// directory, then the project root, then its include directories; in each,
+/// the name as written and then — if it has no extension — with
+/// `extension` and the common extensions appended.
+pub(crate) fn goto_file_candidates(
+    raw: &str,
+    buffer_dir: Option<&Path>,
+    root: &Path,
+    extension: Option<&str>,
+) -> Vec<PathBuf> {
+    let written = expand_tilde(raw);
+
+    let mut names = vec![written.clone()];
+    if written.extension().is_none() {
+        for ext in extension
+            .into_iter()
+            .chain(COMMON_EXTENSIONS.iter().copied())
+        {
+            let mut name = written.clone().into_os_string();
+            name.push(".");
+            name.push(ext);
+            names.push(PathBuf::from(name));
+        }
+    }
+
+    let dirs: Vec<PathBuf> = if written.is_absolute() {
+        vec![PathBuf::new()]
+    } else {
+        buffer_dir
+            .map(Path::to_path_buf)
+            .into_iter()
+            .chain(std::iter::once(root.to_path_buf()))
+            .chain(INCLUDE_DIRS.iter().map(|d| root.join(d)))
+            .collect()
+    };
+
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    for dir in &dirs {
+        for name in &names {
+            let candidate = normalize(&dir.join(name));
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+    candidates
+}
+
+/// The candidates for `raw` that are existing files, per `is_file`.
+pub(crate) fn resolve_goto_file(
+    raw: &str,
+    buffer_dir: Option<&Path>,
+    root: &Path,
+    extension: Option<&str>,
+    is_file: impl Fn(&Path) -> bool,
+) -> Vec<PathBuf> {
+    goto_file_candidates(raw, buffer_dir, root, extension)
+        .into_iter()
+        .filter(|p| is_file(p))
+        .collect()
+}
+
+impl Editor {
+    /// Open the file named under the primary cursor
+    /// (`goto_file_under_cursor`).
+    pub(crate) fn goto_file_under_cursor(&mut self) -> AnyhowResult<()> {
+        let buffer_id = self.active_buffer();
+        let byte_pos = self.active_cursors().primary().position;
+
+        let token = {
+            let state = self.active_state();
+            let pos = crate::model::buffer_position::byte_to_2d(&state.buffer, byte_pos);
+            state.buffer.get_line(pos.line).and_then(|bytes| {
+                let line = String::from_utf8_lossy(&bytes);
+                let line = line.trim_end_matches(['\n', '\r']);
+                let char_col = line
+                    .char_indices()
+                    .take_while(|(b, _)| *b < pos.column)
+                    .count();
+                extract_file_token(line, char_col)
+            })
+        };
+        let Some(token) = token else {
+            self.set_status_message(t!("goto_file.none_at_cursor").to_string());
+            return Ok(());
+        };
+
+        let file_path = self
+            .active_window()
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_path())
+            .map(Path::to_path_buf);
+        let buffer_dir = file_path.as_deref().and_then(Path::parent);
+        let extension = file_path
+            .as_deref()
+            .and_then(Path::extension)
+            .and_then(|e| e.to_str());
+
+        let fs = &self.authority().filesystem;
+        let matches = resolve_goto_file(
+            &token.path,
+            buffer_dir,
+            self.working_dir(),
+            extension,
+            |p| fs.is_file(p).unwrap_or(false),
+        );
+
+        match matches.as_slice() {
+            [] => {
+                self.set_status_message(t!("goto_file.not_found", path = token.path).to_string());
+                Ok(())
+            }
+            [only] => self.handle_open_file_at_location(only.clone(), token.line, token.column),
+            _ => {
+                self.show_goto_file_picker(matches, token.line, token.column);
+                Ok(())
+            }
+        }
+    }
+
+    /// Let the user choose between several files matching the reference.
+    /// Each row's `data` is the absolute path; the location rides on the
+    /// resolver so every choice jumps to the same `:line:col`.
+    fn show_goto_file_picker(
+        &mut self,
+        matches: Vec<PathBuf>,
+        line: Option<usize>,
+        column: Option<usize>,
+    ) {
+        use crate::view::popup::{Popup, PopupKind, PopupListItem, PopupPosition, PopupResolver};
+
+        let root = self.working_dir().to_path_buf();
+        let items: Vec<PopupListItem> = matches
+            .iter()
+            .map(|p| {
+                let shown = p.strip_prefix(&root).unwrap_or(p);
+                PopupListItem::new(shown.to_string_lossy().into_owned())
+                    .with_data(p.to_string_lossy().into_owned())
+            })
+            .collect();
+
+        let mut popup = Popup::list(items, &self.theme.read().unwrap());
+        popup.kind = PopupKind::Action;
+        popup.title = Some(t!("goto_file.picker_title").to_string());
+        popup.position = PopupPosition::BelowCursor;
+        popup.width = 60;
+        popup.resolver = PopupResolver::GotoFile { line, column };
+        // Explicitly invoked, so the choice is expected right away.
+        popup.focused = true;
+
+        let buffer_id = self.active_buffer();
+        if let Some(state) = self.active_window_mut().buffers.get_mut(&buffer_id) {
+            state.popups.show_or_replace(popup);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn token(path: &str, line: Option<usize>, column: Option<usize>) -> FileToken {
+        FileToken {
+            path: path.to_string(),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn extracts_relative_path_around_cursor() {
+        let line = r#"#include "../include/config.h""#;
+        assert_eq!(
+            extract_file_token(line, 15),
+            Some(token("../include/config.h", None, None))
+        );
+        // Cursor on the closing quote still names the token to its left.
+        assert_eq!(
+            extract_file_token(line, line.len() - 1),
+            Some(token("../include/config.h", None, None))
+        );
+        assert_eq!(extract_file_token("a   b", 2), None);
+    }
+
+    #[test]
+    fn extracts_line_and_column_suffix() {
+        let line = "error at src/main.rs:42:7: oops";
+        assert_eq!(
+            extract_file_token(line, 12),
+            Some(token("src/main.rs", Some(42), Some(7)))
+        );
+        // Cursor on the suffix itself.
+        assert_eq!(
+            extract_file_token(line, 22),
+            Some(token("src/main.rs", Some(42), Some(7)))
+        );
+        assert_eq!(
+            extract_file_token("see notes.md:10.", 6),
+            Some(token("notes.md", Some(10), None))
+        );
+    }
+
+    #[test]
+    fn narrows_module_paths_and_trims_punctuation() {
+        assert_eq!(
+            extract_file_token("use crate::utils::helpers;", 13),
+            Some(token("utils", None, None))
+        );
+        assert_eq!(
+            extract_file_token("See README.md.", 6),
+            Some(token("README.md", None, None))
+        );
+    }
+
+    /// A relative reference resolves against the buffer's directory first,
+    /// then the project root and its include directories.
+    #[test]
+    fn resolves_relative_path_from_buffer_dir_and_root() {
+        let root = Path::new("/proj");
+        let buffer_dir = Path::new("/proj/src/app");
+        let files: HashSet<PathBuf> = [
+            "/proj/src/app/sibling.rs",
+            "/proj/src/util.rs",
+            "/proj/include/config.h",
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+        let is_file = |p: &Path| files.contains(p);
+
+        assert_eq!(
+            resolve_goto_file("./sibling.rs", Some(buffer_dir), root, Some("rs"), is_file),
+            vec![PathBuf::from("/proj/src/app/sibling.rs")]
+        );
+        assert_eq!(
+            resolve_goto_file("../util.rs", Some(buffer_dir), root, Some("rs"), is_file),
+            vec![PathBuf::from("/proj/src/util.rs")]
+        );
+        // Include directory, and the current extension filled in.
+        assert_eq!(
+            resolve_goto_file("config.h", Some(buffer_dir), root, Some("c"), is_file),
+            vec![PathBuf::from("/proj/include/config.h")]
+        );
+        assert_eq!(
+            resolve_goto_file("util", Some(buffer_dir), root, Some("rs"), is_file),
+            vec![PathBuf::from("/proj/src/util.rs")]
+        );
+        assert!(resolve_goto_file("missing", Some(buffer_dir), root, None, is_file).is_empty());
+    }
+
+    /// Several distinct matches are all returned (the caller shows a
+    /// picker); the same file reached twice is listed once.
+    #[test]
+    fn ambiguous_reference_returns_every_match_once() {
+        let root = Path::new("/proj");
+        let files: HashSet<PathBuf> = ["/proj/README.md", "/proj/src/README.md"]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let found = resolve_goto_file(
+            "README.md",
+            Some(Path::new("/proj")),
+            root,
+            None,
+            |p: &Path| files.contains(p),
+        );
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from("/proj/README.md"),
+                PathBuf::from("/proj/src/README.md")
+            ]
+        );
+    }
+
+    #[test]
+    fn extracted_suffix_and_resolution_combine() {
+        let root = Path::new("/proj");
+        let files: HashSet<PathBuf> = [PathBuf::from("/proj/src/lib.rs")].into_iter().collect();
+
+        let tok = extract_file_token("panicked at src/lib.rs:12:5", 15).unwrap();
+        assert_eq!((tok.line, tok.column), (Some(12), Some(5)));
+        assert_eq!(
+            resolve_goto_file(&tok.path, None, root, None, |p: &Path| files.contains(p)),
+            vec![PathBuf::from("/proj/src/lib.rs")]
+        );
+    }
+}
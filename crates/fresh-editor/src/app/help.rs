@@ -1,7 +1,15 @@
 //! Built-in help manual support
 //!
 //! This module provides the embedded help manual that is bundled into the binary
-//! at compile time using `include_str!()`.
+//! at compile time using `include_str!()`, and builds the keyboard shortcuts
+//! cheat sheet from the live keybinding tables.
+
+use std::collections::BTreeMap;
+
+use rust_i18n::t;
+
+use crate::input::commands::{Command, CommandSource};
+use crate::input::keybindings::{Action, ActionCategory, KeybindingResolver};
 
 /// The embedded help manual content (bundled at compile time)
 pub const HELP_MANUAL_CONTENT: &str = include_str!("../../docs/fresh.txt");
@@ -11,3 +19,125 @@ pub const HELP_MANUAL_BUFFER_NAME: &str = "*Fresh Manual*";
 
 /// The name of the keyboard shortcuts buffer
 pub const KEYBOARD_SHORTCUTS_BUFFER_NAME: &str = "*Keyboard Shortcuts*";
+
+/// Build the keyboard shortcuts cheat sheet from the keybinding tables:
+/// each bound action with all of its keys, grouped by
+/// [`ActionCategory`], followed by every plugin command in `commands`
+/// (bound or not) under Plugins.
+pub fn keyboard_shortcuts_content(resolver: &KeybindingResolver, commands: &[Command]) -> String {
+    let plugin_name = |action: &Action| {
+        commands
+            .iter()
+            .find(|c| &c.action == action)
+            .map(|c| c.name.clone())
+    };
+
+    // Actions sharing a heading and label (e.g. the vi and regular
+    // cursor moves) are listed once with all their keys.
+    let mut sections: BTreeMap<(ActionCategory, String), Vec<String>> = BTreeMap::new();
+    let mut search_keys = Vec::new();
+    for (action, keys) in resolver.keys_by_action() {
+        if action == Action::Search {
+            search_keys = keys.clone();
+        }
+        let label = match &action {
+            Action::PluginAction(name) => plugin_name(&action).unwrap_or_else(|| name.clone()),
+            _ => KeybindingResolver::format_action(&action),
+        };
+        sections
+            .entry((action.category(), label))
+            .or_default()
+            .extend(keys);
+    }
+    for command in commands {
+        if matches!(command.source, CommandSource::Plugin(_)) {
+            sections
+                .entry((ActionCategory::Plugins, command.name.clone()))
+                .or_default();
+        }
+    }
+
+    let title = t!("shortcuts.title").to_string();
+    let mut content = format!("{}\n{}\n\n", title, "=".repeat(title.chars().count()));
+    content.push_str(&t!("shortcuts.close_hint"));
+    if let Some(key) = search_keys.first() {
+        content.push(' ');
+        content.push_str(&t!("shortcuts.search_hint", key = key));
+    }
+    content.push_str("\n\n");
+
+    let mut current = None;
+    for ((category, label), mut keys) in sections {
+        if current != Some(category) {
+            if current.is_some() {
+                content.push('\n');
+            }
+            content.push_str(&format!("── {} ──\n\n", category.label()));
+            current = Some(category);
+        }
+        keys.sort();
+        keys.dedup();
+        let keys = if keys.is_empty() {
+            t!("shortcuts.unbound").to_string()
+        } else {
+            keys.join(", ")
+        };
+        content.push_str(&format!("  {:36} {}\n", label, keys));
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    /// The lines of the section under `heading`.
+    fn section<'a>(content: &'a str, heading: &str) -> Vec<&'a str> {
+        content
+            .split(&format!("── {} ──", heading))
+            .nth(1)
+            .unwrap_or_else(|| panic!("no '{heading}' section in:\n{content}"))
+            .split("── ")
+            .next()
+            .unwrap()
+            .lines()
+            .collect()
+    }
+
+    #[test]
+    fn movement_action_is_listed_under_movement_with_its_key() {
+        let resolver = KeybindingResolver::new(&Config::default());
+        let content = keyboard_shortcuts_content(&resolver, &[]);
+
+        let label = KeybindingResolver::format_action(&Action::MoveDown);
+        let line = section(&content, &ActionCategory::Movement.label())
+            .into_iter()
+            .find(|line| line.trim_start().starts_with(&format!("{label} ")))
+            .unwrap_or_else(|| panic!("'{label}' not under Movement in:\n{content}"));
+        assert!(line.contains('↓'), "{line}");
+        assert!(!section(&content, &ActionCategory::Editing.label())
+            .iter()
+            .any(|line| line.trim_start().starts_with(&format!("{label} "))));
+    }
+
+    #[test]
+    fn plugin_commands_are_listed_even_when_unbound() {
+        let resolver = KeybindingResolver::new(&Config::default());
+        let command = Command {
+            name: "Sample: Do Thing".to_string(),
+            description: String::new(),
+            action: Action::PluginAction("sample_do_thing".to_string()),
+            contexts: Vec::new(),
+            custom_contexts: Vec::new(),
+            source: CommandSource::Plugin("sample".to_string()),
+            terminal_bypass: false,
+        };
+        let content = keyboard_shortcuts_content(&resolver, &[command]);
+
+        let unbound = t!("shortcuts.unbound").to_string();
+        assert!(section(&content, &ActionCategory::Plugins.label())
+            .iter()
+            .any(|line| line.contains("Sample: Do Thing") && line.contains(&unbound)));
+    }
+}
@@ -91,11 +91,12 @@ impl Window {
         self.set_active_buffer(buffer_id);
     }
 
-    /// Open the keyboard shortcuts viewer in a read-only buffer.
+    /// Open the keyboard shortcuts cheat sheet in a read-only buffer.
     ///
     /// If a keyboard shortcuts buffer already exists, switch to it
     /// instead of creating a new one. The shortcuts are dynamically
-    /// generated from the current keybindings configuration.
+    /// generated from the current keybindings configuration, grouped by
+    /// action category.
     pub fn open_keyboard_shortcuts(&mut self) {
         let existing_buffer = self
             .buffer_metadata
@@ -108,39 +109,13 @@ impl Window {
             return;
         }
 
-        // Get all keybindings from this window's resources.
-        let bindings = self
-            .resources
-            .keybindings
-            .read()
-            .unwrap()
-            .get_all_bindings();
-
-        // Format the keybindings as readable text.
-        let mut content = String::from("Keyboard Shortcuts\n");
-        content.push_str("==================\n\n");
-        content.push_str("Press 'q' to close this buffer.\n\n");
-
-        let mut current_context = String::new();
-        for (key, action) in &bindings {
-            let (context, action_name) = if let Some(bracket_end) = action.find("] ") {
-                let ctx = &action[1..bracket_end];
-                let name = &action[bracket_end + 2..];
-                (ctx.to_string(), name.to_string())
-            } else {
-                ("Normal".to_string(), action.clone())
-            };
-
-            if context != current_context {
-                if !current_context.is_empty() {
-                    content.push('\n');
-                }
-                content.push_str(&format!("── {} Mode ──\n\n", context));
-                current_context = context;
-            }
-
-            content.push_str(&format!("  {:20} {}\n", key, action_name));
-        }
+        // Build the cheat sheet from this window's keybindings and the
+        // commands plugins have registered.
+        let commands = self.resources.command_registry.read().unwrap().get_all();
+        let content = help::keyboard_shortcuts_content(
+            &self.resources.keybindings.read().unwrap(),
+            &commands,
+        );
 
         let buffer_id = self.create_virtual_buffer(
             help::KEYBOARD_SHORTCUTS_BUFFER_NAME.to_string(),
@@ -716,24 +716,24 @@ impl Editor {
                         &key_event,
                         mode_ctx.clone(),
                     );
-                    let resolved = keybindings.resolve(&key_event, mode_ctx);
+                    let resolved = keybindings.resolve(&key_event, mode_ctx.clone());
                     (chord_result, resolved)
                 };
                 match chord_result {
                     crate::input::keybindings::ChordResolution::Complete(action) => {
                         tracing::debug!("Mode chord resolved to action: {:?}", action);
-                        self.active_window_mut().chord_state.clear();
+                        self.end_chord();
                         return self.handle_action(action);
                     }
                     crate::input::keybindings::ChordResolution::Partial => {
                         tracing::debug!("Potential chord prefix in mode '{}'", mode_name);
-                        self.active_window_mut().chord_state.push((code, modifiers));
+                        self.extend_chord(code, modifiers, mode_ctx);
                         return Ok(());
                     }
                     crate::input::keybindings::ChordResolution::NoMatch => {
                         if !self.active_window_mut().chord_state.is_empty() {
                             tracing::debug!("Chord sequence abandoned in mode, clearing state");
-                            self.active_window_mut().chord_state.clear();
+                            self.end_chord();
                         }
                     }
                 }
@@ -898,20 +898,21 @@ impl Editor {
             crate::input::keybindings::ChordResolution::Complete(action) => {
                 // Complete chord match - execute action and clear chord state
                 tracing::debug!("Complete chord match -> Action: {:?}", action);
-                self.active_window_mut().chord_state.clear();
+                self.end_chord();
+                self.record_key_command(&action);
                 return self.handle_action(action);
             }
             crate::input::keybindings::ChordResolution::Partial => {
                 // Partial match - add to chord state and wait for more keys
                 tracing::debug!("Partial chord match - waiting for next key");
-                self.active_window_mut().chord_state.push((code, modifiers));
+                self.extend_chord(code, modifiers, context.clone());
                 return Ok(());
             }
             crate::input::keybindings::ChordResolution::NoMatch => {
                 // No chord match - clear state and try regular resolution
                 if !self.active_window_mut().chord_state.is_empty() {
                     tracing::debug!("Chord sequence abandoned, clearing state");
-                    self.active_window_mut().chord_state.clear();
+                    self.end_chord();
                 }
             }
         }
@@ -924,6 +925,7 @@ impl Editor {
         match action {
             Action::LspCompletion
             | Action::LspGotoDefinition
+            | Action::GotoDefinitionInSplit
             | Action::LspReferences
             | Action::LspImplementation
             | Action::LspHover
@@ -938,8 +940,24 @@ impl Editor {
 
         // Note: Modal components (Settings, Menu, Prompt, Popup, File Browser) are now
         // handled by dispatch_modal_input using the InputHandler system.
-        // All remaining actions delegate to handle_action.
-        self.handle_action(action)
+        // All remaining actions delegate to handle_action. A held navigation
+        // key may move several lines per repeat (`navigation_acceleration`);
+        // each step is a full action, so macros record every one of them.
+        self.record_key_command(&action);
+        let steps = self.navigation_repeat_steps(&action, &context);
+        for _ in 0..steps {
+            self.handle_action(action.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Remember `action` for `RepeatLastCommand` when a key invoked one of
+    /// the registered commands. Plain motions and typing are not commands.
+    fn record_key_command(&self, action: &Action) {
+        let mut registry = self.command_registry.write().unwrap();
+        if registry.is_command_action(action) {
+            registry.record_executed(action);
+        }
     }
 
     /// Handle an action (for normal mode and command execution).
@@ -1010,6 +1028,28 @@ impl Editor {
             self.reset_dabbrev_state();
         }
 
+        // A pending operator (the `d` in `dw`) consumes the next motion.
+        if self.vi_complete_operator(&action)? {
+            return Ok(());
+        }
+
+        // A pending count prefix (the `3` in `3dd`) repeats the next action.
+        if let Some(count) = self.take_vi_count(&action) {
+            for _ in 0..count {
+                self.dispatch_action(action.clone())?;
+            }
+            return Ok(());
+        }
+
+        self.dispatch_action(action)
+    }
+
+    /// Run a single action. Unlike [`Self::handle_action`] this neither
+    /// records into a macro nor consults pending vim operator/count state,
+    /// so compound actions can run their steps through it.
+    pub(super) fn dispatch_action(&mut self, action: Action) -> AnyhowResult<()> {
+        use crate::input::keybindings::Action;
+
         match action {
             Action::Quit => self.quit(),
             Action::ForceQuit => {
@@ -1038,8 +1078,11 @@ impl Editor {
                 self.show_workspace_trust_popup(true);
             }
             Action::Save => {
-                // Check if buffer has a file path - if not, redirect to SaveAs
-                if self.active_state().buffer.file_path().is_none() {
+                if self.stdin_buffer == Some(self.active_buffer()) {
+                    // Piped stdin goes to stdout or `--output`, no prompt
+                    self.save_stdin_buffer();
+                } else if self.active_state().buffer.file_path().is_none() {
+                    // No file path - redirect to SaveAs
                     self.start_prompt_with_initial_text(
                         t!("file.save_as_prompt").to_string(),
                         PromptType::SaveFileAs,
@@ -1131,6 +1174,10 @@ impl Editor {
             Action::New => {
                 self.new_buffer();
             }
+            Action::OpenScratchBuffer(language) => {
+                let language = (!language.is_empty()).then_some(language.as_str());
+                self.open_scratch_buffer(language);
+            }
             Action::Close | Action::CloseTab => {
                 // Both Close and CloseTab use close_tab() which handles:
                 // - Closing the split if this is the last buffer and there are other splits
@@ -1204,6 +1251,16 @@ impl Editor {
                     );
                 }
             }
+            Action::FormatSelection => {
+                if self.refuse_if_editing_disabled() {
+                    return Ok(());
+                }
+                if let Err(e) = self.format_selection() {
+                    self.set_status_message(
+                        t!("error.format_failed", error = e.to_string()).to_string(),
+                    );
+                }
+            }
             Action::TrimTrailingWhitespace => {
                 if self.refuse_if_editing_disabled() {
                     return Ok(());
@@ -1377,6 +1434,30 @@ impl Editor {
             Action::YankToLineEnd => self.yank_to_line_end(),
             Action::YankToLineStart => self.yank_to_line_start(),
             Action::YankViWordEnd => self.yank_vi_word_end(),
+            Action::ViNormalMode => self.vi_normal_mode()?,
+            Action::ViInsertMode => self.vi_insert_mode(&[])?,
+            Action::ViAppend => self.vi_insert_mode(&[Action::MoveRightInLine])?,
+            Action::ViAppendLineEnd => self.vi_insert_mode(&[Action::MoveLineEnd])?,
+            Action::ViInsertAtLineStart => self.vi_insert_mode(&[Action::SmartHome])?,
+            Action::ViOpenLineBelow => {
+                self.vi_insert_mode(&[Action::MoveLineEnd, Action::InsertNewline])?
+            }
+            Action::ViOpenLineAbove => {
+                self.vi_insert_mode(&[Action::MoveLineStart, Action::OpenLine])?
+            }
+            Action::ViVisualMode => self.vi_visual_mode()?,
+            Action::ViVisualOperator(op) => self.vi_visual_operator(op)?,
+            Action::ViOperator(op) => self.vi_operator(op),
+            Action::SelectInside(object) => self.select_text_object(object, false),
+            Action::SelectAround(object) => self.select_text_object(object, true),
+            // Only meaningful after an operator, where `vi_complete_operator`
+            // consumes it.
+            Action::ViSurround(_) => {}
+            Action::ViSubstituteLine => {
+                self.vi_insert_mode(&[Action::SmartHome, Action::DeleteToLineEnd])?
+            }
+            Action::ViChangeToLineEnd => self.vi_insert_mode(&[Action::DeleteToLineEnd])?,
+            Action::ViCountDigit(c) => self.vi_count_digit(c)?,
             Action::Undo => {
                 self.handle_undo();
             }
@@ -1397,6 +1478,9 @@ impl Editor {
             Action::ShowStatusLog => {
                 self.open_status_log();
             }
+            Action::ShowMessageLog => {
+                self.open_message_log();
+            }
             Action::ShowLspStatus => {
                 self.show_lsp_status_popup();
             }
@@ -1417,6 +1501,15 @@ impl Editor {
                 }
                 self.start_quick_open();
             }
+            Action::RepeatLastCommand => {
+                let last = self.command_registry.read().unwrap().last_command();
+                match last {
+                    Some(action) => self.dispatch_action(action)?,
+                    None => {
+                        self.set_status_message(t!("status.no_last_command").to_string());
+                    }
+                }
+            }
             Action::QuickOpen => {
                 if self.close_quick_open_if_open() {
                     return Ok(());
@@ -1441,6 +1534,9 @@ impl Editor {
             Action::ResumeLiveGrep => {
                 self.handle_action(Action::PluginAction("resume_live_grep".to_string()))?;
             }
+            Action::ShowDiagnosticsPanel => {
+                self.handle_action(Action::PluginAction("show_diagnostics_panel".to_string()))?;
+            }
             Action::ToggleUtilityDock => {
                 use crate::view::split::SplitRole;
                 if let Some(dock_leaf) = self
@@ -1664,6 +1760,14 @@ impl Editor {
                     current,
                 );
             }
+            Action::ToggleMarkdownPreview => {
+                #[cfg(feature = "plugins")]
+                self.toggle_markdown_preview();
+                #[cfg(not(feature = "plugins"))]
+                self.set_status_message(
+                    "Markdown preview not available (compiled without plugin support)".to_string(),
+                );
+            }
             Action::SetBackground => {
                 let default_path = self
                     .ansi_background_path
@@ -1701,6 +1805,9 @@ impl Editor {
             Action::LspGotoDefinition => {
                 self.request_goto_definition()?;
             }
+            Action::GotoDefinitionInSplit => {
+                self.request_goto_definition_in_split()?;
+            }
             Action::LspRename => {
                 self.start_rename()?;
             }
@@ -1803,6 +1910,14 @@ impl Editor {
                     true,
                 );
             }
+            Action::SetSearchScope => {
+                self.set_search_scope();
+                self.refresh_active_search();
+            }
+            Action::ClearSearchScope => {
+                self.clear_search_scope();
+                self.refresh_active_search();
+            }
             Action::FindNext => {
                 self.find_next();
             }
@@ -1825,6 +1940,7 @@ impl Editor {
             Action::NextBuffer => self.next_buffer(),
             Action::PrevBuffer => self.prev_buffer(),
             Action::SwitchToPreviousTab => self.switch_to_previous_tab(),
+            Action::BufferSwitcher => self.show_buffer_switcher(),
             Action::SwitchToTabByName => self.start_switch_to_tab_prompt(),
 
             // Tab scrolling (manual scroll - don't auto-adjust)
@@ -1867,6 +1983,7 @@ impl Editor {
                 }
             }
             Action::NavigateBack => self.navigate_back(),
+            Action::GotoLastEdit => self.goto_last_edit(),
             Action::NavigateForward => self.navigate_forward(),
             Action::SplitHorizontal => self.split_pane_horizontal(),
             Action::SplitVertical => self.split_pane_vertical(),
@@ -1890,6 +2007,7 @@ impl Editor {
             Action::ToggleTabBar => self.active_window_mut().toggle_tab_bar(),
             Action::ToggleStatusBar => self.active_window_mut().toggle_status_bar(),
             Action::TogglePromptLine => self.active_window_mut().toggle_prompt_line(),
+            Action::ToggleZenMode => self.toggle_zen_mode(),
             Action::ToggleVerticalScrollbar => self.toggle_vertical_scrollbar(),
             Action::ToggleHorizontalScrollbar => self.toggle_horizontal_scrollbar(),
             Action::ToggleLineNumbers => self.toggle_line_numbers(),
@@ -2031,6 +2149,7 @@ impl Editor {
                 self.active_window_mut().file_explorer_toggle_select()
             }
             Action::FileExplorerSelectAll => self.active_window_mut().file_explorer_select_all(),
+            Action::FileExplorerUndoMove => self.file_explorer_undo_move(),
             Action::RemoveSecondaryCursors => {
                 // Convert action to events and apply them
                 if let Some(events) = self
@@ -2113,6 +2232,13 @@ impl Editor {
             Action::ToggleComment => {
                 self.toggle_comment();
             }
+            Action::SurroundAdd(pair) => {
+                self.surround_add(pair);
+                // `S(` in vim's visual mode consumes the selection.
+                self.vi_leave_visual_mode()?;
+            }
+            Action::SurroundChange(pair) => self.surround_change(pair),
+            Action::SurroundDelete(pair) => self.surround_delete(pair),
             Action::ToggleFold => {
                 self.active_window_mut().toggle_fold_at_cursor();
             }
@@ -2125,6 +2251,12 @@ impl Editor {
             Action::JumpToPreviousError => {
                 self.jump_to_previous_error();
             }
+            Action::OpenLinkAtCursor => {
+                self.open_link_at_cursor()?;
+            }
+            Action::GotoFileUnderCursor => {
+                self.goto_file_under_cursor()?;
+            }
             Action::SetBookmark(key) => {
                 self.active_window_mut().set_bookmark(key);
             }
@@ -2135,7 +2267,7 @@ impl Editor {
                 self.active_window_mut().clear_bookmark(key);
             }
             Action::ListBookmarks => {
-                self.active_window_mut().list_bookmarks();
+                self.show_bookmarks_panel();
             }
             Action::ToggleSearchCaseSensitive if !self.active_prompt_has_search_options() => {}
             Action::ToggleSearchWholeWord if !self.active_prompt_has_search_options() => {}
@@ -2184,6 +2316,17 @@ impl Editor {
                 };
                 self.set_status_message(t!("search.confirm_each_state", state = state).to_string());
             }
+            Action::ToggleSearchWrap => {
+                let new_value = !self.config.editor.search_wrap;
+                self.config_mut().editor.search_wrap = new_value;
+                // The active search picks the change up immediately; a
+                // search scoped to a selection never wraps.
+                if let Some(search_state) = self.active_window_mut().search_state.as_mut() {
+                    search_state.wrap_search = new_value && search_state.search_range.is_none();
+                }
+                let state = if new_value { "enabled" } else { "disabled" };
+                self.set_status_message(t!("search.wrap_state", state = state).to_string());
+            }
             Action::FileBrowserToggleHidden => {
                 // Toggle hidden files in file browser (handled via file_open_toggle_hidden)
                 self.file_open_toggle_hidden();
@@ -2235,11 +2378,14 @@ impl Editor {
                 );
             }
             Action::PromptSetBookmark => {
-                self.start_prompt("Set bookmark (0-9): ".to_string(), PromptType::SetBookmark);
+                self.start_prompt(
+                    "Set bookmark (0-9, a-z, A-Z): ".to_string(),
+                    PromptType::SetBookmark,
+                );
             }
             Action::PromptJumpToBookmark => {
                 self.start_prompt(
-                    "Jump to bookmark (0-9): ".to_string(),
+                    "Jump to bookmark (0-9, a-z, A-Z): ".to_string(),
                     PromptType::JumpToBookmark,
                 );
             }
@@ -2251,6 +2397,8 @@ impl Editor {
                 let buf = self.active_buffer();
                 self.active_window_mut().composite_prev_hunk_active(buf);
             }
+            Action::CompareBuffers => self.start_compare_buffers_prompt(None),
+            Action::DiffAgainstDisk => self.diff_against_disk(),
             Action::None => {}
             Action::DeleteBackward => {
                 if self.active_window().is_editing_disabled() {
@@ -0,0 +1,62 @@
+//! Acceleration for held navigation keys (`navigation_acceleration`).
+//!
+//! Keys of one Up/Down motion that keep arriving within `REPEAT_GAP` of
+//! each other are a run: the key is being held and auto-repeated. The
+//! longer a run lasts, the more lines each repeat moves — one more for
+//! every `navigation_acceleration_ramp_ms` — up to
+//! `navigation_acceleration_max_step`. Any other key, or a pause, ends the
+//! run. Only the buffer (the Normal key context) accelerates; prompts,
+//! popups and text-input modes never see an accelerated key.
+
+use std::time::Duration;
+
+use crate::input::keybindings::{Action, KeyContext};
+
+use super::window::KeyRepeat;
+use super::Editor;
+
+/// Longest pause between two keys of the same run. Terminal auto-repeat
+/// sends a key every 25-50ms, so anything slower is separate presses.
+const REPEAT_GAP: Duration = Duration::from_millis(150);
+
+impl Editor {
+    /// How many times to run `action`, resolved from a key in `context`:
+    /// more than once only while a navigation key is held long enough with
+    /// `navigation_acceleration` on. Tracks the run as a side effect.
+    pub(super) fn navigation_repeat_steps(
+        &mut self,
+        action: &Action,
+        context: &KeyContext,
+    ) -> usize {
+        let editor_config = &self.config.editor;
+        let accelerates = editor_config.navigation_acceleration
+            && *context == KeyContext::Normal
+            && matches!(
+                action,
+                Action::MoveUp | Action::MoveDown | Action::SelectUp | Action::SelectDown
+            );
+        if !accelerates {
+            self.active_window_mut().key_repeat = None;
+            return 1;
+        }
+        let ramp = Duration::from_millis(editor_config.navigation_acceleration_ramp_ms.max(1));
+        let max_step = editor_config.navigation_acceleration_max_step.max(1);
+
+        let now = self.time_source.now();
+        let started = match &self.active_window().key_repeat {
+            Some(run) if run.action == *action && now.duration_since(run.last) <= REPEAT_GAP => {
+                run.started
+            }
+            _ => now,
+        };
+        self.active_window_mut().key_repeat = Some(KeyRepeat {
+            action: action.clone(),
+            started,
+            last: now,
+        });
+
+        let held = now.duration_since(started);
+        let extra = (held.as_millis() / ramp.as_millis()) as usize;
+        extra.saturating_add(1).min(max_step)
+    }
+}
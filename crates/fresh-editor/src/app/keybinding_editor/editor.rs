@@ -445,8 +445,8 @@ impl KeybindingEditor {
             actions.push(format!("menu_open:{}", name));
         }
 
-        // Keybinding maps: the four built-ins plus user-defined.
-        let mut keymaps: Vec<String> = ["default", "emacs", "vscode", "macos"]
+        // Keybinding maps: the built-ins plus user-defined.
+        let mut keymaps: Vec<String> = ["default", "emacs", "vscode", "macos", "vim"]
             .map(String::from)
             .to_vec();
         keymaps.extend(config.keybinding_maps.keys().cloned());
@@ -842,8 +842,22 @@ impl KeybindingEditor {
     /// when it was added this session, otherwise record it in `pending_removes`
     /// so the save drops it from the persisted config.
     fn delete_custom_binding(&mut self, idx: usize) -> DeleteResult {
+        let action_name = self.bindings[idx].action.clone();
+        self.retire_custom_binding(idx);
+
+        self.bindings.remove(idx);
+        self.has_changes = true;
+
+        self.readd_as_unbound_if_orphaned(action_name);
+        self.apply_filters();
+        DeleteResult::CustomRemoved
+    }
+
+    /// Drop the config entry behind the custom binding at `idx`: from
+    /// `pending_adds` when it was added this session, otherwise by recording
+    /// it in `pending_removes`. The display row is left alone.
+    fn retire_custom_binding(&mut self, idx: usize) {
         let binding = &self.bindings[idx];
-        let action_name = binding.action.clone();
 
         // Use the original config-level Keybinding if available (for bindings
         // loaded from config), otherwise reconstruct it. This avoids lossy
@@ -865,23 +879,41 @@ impl KeybindingEditor {
         } else {
             self.pending_removes.push(config_kb);
         }
+    }
 
-        self.bindings.remove(idx);
+    /// Shadow a built-in keymap or plugin binding with a custom `noop` override
+    /// for the same key+context — the underlying map can't be edited in place,
+    /// so the override masks the default in the resolver.
+    fn override_binding_with_noop(&mut self, idx: usize) -> DeleteResult {
+        let action_name = self.bindings[idx].action.clone();
+        self.push_noop_override(idx);
+
+        // Replace the entry with a noop custom entry in the display.
+        let noop_display = KeybindingResolver::format_action_from_str("noop");
+        self.bindings[idx] = ResolvedBinding {
+            key_display: self.bindings[idx].key_display.clone(),
+            action: "noop".to_string(),
+            action_display: noop_display,
+            context: self.bindings[idx].context.clone(),
+            source: BindingSource::Custom,
+            key_code: self.bindings[idx].key_code,
+            modifiers: self.bindings[idx].modifiers,
+            is_chord: self.bindings[idx].is_chord,
+            plugin_name: self.bindings[idx].plugin_name.clone(),
+            command_name: None,
+            original_config: None,
+        };
         self.has_changes = true;
 
         self.readd_as_unbound_if_orphaned(action_name);
         self.apply_filters();
-        DeleteResult::CustomRemoved
+        DeleteResult::KeymapOverridden
     }
 
-    /// Shadow a built-in keymap or plugin binding with a custom `noop` override
-    /// for the same key+context — the underlying map can't be edited in place,
-    /// so the override masks the default in the resolver.
-    fn override_binding_with_noop(&mut self, idx: usize) -> DeleteResult {
+    /// Queue a custom `noop` override for the key+context of the binding at
+    /// `idx`. The display row is left alone.
+    fn push_noop_override(&mut self, idx: usize) {
         let binding = &self.bindings[idx];
-        let action_name = binding.action.clone();
-
-        // Build a noop custom override for the same key+context.
         let noop_kb = Keybinding {
             key: if binding.is_chord {
                 String::new()
@@ -903,27 +935,6 @@ impl KeybindingEditor {
             },
         };
         self.pending_adds.push(noop_kb);
-
-        // Replace the entry with a noop custom entry in the display.
-        let noop_display = KeybindingResolver::format_action_from_str("noop");
-        self.bindings[idx] = ResolvedBinding {
-            key_display: self.bindings[idx].key_display.clone(),
-            action: "noop".to_string(),
-            action_display: noop_display,
-            context: self.bindings[idx].context.clone(),
-            source: BindingSource::Custom,
-            key_code: self.bindings[idx].key_code,
-            modifiers: self.bindings[idx].modifiers,
-            is_chord: self.bindings[idx].is_chord,
-            plugin_name: self.bindings[idx].plugin_name.clone(),
-            command_name: None,
-            original_config: None,
-        };
-        self.has_changes = true;
-
-        self.readd_as_unbound_if_orphaned(action_name);
-        self.apply_filters();
-        DeleteResult::KeymapOverridden
     }
 
     /// After a delete/override, if no binding remains for `action_name`, push an
@@ -1022,6 +1033,24 @@ impl KeybindingEditor {
             when: Some(dialog.context.clone()),
         };
 
+        // Rebinding an existing entry moves it, so the old key stops running
+        // the action: a custom binding's config entry is replaced, and a
+        // keymap or plugin binding whose key or context changed is shadowed
+        // with a `noop` override, as a delete would.
+        if let Some(edit_idx) = dialog.editing_index.filter(|&i| i < self.bindings.len()) {
+            let old = &self.bindings[edit_idx];
+            let moved = old.key_code != key_code
+                || old.modifiers != modifiers
+                || old.context != dialog.context;
+            match old.source {
+                BindingSource::Custom => self.retire_custom_binding(edit_idx),
+                BindingSource::Keymap | BindingSource::Plugin if moved && !old.is_chord => {
+                    self.push_noop_override(edit_idx)
+                }
+                _ => {}
+            }
+        }
+
         // Add as custom binding
         self.pending_adds.push(new_binding.clone());
         self.has_changes = true;
@@ -1192,7 +1221,7 @@ mod tests {
     #[test]
     fn dropdown_lists_builtin_keybinding_maps() {
         let editor = make_editor(&[]);
-        for map in ["default", "emacs", "vscode", "macos"] {
+        for map in ["default", "emacs", "vscode", "macos", "vim"] {
             let qualified = format!("switch_keybinding_map:{}", map);
             assert!(
                 editor.available_actions.contains(&qualified),
@@ -8,6 +8,7 @@ use crate::input::handler::InputResult;
 use crate::view::keybinding_editor::{handle_keybinding_editor_input, KeybindingEditorAction};
 use crate::view::ui::point_in_rect;
 use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use rust_i18n::t;
 
 impl Editor {
     /// Open the keybinding editor modal
@@ -52,7 +53,7 @@ impl Editor {
             }
             KeybindingEditorAction::Close => {
                 // Close without saving
-                self.set_status_message("Keybinding editor closed".to_string());
+                self.set_status_message(t!("keybinding_editor.status_closed").to_string());
                 InputResult::Consumed
             }
             KeybindingEditorAction::SaveAndClose => {
@@ -101,7 +102,13 @@ impl Editor {
         let config_value = match serde_json::to_value(&self.config.keybindings) {
             Ok(v) => v,
             Err(e) => {
-                self.set_status_message(format!("Failed to serialize keybindings: {}", e));
+                self.set_status_message(
+                    t!(
+                        "keybinding_editor.status_serialize_failed",
+                        error = e.to_string()
+                    )
+                    .to_string(),
+                );
                 return;
             }
         };
@@ -120,10 +127,16 @@ impl Editor {
             crate::config_io::ConfigLayer::User,
         ) {
             Ok(()) => {
-                self.set_status_message("Keybinding changes saved".to_string());
+                self.set_status_message(t!("keybinding_editor.status_saved").to_string());
             }
             Err(e) => {
-                self.set_status_message(format!("Failed to save keybindings: {}", e));
+                self.set_status_message(
+                    t!(
+                        "keybinding_editor.status_save_failed",
+                        error = e.to_string()
+                    )
+                    .to_string(),
+                );
             }
         }
     }
@@ -192,7 +205,9 @@ impl Editor {
                             self.save_keybinding_editor_changes(&editor);
                             return Ok(true);
                         } else if point_in_rect(discard_r, col, row) {
-                            self.set_status_message("Keybinding editor closed".to_string());
+                            self.set_status_message(
+                                t!("keybinding_editor.status_closed").to_string(),
+                            );
                             return Ok(true);
                         } else if point_in_rect(cancel_r, col, row) {
                             editor.showing_confirm_dialog = false;
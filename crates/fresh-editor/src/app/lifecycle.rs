@@ -47,6 +47,13 @@ impl Editor {
         self.software_cursor_only = enabled;
     }
 
+    /// Render without wall-clock dependent elements: the status-bar clock
+    /// reads a fixed `00:00` without its blinking colon, and LSP spinners
+    /// stay on their first frame. Used for golden-file screen snapshots.
+    pub fn set_deterministic_render(&mut self, enabled: bool) {
+        self.deterministic_render = enabled;
+    }
+
     /// Set the session name for display in status bar.
     ///
     /// When a session name is set, the recovery service is reinitialized
@@ -299,6 +306,9 @@ impl Editor {
                     Ok(true)
                 }
             }
+            Ev::Key(key_event) if key_event.kind == KeyEventKind::Release => {
+                Ok(self.handle_key_release(key_event))
+            }
             Ev::Mouse(mouse_event) => self.handle_mouse(mouse_event),
             Ev::Resize(w, h) => {
                 self.resize(w, h);
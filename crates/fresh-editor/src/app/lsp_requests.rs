@@ -259,6 +259,8 @@ impl Editor {
         }
 
         self.active_window_mut().pending_goto_definition_request = None;
+        let in_split =
+            std::mem::take(&mut self.active_window_mut().pending_goto_definition_in_split);
 
         if locations.is_empty() {
             self.active_window_mut().status_message = Some(t!("lsp.no_definition").to_string());
@@ -325,6 +327,10 @@ impl Editor {
             return Ok(());
         }
 
+        if in_split {
+            self.focus_split_for_definition();
+        }
+
         // Resolve the URI to a buffer. `open_lsp_uri_target` handles
         // all three cases: host file under the workspace mount,
         // container-only file fetched via `docker exec cat`, and
@@ -355,12 +361,16 @@ impl Editor {
         // formatting the original wire URI.
         let line = location.range.start.line as usize;
         let character = location.range.start.character as usize;
-        let position = self
-            .buffers()
-            .get(&buffer_id)
-            .map(|state| state.buffer.line_col_to_position(line, character));
+        let end = location.range.end;
+        let range = self.buffers().get(&buffer_id).map(|state| {
+            state.buffer.line_col_to_position(line, character)
+                ..state
+                    .buffer
+                    .line_col_to_position(end.line as usize, end.character as usize)
+        });
 
-        if let Some(position) = position {
+        if let Some(range) = range {
+            let position = range.start;
             let (cursor_id, old_position, old_anchor, old_sticky_column) = {
                 let cursors = self.active_cursors();
                 let primary = cursors.primary();
@@ -392,9 +402,10 @@ impl Editor {
                 .apply_event_to_buffer(buffer_id, split_id, &event);
             // Without this the cursor lands at the definition but the
             // viewport never scrolls when the target file is already
-            // open (#1689).
-            self.active_window_mut()
-                .ensure_active_cursor_visible_for_navigation(true);
+            // open (#1689). Reveal the whole definition range, as much
+            // of it as fits.
+            let scroll_on_jump = self.config.editor.scroll_on_jump;
+            self.reveal_range(buffer_id, range, scroll_on_jump);
         }
 
         let display_path = self
@@ -832,8 +843,19 @@ impl Editor {
         }
     }
 
-    /// Request LSP go-to-definition at current cursor position
+    /// Request LSP go-to-definition at current cursor position. Without a
+    /// server, falls back to [`Self::goto_definition_without_lsp`].
     pub(crate) fn request_goto_definition(&mut self) -> AnyhowResult<()> {
+        self.request_goto_definition_impl(false)
+    }
+
+    /// Like [`Self::request_goto_definition`], but the target opens in
+    /// another split so the current view stays as it is.
+    pub(crate) fn request_goto_definition_in_split(&mut self) -> AnyhowResult<()> {
+        self.request_goto_definition_impl(true)
+    }
+
+    fn request_goto_definition_impl(&mut self, in_split: bool) -> AnyhowResult<()> {
         // Get the current buffer and cursor position
         let cursor_pos = self.active_cursors().primary().position;
         let state = self.active_state();
@@ -871,6 +893,10 @@ impl Editor {
         if sent {
             self.active_window_mut().next_lsp_request_id += 1;
             self.active_window_mut().pending_goto_definition_request = Some(request_id);
+            self.active_window_mut().pending_goto_definition_in_split = in_split;
+        } else {
+            // No server to ask: resolve locals via tree-sitter, then search.
+            self.goto_definition_without_lsp(in_split);
         }
 
         Ok(())
@@ -1,10 +1,11 @@
 //! Compose the LSP segment of the status bar.
 //!
 //! Pure function, no `Editor` access. Takes only the LSP state it reads
-//! (progress map, per-server statuses, configured servers, the user's
-//! dismissed-language set) and the current buffer's language. Returns the
-//! rendered text plus an indicator state that the status-bar theme code
-//! consumes.
+//! (progress map, busy server, per-server statuses, configured servers, the
+//! user's dismissed-language set) and the current buffer's language. Returns
+//! the rendered text plus an indicator state that the status-bar theme code
+//! consumes. Also composes the per-server detail rows of the LSP status
+//! popup (`compose_lsp_server_details`).
 //!
 //! Isolated here (rather than on `Editor`) so it can be unit-tested without
 //! a harness — see `docs/internal/editor-modules-refactor-plan.md` phase 1.
@@ -46,6 +47,47 @@ fn centered(s: &str) -> String {
     out
 }
 
+/// Truncate `s` to at most `max_cells` display cells, ending in an ellipsis
+/// when anything was cut (the ellipsis counts toward the budget).
+fn truncate_cells(s: &str, max_cells: usize) -> String {
+    use unicode_width::UnicodeWidthChar;
+    if unicode_width::UnicodeWidthStr::width(s) <= max_cells {
+        return s.to_string();
+    }
+    let budget = max_cells.saturating_sub(1);
+    let mut used = 0;
+    let mut out = String::new();
+    for ch in s.chars() {
+        let cw = ch.width().unwrap_or(0);
+        if used + cw > budget {
+            break;
+        }
+        used += cw;
+        out.push(ch);
+    }
+    out.push('…');
+    out
+}
+
+/// Current frame of the status-bar spinner, advanced by wall-clock time.
+/// Without `animate` it stays on the first frame.
+fn spinner_frame(animate: bool) -> char {
+    const SPINNER: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    // ~100ms per frame.  Using SystemTime (not Instant) keeps this a
+    // pure function of "now" — tests that control wall-clock time can
+    // drive it deterministically if ever needed, and we don't need a
+    // tick counter threaded through the app.
+    if !animate {
+        return SPINNER[0];
+    }
+    let idx = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| (d.as_millis() / 100) as usize)
+        .unwrap_or(0)
+        % SPINNER.len();
+    SPINNER[idx]
+}
+
 /// Compose the LSP segment of the status bar for a given buffer language.
 ///
 /// Returns (text, indicator-state).  The state drives the indicator's color
@@ -54,7 +96,9 @@ fn centered(s: &str) -> String {
 ///
 ///     0. Buffer-level skip (large file, binary, per-buffer toggle)
 ///                       — "LSP (n/a)",              state = OffDismissed
-///     1. Progress       — detailed progress string, state = On
+///     1. Progress       — "LSP " + spinner,         state = On
+///     1b. Requests in flight
+///                       — spinner + server name,    state = On
 ///     2. Error          — "LSP (error)",            state = Error
 ///     3. Running        — "LSP (on)",               state = On
 ///     4. Configured-but-not-running (either auto_start or opt-in dormant)
@@ -66,14 +110,17 @@ fn centered(s: &str) -> String {
 /// analyzer running elsewhere, but this file exceeded the large-file
 /// threshold) would be a lie. When no server is running, the language-
 /// level "LSP (off)" pill is the more informative signal and takes over.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn compose_lsp_status(
     current_language: &str,
     buffer_lsp_disabled_reason: Option<&str>,
     lsp_progress: &HashMap<String, LspProgressInfo>,
+    busy_server: Option<&str>,
     lsp_server_statuses: &HashMap<(String, String), LspServerStatus>,
     lsp_config: &HashMap<String, LspLanguageConfig>,
     user_dismissed_languages: &HashSet<String>,
     lsp_globally_enabled: bool,
+    animate: bool,
 ) -> (String, LspIndicatorState) {
     // 0. Per-buffer LSP skip — only flag it when it's a *mismatch* with
     //    language state: LSP is running for this language, but not for
@@ -105,18 +152,20 @@ pub(crate) fn compose_lsp_status(
         .values()
         .any(|info| info.language == current_language)
     {
-        const SPINNER: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
-        // ~100ms per frame.  Using SystemTime (not Instant) keeps this a
-        // pure function of "now" — tests that control wall-clock time can
-        // drive it deterministically if ever needed, and we don't need a
-        // tick counter threaded through the app.
-        let idx = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| (d.as_millis() / 100) as usize)
-            .unwrap_or(0)
-            % SPINNER.len();
         return (
-            centered(&format!("LSP {}", SPINNER[idx])),
+            centered(&format!("LSP {}", spinner_frame(animate))),
+            LspIndicatorState::On,
+        );
+    }
+
+    // 1b. A server for this language is answering requests (hover,
+    //     completion, semantic tokens, …).  Name it next to the spinner so
+    //     the user can tell which server is busy; the name is cut to fit
+    //     the fixed pill width so the layout still doesn't shift.
+    if let Some(name) = busy_server {
+        let name = truncate_cells(name, INDICATOR_WIDTH - 2);
+        return (
+            centered(&format!("{} {}", spinner_frame(), name)),
             LspIndicatorState::On,
         );
     }
@@ -183,6 +232,54 @@ pub(crate) fn compose_lsp_status(
     (String::new(), LspIndicatorState::None)
 }
 
+/// Runtime details of one server, shown under its header row in the LSP
+/// status popup. Gathered by the popup from the server's `LspHandle` and
+/// the window's last-error record.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct LspServerDetails {
+    /// OS process id, once the server has been spawned.
+    pub pid: Option<u32>,
+    /// Requests sent to the server that haven't been answered yet.
+    pub pending_requests: usize,
+    /// Most recent error reported for the server, if any.
+    pub last_error: Option<String>,
+}
+
+/// Compose the detail rows for one server in the LSP status popup.
+///
+/// A live server gets a `pid · initialization state · pending requests`
+/// line; any server with a recorded error gets a `last error:` line.
+/// Rows are indented to sit under the server's header row; width limits
+/// are left to the popup.
+pub(crate) fn compose_lsp_server_details(
+    status: Option<LspServerStatus>,
+    details: &LspServerDetails,
+) -> Vec<String> {
+    let mut rows = Vec::new();
+    let init_state = match status {
+        Some(LspServerStatus::Starting) => Some("starting"),
+        Some(LspServerStatus::Initializing) => Some("initializing"),
+        Some(LspServerStatus::Running) => Some("initialized"),
+        Some(LspServerStatus::Error) | Some(LspServerStatus::Shutdown) | None => None,
+    };
+    if let Some(init_state) = init_state {
+        let pid = details
+            .pid
+            .map(|pid| format!("pid {pid}"))
+            .unwrap_or_else(|| "pid ?".to_string());
+        let pending = match details.pending_requests {
+            0 => "idle".to_string(),
+            1 => "1 request pending".to_string(),
+            n => format!("{n} requests pending"),
+        };
+        rows.push(format!("    {pid} · {init_state} · {pending}"));
+    }
+    if let Some(ref error) = details.last_error {
+        rows.push(format!("    last error: {error}"));
+    }
+    rows
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,16 +321,81 @@ mod tests {
         m
     }
 
+    #[test]
+    fn busy_server_shows_spinner_and_name() {
+        let statuses = status("rust", "rust-analyzer", LspServerStatus::Running);
+        let (text, state) = compose_lsp_status(
+            "rust",
+            None,
+            &HashMap::new(),
+            Some("rust-analyzer"),
+            &statuses,
+            &configured_for("rust", "rust-analyzer"),
+            &HashSet::new(),
+            true,
+            true,
+        );
+        assert!(
+            text.contains("rust-an"),
+            "busy server name should be visible, got {:?}",
+            text
+        );
+        assert!(text.ends_with('…'), "long name is truncated: {:?}", text);
+        assert_eq!(
+            unicode_width::UnicodeWidthStr::width(text.as_str()),
+            INDICATOR_WIDTH
+        );
+        assert_eq!(state, LspIndicatorState::On);
+    }
+
+    #[test]
+    fn server_details_for_running_server() {
+        let details = LspServerDetails {
+            pid: Some(4242),
+            pending_requests: 3,
+            last_error: None,
+        };
+        assert_eq!(
+            compose_lsp_server_details(Some(LspServerStatus::Running), &details),
+            vec!["    pid 4242 · initialized · 3 requests pending".to_string()]
+        );
+
+        let idle = LspServerDetails {
+            pid: Some(7),
+            ..Default::default()
+        };
+        assert_eq!(
+            compose_lsp_server_details(Some(LspServerStatus::Initializing), &idle),
+            vec!["    pid 7 · initializing · idle".to_string()]
+        );
+    }
+
+    #[test]
+    fn server_details_for_crashed_server_show_last_error_only() {
+        let details = LspServerDetails {
+            pid: Some(4242),
+            pending_requests: 0,
+            last_error: Some("Read error: broken pipe".to_string()),
+        };
+        assert_eq!(
+            compose_lsp_server_details(Some(LspServerStatus::Error), &details),
+            vec!["    last error: Read error: broken pipe".to_string()]
+        );
+        assert!(compose_lsp_server_details(None, &LspServerDetails::default()).is_empty());
+    }
+
     #[test]
     fn empty_when_nothing_configured_or_running() {
         let (text, state) = compose_lsp_status(
             "rust",
             None,
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &HashMap::new(),
             &HashSet::new(),
             true,
+            true,
         );
         assert_eq!(text, "");
         assert_eq!(state, LspIndicatorState::None);
@@ -245,10 +407,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             true,
+            true,
         );
         assert!(text.contains("LSP (off)"));
         assert_eq!(state, LspIndicatorState::Off);
@@ -262,10 +426,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &configured_for("rust", "rust-analyzer"),
             &dismissed,
             true,
+            true,
         );
         assert!(text.contains("LSP (off)"));
         assert_eq!(state, LspIndicatorState::OffDismissed);
@@ -289,10 +455,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &config,
             &HashSet::new(),
             true,
+            true,
         );
         assert!(
             text.contains("LSP (off)"),
@@ -318,10 +486,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             false,
+            true,
         );
         assert!(text.contains("LSP (off)"));
         assert_eq!(state, LspIndicatorState::OffDismissed);
@@ -334,10 +504,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &statuses,
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             true,
+            true,
         );
         assert!(text.contains("LSP (on)"));
         assert_eq!(state, LspIndicatorState::On);
@@ -354,10 +526,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &statuses,
             &HashMap::new(),
             &HashSet::new(),
             true,
+            true,
         );
         assert!(text.contains("LSP (error)"));
         assert_eq!(state, LspIndicatorState::Error);
@@ -372,10 +546,12 @@ mod tests {
             "rust",
             None,
             &progress_for("rust"),
+            None,
             &statuses,
             &HashMap::new(),
             &HashSet::new(),
             true,
+            true,
         );
         assert!(text.contains("LSP"));
         assert_eq!(state, LspIndicatorState::On);
@@ -388,10 +564,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &statuses,
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             true,
+            true,
         );
         assert!(text.contains("LSP (off)"));
         assert_eq!(state, LspIndicatorState::Off);
@@ -404,10 +582,12 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &statuses,
             &HashMap::new(),
             &HashSet::new(),
             true,
+            true,
         );
         assert_eq!(text, "");
         assert_eq!(state, LspIndicatorState::None);
@@ -421,28 +601,34 @@ mod tests {
             "rust",
             None,
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             true,
+            true,
         );
         let (err, _) = compose_lsp_status(
             "rust",
             None,
             &HashMap::new(),
+            None,
             &status("rust", "rust-analyzer", LspServerStatus::Error),
             &HashMap::new(),
             &HashSet::new(),
             true,
+            true,
         );
         let (na, _) = compose_lsp_status(
             "rust",
             Some("File too large"),
             &HashMap::new(),
+            None,
             &status("rust", "rust-analyzer", LspServerStatus::Running),
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             true,
+            true,
         );
         let off_w = unicode_width::UnicodeWidthStr::width(off.as_str());
         let err_w = unicode_width::UnicodeWidthStr::width(err.as_str());
@@ -464,6 +650,7 @@ mod tests {
             "rust",
             Some("File too large (438726656 bytes)"),
             &HashMap::new(),
+            None,
             &statuses,
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
@@ -487,10 +674,12 @@ mod tests {
             "rust",
             Some("Virtual buffer"),
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &HashMap::new(),
             &HashSet::new(),
             true,
+            true,
         );
         assert_eq!(text, "");
         assert_eq!(state, LspIndicatorState::None);
@@ -508,10 +697,12 @@ mod tests {
             "rust",
             Some("user disabled LSP"),
             &HashMap::new(),
+            None,
             &HashMap::new(),
             &configured_for("rust", "rust-analyzer"),
             &HashSet::new(),
             true,
+            true,
         );
         assert!(
             text.contains("LSP (off)"),
@@ -0,0 +1,117 @@
+//! Markdown preview (`ToggleMarkdownPreview`).
+//!
+//! The preview is an output panel beside the Markdown buffer it was opened
+//! from: the source is converted to the panel's HTML subset, so headings,
+//! lists and emphasis render as they do in `showOutputPanel`, and fenced
+//! code blocks are highlighted for their language. Edits to the source
+//! re-render the panel once typing pauses for `PREVIEW_DEBOUNCE`. Focus
+//! stays in the source; closing the panel ends the preview.
+
+use std::time::Duration;
+
+use rust_i18n::t;
+
+use crate::model::event::BufferId;
+use crate::view::markdown::markdown_to_html_subset;
+
+use super::window::MarkdownPreview;
+use super::Editor;
+
+/// Output panel id of the preview.
+const PREVIEW_PANEL: &str = "Markdown Preview";
+
+/// How long the source must go unedited before the preview re-renders.
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(300);
+
+impl Editor {
+    /// Open a preview of the active Markdown buffer, or close the open one.
+    pub(super) fn toggle_markdown_preview(&mut self) {
+        if let Some(panel) = self.output_panel_buffer(PREVIEW_PANEL) {
+            self.active_window_mut().markdown_preview = None;
+            let splits = self.split_manager().splits_for_buffer(panel);
+            for split in splits {
+                self.handle_close_split(split.0);
+            }
+            if let Err(e) = self.close_buffer(panel) {
+                tracing::warn!("Failed to close Markdown preview: {}", e);
+            }
+            self.set_status_message(t!("markdown_preview.closed").to_string());
+            return;
+        }
+
+        if self.active_state().language != "markdown" {
+            self.set_status_message(t!("markdown_preview.not_markdown").to_string());
+            return;
+        }
+        let source = self.active_buffer();
+        let Some((html, version)) = self.markdown_preview_source(source) else {
+            return;
+        };
+
+        let source_split = self.split_manager().active_split();
+        self.handle_show_output_panel(PREVIEW_PANEL.to_string(), html);
+        self.focus_split(source_split, source);
+
+        let now = self.time_source.now();
+        self.active_window_mut().markdown_preview = Some(MarkdownPreview {
+            source,
+            rendered_version: version,
+            seen_version: version,
+            seen_at: now,
+        });
+    }
+
+    /// Re-render the preview once its source has changed and then gone
+    /// unedited for `PREVIEW_DEBOUNCE`. Returns true when the panel was
+    /// updated.
+    pub(super) fn check_markdown_preview_timer(&mut self) -> bool {
+        let Some(mut preview) = self.active_window().markdown_preview else {
+            return false;
+        };
+        let panel = self.output_panel_buffer(PREVIEW_PANEL);
+        let Some(version) = self
+            .active_window()
+            .buffers
+            .get(&preview.source)
+            .map(|state| state.buffer.version())
+        else {
+            self.active_window_mut().markdown_preview = None;
+            return false;
+        };
+        let Some(panel) = panel else {
+            self.active_window_mut().markdown_preview = None;
+            return false;
+        };
+
+        if version != preview.seen_version {
+            preview.seen_version = version;
+            preview.seen_at = self.time_source.now();
+            self.active_window_mut().markdown_preview = Some(preview);
+            return false;
+        }
+        if version == preview.rendered_version
+            || self.time_source.elapsed_since(preview.seen_at) < PREVIEW_DEBOUNCE
+        {
+            return false;
+        }
+
+        let Some((html, version)) = self.markdown_preview_source(preview.source) else {
+            return false;
+        };
+        let entries = self.render_output_panel(&html);
+        if let Err(e) = self.set_virtual_buffer_content(panel, entries) {
+            tracing::warn!("Failed to refresh Markdown preview: {}", e);
+            return false;
+        }
+        preview.rendered_version = version;
+        self.active_window_mut().markdown_preview = Some(preview);
+        true
+    }
+
+    /// The preview HTML for `source`, with the buffer version it reflects.
+    fn markdown_preview_source(&self, source: BufferId) -> Option<(String, u64)> {
+        let buffer = &self.active_window().buffers.get(&source)?.buffer;
+        let text = String::from_utf8_lossy(&buffer.slice_bytes(0..buffer.total_bytes()));
+        Some((markdown_to_html_subset(&text), buffer.version()))
+    }
+}
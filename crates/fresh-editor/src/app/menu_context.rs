@@ -131,6 +131,7 @@ impl Editor {
             .set(context_keys::KEYMAP_EMACS, active_keymap == "emacs")
             .set(context_keys::KEYMAP_VSCODE, active_keymap == "vscode")
             .set(context_keys::KEYMAP_MACOS_GUI, active_keymap == "macos-gui")
+            .set(context_keys::KEYMAP_VIM, active_keymap == "vim")
             .set(context_keys::LINE_NUMBERS, line_numbers)
             .set(context_keys::LINE_WRAP, line_wrap)
             .set(context_keys::PAGE_VIEW, page_view)
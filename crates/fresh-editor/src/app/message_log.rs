@@ -0,0 +1,80 @@
+//! In-memory history of status-bar messages.
+//!
+//! Every core `set_status_message` and plugin `setStatus` call is kept,
+//! with the local time it was shown, in a bounded ring buffer on the
+//! window. `ShowMessageLog` renders that history oldest-first into a
+//! read-only `*Messages*` buffer so messages that flashed by can be read
+//! again. Unlike `ShowStatusLog` it needs no tracing subscriber or log
+//! file, so it also works in sessions started without logging.
+
+use rust_i18n::t;
+
+use super::help_actions::HELP_PANEL_MODE;
+use crate::app::window::Window;
+use crate::app::Editor;
+use crate::primitives::text_property::TextPropertyEntry;
+
+/// Name of the virtual buffer that shows the message history.
+pub const MESSAGE_LOG_BUFFER_NAME: &str = "*Messages*";
+
+/// How many messages the history keeps before dropping the oldest.
+const STATUS_HISTORY_LIMIT: usize = 500;
+
+impl Editor {
+    /// Open (or refresh) the message log buffer for the active window.
+    pub fn open_message_log(&mut self) {
+        self.ensure_help_panel_mode_registered();
+        self.active_window_mut().open_message_log();
+    }
+}
+
+impl Window {
+    /// Append `message` to the status history, evicting the oldest entry
+    /// once the history is full.
+    pub(crate) fn record_status_message(&mut self, message: &str) {
+        if self.status_history.len() == STATUS_HISTORY_LIMIT {
+            self.status_history.pop_front();
+        }
+        self.status_history
+            .push_back((chrono::Local::now(), message.to_string()));
+    }
+
+    /// Show the status history in the `*Messages*` buffer, reusing the
+    /// buffer when it is already open so its content is refreshed.
+    pub fn open_message_log(&mut self) {
+        let content = if self.status_history.is_empty() {
+            format!("{}\n", t!("message_log.empty"))
+        } else {
+            self.status_history
+                .iter()
+                .map(|(time, message)| format!("{}  {}\n", time.format("%H:%M:%S"), message))
+                .collect()
+        };
+
+        let existing_buffer = self
+            .buffer_metadata
+            .iter()
+            .find(|(_, m)| m.display_name == MESSAGE_LOG_BUFFER_NAME)
+            .map(|(id, _)| *id);
+        let buffer_id = existing_buffer.unwrap_or_else(|| {
+            self.create_virtual_buffer(
+                MESSAGE_LOG_BUFFER_NAME.to_string(),
+                HELP_PANEL_MODE.to_string(),
+                true,
+            )
+        });
+
+        if let Err(e) =
+            self.set_virtual_buffer_content(buffer_id, vec![TextPropertyEntry::text(&content)])
+        {
+            tracing::warn!("Failed to fill message log buffer: {}", e);
+            return;
+        }
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.editing_disabled = true;
+            state.margins.configure_for_line_numbers(false);
+        }
+
+        self.set_active_buffer(buffer_id);
+    }
+}
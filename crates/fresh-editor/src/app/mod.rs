@@ -5,9 +5,12 @@ mod async_messages;
 mod bookmark_actions;
 mod bookmarks;
 mod buffer_close;
+mod buffer_compare;
 mod buffer_config_resolve;
 mod buffer_groups;
+mod buffer_link;
 mod buffer_management;
+mod buffer_switcher;
 mod calibration_actions;
 pub mod calibration_wizard;
 mod click_geometry;
@@ -15,25 +18,31 @@ mod click_handlers;
 mod clipboard;
 mod composite_buffer_actions;
 mod dabbrev_actions;
+mod definition_fallback;
 mod diagnostic_jumps;
 mod editor_accessors;
+mod edit_locations;
 mod editor_init;
 mod event_apply;
 pub mod event_debug;
 mod event_debug_actions;
+mod explorer_drag;
 mod file_explorer;
 pub mod file_open;
 mod file_open_input;
 mod file_open_orchestrators;
 mod file_open_queue;
+pub use file_open_queue::QUIT_WAIT_ID;
 mod file_operations;
 mod git_index;
+mod goto_file;
 mod help;
 mod help_actions;
 mod hover;
 mod input;
 mod input_dispatch;
 mod input_helpers;
+mod key_repeat;
 pub mod keybinding_editor;
 mod keybinding_editor_actions;
 mod lifecycle;
@@ -45,8 +54,11 @@ mod lsp_status;
 mod macro_actions;
 mod macro_codegen;
 mod macros;
+#[cfg(feature = "plugins")]
+mod markdown_preview;
 mod menu_actions;
 mod menu_context;
+mod message_log;
 mod mouse_input;
 mod navigation;
 mod on_save_actions;
@@ -57,6 +69,12 @@ mod path_utils;
 mod plugin_commands;
 #[cfg(feature = "plugins")]
 mod plugin_dispatch;
+#[cfg(feature = "plugins")]
+mod plugin_output_panel;
+#[cfg(feature = "plugins")]
+mod plugin_popups;
+#[cfg(feature = "plugins")]
+mod plugin_rendered_region;
 mod popup_actions;
 mod popup_dialogs;
 mod popup_overlay_actions;
@@ -88,9 +106,11 @@ mod theme_inspect;
 mod toggle_actions;
 pub mod types;
 mod undo_actions;
+mod vi_actions;
 mod view_actions;
 mod virtual_buffers;
 pub mod warning_domains;
+mod which_key;
 mod widget_runtime;
 pub mod window;
 mod window_actions;
@@ -169,7 +189,17 @@ pub fn editor_tick(
     if editor.check_completion_trigger_timer() {
         needs_render = true;
     }
+    if editor.check_which_key_timer() {
+        needs_render = true;
+    }
+    #[cfg(feature = "plugins")]
+    if editor.check_markdown_preview_timer() {
+        needs_render = true;
+    }
     editor.active_window_mut().check_diagnostic_pull_timer();
+    if editor.check_diagnostic_overlay_timer() {
+        needs_render = true;
+    }
     editor.check_inlay_hints_timer();
     if editor.check_warning_log() {
         needs_render = true;
@@ -184,6 +214,9 @@ pub fn editor_tick(
     if let Err(e) = editor.auto_save_persistent_buffers() {
         tracing::debug!("Auto-save (disk) error: {}", e);
     }
+    if let Err(e) = editor.auto_save_workspace() {
+        tracing::debug!("Workspace autosave error: {}", e);
+    }
 
     if editor.take_full_redraw_request() {
         clear_terminal()?;
@@ -503,6 +536,10 @@ pub struct Editor {
     /// Backend does not render a hardware cursor — always use software cursor indicators.
     software_cursor_only: bool,
 
+    /// Freeze time-driven chrome (status-bar clock, LSP spinner) so the same
+    /// state always renders the same frame. Set by the test harness.
+    deterministic_render: bool,
+
     /// Session name for display in status bar (session mode only)
     session_name: Option<String>,
 
@@ -800,6 +837,15 @@ pub struct Editor {
     /// `jdt://`, …) out of the core: the core only dispatches by scheme.
     pub(crate) lsp_uri_schemes: std::collections::HashSet<String>,
 
+    /// Text objects registered by plugins via `registerTextObject`, keyed
+    /// by the character typed after `i` / `a`.
+    pub(crate) plugin_text_objects: HashMap<char, text_ops::PluginTextObject>,
+    /// The plugin text object whose range is still being computed, if any.
+    /// A newer request replaces it, so only the latest answer is applied.
+    pub(crate) pending_text_object: Option<text_ops::PendingTextObject>,
+    /// Id for the next plugin text-object request.
+    pub(crate) next_text_object_request: u64,
+
     /// Plugin manager (handles both enabled and disabled cases)
     /// Plugin manager, wrapped in `Arc<RwLock<>>` so windows can fire
     /// hooks (`run_hook`) via WindowResources without holding an
@@ -884,6 +930,11 @@ pub struct Editor {
     #[cfg(feature = "plugins")]
     plugin_render_requested: bool,
 
+    /// Plugin `getRenderedRegion` requests, answered at the end of the
+    /// next render.
+    #[cfg(feature = "plugins")]
+    pending_rendered_regions: Vec<(ratatui::layout::Rect, fresh_core::api::JsCallbackId)>,
+
     /// Pending chord sequence for multi-key bindings (e.g., C-x C-s in Emacs)
     /// Stores the keys pressed so far in a chord sequence
     // `chord_state` moved onto `Window`.
@@ -967,6 +1018,22 @@ pub struct Editor {
     /// Status message log path (for viewing full status history)
     status_log_path: Option<PathBuf>,
 
+    /// Whether workspaces are persisted at all. Cleared by `--no-session`,
+    /// which also turns off the periodic workspace autosave.
+    workspace_enabled: bool,
+
+    /// Buffer holding piped stdin (`fresh -`). Saving it does not prompt
+    /// for a file name; see `save_stdin_buffer`.
+    stdin_buffer: Option<BufferId>,
+
+    /// `--output PATH`: where saving the stdin buffer writes. Without it
+    /// the saved text goes to `stdout_output`.
+    stdin_output_path: Option<PathBuf>,
+
+    /// Stdin buffer bytes as last saved, printed to stdout once the
+    /// terminal is restored on exit.
+    stdout_output: Option<Vec<u8>>,
+
     /// Warning domain registry for extensible warning indicators
     /// Contains LSP warnings, general warnings, and can be extended by plugins
     // `warning_domains` moved onto `Window`.
@@ -3231,11 +3298,12 @@ mod tests {
 
         // Set bookmark '1'
         editor.active_window_mut().set_bookmark('1');
+        let buffer_id = editor.active_buffer();
         assert_eq!(
             editor
                 .active_window()
                 .bookmarks
-                .get('1')
+                .get('1', buffer_id)
                 .map(|b| b.position),
             Some(7)
         );
@@ -3257,7 +3325,7 @@ mod tests {
 
         // Clear bookmark
         editor.active_window_mut().clear_bookmark('1');
-        assert_eq!(editor.active_window().bookmarks.get('1'), None);
+        assert_eq!(editor.active_window().bookmarks.get('1', buffer_id), None);
     }
 
     #[test]
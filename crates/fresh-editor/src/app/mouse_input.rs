@@ -229,6 +229,12 @@ impl Editor {
             return result;
         }
 
+        // Ctrl+Click on a link in an editor buffer (LSP document link, URL,
+        // or file path) follows it instead of placing the cursor.
+        if let Some(result) = self.try_open_buffer_link(col, row, mouse_event) {
+            return result;
+        }
+
         // Dismiss theme info popup on any left-click; check if click is on the button first
         if self.active_window_mut().theme_info_popup.is_some() {
             if let MouseEventKind::Down(MouseButton::Left) = mouse_event.kind {
@@ -311,6 +317,16 @@ impl Editor {
                     .dragging_separator
                     .is_some();
 
+                // Check if we were dragging an explorer entry and complete the move
+                if let Some(drag_state) = self
+                    .active_window_mut()
+                    .mouse_state
+                    .dragging_explorer_entry
+                    .take()
+                {
+                    self.finish_file_explorer_entry_drag(drag_state);
+                }
+
                 // Check if we were dragging a tab and complete the drop
                 if let Some(drag_state) = self.active_window_mut().mouse_state.dragging_tab.take() {
                     if drag_state.is_dragging() {
@@ -371,6 +387,11 @@ impl Editor {
                     self.update_terminal_link_hover(col, row, mouse_event.modifiers);
                 needs_render = needs_render || term_link_changed;
 
+                // Same for links in editor buffers.
+                let buffer_link_changed =
+                    self.update_buffer_link_hover(col, row, mouse_event.modifiers);
+                needs_render = needs_render || buffer_link_changed;
+
                 // Update theme info popup button highlight on hover (only when
                 // the popup actually has a button — the keyless message variant
                 // returns `None` and never highlights).
@@ -974,7 +995,7 @@ impl Editor {
     }
 
     /// Check if mouse position is over any popup (including non-transient ones like completion)
-    fn is_mouse_over_any_popup(&self, col: u16, row: u16) -> bool {
+    pub(super) fn is_mouse_over_any_popup(&self, col: u16, row: u16) -> bool {
         // Editor-level popup overlays absorb every click within their outer
         // rect so the buffer below doesn't receive a stray cursor placement.
         for (_, popup_area, _, _, _) in &self.active_chrome().global_popup_areas {
@@ -3029,6 +3050,17 @@ impl Editor {
             return Ok(());
         }
 
+        // If dragging a file explorer entry, track the directory under the mouse
+        if self
+            .active_window()
+            .mouse_state
+            .dragging_explorer_entry
+            .is_some()
+        {
+            self.handle_file_explorer_entry_drag(col, row);
+            return Ok(());
+        }
+
         Ok(())
     }
 
@@ -4378,5 +4410,6 @@ impl Editor {
         ms.drag_start_popup_scroll = None;
         ms.dragging_prompt_scrollbar = false;
         ms.selecting_in_popup = None;
+        ms.dragging_explorer_entry = None;
     }
 }
@@ -17,42 +17,56 @@
 //! Use [`Editor::ensure_active_cursor_visible_for_navigation`] right after
 //! any explicit cursor mutation that represents a user-visible jump. Use
 //! [`Editor::jump_active_cursor_to`] when the call site can also delegate
-//! the cursor mutation itself.
+//! the cursor mutation itself. Both reveal the cursor through
+//! [`Editor::reveal_range`], which scrolls a whole byte range into view and
+//! places an off-screen target according to the `scroll_on_jump` setting
+//! ([`ScrollOnJump`]); callers pass `config.editor.scroll_on_jump`. Use
+//! `reveal_range` directly when the target is wider than a single position
+//! (a search match, a diagnostic, a definition).
 //!
 //! Edits (typing, paste, indent, …) should keep using the existing
 //! `ensure_cursor_visible` path — they want the "don't undo a deliberate
 //! scroll" behavior of the skip flag.
 
+use std::ops::Range;
+
+use super::Editor;
+use crate::config::ScrollOnJump;
 use crate::model::buffer::LineNumber;
+use crate::model::cursor::Cursor;
+use crate::model::event::BufferId;
+use crate::state::EditorState;
+use crate::view::split::BufferViewState;
 
-/// Whether the active cursor should be vertically recentered when a jump
-/// causes the viewport to scroll, and whether the selection anchor should
-/// be reset.
+/// Where the active cursor should land when a jump causes the viewport to
+/// scroll, and whether the selection anchor should be reset.
 #[derive(Clone, Copy, Debug)]
 pub struct JumpOptions {
     /// If `true`, drop the selection anchor (the jump becomes a plain move).
     /// Set to `false` to extend the selection from the previous anchor.
     pub clear_anchor: bool,
-    /// If the jump caused the viewport to scroll *or* the post-condition
-    /// safety net had to fire, recenter the cursor vertically. This is the
-    /// behavior search/LSP/error navigation want — a cold landing spot
-    /// should show context above and below.
-    pub recenter_on_scroll: bool,
+    /// How to place the cursor if the jump had to scroll (or the
+    /// post-condition safety net had to fire).
+    pub scroll: ScrollOnJump,
 }
 
 impl Default for JumpOptions {
     fn default() -> Self {
         Self {
             clear_anchor: true,
-            recenter_on_scroll: true,
+            scroll: ScrollOnJump::default(),
         }
     }
 }
 
 impl JumpOptions {
-    /// Convenience: defaults for navigation jumps (clear anchor, recenter).
-    pub fn navigation() -> Self {
-        Self::default()
+    /// Convenience: navigation jumps clear the anchor and scroll as the
+    /// user's `scroll_on_jump` setting says.
+    pub fn navigation(scroll: ScrollOnJump) -> Self {
+        Self {
+            clear_anchor: true,
+            scroll,
+        }
     }
 }
 
@@ -92,7 +106,7 @@ impl crate::app::window::Window {
                 }
             }
         }
-        self.ensure_active_cursor_visible_for_navigation(opts.recenter_on_scroll);
+        self.ensure_active_cursor_visible_for_navigation(opts.scroll);
     }
 
     /// Guarantee the active cursor is visible in the active viewport.
@@ -108,15 +122,65 @@ impl crate::app::window::Window {
     ///    range. If it isn't (the lower-level routine short-circuited, or
     ///    `view_lines`-aware logic disagreed with byte-line math), forces a
     ///    hard recenter so the cursor lands roughly mid-viewport.
-    /// 4. If the visible range moved at all and `recenter_on_scroll` is
-    ///    set, recenters for context.
+    /// 4. If the visible range moved at all, places the cursor as
+    ///    `alignment` says (centered or at the top; `Minimal` keeps the
+    ///    minimal scroll).
     ///
     /// Step 3 is the safety net that makes "cursor moves but viewport
     /// stalls" (#1689) impossible to reproduce regardless of what the
     /// lower-level scroll machinery decides to do.
-    pub fn ensure_active_cursor_visible_for_navigation(&mut self, recenter_on_scroll: bool) {
+    pub fn ensure_active_cursor_visible_for_navigation(&mut self, alignment: ScrollOnJump) {
         let active_buffer = self.active_buffer();
-        self.ensure_cursor_visible_for_navigation(active_buffer, recenter_on_scroll);
+        self.ensure_cursor_visible_for_navigation(active_buffer, alignment);
+    }
+
+    /// Scroll the active split so byte `position` of the active buffer is
+    /// visible, without moving the cursor. Same contract as
+    /// [`Self::ensure_active_cursor_visible_for_navigation`], for a target
+    /// that isn't (yet) the cursor.
+    pub fn reveal_position(&mut self, position: usize, alignment: ScrollOnJump) {
+        let active_buffer = self.active_buffer();
+        self.reveal_range(active_buffer, position..position, alignment);
+    }
+
+    /// Scroll a split showing `buffer_id` (the active split if it does)
+    /// so `range` is visible, vertically and horizontally. A range taller
+    /// or wider than the viewport reveals its start.
+    pub fn reveal_range(
+        &mut self,
+        buffer_id: BufferId,
+        range: Range<usize>,
+        alignment: ScrollOnJump,
+    ) {
+        let Some(split) = self.buffers.split_manager().and_then(|m| {
+            let active = m.active_split();
+            if m.get_buffer_id(active.into()) == Some(buffer_id) {
+                Some(active)
+            } else {
+                m.splits_for_buffer(buffer_id).into_iter().next()
+            }
+        }) else {
+            return;
+        };
+        self.buffers
+            .with_buffer_and_split(buffer_id, split, |state, view_state| {
+                reveal_range_in_view(state, view_state, range, alignment);
+            });
+    }
+}
+
+impl Editor {
+    /// Scroll so `range` of `buffer_id` is fully in view, placing it as
+    /// `alignment` says when the viewport has to move. See
+    /// [`crate::app::window::Window::reveal_range`].
+    pub fn reveal_range(
+        &mut self,
+        buffer_id: BufferId,
+        range: Range<usize>,
+        alignment: ScrollOnJump,
+    ) {
+        self.active_window_mut()
+            .reveal_range(buffer_id, range, alignment);
     }
 }
 
@@ -129,83 +193,147 @@ impl crate::app::window::Window {
     pub fn ensure_cursor_visible_for_navigation(
         &mut self,
         active_buffer: crate::model::event::BufferId,
-        recenter_on_scroll: bool,
+        alignment: ScrollOnJump,
     ) {
-        let Some(active_split) = self.buffers.split_manager().map(|m| m.active_split()) else {
+        let Some(cursor_pos) = self
+            .buffers
+            .splits()
+            .and_then(|(mgr, vs_map)| vs_map.get(&mgr.active_split()))
+            .map(|vs| vs.cursors.primary().position)
+        else {
             return;
         };
-        self.buffers
-            .with_buffer_and_split(active_buffer, active_split, |state, view_state| {
-                // 1. Clear stale skip flag — a prior recenter (or scroll action) may
-                // have set it, but this navigation step is *new user intent* and must
-                // not be silently suppressed.
-                view_state.viewport.clear_skip_ensure_visible();
+        self.reveal_range(active_buffer, cursor_pos..cursor_pos, alignment);
+    }
+}
 
-                let cursor_pos = view_state.cursors.primary().position;
-                let top_byte_before = view_state.viewport.top_byte;
+/// Scroll `view_state` so `range` is visible, placing it as `alignment`
+/// says when the viewport had to move. An empty range reveals a single
+/// position.
+fn reveal_range_in_view(
+    state: &mut EditorState,
+    view_state: &mut BufferViewState,
+    range: Range<usize>,
+    alignment: ScrollOnJump,
+) {
+    let start = range.start;
+    let end = range.end.max(start);
 
-                // 2. Best-effort scroll via the existing line-aware routine.
-                view_state.ensure_cursor_visible(&mut state.buffer, &state.marker_list);
+    // 1. Clear stale skip flag — a prior recenter (or scroll action) may
+    // have set it, but this navigation step is *new user intent* and must
+    // not be silently suppressed.
+    view_state.viewport.clear_skip_ensure_visible();
 
-                let scrolled = view_state.viewport.top_byte != top_byte_before;
+    let top_byte_before = view_state.viewport.top_byte;
+    let start_line = state.buffer.get_line_number(start);
+    let end_line = state.buffer.get_line_number(end);
+    // A range taller than the viewport can't be shown whole; reveal its
+    // start and let the rest run off the bottom.
+    let fits_vertically = end_line - start_line < view_state.viewport.visible_line_count();
 
-                // 3. Post-condition check — derive line numbers (cheap, exact for
-                // non-large files; estimated for large files) and confirm the cursor
-                // line lies within the viewport's line range. If it doesn't, the
-                // lower-level routine bailed out for one of its skip-paths and we
-                // must force a recenter.
-                let cursor_visible = is_cursor_line_visible(view_state, &state.buffer, cursor_pos);
+    // 2. Best-effort scroll via the existing line-aware routine: the end
+    // first, then the start, so the start wins if both can't be shown.
+    let hidden: Vec<(usize, usize)> = view_state
+        .folds
+        .resolved_ranges(&state.buffer, &state.marker_list)
+        .into_iter()
+        .map(|r| (r.start_byte, r.end_byte))
+        .collect();
+    if fits_vertically && end_line != start_line {
+        view_state
+            .viewport
+            .ensure_visible(&mut state.buffer, &Cursor::new(end), &hidden);
+    }
+    view_state
+        .viewport
+        .ensure_visible(&mut state.buffer, &Cursor::new(start), &hidden);
 
-                let needs_recenter = !cursor_visible || (scrolled && recenter_on_scroll);
-                if needs_recenter {
-                    // Count real visual rows so a recenter in a wrapped
-                    // document doesn't under-scroll and leave the cursor
-                    // below the viewport — each logical line above the
-                    // cursor can span many rows (e.g. an EPUB/XML paragraph
-                    // on one very long line).
-                    view_state
-                        .viewport
-                        .center_on_position(&mut state.buffer, cursor_pos);
-                    view_state.viewport.scrolled_up_in_wrap = false;
-                    view_state.viewport.set_skip_ensure_visible();
-                }
+    let scrolled = view_state.viewport.top_byte != top_byte_before;
 
-                // 4. Horizontal scroll. The byte-oriented `ensure_cursor_visible`
-                // doesn't adjust `left_column`; for matches deep inside a long
-                // line (an EPUB XML element, a minified bundle, …) the cursor
-                // is on the right line but its column is past the viewport —
-                // the user sees an unchanged screen and has to scroll
-                // horizontally manually. See §5 of
-                // docs/internal/search-replace-scope-replan-on-widgets.md
-                // and #1873.
-                //
-                // Skip when line wrapping is on (every column reaches the eye
-                // via wrap) and when the gutter/scrollbar reservation leaves
-                // no usable visible width.
-                if !view_state.viewport.line_wrap_enabled {
-                    let cursor_visual_col = visual_column_of(&mut state.buffer, cursor_pos);
-                    let gutter_width = if view_state.show_line_numbers { 6 } else { 0 };
-                    let scrollbar_width = 1;
-                    let visible_width = (view_state.viewport.width as usize)
-                        .saturating_sub(gutter_width)
-                        .saturating_sub(scrollbar_width);
-                    if visible_width > 0 {
-                        let left = view_state.viewport.left_column;
-                        let right = left + visible_width;
-                        // Small margin so the cursor isn't pinned to the very
-                        // edge — mirrors `ensure_column_visible_simple`'s
-                        // `effective_offset` behaviour.
-                        let margin = (visible_width / 8).min(8);
-                        if cursor_visual_col < left + margin {
-                            view_state.viewport.left_column =
-                                cursor_visual_col.saturating_sub(margin);
-                        } else if cursor_visual_col + margin >= right {
-                            view_state.viewport.left_column =
-                                (cursor_visual_col + margin + 1).saturating_sub(visible_width);
-                        }
-                    }
-                }
-            });
+    // 3. Post-condition check — derive line numbers (cheap, exact for
+    // non-large files; estimated for large files) and confirm the target
+    // lines lie within the viewport's line range. If they don't, the
+    // lower-level routine bailed out for one of its skip-paths and we
+    // must force a scroll.
+    let visible = is_cursor_line_visible(view_state, &state.buffer, start)
+        && (!fits_vertically || is_cursor_line_visible(view_state, &state.buffer, end));
+
+    let place = match alignment {
+        _ if !visible => true,
+        ScrollOnJump::Center | ScrollOnJump::Top => scrolled,
+        ScrollOnJump::Minimal => false,
+    };
+    if place {
+        // Count real visual rows so a recenter in a wrapped document
+        // doesn't under-scroll and leave the target below the viewport —
+        // each logical line above it can span many rows (e.g. an EPUB/XML
+        // paragraph on one very long line). A multi-line range is centered
+        // on its middle line.
+        let center = if fits_vertically && end_line != start_line {
+            state
+                .buffer
+                .line_start_offset((start_line + end_line) / 2)
+                .unwrap_or(start)
+        } else {
+            start
+        };
+        match alignment {
+            ScrollOnJump::Top => view_state
+                .viewport
+                .top_on_position(&mut state.buffer, start),
+            ScrollOnJump::Center | ScrollOnJump::Minimal => view_state
+                .viewport
+                .center_on_position(&mut state.buffer, center),
+        }
+        view_state.viewport.scrolled_up_in_wrap = false;
+        view_state.viewport.set_skip_ensure_visible();
+    }
+
+    // 4. Horizontal scroll. The byte-oriented `ensure_visible` doesn't
+    // adjust `left_column`; for matches deep inside a long line (an EPUB
+    // XML element, a minified bundle, …) the target is on the right line
+    // but its column is past the viewport — the user sees an unchanged
+    // screen and has to scroll horizontally manually. See §5 of
+    // docs/internal/search-replace-scope-replan-on-widgets.md and #1873.
+    //
+    // Skip when line wrapping is on (every column reaches the eye via
+    // wrap) and when the gutter/scrollbar reservation leaves no usable
+    // visible width.
+    if !view_state.viewport.line_wrap_enabled {
+        let gutter_width = if view_state.show_line_numbers { 6 } else { 0 };
+        let scrollbar_width = 1;
+        let visible_width = (view_state.viewport.width as usize)
+            .saturating_sub(gutter_width)
+            .saturating_sub(scrollbar_width);
+        if visible_width > 0 {
+            let start_col = visual_column_of(&mut state.buffer, start);
+            // Only a single-line range has a meaningful right edge; like
+            // the vertical case, the start wins when it doesn't fit.
+            if end_line == start_line && end > start {
+                let end_col = visual_column_of(&mut state.buffer, end);
+                scroll_column_into_view(&mut view_state.viewport, end_col, visible_width);
+            }
+            scroll_column_into_view(&mut view_state.viewport, start_col, visible_width);
+        }
+    }
+}
+
+/// Adjust `left_column` so visual column `col` is on screen, keeping a
+/// small margin from the edge.
+fn scroll_column_into_view(
+    viewport: &mut crate::view::viewport::Viewport,
+    col: usize,
+    visible_width: usize,
+) {
+    let left = viewport.left_column;
+    let right = left + visible_width;
+    // Small margin so the target isn't pinned to the very edge — mirrors
+    // `ensure_column_visible_simple`'s `effective_offset` behaviour.
+    let margin = (visible_width / 8).min(8);
+    if col < left + margin {
+        viewport.left_column = col.saturating_sub(margin);
+    } else if col + margin >= right {
+        viewport.left_column = (col + margin + 1).saturating_sub(visible_width);
     }
 }
 
@@ -154,10 +154,7 @@ impl Editor {
         // only rewrite the whole file, so when a selection is active and the
         // language server supports range formatting, route through LSP range
         // formatting instead of silently reformatting the entire buffer.
-        if self.active_cursors().primary().selection_range().is_some()
-            && self.active_lsp_supports_range_formatting()
-        {
-            self.request_formatting();
+        if self.try_range_format_selection() {
             return Ok(());
         }
 
@@ -194,6 +191,31 @@ impl Editor {
         }
     }
 
+    /// Format only the active selection.
+    ///
+    /// Sends LSP `textDocument/rangeFormatting` for the primary selection; the
+    /// returned edits are applied as a single undo group. Falls back to
+    /// [`Self::format_buffer`] when there is no selection or the language
+    /// server doesn't advertise range formatting.
+    pub fn format_selection(&mut self) -> Result<(), String> {
+        if self.try_range_format_selection() {
+            return Ok(());
+        }
+        self.format_buffer()
+    }
+
+    /// Request LSP range formatting for the primary selection, if there is
+    /// one and the server supports it. Returns whether a request was sent.
+    fn try_range_format_selection(&mut self) -> bool {
+        if self.active_cursors().primary().selection_range().is_some()
+            && self.active_lsp_supports_range_formatting()
+        {
+            self.request_formatting();
+            return true;
+        }
+        false
+    }
+
     /// Run a formatter on the current buffer content.
     fn run_formatter(&mut self, formatter: &FormatterConfig, file_path: &Path) -> ActionResult {
         let file_path_str = file_path.display().to_string();
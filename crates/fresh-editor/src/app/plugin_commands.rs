@@ -4,7 +4,7 @@
 
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, ContainerId, CursorId, Event, LeafId, OverlayFace, SplitId};
-use crate::view::overlay::{OverlayHandle, OverlayNamespace};
+use crate::view::overlay::{priority, OverlayHandle, OverlayNamespace};
 use crate::view::split::SplitViewState;
 use anyhow::Result as AnyhowResult;
 use fresh_core::api::{
@@ -115,7 +115,7 @@ impl Editor {
                 namespace,
                 range,
                 face,
-                priority: 10,
+                priority: priority::DEFAULT,
                 message: None,
                 extend_to_line_end: options.extend_to_line_end,
                 url: options.url.clone(),
@@ -2004,6 +2004,53 @@ impl Editor {
         symbol: String,
         color: (u8, u8, u8),
         priority: i32,
+    ) {
+        let indicator = crate::view::margin::LineIndicator::new(
+            symbol,
+            ratatui::style::Color::Rgb(color.0, color.1, color.2),
+            priority,
+        );
+        self.set_line_indicator(buffer_id, line, namespace, indicator);
+    }
+
+    /// Handle SetGutterIcon command
+    ///
+    /// A theme-key colour is resolved against the current theme here, as
+    /// the gutter renders indicators with their stored colour.
+    pub(super) fn handle_set_gutter_icon(
+        &mut self,
+        buffer_id: BufferId,
+        line: usize,
+        namespace: String,
+        glyph: String,
+        color: fresh_core::api::OverlayColorSpec,
+        priority: i32,
+    ) {
+        use fresh_core::api::OverlayColorSpec;
+        let color = match color {
+            OverlayColorSpec::Rgb(r, g, b) => ratatui::style::Color::Rgb(r, g, b),
+            OverlayColorSpec::ThemeKey(key) => {
+                let resolved = crate::view::theme::named_color_from_str(&key)
+                    .or_else(|| self.theme.read().unwrap().resolve_theme_key(&key));
+                match resolved {
+                    Some(color) => color,
+                    None => {
+                        tracing::warn!("setGutterIcon: unknown theme key '{}'", key);
+                        return;
+                    }
+                }
+            }
+        };
+        let indicator = crate::view::margin::LineIndicator::new(glyph, color, priority);
+        self.set_line_indicator(buffer_id, line, namespace, indicator);
+    }
+
+    fn set_line_indicator(
+        &mut self,
+        buffer_id: BufferId,
+        line: usize,
+        namespace: String,
+        indicator: crate::view::margin::LineIndicator,
     ) {
         if let Some(state) = self
             .windows
@@ -2013,11 +2060,6 @@ impl Editor {
         {
             // Convert line number to byte offset for marker-based tracking
             let byte_offset = state.buffer.line_start_offset(line).unwrap_or(0);
-            let indicator = crate::view::margin::LineIndicator::new(
-                symbol,
-                ratatui::style::Color::Rgb(color.0, color.1, color.2),
-                priority,
-            );
             state
                 .margins
                 .set_line_indicator(byte_offset, namespace, indicator);
@@ -2077,6 +2119,7 @@ impl Editor {
         } else {
             // Log status message for history
             tracing::info!(target: "status", "{}", message);
+            self.active_window_mut().record_status_message(&message);
             // Detect plugin errors and collect them for test assertions
             // Error patterns: "Plugin error", "JS error", "handler error"
             let lower = message.to_lowercase();
@@ -2555,30 +2598,51 @@ impl Editor {
         whole_words: bool,
         callback_id: JsCallbackId,
     ) {
+        match self.grep_project(
+            &pattern,
+            fixed_string,
+            case_sensitive,
+            max_results,
+            whole_words,
+        ) {
+            Ok(results) => {
+                let json = serde_json::to_string(&results).unwrap_or_else(|_| "[]".to_string());
+                self.plugin_manager
+                    .read()
+                    .unwrap()
+                    .resolve_callback(callback_id, json);
+            }
+            Err(e) => {
+                self.plugin_manager
+                    .read()
+                    .unwrap()
+                    .reject_callback(callback_id, e);
+            }
+        }
+    }
+
+    /// Search every project file for `pattern`: open buffers through their
+    /// piece tree (so unsaved edits count), everything else through the
+    /// filesystem. Stops after `max_results` matches. Errors carry a
+    /// user-facing message (an invalid regex).
+    pub(super) fn grep_project(
+        &mut self,
+        pattern: &str,
+        fixed_string: bool,
+        case_sensitive: bool,
+        max_results: usize,
+        whole_words: bool,
+    ) -> Result<Vec<GrepMatch>, String> {
         if pattern.is_empty() {
-            let json = serde_json::to_string(&Vec::<GrepMatch>::new())
-                .unwrap_or_else(|_| "[]".to_string());
-            self.plugin_manager
-                .read()
-                .unwrap()
-                .resolve_callback(callback_id, json);
-            return;
+            return Ok(Vec::new());
         }
 
         // Build search options for FileSystem::search_file
         let fs_opts = make_search_opts(fixed_string, case_sensitive, whole_words, max_results);
 
         // Build regex for open buffer searches (piece tree path still needs it)
-        let regex = match crate::model::filesystem::build_search_regex(&pattern, &fs_opts) {
-            Ok(re) => re,
-            Err(e) => {
-                self.plugin_manager
-                    .read()
-                    .unwrap()
-                    .reject_callback(callback_id, format!("Invalid regex: {}", e));
-                return;
-            }
-        };
+        let regex = crate::model::filesystem::build_search_regex(pattern, &fs_opts)
+            .map_err(|e| format!("Invalid regex: {}", e))?;
 
         let query_len = pattern.len();
         let mut results: Vec<GrepMatch> = Vec::new();
@@ -2630,7 +2694,7 @@ impl Editor {
                     .buffer_state_mut(bid)
                 {
                     let matches = match state.buffer.search_hybrid(
-                        &pattern,
+                        pattern,
                         &fs_opts,
                         regex.clone(),
                         remaining,
@@ -2661,7 +2725,7 @@ impl Editor {
                 while !cursor.done && file_matches.len() < remaining {
                     match self.authority().filesystem.search_file(
                         file_path,
-                        &pattern,
+                        pattern,
                         &fs_opts_file,
                         &mut cursor,
                     ) {
@@ -2687,11 +2751,7 @@ impl Editor {
             }
         }
 
-        let json = serde_json::to_string(&results).unwrap_or_else(|_| "[]".to_string());
-        self.plugin_manager
-            .read()
-            .unwrap()
-            .resolve_callback(callback_id, json);
+        Ok(results)
     }
 
     // ==================== Pull-Based Streaming Search ====================
@@ -777,6 +777,16 @@ impl Editor {
                     buffer_id, lines, namespace, symbol, color, priority,
                 );
             }
+            PluginCommand::SetGutterIcon {
+                buffer_id,
+                line,
+                namespace,
+                glyph,
+                color,
+                priority,
+            } => {
+                self.handle_set_gutter_icon(buffer_id, line, namespace, glyph, color, priority);
+            }
             PluginCommand::ClearLineIndicators {
                 buffer_id,
                 namespace,
@@ -1339,8 +1349,8 @@ impl Editor {
             }
 
             // ==================== Vi Mode Commands ====================
-            PluginCommand::ExecuteAction { action_name } => {
-                self.handle_execute_action(action_name);
+            PluginCommand::ExecuteAction { action_name, args } => {
+                self.handle_execute_action(action_name, args);
             }
             PluginCommand::ExecuteActions { actions } => {
                 self.handle_execute_actions(actions);
@@ -1361,6 +1371,18 @@ impl Editor {
             } => {
                 self.handle_get_buffer_text(buffer_id, start, end, request_id);
             }
+            PluginCommand::GetRenderedRegion {
+                x,
+                y,
+                width,
+                height,
+                callback_id,
+            } => {
+                self.handle_get_rendered_region(
+                    ratatui::layout::Rect::new(x, y, width, height),
+                    callback_id,
+                );
+            }
             PluginCommand::GetLineStartPosition {
                 buffer_id,
                 line,
@@ -1428,6 +1450,27 @@ impl Editor {
                 self.handle_show_action_popup(popup_id, title, message, actions, buffer_id);
             }
 
+            PluginCommand::ShowPopup {
+                handle,
+                lines,
+                anchor,
+                options,
+            } => {
+                self.handle_show_plugin_popup(handle, lines, anchor, options);
+            }
+
+            PluginCommand::UpdatePopup { handle, lines } => {
+                self.handle_update_plugin_popup(handle, lines);
+            }
+
+            PluginCommand::ClosePopup { handle } => {
+                self.handle_close_plugin_popup(handle);
+            }
+
+            PluginCommand::ShowOutputPanel { id, html } => {
+                self.handle_show_output_panel(id, html);
+            }
+
             PluginCommand::SetLspMenuContributions {
                 plugin_id,
                 language,
@@ -1449,6 +1492,18 @@ impl Editor {
                 self.lsp_uri_schemes.insert(scheme);
             }
 
+            PluginCommand::RegisterTextObject {
+                plugin_name,
+                key,
+                handler_name,
+            } => {
+                self.register_plugin_text_object(plugin_name, key, handler_name);
+            }
+
+            PluginCommand::TextObjectResolved { request_id, range } => {
+                self.handle_text_object_resolved(request_id, range);
+            }
+
             PluginCommand::MarkBufferReadOnly { path } => {
                 self.handle_mark_buffer_read_only(path);
             }
@@ -2310,21 +2365,35 @@ impl Editor {
             .resolve_callback(callback_id, json_str);
     }
 
-    /// Execute an editor action by name (for vi mode plugin)
-    fn handle_execute_action(&mut self, action_name: String) {
+    /// Execute an editor action by name, with the payload `args` for actions
+    /// that carry one (e.g. `{ "char": "x" }` for `insert_char`)
+    fn handle_execute_action(
+        &mut self,
+        action_name: String,
+        args: std::collections::HashMap<String, serde_json::Value>,
+    ) {
         use crate::input::keybindings::Action;
-        use std::collections::HashMap;
 
-        // Parse the action name into an Action enum
-        if let Some(action) = Action::from_str(&action_name, &HashMap::new()) {
-            // Execute the action
-            if let Err(e) = self.handle_action(action) {
-                tracing::warn!("Failed to execute action '{}': {}", action_name, e);
-            } else {
-                tracing::debug!("Executed action: {}", action_name);
+        if is_plugin_forbidden_action(&action_name) {
+            return;
+        }
+        match Action::from_str(&action_name, &args) {
+            Some(action) => {
+                if let Err(e) = self.handle_action(action) {
+                    tracing::warn!("Failed to execute action '{}': {}", action_name, e);
+                } else {
+                    tracing::debug!("Executed action: {}", action_name);
+                }
+            }
+            None => {
+                // Payload actions parse to nothing when their args are
+                // missing or mistyped, so the name alone may be valid.
+                tracing::warn!(
+                    "Unknown action or invalid args: '{}' {:?}",
+                    action_name,
+                    args
+                );
             }
-        } else {
-            tracing::warn!("Unknown action: {}", action_name);
         }
     }
 
@@ -2333,25 +2402,8 @@ impl Editor {
     fn handle_execute_actions(&mut self, actions: Vec<fresh_core::api::ActionSpec>) {
         use crate::input::keybindings::Action;
 
-        // Plugins may *request* the trust prompt (`workspace_trust_prompt`,
-        // which asks the user) but must never *set* the trust level
-        // themselves. Granting/lowering trust is a user+core decision — the
-        // same boundary VS Code, JetBrains, and Zed enforce: an extension can
-        // open the prompt, the user decides. Silently drop any attempt to
-        // dispatch the level-setting actions through this generic channel.
-        const PLUGIN_FORBIDDEN_ACTIONS: &[&str] = &[
-            "workspace_trust_trust",
-            "workspace_trust_restrict",
-            "workspace_trust_block",
-        ];
-
         for action_spec in actions {
-            if PLUGIN_FORBIDDEN_ACTIONS.contains(&action_spec.action.as_str()) {
-                tracing::warn!(
-                    "plugin attempted to set workspace trust via '{}' — denied; \
-                     plugins may request the prompt (workspace_trust_prompt), not set the level",
-                    action_spec.action
-                );
+            if is_plugin_forbidden_action(&action_spec.action) {
                 continue;
             }
             if let Some(action) = Action::from_str(&action_spec.action, &action_spec.args) {
@@ -3230,7 +3282,7 @@ impl Editor {
     }
 
     #[allow(clippy::too_many_arguments)]
-    fn handle_create_virtual_buffer_in_split(
+    pub(super) fn handle_create_virtual_buffer_in_split(
         &mut self,
         name: String,
         mode: String,
@@ -4445,19 +4497,36 @@ impl Editor {
         );
     }
 
+    /// Resolve `callback_id` after `duration_ms` without blocking the editor
+    /// loop: the wait runs off-thread and its completion comes back through
+    /// the async bridge, so overlapping delays resolve in deadline order.
     fn handle_delay(&mut self, callback_id: fresh_core::api::JsCallbackId, duration_ms: u64) {
-        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
+        if let Some(bridge) = &self.async_bridge {
             let sender = bridge.sender();
             let callback_id_u64 = callback_id.as_u64();
-            runtime.spawn(async move {
-                tokio::time::sleep(tokio::time::Duration::from_millis(duration_ms)).await;
+            let complete = move || {
                 #[allow(clippy::let_underscore_must_use)]
                 let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
                     fresh_core::api::PluginAsyncMessage::DelayComplete {
                         callback_id: callback_id_u64,
                     },
                 ));
-            });
+            };
+            let duration = std::time::Duration::from_millis(duration_ms);
+            match &self.tokio_runtime {
+                Some(runtime) => {
+                    runtime.spawn(async move {
+                        tokio::time::sleep(duration).await;
+                        complete();
+                    });
+                }
+                None => {
+                    std::thread::spawn(move || {
+                        std::thread::sleep(duration);
+                        complete();
+                    });
+                }
+            }
         } else {
             std::thread::sleep(std::time::Duration::from_millis(duration_ms));
             self.plugin_manager
@@ -5642,6 +5711,31 @@ fn clamp_buffer_text_range(start: usize, end: usize, len: usize) -> (usize, usiz
     (start, end)
 }
 
+// Plugins may *request* the trust prompt (`workspace_trust_prompt`, which
+// asks the user) but must never *set* the trust level themselves.
+// Granting/lowering trust is a user+core decision — the same boundary VS
+// Code, JetBrains, and Zed enforce: an extension can open the prompt, the
+// user decides. Silently drop any attempt to dispatch the level-setting
+// actions through the generic action channels.
+const PLUGIN_FORBIDDEN_ACTIONS: &[&str] = &[
+    "workspace_trust_trust",
+    "workspace_trust_restrict",
+    "workspace_trust_block",
+];
+
+/// Whether `action_name` is one plugins may not dispatch; logs the denial.
+fn is_plugin_forbidden_action(action_name: &str) -> bool {
+    let forbidden = PLUGIN_FORBIDDEN_ACTIONS.contains(&action_name);
+    if forbidden {
+        tracing::warn!(
+            "plugin attempted to set workspace trust via '{}' — denied; \
+             plugins may request the prompt (workspace_trust_prompt), not set the level",
+            action_name
+        );
+    }
+    forbidden
+}
+
 #[cfg(test)]
 mod tests {
     //! Focused tests for the SpawnHostProcess kill mechanism.
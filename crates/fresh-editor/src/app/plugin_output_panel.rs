@@ -0,0 +1,63 @@
+//! Plugin output panels (`editor.showOutputPanel`).
+//!
+//! An output panel is a read-only virtual buffer in its own split whose rows
+//! are rendered from a constrained HTML subset by
+//! [`render_html_subset`], with code blocks highlighted by the editor's
+//! grammars. The panel id doubles as the `panel_id` of
+//! `createVirtualBufferInSplit`, so showing the same id again refreshes the
+//! open panel instead of opening a second one; closing the buffer closes
+//! the panel.
+
+use fresh_core::text_property::TextPropertyEntry;
+
+use crate::model::event::BufferId;
+use crate::primitives::highlight_engine::highlight_string;
+use crate::view::html_panel::render_html_subset;
+
+use super::Editor;
+
+impl Editor {
+    /// Open (or refresh) the output panel `id` with `html` rendered into it.
+    pub(super) fn handle_show_output_panel(&mut self, id: String, html: String) {
+        let entries = self.render_output_panel(&html);
+        self.handle_create_virtual_buffer_in_split(
+            format!("*{}*", id),
+            "output-panel".to_string(),
+            true,
+            entries,
+            0.5,
+            Some("vertical".to_string()),
+            Some(output_panel_key(&id)),
+            false,
+            true,
+            true,
+            Some(true),
+            false,
+            None,
+            None,
+            None,
+        );
+    }
+
+    /// The buffer of the open output panel `id`, if any.
+    pub(super) fn output_panel_buffer(&self, id: &str) -> Option<BufferId> {
+        let buffer_id = *self.panel_ids().get(&output_panel_key(id))?;
+        self.active_window()
+            .buffers
+            .contains_key(&buffer_id)
+            .then_some(buffer_id)
+    }
+
+    /// Panel rows for `html`, with code blocks highlighted in the current
+    /// theme.
+    pub(super) fn render_output_panel(&self, html: &str) -> Vec<TextPropertyEntry> {
+        let theme = self.theme.read().unwrap();
+        let highlight =
+            |code: &str, lang: &str| highlight_string(code, lang, &self.grammar_registry, &theme);
+        render_html_subset(html, Some(&highlight))
+    }
+}
+
+fn output_panel_key(id: &str) -> String {
+    format!("output-panel:{}", id)
+}
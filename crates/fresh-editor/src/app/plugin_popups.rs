@@ -0,0 +1,111 @@
+//! Plugin floating popups (`editor.showPopup` / `updatePopup` / `closePopup`).
+//!
+//! A plugin popup is an ordinary bordered text popup on a buffer's popup
+//! stack, tagged with `PopupResolver::PluginPopup { handle }` so later
+//! commands can find it again. It lives on the stack of the buffer it was
+//! raised for: it renders while that buffer is active, is dropped when the
+//! buffer closes, and Escape (or Enter, once focused) dismisses it like any
+//! other popup. Updating or closing a popup the user already dismissed is a
+//! no-op. A buffer-anchored popup holds a marker, so it follows edits before
+//! its anchor; the marker is deleted once the popup has left the stack.
+
+use fresh_core::api::{PopupAnchor, PopupOptions};
+
+use crate::model::event::BufferId;
+use crate::state::EditorState;
+use crate::view::popup::{Popup, PopupContent, PopupPosition, PopupResolver};
+
+use super::Editor;
+
+impl Editor {
+    /// Show a plugin popup with `lines`, anchored as requested.
+    pub(super) fn handle_show_plugin_popup(
+        &mut self,
+        handle: u64,
+        lines: Vec<String>,
+        anchor: PopupAnchor,
+        options: PopupOptions,
+    ) {
+        let buffer_id = options
+            .buffer_id
+            .map(BufferId)
+            .unwrap_or_else(|| self.active_buffer());
+        let Some(state) = self
+            .windows
+            .values_mut()
+            .find_map(|w| w.buffers.get_mut(&buffer_id))
+        else {
+            tracing::warn!(
+                "showPopup requested for missing buffer {:?}; ignoring",
+                buffer_id
+            );
+            return;
+        };
+
+        let content_width = options
+            .title
+            .iter()
+            .chain(lines.iter())
+            .map(|line| unicode_width::UnicodeWidthStr::width(line.as_str()))
+            .max()
+            .unwrap_or(0);
+        let mut popup = Popup::text(lines, &self.theme.read().unwrap());
+        popup.title = options.title;
+        popup.width = options
+            .width
+            .unwrap_or_else(|| u16::try_from(content_width + 2).unwrap_or(u16::MAX));
+        popup.max_height = options.max_height.unwrap_or(15);
+        release_popup_anchors(state);
+        popup.position = match anchor {
+            PopupAnchor::Buffer { position } => {
+                let position = position.min(state.buffer.len());
+                PopupPosition::BelowBufferPosition(state.marker_list.create(position, false))
+            }
+            PopupAnchor::Screen { x, y } => PopupPosition::Fixed { x, y },
+        };
+        popup.resolver = PopupResolver::PluginPopup { handle };
+        popup.focused = options.focus.unwrap_or(false);
+        state.popups.show(popup);
+    }
+
+    /// Replace the lines of the plugin popup `handle`, if it is still open.
+    pub(super) fn handle_update_plugin_popup(&mut self, handle: u64, lines: Vec<String>) {
+        let Some(popup) = self.windows.values_mut().find_map(|w| {
+            w.buffers.as_map_mut().values_mut().find_map(|state| {
+                let index = plugin_popup_index(&state.popups, handle)?;
+                state.popups.get_mut(index)
+            })
+        }) else {
+            return;
+        };
+        popup.scroll_offset = popup.scroll_offset.min(lines.len().saturating_sub(1));
+        popup.content = PopupContent::Text(lines);
+    }
+
+    /// Close the plugin popup `handle`, if it is still open.
+    pub(super) fn handle_close_plugin_popup(&mut self, handle: u64) {
+        for window in self.windows.values_mut() {
+            for state in window.buffers.as_map_mut().values_mut() {
+                if let Some(index) = plugin_popup_index(&state.popups, handle) {
+                    state.popups.remove(index);
+                    release_popup_anchors(state);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Delete the anchor markers of popups that have left `state`'s stack.
+pub(super) fn release_popup_anchors(state: &mut EditorState) {
+    for marker in state.popups.take_released_anchors() {
+        state.marker_list.delete(marker);
+    }
+}
+
+fn plugin_popup_index(popups: &crate::view::popup::PopupManager, handle: u64) -> Option<usize> {
+    popups
+        .all()
+        .iter()
+        .position(|p| p.resolver == PopupResolver::PluginPopup { handle })
+}
@@ -0,0 +1,86 @@
+//! `getRenderedRegion`: lets plugin self-tests read back what rendered.
+//!
+//! Requests are queued and answered from the frame buffer at the end of
+//! the next render, so a plugin that changes something and then asks sees
+//! the result of that change. This is a testing aid, served only in debug
+//! builds or when `FRESH_PLUGIN_DEBUG_API=1` is set.
+
+use fresh_core::api::{JsCallbackId, RenderedCell, RenderedRegion};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use super::Editor;
+
+/// Whether plugins may read back the rendered screen.
+fn plugin_debug_api_enabled() -> bool {
+    cfg!(debug_assertions)
+        || std::env::var("FRESH_PLUGIN_DEBUG_API").is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+impl Editor {
+    /// Handle GetRenderedRegion: queue the request for the next render.
+    pub(super) fn handle_get_rendered_region(&mut self, area: Rect, callback_id: JsCallbackId) {
+        if !plugin_debug_api_enabled() {
+            self.plugin_manager.read().unwrap().reject_callback(
+                callback_id,
+                "getRenderedRegion needs a debug build or FRESH_PLUGIN_DEBUG_API=1".to_string(),
+            );
+            return;
+        }
+        self.pending_rendered_regions.push((area, callback_id));
+        self.plugin_render_requested = true;
+    }
+
+    /// Answer queued GetRenderedRegion requests from the finished frame.
+    pub(super) fn resolve_rendered_regions(&mut self, buffer: &Buffer) {
+        for (area, callback_id) in std::mem::take(&mut self.pending_rendered_regions) {
+            let json = serde_json::to_string(&rendered_region(buffer, area))
+                .unwrap_or_else(|_| "null".to_string());
+            self.plugin_manager
+                .read()
+                .unwrap()
+                .resolve_callback(callback_id, json);
+        }
+    }
+}
+
+/// Copy `area`, clipped to the frame, out of a rendered frame buffer.
+fn rendered_region(buffer: &Buffer, area: Rect) -> RenderedRegion {
+    let area = area.intersection(buffer.area);
+    let mut lines = Vec::with_capacity(area.height as usize);
+    let mut cells = Vec::with_capacity(area.height as usize);
+    for y in area.top()..area.bottom() {
+        let row: Vec<RenderedCell> = (area.left()..area.right())
+            .map(|x| {
+                let cell = &buffer[(x, y)];
+                RenderedCell {
+                    symbol: cell.symbol().to_string(),
+                    fg: cell.fg.to_string(),
+                    bg: cell.bg.to_string(),
+                }
+            })
+            .collect();
+        lines.push(row.iter().map(|cell| cell.symbol.as_str()).collect());
+        cells.push(row);
+    }
+    RenderedRegion { lines, cells }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn region_is_clipped_to_the_frame() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(0, 0, "abcdef", Style::default().fg(Color::Red));
+        buffer.set_string(0, 1, "ghijkl", Style::default().bg(Color::Rgb(1, 2, 3)));
+
+        let region = rendered_region(&buffer, Rect::new(4, 0, 10, 10));
+
+        assert_eq!(region.lines, vec!["ef", "kl"]);
+        assert_eq!(region.cells[0][0].fg, "Red");
+        assert_eq!(region.cells[1][1].bg, "#010203");
+    }
+}
@@ -3,7 +3,7 @@
 //! This module contains handlers for popup-related actions like confirmation and cancellation.
 
 use super::Editor;
-use crate::model::event::Event;
+use crate::model::event::{BufferId, Event};
 use crate::primitives::snippet::{expand_snippet, is_snippet};
 use crate::primitives::word_navigation::find_completion_word_start;
 use rust_i18n::t;
@@ -197,6 +197,63 @@ impl Editor {
                 PopupConfirmResult::Done
             }
 
+            Some(PopupResolver::GotoFile { line, column }) => {
+                let path = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.clone());
+                self.hide_popup();
+                if let Some(path) = path {
+                    if let Err(e) = self.handle_open_file_at_location(path.into(), line, column) {
+                        self.set_status_message(
+                            t!("file.error_opening", error = e.to_string()).to_string(),
+                        );
+                    }
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::Bookmarks) => {
+                let target = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.as_deref())
+                    .and_then(|data| {
+                        let (key, buffer) = data.split_once(':')?;
+                        Some((key.chars().next()?, BufferId(buffer.parse().ok()?)))
+                    });
+                self.hide_popup();
+                if let Some((key, buffer_id)) = target {
+                    self.jump_to_bookmark_in(key, buffer_id);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::BufferSwitcher) => {
+                let target = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.as_deref())
+                    .and_then(|data| data.parse().ok())
+                    .map(BufferId);
+                self.hide_popup();
+                if let Some(buffer_id) = target {
+                    self.switch_from_buffer_switcher(buffer_id);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::PluginPopup { .. }) => {
+                self.hide_popup();
+                PopupConfirmResult::Done
+            }
+
             Some(PopupResolver::None) | None => {
                 self.hide_popup();
                 PopupConfirmResult::Done
@@ -424,6 +481,13 @@ impl Editor {
                 }
             }
 
+            Some(PopupResolver::GotoFile { .. })
+            | Some(PopupResolver::Bookmarks)
+            | Some(PopupResolver::BufferSwitcher)
+            | Some(PopupResolver::PluginPopup { .. }) => {
+                self.hide_popup();
+            }
+
             Some(PopupResolver::None) | None => {
                 self.hide_popup();
                 self.active_window_mut().completion_items = None;
@@ -4,7 +4,8 @@
 //! warnings popup, LSP status popup (with refresh hook), file-message
 //! popup, and a small text-properties query helper. The LSP status popup
 //! is the largest; it is split into `collect_lsp_status_servers` (gather
-//! state), `push_lsp_server_rows` / `push_lsp_footer_rows` (build the list),
+//! state, including per-server PID / pending requests / last error),
+//! `push_lsp_server_rows` / `push_lsp_footer_rows` (build the list),
 //! and `present_lsp_status_popup` (pin width + show), orchestrated by
 //! `build_and_show_lsp_status_popup`.
 
@@ -122,6 +123,8 @@ struct LspStatusServers {
     missing: std::collections::HashMap<String, bool>,
     /// display-name → configured `auto_start`.
     auto_start: std::collections::HashMap<String, bool>,
+    /// display-name → PID / pending-request count / last error.
+    details: std::collections::HashMap<String, crate::app::lsp_status::LspServerDetails>,
     /// The user dismissed this language for the session.
     user_dismissed: bool,
     /// At least one configured server has `enabled = true`.
@@ -355,6 +358,26 @@ impl Editor {
             })
             .unwrap_or_default();
 
+        // Per-server runtime details: PID and in-flight request count from
+        // the live handles, plus the last error the server reported (which
+        // outlives the handle, so crashed servers still explain themselves).
+        let mut details: std::collections::HashMap<
+            String,
+            crate::app::lsp_status::LspServerDetails,
+        > = std::collections::HashMap::new();
+        if let Some(mgr) = self.lsp() {
+            for sh in mgr.get_handles(language) {
+                let entry = details.entry(sh.name.clone()).or_default();
+                entry.pid = sh.handle.pid();
+                entry.pending_requests = sh.handle.pending_request_count();
+            }
+        }
+        for ((lang, name), error) in &self.active_window().lsp_last_errors {
+            if lang == language {
+                details.entry(name.clone()).or_default().last_error = Some(error.clone());
+            }
+        }
+
         let user_dismissed = self
             .active_window()
             .is_lsp_language_user_dismissed(language);
@@ -380,6 +403,7 @@ impl Editor {
             running,
             missing,
             auto_start,
+            details,
             user_dismissed,
             any_enabled,
         }
@@ -435,6 +459,18 @@ impl Editor {
                 "{icon} {name} ({label})"
             )));
 
+            // Detail rows (PID, init state, pending requests, last error).
+            // Truncated to the popup's width cap — an error message can be
+            // an arbitrarily long path or stack line.
+            if let Some(details) = servers.details.get(name) {
+                for row in crate::app::lsp_status::compose_lsp_server_details(status, details) {
+                    items.push(crate::view::popup::PopupListItem::new(truncate_to_cells(
+                        &row,
+                        LSP_POPUP_WIDTH_MAX as usize - 4,
+                    )));
+                }
+            }
+
             // Progress row immediately UNDER the server's name row, if there's
             // an active `$/progress` notification for this language. Fields are
             // individually truncated so a runaway progress path can't stretch
@@ -138,7 +138,7 @@ impl Editor {
         // Complete --wait tracking if this buffer had a popup-based wait
         let active = self.active_buffer();
         if let Some((wait_id, true)) = self.active_window_mut().wait_tracking.remove(&active) {
-            self.active_window_mut().completed_waits.push(wait_id);
+            self.complete_wait(wait_id);
         }
 
         // Clear hover symbol highlight if present
@@ -277,18 +277,12 @@ impl Editor {
                 );
             }
             PromptType::SetBookmark => {
-                self.handle_register_input(
-                    &input,
-                    |editor, c| editor.active_window_mut().set_bookmark(c),
-                    "Bookmark",
-                );
+                self.handle_bookmark_input(&input, |editor, c| {
+                    editor.active_window_mut().set_bookmark(c)
+                });
             }
             PromptType::JumpToBookmark => {
-                self.handle_register_input(
-                    &input,
-                    |editor, c| editor.jump_to_bookmark(c),
-                    "Bookmark",
-                );
+                self.handle_bookmark_input(&input, |editor, c| editor.jump_to_bookmark(c));
             }
             PromptType::Plugin { custom_type } => {
                 tracing::info!(
@@ -529,6 +523,14 @@ impl Editor {
                     self.set_status_message(t!("explorer.delete_cancelled").to_string());
                 }
             }
+            PromptType::ConfirmExplorerMoveOverwrite { src, dst } => {
+                let input_lower = input.trim().to_lowercase();
+                if input_lower == "y" || input_lower == "yes" {
+                    self.perform_file_explorer_move(src, dst);
+                } else {
+                    self.set_status_message(t!("explorer.move_cancelled").to_string());
+                }
+            }
             PromptType::ConfirmMultiPasteConflict {
                 safe,
                 confirmed,
@@ -642,6 +644,14 @@ impl Editor {
                     self.switch_to_tab(BufferId(id));
                 }
             }
+            PromptType::CompareBuffers { first } => {
+                if let Ok(id) = input.trim().parse::<usize>() {
+                    match first {
+                        None => self.start_compare_buffers_prompt(Some(BufferId(id))),
+                        Some(first) => self.compare_buffers(first, BufferId(id)),
+                    }
+                }
+            }
             PromptType::QueryReplaceConfirm => {
                 // This is handled by InsertChar, not PromptConfirm
                 // But if somehow Enter is pressed, treat it as skip (n)
@@ -845,6 +855,7 @@ impl Editor {
                         path: full_path.clone(),
                     },
                 );
+                self.refresh_quick_open_path(&full_path);
 
                 if let Some(buffer_to_close) = self.active_window_mut().pending_close_buffer.take()
                 {
@@ -1263,7 +1274,7 @@ impl Editor {
     }
 
     /// Handle SetLanguage prompt confirmation.
-    fn handle_set_language(&mut self, input: &str) {
+    pub(super) fn handle_set_language(&mut self, input: &str) {
         use crate::primitives::detected_language::DetectedLanguage;
 
         let trimmed = input.trim();
@@ -1348,6 +1359,19 @@ impl Editor {
         }
     }
 
+    /// Like `handle_register_input`, but bookmarks also accept letters:
+    /// lowercase for marks local to the buffer, uppercase for file marks.
+    fn handle_bookmark_input<F>(&mut self, input: &str, action: F)
+    where
+        F: FnOnce(&mut Self, char),
+    {
+        match input.trim().chars().next() {
+            Some(c) if crate::app::bookmarks::is_bookmark_key(c) => action(self, c),
+            Some(_) => self.set_status_message(t!("bookmark.invalid_register").to_string()),
+            None => self.set_status_message(t!("register.not_specified").to_string()),
+        }
+    }
+
     /// Handle ConfirmCloseBuffer prompt. Returns true if early return is needed.
     fn handle_confirm_close_buffer(&mut self, input: &str, buffer_id: BufferId) -> bool {
         let input_lower = input.trim().to_lowercase();
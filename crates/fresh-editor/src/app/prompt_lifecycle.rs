@@ -154,6 +154,30 @@ impl Editor {
         self.update_quick_open_suggestions(prefix);
     }
 
+    /// Tell the Quick Open file index that `path` was created, deleted or
+    /// renamed so the next file search sees it without re-indexing the
+    /// whole working directory. Paths outside the working directory are
+    /// ignored; the working directory itself invalidates the whole index.
+    pub(crate) fn refresh_quick_open_path(&self, path: &std::path::Path) {
+        let working_dir = self.working_dir();
+        let Ok(relative) = path.strip_prefix(working_dir) else {
+            return;
+        };
+        let relative = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if let Some((provider, _)) = self.quick_open_registry.get_provider_for_input("") {
+            if let Some(fp) = provider
+                .as_any()
+                .downcast_ref::<crate::input::quick_open::providers::FileProvider>()
+            {
+                fp.refresh_path(&working_dir.display().to_string(), &relative);
+            }
+        }
+    }
+
     /// Build a QuickOpenContext from current editor state
     pub(super) fn build_quick_open_context(&self) -> QuickOpenContext {
         let metadata = &self.active_window().buffer_metadata;
@@ -1017,6 +1041,7 @@ impl Editor {
                     | PromptType::SelectTheme { .. }
                     | PromptType::SelectLocale
                     | PromptType::SwitchToTab
+                    | PromptType::CompareBuffers { .. }
                     | PromptType::SetLanguage
                     | PromptType::SetEncoding
                     | PromptType::SetLineEnding
@@ -1319,6 +1344,7 @@ impl Editor {
                 }
             }
             PromptType::SwitchToTab
+            | PromptType::CompareBuffers { .. }
             | PromptType::SelectTheme { .. }
             | PromptType::StopLspServer
             | PromptType::RestartLspServer
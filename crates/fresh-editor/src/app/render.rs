@@ -44,7 +44,13 @@ impl Editor {
         };
         let scroll_offset = popup.scroll_offset;
         if draw {
-            popup.render_with_hover(frame, popup_area, theme, hover_target);
+            popup.render_with_hover(
+                frame,
+                popup_area,
+                theme,
+                hover_target,
+                self.config.editor.border_style,
+            );
         }
         self.active_chrome_mut().global_popup_areas.push((
             top_idx,
@@ -985,6 +991,11 @@ impl Editor {
             top_is_trust_modal,
             &theme_clone,
         );
+
+        #[cfg(feature = "plugins")]
+        if !self.pending_rendered_regions.is_empty() {
+            self.resolve_rendered_regions(frame.buffer_mut());
+        }
     }
 
     /// Render the search-options bar into `area` when `show_search_options`
@@ -1129,6 +1140,39 @@ impl Editor {
                 .expect("active window must have a populated split layout")
                 .get(&active_split)
                 .map(|vs| *vs.cursors.primary());
+
+            // Popups anchored to a marker (plugin `showPopup`) follow that
+            // marker rather than the cursor. Resolve it against this frame's
+            // view-line mappings, which only cover the visible rows: an
+            // anchor scrolled out of view has no cell and hides its popup.
+            let anchor_bytes: Vec<Option<usize>> = {
+                let state = self.active_state_mut();
+                super::plugin_popups::release_popup_anchors(state);
+                state
+                    .popups
+                    .all()
+                    .iter()
+                    .map(|popup| match popup.position {
+                        crate::view::popup::PopupPosition::BelowBufferPosition(marker) => {
+                            state.marker_list.get_position(marker)
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            };
+            let anchor_cells: Vec<Option<(u16, u16)>> = {
+                let layout = self.active_layout();
+                anchor_bytes
+                    .iter()
+                    .map(|byte| {
+                        let byte = (*byte)?;
+                        let row = layout.find_visual_row(active_split, byte)?;
+                        let col = layout.byte_to_visual_column(active_split, byte)?;
+                        Some((u16::try_from(col).ok()?, u16::try_from(row).ok()?))
+                    })
+                    .collect()
+            };
+
             let state = self.active_state_mut();
             if state.popups.is_visible() {
                 // Get the primary cursor position for popup positioning
@@ -1183,15 +1227,23 @@ impl Editor {
                     .enumerate()
                     .map(|(popup_idx, popup)| {
                         // Use word-start x for completion popups, cursor x for others
-                        let popup_pos = if popup.kind == crate::view::popup::PopupKind::Completion {
-                            (word_start_screen_pos.0, cursor_screen_pos.1)
+                        let popup_pos = if matches!(
+                            popup.position,
+                            crate::view::popup::PopupPosition::BelowBufferPosition(_)
+                        ) {
+                            anchor_cells[popup_idx].map(|(x, y)| (x + base_x, y + base_y))
+                        } else if popup.kind == crate::view::popup::PopupKind::Completion {
+                            Some((word_start_screen_pos.0, cursor_screen_pos.1))
                         } else {
-                            cursor_screen_pos
+                            Some(cursor_screen_pos)
                         };
                         // Clamp within the chrome area (right of a left
                         // dock) so a cursor-anchored popup near the left
-                        // edge can't extend into the dock column.
-                        let popup_area = popup.calculate_area(chrome_area, Some(popup_pos));
+                        // edge can't extend into the dock column. A popup
+                        // whose anchor is out of view gets an empty area.
+                        let popup_area = popup_pos
+                            .map(|pos| popup.calculate_area(chrome_area, Some(pos)))
+                            .unwrap_or_default();
 
                         // Track popup area for mouse hit testing
                         // Account for description height when calculating the list item area
@@ -1255,11 +1307,21 @@ impl Editor {
         // the web renders them natively from `popups_view`, but the area cache
         // above is always populated for hit-routing).
         let draw_popups = !self.suppress_chrome_cells;
+        let border_style = self.config.editor.border_style;
         let state = self.active_state_mut();
         if draw_popups && state.popups.is_visible() {
             for (popup_idx, popup) in state.popups.all().iter().enumerate() {
                 if let Some((_, popup_area, _, _, _, _, _)) = popup_info.get(popup_idx) {
-                    popup.render_with_hover(frame, *popup_area, theme_clone, hover_target.as_ref());
+                    if popup_area.is_empty() {
+                        continue;
+                    }
+                    popup.render_with_hover(
+                        frame,
+                        *popup_area,
+                        theme_clone,
+                        hover_target.as_ref(),
+                        border_style,
+                    );
                 }
             }
         }
@@ -1692,6 +1754,7 @@ impl Editor {
                 &self.theme.read().unwrap(),
                 close_button_hovered,
                 remote_connection.as_deref(),
+                self.config.editor.border_style,
                 cut_paths,
                 &self.config.file_explorer,
                 &mut crate::app::types::CellThemeRecorder::new(&mut fe_runs),
@@ -1717,6 +1780,7 @@ impl Editor {
                 &self.theme.read().unwrap(),
                 close_button_hovered,
                 remote_connection.as_deref(),
+                self.config.editor.border_style,
             );
         }
         self.active_chrome_mut().apply_theme_runs(&fe_runs);
@@ -1777,10 +1841,12 @@ impl Editor {
             &current_language,
             buffer_lsp_disabled_reason,
             &self.active_window().lsp_progress,
+            self.active_window().lsp.busy_server(&current_language),
             &self.active_window().lsp_server_statuses,
             &self.config.lsp,
             &self.active_window().user_dismissed_lsp_languages,
             self.config.lsp_enabled,
+            !self.deterministic_render,
         );
         let chord_state_cloned = self.active_window().chord_state.clone(); // Clone the chord state
 
@@ -1916,6 +1982,7 @@ impl Editor {
                         remote_indicator_on_bar: false,
                         dynamic_status_bar_elements: dynamic_status_bar_elements.clone(),
                         workspace_trust_level,
+                        deterministic: self.deterministic_render,
                     };
                     let mut sb_rec =
                         crate::app::types::CellThemeRecorder::new(&mut status_bar_runs);
@@ -2046,6 +2113,7 @@ impl Editor {
             );
             let hover_target = self.active_window().mouse_state.hover_target.clone();
             let menu_bar_mnemonics = self.config.editor.menu_bar_mnemonics;
+            let border_style = self.config.editor.border_style;
             let draw_chrome = !self.suppress_chrome_cells;
             // The single content source shared with the web `menu_view()`
             // projection (uses the cache populated just above).
@@ -2061,6 +2129,7 @@ impl Editor {
                 &self.theme.read().unwrap(),
                 hover_target.as_ref(),
                 menu_bar_mnemonics,
+                border_style,
                 Some(&mut crate::app::types::CellThemeRecorder::new(
                     &mut menu_runs,
                 )),
@@ -3936,7 +4005,7 @@ impl Editor {
     ) {
         use ratatui::style::Style;
         use ratatui::text::{Line, Span};
-        use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+        use ratatui::widgets::{Clear, Paragraph};
 
         let menu_width = core.width;
         let menu_height = core.height();
@@ -3973,8 +4042,7 @@ impl Editor {
             lines.push(Line::from(vec![Span::styled(padded_label, style)]));
         }
 
-        let block = Block::default()
-            .borders(Borders::ALL)
+        let block = crate::view::ui::border::bordered_block(self.config.editor.border_style)
             .border_style(Style::default().fg(border_fg))
             .style(Style::default().bg(dropdown_bg));
 
@@ -111,7 +111,10 @@ impl crate::app::window::Window {
             .and_then(|vs| vs.view_transform.as_ref())
             .map(|vt| vt.tokens.clone());
 
-        let tab_size = self.config().editor.tab_size;
+        let editor_config = &self.config().editor;
+        let tab_size = editor_config
+            .tab_display_width
+            .unwrap_or(editor_config.tab_size);
         self.scroll_split_by_lines(
             buffer_id,
             target_split,
@@ -40,6 +40,64 @@ impl Editor {
             .update_search_highlights(query, search_fg, search_bg);
     }
 
+    /// Make the primary selection the persistent search scope. Until it is
+    /// cleared, searches and Replace All in this buffer only consider
+    /// matches inside it (a Find in Selection still uses its own range).
+    pub(super) fn set_search_scope(&mut self) {
+        let Some(range) = self
+            .active_cursors()
+            .primary()
+            .selection_range()
+            .filter(|r| !r.is_empty())
+        else {
+            self.set_status_message(t!("search.scope_no_selection").to_string());
+            return;
+        };
+
+        let scope_bg = self.theme.read().unwrap().current_line_bg;
+        let ns = self.active_window().search_scope_namespace.clone();
+        let state = self.active_state_mut();
+        state.overlays.clear_namespace(&ns, &mut state.marker_list);
+        let overlay = crate::view::overlay::Overlay::with_namespace(
+            &mut state.marker_list,
+            range,
+            crate::view::overlay::OverlayFace::Background { color: scope_bg },
+            ns,
+        )
+        .with_priority_value(crate::view::overlay::priority::SEARCH_SCOPE);
+        state.overlays.add(overlay);
+
+        // The scope highlight replaces the selection highlight.
+        self.active_cursors_mut().primary_mut().clear_selection();
+        self.set_status_message(t!("search.scope_set").to_string());
+    }
+
+    /// Drop the search scope of the active buffer, if it has one.
+    pub(super) fn clear_search_scope(&mut self) {
+        if self.search_scope().is_none() {
+            self.set_status_message(t!("search.scope_none").to_string());
+            return;
+        }
+        let ns = self.active_window().search_scope_namespace.clone();
+        let state = self.active_state_mut();
+        state.overlays.clear_namespace(&ns, &mut state.marker_list);
+        self.set_status_message(t!("search.scope_cleared").to_string());
+    }
+
+    /// Current search scope of the active buffer, read back from its
+    /// overlay so it reflects edits made since it was set.
+    pub(super) fn search_scope(&self) -> Option<std::ops::Range<usize>> {
+        let ns = &self.active_window().search_scope_namespace;
+        let state = self.active_state();
+        state
+            .overlays
+            .all()
+            .iter()
+            .find(|o| o.namespace.as_ref() == Some(ns))
+            .map(|o| o.range(&state.marker_list))
+            .filter(|r| !r.is_empty())
+    }
+
     /// Perform a search and update search state.
     ///
     /// For large files (lazy-loaded buffers), this starts an incremental
@@ -48,19 +106,26 @@ impl Editor {
     ///
     /// Matches are capped at `MAX_SEARCH_MATCHES` to bound memory usage,
     /// and overlays are only created for the visible viewport.
-    /// Move the primary cursor to `position`, clear its selection anchor,
-    /// update the cached line number (used by the status bar), and scroll
-    /// the active split so the cursor is visible.
+    /// Move the primary cursor to the match at `position`, clear its
+    /// selection anchor, update the cached line number (used by the status
+    /// bar), and scroll the active split so the `len`-byte match is visible.
     ///
     /// Delegates to [`Editor::jump_active_cursor_to`] so the viewport
     /// invariant (cursor must end up visible) is enforced uniformly with
     /// every other navigation flow (LSP goto-def, jump-to-line, etc.). If
     /// the match was off-screen and required a scroll, the viewport is
-    /// vertically centered on the match to provide surrounding context
-    /// (issue #1251); matches already visible are not re-scrolled.
-    fn move_cursor_to_match(&mut self, position: usize) {
-        self.active_window_mut()
-            .jump_active_cursor_to(position, super::navigation::JumpOptions::navigation());
+    /// placed per `scroll_on_jump` — by default vertically centered on the
+    /// match to provide surrounding context (issue #1251); matches already
+    /// visible are not re-scrolled. A long match (multi-line regex, one
+    /// running past the right edge) is then revealed as a whole.
+    fn move_cursor_to_match(&mut self, position: usize, len: usize) {
+        let scroll_on_jump = self.config.editor.scroll_on_jump;
+        self.active_window_mut().jump_active_cursor_to(
+            position,
+            super::navigation::JumpOptions::navigation(scroll_on_jump),
+        );
+        let buffer_id = self.active_buffer();
+        self.reveal_range(buffer_id, position..position + len, scroll_on_jump);
     }
 
     pub(super) fn perform_search(&mut self, query: &str) {
@@ -70,7 +135,8 @@ impl Editor {
             return;
         }
 
-        let search_range = self.active_window_mut().pending_search_range.take();
+        let pending_range = self.active_window_mut().pending_search_range.take();
+        let search_range = pending_range.or_else(|| self.search_scope());
 
         // Build the regex early so we can bail on invalid patterns
         let regex = match self.active_window().build_search_regex(query) {
@@ -165,7 +231,7 @@ impl Editor {
 
         // Move cursor to the first match
         let match_pos = matches[current_match_index];
-        self.move_cursor_to_match(match_pos);
+        self.move_cursor_to_match(match_pos, match_lengths[current_match_index]);
 
         let num_matches = matches.len();
 
@@ -174,7 +240,7 @@ impl Editor {
             matches,
             match_lengths: match_lengths.clone(),
             current_match_index: Some(current_match_index),
-            wrap_search: search_range.is_none(),
+            wrap_search: search_range.is_none() && self.config.editor.search_wrap,
             search_range,
             capped,
         });
@@ -204,7 +270,7 @@ impl Editor {
                     },
                     ns.clone(),
                 )
-                .with_priority_value(10);
+                .with_priority_value(crate::view::overlay::priority::DEFAULT);
                 state.overlays.add(overlay);
             }
         }
@@ -318,7 +384,7 @@ impl Editor {
                 },
                 ns.clone(),
             )
-            .with_priority_value(10);
+            .with_priority_value(crate::view::overlay::priority::DEFAULT);
             state.overlays.add(overlay);
         }
     }
@@ -491,6 +557,7 @@ impl Editor {
                 return;
             }
 
+            let mut wrapped = false;
             let target_index = match direction {
                 SearchDirection::Forward => {
                     // First match strictly after the cursor position.
@@ -505,7 +572,10 @@ impl Editor {
                     };
                     match idx {
                         Some(i) => i,
-                        None if search_state.wrap_search => 0,
+                        None if search_state.wrap_search => {
+                            wrapped = true;
+                            0
+                        }
                         None => {
                             self.set_status_message(t!("search.no_matches").to_string());
                             return;
@@ -530,7 +600,10 @@ impl Editor {
                     };
                     match idx {
                         Some(i) => i,
-                        None if search_state.wrap_search => match_positions.len() - 1,
+                        None if search_state.wrap_search => {
+                            wrapped = true;
+                            match_positions.len() - 1
+                        }
                         None => {
                             self.set_status_message(t!("search.no_matches").to_string());
                             return;
@@ -542,17 +615,28 @@ impl Editor {
             search_state.current_match_index = Some(target_index);
             let match_pos = match_positions[target_index];
             let matches_len = match_positions.len();
-
-            self.move_cursor_to_match(match_pos);
-
-            self.set_status_message(
-                t!(
-                    "search.match_of",
-                    current = target_index + 1,
-                    total = matches_len
-                )
-                .to_string(),
-            );
+            // Overlay positions track edits, so look the length up by
+            // position rather than by index.
+            let match_len = search_state
+                .matches
+                .binary_search(&match_pos)
+                .ok()
+                .and_then(|i| search_state.match_lengths.get(i).copied())
+                .unwrap_or(0);
+
+            self.move_cursor_to_match(match_pos, match_len);
+
+            let (current, total) = (target_index + 1, matches_len);
+            let message = match (wrapped, direction) {
+                (false, _) => t!("search.match_of", current = current, total = total),
+                (true, SearchDirection::Forward) => {
+                    t!("search.wrapped_to_top", current = current, total = total)
+                }
+                (true, SearchDirection::Backward) => {
+                    t!("search.wrapped_to_bottom", current = current, total = total)
+                }
+            };
+            self.set_status_message(message.to_string());
 
             if is_large {
                 self.refresh_search_overlays();
@@ -857,6 +941,14 @@ impl Editor {
             matches
         };
 
+        let matches = match self.search_scope() {
+            Some(scope) => matches
+                .into_iter()
+                .filter(|(pos, len, _)| *pos >= scope.start && pos + len <= scope.end)
+                .collect(),
+            None => matches,
+        };
+
         let count = matches.len();
 
         if count == 0 {
@@ -971,7 +1063,7 @@ impl Editor {
         });
 
         // Move cursor to first match
-        self.move_cursor_to_match(first_match_pos);
+        self.move_cursor_to_match(first_match_pos, first_match_len);
 
         // Show the query-replace prompt
         self.active_window_mut().prompt = Some(Prompt::new(
@@ -1275,7 +1367,7 @@ impl Editor {
 
     /// Move cursor to the current match in interactive replace
     pub(super) fn move_to_current_match(&mut self, ir_state: &InteractiveReplaceState) {
-        self.move_cursor_to_match(ir_state.current_match_pos);
+        self.move_cursor_to_match(ir_state.current_match_pos, ir_state.current_match_len);
 
         // Update the prompt message (show [Wrapped] if we've wrapped around)
         let msg = if ir_state.has_wrapped {
@@ -165,6 +165,7 @@ impl Editor {
             .write()
             .unwrap()
             .reload_from_config(&self.config);
+        self.sync_vi_mode_with_keymap();
 
         // Update LSP configs
         let __active_id = self.active_window;
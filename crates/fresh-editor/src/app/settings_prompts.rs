@@ -602,7 +602,7 @@ impl Editor {
     /// Start the keybinding map selection prompt with available maps
     pub(super) fn start_select_keybinding_map_prompt(&mut self) {
         // Built-in keybinding maps
-        let builtin_maps = vec!["default", "emacs", "vscode", "macos"];
+        let builtin_maps = vec!["default", "emacs", "vscode", "macos", "vim"];
 
         // Collect user-defined keybinding maps from config
         let user_maps: Vec<&str> = self
@@ -671,7 +671,7 @@ impl Editor {
         }
 
         // Check if the map exists (either built-in or user-defined)
-        let is_builtin = matches!(map_name, "default" | "emacs" | "vscode" | "macos");
+        let is_builtin = matches!(map_name, "default" | "emacs" | "vscode" | "macos" | "vim");
         let is_user_defined = self.config.keybinding_maps.contains_key(map_name);
 
         if is_builtin || is_user_defined {
@@ -684,6 +684,7 @@ impl Editor {
                 .write()
                 .unwrap()
                 .reload_from_config(&self.config);
+            self.sync_vi_mode_with_keymap();
 
             // Persist to config file
             self.save_keybinding_map_to_config();
@@ -776,7 +777,7 @@ impl Editor {
             if self.session_mode {
                 // In session mode, queue the escape sequence to be sent to the client
                 self.queue_escape_sequences(style.to_escape_sequence());
-            } else {
+            } else if CursorStyle::is_supported_by_current_terminal() {
                 // In normal mode, write directly to stdout
                 use std::io::stdout;
                 // Best-effort cursor style change to stdout.
@@ -26,6 +26,29 @@ impl Editor {
         self.split_pane_impl(crate::model::event::SplitDirection::Vertical);
     }
 
+    /// Focus the split a go-to-definition in a split lands in, leaving the
+    /// current split's view untouched: another editor split when one
+    /// exists, otherwise a new vertical split of the current buffer.
+    pub(super) fn focus_split_for_definition(&mut self) {
+        let (mgr, view_states) = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .expect("active window must have a populated split layout");
+        let active_split = mgr.active_split();
+        let other = mgr
+            .root()
+            .leaf_split_ids()
+            .into_iter()
+            .filter(|&leaf| leaf != active_split && mgr.leaf_role(leaf).is_none())
+            .find_map(|leaf| view_states.get(&leaf).map(|vs| (leaf, vs.active_buffer)));
+
+        match other {
+            Some((leaf, buffer_id)) => self.focus_split(leaf, buffer_id),
+            None => self.split_pane_vertical(),
+        }
+    }
+
     /// Common split creation logic
     fn split_pane_impl(&mut self, direction: crate::model::event::SplitDirection) {
         // Splitting the layout is a commitment gesture for any preview tab:
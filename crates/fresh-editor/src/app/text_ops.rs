@@ -1,16 +1,43 @@
 //! Text-manipulation orchestrators on `Editor`.
 //!
-//! Smart-home, comment toggling, bracket matching — operations that read
-//! cursor + buffer state, compute a target position or edit, and apply
-//! events to the active buffer. Pure decision logic for smart-home lives
-//! in `super::smart_home`; these methods are the cross-cutting drivers.
-
+//! Smart-home, comment toggling, bracket matching, text objects, surround —
+//! operations that read cursor + buffer state, compute a target position
+//! or edit, and apply events to the active buffer. Pure decision logic for
+//! smart-home lives in `super::smart_home` and for text objects in
+//! `crate::input::text_object`; these methods are the cross-cutting drivers.
+//! Text objects a plugin registers are resolved by calling into the plugin,
+//! so they complete asynchronously when its `TextObjectResolved` arrives.
+
+use crossterm::event::{KeyCode, KeyModifiers};
 use rust_i18n::t;
 
-use crate::model::event::{Event, LeafId};
+use crate::input::keybindings::{normalize_key, Action, KeyContext};
+use crate::input::operator::{MotionRange, Operator};
+use crate::input::text_object::{surrounding_delimiters, text_object, TextObject};
+use crate::model::event::{BufferId, CursorId, Event, LeafId};
+use crate::view::bracket_highlight_overlay::bracket_skip_ranges;
+use std::ops::Range;
 
+use super::vi_actions::{VI_OPERATOR_MODE, VI_VISUAL_MODE};
 use super::Editor;
 
+/// A text object contributed by a plugin via `registerTextObject`.
+#[derive(Debug, Clone)]
+pub(crate) struct PluginTextObject {
+    pub plugin_name: String,
+    pub handler_name: String,
+}
+
+/// A plugin text object whose range hasn't come back yet, and what to do
+/// with it: apply `operator`, or select it when there is none.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PendingTextObject {
+    pub request_id: u64,
+    pub buffer_id: BufferId,
+    pub object: char,
+    pub operator: Option<Operator>,
+}
+
 impl Editor {
     /// Smart home: toggle between line start and first non-whitespace character.
     ///
@@ -527,4 +554,310 @@ impl Editor {
             self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
         }
     }
+
+    /// Byte range of the text object named by `object` (`w`, `"`, `(`, ...)
+    /// around the primary cursor.
+    pub(super) fn text_object_at_cursor(
+        &mut self,
+        object: char,
+        around: bool,
+    ) -> Option<Range<usize>> {
+        let kind = TextObject::from_char(object)?;
+        let cursor = self.active_cursors().primary().position;
+        let skip_ranges = self.text_object_skip_ranges(kind, cursor);
+        text_object(
+            kind,
+            around,
+            cursor,
+            &self.active_state().buffer,
+            &skip_ranges,
+        )
+    }
+
+    /// For bracket objects, the comment and string spans near `cursor`, so
+    /// brackets inside them don't count as structure. Other kinds don't
+    /// need them, so skip the highlighting pass.
+    fn text_object_skip_ranges(&mut self, kind: TextObject, cursor: usize) -> Vec<Range<usize>> {
+        if !matches!(kind, TextObject::Bracket { .. }) {
+            return Vec::new();
+        }
+        let context_bytes = self.config.editor.highlight_context_bytes;
+        let theme = self.theme.clone();
+        let state = self.active_state_mut();
+        let spans = state.highlighter.highlight_viewport(
+            &state.buffer,
+            cursor.saturating_sub(context_bytes),
+            cursor.saturating_add(context_bytes).min(state.buffer.len()),
+            &theme.read().unwrap(),
+            context_bytes,
+        );
+        bracket_skip_ranges(&spans)
+    }
+
+    /// Select the text object named by `object` around the primary cursor.
+    pub(super) fn select_text_object(&mut self, object: char, around: bool) {
+        if self.request_plugin_text_object(object, around, None) {
+            return;
+        }
+        let Some(range) = self.text_object_at_cursor(object, around) else {
+            self.set_status_message(t!("status.no_text_object", object = object).to_string());
+            return;
+        };
+        self.select_text_object_range(range);
+    }
+
+    fn select_text_object_range(&mut self, range: Range<usize>) {
+        let primary_id = self.active_cursors().primary_id();
+        let primary = self.active_cursors().primary();
+        let event = Event::MoveCursor {
+            cursor_id: primary_id,
+            old_position: primary.position,
+            new_position: range.end,
+            old_anchor: primary.anchor,
+            new_anchor: Some(range.start),
+            old_sticky_column: primary.sticky_column,
+            new_sticky_column: None,
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+    }
+
+    /// Record a plugin text object and bind `i{key}` / `a{key}` to it in the
+    /// vim keymap's operator-pending and visual modes. Built-in objects
+    /// can't be replaced.
+    pub(super) fn register_plugin_text_object(
+        &mut self,
+        plugin_name: String,
+        key: char,
+        handler_name: String,
+    ) {
+        if TextObject::from_char(key).is_some() {
+            tracing::warn!(
+                "Plugin '{}' tried to register built-in text object '{}'",
+                plugin_name,
+                key
+            );
+            return;
+        }
+        let key_event = normalize_key(KeyCode::Char(key), KeyModifiers::NONE);
+        {
+            let mut keybindings = self.keybindings.write().unwrap();
+            for mode in [VI_OPERATOR_MODE, VI_VISUAL_MODE] {
+                for (prefix, action) in [
+                    ('i', Action::SelectInside(key)),
+                    ('a', Action::SelectAround(key)),
+                ] {
+                    keybindings.load_plugin_chord_default(
+                        KeyContext::Mode(mode.to_string()),
+                        vec![(KeyCode::Char(prefix), KeyModifiers::NONE), key_event],
+                        action,
+                    );
+                }
+            }
+        }
+        self.plugin_text_objects.insert(
+            key,
+            PluginTextObject {
+                plugin_name,
+                handler_name,
+            },
+        );
+    }
+
+    /// Ask the plugin that registered `object` for its range around the
+    /// primary cursor. `operator` is applied to the range once it arrives
+    /// (see [`Self::handle_text_object_resolved`]); without one the range is
+    /// selected. Returns `false` if `object` is built in or no plugin
+    /// provides it.
+    pub(super) fn request_plugin_text_object(
+        &mut self,
+        object: char,
+        around: bool,
+        operator: Option<Operator>,
+    ) -> bool {
+        if TextObject::from_char(object).is_some() {
+            return false;
+        }
+        let Some(provider) = self.plugin_text_objects.get(&object).cloned() else {
+            return false;
+        };
+        let request_id = self.next_text_object_request;
+        self.next_text_object_request += 1;
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        self.pending_text_object = Some(PendingTextObject {
+            request_id,
+            buffer_id,
+            object,
+            operator,
+        });
+        self.plugin_manager.read().unwrap().resolve_text_object(
+            &provider.plugin_name,
+            &provider.handler_name,
+            request_id,
+            serde_json::json!({
+                "bufferId": buffer_id.0,
+                "position": position,
+                "around": around,
+            }),
+        );
+        true
+    }
+
+    /// A plugin answered text-object request `request_id`. Stale answers
+    /// (superseded, or for a buffer that is no longer active) are dropped.
+    pub(super) fn handle_text_object_resolved(
+        &mut self,
+        request_id: u64,
+        range: Option<Range<usize>>,
+    ) {
+        let Some(pending) = self
+            .pending_text_object
+            .take_if(|pending| pending.request_id == request_id)
+        else {
+            return;
+        };
+        if pending.buffer_id != self.active_buffer() {
+            return;
+        }
+        let buffer_len = self.active_state().buffer.len();
+        let Some(range) = range.filter(|range| range.end <= buffer_len) else {
+            self.set_status_message(
+                t!("status.no_text_object", object = pending.object).to_string(),
+            );
+            return;
+        };
+        match pending.operator {
+            Some(operator) => {
+                let range = MotionRange {
+                    range,
+                    linewise: false,
+                };
+                if let Err(e) = self.vi_apply_operator(operator, range) {
+                    tracing::warn!("Failed to apply plugin text object: {}", e);
+                }
+            }
+            None => self.select_text_object_range(range),
+        }
+    }
+
+    /// Wrap the selection (or the word at the cursor) in the pair named by
+    /// `pair`, leaving the cursor on the opening delimiter.
+    pub(super) fn surround_add(&mut self, pair: char) {
+        let Some((open, close)) = TextObject::from_char(pair).and_then(TextObject::delimiters)
+        else {
+            self.set_status_message(t!("status.surround_no_pair", pair = pair).to_string());
+            return;
+        };
+        let cursor = *self.active_cursors().primary();
+        let cursor_id = self.active_cursors().primary_id();
+        let range = match cursor.selection_range() {
+            Some(range) => range,
+            None => match self.text_object_at_cursor('w', false) {
+                Some(range) => range,
+                None => {
+                    self.set_status_message(t!("status.no_text_object", object = 'w').to_string());
+                    return;
+                }
+            },
+        };
+        let events = vec![
+            Event::Insert {
+                position: range.end,
+                text: close.to_string(),
+                cursor_id,
+            },
+            Event::Insert {
+                position: range.start,
+                text: open.to_string(),
+                cursor_id,
+            },
+            Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: range.start,
+                old_anchor: cursor.anchor,
+                new_anchor: None,
+                old_sticky_column: cursor.sticky_column,
+                new_sticky_column: None,
+            },
+        ];
+        self.apply_surround_events(events, "Surround");
+    }
+
+    /// Remove the `pair` delimiters enclosing the primary cursor.
+    pub(super) fn surround_delete(&mut self, pair: char) {
+        let Some(kind) = TextObject::from_char(pair).filter(|k| k.delimiters().is_some()) else {
+            self.set_status_message(t!("status.surround_no_pair", pair = pair).to_string());
+            return;
+        };
+        let Some((open_pos, close_pos)) = self.surrounding_delimiters_at_cursor(kind) else {
+            self.set_status_message(t!("status.no_text_object", object = pair).to_string());
+            return;
+        };
+        let cursor_id = self.active_cursors().primary_id();
+        let events = [close_pos, open_pos]
+            .into_iter()
+            .map(|pos| self.delete_delimiter_event(pos, cursor_id))
+            .collect();
+        self.apply_surround_events(events, "Delete surrounding pair");
+    }
+
+    /// Replace the innermost quotes or brackets enclosing the primary cursor
+    /// with the pair named by `pair`.
+    pub(super) fn surround_change(&mut self, pair: char) {
+        let Some((open, close)) = TextObject::from_char(pair).and_then(TextObject::delimiters)
+        else {
+            self.set_status_message(t!("status.surround_no_pair", pair = pair).to_string());
+            return;
+        };
+        let cursor = self.active_cursors().primary().position;
+        let innermost = ['(', '[', '{', '<', '"', '\'', '`']
+            .into_iter()
+            .filter_map(TextObject::from_char)
+            .filter_map(|kind| self.surrounding_delimiters_at_cursor(kind))
+            // Quote pairs may lie after the cursor; only enclosing pairs count.
+            .filter(|&(open_pos, close_pos)| open_pos <= cursor && cursor <= close_pos)
+            .max_by_key(|&(open_pos, _)| open_pos);
+        let Some((open_pos, close_pos)) = innermost else {
+            self.set_status_message(t!("status.surround_none").to_string());
+            return;
+        };
+        let cursor_id = self.active_cursors().primary_id();
+        let mut events = Vec::new();
+        for (pos, delimiter) in [(close_pos, close), (open_pos, open)] {
+            events.push(self.delete_delimiter_event(pos, cursor_id));
+            events.push(Event::Insert {
+                position: pos,
+                text: delimiter.to_string(),
+                cursor_id,
+            });
+        }
+        self.apply_surround_events(events, "Change surrounding pair");
+    }
+
+    fn surrounding_delimiters_at_cursor(&mut self, kind: TextObject) -> Option<(usize, usize)> {
+        let cursor = self.active_cursors().primary().position;
+        let skip_ranges = self.text_object_skip_ranges(kind, cursor);
+        surrounding_delimiters(kind, cursor, &self.active_state().buffer, &skip_ranges)
+    }
+
+    /// Delete event for the (single-character) delimiter at `pos`.
+    fn delete_delimiter_event(&self, pos: usize, cursor_id: CursorId) -> Event {
+        let buffer = &self.active_state().buffer;
+        let len = buffer.next_grapheme_boundary(pos) - pos;
+        let deleted_text =
+            String::from_utf8_lossy(&buffer.slice_bytes(pos..pos + len)).into_owned();
+        Event::Delete {
+            range: pos..pos + len,
+            deleted_text,
+            cursor_id,
+        }
+    }
+
+    fn apply_surround_events(&mut self, events: Vec<Event>, description: &str) {
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 //! Toggle actions and configuration operations for the Editor.
 //!
 //! This module contains toggle methods and configuration operations:
-//! - Toggle line numbers, debug highlights, menu bar
+//! - Toggle line numbers, debug highlights, menu bar, zen mode
 //! - Toggle mouse capture, mouse hover, inlay hints
 //! - Reset buffer settings
 //! - Config dump, save, and reload
@@ -11,7 +11,9 @@ use rust_i18n::t;
 
 use crate::config::{Config, FileExplorerSide};
 use crate::config_io::{ConfigLayer, ConfigResolver};
+use crate::input::keybindings::KeyContext;
 
+use super::window::ZenModeChrome;
 use super::Editor;
 
 impl Editor {
@@ -308,6 +310,42 @@ impl Editor {
         self.set_status_message(status.to_string());
     }
 
+    /// Toggle zen mode for the active window: hide the menu bar, file
+    /// explorer, tab bar and status bar to give the buffer the whole
+    /// screen, then put each back as it was when toggled off.
+    ///
+    /// Unlike the individual toggles this never touches the config — it is
+    /// a temporary layout, not a preference.
+    pub fn toggle_zen_mode(&mut self) {
+        if let Some(saved) = self.active_window_mut().zen_mode_saved.take() {
+            let window = self.active_window_mut();
+            window.menu_bar_visible = saved.menu_bar;
+            window.tab_bar_visible = saved.tab_bar;
+            window.status_bar_visible = saved.status_bar;
+            window.file_explorer_visible = saved.file_explorer;
+            self.set_status_message(t!("toggle.zen_mode_off").to_string());
+        } else {
+            let window = self.active_window_mut();
+            window.zen_mode_saved = Some(ZenModeChrome {
+                menu_bar: window.menu_bar_visible,
+                tab_bar: window.tab_bar_visible,
+                status_bar: window.status_bar_visible,
+                file_explorer: window.file_explorer_visible,
+            });
+            window.menu_bar_visible = false;
+            window.menu_bar_auto_shown = false;
+            window.tab_bar_visible = false;
+            window.status_bar_visible = false;
+            window.file_explorer_visible = false;
+            if window.key_context == KeyContext::FileExplorer {
+                window.key_context = KeyContext::Normal;
+            }
+            self.menu_state.close_menu();
+            self.set_status_message(t!("toggle.zen_mode_on").to_string());
+        }
+        self.relayout();
+    }
+
     // `toggle_tab_bar` / `toggle_status_bar` / `toggle_prompt_line` and
     // their `*_visible` getters live on `impl Window` — call them via
     // `self.active_window_mut().toggle_tab_bar()` etc. (or read
@@ -632,6 +670,7 @@ impl Editor {
             .write()
             .unwrap()
             .reload_from_config(&self.config);
+        self.sync_vi_mode_with_keymap();
 
         // Update clipboard configuration
         self.clipboard.apply_config(&self.config.clipboard);
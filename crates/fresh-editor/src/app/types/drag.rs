@@ -1,4 +1,5 @@
 use crate::model::event::{BufferId, LeafId};
+use std::path::PathBuf;
 
 /// Drop zone for tab drag-and-drop
 /// Indicates where a dragged tab will be placed when released
@@ -67,3 +68,34 @@ impl TabDragState {
         dx > 3 || dy > 3 // Threshold of 3 pixels before drag activates
     }
 }
+
+/// State for a file explorer entry being dragged onto a directory
+#[derive(Debug, Clone)]
+pub struct ExplorerDragState {
+    /// The file or directory being dragged
+    pub source: PathBuf,
+    /// Starting mouse position when drag began
+    pub start_position: (u16, u16),
+    /// Current mouse position
+    pub current_position: (u16, u16),
+    /// Directory under the mouse that the entry would be moved into (if any)
+    pub drop_target: Option<PathBuf>,
+}
+
+impl ExplorerDragState {
+    /// Create a new explorer drag state
+    pub fn new(source: PathBuf, start_position: (u16, u16)) -> Self {
+        Self {
+            source,
+            start_position,
+            current_position: start_position,
+            drop_target: None,
+        }
+    }
+
+    /// Check if the drag has left the pressed row. Explorer rows are one
+    /// cell tall, so unlike tab drags any vertical movement counts.
+    pub fn is_dragging(&self) -> bool {
+        self.current_position.1 != self.start_position.1
+    }
+}
@@ -29,7 +29,7 @@ pub use context_menu::{
 };
 
 // drag re-exports
-pub use drag::{TabDragState, TabDropZone};
+pub use drag::{ExplorerDragState, TabDragState, TabDropZone};
 
 // hover re-exports
 pub use hover::HoverTarget;
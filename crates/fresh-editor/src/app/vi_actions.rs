@@ -0,0 +1,317 @@
+//! Built-in modal ("vim") keymap support.
+//!
+//! The `vim` keymap lives in `keymaps/vim.json`; its normal-,
+//! operator-pending and visual-mode bindings are scoped to the
+//! `vim-normal` / `vim-operator` / `vim-visual` editor modes. Insert mode
+//! is simply "no editor mode", so the default keymap's Normal-context
+//! bindings apply while typing. This module owns the mode transitions,
+//! the operator-pending state behind `d{motion}` and friends, and the
+//! handful of compound actions (open line, count prefix) that don't map
+//! onto a single core action.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::input::buffer_mode::BufferMode;
+use crate::input::keybindings::Action;
+use crate::input::operator::{
+    operator_range, resolve_motion, Motion, MotionRange, Operator, PendingOperator,
+};
+use crate::model::event::Event;
+
+/// Editor mode carrying the vim keymap's normal-mode bindings.
+pub(crate) const VI_NORMAL_MODE: &str = "vim-normal";
+/// Editor mode active between an operator key and its motion or text
+/// object, where `i` / `a` start a text object instead of inserting.
+pub(crate) const VI_OPERATOR_MODE: &str = "vim-operator";
+/// Editor mode carrying the vim keymap's visual-mode bindings.
+pub(crate) const VI_VISUAL_MODE: &str = "vim-visual";
+
+/// Upper bound for a typed count prefix, so a stray `99999999dd`
+/// can't wedge the editor in a multi-million iteration loop.
+const MAX_VI_COUNT: usize = 9999;
+
+impl Editor {
+    /// Register the built-in vim modes. Both are read-only (unbound
+    /// keys are swallowed instead of inserting text) and inherit the
+    /// Normal-context bindings so arrows, Ctrl+S, the palette etc.
+    /// keep working. Idempotent, like `ensure_help_panel_mode_registered`.
+    fn ensure_vi_modes_registered(&mut self) {
+        for mode in [VI_NORMAL_MODE, VI_OPERATOR_MODE, VI_VISUAL_MODE] {
+            self.keybindings
+                .write()
+                .unwrap()
+                .set_mode_inherits_normal_bindings(mode, true);
+            self.mode_registry.register(
+                BufferMode::new(mode)
+                    .with_read_only(true)
+                    .with_inherit_normal_bindings(true),
+            );
+        }
+    }
+
+    /// Put the editor into the mode matching the active keybinding map:
+    /// normal mode when the `vim` map is active, and out of any vim mode
+    /// otherwise. Called at startup and whenever the map is switched.
+    pub(crate) fn sync_vi_mode_with_keymap(&mut self) {
+        let active_keymap: &str = &self.config.active_keybinding_map;
+        let vim_active = active_keymap == "vim";
+        let in_vi_mode = matches!(
+            self.active_window().editor_mode.as_deref(),
+            Some(VI_NORMAL_MODE | VI_OPERATOR_MODE | VI_VISUAL_MODE)
+        );
+        if vim_active {
+            self.ensure_vi_modes_registered();
+            if !in_vi_mode {
+                self.set_vi_mode(Some(VI_NORMAL_MODE));
+            }
+        } else if in_vi_mode {
+            self.set_vi_mode(None);
+        }
+    }
+
+    fn set_vi_mode(&mut self, mode: Option<&str>) {
+        let window = self.active_window_mut();
+        window.editor_mode = mode.map(str::to_string);
+        window.vi_count = None;
+        window.vi_pending_operator = None;
+        window.chord_state.clear();
+    }
+
+    /// Take the pending count prefix for `action`, if any. Count digits
+    /// and operators (which carry the count over to their motion) never
+    /// consume it.
+    pub(crate) fn take_vi_count(&mut self, action: &Action) -> Option<usize> {
+        if matches!(action, Action::ViCountDigit(_) | Action::ViOperator(_)) {
+            return None;
+        }
+        self.active_window_mut().vi_count.take()
+    }
+
+    /// `0`-`9` in normal mode: accumulate a count prefix. A leading `0`
+    /// is the "start of line" motion rather than part of a count.
+    pub(crate) fn vi_count_digit(&mut self, digit: char) -> anyhow::Result<()> {
+        let Some(value) = digit.to_digit(10) else {
+            return Ok(());
+        };
+        let pending = self.active_window().vi_count;
+        if pending.is_none() && value == 0 {
+            return self.apply_action_as_events(Action::MoveLineStart);
+        }
+        let count = pending.unwrap_or(0) * 10 + value as usize;
+        self.active_window_mut().vi_count = Some(count.min(MAX_VI_COUNT));
+        Ok(())
+    }
+
+    /// Esc: back to normal mode from insert or visual mode. Leaving insert
+    /// mode steps the cursor back onto the last inserted character.
+    pub(crate) fn vi_normal_mode(&mut self) -> anyhow::Result<()> {
+        let from_insert = self.active_window().editor_mode.is_none();
+        self.ensure_vi_modes_registered();
+        self.set_vi_mode(Some(VI_NORMAL_MODE));
+        self.apply_action_as_events(Action::ClearMark)?;
+        if from_insert {
+            self.apply_action_as_events(Action::MoveLeftInLine)?;
+        }
+        self.set_status_message(t!("status.vi_mode_normal").to_string());
+        Ok(())
+    }
+
+    /// Enter insert mode, first running `setup` (e.g. a motion or a delete).
+    pub(crate) fn vi_insert_mode(&mut self, setup: &[Action]) -> anyhow::Result<()> {
+        for action in setup {
+            self.dispatch_action(action.clone())?;
+        }
+        self.set_vi_mode(None);
+        self.set_status_message(t!("status.vi_mode_insert").to_string());
+        Ok(())
+    }
+
+    /// `v`: start a selection at the cursor and enter visual mode.
+    pub(crate) fn vi_visual_mode(&mut self) -> anyhow::Result<()> {
+        self.ensure_vi_modes_registered();
+        self.apply_action_as_events(Action::SetMark)?;
+        self.set_vi_mode(Some(VI_VISUAL_MODE));
+        self.set_status_message(t!("status.vi_mode_visual").to_string());
+        Ok(())
+    }
+
+    /// Apply a visual-mode operator (`d`, `y` or `c`) to the selection.
+    pub(crate) fn vi_visual_operator(&mut self, op: char) -> anyhow::Result<()> {
+        let Some(operator) = Operator::from_char(op) else {
+            return Ok(());
+        };
+        match self.active_cursors().primary().selection_range() {
+            Some(range) => self.vi_apply_operator(
+                operator,
+                MotionRange {
+                    range,
+                    linewise: false,
+                },
+            ),
+            None => self.vi_visual_done(),
+        }
+    }
+
+    /// `d`, `y`, `c` in normal mode: wait for the motion or text object
+    /// the operator acts on. A count typed before the operator multiplies
+    /// the motion's.
+    pub(crate) fn vi_operator(&mut self, op: char) {
+        let Some(operator) = Operator::from_char(op) else {
+            return;
+        };
+        let count = self.active_window_mut().vi_count.take().unwrap_or(1);
+        if self.active_window().editor_mode.as_deref() == Some(VI_NORMAL_MODE) {
+            self.set_vi_mode(Some(VI_OPERATOR_MODE));
+        }
+        self.active_window_mut().vi_pending_operator = Some(PendingOperator { operator, count });
+    }
+
+    /// Feed `action` to a pending operator. Returns `true` when the action
+    /// was consumed as the operator's motion, text object or surround pair
+    /// (`ds"`, `cs[`). Count digits accumulate as usual (`d2w`), the doubled
+    /// operator key acts on whole lines (`dd`), and any other action cancels
+    /// the operator and runs as normal.
+    pub(crate) fn vi_complete_operator(&mut self, action: &Action) -> anyhow::Result<bool> {
+        let Some(pending) = self.active_window().vi_pending_operator else {
+            return Ok(false);
+        };
+        let motion = match action {
+            Action::ViCountDigit(c) if *c != '0' || self.active_window().vi_count.is_some() => {
+                return Ok(false);
+            }
+            Action::ViCountDigit(_) => Some(Motion::LineStart),
+            Action::ViOperator(c) if Operator::from_char(*c) == Some(pending.operator) => {
+                Some(Motion::CurrentLine)
+            }
+            _ => Motion::from_action(action),
+        };
+        let text_object = match action {
+            Action::SelectInside(c) => Some((*c, false)),
+            Action::SelectAround(c) => Some((*c, true)),
+            _ => None,
+        };
+        let count = pending.count * self.active_window_mut().vi_count.take().unwrap_or(1);
+        self.active_window_mut().vi_pending_operator = None;
+        if self.active_window().editor_mode.as_deref() == Some(VI_OPERATOR_MODE) {
+            self.set_vi_mode(Some(VI_NORMAL_MODE));
+        }
+
+        if let Some((object, around)) = text_object {
+            if self.request_plugin_text_object(object, around, Some(pending.operator)) {
+                return Ok(true);
+            }
+            if let Some(range) = self.text_object_at_cursor(object, around) {
+                let range = MotionRange {
+                    range,
+                    linewise: false,
+                };
+                self.vi_apply_operator(pending.operator, range)?;
+            }
+            return Ok(true);
+        }
+        if let Action::ViSurround(pair) = *action {
+            // `ds(` / `cs[`; there is no `ys`, so `y` just drops the pair.
+            match pending.operator {
+                Operator::Delete => self.surround_delete(pair),
+                Operator::Change => self.surround_change(pair),
+                Operator::Yank => {}
+            }
+            return Ok(true);
+        }
+        let Some(motion) = motion else {
+            return Ok(false);
+        };
+
+        let motion = motion.for_operator(pending.operator);
+        let cursor = self.active_cursors().primary().position;
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let resolved = resolve_motion(
+            &mut state.buffer,
+            cursor,
+            motion,
+            count,
+            estimated_line_length,
+        )
+        .map(|range| operator_range(&state.buffer, pending.operator, range));
+        if let Some(range) = resolved {
+            self.vi_apply_operator(pending.operator, range)?;
+        }
+        Ok(true)
+    }
+
+    /// Select `range` on the primary cursor and apply `operator` to it.
+    pub(super) fn vi_apply_operator(
+        &mut self,
+        operator: Operator,
+        range: MotionRange,
+    ) -> anyhow::Result<()> {
+        let origin = self.active_cursors().primary().position;
+        if range.range.is_empty() {
+            // Nothing to cut or copy (`ci(` on `()`), but the operator
+            // still lands the cursor there.
+            self.vi_set_primary_cursor(range.range.start, None);
+            return match operator {
+                Operator::Change => self.vi_insert_mode(&[]),
+                Operator::Delete | Operator::Yank => Ok(()),
+            };
+        }
+        self.vi_set_primary_cursor(range.range.end, Some(range.range.start));
+        match operator {
+            Operator::Delete => {
+                self.dispatch_action(Action::Cut)?;
+                self.vi_visual_done()
+            }
+            Operator::Yank => {
+                self.dispatch_action(Action::Copy)?;
+                // Yanking leaves the cursor at the start of the text, except
+                // for linewise yanks, which don't move it at all (`yj`).
+                let target = if range.linewise {
+                    origin
+                } else {
+                    range.range.start.min(origin)
+                };
+                self.vi_set_primary_cursor(target, None);
+                self.vi_visual_done()
+            }
+            Operator::Change => {
+                self.dispatch_action(Action::Cut)?;
+                self.apply_action_as_events(Action::ClearMark)?;
+                self.vi_insert_mode(&[])
+            }
+        }
+    }
+
+    fn vi_set_primary_cursor(&mut self, position: usize, anchor: Option<usize>) {
+        let cursors = self.active_cursors();
+        let primary = cursors.primary();
+        let event = Event::MoveCursor {
+            cursor_id: cursors.primary_id(),
+            old_position: primary.position,
+            new_position: position,
+            old_anchor: primary.anchor,
+            new_anchor: anchor,
+            old_sticky_column: primary.sticky_column,
+            new_sticky_column: None,
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+    }
+
+    /// Back to normal mode if in visual mode, for actions that consume the
+    /// selection but aren't vim-specific.
+    pub(crate) fn vi_leave_visual_mode(&mut self) -> anyhow::Result<()> {
+        if self.active_window().editor_mode.as_deref() == Some(VI_VISUAL_MODE) {
+            self.vi_visual_done()?;
+        }
+        Ok(())
+    }
+
+    fn vi_visual_done(&mut self) -> anyhow::Result<()> {
+        self.apply_action_as_events(Action::ClearMark)?;
+        self.set_vi_mode(Some(VI_NORMAL_MODE));
+        self.set_status_message(t!("status.vi_mode_normal").to_string());
+        Ok(())
+    }
+}
@@ -108,6 +108,8 @@ impl Editor {
             .event_logs
             .insert(buffer_id, crate::model::event::EventLog::new());
 
+        self.stdin_buffer = Some(buffer_id);
+
         // Create metadata for this buffer (no file path)
         let metadata =
             super::types::BufferMetadata::new_unnamed(t!("stdin.display_name").to_string());
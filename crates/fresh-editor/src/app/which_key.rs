@@ -0,0 +1,104 @@
+//! Which-key popup.
+//!
+//! After a chord prefix (e.g. `Ctrl+X` in the emacs keymap), pausing for
+//! `WHICH_KEY_DELAY` opens a small popup in the bottom-right corner listing
+//! every key that can follow and the action it runs. The popup is
+//! transient: the next key press closes it and continues the chord as
+//! usual, so it never changes what a key sequence does.
+
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use rust_i18n::t;
+
+use crate::input::keybindings::{format_keybinding, KeyContext, KeybindingResolver};
+use crate::view::popup::{Popup, PopupPosition};
+
+use super::Editor;
+
+/// How long a chord prefix must sit unanswered before the popup appears.
+const WHICH_KEY_DELAY: Duration = Duration::from_millis(500);
+
+impl Editor {
+    /// Add a chord prefix key resolved in `context` and restart the
+    /// which-key delay.
+    pub(super) fn extend_chord(
+        &mut self,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        context: KeyContext,
+    ) {
+        let now = self.time_source.now();
+        let window = self.active_window_mut();
+        window.chord_state.push((code, modifiers));
+        window.which_key_pending = Some((now, context));
+    }
+
+    /// Clear the pending chord once it completes or is abandoned.
+    pub(super) fn end_chord(&mut self) {
+        let window = self.active_window_mut();
+        window.chord_state.clear();
+        window.which_key_pending = None;
+    }
+
+    /// Show the which-key popup once the chord prefix has waited long
+    /// enough. Returns true when the popup was opened.
+    pub fn check_which_key_timer(&mut self) -> bool {
+        let Some((since, context)) = self.active_window().which_key_pending.clone() else {
+            return false;
+        };
+        if self.time_source.elapsed_since(since) < WHICH_KEY_DELAY {
+            return false;
+        }
+        self.active_window_mut().which_key_pending = None;
+        let prefix = self.active_window().chord_state.clone();
+        if prefix.is_empty() || self.global_popups.is_visible() {
+            return false;
+        }
+
+        let continuations = self
+            .keybindings
+            .read()
+            .unwrap()
+            .chord_continuations(&prefix, context);
+        if continuations.is_empty() {
+            return false;
+        }
+
+        let rows: Vec<(String, String)> = continuations
+            .iter()
+            .map(|((code, modifiers), action)| {
+                let label = match action {
+                    Some(action) => KeybindingResolver::format_action(action),
+                    None => t!("which_key.more_keys").to_string(),
+                };
+                (format_keybinding(code, modifiers), label)
+            })
+            .collect();
+        let key_width = rows
+            .iter()
+            .map(|(key, _)| key.chars().count())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<String> = rows
+            .into_iter()
+            .map(|(key, label)| format!("{key:<key_width$}  {label}"))
+            .collect();
+        let content_width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let title = prefix
+            .iter()
+            .map(|(code, modifiers)| format_keybinding(code, modifiers))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let height = lines.len() + 2;
+        let popup = Popup::text(lines, &self.theme.read().unwrap())
+            .with_title(title)
+            .with_transient(true)
+            .with_position(PopupPosition::BottomRight)
+            .with_width((content_width + 4).clamp(24, 60) as u16)
+            .with_max_height(height.min(20) as u16);
+        self.global_popups.show(popup);
+        true
+    }
+}
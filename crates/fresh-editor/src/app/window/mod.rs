@@ -79,6 +79,48 @@ pub struct TerminalLinkHover {
     pub cols: std::ops::Range<usize>,
 }
 
+/// A link underlined under a Ctrl+hover in an editor buffer. The underline
+/// itself is an overlay; this records where it is so it can be cleared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferLinkHover {
+    /// The buffer the link is in.
+    pub buffer_id: BufferId,
+    /// Byte range the link spans.
+    pub range: std::ops::Range<usize>,
+}
+
+/// The panels zen mode hides, as they were before it was turned on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZenModeChrome {
+    pub menu_bar: bool,
+    pub tab_bar: bool,
+    pub status_bar: bool,
+    pub file_explorer: bool,
+}
+
+/// The Markdown buffer an open preview panel follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkdownPreview {
+    pub source: BufferId,
+    /// Source version the panel currently shows.
+    pub rendered_version: u64,
+    /// The latest source version seen and when it was first seen; the
+    /// panel re-renders once that version has stood for the debounce.
+    pub seen_version: u64,
+    pub seen_at: std::time::Instant,
+}
+
+/// A held navigation key: a run of one action arriving at key-repeat rate,
+/// which `navigation_acceleration` speeds up the longer it lasts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRepeat {
+    pub action: crate::input::keybindings::Action,
+    /// When the run's first key arrived.
+    pub started: std::time::Instant,
+    /// When its latest key arrived.
+    pub last: std::time::Instant,
+}
+
 /// Per-terminal-buffer editor state, keyed by `BufferId` in
 /// [`Window::terminal_buffers`]. PTY I/O lives in the `TerminalManager`; the
 /// byte-stream backing files stay keyed by `TerminalId`.
@@ -272,6 +314,10 @@ pub struct Window {
     /// status, also per-window).
     pub plugin_status_message: Option<String>,
 
+    /// Recent status messages (core and plugin) with the time they were
+    /// shown, oldest first. Bounded; viewed with `ShowMessageLog`.
+    pub status_history: std::collections::VecDeque<(chrono::DateTime<chrono::Local>, String)>,
+
     /// Active prompt (minibuffer) for this window. Each window can
     /// have its own prompt mid-flight; switching windows preserves
     /// each window's prompt state independently.
@@ -309,6 +355,10 @@ pub struct Window {
     /// Pending LSP go-to-definition request id.
     pub pending_goto_definition_request: Option<u64>,
 
+    /// Whether the pending go-to-definition opens its target in another
+    /// split (`GotoDefinitionInSplit`) instead of the current one.
+    pub pending_goto_definition_in_split: bool,
+
     /// Pending LSP find-references request id and the symbol name.
     pub pending_references_request: Option<u64>,
     pub pending_references_symbol: String,
@@ -378,6 +428,10 @@ pub struct Window {
     /// window has its own register set.
     pub(crate) bookmarks: crate::app::bookmarks::BookmarkState,
 
+    /// Where recent edits happened, across this window's buffers, for
+    /// `GotoLastEdit`.
+    pub(crate) edit_locations: crate::app::edit_locations::EditLocations,
+
     /// Composite buffers in this window (separate from regular
     /// buffers). These display multiple source buffers in a single
     /// tab — Live Grep results, References, Diagnostics list,
@@ -391,6 +445,10 @@ pub struct Window {
     pub composite_view_states:
         HashMap<(LeafId, BufferId), crate::view::composite_view::CompositeViewState>,
 
+    /// Hidden on-disk snapshot buffer owned by each "diff against disk"
+    /// composite, keyed by the composite's id. Freed with the composite.
+    pub(crate) disk_snapshots: HashMap<BufferId, BufferId>,
+
     /// Grouped `SplitNode` subtrees for this window, keyed by their
     /// `LeafId` (which is what `TabTarget::Group(leaf_id)`
     /// references). Each entry is a `SplitNode::Grouped` node
@@ -531,6 +589,11 @@ pub struct Window {
     /// pointer leaves a resolvable path. See [`TerminalLinkHover`].
     pub terminal_link_hover: Option<TerminalLinkHover>,
 
+    /// Link currently underlined under a Ctrl+hover in an editor buffer
+    /// (LSP document link, URL, or resolvable path). See
+    /// [`BufferLinkHover`].
+    pub buffer_link_hover: Option<BufferLinkHover>,
+
     /// Track which byte ranges have been seen per buffer (for the
     /// `lines_changed` plugin-hook optimisation). Keyed by `BufferId`,
     /// follows the buffers onto Window.
@@ -604,6 +667,11 @@ pub struct Window {
     /// because the overlays it scopes are per-buffer (per-window).
     pub search_namespace: crate::view::overlay::OverlayNamespace,
 
+    /// Overlay namespace marking the persistent search scope. The scope
+    /// lives as a marker-backed overlay in the buffer, so it follows
+    /// edits and doubles as the scope's highlight.
+    pub search_scope_namespace: crate::view::overlay::OverlayNamespace,
+
     /// Range that should be reused when the next search is confirmed
     /// (e.g. after the user picks a hit in the search overlay).
     pub pending_search_range: Option<std::ops::Range<usize>>,
@@ -644,6 +712,11 @@ pub struct Window {
     /// per-window (Step 0k).
     pub scheduled_diagnostic_pull: Option<(BufferId, std::time::Instant)>,
     pub scheduled_inlay_hints_request: Option<(BufferId, std::time::Instant)>,
+    /// Per-URI deadline before which published diagnostics are held back
+    /// from the overlays. Pushed out on every edit, so the burst of
+    /// publishes a server sends while the user types collapses into one
+    /// overlay update once typing pauses.
+    pub diagnostic_overlay_debounce: std::collections::HashMap<String, std::time::Instant>,
 
     /// LSP languages the user dismissed the "do you want to enable
     /// LSP for this language?" popup for. Per-window because LSP is
@@ -694,6 +767,11 @@ pub struct Window {
     pub lsp_server_statuses:
         HashMap<(String, String), crate::services::async_bridge::LspServerStatus>,
 
+    /// Most recent error reported by each `(language, server_name)` pair
+    /// (spawn failure, broken pipe, …). Kept across restarts so the LSP
+    /// status popup can explain why a server went down.
+    pub lsp_last_errors: HashMap<(String, String), String>,
+
     /// Plugin-contributed menu items merged into the LSP-Servers popup
     /// (the one opened by clicking the LSP indicator). Keyed by
     /// `(language, plugin_id)` so each plugin owns its own slice and
@@ -738,10 +816,13 @@ pub struct Window {
 
     /// Merged view of push + pull diagnostics with every position mapped
     /// forward to the buffer's current version, exposed to plugins and read
-    /// by hover / the diagnostics panel. Derived — never a source of truth;
-    /// `recompute_merged_diagnostics` rebuilds it from the anchored push/pull
-    /// stores. `Arc` wrapper so plugin snapshots can hold a refcount-bumped
-    /// reference; mutation goes through `Arc::make_mut` (CoW).
+    /// by hover / the diagnostics panel. Keyed by file URI and covers every
+    /// file a server has reported on, including files with no open buffer,
+    /// so it doubles as the workspace-wide diagnostics list. Derived — never
+    /// a source of truth; `recompute_merged_diagnostics` rebuilds it from the
+    /// anchored push/pull stores. `Arc` wrapper so plugin snapshots can hold
+    /// a refcount-bumped reference; mutation goes through `Arc::make_mut`
+    /// (CoW).
     pub stored_diagnostics: Arc<HashMap<String, Vec<lsp_types::Diagnostic>>>,
 
     /// Per-URI folding ranges from `textDocument/foldingRange`. Same
@@ -848,6 +929,23 @@ pub struct Window {
     /// Each window tracks its own in-progress chord.
     pub chord_state: Vec<(crossterm::event::KeyCode, crossterm::event::KeyModifiers)>,
 
+    /// When the last chord prefix key was pressed, and the context it was
+    /// resolved in. The which-key popup listing the possible next keys
+    /// appears once `WHICH_KEY_DELAY` has passed without another key.
+    pub(crate) which_key_pending:
+        Option<(std::time::Instant, crate::input::keybindings::KeyContext)>,
+
+    /// Pending count prefix typed in the vim keymap's normal mode
+    /// (e.g. the `3` in `3dd`). Consumed by the next action.
+    pub vi_count: Option<usize>,
+
+    /// Operator (`d`, `y`, `c`) waiting for the motion it will act on.
+    pub vi_pending_operator: Option<crate::input::operator::PendingOperator>,
+
+    /// The navigation key currently being held, if any (see
+    /// `navigation_acceleration`).
+    pub key_repeat: Option<KeyRepeat>,
+
     /// Multi-click detection state (per-window because clicks land
     /// inside a window).
     pub previous_click_time: Option<std::time::Instant>,
@@ -867,11 +965,24 @@ pub struct Window {
     pub status_bar_visible: bool,
     pub prompt_line_visible: bool,
 
+    /// Chrome visibility saved on entering zen mode; `Some` while zen
+    /// mode is on, restored and cleared when it is turned off.
+    pub zen_mode_saved: Option<ZenModeChrome>,
+
+    /// The open Markdown preview, if any (see `ToggleMarkdownPreview`).
+    pub markdown_preview: Option<MarkdownPreview>,
+
     /// Timing state for auto-recovery saves and persistent auto-saves
     /// in this window.
     pub last_auto_recovery_save: std::time::Instant,
     pub last_persistent_auto_save: std::time::Instant,
 
+    /// When this window's session was last considered for autosave, and a
+    /// fingerprint of the layout that autosave wrote (`None` until the
+    /// first one), so an unchanged session isn't rewritten.
+    pub last_workspace_auto_save: std::time::Instant,
+    pub workspace_auto_save_fingerprint: Option<u64>,
+
     /// Warning domain registry for this window's status indicator.
     pub warning_domains: crate::app::warning_domains::WarningDomainRegistry,
 
@@ -970,6 +1081,10 @@ pub struct Window {
     /// require a separately-shared clipboard.
     pub file_explorer_clipboard: Option<crate::app::file_explorer::FileExplorerClipboard>,
 
+    /// The last drag-and-drop move in the file explorer as `(from, to)`,
+    /// kept so `FileExplorerUndoMove` can put the entry back.
+    pub file_explorer_last_move: Option<(std::path::PathBuf, std::path::PathBuf)>,
+
     /// Process-group tracking for everything this window owns
     /// (today: pty children from `terminal_manager.spawn`).
     /// Exposed through `signal_all` so window-level lifecycle
@@ -1734,6 +1849,7 @@ impl Window {
                     file_state.cursor.anchor.map(|a| a.min(max_pos));
                 buf_state.viewport.top_byte = file_state.scroll.top_byte;
                 buf_state.viewport.left_column = file_state.scroll.left_column;
+                crate::app::workspace::restore_folds(buf_state, buffer_state, &file_state.folds);
                 crate::app::navigation::reconcile_restored_buffer_view(
                     buf_state,
                     &mut buffer_state.buffer,
@@ -2030,6 +2146,7 @@ impl Window {
             event_logs: HashMap::new(),
             status_message: None,
             plugin_status_message: None,
+            status_history: std::collections::VecDeque::new(),
             prompt: None,
             bridge,
             next_lsp_request_id: 0,
@@ -2038,6 +2155,7 @@ impl Window {
             scheduled_completion_trigger: None,
             dabbrev_state: None,
             pending_goto_definition_request: None,
+            pending_goto_definition_in_split: false,
             pending_references_request: None,
             pending_references_symbol: String::new(),
             pending_implementation_request: None,
@@ -2061,9 +2179,11 @@ impl Window {
             in_navigation: false,
             suppress_position_history_once: false,
             bookmarks: crate::app::bookmarks::BookmarkState::default(),
+            edit_locations: crate::app::edit_locations::EditLocations::default(),
             grouped_subtrees: HashMap::new(),
             composite_buffers: HashMap::new(),
             composite_view_states: HashMap::new(),
+            disk_snapshots: HashMap::new(),
             layout_cache: WindowLayoutCache::default(),
             chrome_layout: ChromeLayout::default(),
             terminal_width: 80,
@@ -2071,6 +2191,7 @@ impl Window {
             dock_cols: 0,
             preview: None,
             terminal_link_hover: None,
+            buffer_link_hover: None,
             seen_byte_ranges: HashMap::new(),
             previous_viewports: HashMap::new(),
             same_buffer_scroll_sync: false,
@@ -2093,6 +2214,9 @@ impl Window {
             search_namespace: crate::view::overlay::OverlayNamespace::from_string(
                 "search".to_string(),
             ),
+            search_scope_namespace: crate::view::overlay::OverlayNamespace::from_string(
+                "search-scope".to_string(),
+            ),
             pending_search_range: None,
             live_grep_last_state: None,
             overlay_preview_state: None,
@@ -2105,6 +2229,7 @@ impl Window {
             search_use_regex: false,
             search_confirm_each: false,
             scheduled_diagnostic_pull: None,
+            diagnostic_overlay_debounce: std::collections::HashMap::new(),
             scheduled_inlay_hints_request: None,
             user_dismissed_lsp_languages: std::collections::HashSet::new(),
             editor_mode: None,
@@ -2117,6 +2242,7 @@ impl Window {
             diagnostic_result_ids: HashMap::new(),
             lsp_progress: HashMap::new(),
             lsp_server_statuses: HashMap::new(),
+            lsp_last_errors: HashMap::new(),
             lsp_menu_contributions: HashMap::new(),
             lsp_window_messages: Vec::new(),
             lsp_log_messages: Vec::new(),
@@ -2140,6 +2266,10 @@ impl Window {
             mouse_state: crate::app::types::MouseState::default(),
             key_context: crate::input::keybindings::KeyContext::Normal,
             chord_state: Vec::new(),
+            which_key_pending: None,
+            vi_count: None,
+            vi_pending_operator: None,
+            key_repeat: None,
             previous_click_time: None,
             previous_click_position: None,
             click_count: 0,
@@ -2150,8 +2280,12 @@ impl Window {
             tab_bar_visible: resources.config.editor.show_tab_bar,
             status_bar_visible: resources.config.editor.show_status_bar,
             prompt_line_visible: resources.config.editor.show_prompt_line,
+            zen_mode_saved: None,
+            markdown_preview: None,
             last_auto_recovery_save: now,
             last_persistent_auto_save: now,
+            last_workspace_auto_save: now,
+            workspace_auto_save_fingerprint: None,
             warning_domains: crate::app::warning_domains::WarningDomainRegistry::default(),
             tab_context_menu: None,
             new_tab_menu: None,
@@ -2181,6 +2315,7 @@ impl Window {
             animations: crate::view::animation::AnimationRunner::default(),
             plugin_errors: Vec::new(),
             file_explorer_clipboard: None,
+            file_explorer_last_move: None,
             process_groups: ProcessGroups::default(),
             resources,
         }
@@ -2219,6 +2354,7 @@ impl Window {
     /// Clears any plugin-supplied status (matches Editor behaviour).
     pub fn set_status_message(&mut self, message: String) {
         tracing::info!(target: "status", "{}", message);
+        self.record_status_message(&message);
         self.plugin_status_message = None;
         self.status_message = Some(message);
     }
@@ -2782,6 +2918,12 @@ impl Window {
         !self.lsp_progress.is_empty()
     }
 
+    /// Check if the LSP status-bar spinner should be animating: either a
+    /// `$/progress` task is active or a server has requests in flight.
+    pub fn has_lsp_activity(&self) -> bool {
+        self.has_active_lsp_progress() || self.lsp.has_requests_in_flight()
+    }
+
     /// Snapshot of the current LSP progress entries for this window:
     /// `(token, title, message)` tuples.
     pub fn get_lsp_progress(&self) -> Vec<(String, String, Option<String>)> {
@@ -2844,7 +2986,7 @@ impl Window {
             .set_active_split(target);
     }
 
-    /// Restore per-file state (cursors, scroll, etc.) for a buffer in a
+    /// Restore per-file state (cursors, scroll, folds) for a buffer in a
     /// specific split, lazily loaded from disk via
     /// `PersistedFileWorkspace::load`. No-op if there's no saved state
     /// for this path.
@@ -2929,7 +3071,11 @@ impl Window {
             line_wrap: None,
             virtual_space: None,
             plugin_state: std::collections::HashMap::new(),
-            folds: Vec::new(),
+            folds: self
+                .buffers
+                .get(&buffer_id)
+                .map(|state| crate::app::workspace::serialize_folds(buf_state, state))
+                .unwrap_or_default(),
         };
 
         PersistedFileWorkspace::save(&abs_path, file_state);
@@ -3583,6 +3729,7 @@ impl Window {
         changes: Vec<lsp_types::TextDocumentContentChangeEvent>,
     ) {
         const INLAY_HINTS_DEBOUNCE_MS: u64 = 500;
+        const DIAGNOSTIC_OVERLAY_DEBOUNCE_MS: u64 = 150;
 
         if changes.is_empty() {
             return;
@@ -3741,6 +3888,11 @@ impl Window {
                 buffer_id,
                 std::time::Instant::now() + std::time::Duration::from_millis(1000),
             ));
+            self.diagnostic_overlay_debounce.insert(
+                uri.as_str().to_string(),
+                std::time::Instant::now()
+                    + std::time::Duration::from_millis(DIAGNOSTIC_OVERLAY_DEBOUNCE_MS),
+            );
 
             if self.resources.config.editor.enable_inlay_hints {
                 self.scheduled_inlay_hints_request = Some((
@@ -3921,7 +4073,10 @@ impl Window {
             vec![active_split]
         };
 
-        let tab_size = self.resources.config.editor.tab_size;
+        let editor_config = &self.resources.config.editor;
+        let tab_size = editor_config
+            .tab_display_width
+            .unwrap_or(editor_config.tab_size);
         for split_id in splits_to_scroll {
             let (mgr, vs_map) = self.buffers.splits().expect("splits checked above");
             let Some(buffer_id) = mgr.buffer_for_split(split_id) else {
@@ -31,7 +31,7 @@ use crate::state::EditorState;
 use crate::model::event::{BufferId, LeafId, SplitDirection, SplitId};
 use crate::services::terminal::TerminalId;
 use crate::state::ViewMode;
-use crate::view::split::{SplitNode, SplitViewState};
+use crate::view::split::{BufferViewState, SplitNode, SplitViewState};
 use crate::workspace::{
     FileExplorerState, PersistedFileWorkspace, SearchOptions, SerializedBookmark, SerializedCursor,
     SerializedFileState, SerializedFoldRange, SerializedScroll, SerializedSplitDirection,
@@ -93,6 +93,72 @@ fn resolve_fold_header_line(
     None
 }
 
+/// The collapsed folds of `buf_state` as line ranges, for persistence.
+pub(crate) fn serialize_folds(
+    buf_state: &BufferViewState,
+    state: &EditorState,
+) -> Vec<SerializedFoldRange> {
+    buf_state
+        .folds
+        .collapsed_line_ranges(&state.buffer, &state.marker_list)
+        .into_iter()
+        .map(|range| SerializedFoldRange {
+            header_line: range.header_line,
+            end_line: range.end_line,
+            placeholder: range.placeholder,
+            header_text: range.header_text,
+        })
+        .collect()
+}
+
+/// Replace `buf_state`'s folds with the saved `folds`, resolved against the
+/// buffer's current content.
+pub(crate) fn restore_folds(
+    buf_state: &mut BufferViewState,
+    state: &mut EditorState,
+    folds: &[SerializedFoldRange],
+) {
+    buf_state.folds.clear(&mut state.marker_list);
+    for fold in folds {
+        // Resolve the stored line numbers against the current buffer
+        // content. If a header_text was recorded (issue #1568), validate —
+        // and if necessary relocate — the fold so it lands on the line it
+        // was actually meant for, even after an external edit shifted line
+        // numbers.
+        let Some(resolved_header) =
+            resolve_fold_header_line(&state.buffer, fold.header_line, fold.header_text.as_deref())
+        else {
+            tracing::debug!(
+                "Dropping stale fold: header_line={} no longer matches stored header_text \
+                 after external edit",
+                fold.header_line,
+            );
+            continue;
+        };
+
+        // Adjust end_line by the same shift we applied to the header.
+        let shift = resolved_header as i64 - fold.header_line as i64;
+        let end_line = (fold.end_line as i64 + shift).max(0) as usize;
+        let start_line = resolved_header.saturating_add(1);
+        if start_line > end_line {
+            continue;
+        }
+        let Some(start_byte) = state.buffer.line_start_offset(start_line) else {
+            continue;
+        };
+        let end_byte = state
+            .buffer
+            .line_start_offset(end_line.saturating_add(1))
+            .unwrap_or_else(|| state.buffer.len());
+        buf_state.folds.add(
+            &mut state.marker_list,
+            start_byte,
+            end_byte,
+            fold.placeholder.clone(),
+        );
+    }
+}
+
 /// Workspace persistence state tracker
 ///
 /// Tracks dirty state and handles debounced saving for crash resistance.
@@ -505,6 +571,11 @@ impl Editor {
 
         // Editor-global config overrides (the shared `Config`).
         self.restore_config_overrides(&workspace.config_overrides);
+        // Command palette ranking lives in the shared command registry.
+        self.command_registry.write().unwrap().restore_usage(
+            workspace.histories.command_palette.clone(),
+            workspace.histories.command_usage.clone(),
+        );
         // Editor-global plugin state is NOT taken from per-window
         // workspace files: it has a single canonical home in the
         // global `orchestrator/state/` store, loaded once at boot.
@@ -648,6 +719,48 @@ impl Editor {
         }
     }
 
+    /// Autosave the active window's workspace so a crash restores the last
+    /// autosaved layout rather than the one from the last clean exit.
+    ///
+    /// Called every tick; rate-limited by `workspace_auto_save_interval_secs`
+    /// (0 disables it) and skipped entirely when workspaces are disabled
+    /// (`--no-session`). The captured snapshot is fingerprinted, ignoring
+    /// `saved_at`, and the write is skipped when it matches what the previous
+    /// autosave wrote. Same guard as [`Self::checkpoint_window_workspace`].
+    /// Returns whether the workspace was written.
+    pub fn auto_save_workspace(&mut self) -> Result<bool, WorkspaceError> {
+        let interval_secs = self.config.editor.workspace_auto_save_interval_secs;
+        if !self.workspace_enabled || interval_secs == 0 {
+            return Ok(false);
+        }
+        let interval = std::time::Duration::from_secs(interval_secs as u64);
+        if self
+            .time_source
+            .elapsed_since(self.active_window().last_workspace_auto_save)
+            < interval
+        {
+            return Ok(false);
+        }
+        self.active_window_mut().last_workspace_auto_save = self.time_source.now();
+
+        let id = self.active_window;
+        if self.active_window().buffers.splits().is_none()
+            || self.materialize_pending.contains(&id)
+            || self.dormant_remote.contains_key(&id)
+        {
+            return Ok(false);
+        }
+
+        let fingerprint = workspace_fingerprint(&self.active_window().capture_workspace())?;
+        if self.active_window().workspace_auto_save_fingerprint == Some(fingerprint) {
+            return Ok(false);
+        }
+
+        self.save_workspace_for(id)?;
+        self.active_window_mut().workspace_auto_save_fingerprint = Some(fingerprint);
+        Ok(true)
+    }
+
     /// Restore window `id`'s persisted workspace from disk the first
     /// time it's dived into or previewed — the lazy counterpart to the
     /// active window's eager `try_restore_workspace`. Idempotent: the
@@ -718,9 +831,9 @@ impl crate::app::window::Window {
     }
 
     /// Re-create bookmarks from the saved workspace, resolving file paths to buffer IDs.
-    fn restore_bookmarks_from_workspace(
+    fn restore_bookmarks_from_workspace<'a>(
         &mut self,
-        bookmarks: &HashMap<char, SerializedBookmark>,
+        bookmarks: impl Iterator<Item = (char, &'a SerializedBookmark)>,
         path_to_buffer: &HashMap<PathBuf, BufferId>,
     ) {
         for (key, bookmark) in bookmarks {
@@ -728,12 +841,31 @@ impl crate::app::window::Window {
                 continue;
             };
             if let Some(buffer) = self.buffers.get(&buffer_id) {
-                let pos = bookmark.position.min(buffer.buffer.len());
+                let mut pos = bookmark.position.min(buffer.buffer.len());
+                // Follow the bookmarked line if the file changed since.
+                if let Some(hash) = &bookmark.line_hash {
+                    if let Some(text) = buffer.buffer.to_string() {
+                        let anchor = super::bookmarks::LineAnchor {
+                            hash: hash.clone(),
+                            column: bookmark.column,
+                        };
+                        pos = super::bookmarks::relocate(&text, bookmark.position, &anchor);
+                    }
+                }
+                let file_path = if super::bookmarks::is_file_mark_key(key) {
+                    self.buffer_metadata
+                        .get(&buffer_id)
+                        .and_then(|meta| meta.file_path())
+                        .cloned()
+                } else {
+                    None
+                };
                 self.bookmarks.set(
-                    *key,
+                    key,
                     Bookmark {
                         buffer_id,
                         position: pos,
+                        file_path,
                     },
                 );
             }
@@ -1408,50 +1540,7 @@ impl crate::app::window::Window {
                             state.buffer_settings.virtual_space = virtual_space;
                             state.buffer_settings.virtual_space_override = Some(virtual_space);
                         }
-                        buf_state.folds.clear(&mut state.marker_list);
-                        for fold in &file_state.folds {
-                            // Resolve the stored line numbers against the current
-                            // buffer content. If a header_text was recorded (issue
-                            // #1568), validate — and if necessary relocate — the
-                            // fold so it lands on the line it was actually meant
-                            // for, even after an external edit shifted line
-                            // numbers.
-                            let Some(resolved_header) = resolve_fold_header_line(
-                                &state.buffer,
-                                fold.header_line,
-                                fold.header_text.as_deref(),
-                            ) else {
-                                tracing::debug!(
-                                    "Dropping stale fold: header_line={} no longer matches stored \
-                             header_text after external edit",
-                                    fold.header_line,
-                                );
-                                continue;
-                            };
-
-                            // Adjust end_line by the same shift we applied to the header.
-                            let shift = resolved_header as i64 - fold.header_line as i64;
-                            let adjusted_end = (fold.end_line as i64 + shift).max(0) as usize;
-                            let start_line = resolved_header.saturating_add(1);
-                            let end_line = adjusted_end;
-                            if start_line > end_line {
-                                continue;
-                            }
-                            let Some(start_byte) = state.buffer.line_start_offset(start_line)
-                            else {
-                                continue;
-                            };
-                            let end_byte = state
-                                .buffer
-                                .line_start_offset(end_line.saturating_add(1))
-                                .unwrap_or_else(|| state.buffer.len());
-                            buf_state.folds.add(
-                                &mut state.marker_list,
-                                start_byte,
-                                end_byte,
-                                fold.placeholder.clone(),
-                            );
-                        }
+                        restore_folds(buf_state, state, &file_state.folds);
                     }
 
                     tracing::trace!(
@@ -1700,7 +1789,7 @@ impl crate::app::window::Window {
             .any(|m| matches!(m.kind, crate::app::types::BufferKind::Virtual { .. }))
     }
 
-    /// Persist per-file global state (cursor/scroll) for every file
+    /// Persist per-file global state (cursor/scroll/folds) for every file
     /// buffer in this window's splits.
     pub(crate) fn save_all_global_file_states(&self) {
         for (leaf_id, view_state) in self
@@ -1726,7 +1815,7 @@ impl crate::app::window::Window {
         }
     }
 
-    /// Save per-file global state (cursor/scroll) for a specific buffer.
+    /// Save per-file global state (cursor/scroll/folds) for a specific buffer.
     fn save_buffer_file_state(&self, buffer_id: BufferId, view_state: &SplitViewState) {
         let abs_path = match self.buffer_metadata.get(&buffer_id) {
             Some(metadata) => match metadata.file_path() {
@@ -1766,7 +1855,11 @@ impl crate::app::window::Window {
             line_wrap: None,
             virtual_space: None,
             plugin_state: std::collections::HashMap::new(),
-            folds: Vec::new(),
+            folds: self
+                .buffers
+                .get(&buffer_id)
+                .map(|state| serialize_folds(view_state, state))
+                .unwrap_or_default(),
         };
 
         PersistedFileWorkspace::save(&abs_path, file_state);
@@ -2205,7 +2298,12 @@ impl crate::app::window::Window {
                 .set_active_split(LeafId(new_active_split));
         }
 
-        self.restore_bookmarks_from_workspace(&workspace.bookmarks, &path_to_buffer);
+        let bookmarks = workspace
+            .bookmarks
+            .iter()
+            .map(|(key, bookmark)| (*key, bookmark))
+            .chain(workspace.local_bookmarks.iter().map(|(key, b)| (*key, b)));
+        self.restore_bookmarks_from_workspace(bookmarks, &path_to_buffer);
         self.clean_orphaned_buffers();
         self.log_restore_summary(session_name);
 
@@ -2384,6 +2482,8 @@ impl crate::app::window::Window {
             menu_bar_hidden: None,
         };
 
+        let (command_palette, command_usage) =
+            self.resources.command_registry.read().unwrap().usage();
         let histories = WorkspaceHistories {
             search: self
                 .prompt_histories
@@ -2395,7 +2495,8 @@ impl crate::app::window::Window {
                 .get("replace")
                 .map(|h| h.items().to_vec())
                 .unwrap_or_default(),
-            command_palette: Vec::new(),
+            command_palette,
+            command_usage,
             goto_line: self
                 .prompt_histories
                 .get("goto_line")
@@ -2411,7 +2512,12 @@ impl crate::app::window::Window {
             confirm_each: self.search_confirm_each,
         };
 
-        let bookmarks = serialize_bookmarks(&self.bookmarks, &self.buffer_metadata, &self.root);
+        let (bookmarks, local_bookmarks) = serialize_bookmarks(
+            &self.bookmarks,
+            &self.buffer_metadata,
+            &self.buffers,
+            &self.root,
+        );
 
         let external_files: Vec<PathBuf> = self
             .buffer_metadata
@@ -2473,6 +2579,7 @@ impl crate::app::window::Window {
             histories,
             search_options,
             bookmarks,
+            local_bookmarks,
             terminals,
             external_files,
             read_only_files,
@@ -2777,19 +2884,7 @@ fn serialize_split_view_state(
         let primary_cursor = buf_state.cursors.primary();
         let folds = buffers
             .get(buffer_id)
-            .map(|state| {
-                buf_state
-                    .folds
-                    .collapsed_line_ranges(&state.buffer, &state.marker_list)
-                    .into_iter()
-                    .map(|range| SerializedFoldRange {
-                        header_line: range.header_line,
-                        end_line: range.end_line,
-                        placeholder: range.placeholder,
-                        header_text: range.header_text,
-                    })
-                    .collect::<Vec<_>>()
-            })
+            .map(|state| serialize_folds(buf_state, state))
             .unwrap_or_default();
 
         file_states.insert(
@@ -2855,30 +2950,47 @@ fn serialize_split_view_state(
     }
 }
 
+/// Split the window's bookmarks into global marks (one per key) and local
+/// marks (one per key per file) for the workspace file.
 fn serialize_bookmarks(
     bookmarks: &BookmarkState,
     buffer_metadata: &HashMap<BufferId, super::types::BufferMetadata>,
+    buffers: &super::window::buffers::WindowBuffers,
     working_dir: &Path,
-) -> HashMap<char, SerializedBookmark> {
-    bookmarks
-        .iter()
-        .filter_map(|(key, bookmark)| {
-            buffer_metadata
-                .get(&bookmark.buffer_id)
-                .and_then(|meta| meta.file_path())
-                .and_then(|abs_path| {
-                    abs_path.strip_prefix(working_dir).ok().map(|rel_path| {
-                        (
-                            key,
-                            SerializedBookmark {
-                                file_path: rel_path.to_path_buf(),
-                                position: bookmark.position,
-                            },
-                        )
-                    })
-                })
-        })
-        .collect()
+) -> (
+    HashMap<char, SerializedBookmark>,
+    Vec<(char, SerializedBookmark)>,
+) {
+    let mut global = HashMap::new();
+    let mut local = Vec::new();
+    for (key, bookmark) in bookmarks.iter() {
+        let Some(rel_path) = buffer_metadata
+            .get(&bookmark.buffer_id)
+            .and_then(|meta| meta.file_path())
+            .and_then(|abs_path| abs_path.strip_prefix(working_dir).ok())
+        else {
+            continue;
+        };
+        // Anchor the bookmark to its line's content so it can follow the
+        // line if the file changes while closed.
+        let anchor = buffers
+            .get(&bookmark.buffer_id)
+            .and_then(|state| state.buffer.to_string())
+            .and_then(|text| super::bookmarks::anchor_at(&text, bookmark.position));
+        let serialized = SerializedBookmark {
+            file_path: rel_path.to_path_buf(),
+            position: bookmark.position,
+            column: anchor.as_ref().map_or(0, |a| a.column),
+            line_hash: anchor.map(|a| a.hash),
+        };
+        if super::bookmarks::is_local_key(key) {
+            local.push((key, serialized));
+        } else {
+            global.insert(key, serialized);
+        }
+    }
+    local.sort_by(|(ka, a), (kb, b)| (ka, &a.file_path).cmp(&(kb, &b.file_path)));
+    (global, local)
 }
 
 /// Collect all unique file paths from split_states
@@ -2926,3 +3038,19 @@ fn get_expanded_dirs(
 
     expanded
 }
+
+/// Hash of `workspace` with `saved_at` zeroed, so two captures of the same
+/// layout compare equal. Goes through `serde_json::Value`, whose maps are
+/// sorted, because the snapshot's `HashMap`s iterate in a different order
+/// on every capture.
+fn workspace_fingerprint(workspace: &Workspace) -> Result<u64, WorkspaceError> {
+    use std::hash::{Hash, Hasher};
+
+    let mut value = serde_json::to_value(workspace)?;
+    if let Some(saved_at) = value.get_mut("saved_at") {
+        *saved_at = serde_json::Value::Null;
+    }
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.to_string().hash(&mut hasher);
+    Ok(hasher.finish())
+}
@@ -188,6 +188,19 @@ impl CursorStyle {
         }
     }
 
+    /// Whether a terminal with the given `TERM` understands cursor-style
+    /// (DECSCUSR) sequences. The Linux and BSD consoles and dumb terminals
+    /// don't, and may print them as stray characters, so no cursor style
+    /// is sent to them. An unset `TERM` is assumed to be capable.
+    pub fn is_supported_by_term(term: Option<&str>) -> bool {
+        !matches!(term, Some("linux" | "cons25" | "dumb"))
+    }
+
+    /// [`Self::is_supported_by_term`] for the terminal this process runs in
+    pub fn is_supported_by_current_terminal() -> bool {
+        Self::is_supported_by_term(std::env::var("TERM").ok().as_deref())
+    }
+
     /// Parse from string (for command palette)
     pub fn parse(s: &str) -> Option<Self> {
         match s {
@@ -270,7 +283,7 @@ pub struct KeybindingMapName(pub String);
 impl KeybindingMapName {
     /// Built-in keybinding map options shown in the settings dropdown
     pub const BUILTIN_OPTIONS: &'static [&'static str] =
-        &["default", "emacs", "vscode", "macos", "macos-gui"];
+        &["default", "emacs", "vscode", "macos", "macos-gui", "vim"];
 }
 
 impl Deref for KeybindingMapName {
@@ -337,6 +350,64 @@ impl JsonSchema for LineEndingOption {
     }
 }
 
+/// Where a jump target lands in the viewport when the jump has to scroll.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrollOnJump {
+    /// Center the target line vertically
+    #[default]
+    Center,
+    /// Put the target line at the top of the viewport
+    Top,
+    /// Scroll just enough to bring the target into view
+    Minimal,
+}
+
+impl JsonSchema for ScrollOnJump {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ScrollOnJump")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Where a jump target lands in the viewport when the jump has to scroll",
+            "type": "string",
+            "enum": ["center", "top", "minimal"],
+            "default": "center"
+        })
+    }
+}
+
+/// Border drawn around popups, menus and the file explorer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BorderStyle {
+    /// Blank border cells (the frame keeps its size, nothing is drawn)
+    None,
+    /// Single lines with square corners (┌─┐)
+    #[default]
+    Plain,
+    /// Single lines with rounded corners (╭─╮)
+    Rounded,
+    /// Double lines (╔═╗)
+    Double,
+}
+
+impl JsonSchema for BorderStyle {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("BorderStyle")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Border drawn around popups, menus and the file explorer",
+            "type": "string",
+            "enum": ["none", "plain", "rounded", "double"],
+            "default": "plain"
+        })
+    }
+}
+
 /// Where the cursor may move beyond the end of a line (virtual space).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -1101,6 +1172,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub hide_current_line_on_selection: bool,
 
+    /// Paint the selection background out to the right edge of the window
+    /// on every line a multi-line selection runs past. When `false`
+    /// (default) the highlight stops at each line's last character.
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub selection_full_line: bool,
+
     /// Highlight the column containing the cursor
     #[serde(default = "default_false")]
     #[schemars(extend("x-section" = "Display"))]
@@ -1125,6 +1203,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub wrap_column: Option<usize>,
 
+    /// Draw a vertical guide at `wrap_column` while line wrapping is on, so
+    /// the wrap boundary stays visible on lines that don't reach it.
+    /// Has no effect when lines wrap at the viewport edge.
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub show_wrap_guide: bool,
+
     /// Width of the page in page view mode (in columns).
     /// Controls the content width when page view is active, with centering margins.
     /// Defaults to 80. Set to `null` (or `0`) to use the full viewport width.
@@ -1132,6 +1217,12 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub page_width: Option<usize>,
 
+    /// Focus mode for page view: dim every paragraph except the one
+    /// containing the cursor, so the text being written stands out.
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub page_view_focus: bool,
+
     /// Enable syntax highlighting for code files
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Display"))]
@@ -1169,6 +1260,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub menu_bar_mnemonics: bool,
 
+    /// Border drawn around popups, menus and the file explorer.
+    /// "plain": single lines. "rounded": single lines with rounded corners.
+    /// "double": double lines. "none": no visible border.
+    /// Default: "plain"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub border_style: BorderStyle,
+
     /// Whether the tab bar is visible by default.
     /// The tab bar shows open files in each split pane.
     /// Can be toggled at runtime via command palette or keybinding.
@@ -1376,6 +1475,15 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub tab_size: usize,
 
+    /// Number of columns a tab character occupies on screen.
+    /// If not specified (`null`), tabs render `tab_size` columns wide.
+    /// Set it to show tabs wider or narrower than the indentation unit,
+    /// e.g. `8` with a `tab_size` of 4. A value of `0` is treated the same
+    /// as `null`.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub tab_display_width: Option<usize>,
+
     /// Automatically indent new lines based on the previous line
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Editing"))]
@@ -1415,6 +1523,45 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub scroll_offset: usize,
 
+    /// Where the target lands when a jump (search, go to definition, go
+    /// to line, next diagnostic, ...) has to scroll the viewport.
+    /// "center": center the target line. "top": put it at the top.
+    /// "minimal": scroll just enough to bring it into view.
+    /// Targets that are already visible never scroll.
+    /// Default: "center"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub scroll_on_jump: ScrollOnJump,
+
+    /// Speed up held Up/Down keys: the longer a key auto-repeats, the more
+    /// lines each repeat moves, up to `navigation_acceleration_max_step`.
+    /// Prompts and other text inputs are never accelerated.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub navigation_acceleration: bool,
+
+    /// How long, in milliseconds, a held navigation key must repeat to move
+    /// one more line per repeat. Lower values accelerate faster.
+    /// Default: 300ms
+    #[serde(default = "default_navigation_acceleration_ramp")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub navigation_acceleration_ramp_ms: u64,
+
+    /// The most lines a single accelerated key repeat moves.
+    /// Default: 8
+    #[serde(default = "default_navigation_acceleration_max_step")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub navigation_acceleration_max_step: usize,
+
+    /// Whether Find Next / Find Previous wrap around to the other end of
+    /// the buffer after the last match. When off, they stop at the last
+    /// match and report that there are no more.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub search_wrap: bool,
+
     /// Default line ending format for new files.
     /// Files loaded from disk will use their detected line ending format.
     /// Options: "lf" (Unix/Linux/macOS), "crlf" (Windows), "cr" (Classic Mac)
@@ -1635,6 +1782,15 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Recovery"))]
     pub auto_recovery_save_interval_secs: u32,
 
+    /// Interval in seconds for autosaving the session (open files, splits,
+    /// cursors and scroll) so a crash restores the last autosaved layout
+    /// instead of the one from the last clean exit. Skipped while nothing
+    /// has changed since the previous autosave. 0 disables it.
+    /// Default: 30 seconds
+    #[serde(default = "default_workspace_auto_save_interval")]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub workspace_auto_save_interval_secs: u32,
+
     /// Poll interval in milliseconds for auto-reverting open buffers.
     /// When auto-revert is enabled, file modification times are checked at this interval.
     /// Lower values detect external changes faster but use more CPU.
@@ -1786,6 +1942,14 @@ fn default_scroll_offset() -> usize {
     3
 }
 
+fn default_navigation_acceleration_ramp() -> u64 {
+    300 // one more line per repeat for every 300ms the key is held
+}
+
+fn default_navigation_acceleration_max_step() -> usize {
+    8
+}
+
 fn default_highlight_timeout() -> u64 {
     5
 }
@@ -1806,6 +1970,10 @@ fn default_auto_recovery_save_interval() -> u32 {
     2 // 2 seconds between recovery saves
 }
 
+fn default_workspace_auto_save_interval() -> u32 {
+    30 // 30 seconds between session autosaves
+}
+
 fn default_highlight_context_bytes() -> usize {
     10_000 // 10KB context for accurate syntax highlighting
 }
@@ -1835,6 +2003,7 @@ impl Default for EditorConfig {
         Self {
             use_tabs: false,
             tab_size: default_tab_size(),
+            tab_display_width: None,
             auto_indent: true,
             auto_close: true,
             auto_surround: true,
@@ -1844,15 +2013,23 @@ impl Default for EditorConfig {
             line_numbers: true,
             relative_line_numbers: false,
             scroll_offset: default_scroll_offset(),
+            scroll_on_jump: ScrollOnJump::default(),
+            navigation_acceleration: false,
+            navigation_acceleration_ramp_ms: default_navigation_acceleration_ramp(),
+            navigation_acceleration_max_step: default_navigation_acceleration_max_step(),
+            search_wrap: true,
             syntax_highlighting: true,
             highlight_current_line: true,
             highlight_occurrences: true,
             hide_current_line_on_selection: false,
+            selection_full_line: false,
             highlight_current_column: false,
             line_wrap: true,
             wrap_indent: true,
             wrap_column: None,
+            show_wrap_guide: false,
             page_width: default_page_width(),
+            page_view_focus: false,
             highlight_timeout_ms: default_highlight_timeout(),
             snapshot_interval: default_snapshot_interval(),
             large_file_threshold_bytes: default_large_file_threshold(),
@@ -1869,6 +2046,7 @@ impl Default for EditorConfig {
             auto_create_empty_buffer_on_last_buffer_close: true,
             recovery_enabled: true,
             auto_recovery_save_interval_secs: default_auto_recovery_save_interval(),
+            workspace_auto_save_interval_secs: default_workspace_auto_save_interval(),
             highlight_context_bytes: default_highlight_context_bytes(),
             mouse_hover_enabled: default_mouse_hover_enabled(),
             mouse_hover_delay_ms: default_mouse_hover_delay(),
@@ -1895,6 +2073,7 @@ impl Default for EditorConfig {
             screensaver_enabled: false,
             screensaver_idle_minutes: default_screensaver_idle_minutes(),
             menu_bar_mnemonics: true,
+            border_style: BorderStyle::default(),
             show_tab_bar: true,
             show_status_bar: true,
             status_bar: StatusBarConfig::default(),
@@ -1996,6 +2175,13 @@ pub struct FileExplorerConfig {
     #[serde(default = "default_true")]
     pub compact_directories: bool,
 
+    /// Delete files from the file explorer by moving them to the system
+    /// trash (or, on a remote host, `~/.local/share/fresh/trash`). Set to
+    /// `false` to delete permanently instead.
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub delete_to_trash: bool,
+
     /// Symbol shown next to a collapsed (closed) directory in the file
     /// explorer tree. A short string (single character recommended).
     /// A trailing space is added automatically during rendering; the
@@ -2425,7 +2611,7 @@ impl Default for PackagesConfig {
 }
 
 // Re-export PluginConfig from fresh-core for shared type usage
-pub use fresh_core::config::PluginConfig;
+pub use fresh_core::config::{PluginConfig, PluginPermissions};
 
 impl Default for FileExplorerConfig {
     fn default() -> Self {
@@ -2440,6 +2626,7 @@ impl Default for FileExplorerConfig {
             auto_open_on_last_buffer_close: true,
             follow_active_buffer: false,
             compact_directories: true,
+            delete_to_trash: true,
             tree_indicator_collapsed: default_tree_indicator_collapsed(),
             tree_indicator_expanded: default_tree_indicator_expanded(),
         }
@@ -2493,6 +2680,31 @@ pub struct Keybinding {
     pub when: Option<String>,
 }
 
+/// Custom keybindings that bind the same keys in the same context to
+/// different actions. Only the last of them takes effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeybindingConflict {
+    /// The keys, formatted for display (e.g. "Ctrl+X Ctrl+S")
+    pub keys: String,
+    /// The `when` context the bindings share
+    pub context: String,
+    /// The conflicting actions in config order; the last one wins
+    pub actions: Vec<String>,
+}
+
+impl std::fmt::Display for KeybindingConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in '{}' is bound to {}; using {}",
+            self.keys,
+            self.context,
+            self.actions.join(", "),
+            self.actions.last().map(String::as_str).unwrap_or_default()
+        )
+    }
+}
+
 /// Keymap configuration (for built-in and user-defined keymaps)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[schemars(extend("x-display-field" = "/inherits"))]
@@ -2666,7 +2878,8 @@ pub struct LanguageConfig {
 
     /// Extra characters (beyond alphanumeric and `_`) considered part of
     /// identifiers for this language. Used by dabbrev and buffer-word
-    /// completion to correctly tokenise language-specific naming conventions.
+    /// completion and by occurrence highlighting to correctly tokenise
+    /// language-specific naming conventions.
     ///
     /// Examples:
     /// - Lisp/Clojure/CSS: `"-"` (kebab-case identifiers)
@@ -2785,6 +2998,10 @@ pub struct BufferConfig {
     /// Number of spaces per tab character
     pub tab_size: usize,
 
+    /// Number of columns a tab character occupies on screen
+    /// (None = `tab_size`)
+    pub tab_display_width: Option<usize>,
+
     /// Whether to insert a tab character (true) or spaces (false) when pressing Tab
     pub use_tabs: bool,
 
@@ -2851,6 +3068,7 @@ impl BufferConfig {
         let mut whitespace = WhitespaceVisibility::from_editor_config(editor);
         let mut config = BufferConfig {
             tab_size: editor.tab_size,
+            tab_display_width: editor.tab_display_width,
             use_tabs: editor.use_tabs,
             auto_indent: editor.auto_indent,
             auto_close: editor.auto_close,
@@ -3554,6 +3772,17 @@ impl MenuConfig {
                                 when: None,
                                 checkbox: Some(context_keys::KEYMAP_MACOS_GUI.to_string()),
                             },
+                            MenuItem::Action {
+                                label: t!("menu.view.keybinding_vim").to_string(),
+                                action: "switch_keybinding_map".to_string(),
+                                args: {
+                                    let mut map = HashMap::new();
+                                    map.insert("map".to_string(), serde_json::json!("vim"));
+                                    map
+                                },
+                                when: None,
+                                checkbox: Some(context_keys::KEYMAP_VIM.to_string()),
+                            },
                         ],
                     },
                 ],
@@ -3937,7 +4166,8 @@ impl Config {
     /// column of 0 wraps every character, a tab size of 0 divides by zero):
     /// - At the **global** (`editor`) level, `0` falls back to the built-in
     ///   default behavior: `wrap_column`/`page_width` become `None` (viewport
-    ///   edge / full viewport width) and `tab_size` becomes the default (4).
+    ///   edge / full viewport width), `tab_display_width` becomes `None`
+    ///   (follow `tab_size`) and `tab_size` becomes the default (4).
     /// - At the **language** level, `0` clears the override to `None` so it
     ///   inherits the global value, exactly as if the key were omitted.
     ///
@@ -3953,6 +4183,9 @@ impl Config {
         if self.editor.tab_size == 0 {
             self.editor.tab_size = default_tab_size();
         }
+        if self.editor.tab_display_width == Some(0) {
+            self.editor.tab_display_width = None;
+        }
         for lang in self.languages.values_mut() {
             if lang.wrap_column == Some(0) {
                 lang.wrap_column = None;
@@ -4010,6 +4243,7 @@ impl Config {
             "vscode" => include_str!("../keymaps/vscode.json"),
             "macos" => include_str!("../keymaps/macos.json"),
             "macos-gui" => include_str!("../keymaps/macos-gui.json"),
+            "vim" => include_str!("../keymaps/vim.json"),
             _ => return None,
         };
 
@@ -4029,6 +4263,84 @@ impl Config {
         self.resolve_keymap_recursive(map_name, &mut visited)
     }
 
+    /// Find custom keybindings that bind the same keys in the same context
+    /// to different actions. Key names, modifiers and `when` clauses are
+    /// compared the way the keybinding resolver reads them, so `Esc` and
+    /// `Escape`, or a missing `when` and `"normal"`, count as the same.
+    /// Bindings with unknown keys are ignored.
+    #[cfg(feature = "runtime")]
+    pub fn detect_keybinding_conflicts(&self) -> Vec<KeybindingConflict> {
+        use crate::input::keybindings::{
+            format_keybinding, normalize_key, KeyContext, KeybindingResolver,
+        };
+
+        type Sequence = Vec<(crossterm::event::KeyCode, crossterm::event::KeyModifiers)>;
+        let mut seen: Vec<((Sequence, KeyContext), Vec<&Keybinding>)> = Vec::new();
+        for binding in &self.keybindings {
+            let presses: Vec<(&str, &[String])> = if binding.keys.is_empty() {
+                vec![(binding.key.as_str(), binding.modifiers.as_slice())]
+            } else {
+                binding
+                    .keys
+                    .iter()
+                    .map(|press| (press.key.as_str(), press.modifiers.as_slice()))
+                    .collect()
+            };
+            let Some(sequence) = presses
+                .into_iter()
+                .map(|(key, modifiers)| {
+                    let code = KeybindingResolver::parse_key_public(key)?;
+                    let modifiers = KeybindingResolver::parse_modifiers_public(modifiers);
+                    Some(normalize_key(code, modifiers))
+                })
+                .collect::<Option<Sequence>>()
+            else {
+                continue;
+            };
+            let context = binding
+                .when
+                .as_deref()
+                .and_then(KeyContext::from_when_clause)
+                .unwrap_or(KeyContext::Normal);
+
+            let slot = (sequence, context);
+            match seen.iter_mut().find(|(key, _)| *key == slot) {
+                Some((_, bindings)) => bindings.push(binding),
+                None => seen.push((slot, vec![binding])),
+            }
+        }
+
+        let describe = |binding: &Keybinding| {
+            if binding.args.is_empty() {
+                binding.action.clone()
+            } else {
+                let args: std::collections::BTreeMap<_, _> = binding.args.iter().collect();
+                let args = serde_json::to_string(&args).unwrap_or_default();
+                format!("{} {}", binding.action, args)
+            }
+        };
+        seen.into_iter()
+            .filter_map(|((sequence, context), bindings)| {
+                // Repeating a binding is harmless; keep each distinct action
+                // once, at its last position.
+                let mut distinct: Vec<&Keybinding> = Vec::new();
+                for binding in bindings {
+                    distinct.retain(|b| (&b.action, &b.args) != (&binding.action, &binding.args));
+                    distinct.push(binding);
+                }
+                (distinct.len() > 1).then(|| KeybindingConflict {
+                    keys: sequence
+                        .iter()
+                        .map(|(code, modifiers)| format_keybinding(code, modifiers))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    context: context.to_when_clause(),
+                    actions: distinct.into_iter().map(&describe).collect(),
+                })
+            })
+            .collect()
+    }
+
     /// Recursive helper for resolve_keymap
     fn resolve_keymap_recursive(
         &self,
@@ -8650,6 +8962,57 @@ mod tests {
         assert_eq!(config.keybindings[0].modifiers.len(), 2);
     }
 
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn test_detect_keybinding_conflicts_reports_same_keys_and_context() {
+        let json = r#"{
+            "keybindings": [
+                { "key": "k", "modifiers": ["ctrl"], "action": "delete_line" },
+                { "key": "K", "modifiers": ["Ctrl"], "action": "delete_line" },
+                { "key": "k", "modifiers": ["ctrl"], "action": "cut", "when": "normal" },
+                {
+                    "keys": [
+                        { "key": "x", "modifiers": ["ctrl"] },
+                        { "key": "s", "modifiers": ["ctrl"] }
+                    ],
+                    "action": "save"
+                },
+                {
+                    "keys": [
+                        { "key": "x", "modifiers": ["ctrl"] },
+                        { "key": "s", "modifiers": ["ctrl"] }
+                    ],
+                    "action": "save_as"
+                }
+            ]
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        let conflicts = config.detect_keybinding_conflicts();
+        assert_eq!(conflicts.len(), 2, "{conflicts:?}");
+        assert_eq!(conflicts[0].context, "normal");
+        assert_eq!(conflicts[0].actions, vec!["delete_line", "cut"]);
+        assert_eq!(conflicts[1].actions, vec!["save", "save_as"]);
+        assert_eq!(conflicts[1].keys.split(' ').count(), 2);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn test_detect_keybinding_conflicts_ignores_other_contexts_and_repeats() {
+        let json = r#"{
+            "keybindings": [
+                { "key": "enter", "modifiers": [], "action": "insert_newline" },
+                { "key": "enter", "modifiers": [], "action": "prompt_confirm", "when": "prompt" },
+                { "key": "enter", "modifiers": [], "action": "insert_newline", "when": "normal" },
+                { "key": "y", "modifiers": ["alt"], "action": "insert_char", "args": { "char": "y" } },
+                { "key": "y", "modifiers": ["alt"], "action": "insert_char", "args": { "char": "y" } }
+            ]
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        assert!(config.detect_keybinding_conflicts().is_empty());
+    }
+
     #[test]
     fn test_sparse_config_merges_with_defaults() {
         // User config that only specifies one LSP server
@@ -9189,4 +9552,47 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    #[cfg(feature = "runtime")]
+    fn test_cursor_style_maps_to_matching_crossterm_command() {
+        use crossterm::cursor::SetCursorStyle;
+        use crossterm::Command;
+
+        let expected = [
+            ("default", SetCursorStyle::DefaultUserShape),
+            ("blinking_block", SetCursorStyle::BlinkingBlock),
+            ("steady_block", SetCursorStyle::SteadyBlock),
+            ("blinking_bar", SetCursorStyle::BlinkingBar),
+            ("steady_bar", SetCursorStyle::SteadyBar),
+            ("blinking_underline", SetCursorStyle::BlinkingUnderScore),
+            ("steady_underline", SetCursorStyle::SteadyUnderScore),
+        ];
+        assert_eq!(expected.len(), CursorStyle::OPTIONS.len());
+        let ansi = |command: SetCursorStyle| {
+            let mut out = String::new();
+            command.write_ansi(&mut out).unwrap();
+            out
+        };
+        for (name, command) in expected {
+            let style = CursorStyle::parse(name).unwrap();
+            assert_eq!(style.as_str(), name);
+            assert_eq!(ansi(style.to_crossterm_style()), ansi(command), "{name}");
+            // Session mode sends the raw sequence; it must match what
+            // crossterm writes for the same style in direct mode.
+            assert_eq!(
+                style.to_escape_sequence(),
+                ansi(command).as_bytes(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cursor_style_skipped_for_terminals_without_decscusr() {
+        assert!(CursorStyle::is_supported_by_term(Some("xterm-256color")));
+        assert!(CursorStyle::is_supported_by_term(None));
+        assert!(!CursorStyle::is_supported_by_term(Some("linux")));
+        assert!(!CursorStyle::is_supported_by_term(Some("dumb")));
+    }
 }
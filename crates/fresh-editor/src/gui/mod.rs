@@ -126,6 +126,7 @@ pub fn run_gui(
         editor.set_software_cursor_only(true);
 
         let workspace_enabled = !no_session_flag && file_locations.is_empty();
+        editor.set_workspace_enabled(workspace_enabled);
 
         if !file_locations.is_empty() {
             for (path, line, col) in &file_locations {
@@ -59,11 +59,10 @@ pub fn translate_plugin_string(
         }
     };
 
-    // Try current locale, then fallback to English
-    let lang_map: Option<&HashMap<String, String>> =
-        plugin_map.get(&locale).or_else(|| plugin_map.get("en"));
-
-    let template: &String = match lang_map.and_then(|m| m.get(key)) {
+    // Try current locale, then fallback to English, key by key so a
+    // partially translated bundle still shows English for the rest
+    let lookup = |locale: &str| plugin_map.get(locale).and_then(|m| m.get(key));
+    let template: &String = match lookup(&locale).or_else(|| lookup("en")) {
         Some(t) => t,
         None => {
             tracing::debug!(
@@ -77,10 +76,13 @@ pub fn translate_plugin_string(
         }
     };
 
-    // Simple interpolation: %{variable}
+    // Simple interpolation: %{variable} (as in the editor's own locales)
+    // or the plain {variable} form
     let mut result = template.clone();
     for (k, v) in args {
-        result = result.replace(&format!("%{{{}}}", k), v);
+        result = result
+            .replace(&format!("%{{{}}}", k), v)
+            .replace(&format!("{{{}}}", k), v);
     }
     result
 }
@@ -287,6 +289,34 @@ mod tests {
         assert_eq!(msg, "Locale changed to es");
     }
 
+    #[test]
+    fn test_plugin_bundle_translation_interpolates_and_falls_back() {
+        set_locale("en");
+        let bundle: HashMap<String, HashMap<String, String>> = serde_json::from_str(
+            r#"{
+                "en": { "greet": "Hello, {name}", "count": "%{n} files" },
+                "de": { "greet": "Hallo, {name}" }
+            }"#,
+        )
+        .unwrap();
+        register_plugin_strings("i18n_test_plugin", bundle);
+
+        let args = HashMap::from([
+            ("name".to_string(), "Ada".to_string()),
+            ("n".to_string(), "3".to_string()),
+        ]);
+        let tr = |key| translate_plugin_string("i18n_test_plugin", key, &args);
+        assert_eq!(tr("greet"), "Hello, Ada");
+        assert_eq!(tr("count"), "3 files");
+        assert_eq!(tr("missing.key"), "missing.key");
+        assert_eq!(
+            translate_plugin_string("no_such_plugin", "greet", &args),
+            "greet"
+        );
+
+        unregister_plugin_strings("i18n_test_plugin");
+    }
+
     #[test]
     fn test_available_locales_includes_en() {
         let locales = available_locales();
@@ -1,6 +1,6 @@
 //! Action to event conversion - translates high-level actions into buffer events
 
-use crate::input::keybindings::Action;
+use crate::input::keybindings::{Action, SortLinesOptions};
 use crate::input::line_move::{move_lines, LineMoveDirection};
 use crate::model::buffer::{Buffer, LineEnding};
 use crate::model::buffer_position::{byte_to_2d, pos_2d_to_byte};
@@ -10,6 +10,7 @@ use crate::model::virtual_space::{
     cursor_virtual_columns, cursor_virtual_lines, line_width_at_content_end,
 };
 use crate::primitives::display_width::{byte_offset_at_visual_column, str_width};
+use crate::primitives::grapheme;
 use crate::primitives::highlighter::HighlightCategory;
 use crate::primitives::indent_pattern::PatternIndentCalculator;
 use crate::primitives::word_navigation::{
@@ -76,7 +77,12 @@ fn clamp_to_last_char_on_line(line_start: usize, line_content: &str, pos: usize)
     pos.min(last_char_pos)
 }
 
-fn find_paragraph_up(buffer: &mut Buffer, pos: usize, estimated_line_length: usize) -> usize {
+/// Start of the nearest blank line above `pos`, or the buffer start (`{`).
+pub(crate) fn find_paragraph_up(
+    buffer: &mut Buffer,
+    pos: usize,
+    estimated_line_length: usize,
+) -> usize {
     let mut iter = buffer.line_iterator(pos, estimated_line_length);
     let mut found_pos = None;
     while let Some((line_start, line_content)) = iter.prev() {
@@ -172,7 +178,9 @@ fn paragraph_down_eof_position(buffer: &Buffer) -> usize {
     }
 }
 
-fn paragraph_down_selection_position(
+/// Where a selection extended by `}` from `pos` ends: the next blank line,
+/// or the buffer end in the last paragraph.
+pub(crate) fn paragraph_down_selection_position(
     buffer: &mut Buffer,
     pos: usize,
     estimated_line_length: usize,
@@ -2011,7 +2019,15 @@ fn handle_delete_backward(
                 // Use prev_char_boundary to delete one code point at a time
                 // This allows "layer-by-layer" deletion of Thai combining marks
                 // In CRLF files, this also ensures we delete \r\n as a unit
-                let delete_from = state.buffer.prev_char_boundary(cursor.position);
+                // Emoji sequences (flags, ZWJ, skin tones) go as a whole cluster
+                let cluster_start = state.buffer.prev_grapheme_boundary(cursor.position);
+                let cluster = state.buffer.slice_bytes(cluster_start..cursor.position);
+                let delete_from = if grapheme::is_emoji_sequence(&String::from_utf8_lossy(&cluster))
+                {
+                    cluster_start
+                } else {
+                    state.buffer.prev_char_boundary(cursor.position)
+                };
                 let delete_from = adjust_position_for_crlf_left(&state.buffer, delete_from);
 
                 // Check for auto-pair deletion when auto_close is enabled
@@ -2391,17 +2407,93 @@ fn handle_toggle_case(state: &mut EditorState, cursors: &Cursors, events: &mut V
     }
 }
 
-fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Vec<Event>) {
-    // Sort selected lines alphabetically
-    // Process cursors in reverse order to avoid position shifts
+/// The number a line starts with (after leading whitespace), for numeric
+/// sorting. Lines without one sort before every line that has one.
+fn leading_number(line: &str) -> Option<f64> {
+    let line = line.trim_start();
+    let mut end = 0;
+    let mut seen_digit = false;
+    let mut seen_dot = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '-' | '+' if i == 0 => {}
+            '.' if !seen_dot => seen_dot = true,
+            c if c.is_ascii_digit() => seen_digit = true,
+            _ => break,
+        }
+        end = i + c.len_utf8();
+    }
+    if !seen_digit {
+        return None;
+    }
+    line[..end].trim_end_matches('.').parse().ok()
+}
+
+fn compare_lines(a: &str, b: &str, options: SortLinesOptions) -> std::cmp::Ordering {
+    let ordering = if options.numeric {
+        match (leading_number(a), leading_number(b)) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (x, y) => x.is_some().cmp(&y.is_some()),
+        }
+    } else {
+        std::cmp::Ordering::Equal
+    };
+    let ordering = ordering.then_with(|| {
+        if options.case_insensitive {
+            a.to_lowercase().cmp(&b.to_lowercase())
+        } else {
+            a.cmp(b)
+        }
+    });
+    if options.reverse {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+/// `Action::SortLines` — sort the full lines each selection touches, or the
+/// whole buffer when nothing is selected.
+fn handle_sort_lines(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    options: SortLinesOptions,
+    estimated_line_length: usize,
+) {
     let line_ending = state.buffer.line_ending().insertion_str();
-    let mut selections: Vec<_> = cursors
+    let mut ranges: Vec<_> = cursors
         .iter()
-        .filter_map(|(cursor_id, cursor)| cursor.selection_range().map(|range| (cursor_id, range)))
+        .filter_map(|(cursor_id, cursor)| {
+            let range = cursor.selection_range()?;
+            // Expand the selection to the full lines it touches
+            let start_line = state.buffer.get_line_number(range.start);
+            let end_line = state
+                .buffer
+                .get_line_number(range.end.saturating_sub(1).max(range.start));
+            let start = state.buffer.line_start_offset(start_line)?;
+            let end_line_start = state.buffer.line_start_offset(end_line)?;
+            let (_, content) = state
+                .buffer
+                .line_iterator(end_line_start, estimated_line_length)
+                .next_line()?;
+            Some((cursor_id, start..end_line_start + content.len()))
+        })
         .collect();
-    selections.sort_by_key(|(_, range)| std::cmp::Reverse(range.start));
+    if !cursors.iter().any(|(_, c)| c.selection_range().is_some()) {
+        ranges.push((cursors.primary_id(), 0..state.buffer.len()));
+    }
+
+    // Process in reverse order to avoid position shifts, skipping ranges
+    // that overlap one already sorted
+    ranges.sort_by_key(|(_, range)| std::cmp::Reverse(range.start));
+    let mut sorted_from = usize::MAX;
+    for (cursor_id, range) in ranges {
+        if range.end > sorted_from {
+            continue;
+        }
+        sorted_from = range.start;
 
-    for (cursor_id, range) in selections {
         let text = state.get_text_range(range.start, range.end);
         // Split into lines, preserving the original line ending style
         let mut lines: Vec<&str> = text.lines().collect();
@@ -2409,7 +2501,7 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
         let ends_with_newline = text.ends_with('\n') || text.ends_with("\r\n");
 
         if lines.len() > 1 {
-            lines.sort();
+            lines.sort_by(|a, b| compare_lines(a, b, options));
             let mut sorted_text = lines.join(line_ending);
             if ends_with_newline {
                 sorted_text.push_str(line_ending);
@@ -2514,6 +2606,209 @@ fn handle_duplicate_line(
     }
 }
 
+/// `Action::DuplicateSelection` — insert a copy of each cursor's selection
+/// right after it and select the copy, or without a selection duplicate the
+/// cursor's line below and keep the cursor's column on the copy.
+fn handle_duplicate_selection(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    estimated_line_length: usize,
+) {
+    // (cursor, insert position, text to insert, new position, new anchor)
+    let mut inserts: Vec<_> = cursors
+        .iter()
+        .filter_map(|(cursor_id, cursor)| {
+            if let Some(range) = cursor.selection_range() {
+                let text = state.get_text_range(range.start, range.end);
+                let copy_end = range.end + text.len();
+                // Keep the selection's direction on the copy
+                let (position, anchor) = if cursor.position == range.start {
+                    (range.end, copy_end)
+                } else {
+                    (copy_end, range.end)
+                };
+                Some((cursor_id, range.end, text, position, Some(anchor)))
+            } else {
+                let mut iter = state
+                    .buffer
+                    .line_iterator(cursor.position, estimated_line_length);
+                let line_start = iter.current_position();
+                let (_, content) = iter.next_line()?;
+                let line_end = line_start + content.len();
+                let column = cursor.position - line_start;
+                // The last line may lack a line ending: prepend one
+                let (text, copy_start) = if content.ends_with('\n') {
+                    (content, line_end)
+                } else {
+                    let line_ending = state.buffer.line_ending().insertion_str();
+                    (
+                        format!("{}{}", line_ending, content),
+                        line_end + line_ending.len(),
+                    )
+                };
+                Some((cursor_id, line_end, text, copy_start + column, None))
+            }
+        })
+        .collect();
+    // Process in reverse order to avoid position shifts
+    inserts.sort_by_key(|(_, position, ..)| std::cmp::Reverse(*position));
+
+    for (cursor_id, position, text, new_position, new_anchor) in inserts {
+        let old_position = position + text.len();
+        let old_sticky_column = cursors.get(cursor_id).and_then(|c| c.sticky_column);
+        events.push(Event::Insert {
+            position,
+            text,
+            cursor_id,
+        });
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position,
+            new_position,
+            old_anchor: None,
+            new_anchor,
+            old_sticky_column,
+            new_sticky_column: None,
+        });
+    }
+}
+
+/// Join `lines` (without their line endings) Vim `J` style: the whitespace
+/// around each line break collapses to a single space, or to nothing when
+/// either side of the break is empty. Returns the joined text and the byte
+/// offset of the last join point.
+fn join_lines_text(lines: &[&str]) -> (String, usize) {
+    let mut joined = lines.first().copied().unwrap_or_default().to_string();
+    let mut join_point = 0;
+    for next in lines.iter().skip(1) {
+        joined.truncate(joined.trim_end_matches([' ', '\t']).len());
+        join_point = joined.len();
+        let next = next.trim_start_matches([' ', '\t']);
+        if !joined.is_empty() && !next.is_empty() {
+            joined.push(' ');
+        }
+        joined.push_str(next);
+    }
+    (joined, join_point)
+}
+
+/// `Action::JoinLines` — join each cursor's line with the next one, or all
+/// the lines a selection touches, and leave the cursor at the last join
+/// point. Cursors whose line ranges overlap share one join.
+fn handle_join_lines(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    estimated_line_length: usize,
+) {
+    // (cursor, first line, last line) to join; a single line joins the next.
+    let mut line_ranges: Vec<_> = cursors
+        .iter()
+        .map(|(cursor_id, cursor)| {
+            let (first, last) = match cursor.selection_range() {
+                Some(range) => (
+                    state.buffer.get_line_number(range.start),
+                    state
+                        .buffer
+                        .get_line_number(range.end.saturating_sub(1).max(range.start)),
+                ),
+                None => {
+                    let line = state.buffer.get_line_number(cursor.position);
+                    (line, line)
+                }
+            };
+            (cursor_id, first, last.max(first + 1))
+        })
+        .collect();
+    line_ranges.sort_by_key(|(_, first, _)| *first);
+    let mut merged: Vec<(CursorId, usize, usize)> = Vec::new();
+    for (cursor_id, first, last) in line_ranges {
+        match merged.last_mut() {
+            Some((_, _, prev_last)) if first <= *prev_last => *prev_last = (*prev_last).max(last),
+            _ => merged.push((cursor_id, first, last)),
+        }
+    }
+
+    // Process in reverse order to avoid position shifts
+    for (cursor_id, first, last) in merged.into_iter().rev() {
+        let Some(block_start) = state.buffer.line_start_offset(first) else {
+            continue;
+        };
+        let buffer_len = state.buffer.len();
+        let mut iter = state
+            .buffer
+            .line_iterator(block_start, estimated_line_length);
+        let mut lines = Vec::new();
+        let mut block_end = block_start;
+        while lines.len() <= last - first {
+            let Some((line_start, content)) = iter.next_line() else {
+                break;
+            };
+            // The empty "line" after a final newline is not joinable.
+            if line_start == buffer_len && !lines.is_empty() {
+                break;
+            }
+            let text = content
+                .strip_suffix('\n')
+                .map(|t| t.strip_suffix('\r').unwrap_or(t))
+                .unwrap_or(&content)
+                .to_string();
+            block_end = line_start + text.len();
+            lines.push(text);
+        }
+        if lines.len() < 2 {
+            continue;
+        }
+
+        let line_refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let (joined, join_point) = join_lines_text(&line_refs);
+        let old_text = state.get_text_range(block_start, block_end);
+
+        // Only replace the part that changed, so markers on the surrounding
+        // text stay put.
+        let prefix: usize = old_text
+            .chars()
+            .zip(joined.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let suffix: usize = old_text[prefix..]
+            .chars()
+            .rev()
+            .zip(joined[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let replace_start = block_start + prefix;
+        let deleted_text = old_text[prefix..old_text.len() - suffix].to_string();
+        let inserted_text = joined[prefix..joined.len() - suffix].to_string();
+        let inserted_len = inserted_text.len();
+
+        events.push(Event::Delete {
+            range: replace_start..replace_start + deleted_text.len(),
+            deleted_text,
+            cursor_id,
+        });
+        if !inserted_text.is_empty() {
+            events.push(Event::Insert {
+                position: replace_start,
+                text: inserted_text,
+                cursor_id,
+            });
+        }
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position: replace_start + inserted_len,
+            new_position: block_start + join_point,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: None,
+            new_sticky_column: None,
+        });
+    }
+}
+
 /// Convert an action into a sequence of events that can be applied to the editor state
 ///
 /// # Parameters
@@ -3069,8 +3364,8 @@ pub fn action_to_events(
             handle_toggle_case(state, cursors, &mut events);
         }
 
-        Action::SortLines => {
-            handle_sort_lines(state, cursors, &mut events);
+        Action::SortLines(options) => {
+            handle_sort_lines(state, cursors, &mut events, options, estimated_line_length);
         }
 
         Action::OpenLine => {
@@ -3081,6 +3376,13 @@ pub fn action_to_events(
             handle_duplicate_line(state, cursors, &mut events, estimated_line_length);
         }
 
+        Action::JoinLines => {
+            handle_join_lines(state, cursors, &mut events, estimated_line_length);
+        }
+        Action::DuplicateSelection => {
+            handle_duplicate_selection(state, cursors, &mut events, estimated_line_length);
+        }
+
         Action::Recenter => {
             // Scroll so that the cursor is centered in the view
             // This is handled specially - we emit a Recenter event
@@ -3135,6 +3437,7 @@ pub fn action_to_events(
         | Action::Open
         | Action::SwitchProject
         | Action::New
+        | Action::OpenScratchBuffer(_)
         | Action::Close
         | Action::CloseTab
         | Action::GotoLine
@@ -3142,9 +3445,11 @@ pub fn action_to_events(
         | Action::NextBuffer
         | Action::PrevBuffer
         | Action::SwitchToPreviousTab
+        | Action::BufferSwitcher
         | Action::SwitchToTabByName
         | Action::NavigateBack
         | Action::NavigateForward
+        | Action::GotoLastEdit
         | Action::SplitHorizontal
         | Action::SplitVertical
         | Action::CloseSplit
@@ -3166,11 +3471,31 @@ pub fn action_to_events(
         | Action::YankToLineEnd
         | Action::YankToLineStart
         | Action::YankViWordEnd
+        | Action::ViNormalMode
+        | Action::ViInsertMode
+        | Action::ViAppend
+        | Action::ViAppendLineEnd
+        | Action::ViInsertAtLineStart
+        | Action::ViOpenLineBelow
+        | Action::ViOpenLineAbove
+        | Action::ViVisualMode
+        | Action::ViVisualOperator(_)
+        | Action::ViOperator(_)
+        | Action::ViSubstituteLine
+        | Action::ViChangeToLineEnd
+        | Action::ViCountDigit(_)
+        | Action::SelectInside(_)
+        | Action::SelectAround(_)
+        | Action::SurroundAdd(_)
+        | Action::SurroundChange(_)
+        | Action::SurroundDelete(_)
+        | Action::ViSurround(_)
         | Action::AddCursorNextMatch
         | Action::AddCursorAbove
         | Action::AddCursorBelow
         | Action::AddCursorsToLineEnds
         | Action::CommandPalette
+        | Action::RepeatLastCommand
         | Action::QuickOpen
         | Action::QuickOpenBuffers
         | Action::QuickOpenFiles
@@ -3179,6 +3504,7 @@ pub fn action_to_events(
         | Action::ToggleUtilityDock
         | Action::OpenTerminalInDock
         | Action::CycleLiveGrepProvider
+        | Action::ShowDiagnosticsPanel
         | Action::ShowHelp
         | Action::ToggleLineWrap
         | Action::ToggleCurrentLineHighlight
@@ -3186,6 +3512,7 @@ pub fn action_to_events(
         | Action::ToggleReadOnly
         | Action::TogglePageView
         | Action::SetPageWidth
+        | Action::ToggleMarkdownPreview
         | Action::IncreaseSplitSize
         | Action::DecreaseSplitSize
         | Action::ToggleMaximizeSplit
@@ -3194,9 +3521,12 @@ pub fn action_to_events(
         | Action::GoToMatchingBracket
         | Action::JumpToNextError
         | Action::JumpToPreviousError
+        | Action::OpenLinkAtCursor
+        | Action::GotoFileUnderCursor
         | Action::ShowKeyboardShortcuts
         | Action::ShowWarnings
         | Action::ShowStatusLog
+        | Action::ShowMessageLog
         | Action::ShowLspStatus
         | Action::ShowRemoteIndicatorMenu
         | Action::ShowReadOnlyMenu
@@ -3213,6 +3543,7 @@ pub fn action_to_events(
         | Action::ToggleSearchWholeWord
         | Action::ToggleSearchRegex
         | Action::ToggleSearchConfirmEach
+        | Action::ToggleSearchWrap
         | Action::StartMacroRecording
         | Action::StopMacroRecording
         | Action::PlayMacro(_)
@@ -3272,6 +3603,7 @@ pub fn action_to_events(
         | Action::ToggleTabBar
         | Action::ToggleStatusBar
         | Action::TogglePromptLine
+        | Action::ToggleZenMode
         | Action::ToggleVerticalScrollbar
         | Action::ToggleHorizontalScrollbar
         | Action::FocusFileExplorer
@@ -3305,8 +3637,10 @@ pub fn action_to_events(
         | Action::FileExplorerExtendSelectionDown
         | Action::FileExplorerToggleSelect
         | Action::FileExplorerSelectAll
+        | Action::FileExplorerUndoMove
         | Action::LspCompletion
         | Action::LspGotoDefinition
+        | Action::GotoDefinitionInSplit
         | Action::LspReferences
         | Action::LspImplementation
         | Action::LspRename
@@ -3329,6 +3663,8 @@ pub fn action_to_events(
         | Action::RedrawScreen
         | Action::Search
         | Action::FindInSelection
+        | Action::SetSearchScope
+        | Action::ClearSearchScope
         | Action::FindNext
         | Action::FindPrevious
         | Action::FindSelectionNext
@@ -3359,6 +3695,7 @@ pub fn action_to_events(
         | Action::UpdateFresh
         | Action::OpenUpdateLog
         | Action::FormatBuffer
+        | Action::FormatSelection
         | Action::TrimTrailingWhitespace
         | Action::EnsureFinalNewline
         | Action::OpenTerminal
@@ -3405,6 +3742,8 @@ pub fn action_to_events(
         | Action::RemoveRuler
         | Action::CompositeNextHunk
         | Action::CompositePrevHunk
+        | Action::CompareBuffers
+        | Action::DiffAgainstDisk
         | Action::WorkspaceTrustTrust
         | Action::WorkspaceTrustRestrict
         | Action::WorkspaceTrustBlock
@@ -4210,6 +4549,41 @@ mod tests {
         assert_eq!(line_53, "Line 0052\n");
     }
 
+    #[test]
+    fn test_move_line_down_middle_line_keeps_column() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("one\ntwo\nthree\n", 5, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::MoveLineDown);
+        assert_eq!(state.buffer.to_string().unwrap(), "one\nthree\ntwo\n");
+        // Still after the 't' of "two", now on line 2
+        assert_eq!(cursors.primary().position, 11);
+    }
+
+    /// A selected block moves as a unit and keeps its anchor before its caret.
+    #[test]
+    fn test_move_line_up_selected_block() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("a\nb\nc\nd\n", 4, VirtualSpaceMode::Off);
+        state.apply(
+            &mut cursors,
+            &Event::MoveCursor {
+                cursor_id: CursorId(0),
+                old_position: 4,
+                new_position: 7,
+                old_anchor: None,
+                new_anchor: Some(4),
+                old_sticky_column: None,
+                new_sticky_column: None,
+            },
+        );
+        run_action(&mut state, &mut cursors, Action::MoveLineUp);
+        assert_eq!(state.buffer.to_string().unwrap(), "a\nc\nd\nb\n");
+        assert_eq!(cursors.primary().anchor, Some(2));
+        assert_eq!(cursors.primary().position, 5);
+    }
+
     #[test]
     fn test_move_up_basic() {
         let mut state = EditorState::new(
@@ -6234,6 +6608,197 @@ mod tests {
 
         assert_eq!(state.buffer.to_string().unwrap(), "(bc)");
     }
+
+    #[test]
+    fn test_join_lines_collapses_indentation_to_one_space() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("foo\n  bar\nbaz", 1, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::JoinLines);
+        assert_eq!(state.buffer.to_string().unwrap(), "foo bar\nbaz");
+        assert_eq!(cursors.primary().position, 3);
+    }
+
+    #[test]
+    fn test_join_lines_strips_trailing_whitespace() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("foo   \n\tbar", 0, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::JoinLines);
+        assert_eq!(state.buffer.to_string().unwrap(), "foo bar");
+        assert_eq!(cursors.primary().position, 3);
+
+        // An empty line joins without a separating space
+        let (mut state, mut cursors) = virtual_space_state("foo\n\nbar", 0, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::JoinLines);
+        assert_eq!(state.buffer.to_string().unwrap(), "foo\nbar");
+    }
+
+    /// A selection joins every line it touches, leaving the cursor at the
+    /// last join point.
+    #[test]
+    fn test_join_lines_joins_all_selected_lines() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("a\n  b\n  c\nd\n", 0, VirtualSpaceMode::Off);
+        state.apply(
+            &mut cursors,
+            &Event::MoveCursor {
+                cursor_id: CursorId(0),
+                old_position: 0,
+                new_position: 9,
+                old_anchor: None,
+                new_anchor: Some(0),
+                old_sticky_column: None,
+                new_sticky_column: None,
+            },
+        );
+        run_action(&mut state, &mut cursors, Action::JoinLines);
+        assert_eq!(state.buffer.to_string().unwrap(), "a b c\nd\n");
+        assert_eq!(cursors.primary().position, 3);
+        assert_eq!(cursors.primary().anchor, None);
+    }
+
+    #[test]
+    fn test_join_lines_at_each_cursor() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("a\nb\nc\nd\n", 0, VirtualSpaceMode::Off);
+        cursors.add(Cursor::new(4));
+        run_action(&mut state, &mut cursors, Action::JoinLines);
+        assert_eq!(state.buffer.to_string().unwrap(), "a b\nc d\n");
+
+        // Joining the last line is a no-op
+        let (mut state, mut cursors) = virtual_space_state("a\nb\n", 2, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::JoinLines);
+        assert_eq!(state.buffer.to_string().unwrap(), "a\nb\n");
+    }
+
+    fn sort_lines(
+        content: &str,
+        selection: Option<Range<usize>>,
+        options: SortLinesOptions,
+    ) -> String {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) = virtual_space_state(content, 0, VirtualSpaceMode::Off);
+        if let Some(range) = selection {
+            state.apply(
+                &mut cursors,
+                &Event::MoveCursor {
+                    cursor_id: CursorId(0),
+                    old_position: 0,
+                    new_position: range.end,
+                    old_anchor: None,
+                    new_anchor: Some(range.start),
+                    old_sticky_column: None,
+                    new_sticky_column: None,
+                },
+            );
+        }
+        run_action(&mut state, &mut cursors, Action::SortLines(options));
+        state.buffer.to_string().unwrap()
+    }
+
+    #[test]
+    fn test_sort_lines_whole_buffer_without_selection() {
+        assert_eq!(
+            sort_lines("cherry\napple\nbanana\n", None, SortLinesOptions::default()),
+            "apple\nbanana\ncherry\n"
+        );
+        assert_eq!(
+            sort_lines("b\na", None, SortLinesOptions::default()),
+            "a\nb"
+        );
+    }
+
+    #[test]
+    fn test_sort_lines_numeric_and_reverse() {
+        let numeric = SortLinesOptions {
+            numeric: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            sort_lines("10 items\n2 items\n-1 debt\nnone\n", None, numeric),
+            "none\n-1 debt\n2 items\n10 items\n"
+        );
+        let reverse = SortLinesOptions {
+            reverse: true,
+            ..Default::default()
+        };
+        assert_eq!(sort_lines("a\nc\nb", None, reverse), "c\nb\na");
+        let case_insensitive = SortLinesOptions {
+            case_insensitive: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            sort_lines("Banana\napple\nCherry", None, case_insensitive),
+            "apple\nBanana\nCherry"
+        );
+    }
+
+    /// A selection starting and ending mid-line sorts the full lines it
+    /// touches and leaves the others alone.
+    #[test]
+    fn test_sort_lines_expands_selection_to_full_lines() {
+        let content = "first\nzebra\napple\nmango\nlast";
+        // From "bra" in "zebra" to "man" in "mango"
+        let selection = content.find("bra").unwrap()..content.find("go").unwrap();
+        assert_eq!(
+            sort_lines(content, Some(selection), SortLinesOptions::default()),
+            "first\napple\nmango\nzebra\nlast"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_selection_without_selection_copies_line() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) =
+            virtual_space_state("one\ntwo\nthree\n", 5, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::DuplicateSelection);
+        assert_eq!(state.buffer.to_string().unwrap(), "one\ntwo\ntwo\nthree\n");
+        // Same column, on the copy
+        assert_eq!(cursors.primary().position, 9);
+        assert_eq!(cursors.primary().anchor, None);
+    }
+
+    #[test]
+    fn test_duplicate_selection_last_line_without_newline() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) = virtual_space_state("a\nlast", 4, VirtualSpaceMode::Off);
+        run_action(&mut state, &mut cursors, Action::DuplicateSelection);
+        assert_eq!(state.buffer.to_string().unwrap(), "a\nlast\nlast");
+        assert_eq!(cursors.primary().position, 9);
+    }
+
+    #[test]
+    fn test_duplicate_selection_selects_the_copy() {
+        use crate::config::VirtualSpaceMode;
+        let (mut state, mut cursors) = virtual_space_state("foo bar baz", 4, VirtualSpaceMode::Off);
+        state.apply(
+            &mut cursors,
+            &Event::MoveCursor {
+                cursor_id: CursorId(0),
+                old_position: 4,
+                new_position: 7,
+                old_anchor: None,
+                new_anchor: Some(4),
+                old_sticky_column: None,
+                new_sticky_column: None,
+            },
+        );
+        let second = cursors.add(Cursor::new(0));
+        run_action(&mut state, &mut cursors, Action::DuplicateSelection);
+        assert_eq!(
+            state.buffer.to_string().unwrap(),
+            "foo barbar baz\nfoo bar baz"
+        );
+        // The copy of the selection is selected; the plain cursor keeps its
+        // column on the copy of its line
+        let selecting = cursors.get(CursorId(0)).unwrap();
+        assert_eq!(selecting.selection_range(), Some(7..10));
+        assert_eq!(selecting.position, 10);
+        assert_eq!(cursors.get(second).unwrap().position, 15);
+    }
 }
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use crate::input::commands::{get_all_commands, Command, CommandSource, Suggestio
 use crate::input::fuzzy::fuzzy_match;
 use crate::input::keybindings::Action;
 use crate::input::keybindings::KeyContext;
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 /// Registry for managing editor commands
@@ -23,18 +24,33 @@ pub struct CommandRegistry {
     /// Command usage history (most recent first)
     /// Used to sort command palette suggestions by recency
     command_history: Vec<String>,
+
+    /// How many times each command in `command_history` has been used.
+    /// Together with the history position this ranks the palette.
+    usage_counts: HashMap<String, u32>,
+
+    /// Action of the most recently executed command, run from the palette
+    /// or a key bound to a command. Replayed by `RepeatLastCommand`.
+    last_command: Option<Action>,
 }
 
 impl CommandRegistry {
     /// Maximum number of commands to keep in history
     const MAX_HISTORY_SIZE: usize = 50;
 
+    /// Largest fuzzy-score bonus usage can earn. Kept below a single
+    /// consecutive-match bonus so a frequently used command only wins over
+    /// matches of about the same quality.
+    const MAX_USAGE_BONUS: i32 = 12;
+
     /// Create a new command registry with built-in commands
     pub fn new() -> Self {
         Self {
             builtin_commands: get_all_commands(),
             plugin_commands: Arc::new(RwLock::new(Vec::new())),
             command_history: Vec::new(),
+            usage_counts: HashMap::new(),
+            last_command: None,
         }
     }
 
@@ -68,21 +84,88 @@ impl CommandRegistry {
 
     /// Record that a command was used (for history/sorting)
     ///
-    /// This moves the command to the front of the history list.
-    /// Recently used commands appear first in suggestions.
+    /// This moves the command to the front of the history list and bumps
+    /// its use count. Frequently and recently used commands appear first
+    /// in suggestions.
     pub fn record_usage(&mut self, command_name: &str) {
         // Remove existing entry if present
         self.command_history.retain(|name| name != command_name);
 
         // Add to front (most recent)
         self.command_history.insert(0, command_name.to_string());
+        *self
+            .usage_counts
+            .entry(command_name.to_string())
+            .or_insert(0) += 1;
 
         // Trim to max size
         if self.command_history.len() > Self::MAX_HISTORY_SIZE {
             self.command_history.truncate(Self::MAX_HISTORY_SIZE);
+            self.forget_unlisted_counts();
+        }
+    }
+
+    /// Command usage history, most recent first, with each command's use
+    /// count. Persisted per project in the workspace file.
+    pub fn usage(&self) -> (Vec<String>, HashMap<String, u32>) {
+        (self.command_history.clone(), self.usage_counts.clone())
+    }
+
+    /// Replace the usage history with one loaded from a workspace.
+    /// Commands missing from `counts` count as used once.
+    pub fn restore_usage(&mut self, history: Vec<String>, counts: HashMap<String, u32>) {
+        self.command_history = history;
+        self.command_history.truncate(Self::MAX_HISTORY_SIZE);
+        self.usage_counts = counts;
+        self.forget_unlisted_counts();
+    }
+
+    /// Drop use counts of commands that fell out of the history.
+    fn forget_unlisted_counts(&mut self) {
+        let history = &self.command_history;
+        self.usage_counts.retain(|name, _| history.contains(name));
+    }
+
+    /// Frecency of a command: its use count weighted by how recently it
+    /// was used. Zero for commands not in the history.
+    fn frecency(&self, command_name: &str) -> u32 {
+        let Some(position) = self.history_position(command_name) else {
+            return 0;
+        };
+        let count = self
+            .usage_counts
+            .get(command_name)
+            .copied()
+            .unwrap_or(1)
+            .max(1);
+        count.saturating_mul((Self::MAX_HISTORY_SIZE - position) as u32)
+    }
+
+    /// Record that the command running `action` was executed, so
+    /// `RepeatLastCommand` can run it again. Repeating does not replace the
+    /// command being repeated.
+    pub fn record_executed(&mut self, action: &Action) {
+        if *action != Action::RepeatLastCommand {
+            self.last_command = Some(action.clone());
         }
     }
 
+    /// Action of the most recently executed command, if any
+    pub fn last_command(&self) -> Option<Action> {
+        self.last_command.clone()
+    }
+
+    /// Whether `action` is run by a registered command (built-in or plugin)
+    pub fn is_command_action(&self, action: &Action) -> bool {
+        self.builtin_commands.iter().any(|c| c.action == *action)
+            || self
+                .plugin_commands
+                .read()
+                .unwrap()
+                .iter()
+                .any(|c| c.action == *action)
+    }
+
     /// Get the position of a command in history (0 = most recent)
     /// Returns None if command is not in history
     fn history_position(&self, command_name: &str) -> Option<usize> {
@@ -205,9 +288,10 @@ impl CommandRegistry {
 
     /// Filter commands by fuzzy matching query with context awareness
     ///
-    /// When query is empty, commands are sorted by recency (most recently used first).
-    /// When query is not empty, commands are sorted by match quality (fzf-style scoring)
-    /// with recency as tiebreaker for equal scores.
+    /// When query is empty, commands are sorted by frecency (frequently and recently
+    /// used first). When query is not empty, commands are sorted by match quality
+    /// (fzf-style scoring) plus a small frecency bonus, with frecency as tiebreaker
+    /// for equal scores.
     /// Disabled commands always appear after enabled ones.
     ///
     /// `has_lsp_config` indicates whether the active buffer's language has an LSP server
@@ -254,7 +338,9 @@ impl CommandRegistry {
         let make_suggestion =
             |cmd: &Command, score: i32, localized_name: String, localized_desc: String| {
                 let mut available = is_available(cmd);
-                if cmd.action == Action::FindInSelection && !selection_active {
+                if matches!(cmd.action, Action::FindInSelection | Action::SetSearchScope)
+                    && !selection_active
+                {
                     available = false;
                 }
                 // Disable LSP start/restart/toggle commands when no LSP is configured
@@ -265,20 +351,21 @@ impl CommandRegistry {
                 }
                 let keybinding = keybinding_resolver
                     .get_keybinding_for_action(&cmd.action, current_context_ref.clone());
-                let history_pos = self.history_position(&cmd.name);
+                let frecency = self.frecency(&cmd.name);
+                let bonus = (frecency / 25).min(Self::MAX_USAGE_BONUS as u32) as i32;
 
                 let suggestion = Suggestion::new(localized_name)
                     .with_description(localized_desc)
                     .set_disabled(!available)
                     .with_keybinding(keybinding)
                     .with_source(Some(cmd.source.clone()));
-                (suggestion, history_pos, score)
+                (suggestion, frecency, score + bonus)
             };
 
         // Match by name or description
         // Commands with unmet custom contexts are completely hidden
         // match_kind: 0 = name match, 1 = description match
-        let mut suggestions: Vec<(Suggestion, Option<usize>, i32, u8)> = commands
+        let mut suggestions: Vec<(Suggestion, u32, i32, u8)> = commands
             .iter()
             .filter(|cmd| is_visible(cmd))
             .filter_map(|cmd| {
@@ -308,11 +395,11 @@ impl CommandRegistry {
         // Sort by:
         // 1. Disabled status (enabled first)
         // 2. Match kind (name matches before description matches) - only when query is not empty
-        // 3. Fuzzy match score (higher is better) - only when query is not empty
-        // 4. History position (recent first, then never-used alphabetically)
+        // 3. Fuzzy match score plus usage bonus (higher is better) - only when query is not empty
+        // 4. Frecency (frequent and recent first, then never-used alphabetically)
         let has_query = !query.is_empty();
         suggestions.sort_by(
-            |(a, a_hist, a_score, a_kind), (b, b_hist, b_score, b_kind)| {
+            |(a, a_frecency, a_score, a_kind), (b, b_frecency, b_score, b_kind)| {
                 // First sort by disabled status
                 match a.disabled.cmp(&b.disabled) {
                     std::cmp::Ordering::Equal => {}
@@ -333,13 +420,9 @@ impl CommandRegistry {
                     }
                 }
 
-                // Then sort by history position (lower = more recent = better)
-                match (a_hist, b_hist) {
-                    (Some(a_pos), Some(b_pos)) => a_pos.cmp(b_pos),
-                    (Some(_), None) => std::cmp::Ordering::Less, // In history beats not in history
-                    (None, Some(_)) => std::cmp::Ordering::Greater,
-                    (None, None) => a.text.cmp(&b.text), // Alphabetical for never-used commands
-                }
+                // Then sort by frecency (higher = better); never-used commands
+                // all score zero and fall back to alphabetical order
+                b_frecency.cmp(a_frecency).then_with(|| a.text.cmp(&b.text))
             },
         );
 
@@ -681,6 +764,84 @@ mod tests {
         assert_eq!(registry.history_position("Open File"), Some(1));
     }
 
+    #[test]
+    fn test_used_command_ranks_above_equal_match() {
+        use crate::config::Config;
+        use crate::input::keybindings::KeybindingResolver;
+
+        let mut registry = CommandRegistry::new();
+        let keybindings = KeybindingResolver::new(&Config::default());
+        let empty_contexts = std::collections::HashSet::new();
+        let position = |registry: &CommandRegistry, name: &str| {
+            registry
+                .filter(
+                    "toggle line",
+                    KeyContext::Normal,
+                    &keybindings,
+                    false,
+                    &empty_contexts,
+                    None,
+                    true,
+                )
+                .iter()
+                .position(|s| s.text == name)
+                .unwrap()
+        };
+
+        // Never used: alphabetical among equal matches.
+        assert!(
+            position(&registry, "Toggle Line Numbers") < position(&registry, "Toggle Line Wrap")
+        );
+
+        registry.record_usage("Toggle Line Wrap");
+        assert!(
+            position(&registry, "Toggle Line Wrap") < position(&registry, "Toggle Line Numbers")
+        );
+    }
+
+    #[test]
+    fn test_frequent_command_outranks_recent_one() {
+        let mut registry = CommandRegistry::new();
+        for _ in 0..3 {
+            registry.record_usage("Save File");
+        }
+        registry.record_usage("Open File");
+
+        assert!(registry.frecency("Save File") > registry.frecency("Open File"));
+        assert_eq!(registry.frecency("Quit"), 0);
+    }
+
+    #[test]
+    fn test_restore_usage_round_trips() {
+        let mut registry = CommandRegistry::new();
+        registry.record_usage("Save File");
+        registry.record_usage("Save File");
+        registry.record_usage("Open File");
+        let (history, counts) = registry.usage();
+        assert_eq!(history, vec!["Open File", "Save File"]);
+        assert_eq!(counts.get("Save File"), Some(&2));
+
+        let mut restored = CommandRegistry::new();
+        // Counts for commands outside the history are dropped.
+        let mut stale = counts.clone();
+        stale.insert("Quit".to_string(), 7);
+        restored.restore_usage(history, stale);
+        assert_eq!(restored.usage(), registry.usage());
+    }
+
+    #[test]
+    fn test_record_executed_skips_repeat() {
+        let mut registry = CommandRegistry::new();
+        assert_eq!(registry.last_command(), None);
+
+        registry.record_executed(&Action::DuplicateLine);
+        registry.record_executed(&Action::RepeatLastCommand);
+        assert_eq!(registry.last_command(), Some(Action::DuplicateLine));
+
+        assert!(registry.is_command_action(&Action::DuplicateLine));
+        assert!(!registry.is_command_action(&Action::MoveDown));
+    }
+
     #[test]
     fn test_history_sorting() {
         use crate::config::Config;
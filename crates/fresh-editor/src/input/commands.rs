@@ -1,6 +1,6 @@
 //! Command palette system for executing editor actions by name
 
-use crate::input::keybindings::{Action, KeyContext};
+use crate::input::keybindings::{Action, KeyContext, SortLinesOptions};
 use crate::types::context_keys;
 use rust_i18n::t;
 
@@ -216,6 +216,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.open_scratch_buffer",
+        desc_key: "cmd.open_scratch_buffer_desc",
+        action: || Action::OpenScratchBuffer(String::new()),
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.close_buffer",
         desc_key: "cmd.close_buffer_desc",
@@ -251,6 +258,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.format_selection",
+        desc_key: "cmd.format_selection_desc",
+        action: || Action::FormatSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.trim_trailing_whitespace",
         desc_key: "cmd.trim_trailing_whitespace_desc",
@@ -294,6 +308,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.repeat_last_command",
+        desc_key: "cmd.repeat_last_command_desc",
+        action: || Action::RepeatLastCommand,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Edit operations
     CommandDef {
         name_key: "cmd.undo",
@@ -403,7 +424,46 @@ static COMMAND_DEFS: &[CommandDef] = &[
     CommandDef {
         name_key: "cmd.sort_lines",
         desc_key: "cmd.sort_lines_desc",
-        action: || Action::SortLines,
+        action: || Action::SortLines(SortLinesOptions::default()),
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.sort_lines_reverse",
+        desc_key: "cmd.sort_lines_reverse_desc",
+        action: || {
+            Action::SortLines(SortLinesOptions {
+                reverse: true,
+                case_insensitive: false,
+                numeric: false,
+            })
+        },
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.sort_lines_case_insensitive",
+        desc_key: "cmd.sort_lines_case_insensitive_desc",
+        action: || {
+            Action::SortLines(SortLinesOptions {
+                reverse: false,
+                case_insensitive: true,
+                numeric: false,
+            })
+        },
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.sort_lines_numeric",
+        desc_key: "cmd.sort_lines_numeric_desc",
+        action: || {
+            Action::SortLines(SortLinesOptions {
+                reverse: false,
+                case_insensitive: false,
+                numeric: true,
+            })
+        },
         contexts: &[Normal],
         custom_contexts: &[],
     },
@@ -421,6 +481,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.join_lines",
+        desc_key: "cmd.join_lines_desc",
+        action: || Action::JoinLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.duplicate_selection",
+        desc_key: "cmd.duplicate_selection_desc",
+        action: || Action::DuplicateSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.recenter",
         desc_key: "cmd.recenter_desc",
@@ -543,6 +617,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.buffer_switcher",
+        desc_key: "cmd.buffer_switcher_desc",
+        action: || Action::BufferSwitcher,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.compare_buffers",
+        desc_key: "cmd.compare_buffers_desc",
+        action: || Action::CompareBuffers,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.diff_against_disk",
+        desc_key: "cmd.diff_against_disk_desc",
+        action: || Action::DiffAgainstDisk,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Split operations
     CommandDef {
         name_key: "cmd.split_horizontal",
@@ -851,6 +946,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, FileExplorer, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_zen_mode",
+        desc_key: "cmd.toggle_zen_mode_desc",
+        action: || Action::ToggleZenMode,
+        contexts: &[Normal, FileExplorer, Terminal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_vertical_scrollbar",
         desc_key: "cmd.toggle_vertical_scrollbar_desc",
@@ -914,6 +1016,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[FileExplorer],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.explorer_undo_move",
+        desc_key: "cmd.explorer_undo_move_desc",
+        action: || Action::FileExplorerUndoMove,
+        contexts: &[FileExplorer],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.explorer_rename",
         desc_key: "cmd.explorer_rename_desc",
@@ -957,6 +1066,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_search_wrap",
+        desc_key: "cmd.toggle_search_wrap_desc",
+        action: || Action::ToggleSearchWrap,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_page_view",
         desc_key: "cmd.toggle_page_view_desc",
@@ -971,6 +1087,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_markdown_preview",
+        desc_key: "cmd.toggle_markdown_preview_desc",
+        action: || Action::ToggleMarkdownPreview,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_read_only",
         desc_key: "cmd.toggle_read_only_desc",
@@ -1007,6 +1130,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.set_search_scope",
+        desc_key: "cmd.set_search_scope_desc",
+        action: || Action::SetSearchScope,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.clear_search_scope",
+        desc_key: "cmd.clear_search_scope_desc",
+        action: || Action::ClearSearchScope,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.find_next",
         desc_key: "cmd.find_next_desc",
@@ -1092,6 +1229,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.goto_definition_in_split",
+        desc_key: "cmd.goto_definition_in_split_desc",
+        action: || Action::GotoDefinitionInSplit,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_hover_info",
         desc_key: "cmd.show_hover_info_desc",
@@ -1169,6 +1313,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.goto_last_edit",
+        desc_key: "cmd.goto_last_edit_desc",
+        action: || Action::GotoLastEdit,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Smart editing
     CommandDef {
         name_key: "cmd.toggle_comment",
@@ -1206,6 +1357,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.open_link_at_cursor",
+        desc_key: "cmd.open_link_at_cursor_desc",
+        action: || Action::OpenLinkAtCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.goto_file_under_cursor",
+        desc_key: "cmd.goto_file_under_cursor_desc",
+        action: || Action::GotoFileUnderCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // LSP
     CommandDef {
         name_key: "cmd.rename_symbol",
@@ -1307,6 +1472,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_message_log",
+        desc_key: "cmd.show_message_log_desc",
+        action: || Action::ShowMessageLog,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_lsp_status",
         desc_key: "cmd.show_lsp_status_desc",
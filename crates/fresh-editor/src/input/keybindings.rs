@@ -360,6 +360,19 @@ impl KeyContext {
     }
 }
 
+/// Ordering flags for [`Action::SortLines`], read from a binding's args
+/// (e.g. `{"numeric": true, "reverse": true}`). All off is a plain
+/// lexicographic sort.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SortLinesOptions {
+    /// Largest first
+    pub reverse: bool,
+    /// Compare lines ignoring letter case
+    pub case_insensitive: bool,
+    /// Compare by each line's leading number, so `2` sorts before `10`
+    pub numeric: bool,
+}
+
 /// High-level actions that can be performed in the editor
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Action {
@@ -413,6 +426,8 @@ pub enum Action {
     SelectWord,
     SelectLine,
     ExpandSelection,
+    SelectInside(char), // Select inside the text object named by the char (word, quote, bracket)
+    SelectAround(char), // Select the text object including its delimiters
 
     // Block/rectangular selection (column-wise)
     BlockSelectLeft,
@@ -432,6 +447,8 @@ pub enum Action {
     TransposeChars,
     OpenLine,
     DuplicateLine,
+    JoinLines,
+    DuplicateSelection,
 
     // View
     Recenter,
@@ -459,6 +476,22 @@ pub enum Action {
     YankToLineStart,
     YankViWordEnd, // Yank from cursor to end of word (vim ye)
 
+    // Built-in modal ("vim") keymap
+    ViNormalMode,           // Enter vim NORMAL mode (Esc)
+    ViInsertMode,           // Enter INSERT mode at the cursor (vim i)
+    ViAppend,               // Enter INSERT mode after the cursor (vim a)
+    ViAppendLineEnd,        // Enter INSERT mode at the end of the line (vim A)
+    ViInsertAtLineStart,    // Enter INSERT mode at the first non-blank (vim I)
+    ViOpenLineBelow,        // Open a line below and enter INSERT mode (vim o)
+    ViOpenLineAbove,        // Open a line above and enter INSERT mode (vim O)
+    ViVisualMode,           // Enter VISUAL mode, anchoring a selection (vim v)
+    ViVisualOperator(char), // Apply d/c/y to the VISUAL selection and leave VISUAL mode
+    ViOperator(char),       // Wait for a motion, then apply d/c/y over it (vim d{motion})
+    ViSubstituteLine,       // Clear the line's content and enter INSERT mode (vim cc)
+    ViChangeToLineEnd,      // Delete to the end of the line and enter INSERT mode (vim C)
+    ViCountDigit(char),     // Accumulate a count prefix digit (vim 3dw); `0` alone is line start
+    ViSurround(char), // `s{pair}` after an operator: ds( deletes, cs[ changes the surrounding pair
+
     // Multi-cursor
     AddCursorAbove,
     AddCursorBelow,
@@ -473,6 +506,9 @@ pub enum Action {
     Open,
     SwitchProject,
     New,
+    /// Open a numbered `*scratch*` buffer for quick notes, highlighted as
+    /// the given language (empty = plain text).
+    OpenScratchBuffer(String),
     Close,
     CloseTab,
     Quit,
@@ -481,6 +517,10 @@ pub enum Action {
     Revert,
     ToggleAutoRevert,
     FormatBuffer,
+    /// Format only the active selection via LSP `textDocument/rangeFormatting`,
+    /// falling back to `FormatBuffer` when there is no selection or the
+    /// server can't format ranges.
+    FormatSelection,
     TrimTrailingWhitespace,
     EnsureFinalNewline,
 
@@ -490,6 +530,12 @@ pub enum Action {
     GoToMatchingBracket,
     JumpToNextError,
     JumpToPreviousError,
+    /// Follow the link under the cursor: an LSP document link, a URL, or a
+    /// file path with an optional `:line[:col]` suffix.
+    OpenLinkAtCursor,
+    /// Open the file named under the cursor (Vim's `gf`), searching the
+    /// buffer's directory and the project root; honors `:line[:col]`.
+    GotoFileUnderCursor,
 
     // Smart editing
     SmartHome,
@@ -497,6 +543,9 @@ pub enum Action {
     ToggleComment,
     DabbrevExpand,
     ToggleFold,
+    SurroundAdd(char), // Wrap the selection (or word) in the pair named by the char
+    SurroundChange(char), // Replace the innermost enclosing pair with the named one
+    SurroundDelete(char), // Remove the enclosing pair named by the char
 
     // Bookmarks
     SetBookmark(char),
@@ -509,6 +558,8 @@ pub enum Action {
     ToggleSearchWholeWord,
     ToggleSearchRegex,
     ToggleSearchConfirmEach,
+    /// Toggle whether Find Next / Find Previous wrap around the buffer
+    ToggleSearchWrap,
 
     // Macros
     StartMacroRecording,
@@ -542,6 +593,7 @@ pub enum Action {
     ShowKeyboardShortcuts,
     ShowWarnings,
     ShowStatusLog,
+    ShowMessageLog,
     ShowLspStatus,
     ShowRemoteIndicatorMenu,
     ShowReadOnlyMenu,
@@ -551,6 +603,10 @@ pub enum Action {
     OpenUpdateLog,
     ClearWarnings,
     CommandPalette, // Alias for QuickOpen — kept for keymap/plugin compatibility
+    /// Re-run the most recent command run from the palette or a key
+    /// bound to a command. Unlike dot-repeat, which replays edits, this
+    /// repeats commands.
+    RepeatLastCommand,
     /// Quick Open - unified prompt with prefix-based provider routing
     QuickOpen,
     /// Quick Open - buffers (prefix: "#")
@@ -573,12 +629,16 @@ pub enum Action {
     /// current query under it. Plumbs through to the
     /// `live_grep_cycle_provider` plugin handler.
     CycleLiveGrepProvider,
+    /// Open the diagnostics panel in the Utility Dock. Plumbs through to
+    /// the `show_diagnostics_panel` plugin handler.
+    ShowDiagnosticsPanel,
     ToggleLineWrap,
     ToggleCurrentLineHighlight,
     ToggleOccurrenceHighlight,
     ToggleReadOnly,
     TogglePageView,
     SetPageWidth,
+    ToggleMarkdownPreview,
     InspectThemeAtCursor,
     SelectTheme,
     SelectKeybindingMap,
@@ -590,6 +650,8 @@ pub enum Action {
     PrevBuffer,
     SwitchToPreviousTab,
     SwitchToTabByName,
+    /// Pick a tab from a most-recently-used list (Ctrl+Tab)
+    BufferSwitcher,
 
     // Tab scrolling
     ScrollTabsLeft,
@@ -598,6 +660,8 @@ pub enum Action {
     // Position history navigation
     NavigateBack,
     NavigateForward,
+    /// Jump to the most recent edit location; repeat to go further back
+    GotoLastEdit,
 
     // Split view operations
     SplitHorizontal,
@@ -683,6 +747,8 @@ pub enum Action {
     ToggleStatusBar,
     // Prompt line visibility
     TogglePromptLine,
+    // Hide all chrome (menu, explorer, tabs, status) / restore it
+    ToggleZenMode,
     // Scrollbar visibility
     ToggleVerticalScrollbar,
     ToggleHorizontalScrollbar,
@@ -718,10 +784,14 @@ pub enum Action {
     FileExplorerExtendSelectionDown,
     FileExplorerToggleSelect,
     FileExplorerSelectAll,
+    /// Move the entry from the last explorer drag-and-drop back
+    FileExplorerUndoMove,
 
     // LSP operations
     LspCompletion,
     LspGotoDefinition,
+    /// Go to definition in another split, keeping the current view
+    GotoDefinitionInSplit,
     LspReferences,
     LspImplementation,
     LspRename,
@@ -776,6 +846,9 @@ pub enum Action {
     // Search and replace
     Search,
     FindInSelection,
+    /// Confine find and replace to the current selection until cleared
+    SetSearchScope,
+    ClearSearchScope,
     FindNext,
     FindPrevious,
     FindSelectionNext,     // Quick find next occurrence of selection (Ctrl+F3)
@@ -832,7 +905,8 @@ pub enum Action {
     ToUpperCase, // Convert selection to uppercase
     ToLowerCase, // Convert selection to lowercase
     ToggleCase,  // Toggle case of character under cursor (vim ~)
-    SortLines,   // Sort selected lines alphabetically
+    /// Sort the selected lines, or the whole buffer without a selection
+    SortLines(SortLinesOptions),
 
     // Input calibration
     CalibrateInput, // Open the input calibration wizard
@@ -857,6 +931,8 @@ pub enum Action {
     // Composite buffer (side-by-side diff) hunk navigation
     CompositeNextHunk, // Navigate to the next hunk in a composite diff view
     CompositePrevHunk, // Navigate to the previous hunk in a composite diff view
+    CompareBuffers,    // Pick two open buffers and diff them side by side
+    DiffAgainstDisk,   // Diff the active buffer against its file on disk
 
     // Workspace trust (per-project process-execution policy)
     WorkspaceTrustTrust,    // Trust this workspace: allow all process execution
@@ -1005,6 +1081,8 @@ impl Action {
             "transpose_chars" => TransposeChars,
             "open_line" => OpenLine,
             "duplicate_line" => DuplicateLine,
+            "join_lines" => JoinLines,
+            "duplicate_selection" => DuplicateSelection,
             "recenter" => Recenter,
             "set_mark" => SetMark,
             "cancel_mark" => CancelMark,
@@ -1022,6 +1100,17 @@ impl Action {
             "yank_to_line_start" => YankToLineStart,
             "yank_vi_word_end" => YankViWordEnd,
 
+            "vi_normal_mode" => ViNormalMode,
+            "vi_insert_mode" => ViInsertMode,
+            "vi_append" => ViAppend,
+            "vi_append_line_end" => ViAppendLineEnd,
+            "vi_insert_at_line_start" => ViInsertAtLineStart,
+            "vi_open_line_below" => ViOpenLineBelow,
+            "vi_open_line_above" => ViOpenLineAbove,
+            "vi_visual_mode" => ViVisualMode,
+            "vi_substitute_line" => ViSubstituteLine,
+            "vi_change_to_line_end" => ViChangeToLineEnd,
+
             "add_cursor_above" => AddCursorAbove,
             "add_cursor_below" => AddCursorBelow,
             "add_cursor_next_match" => AddCursorNextMatch,
@@ -1042,6 +1131,7 @@ impl Action {
             "revert" => Revert,
             "toggle_auto_revert" => ToggleAutoRevert,
             "format_buffer" => FormatBuffer,
+            "format_selection" => FormatSelection,
             "trim_trailing_whitespace" => TrimTrailingWhitespace,
             "ensure_final_newline" => EnsureFinalNewline,
             "goto_line" => GotoLine,
@@ -1049,6 +1139,8 @@ impl Action {
             "goto_matching_bracket" => GoToMatchingBracket,
             "jump_to_next_error" => JumpToNextError,
             "jump_to_previous_error" => JumpToPreviousError,
+            "open_link_at_cursor" => OpenLinkAtCursor,
+            "goto_file_under_cursor" => GotoFileUnderCursor,
 
             "smart_home" => SmartHome,
             "dedent_selection" => DedentSelection,
@@ -1062,6 +1154,7 @@ impl Action {
             "toggle_search_whole_word" => ToggleSearchWholeWord,
             "toggle_search_regex" => ToggleSearchRegex,
             "toggle_search_confirm_each" => ToggleSearchConfirmEach,
+            "toggle_search_wrap" => ToggleSearchWrap,
 
             "start_macro_recording" => StartMacroRecording,
             "stop_macro_recording" => StopMacroRecording,
@@ -1084,6 +1177,7 @@ impl Action {
             "keyboard_shortcuts" => ShowKeyboardShortcuts,
             "show_warnings" => ShowWarnings,
             "show_status_log" => ShowStatusLog,
+            "show_message_log" => ShowMessageLog,
             "show_lsp_status" => ShowLspStatus,
             "show_remote_indicator_menu" => ShowRemoteIndicatorMenu,
             "show_read_only_menu" => ShowReadOnlyMenu,
@@ -1091,6 +1185,7 @@ impl Action {
             "open_update_log" => OpenUpdateLog,
             "clear_warnings" => ClearWarnings,
             "command_palette" => CommandPalette,
+            "repeat_last_command" => RepeatLastCommand,
             "quick_open" => QuickOpen,
             "quick_open_buffers" => QuickOpenBuffers,
             "quick_open_files" => QuickOpenFiles,
@@ -1099,21 +1194,25 @@ impl Action {
             "toggle_utility_dock" => ToggleUtilityDock,
             "open_terminal_in_dock" => OpenTerminalInDock,
             "cycle_live_grep_provider" => CycleLiveGrepProvider,
+            "show_diagnostics_panel" => ShowDiagnosticsPanel,
             "toggle_line_wrap" => ToggleLineWrap,
             "toggle_current_line_highlight" => ToggleCurrentLineHighlight,
             "toggle_occurrence_highlight" => ToggleOccurrenceHighlight,
             "toggle_read_only" => ToggleReadOnly,
             "toggle_page_view" => TogglePageView,
             "set_page_width" => SetPageWidth,
+            "toggle_markdown_preview" => ToggleMarkdownPreview,
 
             "next_buffer" => NextBuffer,
             "prev_buffer" => PrevBuffer,
             "switch_to_previous_tab" => SwitchToPreviousTab,
+            "buffer_switcher" => BufferSwitcher,
             "switch_to_tab_by_name" => SwitchToTabByName,
             "scroll_tabs_left" => ScrollTabsLeft,
             "scroll_tabs_right" => ScrollTabsRight,
 
             "navigate_back" => NavigateBack,
+            "goto_last_edit" => GotoLastEdit,
             "navigate_forward" => NavigateForward,
 
             "split_horizontal" => SplitHorizontal,
@@ -1178,6 +1277,7 @@ impl Action {
             "toggle_tab_bar" => ToggleTabBar,
             "toggle_status_bar" => ToggleStatusBar,
             "toggle_prompt_line" => TogglePromptLine,
+            "toggle_zen_mode" => ToggleZenMode,
             "toggle_vertical_scrollbar" => ToggleVerticalScrollbar,
             "toggle_horizontal_scrollbar" => ToggleHorizontalScrollbar,
             "focus_file_explorer" => FocusFileExplorer,
@@ -1209,9 +1309,11 @@ impl Action {
             "file_explorer_extend_selection_down" => FileExplorerExtendSelectionDown,
             "file_explorer_toggle_select" => FileExplorerToggleSelect,
             "file_explorer_select_all" => FileExplorerSelectAll,
+            "file_explorer_undo_move" => FileExplorerUndoMove,
 
             "lsp_completion" => LspCompletion,
             "lsp_goto_definition" => LspGotoDefinition,
+            "goto_definition_in_split" => GotoDefinitionInSplit,
             "lsp_references" => LspReferences,
             "lsp_implementation" => LspImplementation,
             "lsp_rename" => LspRename,
@@ -1257,6 +1359,8 @@ impl Action {
 
             "search" => Search,
             "find_in_selection" => FindInSelection,
+            "set_search_scope" => SetSearchScope,
+            "clear_search_scope" => ClearSearchScope,
             "find_next" => FindNext,
             "find_previous" => FindPrevious,
             "find_selection_next" => FindSelectionNext,
@@ -1289,7 +1393,6 @@ impl Action {
             "to_upper_case" => ToUpperCase,
             "to_lower_case" => ToLowerCase,
             "toggle_case" => ToggleCase,
-            "sort_lines" => SortLines,
 
             "calibrate_input" => CalibrateInput,
             "event_debug" => EventDebug,
@@ -1302,6 +1405,8 @@ impl Action {
 
             "composite_next_hunk" => CompositeNextHunk,
             "composite_prev_hunk" => CompositePrevHunk,
+            "compare_buffers" => CompareBuffers,
+            "diff_against_disk" => DiffAgainstDisk,
 
             "workspace_trust_trust" => WorkspaceTrustTrust,
             "workspace_trust_restrict" => WorkspaceTrustRestrict,
@@ -1325,6 +1430,7 @@ impl Action {
         alias {
             "toggle_compose_mode" => TogglePageView,
             "set_compose_width" => SetPageWidth,
+            "edit_keybindings" => OpenKeybindingEditor,
             // Common synonym users reach for when trying to disable a
             // default binding (issue #2030). Without this alias,
             // `Action::from_str` returns `None` for `"none"` and the
@@ -1339,6 +1445,15 @@ impl Action {
             "play_macro" => PlayMacro,
             "toggle_macro_recording" => ToggleMacroRecording,
             "show_macro" => ShowMacro,
+            "vi_visual_operator" => ViVisualOperator,
+            "vi_operator" => ViOperator,
+            "vi_count_digit" => ViCountDigit,
+            "select_inside" => SelectInside,
+            "select_around" => SelectAround,
+            "surround_add" => SurroundAdd,
+            "surround_change" => SurroundChange,
+            "surround_delete" => SurroundDelete,
+            "vi_surround" => ViSurround,
         }
         custom {
             "copy_with_theme" => CopyWithTheme : {
@@ -1346,6 +1461,19 @@ impl Action {
                 let theme = args.get("theme").and_then(|v| v.as_str()).unwrap_or("");
                 Self::CopyWithTheme(theme.to_string())
             },
+            "sort_lines" => SortLines : {
+                let flag = |name: &str| args.get(name).and_then(|v| v.as_bool()).unwrap_or(false);
+                Self::SortLines(SortLinesOptions {
+                    reverse: flag("reverse"),
+                    case_insensitive: flag("case_insensitive"),
+                    numeric: flag("numeric"),
+                })
+            },
+            "open_scratch_buffer" => OpenScratchBuffer : {
+                // Empty language = plain text
+                let language = args.get("language").and_then(|v| v.as_str()).unwrap_or("");
+                Self::OpenScratchBuffer(language.to_string())
+            },
             "menu_open" => MenuOpen : {
                 let name = args.get("name")?.as_str()?;
                 Self::MenuOpen(name.to_string())
@@ -1409,8 +1537,8 @@ impl Action {
     /// `count` is always 1 — recorded macros carry one Action per step; the
     /// repeat-count sugar is a hand-authoring convenience, not something we
     /// emit. Payload variants populate `args` with the same keys `from_str`
-    /// reads (`char`, `text`, `theme`, `name`, `map`); everything else gets an
-    /// empty map.
+    /// reads (`char`, `text`, `theme`, `name`, `map`, the sort flags);
+    /// everything else gets an empty map.
     pub fn to_action_spec(&self) -> fresh_core::api::ActionSpec {
         use serde_json::Value;
         let mut args: HashMap<String, Value> = HashMap::new();
@@ -1422,13 +1550,36 @@ impl Action {
             | Self::ClearBookmark(c)
             | Self::PlayMacro(c)
             | Self::ToggleMacroRecording(c)
-            | Self::ShowMacro(c) => {
+            | Self::ShowMacro(c)
+            | Self::ViVisualOperator(c)
+            | Self::ViOperator(c)
+            | Self::ViCountDigit(c)
+            | Self::SelectInside(c)
+            | Self::SelectAround(c)
+            | Self::SurroundAdd(c)
+            | Self::SurroundChange(c)
+            | Self::SurroundDelete(c)
+            | Self::ViSurround(c) => {
                 args.insert("char".to_string(), Value::String(c.to_string()));
             }
             // `custom` family — each carries a distinct string-keyed arg.
             Self::CopyWithTheme(theme) => {
                 args.insert("theme".to_string(), Value::String(theme.clone()));
             }
+            Self::OpenScratchBuffer(language) => {
+                args.insert("language".to_string(), Value::String(language.clone()));
+            }
+            Self::SortLines(options) => {
+                for (key, on) in [
+                    ("reverse", options.reverse),
+                    ("case_insensitive", options.case_insensitive),
+                    ("numeric", options.numeric),
+                ] {
+                    if on {
+                        args.insert(key.to_string(), Value::Bool(true));
+                    }
+                }
+            }
             Self::MenuOpen(name) => {
                 args.insert("name".to_string(), Value::String(name.clone()));
             }
@@ -1532,6 +1683,8 @@ impl Action {
                 | Action::TransposeChars
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::JoinLines
+                | Action::DuplicateSelection
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 // Clipboard editing (but not Copy)
@@ -1562,6 +1715,8 @@ impl Action {
                 | Action::TransposeChars
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::JoinLines
+                | Action::DuplicateSelection
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 | Action::Cut
@@ -1570,6 +1725,173 @@ impl Action {
     }
 }
 
+/// Heading an action is listed under in the keyboard shortcuts cheat
+/// sheet. Variants are declared in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActionCategory {
+    Movement,
+    Selection,
+    Editing,
+    Search,
+    Files,
+    Navigation,
+    View,
+    Code,
+    Terminal,
+    FileExplorer,
+    Prompt,
+    Popup,
+    Menu,
+    Settings,
+    Plugins,
+    General,
+}
+
+impl ActionCategory {
+    /// Heading shown in the cheat sheet
+    pub fn label(&self) -> String {
+        let key = match self {
+            Self::Movement => "shortcuts.category.movement",
+            Self::Selection => "shortcuts.category.selection",
+            Self::Editing => "shortcuts.category.editing",
+            Self::Search => "shortcuts.category.search",
+            Self::Files => "shortcuts.category.files",
+            Self::Navigation => "shortcuts.category.navigation",
+            Self::View => "shortcuts.category.view",
+            Self::Code => "shortcuts.category.code",
+            Self::Terminal => "shortcuts.category.terminal",
+            Self::FileExplorer => "shortcuts.category.file_explorer",
+            Self::Prompt => "shortcuts.category.prompt",
+            Self::Popup => "shortcuts.category.popup",
+            Self::Menu => "shortcuts.category.menu",
+            Self::Settings => "shortcuts.category.settings",
+            Self::Plugins => "shortcuts.category.plugins",
+            Self::General => "shortcuts.category.general",
+        };
+        t!(key).to_string()
+    }
+}
+
+impl Action {
+    /// The cheat-sheet category of this action, derived from its action
+    /// name. Plugin actions are always `Plugins`.
+    pub fn category(&self) -> ActionCategory {
+        use ActionCategory::*;
+        if matches!(self, Action::PluginAction(_)) {
+            return Plugins;
+        }
+        if self.is_editing() {
+            return Editing;
+        }
+        let name = self.to_action_str();
+        let name = name.as_str();
+        let has = |part: &str| name.contains(part);
+        match name {
+            "save"
+            | "save_as"
+            | "save_all"
+            | "open"
+            | "new"
+            | "close"
+            | "quit"
+            | "force_quit"
+            | "detach"
+            | "revert"
+            | "toggle_auto_revert"
+            | "switch_project"
+            | "quick_open_files"
+            | "copy_file_path"
+            | "copy_relative_file_path"
+            | "goto_file_under_cursor"
+            | "set_encoding"
+            | "reload_with_encoding"
+            | "set_line_ending"
+            | "compare_buffers"
+            | "diff_against_disk" => Files,
+            "select_theme" | "select_keybinding_map" | "select_cursor_style" | "select_locale" => {
+                View
+            }
+            "goto_line" | "goto_matching_bracket" | "smart_home" | "recenter" => Movement,
+            "navigate_back" | "navigate_forward" | "goto_last_edit" | "quick_open_buffers" => {
+                Navigation
+            }
+            "format_buffer"
+            | "format_selection"
+            | "toggle_inlay_hints"
+            | "toggle_mouse_hover"
+            | "jump_to_next_error"
+            | "jump_to_previous_error"
+            | "show_diagnostics_panel"
+            | "toggle_fold" => Code,
+            "open_settings" | "close_settings" => Settings,
+            _ if has("bookmark") => Navigation,
+            _ if has("macro") => Editing,
+            _ if name.starts_with("prompt_") || name.starts_with("file_browser_") => Prompt,
+            _ if name.starts_with("popup_") || name.starts_with("completion_") => Popup,
+            _ if name.starts_with("menu_") => Menu,
+            _ if name.starts_with("settings_") => Settings,
+            _ if has("file_explorer") => FileExplorer,
+            _ if name.starts_with("lsp_") => Code,
+            _ if has("terminal") || has("keyboard_capture") => Terminal,
+            _ if has("search") || has("find") || has("replace") || has("live_grep") => Search,
+            _ if name.starts_with("select_")
+                || name.starts_with("block_select_")
+                || name.starts_with("vi_select_")
+                || name.starts_with("add_cursor")
+                || has("mark")
+                || name == "expand_selection"
+                || name == "remove_secondary_cursors" =>
+            {
+                Selection
+            }
+            _ if name.starts_with("move_")
+                || name.starts_with("vi_move_")
+                || name == "scroll_up"
+                || name == "scroll_down" =>
+            {
+                Movement
+            }
+            _ if name.starts_with("delete_")
+                || name.starts_with("insert_")
+                || name.starts_with("yank_")
+                || name.starts_with("surround_")
+                || name.starts_with("vi_")
+                || matches!(
+                    name,
+                    "copy"
+                        | "cut"
+                        | "paste"
+                        | "undo"
+                        | "redo"
+                        | "toggle_comment"
+                        | "dedent_selection"
+                        | "dabbrev_expand"
+                        | "to_upper_case"
+                        | "to_lower_case"
+                        | "toggle_case"
+                        | "sort_lines"
+                        | "trim_trailing_whitespace"
+                        | "ensure_final_newline"
+                        | "copy_with_theme"
+                ) =>
+            {
+                Editing
+            }
+            _ if name.starts_with("toggle_")
+                || name.starts_with("set_")
+                || has("ruler")
+                || name == "redraw_screen" =>
+            {
+                View
+            }
+            _ if has("split") || has("tab") || has("buffer") || has("window") || has("pane") => {
+                Navigation
+            }
+            _ => General,
+        }
+    }
+}
+
 /// Result of chord resolution
 #[derive(Debug, Clone, PartialEq)]
 pub enum ChordResolution {
@@ -1657,6 +1979,15 @@ impl KeybindingResolver {
         rebuilt.plugin_chord_defaults = std::mem::take(&mut self.plugin_chord_defaults);
         rebuilt.inheriting_modes = std::mem::take(&mut self.inheriting_modes);
         *self = rebuilt;
+        Self::warn_conflicts(config);
+    }
+
+    /// Log a warning for each conflict in the user's custom keybindings,
+    /// so it shows up in the status bar's warning indicator.
+    pub fn warn_conflicts(config: &Config) {
+        for conflict in config.detect_keybinding_conflicts() {
+            tracing::warn!("Keybinding conflict: {}", conflict);
+        }
     }
 
     /// Load default bindings from a vector of keybinding definitions (into default_bindings/default_chord_bindings)
@@ -1903,6 +2234,7 @@ impl KeybindingResolver {
                 // Tab/buffer navigation
                 | Action::NextBuffer
                 | Action::PrevBuffer
+                | Action::BufferSwitcher
                 | Action::Close
                 | Action::CloseTab
                 | Action::ScrollTabsLeft
@@ -1920,6 +2252,8 @@ impl KeybindingResolver {
                 | Action::ToggleFileExplorerSide
                 // Menu bar
                 | Action::ToggleMenuBar
+                // Zen mode
+                | Action::ToggleZenMode
         )
     }
 
@@ -1948,52 +2282,101 @@ impl KeybindingResolver {
             context
         );
 
-        // Check all chord binding sources in priority order
-        let search_order = vec![
+        let mut has_partial_match = false;
+
+        for (context_chords, label) in self.chord_sources(&context) {
+            // Check for exact match
+            if let Some(action) = context_chords.get(&full_sequence) {
+                tracing::trace!("  -> Complete chord match in {}: {:?}", label, action);
+                return ChordResolution::Complete(action.clone());
+            }
+
+            // Check for partial match (our sequence is a prefix of any binding)
+            for (chord_seq, _) in context_chords.iter() {
+                if chord_seq.len() > full_sequence.len()
+                    && chord_seq[..full_sequence.len()] == full_sequence[..]
+                {
+                    tracing::trace!("  -> Partial chord match in {}", label);
+                    has_partial_match = true;
+                    break;
+                }
+            }
+        }
+
+        if has_partial_match {
+            ChordResolution::Partial
+        } else {
+            tracing::trace!("  -> No chord match");
+            ChordResolution::NoMatch
+        }
+    }
+
+    /// Chord binding tables for `context`, in priority order.
+    fn chord_sources<'a>(
+        &'a self,
+        context: &KeyContext,
+    ) -> Vec<(
+        &'a HashMap<Vec<(KeyCode, KeyModifiers)>, Action>,
+        &'static str,
+    )> {
+        [
             (&self.chord_bindings, &KeyContext::Global, "custom global"),
             (
                 &self.default_chord_bindings,
                 &KeyContext::Global,
                 "default global",
             ),
-            (&self.chord_bindings, &context, "custom context"),
-            (&self.default_chord_bindings, &context, "default context"),
+            (&self.chord_bindings, context, "custom context"),
+            (&self.default_chord_bindings, context, "default context"),
             (
                 &self.plugin_chord_defaults,
-                &context,
+                context,
                 "plugin default context",
             ),
-        ];
-
-        let mut has_partial_match = false;
+        ]
+        .into_iter()
+        .filter_map(|(binding_map, bind_context, label)| {
+            binding_map.get(bind_context).map(|chords| (chords, label))
+        })
+        .collect()
+    }
 
-        for (binding_map, bind_context, label) in search_order {
-            if let Some(context_chords) = binding_map.get(bind_context) {
-                // Check for exact match
-                if let Some(action) = context_chords.get(&full_sequence) {
-                    tracing::trace!("  -> Complete chord match in {}: {:?}", label, action);
-                    return ChordResolution::Complete(action.clone());
+    /// The keys that can follow the pending chord `prefix` in `context`,
+    /// sorted by their display form. Each key maps to the action it
+    /// completes, or `None` when it only leads to a longer chord. Sources
+    /// are searched in the same order as `resolve_chord`, so a key listed
+    /// here runs the action shown.
+    pub fn chord_continuations(
+        &self,
+        prefix: &[(KeyCode, KeyModifiers)],
+        context: KeyContext,
+    ) -> Vec<((KeyCode, KeyModifiers), Option<Action>)> {
+        let prefix: Vec<(KeyCode, KeyModifiers)> =
+            prefix.iter().map(|(c, m)| normalize_key(*c, *m)).collect();
+        let mut next: Vec<((KeyCode, KeyModifiers), Option<Action>)> = Vec::new();
+
+        for (context_chords, _) in self.chord_sources(&context) {
+            for (chord_seq, action) in context_chords {
+                if chord_seq.len() <= prefix.len() || chord_seq[..prefix.len()] != prefix[..] {
+                    continue;
                 }
-
-                // Check for partial match (our sequence is a prefix of any binding)
-                for (chord_seq, _) in context_chords.iter() {
-                    if chord_seq.len() > full_sequence.len()
-                        && chord_seq[..full_sequence.len()] == full_sequence[..]
-                    {
-                        tracing::trace!("  -> Partial chord match in {}", label);
-                        has_partial_match = true;
-                        break;
+                let key = chord_seq[prefix.len()];
+                let completes = (chord_seq.len() == prefix.len() + 1).then(|| action.clone());
+                match next.iter_mut().find(|(k, _)| *k == key) {
+                    // The first exact match wins, even over a longer chord
+                    // found in an earlier source.
+                    Some((_, existing)) => {
+                        if existing.is_none() {
+                            *existing = completes;
+                        }
                     }
+                    None => next.push((key, completes)),
                 }
             }
         }
 
-        if has_partial_match {
-            ChordResolution::Partial
-        } else {
-            tracing::trace!("  -> No chord match");
-            ChordResolution::NoMatch
-        }
+        next.sort_by_cached_key(|((code, modifiers), _)| format_keybinding(code, modifiers));
+        next
     }
 
     /// Resolve a key event to an action in the given context
@@ -2492,6 +2875,52 @@ impl KeybindingResolver {
         bindings
     }
 
+    /// Every action bound outside buffer-local modes, with the keys bound
+    /// to it — single keys and chords, custom bindings overriding the
+    /// keymap's. Keys are formatted and sorted; actions come in no
+    /// particular order.
+    pub fn keys_by_action(&self) -> Vec<(Action, Vec<String>)> {
+        type Sequence = Vec<(KeyCode, KeyModifiers)>;
+        let mut effective: HashMap<(KeyContext, Sequence), Action> = HashMap::new();
+        for table in [&self.default_bindings, &self.bindings] {
+            for (context, bindings) in table {
+                for (key, action) in bindings {
+                    effective.insert((context.clone(), vec![*key]), action.clone());
+                }
+            }
+        }
+        for table in [&self.default_chord_bindings, &self.chord_bindings] {
+            for (context, chords) in table {
+                for (sequence, action) in chords {
+                    effective.insert((context.clone(), sequence.clone()), action.clone());
+                }
+            }
+        }
+
+        let mut by_action: Vec<(Action, Vec<String>)> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for ((context, sequence), action) in effective {
+            if matches!(context, KeyContext::Mode(_)) || action == Action::None {
+                continue;
+            }
+            let keys = sequence
+                .iter()
+                .map(|(code, modifiers)| Self::format_key(*code, *modifiers))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let slot = *index.entry(format!("{:?}", action)).or_insert_with(|| {
+                by_action.push((action, Vec::new()));
+                by_action.len() - 1
+            });
+            by_action[slot].1.push(keys);
+        }
+        for (_, keys) in &mut by_action {
+            keys.sort();
+            keys.dedup();
+        }
+        by_action
+    }
+
     /// Format a key combination as a readable string
     fn format_key(key_code: KeyCode, modifiers: KeyModifiers) -> String {
         format_keybinding(&key_code, &modifiers)
@@ -2544,6 +2973,8 @@ impl KeybindingResolver {
             Action::SelectAll => t!("action.select_all"),
             Action::SelectWord => t!("action.select_word"),
             Action::SelectLine => t!("action.select_line"),
+            Action::SelectInside(c) => t!("action.select_inside", key = c),
+            Action::SelectAround(c) => t!("action.select_around", key = c),
             Action::ExpandSelection => t!("action.expand_selection"),
             Action::BlockSelectLeft => t!("action.block_select_left"),
             Action::BlockSelectRight => t!("action.block_select_right"),
@@ -2560,6 +2991,8 @@ impl KeybindingResolver {
             Action::TransposeChars => t!("action.transpose_chars"),
             Action::OpenLine => t!("action.open_line"),
             Action::DuplicateLine => t!("action.duplicate_line"),
+            Action::JoinLines => t!("action.join_lines"),
+            Action::DuplicateSelection => t!("action.duplicate_selection"),
             Action::Recenter => t!("action.recenter"),
             Action::SetMark => t!("action.set_mark"),
             Action::CancelMark => t!("action.cancel_mark"),
@@ -2576,6 +3009,20 @@ impl KeybindingResolver {
             Action::YankToLineEnd => t!("action.yank_to_line_end"),
             Action::YankToLineStart => t!("action.yank_to_line_start"),
             Action::YankViWordEnd => t!("action.yank_word_forward"),
+            Action::ViNormalMode => t!("action.vi_normal_mode"),
+            Action::ViInsertMode => t!("action.vi_insert_mode"),
+            Action::ViAppend => t!("action.vi_append"),
+            Action::ViAppendLineEnd => t!("action.vi_append_line_end"),
+            Action::ViInsertAtLineStart => t!("action.vi_insert_at_line_start"),
+            Action::ViOpenLineBelow => t!("action.vi_open_line_below"),
+            Action::ViOpenLineAbove => t!("action.vi_open_line_above"),
+            Action::ViVisualMode => t!("action.vi_visual_mode"),
+            Action::ViVisualOperator(c) => t!("action.vi_visual_operator", key = c),
+            Action::ViOperator(c) => t!("action.vi_operator", key = c),
+            Action::ViSurround(c) => t!("action.vi_surround", key = c),
+            Action::ViSubstituteLine => t!("action.vi_substitute_line"),
+            Action::ViChangeToLineEnd => t!("action.vi_change_to_line_end"),
+            Action::ViCountDigit(c) => t!("action.vi_count_digit", key = c),
             Action::AddCursorAbove => t!("action.add_cursor_above"),
             Action::AddCursorBelow => t!("action.add_cursor_below"),
             Action::AddCursorNextMatch => t!("action.add_cursor_next_match"),
@@ -2587,6 +3034,15 @@ impl KeybindingResolver {
             Action::Open => t!("action.open"),
             Action::SwitchProject => t!("action.switch_project"),
             Action::New => t!("action.new"),
+            Action::OpenScratchBuffer(language) if language.is_empty() => {
+                t!("action.open_scratch_buffer")
+            }
+            Action::OpenScratchBuffer(language) => {
+                t!(
+                    "action.open_scratch_buffer_with_language",
+                    language = language
+                )
+            }
             Action::Close => t!("action.close"),
             Action::CloseTab => t!("action.close_tab"),
             Action::Quit => t!("action.quit"),
@@ -2595,6 +3051,7 @@ impl KeybindingResolver {
             Action::Revert => t!("action.revert"),
             Action::ToggleAutoRevert => t!("action.toggle_auto_revert"),
             Action::FormatBuffer => t!("action.format_buffer"),
+            Action::FormatSelection => t!("action.format_selection"),
             Action::TrimTrailingWhitespace => t!("action.trim_trailing_whitespace"),
             Action::EnsureFinalNewline => t!("action.ensure_final_newline"),
             Action::GotoLine => t!("action.goto_line"),
@@ -2602,9 +3059,14 @@ impl KeybindingResolver {
             Action::GoToMatchingBracket => t!("action.goto_matching_bracket"),
             Action::JumpToNextError => t!("action.jump_to_next_error"),
             Action::JumpToPreviousError => t!("action.jump_to_previous_error"),
+            Action::OpenLinkAtCursor => t!("action.open_link_at_cursor"),
+            Action::GotoFileUnderCursor => t!("action.goto_file_under_cursor"),
             Action::SmartHome => t!("action.smart_home"),
             Action::DedentSelection => t!("action.dedent_selection"),
             Action::ToggleComment => t!("action.toggle_comment"),
+            Action::SurroundAdd(c) => t!("action.surround_add", key = c),
+            Action::SurroundChange(c) => t!("action.surround_change", key = c),
+            Action::SurroundDelete(c) => t!("action.surround_delete", key = c),
             Action::DabbrevExpand => std::borrow::Cow::Borrowed("Expand abbreviation (dabbrev)"),
             Action::ToggleFold => t!("action.toggle_fold"),
             Action::SetBookmark(c) => t!("action.set_bookmark", key = c),
@@ -2615,6 +3077,7 @@ impl KeybindingResolver {
             Action::ToggleSearchWholeWord => t!("action.toggle_search_whole_word"),
             Action::ToggleSearchRegex => t!("action.toggle_search_regex"),
             Action::ToggleSearchConfirmEach => t!("action.toggle_search_confirm_each"),
+            Action::ToggleSearchWrap => t!("action.toggle_search_wrap"),
             Action::StartMacroRecording => t!("action.start_macro_recording"),
             Action::StopMacroRecording => t!("action.stop_macro_recording"),
             Action::PlayMacro(c) => t!("action.play_macro", key = c),
@@ -2636,6 +3099,7 @@ impl KeybindingResolver {
             Action::ShowKeyboardShortcuts => t!("action.show_keyboard_shortcuts"),
             Action::ShowWarnings => t!("action.show_warnings"),
             Action::ShowStatusLog => t!("action.show_status_log"),
+            Action::ShowMessageLog => t!("action.show_message_log"),
             Action::ShowLspStatus => t!("action.show_lsp_status"),
             Action::ShowRemoteIndicatorMenu => t!("action.show_remote_indicator_menu"),
             Action::ShowReadOnlyMenu => t!("action.show_read_only_menu"),
@@ -2643,6 +3107,7 @@ impl KeybindingResolver {
             Action::OpenUpdateLog => t!("action.open_update_log"),
             Action::ClearWarnings => t!("action.clear_warnings"),
             Action::CommandPalette => t!("action.command_palette"),
+            Action::RepeatLastCommand => t!("action.repeat_last_command"),
             Action::QuickOpen => t!("action.quick_open"),
             Action::QuickOpenBuffers => t!("action.quick_open_buffers"),
             Action::QuickOpenFiles => t!("action.quick_open_files"),
@@ -2651,6 +3116,7 @@ impl KeybindingResolver {
             Action::ToggleUtilityDock => t!("action.toggle_utility_dock"),
             Action::OpenTerminalInDock => t!("action.open_terminal_in_dock"),
             Action::CycleLiveGrepProvider => t!("action.cycle_live_grep_provider"),
+            Action::ShowDiagnosticsPanel => t!("action.show_diagnostics_panel"),
             Action::InspectThemeAtCursor => t!("action.inspect_theme_at_cursor"),
             Action::ToggleLineWrap => t!("action.toggle_line_wrap"),
             Action::ToggleCurrentLineHighlight => t!("action.toggle_current_line_highlight"),
@@ -2658,9 +3124,11 @@ impl KeybindingResolver {
             Action::ToggleReadOnly => t!("action.toggle_read_only"),
             Action::TogglePageView => t!("action.toggle_page_view"),
             Action::SetPageWidth => t!("action.set_page_width"),
+            Action::ToggleMarkdownPreview => t!("action.toggle_markdown_preview"),
             Action::NextBuffer => t!("action.next_buffer"),
             Action::PrevBuffer => t!("action.prev_buffer"),
             Action::NavigateBack => t!("action.navigate_back"),
+            Action::GotoLastEdit => t!("action.goto_last_edit"),
             Action::NavigateForward => t!("action.navigate_forward"),
             Action::SplitHorizontal => t!("action.split_horizontal"),
             Action::SplitVertical => t!("action.split_vertical"),
@@ -2725,6 +3193,7 @@ impl KeybindingResolver {
             Action::ToggleTabBar => t!("action.toggle_tab_bar"),
             Action::ToggleStatusBar => t!("action.toggle_status_bar"),
             Action::TogglePromptLine => t!("action.toggle_prompt_line"),
+            Action::ToggleZenMode => t!("action.toggle_zen_mode"),
             Action::ToggleVerticalScrollbar => t!("action.toggle_vertical_scrollbar"),
             Action::ToggleHorizontalScrollbar => t!("action.toggle_horizontal_scrollbar"),
             Action::FocusFileExplorer => t!("action.focus_file_explorer"),
@@ -2758,8 +3227,10 @@ impl KeybindingResolver {
             }
             Action::FileExplorerToggleSelect => t!("action.file_explorer_toggle_select"),
             Action::FileExplorerSelectAll => t!("action.file_explorer_select_all"),
+            Action::FileExplorerUndoMove => t!("action.file_explorer_undo_move"),
             Action::LspCompletion => t!("action.lsp_completion"),
             Action::LspGotoDefinition => t!("action.lsp_goto_definition"),
+            Action::GotoDefinitionInSplit => t!("action.goto_definition_in_split"),
             Action::LspReferences => t!("action.lsp_references"),
             Action::LspImplementation => t!("action.lsp_implementation"),
             Action::LspRename => t!("action.lsp_rename"),
@@ -2800,6 +3271,8 @@ impl KeybindingResolver {
             Action::RedrawScreen => t!("action.redraw_screen"),
             Action::Search => t!("action.search"),
             Action::FindInSelection => t!("action.find_in_selection"),
+            Action::SetSearchScope => t!("action.set_search_scope"),
+            Action::ClearSearchScope => t!("action.clear_search_scope"),
             Action::FindNext => t!("action.find_next"),
             Action::FindPrevious => t!("action.find_previous"),
             Action::FindSelectionNext => t!("action.find_selection_next"),
@@ -2824,6 +3297,7 @@ impl KeybindingResolver {
             Action::SelectCursorStyle => t!("action.select_cursor_style"),
             Action::SelectLocale => t!("action.select_locale"),
             Action::SwitchToPreviousTab => t!("action.switch_to_previous_tab"),
+            Action::BufferSwitcher => t!("action.buffer_switcher"),
             Action::SwitchToTabByName => t!("action.switch_to_tab_by_name"),
             Action::OpenTerminal => t!("action.open_terminal"),
             Action::OpenTerminalRight => t!("action.open_terminal_right"),
@@ -2850,7 +3324,7 @@ impl KeybindingResolver {
             Action::ToUpperCase => t!("action.to_uppercase"),
             Action::ToLowerCase => t!("action.to_lowercase"),
             Action::ToggleCase => t!("action.to_uppercase"),
-            Action::SortLines => t!("action.sort_lines"),
+            Action::SortLines(_) => t!("action.sort_lines"),
             Action::CalibrateInput => t!("action.calibrate_input"),
             Action::EventDebug => t!("action.event_debug"),
             Action::SuspendProcess => t!("action.suspend_process"),
@@ -2861,6 +3335,8 @@ impl KeybindingResolver {
             Action::OpenKeybindingEditor => "Keybinding Editor".into(),
             Action::CompositeNextHunk => t!("action.composite_next_hunk"),
             Action::CompositePrevHunk => t!("action.composite_prev_hunk"),
+            Action::CompareBuffers => t!("action.compare_buffers"),
+            Action::DiffAgainstDisk => t!("action.diff_against_disk"),
             Action::WorkspaceTrustTrust => t!("action.workspace_trust_trust"),
             Action::WorkspaceTrustRestrict => t!("action.workspace_trust_restrict"),
             Action::WorkspaceTrustBlock => t!("action.workspace_trust_block"),
@@ -3550,6 +4026,12 @@ mod tests {
             Action::MenuOpen("File".to_string()),
             Action::SwitchKeybindingMap("emacs".to_string()),
             Action::CopyWithTheme("one-dark".to_string()),
+            Action::SortLines(SortLinesOptions::default()),
+            Action::SortLines(SortLinesOptions {
+                reverse: true,
+                case_insensitive: false,
+                numeric: true,
+            }),
         ];
         for action in cases {
             let spec = action.to_action_spec();
@@ -4343,4 +4825,33 @@ mod tests {
             "inheriting-modes membership must survive reload_from_config"
         );
     }
+
+    #[test]
+    fn test_chord_continuations_lists_next_keys() {
+        let config = Config::default();
+        let mut resolver = KeybindingResolver::new(&config);
+        let mode_ctx = KeyContext::Mode("test-chords".to_string());
+        let g = (KeyCode::Char('g'), KeyModifiers::NONE);
+        let d = (KeyCode::Char('d'), KeyModifiers::NONE);
+        let z = (KeyCode::Char('z'), KeyModifiers::NONE);
+        let chord = |name: &str| Action::PluginAction(name.to_string());
+
+        resolver.load_plugin_chord_default(mode_ctx.clone(), vec![g, g], chord("top"));
+        resolver.load_plugin_chord_default(mode_ctx.clone(), vec![g, d], chord("definition"));
+        resolver.load_plugin_chord_default(mode_ctx.clone(), vec![g, z, z], chord("center"));
+
+        assert_eq!(
+            resolver.chord_continuations(&[g], mode_ctx.clone()),
+            vec![
+                (d, Some(chord("definition"))),
+                (g, Some(chord("top"))),
+                (z, None),
+            ]
+        );
+        assert_eq!(
+            resolver.chord_continuations(&[g, z], mode_ctx.clone()),
+            vec![(z, Some(chord("center")))]
+        );
+        assert!(resolver.chord_continuations(&[d], mode_ctx).is_empty());
+    }
 }
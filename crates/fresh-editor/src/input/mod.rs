@@ -14,8 +14,10 @@ pub mod key_translator;
 pub mod keybindings;
 mod line_move;
 pub mod multi_cursor;
+pub mod operator;
 pub mod position_history;
 pub mod quick_open;
+pub mod text_object;
 
 #[cfg(test)]
 pub mod tests_language_features;
@@ -0,0 +1,434 @@
+//! Operator-pending composition (`d{motion}`, `y{motion}`, `c{motion}`).
+//!
+//! An operator key doesn't act immediately: it leaves a [`PendingOperator`]
+//! on the window, and the next motion action resolves — via
+//! [`resolve_motion`] — the byte range the operator then acts on. Motions are
+//! the ordinary cursor-movement actions, reinterpreted through
+//! [`Motion::from_action`], so any keymap that binds an operator gets the
+//! full set of motions for free.
+
+use crate::input::actions::{find_paragraph_up, paragraph_down_selection_position};
+use crate::input::keybindings::Action;
+use crate::model::buffer::Buffer;
+use crate::primitives::word_navigation::{
+    find_vi_word_end, find_word_start_left, find_word_start_right,
+};
+use std::ops::Range;
+
+/// An editing verb waiting for a motion to tell it what to act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// Cut the range to the clipboard (vim `d`)
+    Delete,
+    /// Copy the range to the clipboard (vim `y`)
+    Yank,
+    /// Cut the range and start inserting in its place (vim `c`)
+    Change,
+}
+
+impl Operator {
+    /// Map an operator key (`d`, `y`, `c`) to its operator.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'd' => Some(Self::Delete),
+            'y' => Some(Self::Yank),
+            'c' => Some(Self::Change),
+            _ => None,
+        }
+    }
+}
+
+/// Operator state held between the operator key and its motion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingOperator {
+    pub operator: Operator,
+    /// Count typed before the operator (the `2` in `2dw`)
+    pub count: usize,
+}
+
+/// A cursor motion an operator can be applied over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// One grapheme left, within the line (`h`)
+    Left,
+    /// One grapheme right, within the line (`l`)
+    Right,
+    /// To the start of the next word (`w`)
+    WordForward,
+    /// To the start of the previous word (`b`)
+    WordBackward,
+    /// Through the end of the word, inclusive (`e`)
+    WordEnd,
+    /// To the start of the line (`0`)
+    LineStart,
+    /// To the end of the line, excluding the line ending (`$`)
+    LineEnd,
+    /// The current line and the lines below it (`j`); linewise
+    LineDown,
+    /// The current line and the lines above it (`k`); linewise
+    LineUp,
+    /// Whole lines starting at the cursor's line (the doubled operator, `dd`)
+    CurrentLine,
+    /// Through the first line of the buffer (`gg`); linewise
+    DocumentStart,
+    /// Through the last line of the buffer (`G`); linewise
+    DocumentEnd,
+    /// Back to the blank line before the paragraph (`{`)
+    ParagraphBackward,
+    /// Up to the blank line after the paragraph, or the buffer end (`}`)
+    ParagraphForward,
+}
+
+impl Motion {
+    /// Adjust the motion for the operator applying it. Like Vim, `cw` on a
+    /// word changes to the end of the word rather than eating the
+    /// whitespace after it.
+    pub fn for_operator(self, operator: Operator) -> Self {
+        match (operator, self) {
+            (Operator::Change, Self::WordForward) => Self::WordEnd,
+            _ => self,
+        }
+    }
+
+    /// The motion an action performs when it follows a pending operator, if
+    /// it is a motion at all.
+    pub fn from_action(action: &Action) -> Option<Self> {
+        Some(match action {
+            Action::MoveLeft | Action::MoveLeftInLine => Self::Left,
+            Action::MoveRight | Action::MoveRightInLine => Self::Right,
+            Action::MoveWordRight => Self::WordForward,
+            Action::MoveWordLeft => Self::WordBackward,
+            Action::MoveWordEnd | Action::ViMoveWordEnd => Self::WordEnd,
+            Action::MoveLineStart | Action::SmartHome => Self::LineStart,
+            Action::MoveLineEnd => Self::LineEnd,
+            Action::MoveDown | Action::ViMoveDown => Self::LineDown,
+            Action::MoveUp | Action::ViMoveUp => Self::LineUp,
+            Action::MoveDocumentStart => Self::DocumentStart,
+            Action::MoveDocumentEnd => Self::DocumentEnd,
+            Action::MoveToParagraphUp => Self::ParagraphBackward,
+            Action::MoveToParagraphDown => Self::ParagraphForward,
+            _ => return None,
+        })
+    }
+}
+
+/// The span a motion covers from the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MotionRange {
+    pub range: Range<usize>,
+    /// Whole lines, including the final line ending
+    pub linewise: bool,
+}
+
+/// Resolve `motion`, repeated `count` times, from `cursor` into the range an
+/// operator acts on. Returns `None` when the motion covers nothing (e.g. `dh`
+/// at the start of a line).
+pub fn resolve_motion(
+    buffer: &mut Buffer,
+    cursor: usize,
+    motion: Motion,
+    count: usize,
+    estimated_line_length: usize,
+) -> Option<MotionRange> {
+    let count = count.max(1);
+    let charwise = |range: Range<usize>| {
+        (range.start < range.end).then_some(MotionRange {
+            range,
+            linewise: false,
+        })
+    };
+    match motion {
+        Motion::Left => {
+            let (line_start, _) = line_at(buffer, cursor, estimated_line_length);
+            let mut start = cursor;
+            for _ in 0..count {
+                start = buffer.prev_grapheme_boundary(start).max(line_start);
+            }
+            charwise(start..cursor)
+        }
+        Motion::Right => {
+            let (line_start, content) = line_at(buffer, cursor, estimated_line_length);
+            let line_end = line_start + content_len(&content);
+            let mut end = cursor;
+            for _ in 0..count {
+                end = buffer.next_grapheme_boundary(end).min(line_end);
+            }
+            charwise(cursor..end)
+        }
+        Motion::WordForward => {
+            let mut end = cursor;
+            for _ in 0..count {
+                end = find_word_start_right(buffer, end);
+            }
+            charwise(cursor..end)
+        }
+        Motion::WordBackward => {
+            let mut start = cursor;
+            for _ in 0..count {
+                start = find_word_start_left(buffer, start);
+            }
+            charwise(start..cursor)
+        }
+        Motion::WordEnd => {
+            let mut end = cursor;
+            for _ in 0..count {
+                // `e` is inclusive of the word's last character
+                end = (find_vi_word_end(buffer, end) + 1).min(buffer.len());
+            }
+            charwise(cursor..end)
+        }
+        Motion::LineStart => {
+            let (line_start, _) = line_at(buffer, cursor, estimated_line_length);
+            charwise(line_start..cursor)
+        }
+        Motion::LineEnd => {
+            let (start, end) = lines_from(buffer, cursor, count, estimated_line_length);
+            let last = buffer
+                .line_iterator(end.saturating_sub(1).max(start), estimated_line_length)
+                .next_line();
+            let end = last.map_or(end, |(ls, content)| ls + content_len(&content));
+            charwise(cursor..end.max(cursor))
+        }
+        Motion::CurrentLine => {
+            let (start, end) = lines_from(buffer, cursor, count, estimated_line_length);
+            Some(linewise(start..end))
+        }
+        Motion::LineDown => {
+            let (start, end) = lines_from(buffer, cursor, count + 1, estimated_line_length);
+            Some(linewise(start..end))
+        }
+        Motion::LineUp => {
+            let (_, end) = lines_from(buffer, cursor, 1, estimated_line_length);
+            let mut iter = buffer.line_iterator(cursor, estimated_line_length);
+            let mut start = iter.current_position();
+            for _ in 0..count {
+                match iter.prev() {
+                    Some((line_start, _)) => start = line_start,
+                    None => break,
+                }
+            }
+            Some(linewise(start..end))
+        }
+        Motion::DocumentStart => {
+            let (_, end) = lines_from(buffer, cursor, 1, estimated_line_length);
+            Some(linewise(0..end))
+        }
+        Motion::DocumentEnd => {
+            let (start, _) = line_at(buffer, cursor, estimated_line_length);
+            let len = buffer.len();
+            Some(linewise(start..len))
+        }
+        Motion::ParagraphBackward => {
+            let mut start = cursor;
+            for _ in 0..count {
+                start = find_paragraph_up(buffer, start, estimated_line_length);
+            }
+            charwise(start..cursor)
+        }
+        Motion::ParagraphForward => {
+            let mut end = cursor;
+            for _ in 0..count {
+                end = paragraph_down_selection_position(buffer, end, estimated_line_length);
+            }
+            charwise(cursor..end)
+        }
+    }
+}
+
+/// Start offset and full content (line ending included) of the line
+/// containing `pos`.
+fn line_at(buffer: &mut Buffer, pos: usize, estimated_line_length: usize) -> (usize, String) {
+    let mut iter = buffer.line_iterator(pos, estimated_line_length);
+    let start = iter.current_position();
+    let content = iter.next_line().map(|(_, c)| c).unwrap_or_default();
+    (start, content)
+}
+
+/// Byte span of `count` whole lines starting with the one containing `pos`,
+/// line endings included. Stops early at the end of the buffer.
+fn lines_from(
+    buffer: &mut Buffer,
+    pos: usize,
+    count: usize,
+    estimated_line_length: usize,
+) -> (usize, usize) {
+    let len = buffer.len();
+    let mut iter = buffer.line_iterator(pos, estimated_line_length);
+    let start = iter.current_position();
+    let mut end = start;
+    for _ in 0..count {
+        match iter.next_line() {
+            Some((line_start, content)) if line_start < len || !content.is_empty() => {
+                end = line_start + content.len();
+            }
+            _ => break,
+        }
+    }
+    (start, end)
+}
+
+fn linewise(range: Range<usize>) -> MotionRange {
+    MotionRange {
+        range,
+        linewise: true,
+    }
+}
+
+/// Narrow or widen a resolved motion range for `operator`. Linewise deletes
+/// that run to the end of a buffer without a trailing newline take the line
+/// ending *before* them instead, so deleting the last line doesn't leave an
+/// empty line behind; linewise changes keep the final line ending so there
+/// is a line left to type on.
+pub fn operator_range(buffer: &Buffer, operator: Operator, motion: MotionRange) -> MotionRange {
+    let MotionRange {
+        mut range,
+        linewise,
+    } = motion;
+    if !linewise {
+        return MotionRange { range, linewise };
+    }
+    let trailing = line_ending_len(&buffer.slice_bytes(range.start..range.end));
+    match operator {
+        Operator::Delete if trailing == 0 && range.start > 0 => {
+            let before = buffer.slice_bytes(range.start.saturating_sub(2)..range.start);
+            range.start -= line_ending_len(&before);
+        }
+        Operator::Change => range.end -= trailing,
+        _ => {}
+    }
+    MotionRange { range, linewise }
+}
+
+/// Length of the line ending (`\n` or `\r\n`) `bytes` ends with, if any.
+fn line_ending_len(bytes: &[u8]) -> usize {
+    if bytes.ends_with(b"\r\n") {
+        2
+    } else if bytes.ends_with(b"\n") {
+        1
+    } else {
+        0
+    }
+}
+
+fn content_len(content: &str) -> usize {
+    content.trim_end_matches(['\n', '\r']).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE_LEN: usize = 80;
+
+    fn apply(
+        text: &str,
+        operator: Operator,
+        cursor: usize,
+        motion: Motion,
+        count: usize,
+    ) -> String {
+        let mut buffer = Buffer::from_str_test(text);
+        let motion = motion.for_operator(operator);
+        let Some(resolved) = resolve_motion(&mut buffer, cursor, motion, count, LINE_LEN) else {
+            return text.to_string();
+        };
+        let range = operator_range(&buffer, operator, resolved).range;
+        format!("{}{}", &text[..range.start], &text[range.end..])
+    }
+
+    fn delete(text: &str, cursor: usize, motion: Motion, count: usize) -> String {
+        apply(text, Operator::Delete, cursor, motion, count)
+    }
+
+    #[test]
+    fn operator_from_char() {
+        assert_eq!(Operator::from_char('d'), Some(Operator::Delete));
+        assert_eq!(Operator::from_char('y'), Some(Operator::Yank));
+        assert_eq!(Operator::from_char('c'), Some(Operator::Change));
+        assert_eq!(Operator::from_char('x'), None);
+    }
+
+    #[test]
+    fn delete_word_motions() {
+        assert_eq!(delete("foo bar baz", 0, Motion::WordForward, 1), "bar baz");
+        assert_eq!(delete("foo bar baz", 0, Motion::WordForward, 2), "baz");
+        assert_eq!(delete("foo bar baz", 4, Motion::WordEnd, 1), "foo  baz");
+        assert_eq!(delete("foo bar baz", 8, Motion::WordBackward, 1), "foo baz");
+    }
+
+    #[test]
+    fn delete_within_line_motions() {
+        assert_eq!(delete("hello world", 6, Motion::LineStart, 1), "world");
+        assert_eq!(
+            delete("hello world\nnext", 5, Motion::LineEnd, 1),
+            "hello\nnext"
+        );
+        assert_eq!(delete("abc", 1, Motion::Left, 1), "bc");
+        assert_eq!(delete("abc", 1, Motion::Right, 5), "a");
+        // Nothing to the left of column 0
+        assert_eq!(delete("a\nbc", 2, Motion::Left, 1), "a\nbc");
+    }
+
+    #[test]
+    fn delete_line_motions() {
+        let text = "one\ntwo\nthree\nfour\n";
+        assert_eq!(
+            delete(text, 5, Motion::CurrentLine, 1),
+            "one\nthree\nfour\n"
+        );
+        assert_eq!(delete(text, 5, Motion::CurrentLine, 2), "one\nfour\n");
+        assert_eq!(delete(text, 5, Motion::LineDown, 1), "one\nfour\n");
+        assert_eq!(delete(text, 5, Motion::LineUp, 1), "three\nfour\n");
+        assert_eq!(delete(text, 5, Motion::DocumentStart, 1), "three\nfour\n");
+        assert_eq!(delete(text, 5, Motion::DocumentEnd, 1), "one\n");
+    }
+
+    #[test]
+    fn delete_paragraph_motions() {
+        let text = "one\ntwo\n\nthree\nfour\n\nfive";
+        assert_eq!(
+            delete(text, 0, Motion::ParagraphForward, 1),
+            "\nthree\nfour\n\nfive"
+        );
+        assert_eq!(delete(text, 9, Motion::ParagraphForward, 2), "one\ntwo\n\n");
+        assert_eq!(
+            delete(text, 9, Motion::ParagraphBackward, 1),
+            "one\ntwo\nthree\nfour\n\nfive"
+        );
+    }
+
+    #[test]
+    fn delete_last_line_takes_preceding_newline() {
+        assert_eq!(delete("one\ntwo", 5, Motion::CurrentLine, 1), "one");
+        assert_eq!(delete("one\r\ntwo", 6, Motion::CurrentLine, 1), "one");
+        assert_eq!(delete("only", 0, Motion::CurrentLine, 1), "");
+    }
+
+    #[test]
+    fn change_keeps_line_and_trailing_whitespace() {
+        let change = |text, cursor, motion| apply(text, Operator::Change, cursor, motion, 1);
+        assert_eq!(change("foo bar", 0, Motion::WordForward), " bar");
+        assert_eq!(
+            change("one\ntwo\nthree", 4, Motion::CurrentLine),
+            "one\n\nthree"
+        );
+        assert_eq!(change("one\ntwo", 4, Motion::CurrentLine), "one\n");
+    }
+
+    #[test]
+    fn motion_from_action() {
+        assert_eq!(
+            Motion::from_action(&Action::MoveWordRight),
+            Some(Motion::WordForward)
+        );
+        assert_eq!(
+            Motion::from_action(&Action::ViMoveDown),
+            Some(Motion::LineDown)
+        );
+        assert_eq!(
+            Motion::from_action(&Action::MoveToParagraphDown),
+            Some(Motion::ParagraphForward)
+        );
+        assert_eq!(Motion::from_action(&Action::Paste), None);
+    }
+}
@@ -93,6 +93,7 @@ impl QuickOpenProvider for CommandProvider {
 
         if let Ok(mut reg) = self.command_registry.write() {
             reg.record_usage(&name);
+            reg.record_executed(&action);
         }
         QuickOpenResult::ExecuteAction(action)
     }
@@ -424,6 +425,66 @@ impl FileProvider {
         }
     }
 
+    /// Bring the cached file list up to date for one path (relative to
+    /// `cwd`) that was created, deleted, renamed or refreshed, instead of
+    /// re-indexing the whole project. Whatever the cache held at or under
+    /// the path is dropped; if a file exists there it is added back, and if
+    /// a directory does, just that subtree is walked.
+    ///
+    /// A no-op until the index has been built (and while it is being
+    /// built — the running scan sees the change itself). An empty path
+    /// means the whole project changed, so the cache is dropped and the
+    /// next open re-indexes.
+    pub fn refresh_path(&self, cwd: &str, relative_path: &str) {
+        use std::path::Path;
+
+        let relative_path = relative_path.trim_matches('/');
+        if relative_path.is_empty() {
+            self.clear_cache();
+            return;
+        }
+        let Ok(mut c) = self.cache.lock() else {
+            return;
+        };
+        if c.loading || c.loaded_cwd.as_deref() != Some(cwd) {
+            return;
+        }
+        let Some(files) = c.files.as_mut() else {
+            return;
+        };
+
+        let dir_prefix = format!("{}/", relative_path);
+        let files = std::sync::Arc::make_mut(files);
+        files.retain(|f| {
+            f.relative_path != relative_path && !f.relative_path.starts_with(&dir_prefix)
+        });
+
+        let absolute = Path::new(cwd).join(relative_path);
+        let mut added = Vec::new();
+        match self.filesystem.is_dir(&absolute) {
+            Ok(true) => {
+                let cancel = std::sync::atomic::AtomicBool::new(false);
+                drop(self.filesystem.walk_files(
+                    &absolute,
+                    IGNORED_DIRS,
+                    &cancel,
+                    &mut |_path, rel| {
+                        added.push(format!("{}{}", dir_prefix, rel));
+                        files.len() + added.len() < MAX_FILES
+                    },
+                ));
+            }
+            Ok(false) => added.push(relative_path.to_string()),
+            Err(_) => {}
+        }
+        for path in added {
+            files.push(FileEntry {
+                frecency_score: self.get_frecency_score(&path),
+                relative_path: path,
+            });
+        }
+    }
+
     /// Returns `true` if a background file scan is in progress.
     fn is_loading(&self) -> bool {
         self.cache.lock().is_ok_and(|c| c.loading)
@@ -1378,6 +1439,46 @@ mod tests {
         assert_eq!(suggestions[0].value.as_deref(), Some("main.rs"));
     }
 
+    #[test]
+    fn test_file_provider_refresh_path_updates_index_incrementally() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        let cwd = base.display().to_string();
+
+        std::fs::create_dir(base.join("src")).unwrap();
+        std::fs::write(base.join("src").join("main.rs"), b"").unwrap();
+
+        let provider = make_file_provider();
+        let context = make_test_context(&cwd);
+        let values = |query: &str| -> Vec<String> {
+            provider
+                .suggestions(query, &context)
+                .into_iter()
+                .filter(|s| !s.disabled)
+                .filter_map(|s| s.value)
+                .collect()
+        };
+        // First use builds the index.
+        assert_eq!(values("main"), vec!["src/main.rs"]);
+
+        // A new file shows up once its path is refreshed, fuzzy-matched
+        // by an abbreviation of its name.
+        std::fs::write(base.join("src").join("request_handler.rs"), b"").unwrap();
+        assert!(values("reqhan").is_empty());
+        provider.refresh_path(&cwd, "src/request_handler.rs");
+        assert_eq!(values("reqhan"), vec!["src/request_handler.rs"]);
+
+        // A new directory is walked; a deleted file disappears.
+        std::fs::create_dir(base.join("docs")).unwrap();
+        std::fs::write(base.join("docs").join("guide.md"), b"").unwrap();
+        provider.refresh_path(&cwd, "docs");
+        assert_eq!(values("guide"), vec!["docs/guide.md"]);
+
+        std::fs::remove_file(base.join("src").join("main.rs")).unwrap();
+        provider.refresh_path(&cwd, "src/main.rs");
+        assert!(values("main").is_empty());
+    }
+
     #[test]
     fn test_file_provider_empty_dir() {
         let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,411 @@
+//! Text objects: the word, quoted string or bracketed block around the
+//! cursor (vim's `iw`, `a"`, `i{`, ...).
+//!
+//! [`text_object`] resolves one into a byte range. Operators act on that
+//! range the same way they act on a motion's, and the `select_inside` /
+//! `select_around` actions turn it into a selection. The surround actions
+//! use [`surrounding_delimiters`] to find the quotes or brackets to edit.
+
+use crate::model::buffer::Buffer;
+use crate::primitives::grapheme::{next_grapheme_boundary, prev_grapheme_boundary};
+use crate::primitives::word_navigation::{get_grapheme_class, CharClass};
+use crate::view::bracket_highlight_overlay::{
+    find_matching_bracket, pos_in_ranges, MAX_BRACKET_SEARCH_BYTES,
+};
+use std::ops::Range;
+
+/// Chunk size for the line-boundary scans.
+const LINE_SCAN_CHUNK: usize = 4 * 1024;
+
+/// What a text object selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextObject {
+    /// A run of word characters, punctuation or whitespace (`w`)
+    Word,
+    /// A string delimited by the given quote character on the cursor's
+    /// line (`"`, `'`, `` ` ``)
+    Quote(char),
+    /// A block delimited by a bracket pair, respecting nesting (`(`, `[`,
+    /// `{`, `<`, and the `b` / `B` aliases for parens and braces)
+    Bracket { open: char, close: char },
+}
+
+impl TextObject {
+    /// Map the key typed after `i` / `a` to its text object.
+    pub fn from_char(c: char) -> Option<Self> {
+        let bracket = |open, close| Some(Self::Bracket { open, close });
+        match c {
+            'w' => Some(Self::Word),
+            '"' | '\'' | '`' => Some(Self::Quote(c)),
+            '(' | ')' | 'b' => bracket('(', ')'),
+            '[' | ']' => bracket('[', ']'),
+            '{' | '}' | 'B' => bracket('{', '}'),
+            '<' | '>' => bracket('<', '>'),
+            _ => None,
+        }
+    }
+
+    /// The opening and closing delimiters of a quote or bracket object.
+    pub fn delimiters(self) -> Option<(char, char)> {
+        match self {
+            Self::Word => None,
+            Self::Quote(quote) => Some((quote, quote)),
+            Self::Bracket { open, close } => Some((open, close)),
+        }
+    }
+}
+
+/// Resolve the text object `kind` around `cursor`. The inner object
+/// excludes the delimiters (for words: the surrounding whitespace); `around`
+/// includes them, plus trailing (or, failing that, leading) whitespace for
+/// words and quoted strings.
+///
+/// Brackets inside `skip_ranges` — the comment and string spans reported by
+/// the highlighter, sorted by start — don't count as structure, unless the
+/// cursor is itself inside one of those spans. Returns `None` when the
+/// cursor isn't in such an object.
+pub fn text_object(
+    kind: TextObject,
+    around: bool,
+    cursor: usize,
+    buffer: &Buffer,
+    skip_ranges: &[Range<usize>],
+) -> Option<Range<usize>> {
+    match kind {
+        TextObject::Word => word_object(buffer, cursor, around),
+        TextObject::Quote(quote) => quote_object(buffer, cursor, quote, around),
+        TextObject::Bracket { open, close } => {
+            let skip_ranges = structural_skip_ranges(skip_ranges, cursor);
+            bracket_object(buffer, cursor, open, close, around, skip_ranges)
+        }
+    }
+}
+
+/// Byte offsets of the opening and closing delimiter of the quote or
+/// bracket object `kind` around `cursor`; `None` for words or when there is
+/// no such object. `skip_ranges` is as for [`text_object`].
+pub fn surrounding_delimiters(
+    kind: TextObject,
+    cursor: usize,
+    buffer: &Buffer,
+    skip_ranges: &[Range<usize>],
+) -> Option<(usize, usize)> {
+    match kind {
+        TextObject::Word => None,
+        TextObject::Quote(quote) => {
+            let (line_start, text) = line_text(buffer, cursor)?;
+            let (open, close) = quote_pair(&text, cursor - line_start, quote)?;
+            Some((line_start + open, line_start + close))
+        }
+        TextObject::Bracket { open, close } => {
+            let skip_ranges = structural_skip_ranges(skip_ranges, cursor);
+            bracket_pair(buffer, cursor, open, close, skip_ranges)
+        }
+    }
+}
+
+/// Inside a comment or string, brackets in it are the structure that
+/// matters, so skip nothing.
+fn structural_skip_ranges(skip_ranges: &[Range<usize>], cursor: usize) -> &[Range<usize>] {
+    if pos_in_ranges(skip_ranges, cursor) {
+        &[]
+    } else {
+        skip_ranges
+    }
+}
+
+fn word_object(buffer: &Buffer, cursor: usize, around: bool) -> Option<Range<usize>> {
+    let (line_start, text) = line_text(buffer, cursor)?;
+    if text.is_empty() {
+        return None;
+    }
+    let mut idx = (cursor - line_start).min(text.len());
+    if idx == text.len() {
+        idx = prev_grapheme_boundary(&text, idx);
+    }
+    let class_at = |i: usize| get_grapheme_class(&text[i..next_grapheme_boundary(&text, i)]);
+    let class = class_at(idx);
+
+    let run_start = |mut i: usize, class: CharClass| {
+        while i > 0 {
+            let prev = prev_grapheme_boundary(&text, i);
+            if class_at(prev) != class {
+                break;
+            }
+            i = prev;
+        }
+        i
+    };
+    let run_end = |mut i: usize, class: CharClass| {
+        while i < text.len() && class_at(i) == class {
+            i = next_grapheme_boundary(&text, i);
+        }
+        i
+    };
+
+    let mut start = run_start(idx, class);
+    let mut end = run_end(idx, class);
+    if around {
+        if class == CharClass::Whitespace {
+            // Whitespace plus the word after it
+            if end < text.len() {
+                end = run_end(end, class_at(end));
+            }
+        } else if end < text.len() && class_at(end) == CharClass::Whitespace {
+            end = run_end(end, CharClass::Whitespace);
+        } else {
+            start = run_start(start, CharClass::Whitespace);
+        }
+    }
+    Some(line_start + start..line_start + end)
+}
+
+fn quote_object(buffer: &Buffer, cursor: usize, quote: char, around: bool) -> Option<Range<usize>> {
+    let (line_start, text) = line_text(buffer, cursor)?;
+    let (open, close) = quote_pair(&text, cursor - line_start, quote)?;
+
+    if !around {
+        return Some(line_start + open + quote.len_utf8()..line_start + close);
+    }
+    let mut start = open;
+    let mut end = close + quote.len_utf8();
+    let trailing = text[end..].len() - text[end..].trim_start_matches([' ', '\t']).len();
+    if trailing > 0 {
+        end += trailing;
+    } else {
+        start = text[..start].trim_end_matches([' ', '\t']).len();
+    }
+    Some(line_start + start..line_start + end)
+}
+
+/// Offsets within `text` (one line) of the `quote` pair containing `idx`,
+/// or else of the first pair after it.
+fn quote_pair(text: &str, idx: usize, quote: char) -> Option<(usize, usize)> {
+    // Quotes pair up left to right; a backslash escapes the next character.
+    let mut quotes = Vec::new();
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            quotes.push(i);
+        }
+    }
+    quotes
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .find(|&(_, close)| idx <= close)
+}
+
+fn bracket_object(
+    buffer: &Buffer,
+    cursor: usize,
+    open: char,
+    close: char,
+    around: bool,
+    skip_ranges: &[Range<usize>],
+) -> Option<Range<usize>> {
+    let (open_pos, close_pos) = bracket_pair(buffer, cursor, open, close, skip_ranges)?;
+    if around {
+        return Some(open_pos..close_pos + 1);
+    }
+
+    // A block whose brackets sit on their own lines is inner up to, but not
+    // including, those lines, so `di{` leaves `{` and `}` in place.
+    let mut start = open_pos + 1;
+    let mut end = close_pos;
+    let body = buffer.slice_bytes(start..end);
+    if body.starts_with(b"\r\n") {
+        start += 2;
+    } else if body.starts_with(b"\n") {
+        start += 1;
+    }
+    if let Some(newline) = body.iter().rposition(|&b| b == b'\n') {
+        let indent_only = body[newline + 1..].iter().all(|&b| b == b' ' || b == b'\t');
+        if indent_only && open_pos + 1 + newline + 1 >= start {
+            end = open_pos + 1 + newline + 1;
+        }
+    }
+    Some(start..end.max(start))
+}
+
+/// Offsets of the innermost `open`/`close` pair enclosing `cursor` (or
+/// under it).
+fn bracket_pair(
+    buffer: &Buffer,
+    cursor: usize,
+    open: char,
+    close: char,
+    skip_ranges: &[Range<usize>],
+) -> Option<(usize, usize)> {
+    let on_open = buffer.slice_bytes(cursor..cursor + 1) == [open as u8]
+        && !pos_in_ranges(skip_ranges, cursor);
+    let open_pos = if on_open {
+        cursor
+    } else {
+        // Scanning back from the cursor with depth 1 finds the innermost
+        // unmatched opening bracket, which is also the partner of a closing
+        // bracket under the cursor.
+        find_matching_bracket(buffer, cursor, open, close, false, skip_ranges)?
+    };
+    let close_pos = find_matching_bracket(buffer, open_pos, open, close, true, skip_ranges)?;
+    Some((open_pos, close_pos))
+}
+
+/// Start offset and text (without line ending) of the line containing
+/// `pos`, or `None` if it isn't valid UTF-8.
+fn line_text(buffer: &Buffer, pos: usize) -> Option<(usize, String)> {
+    let len = buffer.len();
+    let pos = pos.min(len);
+
+    let limit = pos.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
+    let mut start = pos;
+    while start > limit {
+        let chunk_start = start.saturating_sub(LINE_SCAN_CHUNK).max(limit);
+        let chunk = buffer.slice_bytes(chunk_start..start);
+        if let Some(i) = chunk.iter().rposition(|&b| b == b'\n') {
+            start = chunk_start + i + 1;
+            break;
+        }
+        start = chunk_start;
+    }
+
+    let limit = (pos + MAX_BRACKET_SEARCH_BYTES).min(len);
+    let mut end = pos;
+    while end < limit {
+        let chunk_end = (end + LINE_SCAN_CHUNK).min(limit);
+        let chunk = buffer.slice_bytes(end..chunk_end);
+        if let Some(i) = chunk.iter().position(|&b| b == b'\n') {
+            end += i;
+            break;
+        }
+        end = chunk_end;
+    }
+
+    let mut text = String::from_utf8(buffer.slice_bytes(start..end)).ok()?;
+    if text.ends_with('\r') {
+        text.pop();
+    }
+    Some((start, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The text selected by `object` with the cursor on the first `|`
+    /// (which is removed from `text`).
+    fn select(text: &str, object: char, around: bool) -> Option<String> {
+        let cursor = text.find('|').expect("cursor marker");
+        let text = text.replacen('|', "", 1);
+        let buffer = Buffer::from_str_test(&text);
+        let kind = TextObject::from_char(object).expect("known object");
+        text_object(kind, around, cursor, &buffer, &[]).map(|range| text[range].to_string())
+    }
+
+    #[test]
+    fn inner_quotes() {
+        assert_eq!(
+            select(r#"say "he|llo" now"#, '"', false).as_deref(),
+            Some("hello")
+        );
+        // On either quote character
+        assert_eq!(
+            select(r#"say |"hello" now"#, '"', false).as_deref(),
+            Some("hello")
+        );
+        assert_eq!(
+            select(r#"say "hello|" now"#, '"', false).as_deref(),
+            Some("hello")
+        );
+        // Before the first quote: the next string on the line
+        assert_eq!(
+            select(r#"s|ay "hello" now"#, '"', false).as_deref(),
+            Some("hello")
+        );
+        // Escaped quotes don't close the string
+        assert_eq!(
+            select(r#"x = "a \"b|\" c";"#, '"', false).as_deref(),
+            Some(r#"a \"b\" c"#)
+        );
+        assert_eq!(select(r#"no "quo|tes"#, '"', false), None);
+    }
+
+    #[test]
+    fn around_quotes_takes_trailing_whitespace() {
+        assert_eq!(
+            select(r#"say 'he|llo'  now"#, '\'', true).as_deref(),
+            Some("'hello'  ")
+        );
+        assert_eq!(
+            select(r#"say 'he|llo'"#, '\'', true).as_deref(),
+            Some(" 'hello'")
+        );
+    }
+
+    #[test]
+    fn around_parens() {
+        assert_eq!(select("f(a, |b)", '(', true).as_deref(), Some("(a, b)"));
+        assert_eq!(select("f(a, (b|))", 'b', true).as_deref(), Some("(b)"));
+        // On the closing bracket
+        assert_eq!(select("f(a, (b)|)", ')', true).as_deref(), Some("(a, (b))"));
+        assert_eq!(select("f(a) |x (b)", '(', true), None);
+    }
+
+    #[test]
+    fn nested_inner_braces() {
+        let text = "fn f() { if x { y| } else { z } }";
+        assert_eq!(select(text, '{', false).as_deref(), Some(" y "));
+        let text = "fn f() { if |x { y } else { z } }";
+        assert_eq!(
+            select(text, '{', false).as_deref(),
+            Some(" if x { y } else { z } ")
+        );
+        // Multi-line blocks keep the lines holding the braces.
+        let text = "{\n    a|;\n    { b }\n}";
+        assert_eq!(
+            select(text, 'B', false).as_deref(),
+            Some("    a;\n    { b }\n")
+        );
+    }
+
+    #[test]
+    fn brackets_in_skip_ranges_are_ignored() {
+        // `( ")" x )` with the string at 2..5: the `)` inside it doesn't
+        // close the block.
+        let text = r#"( ")" x )"#;
+        let buffer = Buffer::from_str_test(text);
+        let kind = TextObject::from_char('(').unwrap();
+        let range = text_object(kind, true, 6, &buffer, &[2..5]);
+        assert_eq!(range, Some(0..text.len()));
+    }
+
+    #[test]
+    fn surrounding_delimiter_offsets() {
+        let buffer = Buffer::from_str_test(r#"f("a", [b])"#);
+        let delimiters = |object, cursor| {
+            let kind = TextObject::from_char(object).unwrap();
+            surrounding_delimiters(kind, cursor, &buffer, &[])
+        };
+        assert_eq!(delimiters('"', 3), Some((2, 4)));
+        assert_eq!(delimiters('(', 3), Some((1, 10)));
+        assert_eq!(delimiters('[', 8), Some((7, 9)));
+        assert_eq!(delimiters('w', 3), None);
+        assert_eq!(
+            TextObject::from_char('B').unwrap().delimiters(),
+            Some(('{', '}'))
+        );
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(select("foo ba|r baz", 'w', false).as_deref(), Some("bar"));
+        assert_eq!(select("foo ba|r baz", 'w', true).as_deref(), Some("bar "));
+        // The last word takes the whitespace before it instead
+        assert_eq!(select("foo ba|r", 'w', true).as_deref(), Some(" bar"));
+        assert_eq!(select("a|.b", 'w', false).as_deref(), Some("."));
+        assert_eq!(select("|", 'w', false), None);
+    }
+}
@@ -63,6 +63,18 @@ struct Cli {
     #[arg(long)]
     stdin: bool,
 
+    /// With --stdin, save the buffer to PATH instead of writing it to stdout
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Exit only once the (last) file is closed, for use as $EDITOR or GIT_EDITOR
+    #[arg(long, conflicts_with_all = ["stdin", "diff"])]
+    wait: bool,
+
+    /// Open two files side by side in a diff view
+    #[arg(long, num_args = 2, value_names = ["LEFT", "RIGHT"], conflicts_with_all = ["files", "stdin"])]
+    diff: Vec<PathBuf>,
+
     /// Disable plugin loading
     #[arg(long)]
     no_plugins: bool,
@@ -159,6 +171,13 @@ struct Cli {
 struct Args {
     files: Vec<String>,
     stdin: bool,
+    /// `--output PATH`: where saving the stdin buffer writes, instead of
+    /// stdout.
+    output: Option<PathBuf>,
+    /// `--wait`: quit once the last file given is closed.
+    wait: bool,
+    /// `--diff LEFT RIGHT`: open the two files in a side-by-side diff.
+    diff: Option<(PathBuf, PathBuf)>,
     no_plugins: bool,
     no_init: bool,
     safe: bool,
@@ -464,9 +483,17 @@ impl From<Cli> for Args {
         let no_plugins = cli.no_plugins || safe;
         let no_init = cli.no_init || safe;
 
+        let diff = match <[PathBuf; 2]>::try_from(cli.diff) {
+            Ok([left, right]) => Some((left, right)),
+            Err(_) => None,
+        };
+
         Args {
             files,
             stdin: cli.stdin,
+            output: cli.output,
+            wait: cli.wait,
+            diff,
             no_plugins,
             no_init,
             safe,
@@ -559,6 +586,9 @@ struct SetupState {
     /// Stdin streaming state (if --stdin flag or "-" file was used)
     /// Contains temp file path and background thread handle
     stdin_stream: Option<StdinStreamState>,
+    /// The original stdout when it was a pipe and the UI was moved to the
+    /// terminal; the saved stdin buffer is written here on exit.
+    piped_stdout: Option<std::fs::File>,
     /// Single backend slot for "where does the editor act?".
     ///
     /// The editor always boots with `Authority::local()`. The SSH
@@ -773,6 +803,43 @@ fn reopen_stdin_from_tty() -> AnyhowResult<()> {
     Ok(())
 }
 
+/// Point stdout at the terminal when it is a pipe, returning the pipe.
+/// Like `reopen_stdin_from_tty`, this lets the UI use the terminal while
+/// the original stream stays available for the edited input.
+#[cfg(unix)]
+fn detour_stdout_to_tty() -> AnyhowResult<Option<std::fs::File>> {
+    use std::fs::{File, OpenOptions};
+    use std::io::IsTerminal;
+    use std::os::unix::io::{AsRawFd, FromRawFd};
+
+    if io::stdout().is_terminal() {
+        return Ok(None);
+    }
+
+    // Keep the pipe open under a new descriptor before fd 1 is replaced
+    let pipe_fd = unsafe { libc::dup(libc::STDOUT_FILENO) };
+    if pipe_fd == -1 {
+        anyhow::bail!("Failed to dup stdout: {}", io::Error::last_os_error());
+    }
+    // SAFETY: `pipe_fd` is a freshly duplicated descriptor owned by nobody else
+    let pipe = unsafe { File::from_raw_fd(pipe_fd) };
+
+    let tty = OpenOptions::new().write(true).open("/dev/tty")?;
+    // SAFETY: dup2 is safe to call with valid file descriptors
+    let result = unsafe { libc::dup2(tty.as_raw_fd(), libc::STDOUT_FILENO) };
+    if result == -1 {
+        anyhow::bail!(io::Error::last_os_error());
+    }
+
+    Ok(Some(pipe))
+}
+
+/// Windows leaves stdout alone, so the edited input follows the UI output.
+#[cfg(windows)]
+fn detour_stdout_to_tty() -> AnyhowResult<Option<std::fs::File>> {
+    Ok(None)
+}
+
 /// Reopen stdin from CONIN$ on Windows.
 /// This allows crossterm to receive keyboard events after stdin was a pipe.
 #[cfg(windows)]
@@ -838,7 +905,8 @@ fn handle_first_run_setup(
     // the launch as a focused "open these files" invocation: skip the full
     // session restore but still recover hot-exit content. `--restore` (force)
     // is a deliberate user override that wins.
-    let cli_has_file_args = file_locations.iter().any(|loc| !loc.path.is_dir());
+    let cli_has_file_args =
+        args.diff.is_some() || file_locations.iter().any(|loc| !loc.path.is_dir());
     let cli_overrides_restore = cli_has_file_args
         && editor
             .config()
@@ -914,13 +982,22 @@ fn handle_first_run_setup(
     if let Some(mut stream_state) = stdin_stream.take() {
         tracing::info!("Opening stdin buffer from: {:?}", stream_state.temp_path);
         editor.open_stdin_buffer(&stream_state.temp_path, stream_state.thread_handle.take())?;
+        if let Some(output) = &args.output {
+            editor.set_stdin_output_path(std::path::absolute(output)?);
+        }
     }
 
     // Queue CLI files to be opened after the TUI starts
     // This ensures they go through the same code path as interactive file opens,
     // with consistent error handling (e.g., encoding confirmation prompts in the UI)
+    // With `--wait`, closing the last file quits the editor.
+    let wait_index = if args.wait {
+        file_locations.iter().rposition(|loc| !loc.path.is_dir())
+    } else {
+        None
+    };
     let mut has_cli_files = false;
-    for loc in file_locations {
+    for (i, loc) in file_locations.iter().enumerate() {
         if loc.path.is_dir() {
             continue;
         }
@@ -932,11 +1009,17 @@ fn handle_first_run_setup(
             loc.end_line,
             loc.end_column,
             loc.message.clone(),
-            None,
+            (wait_index == Some(i)).then_some(fresh::app::QUIT_WAIT_ID),
         );
         has_cli_files = true;
     }
 
+    // `--diff LEFT RIGHT` opens both files and lands on their diff view.
+    if let Some((left, right)) = &args.diff {
+        editor.diff_files(left, right)?;
+        has_cli_files = true;
+    }
+
     // Schedule hot exit recovery for CLI-opened files (not covered by workspace restore)
     if has_cli_files {
         editor.schedule_hot_exit_recovery();
@@ -965,6 +1048,20 @@ fn handle_first_run_setup(
     Ok(())
 }
 
+/// Reject a `--diff` operand that is missing or is a directory.
+fn check_diff_path(path: &Path) -> AnyhowResult<()> {
+    match std::fs::metadata(path) {
+        Ok(meta) if meta.is_dir() => {
+            anyhow::bail!("--diff: {} is a directory", path.display())
+        }
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            anyhow::bail!("--diff: {} does not exist", path.display())
+        }
+        Err(e) => anyhow::bail!("--diff: cannot read {}: {}", path.display(), e),
+    }
+}
+
 /// Parse a file path that may include line/column, range, and message information.
 /// Supports formats:
 /// - file.txt
@@ -1557,6 +1654,12 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
     // Check if we should read from stdin
     // This can be triggered by --stdin flag or by using "-" as a file argument
     let stdin_requested = args.stdin || args.files.iter().any(|f| f == "-");
+    if args.output.is_some() && !stdin_requested {
+        anyhow::bail!("--output only applies with --stdin or \"-\"");
+    }
+    if args.wait && args.files.is_empty() {
+        anyhow::bail!("--wait needs a file to edit");
+    }
 
     // Start stdin streaming in background BEFORE entering raw mode
     // This is critical - once raw mode is enabled, stdin is used for terminal events
@@ -1588,6 +1691,21 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
         None
     };
 
+    // Saving the stdin buffer writes it to stdout unless `--output` is
+    // given, so a piped stdout must not also receive the UI.
+    let piped_stdout = if stdin_requested && args.output.is_none() {
+        detour_stdout_to_tty()?
+    } else {
+        None
+    };
+
+    // Reject unusable `--diff` operands before the terminal is taken over,
+    // so the error stays readable.
+    if let Some((left, right)) = &args.diff {
+        check_diff_path(left)?;
+        check_diff_path(right)?;
+    }
+
     // Determine working directory early for config loading
     // Filter out "-" from files list since it's handled via stdin_stream
     // Parse locations which may be local or remote (user@host:path)
@@ -1792,10 +1910,14 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
 
     // Set cursor style from config
     use crossterm::ExecutableCommand;
-    // Best-effort cursor style set
-    #[allow(clippy::let_underscore_must_use)]
-    let _ = stdout().execute(config.editor.cursor_style.to_crossterm_style());
-    tracing::info!("Set cursor style to {:?}", config.editor.cursor_style);
+    if fresh::config::CursorStyle::is_supported_by_current_terminal() {
+        // Best-effort cursor style set
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = stdout().execute(config.editor.cursor_style.to_crossterm_style());
+        tracing::info!("Set cursor style to {:?}", config.editor.cursor_style);
+    } else {
+        tracing::info!("Terminal does not support cursor styles; leaving cursor unchanged");
+    }
 
     tracing::info!("Initializing terminal backend...");
     let backend = ratatui::backend::CrosstermBackend::new(stdout());
@@ -1832,6 +1954,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
         dir_context,
         current_working_dir,
         stdin_stream,
+        piped_stdout,
         key_translator,
         gpm_client,
         terminal_modes,
@@ -4135,6 +4258,9 @@ fn build_localized_cli_command() -> clap::Command {
         .mut_arg("files", |a| a.help(t("cli.arg.files")))
         .mut_arg("attach", |a| a.help(t("cli.arg.attach")))
         .mut_arg("stdin", |a| a.help(t("cli.arg.stdin")))
+        .mut_arg("output", |a| a.help(t("cli.arg.output")))
+        .mut_arg("wait", |a| a.help(t("cli.arg.wait")))
+        .mut_arg("diff", |a| a.help(t("cli.arg.diff")))
         .mut_arg("no_plugins", |a| a.help(t("cli.arg.no_plugins")))
         .mut_arg("no_init", |a| a.help(t("cli.arg.no_init")))
         .mut_arg("safe", |a| a.help(t("cli.arg.safe")))
@@ -4244,6 +4370,7 @@ fn real_main() -> AnyhowResult<()> {
         dir_context,
         current_working_dir: initial_working_dir,
         mut stdin_stream,
+        piped_stdout,
         key_translator,
         #[cfg(target_os = "linux")]
         gpm_client,
@@ -4308,6 +4435,9 @@ fn real_main() -> AnyhowResult<()> {
 
     // Main editor loop - supports restarting with a new working directory
     // Returns (loop_result, last_update_result) tuple
+    // Bytes of the stdin buffer as last saved, printed once the UI is gone.
+    let mut stdout_output: Option<Vec<u8>> = None;
+
     let (result, last_update_result) = loop {
         let first_run = is_first_run;
         let workspace_enabled = !args.no_session;
@@ -4353,6 +4483,7 @@ fn real_main() -> AnyhowResult<()> {
         )
         .context("Failed to create editor instance")?;
         tracing::info!("Editor instance created");
+        editor.set_workspace_enabled(workspace_enabled);
 
         // Orchestrator cross-restart persistence is now loaded by
         // `Editor::with_options` before construction — it reads
@@ -4519,6 +4650,10 @@ fn real_main() -> AnyhowResult<()> {
             }
         }
 
+        if let Some(bytes) = editor.take_stdout_output() {
+            stdout_output = Some(bytes);
+        }
+
         drop(editor);
 
         if let Some(new_dir) = restart_dir {
@@ -4548,6 +4683,21 @@ fn real_main() -> AnyhowResult<()> {
     #[cfg(windows)]
     let _ = fresh_winterm::restore_console_mode(original_console_mode);
 
+    // A saved stdin buffer goes to the original stdout, after the UI.
+    if let Some(bytes) = stdout_output {
+        use std::io::Write;
+        let written = match piped_stdout {
+            Some(mut pipe) => pipe.write_all(&bytes).and_then(|()| pipe.flush()),
+            None => {
+                let mut out = stdout();
+                out.write_all(&bytes).and_then(|()| out.flush())
+            }
+        };
+        if let Err(e) = written {
+            eprintln!("Error: Failed to write the edited input to stdout: {}", e);
+        }
+    }
+
     // Check for updates after terminal is restored (using cached result)
     if let Some(update_result) = last_update_result {
         if update_result.update_available {
@@ -4891,12 +5041,12 @@ where
         }
 
         // Active animations force a render every FRAME_DURATION.
-        // Same for an in-flight LSP `$/progress` — the status-bar
+        // Same for an in-flight LSP `$/progress` or request — the status-bar
         // spinner is wall-clock-derived (see
         // `lsp_status::compose_lsp_status`) and needs a periodic
         // re-render to advance even when no other event fires.
         let animations_active = editor.active_window().animations.is_active();
-        let lsp_progress_active = editor.active_window().has_active_lsp_progress();
+        let lsp_progress_active = editor.active_window().has_lsp_activity();
         if animations_active || lsp_progress_active {
             needs_render = true;
         }
@@ -5171,6 +5321,34 @@ mod tests {
         assert_eq!(rest, vec!["cmd", "list"]);
     }
 
+    #[test]
+    fn test_diff_flag_takes_two_paths() {
+        let cli = Cli::try_parse_from(["fresh", "--diff", "a.txt", "b.txt"]).unwrap();
+        let args = Args::from(cli);
+        assert_eq!(
+            args.diff,
+            Some((PathBuf::from("a.txt"), PathBuf::from("b.txt")))
+        );
+        assert!(args.files.is_empty());
+
+        assert!(Cli::try_parse_from(["fresh", "--diff", "a.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_check_diff_path_rejects_directories_and_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file.txt");
+        std::fs::write(&file, "x\n").unwrap();
+
+        assert!(check_diff_path(&file).is_ok());
+        let err = check_diff_path(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("is a directory"), "{err}");
+        let err = check_diff_path(&dir.path().join("missing.txt"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("does not exist"), "{err}");
+    }
+
     #[test]
     fn test_parse_file_location_simple_path() {
         let loc = parse_file_location("foo.txt");
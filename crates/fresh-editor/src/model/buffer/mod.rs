@@ -2868,6 +2868,9 @@ impl TextBuffer {
     /// Convert LSP position (line, UTF-16 code units) to byte position
     /// LSP uses UTF-16 code units for character offsets, not bytes
     /// Optimized to use single line_range() call instead of two
+    ///
+    /// A `utf16_offset` past the end of the line clamps to the end of its
+    /// content, before the line terminator, as the LSP spec requires.
     pub fn lsp_position_to_byte(&self, line: usize, utf16_offset: usize) -> usize {
         if let Some((line_start, end)) = self.piece_tree.line_range(line, &self.buffers) {
             // Calculate line length and get line content
@@ -2889,7 +2892,7 @@ impl TextBuffer {
                 let mut byte_offset = 0;
 
                 for ch in line_str.chars() {
-                    if utf16_count >= utf16_offset {
+                    if utf16_count >= utf16_offset || ch == '\n' || ch == '\r' {
                         break;
                     }
                     utf16_count += ch.len_utf16();
@@ -176,6 +176,9 @@ impl SourcePane {
 /// Visual styling for a pane
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PaneStyle {
+    /// Background for the pane's unchanged rows (RGB), overriding the
+    /// theme's editor background so panes can be told apart
+    pub bg: Option<(u8, u8, u8)>,
     /// Background color for added lines (RGB)
     pub add_bg: Option<(u8, u8, u8)>,
     /// Background color for removed lines (RGB)
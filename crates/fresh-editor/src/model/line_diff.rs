@@ -3,6 +3,7 @@
 //! This module provides a simple but robust diff algorithm that correctly handles
 //! insertions, deletions, and modifications. It uses a longest common subsequence (LCS)
 //! approach to identify which lines are unchanged, then marks the ranges that differ.
+//! The LCS comes from a Myers diff, so memory stays linear in the line count.
 
 use std::ops::Range;
 
@@ -82,6 +83,48 @@ pub fn diff_lines(saved: &[u8], current: &[u8]) -> LineDiff {
     }
 }
 
+/// A maximal run of lines that differ between two texts: `old_count` lines
+/// of the old text starting at `old_start` stand where `new_count` lines of
+/// the new text start at `new_start`. Either count may be zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangedRun {
+    pub old_start: usize,
+    pub old_count: usize,
+    pub new_start: usize,
+    pub new_count: usize,
+}
+
+/// Compare two texts line by line and return the runs of lines between
+/// their longest common subsequence, in order. Identical texts yield no runs.
+pub fn changed_runs(old: &[u8], new: &[u8]) -> Vec<ChangedRun> {
+    if old == new {
+        return Vec::new();
+    }
+    let old_lines: Vec<&[u8]> = old.split(|&b| b == b'\n').collect();
+    let new_lines: Vec<&[u8]> = new.split(|&b| b == b'\n').collect();
+    let lcs = longest_common_subsequence(&old_lines, &new_lines);
+
+    let mut runs = Vec::new();
+    let (mut old_idx, mut new_idx) = (0, 0);
+    let ends = lcs
+        .iter()
+        .map(|m| (m.saved_idx, m.current_idx))
+        .chain(std::iter::once((old_lines.len(), new_lines.len())));
+    for (old_match, new_match) in ends {
+        if old_match > old_idx || new_match > new_idx {
+            runs.push(ChangedRun {
+                old_start: old_idx,
+                old_count: old_match - old_idx,
+                new_start: new_idx,
+                new_count: new_match - new_idx,
+            });
+        }
+        old_idx = old_match + 1;
+        new_idx = new_match + 1;
+    }
+    runs
+}
+
 /// Represents a match between saved and current line indices
 #[derive(Debug, Clone, Copy)]
 struct LineMatch {
@@ -91,49 +134,28 @@ struct LineMatch {
 
 /// Find the longest common subsequence of lines between saved and current.
 /// Returns a list of LineMatch with both saved and current indices.
+///
+/// Uses Myers' algorithm, which runs in linear space and O((n + m) * d)
+/// time for `d` differing lines, so large mostly-equal buffers stay cheap.
 fn longest_common_subsequence(saved: &[&[u8]], current: &[&[u8]]) -> Vec<LineMatch> {
-    let n = saved.len();
-    let m = current.len();
-
-    if n == 0 || m == 0 {
+    if saved.is_empty() || current.is_empty() {
         return vec![];
     }
 
-    // DP table for LCS length
-    // dp[i][j] = length of LCS of saved[0..i] and current[0..j]
-    let mut dp = vec![vec![0usize; m + 1]; n + 1];
-
-    for i in 1..=n {
-        for j in 1..=m {
-            if saved[i - 1] == current[j - 1] {
-                dp[i][j] = dp[i - 1][j - 1] + 1;
-            } else {
-                dp[i][j] = dp[i - 1][j].max(dp[i][j - 1]);
-            }
-        }
-    }
-
-    // Backtrack to find the actual LCS
     let mut lcs = Vec::new();
-    let mut i = n;
-    let mut j = m;
-
-    while i > 0 && j > 0 {
-        if saved[i - 1] == current[j - 1] {
-            lcs.push(LineMatch {
-                saved_idx: i - 1,
-                current_idx: j - 1,
-            });
-            i -= 1;
-            j -= 1;
-        } else if dp[i - 1][j] > dp[i][j - 1] {
-            i -= 1;
-        } else {
-            j -= 1;
+    for op in similar::capture_diff_slices(similar::Algorithm::Myers, saved, current) {
+        if let similar::DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = op
+        {
+            lcs.extend((0..len).map(|k| LineMatch {
+                saved_idx: old_index + k,
+                current_idx: new_index + k,
+            }));
         }
     }
-
-    lcs.reverse();
     lcs
 }
 
@@ -593,4 +615,53 @@ mod proptests {
             }
         }
     }
+
+    #[test]
+    fn test_changed_runs() {
+        let old = b"a\nb\nc\nd";
+        let new = b"a\nX\nb\nd\nE";
+        assert_eq!(
+            changed_runs(old, new),
+            vec![
+                // X inserted before b
+                ChangedRun {
+                    old_start: 1,
+                    old_count: 0,
+                    new_start: 1,
+                    new_count: 1,
+                },
+                // c deleted
+                ChangedRun {
+                    old_start: 2,
+                    old_count: 1,
+                    new_start: 3,
+                    new_count: 0,
+                },
+                // E appended
+                ChangedRun {
+                    old_start: 4,
+                    old_count: 0,
+                    new_start: 4,
+                    new_count: 1,
+                },
+            ]
+        );
+        assert!(changed_runs(old, old).is_empty());
+    }
+
+    #[test]
+    fn test_changed_runs_large_input() {
+        // A quadratic LCS table for this would need ~10^10 cells.
+        let old: String = (0..100_000).map(|i| format!("line {i}\n")).collect();
+        let new = old.replacen("line 50000\n", "changed\n", 1);
+        assert_eq!(
+            changed_runs(old.as_bytes(), new.as_bytes()),
+            vec![ChangedRun {
+                old_start: 50_000,
+                old_count: 1,
+                new_start: 50_000,
+                new_count: 1,
+            }]
+        );
+    }
 }
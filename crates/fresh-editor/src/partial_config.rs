@@ -6,7 +6,8 @@
 use crate::config::{
     ClipboardConfig, CursorStyle, FileBrowserConfig, FileExplorerConfig, FormatterConfig,
     IndentationGuideMode, Keybinding, KeybindingMapName, KeymapConfig, LanguageConfig,
-    LineEndingOption, OnSaveAction, PluginConfig, TerminalConfig, ThemeName, WarningsConfig,
+    LineEndingOption, OnSaveAction, PluginConfig, PluginPermissions, TerminalConfig, ThemeName,
+    WarningsConfig,
 };
 use crate::types::LspLanguageConfig;
 use serde::{Deserialize, Serialize};
@@ -150,6 +151,7 @@ fn merge_partial<T: Merge + Clone>(target: &mut Option<T>, other: &Option<T>) {
 pub struct PartialEditorConfig {
     pub use_tabs: Option<bool>,
     pub tab_size: Option<usize>,
+    pub tab_display_width: Option<Option<usize>>,
     pub auto_indent: Option<bool>,
     pub auto_close: Option<bool>,
     pub auto_surround: Option<bool>,
@@ -159,15 +161,23 @@ pub struct PartialEditorConfig {
     pub line_numbers: Option<bool>,
     pub relative_line_numbers: Option<bool>,
     pub scroll_offset: Option<usize>,
+    pub scroll_on_jump: Option<crate::config::ScrollOnJump>,
+    pub navigation_acceleration: Option<bool>,
+    pub navigation_acceleration_ramp_ms: Option<u64>,
+    pub navigation_acceleration_max_step: Option<usize>,
+    pub search_wrap: Option<bool>,
     pub syntax_highlighting: Option<bool>,
     pub highlight_current_line: Option<bool>,
     pub highlight_occurrences: Option<bool>,
     pub hide_current_line_on_selection: Option<bool>,
+    pub selection_full_line: Option<bool>,
     pub highlight_current_column: Option<bool>,
     pub line_wrap: Option<bool>,
     pub wrap_indent: Option<bool>,
     pub wrap_column: Option<Option<usize>>,
+    pub show_wrap_guide: Option<bool>,
     pub page_width: Option<Option<usize>>,
+    pub page_view_focus: Option<bool>,
     pub highlight_timeout_ms: Option<u64>,
     pub snapshot_interval: Option<usize>,
     pub large_file_threshold_bytes: Option<u64>,
@@ -177,6 +187,7 @@ pub struct PartialEditorConfig {
     pub diagnostics_inline_text: Option<bool>,
     pub recovery_enabled: Option<bool>,
     pub auto_recovery_save_interval_secs: Option<u32>,
+    pub workspace_auto_save_interval_secs: Option<u32>,
     pub auto_save_enabled: Option<bool>,
     pub auto_save_interval_secs: Option<u32>,
     pub hot_exit: Option<bool>,
@@ -210,6 +221,7 @@ pub struct PartialEditorConfig {
     pub screensaver_enabled: Option<bool>,
     pub screensaver_idle_minutes: Option<u32>,
     pub menu_bar_mnemonics: Option<bool>,
+    pub border_style: Option<crate::config::BorderStyle>,
     pub show_tab_bar: Option<bool>,
     pub show_status_bar: Option<bool>,
     pub status_bar: Option<crate::config::StatusBarConfig>,
@@ -238,6 +250,7 @@ impl Merge for PartialEditorConfig {
     fn merge_from(&mut self, other: &Self) {
         self.use_tabs.merge_from(&other.use_tabs);
         self.tab_size.merge_from(&other.tab_size);
+        self.tab_display_width.merge_from(&other.tab_display_width);
         self.auto_indent.merge_from(&other.auto_indent);
         self.auto_close.merge_from(&other.auto_close);
         self.auto_surround.merge_from(&other.auto_surround);
@@ -248,13 +261,25 @@ impl Merge for PartialEditorConfig {
         self.line_numbers.merge_from(&other.line_numbers);
         self.relative_line_numbers
             .merge_from(&other.relative_line_numbers);
+        self.selection_full_line
+            .merge_from(&other.selection_full_line);
         self.scroll_offset.merge_from(&other.scroll_offset);
+        self.scroll_on_jump.merge_from(&other.scroll_on_jump);
+        self.navigation_acceleration
+            .merge_from(&other.navigation_acceleration);
+        self.navigation_acceleration_ramp_ms
+            .merge_from(&other.navigation_acceleration_ramp_ms);
+        self.navigation_acceleration_max_step
+            .merge_from(&other.navigation_acceleration_max_step);
+        self.search_wrap.merge_from(&other.search_wrap);
         self.syntax_highlighting
             .merge_from(&other.syntax_highlighting);
         self.line_wrap.merge_from(&other.line_wrap);
         self.wrap_indent.merge_from(&other.wrap_indent);
         self.wrap_column.merge_from(&other.wrap_column);
+        self.show_wrap_guide.merge_from(&other.show_wrap_guide);
         self.page_width.merge_from(&other.page_width);
+        self.page_view_focus.merge_from(&other.page_view_focus);
         self.highlight_timeout_ms
             .merge_from(&other.highlight_timeout_ms);
         self.snapshot_interval.merge_from(&other.snapshot_interval);
@@ -271,6 +296,8 @@ impl Merge for PartialEditorConfig {
         self.recovery_enabled.merge_from(&other.recovery_enabled);
         self.auto_recovery_save_interval_secs
             .merge_from(&other.auto_recovery_save_interval_secs);
+        self.workspace_auto_save_interval_secs
+            .merge_from(&other.workspace_auto_save_interval_secs);
         self.auto_save_enabled.merge_from(&other.auto_save_enabled);
         self.auto_save_interval_secs
             .merge_from(&other.auto_save_interval_secs);
@@ -328,6 +355,7 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.screensaver_idle_minutes);
         self.menu_bar_mnemonics
             .merge_from(&other.menu_bar_mnemonics);
+        self.border_style.merge_from(&other.border_style);
         self.show_tab_bar.merge_from(&other.show_tab_bar);
         self.show_status_bar.merge_from(&other.show_status_bar);
         if other.status_bar.is_some() {
@@ -384,6 +412,7 @@ pub struct PartialFileExplorerConfig {
     pub auto_open_on_last_buffer_close: Option<bool>,
     pub follow_active_buffer: Option<bool>,
     pub compact_directories: Option<bool>,
+    pub delete_to_trash: Option<bool>,
     pub tree_indicator_collapsed: Option<String>,
     pub tree_indicator_expanded: Option<String>,
 }
@@ -404,6 +433,7 @@ impl Merge for PartialFileExplorerConfig {
             .merge_from(&other.follow_active_buffer);
         self.compact_directories
             .merge_from(&other.compact_directories);
+        self.delete_to_trash.merge_from(&other.delete_to_trash);
         self.tree_indicator_collapsed
             .merge_from(&other.tree_indicator_collapsed);
         self.tree_indicator_expanded
@@ -507,6 +537,8 @@ pub struct PartialPluginConfig {
     /// config docs describe "lists are replaced, objects are deep-merged").
     #[serde(skip_serializing_if = "serde_json::Value::is_null", default)]
     pub settings: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<PluginPermissions>,
 }
 
 fn merge_json_values(target: &mut serde_json::Value, other: &serde_json::Value) {
@@ -531,6 +563,7 @@ impl Merge for PartialPluginConfig {
         self.enabled.merge_from(&other.enabled);
         self.path.merge_from(&other.path);
         merge_json_values(&mut self.settings, &other.settings);
+        self.permissions.merge_from(&other.permissions);
     }
 }
 
@@ -595,6 +628,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
         Self {
             use_tabs: Some(cfg.use_tabs),
             tab_size: Some(cfg.tab_size),
+            tab_display_width: Some(cfg.tab_display_width),
             auto_indent: Some(cfg.auto_indent),
             auto_close: Some(cfg.auto_close),
             auto_surround: Some(cfg.auto_surround),
@@ -604,15 +638,23 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             line_numbers: Some(cfg.line_numbers),
             relative_line_numbers: Some(cfg.relative_line_numbers),
             scroll_offset: Some(cfg.scroll_offset),
+            scroll_on_jump: Some(cfg.scroll_on_jump),
+            navigation_acceleration: Some(cfg.navigation_acceleration),
+            navigation_acceleration_ramp_ms: Some(cfg.navigation_acceleration_ramp_ms),
+            navigation_acceleration_max_step: Some(cfg.navigation_acceleration_max_step),
+            search_wrap: Some(cfg.search_wrap),
             syntax_highlighting: Some(cfg.syntax_highlighting),
             highlight_current_line: Some(cfg.highlight_current_line),
             highlight_occurrences: Some(cfg.highlight_occurrences),
             hide_current_line_on_selection: Some(cfg.hide_current_line_on_selection),
+            selection_full_line: Some(cfg.selection_full_line),
             highlight_current_column: Some(cfg.highlight_current_column),
             line_wrap: Some(cfg.line_wrap),
             wrap_indent: Some(cfg.wrap_indent),
             wrap_column: Some(cfg.wrap_column),
+            show_wrap_guide: Some(cfg.show_wrap_guide),
             page_width: Some(cfg.page_width),
+            page_view_focus: Some(cfg.page_view_focus),
             highlight_timeout_ms: Some(cfg.highlight_timeout_ms),
             snapshot_interval: Some(cfg.snapshot_interval),
             large_file_threshold_bytes: Some(cfg.large_file_threshold_bytes),
@@ -622,6 +664,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             diagnostics_inline_text: Some(cfg.diagnostics_inline_text),
             recovery_enabled: Some(cfg.recovery_enabled),
             auto_recovery_save_interval_secs: Some(cfg.auto_recovery_save_interval_secs),
+            workspace_auto_save_interval_secs: Some(cfg.workspace_auto_save_interval_secs),
             auto_save_enabled: Some(cfg.auto_save_enabled),
             auto_save_interval_secs: Some(cfg.auto_save_interval_secs),
             hot_exit: Some(cfg.hot_exit),
@@ -661,6 +704,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             screensaver_enabled: Some(cfg.screensaver_enabled),
             screensaver_idle_minutes: Some(cfg.screensaver_idle_minutes),
             menu_bar_mnemonics: Some(cfg.menu_bar_mnemonics),
+            border_style: Some(cfg.border_style),
             show_tab_bar: Some(cfg.show_tab_bar),
             show_status_bar: Some(cfg.show_status_bar),
             status_bar: Some(cfg.status_bar.clone()),
@@ -693,6 +737,7 @@ impl PartialEditorConfig {
         crate::config::EditorConfig {
             use_tabs: self.use_tabs.unwrap_or(defaults.use_tabs),
             tab_size: self.tab_size.unwrap_or(defaults.tab_size),
+            tab_display_width: self.tab_display_width.unwrap_or(defaults.tab_display_width),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
             auto_close: self.auto_close.unwrap_or(defaults.auto_close),
             auto_surround: self.auto_surround.unwrap_or(defaults.auto_surround),
@@ -706,6 +751,17 @@ impl PartialEditorConfig {
                 .relative_line_numbers
                 .unwrap_or(defaults.relative_line_numbers),
             scroll_offset: self.scroll_offset.unwrap_or(defaults.scroll_offset),
+            scroll_on_jump: self.scroll_on_jump.unwrap_or(defaults.scroll_on_jump),
+            navigation_acceleration: self
+                .navigation_acceleration
+                .unwrap_or(defaults.navigation_acceleration),
+            navigation_acceleration_ramp_ms: self
+                .navigation_acceleration_ramp_ms
+                .unwrap_or(defaults.navigation_acceleration_ramp_ms),
+            navigation_acceleration_max_step: self
+                .navigation_acceleration_max_step
+                .unwrap_or(defaults.navigation_acceleration_max_step),
+            search_wrap: self.search_wrap.unwrap_or(defaults.search_wrap),
             syntax_highlighting: self
                 .syntax_highlighting
                 .unwrap_or(defaults.syntax_highlighting),
@@ -718,13 +774,18 @@ impl PartialEditorConfig {
             hide_current_line_on_selection: self
                 .hide_current_line_on_selection
                 .unwrap_or(defaults.hide_current_line_on_selection),
+            selection_full_line: self
+                .selection_full_line
+                .unwrap_or(defaults.selection_full_line),
             highlight_current_column: self
                 .highlight_current_column
                 .unwrap_or(defaults.highlight_current_column),
             line_wrap: self.line_wrap.unwrap_or(defaults.line_wrap),
             wrap_indent: self.wrap_indent.unwrap_or(defaults.wrap_indent),
             wrap_column: self.wrap_column.unwrap_or(defaults.wrap_column),
+            show_wrap_guide: self.show_wrap_guide.unwrap_or(defaults.show_wrap_guide),
             page_width: self.page_width.unwrap_or(defaults.page_width),
+            page_view_focus: self.page_view_focus.unwrap_or(defaults.page_view_focus),
             highlight_timeout_ms: self
                 .highlight_timeout_ms
                 .unwrap_or(defaults.highlight_timeout_ms),
@@ -748,6 +809,9 @@ impl PartialEditorConfig {
             auto_recovery_save_interval_secs: self
                 .auto_recovery_save_interval_secs
                 .unwrap_or(defaults.auto_recovery_save_interval_secs),
+            workspace_auto_save_interval_secs: self
+                .workspace_auto_save_interval_secs
+                .unwrap_or(defaults.workspace_auto_save_interval_secs),
             auto_save_enabled: self.auto_save_enabled.unwrap_or(defaults.auto_save_enabled),
             auto_save_interval_secs: self
                 .auto_save_interval_secs
@@ -829,6 +893,7 @@ impl PartialEditorConfig {
             menu_bar_mnemonics: self
                 .menu_bar_mnemonics
                 .unwrap_or(defaults.menu_bar_mnemonics),
+            border_style: self.border_style.unwrap_or(defaults.border_style),
             show_tab_bar: self.show_tab_bar.unwrap_or(defaults.show_tab_bar),
             show_status_bar: self.show_status_bar.unwrap_or(defaults.show_status_bar),
             status_bar: self
@@ -893,6 +958,7 @@ impl From<&FileExplorerConfig> for PartialFileExplorerConfig {
             auto_open_on_last_buffer_close: Some(cfg.auto_open_on_last_buffer_close),
             follow_active_buffer: Some(cfg.follow_active_buffer),
             compact_directories: Some(cfg.compact_directories),
+            delete_to_trash: Some(cfg.delete_to_trash),
             tree_indicator_collapsed: Some(cfg.tree_indicator_collapsed.clone()),
             tree_indicator_expanded: Some(cfg.tree_indicator_expanded.clone()),
         }
@@ -920,6 +986,7 @@ impl PartialFileExplorerConfig {
             compact_directories: self
                 .compact_directories
                 .unwrap_or(defaults.compact_directories),
+            delete_to_trash: self.delete_to_trash.unwrap_or(defaults.delete_to_trash),
             tree_indicator_collapsed: self
                 .tree_indicator_collapsed
                 .unwrap_or_else(|| defaults.tree_indicator_collapsed.clone()),
@@ -1041,6 +1108,7 @@ impl From<&PluginConfig> for PartialPluginConfig {
             enabled: Some(cfg.enabled),
             path: cfg.path.clone(),
             settings: cfg.settings.clone(),
+            permissions: Some(cfg.permissions),
         }
     }
 }
@@ -1055,6 +1123,7 @@ impl PartialPluginConfig {
             enabled: self.enabled.unwrap_or(defaults.enabled),
             path: self.path.or_else(|| defaults.path.clone()),
             settings,
+            permissions: self.permissions.unwrap_or(defaults.permissions),
         }
     }
 }
@@ -1194,7 +1263,9 @@ impl From<&crate::config::Config> for PartialConfig {
                             serde_json::Value::Object(o) => !o.is_empty(),
                             _ => true,
                         };
-                        v.enabled != default_plugin.enabled || settings_changed
+                        v.enabled != default_plugin.enabled
+                            || settings_changed
+                            || v.permissions != default_plugin.permissions
                     })
                     .map(|(k, v)| {
                         (
@@ -1203,6 +1274,7 @@ impl From<&crate::config::Config> for PartialConfig {
                                 enabled: Some(v.enabled),
                                 path: None, // Don't save path - it's auto-discovered
                                 settings: v.settings.clone(),
+                                permissions: (!v.permissions.is_default()).then_some(v.permissions),
                             },
                         )
                     })
@@ -1738,6 +1810,7 @@ mod tests {
                 enabled: true, // Default value
                 path: Some(std::path::PathBuf::from("/path/to/plugin.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
 
@@ -1760,6 +1833,7 @@ mod tests {
                 enabled: true,
                 path: Some(std::path::PathBuf::from("/path/to/enabled.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
         config.plugins.insert(
@@ -1768,6 +1842,7 @@ mod tests {
                 enabled: false, // Not default!
                 path: Some(std::path::PathBuf::from("/path/to/disabled.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
 
@@ -1801,6 +1876,7 @@ mod tests {
                 enabled: false,
                 path: Some(std::path::PathBuf::from("/some/path/plugin.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
 
@@ -1824,6 +1900,7 @@ mod tests {
                     enabled: Some(false),
                     path: None,
                     settings: serde_json::Value::Null,
+                    permissions: None,
                 },
             )])),
             ..Default::default()
@@ -1853,6 +1930,7 @@ mod tests {
                     enabled: Some(false), // User disabled
                     path: None,
                     settings: serde_json::Value::Null,
+                    permissions: None,
                 },
             )])),
             ..Default::default()
@@ -1865,6 +1943,7 @@ mod tests {
                     enabled: Some(true), // Lower layer has it enabled
                     path: None,
                     settings: serde_json::Value::Null,
+                    permissions: None,
                 },
             )])),
             ..Default::default()
@@ -1892,6 +1971,7 @@ mod tests {
                 enabled: true,
                 path: Some(std::path::PathBuf::from("/a.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
         config.plugins.insert(
@@ -1900,6 +1980,7 @@ mod tests {
                 enabled: false,
                 path: Some(std::path::PathBuf::from("/b.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
         config.plugins.insert(
@@ -1908,6 +1989,7 @@ mod tests {
                 enabled: true,
                 path: Some(std::path::PathBuf::from("/c.ts")),
                 settings: serde_json::Value::Null,
+                permissions: Default::default(),
             },
         );
 
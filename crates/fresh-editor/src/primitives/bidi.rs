@@ -0,0 +1,71 @@
+//! Bidirectional text reordering for display.
+//!
+//! Buffers always store text in logical order. When a line mixes
+//! left-to-right and right-to-left scripts (Arabic, Hebrew), the Unicode
+//! Bidirectional Algorithm (UAX #9) decides the order the characters are
+//! shown in. This module computes that visual order for a single line;
+//! the view pipeline applies it to each rendered row while keeping every
+//! character's source byte, so cursors and clicks still address the
+//! logical text.
+//!
+//! The paragraph direction is always left-to-right: a line that starts
+//! with Hebrew still begins at the left edge, with its RTL runs reversed
+//! in place.
+
+use unicode_bidi::{BidiInfo, Level};
+
+/// The visual order of the characters of `line`: element `i` is the index
+/// (in chars) of the character shown at the `i`-th position from the left.
+///
+/// Returns `None` when the line has no right-to-left text, which is the
+/// common case and means the logical order is already the visual one.
+pub fn visual_char_order(line: &str) -> Option<Vec<usize>> {
+    if line.is_ascii() {
+        return None;
+    }
+    let info = BidiInfo::new(line, Some(Level::ltr()));
+    let mut order = Vec::with_capacity(line.len());
+    let mut has_rtl = false;
+    for para in &info.paragraphs {
+        let levels = info.reordered_levels_per_char(para, para.range.clone());
+        has_rtl |= levels.iter().any(|level| level.is_rtl());
+        let offset = order.len();
+        order.extend(
+            BidiInfo::reorder_visual(&levels)
+                .into_iter()
+                .map(|i| i + offset),
+        );
+    }
+    has_rtl.then_some(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reorder(line: &str) -> String {
+        let chars: Vec<char> = line.chars().collect();
+        match visual_char_order(line) {
+            Some(order) => order.into_iter().map(|i| chars[i]).collect(),
+            None => line.to_string(),
+        }
+    }
+
+    #[test]
+    fn ltr_text_keeps_logical_order() {
+        assert_eq!(visual_char_order("let x = 1;"), None);
+        assert_eq!(visual_char_order("café naïve"), None);
+    }
+
+    #[test]
+    fn rtl_run_is_reversed_within_ltr_line() {
+        // "say שלום now": the Hebrew word reads right-to-left in place
+        assert_eq!(reorder("say שלום now"), "say םולש now");
+    }
+
+    #[test]
+    fn numbers_inside_rtl_run_stay_left_to_right() {
+        // Digits keep their own order even between Arabic words
+        assert_eq!(reorder("x = مرحبا 123 عالم"), "x = ملاع 123 ابحرم");
+    }
+}
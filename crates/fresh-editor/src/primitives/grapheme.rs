@@ -133,6 +133,30 @@ pub fn snap_to_grapheme_boundary(s: &str, pos: usize) -> usize {
     last_boundary
 }
 
+/// Whether `cluster` is a multi-code-point emoji sequence: a flag (pair of
+/// regional indicators or a tag sequence), a ZWJ sequence, an emoji with a
+/// skin-tone modifier, or one with an emoji presentation selector or
+/// keycap.
+///
+/// Such a cluster only means something as a whole — deleting one code
+/// point of a flag leaves a stray regional indicator — so backspace
+/// removes it in one step, while combining marks (Thai tone marks,
+/// accents) are still removed one layer at a time.
+pub fn is_emoji_sequence(cluster: &str) -> bool {
+    cluster.chars().nth(1).is_some()
+        && cluster.chars().any(|c| {
+            matches!(
+                c,
+                '\u{200D}' // zero-width joiner
+                    | '\u{FE0F}' // emoji presentation selector
+                    | '\u{20E3}' // combining enclosing keycap
+                    | '\u{1F1E6}'..='\u{1F1FF}' // regional indicators
+                    | '\u{1F3FB}'..='\u{1F3FF}' // skin-tone modifiers
+                    | '\u{E0020}'..='\u{E007F}' // tag characters
+            )
+        })
+}
+
 /// Count the number of grapheme clusters in a string.
 ///
 /// This is what users would count as "characters".
@@ -271,6 +295,19 @@ mod tests {
         assert!(grapheme_at(s, 11).is_none()); // past end
     }
 
+    #[test]
+    fn test_is_emoji_sequence() {
+        assert!(is_emoji_sequence("\u{1F1EF}\u{1F1F5}")); // flag
+        assert!(is_emoji_sequence("\u{1F44D}\u{1F3FD}")); // thumbs-up + skin tone
+        assert!(is_emoji_sequence("\u{1F469}\u{200D}\u{1F4BB}")); // ZWJ sequence
+        assert!(is_emoji_sequence("\u{2764}\u{FE0F}")); // heart + VS16
+        assert!(is_emoji_sequence("1\u{FE0F}\u{20E3}")); // keycap
+
+        assert!(!is_emoji_sequence("\u{1F600}")); // single code point
+        assert!(!is_emoji_sequence("e\u{301}")); // combining accent
+        assert!(!is_emoji_sequence("ที่")); // Thai base + marks
+    }
+
     #[test]
     fn test_empty_string() {
         let s = "";
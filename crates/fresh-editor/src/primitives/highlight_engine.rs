@@ -1677,15 +1677,19 @@ impl HighlightEngine {
 
     /// Notify the highlighting engine of a buffer insert (for checkpoint position tracking).
     pub fn notify_insert(&mut self, position: usize, length: usize) {
-        if let Self::TextMate(h) = self {
-            h.notify_insert(position, length);
+        match self {
+            Self::TreeSitter(h) => h.notify_insert(position, length),
+            Self::TextMate(h) => h.notify_insert(position, length),
+            Self::None => {}
         }
     }
 
     /// Notify the highlighting engine of a buffer delete (for checkpoint position tracking).
     pub fn notify_delete(&mut self, position: usize, length: usize) {
-        if let Self::TextMate(h) = self {
-            h.notify_delete(position, length);
+        match self {
+            Self::TreeSitter(h) => h.notify_delete(position, length),
+            Self::TextMate(h) => h.notify_delete(position, length),
+            Self::None => {}
         }
     }
 
@@ -2298,6 +2302,41 @@ mod tests {
         assert_eq!(category_at("script>"), Some(HighlightCategory::Property));
     }
 
+    /// HTML `<script>` / `<style>` need no embedding spec: the HTML grammar
+    /// embeds JavaScript and CSS directly through the `SyntaxSet`.
+    #[test]
+    fn test_html_script_and_style_blocks_use_embedded_grammars() {
+        let registry =
+            GrammarRegistry::load(&crate::primitives::grammar::LocalGrammarLoader::embedded_only());
+        let mut engine = HighlightEngine::for_file(Path::new("index.html"), None, &registry);
+        assert_eq!(engine.backend_name(), "textmate");
+
+        let content = "<html>\n<script>const x = 1;</script>\n<style>\n.foo { color: red; }\n</style>\n</html>\n";
+        let buffer = Buffer::from_str(content, 0, test_fs());
+        let theme = Theme::load_builtin(theme::THEME_LIGHT).unwrap();
+        let spans = engine.highlight_viewport(&buffer, 0, buffer.len(), &theme, 0);
+
+        let category_at = |needle: &str| {
+            let position = content.find(needle).unwrap();
+            spans
+                .iter()
+                .find(|span| span.range.start <= position && position < span.range.end)
+                .and_then(|span| span.category)
+        };
+
+        assert_eq!(
+            category_at("const"),
+            Some(HighlightCategory::Keyword),
+            "<script> content must be highlighted as JavaScript"
+        );
+        assert_eq!(category_at("1;"), Some(HighlightCategory::Number));
+        assert_eq!(
+            category_at("color"),
+            Some(HighlightCategory::Type),
+            "<style> content must be highlighted as CSS"
+        );
+    }
+
     /// A `lang` attribute dynamically selects the embedded language.
     #[test]
     fn test_vue_lang_attribute_selects_language() {
@@ -26,6 +26,12 @@ use std::ops::Range;
 /// than tracking that setting.
 const MAX_PARSE_BYTES: usize = 1024 * 1024; // 1 MB
 
+/// How many parsed ranges the highlighter keeps. Each is at most
+/// `MAX_PARSE_BYTES` of source, so memory stays bounded however large the
+/// file; a few entries let scrolling back to a recent viewport (PageUp then
+/// PageDown) skip the re-parse.
+const MAX_CACHED_RANGES: usize = 4;
+
 /// Get the color for a highlight category from the theme
 pub fn highlight_color(category: HighlightCategory, theme: &Theme) -> Color {
     match category {
@@ -101,6 +107,17 @@ struct HighlightCache {
     spans: Vec<CachedSpan>,
 }
 
+impl HighlightCache {
+    /// Move the range and every span through `map`, used when an edit
+    /// before the range shifts its text.
+    fn remap(&mut self, map: impl Fn(usize) -> usize) {
+        self.range = map(self.range.start)..map(self.range.end);
+        for span in &mut self.spans {
+            span.range = map(span.range.start)..map(span.range.end);
+        }
+    }
+}
+
 /// Syntax highlighter with incremental viewport-based parsing
 pub struct Highlighter {
     /// Tree-sitter highlighter instance
@@ -109,8 +126,9 @@ pub struct Highlighter {
     language: Language,
     /// Highlight configuration for the language
     config: HighlightConfiguration,
-    /// Cache of highlighted spans (only for visible viewport)
-    cache: Option<HighlightCache>,
+    /// Recently parsed ranges, most recently used first (at most
+    /// `MAX_CACHED_RANGES`)
+    caches: Vec<HighlightCache>,
     /// Last known buffer length (for detecting complete buffer changes)
     last_buffer_len: usize,
 }
@@ -123,7 +141,7 @@ impl Highlighter {
             ts_highlighter: TSHighlighter::new(),
             language,
             config,
-            cache: None,
+            caches: Vec::new(),
             last_buffer_len: 0,
         })
     }
@@ -143,27 +161,32 @@ impl Highlighter {
         theme: &Theme,
         context_bytes: usize,
     ) -> Vec<HighlightSpan> {
-        // Check if cache is valid for this range
-        if let Some(cache) = &self.cache {
-            if cache.range.start <= viewport_start
-                && cache.range.end >= viewport_end
-                && self.last_buffer_len == buffer.len()
-            {
-                // Cache hit! Filter spans to the requested range and resolve colors from theme
-                return cache
-                    .spans
-                    .iter()
-                    .filter(|span| {
-                        span.range.start < viewport_end && span.range.end > viewport_start
-                    })
-                    .map(|span| HighlightSpan {
-                        range: span.range.clone(),
-                        color: highlight_color(span.category, theme),
-                        bg: None,
-                        category: Some(span.category),
-                    })
-                    .collect();
-            }
+        // A length change means the buffer was edited without an
+        // invalidation; no cached range can be trusted.
+        if self.last_buffer_len != buffer.len() {
+            self.caches.clear();
+        }
+
+        // Check if any cached range covers the viewport
+        if let Some(index) = self.caches.iter().position(|cache| {
+            cache.range.start <= viewport_start && cache.range.end >= viewport_end
+        }) {
+            // Cache hit! Move it to the front, filter spans to the requested
+            // range and resolve colors from theme
+            let cache = self.caches.remove(index);
+            let spans = cache
+                .spans
+                .iter()
+                .filter(|span| span.range.start < viewport_end && span.range.end > viewport_start)
+                .map(|span| HighlightSpan {
+                    range: span.range.clone(),
+                    color: highlight_color(span.category, theme),
+                    bg: None,
+                    category: Some(span.category),
+                })
+                .collect();
+            self.caches.insert(0, cache);
+            return spans;
         }
 
         // Cache miss - need to parse
@@ -245,11 +268,15 @@ impl Highlighter {
             }
         }
 
-        // Update cache
-        self.cache = Some(HighlightCache {
-            range: parse_range,
-            spans: cached_spans.clone(),
-        });
+        // Update cache, evicting the least recently used range
+        self.caches.insert(
+            0,
+            HighlightCache {
+                range: parse_range,
+                spans: cached_spans.clone(),
+            },
+        );
+        self.caches.truncate(MAX_CACHED_RANGES);
         self.last_buffer_len = buffer.len();
 
         // Filter to requested viewport and resolve colors from theme
@@ -268,29 +295,63 @@ impl Highlighter {
             .collect()
     }
 
+    /// Buffer-insert notification: cached ranges after `position` move
+    /// with their text, and a range the insert lands inside is dropped.
+    pub fn notify_insert(&mut self, position: usize, length: usize) {
+        self.caches.retain_mut(|cache| {
+            if cache.range.end <= position {
+                true
+            } else if cache.range.start >= position {
+                cache.remap(|offset| offset + length);
+                true
+            } else {
+                false
+            }
+        });
+        self.last_buffer_len += length;
+    }
+
+    /// Buffer-delete notification. Mirror of `notify_insert`.
+    pub fn notify_delete(&mut self, position: usize, length: usize) {
+        let delete_end = position + length;
+        self.caches.retain_mut(|cache| {
+            if cache.range.end <= position {
+                true
+            } else if cache.range.start >= delete_end {
+                cache.remap(|offset| offset - length);
+                true
+            } else {
+                false
+            }
+        });
+        self.last_buffer_len = self.last_buffer_len.saturating_sub(length);
+    }
+
     /// Invalidate cache for an edited range
     ///
-    /// Call this when the buffer is edited to mark the cache as stale.
+    /// Call this when the buffer is edited to mark the cache as stale. Only
+    /// cached ranges the edit intersects are dropped; `notify_insert` and
+    /// `notify_delete` keep the others in step with the buffer.
     pub fn invalidate_range(&mut self, edit_range: Range<usize>) {
-        if let Some(cache) = &self.cache {
-            // If edit intersects cache, invalidate it
-            if edit_range.start < cache.range.end && edit_range.end > cache.range.start {
-                self.cache = None;
-            }
-        }
+        self.caches.retain(|cache| {
+            edit_range.start >= cache.range.end || edit_range.end <= cache.range.start
+        });
     }
 
     /// Invalidate entire cache
     pub fn invalidate_all(&mut self) {
-        self.cache = None;
+        self.caches.clear();
     }
 
     /// Get the highlight category at a byte position from the cache.
     ///
     /// Returns the category if the position falls within a cached highlight span.
-    /// The position must be within the last highlighted viewport range for a result.
+    /// The position must be within a recently highlighted range for a result.
     pub fn category_at_position(&self, position: usize) -> Option<HighlightCategory> {
-        let cache = self.cache.as_ref()?;
+        let cache = self
+            .caches
+            .iter()
+            .find(|cache| cache.range.contains(&position))?;
         cache
             .spans
             .iter()
@@ -408,10 +469,6 @@ mod tests {
         let path = std::path::Path::new("home.templ");
         assert!(matches!(Language::from_path(path), Some(Language::Templ)));
 
-        // Markdown disabled due to tree-sitter version conflict
-        // let path = std::path::Path::new("test.md");
-        // assert!(matches!(Language::from_path(path), Some(Language::Markdown)));
-
         let path = std::path::Path::new("test.txt");
         assert!(Language::from_path(path).is_none());
     }
@@ -501,19 +558,113 @@ mod tests {
 
         // First highlight
         highlighter.highlight_viewport(&buffer, 0, buffer.len(), &theme, 100_000);
-        assert!(highlighter.cache.is_some());
+        assert_eq!(highlighter.caches.len(), 1);
 
         // Invalidate a range
         highlighter.invalidate_range(5..10);
-        assert!(highlighter.cache.is_none());
+        assert!(highlighter.caches.is_empty());
 
         // Highlight again to rebuild cache
         highlighter.highlight_viewport(&buffer, 0, buffer.len(), &theme, 100_000);
-        assert!(highlighter.cache.is_some());
+        assert_eq!(highlighter.caches.len(), 1);
 
         // Invalidate all
         highlighter.invalidate_all();
-        assert!(highlighter.cache.is_none());
+        assert!(highlighter.caches.is_empty());
+    }
+
+    /// Rows of 20 bytes each: `const v0000 = 0000;\n`, ...
+    fn numbered_source(rows: usize) -> String {
+        (0..rows)
+            .map(|i| format!("const v{i:04} = {i:04};\n"))
+            .collect()
+    }
+
+    #[test]
+    fn test_alternating_viewports_hit_cache_on_revisit() {
+        let buffer = Buffer::from_str_test(&numbered_source(1000));
+        let mut highlighter = Highlighter::new(Language::TypeScript).unwrap();
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let top = 0..400;
+        let bottom = 15_000..15_400;
+
+        let first_top = highlighter.highlight_viewport(&buffer, top.start, top.end, &theme, 100);
+        highlighter.highlight_viewport(&buffer, bottom.start, bottom.end, &theme, 100);
+        assert_eq!(highlighter.caches.len(), 2);
+
+        // Revisiting either viewport is served from cache: no new parse
+        // is recorded, and the spans are the same.
+        let second_top = highlighter.highlight_viewport(&buffer, top.start, top.end, &theme, 100);
+        highlighter.highlight_viewport(&buffer, bottom.start, bottom.end, &theme, 100);
+        assert_eq!(highlighter.caches.len(), 2);
+        assert_eq!(
+            first_top
+                .iter()
+                .map(|s| s.range.clone())
+                .collect::<Vec<_>>(),
+            second_top
+                .iter()
+                .map(|s| s.range.clone())
+                .collect::<Vec<_>>()
+        );
+
+        // An edit drops only the ranges it touches.
+        highlighter.invalidate_range(100..101);
+        assert_eq!(highlighter.caches.len(), 1);
+        assert!(highlighter.caches[0].range.contains(&bottom.start));
+    }
+
+    #[test]
+    fn test_edits_shift_cached_ranges_between_them() {
+        let mut buffer = Buffer::from_str_test(&numbered_source(1000));
+        let mut highlighter = Highlighter::new(Language::TypeScript).unwrap();
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let middle = 5_000..5_400;
+        highlighter.highlight_viewport(&buffer, middle.start, middle.end, &theme, 100);
+
+        // An insert above the range and a delete below it that cancel out
+        // in length, with no render in between.
+        let row = "const x = 1;\n";
+        buffer.insert(100, row);
+        highlighter.notify_insert(100, row.len());
+        highlighter.invalidate_range(100..100 + row.len());
+        buffer.delete(14_000..14_000 + row.len());
+        highlighter.notify_delete(14_000, row.len());
+        highlighter.invalidate_range(14_000..14_000 + row.len());
+        assert_eq!(highlighter.caches.len(), 1);
+
+        let shifted = middle.start + row.len()..middle.end + row.len();
+        let cached =
+            highlighter.highlight_viewport(&buffer, shifted.start, shifted.end, &theme, 100);
+        let fresh = Highlighter::new(Language::TypeScript)
+            .unwrap()
+            .highlight_viewport(&buffer, shifted.start, shifted.end, &theme, 100);
+        let ranges = |spans: &[HighlightSpan]| {
+            spans
+                .iter()
+                .map(|s| (s.range.clone(), s.category))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranges(&cached), ranges(&fresh));
+    }
+
+    #[test]
+    fn test_cache_keeps_only_recent_ranges() {
+        let buffer = Buffer::from_str_test(&numbered_source(1000));
+        let mut highlighter = Highlighter::new(Language::TypeScript).unwrap();
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+
+        for i in 0..MAX_CACHED_RANGES + 2 {
+            let start = i * 2_000;
+            highlighter.highlight_viewport(&buffer, start, start + 200, &theme, 0);
+        }
+        assert_eq!(highlighter.caches.len(), MAX_CACHED_RANGES);
+        // The oldest ranges were evicted; the newest is first.
+        assert_eq!(
+            highlighter.caches[0].range.start,
+            (MAX_CACHED_RANGES + 1) * 2_000
+        );
+        assert!(highlighter.caches.iter().all(|c| c.range.start >= 4_000));
     }
 
     #[test]
@@ -15,6 +15,7 @@
 //! | Reference highlighting | `reference_highlight_text` | `reference_highlighter` |
 
 // Pure modules - available for both runtime and WASM
+pub mod bidi;
 pub mod display_width;
 pub mod glob_match;
 pub mod grapheme;
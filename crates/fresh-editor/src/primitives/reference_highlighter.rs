@@ -45,6 +45,9 @@ pub struct ReferenceHighlighter {
     pub min_word_length: usize,
     /// Whether semantic highlighting is enabled
     pub enabled: bool,
+    /// Extra ASCII characters that are part of a word in text-matching
+    /// mode (the language's `word_characters`, e.g. `-` for CSS)
+    pub word_chars_extra: String,
     /// Tree-sitter parser (optional, for syntax-aware highlighting)
     parser: Option<Parser>,
     /// Query to find identifier nodes (fallback when locals not available)
@@ -63,6 +66,15 @@ struct LocalsCaptures {
     reference: Option<u32>,
 }
 
+/// Output of one locals-query pass: `(range, name, scope_id)` definitions
+/// (`usize::MAX` = no enclosing scope) and `(range, name)` references.
+#[derive(Default)]
+struct LocalsIndex {
+    scopes: Vec<Range<usize>>,
+    definitions: Vec<(Range<usize>, String, usize)>,
+    references: Vec<(Range<usize>, String)>,
+}
+
 /// Query pattern to find identifier nodes
 /// Note: Different languages use different node types for identifiers.
 /// We use just (identifier) which works for most C-family languages.
@@ -88,6 +100,7 @@ const RUST_LOCALS_QUERY: &str = r#"
 ; Scopes
 (function_item body: (_) @local.scope)
 (closure_expression body: (_) @local.scope)
+(block) @local.scope
 
 ; Definitions - parameters
 ((parameter pattern: (identifier) @local.definition))
@@ -184,6 +197,7 @@ impl ReferenceHighlighter {
             highlight_color: DEFAULT_HIGHLIGHT_COLOR,
             min_word_length: 2,
             enabled: true,
+            word_chars_extra: String::new(),
             parser: None,
             identifier_query: None,
             locals_query: None,
@@ -387,86 +401,24 @@ impl ReferenceHighlighter {
         viewport_end: usize,
         context_bytes: usize,
     ) -> Vec<HighlightSpan> {
-        let parser = match &mut self.parser {
-            Some(p) => p,
-            None => return Vec::new(),
-        };
-        let query = match &self.locals_query {
-            Some(q) => q,
-            None => return Vec::new(),
-        };
-
-        let def_idx = match self.locals_captures.definition {
-            Some(i) => i,
-            None => return Vec::new(),
-        };
-        let ref_idx = match self.locals_captures.reference {
-            Some(i) => i,
-            None => return Vec::new(),
-        };
-        let scope_idx = self.locals_captures.scope;
-
         // Parse the entire visible region plus context
         let parse_start = viewport_start.saturating_sub(context_bytes);
         let parse_end = (viewport_end + context_bytes).min(buffer.len());
-        let source = buffer.slice_bytes(parse_start..parse_end);
-
-        // Parse the source
-        let tree = match parser.parse(&source, None) {
-            Some(t) => t,
-            None => {
-                return self.highlight_with_tree_sitter(
-                    buffer,
-                    cursor_position,
-                    viewport_start,
-                    viewport_end,
-                    context_bytes,
-                );
-            }
+        let Some(LocalsIndex {
+            scopes,
+            definitions,
+            references,
+        }) = self.collect_locals(buffer, parse_start, parse_end)
+        else {
+            return self.highlight_with_tree_sitter(
+                buffer,
+                cursor_position,
+                viewport_start,
+                viewport_end,
+                context_bytes,
+            );
         };
 
-        // Run the locals query
-        let mut query_cursor = QueryCursor::new();
-        let mut matches = query_cursor.matches(query, tree.root_node(), source.as_slice());
-
-        // Collect scopes, definitions, and references
-        let mut scopes: Vec<Range<usize>> = Vec::new();
-        let mut definitions: Vec<(Range<usize>, String, usize)> = Vec::new(); // (range, name, scope_id)
-        let mut references: Vec<(Range<usize>, String)> = Vec::new();
-
-        // Build scope stack for each position
-        while let Some(m) = matches.next() {
-            for capture in m.captures {
-                let node = capture.node;
-                let start = parse_start + node.start_byte();
-                let end = parse_start + node.end_byte();
-                let range = start..end;
-
-                // Get the text
-                let text_bytes = &source[node.start_byte()..node.end_byte()];
-                let text = match std::str::from_utf8(text_bytes) {
-                    Ok(s) => s.to_string(),
-                    Err(_) => continue,
-                };
-
-                if Some(capture.index) == scope_idx {
-                    scopes.push(range);
-                } else if capture.index == def_idx {
-                    // Find which scope this definition is in
-                    let scope_id = scopes
-                        .iter()
-                        .enumerate()
-                        .filter(|(_, s)| s.start <= start && end <= s.end)
-                        .map(|(i, _)| i)
-                        .next_back()
-                        .unwrap_or(usize::MAX);
-                    definitions.push((range, text, scope_id));
-                } else if capture.index == ref_idx {
-                    references.push((range, text));
-                }
-            }
-        }
-
         // Find what's under the cursor
         let cursor_item = definitions
             .iter()
@@ -593,6 +545,127 @@ impl ReferenceHighlighter {
         highlights
     }
 
+    /// Run the locals query over `parse_start..parse_end` and collect its
+    /// scopes, definitions and references (absolute byte ranges).
+    ///
+    /// Returns `None` when locals mode is unavailable or parsing fails.
+    fn collect_locals(
+        &mut self,
+        buffer: &Buffer,
+        parse_start: usize,
+        parse_end: usize,
+    ) -> Option<LocalsIndex> {
+        let parser = self.parser.as_mut()?;
+        let query = self.locals_query.as_ref()?;
+        let def_idx = self.locals_captures.definition?;
+        let ref_idx = self.locals_captures.reference?;
+        let scope_idx = self.locals_captures.scope;
+
+        let source = buffer.slice_bytes(parse_start..parse_end);
+        let tree = parser.parse(&source, None)?;
+
+        // Run the locals query
+        let mut query_cursor = QueryCursor::new();
+        let mut matches = query_cursor.matches(query, tree.root_node(), source.as_slice());
+
+        let mut index = LocalsIndex::default();
+
+        // Build scope stack for each position
+        while let Some(m) = matches.next() {
+            for capture in m.captures {
+                let node = capture.node;
+                let start = parse_start + node.start_byte();
+                let end = parse_start + node.end_byte();
+                let range = start..end;
+
+                // Get the text
+                let text_bytes = &source[node.start_byte()..node.end_byte()];
+                let text = match std::str::from_utf8(text_bytes) {
+                    Ok(s) => s.to_string(),
+                    Err(_) => continue,
+                };
+
+                if Some(capture.index) == scope_idx {
+                    index.scopes.push(range);
+                } else if capture.index == def_idx {
+                    // Find which scope this definition is in
+                    let scope_id = index
+                        .scopes
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, s)| s.start <= start && end <= s.end)
+                        .map(|(i, _)| i)
+                        .next_back()
+                        .unwrap_or(usize::MAX);
+                    index.definitions.push((range, text, scope_id));
+                } else if capture.index == ref_idx {
+                    index.references.push((range, text));
+                }
+            }
+        }
+
+        Some(index)
+    }
+
+    /// Find the local definition of the identifier at `cursor_position`
+    /// using the locals query (scope-aware, within this buffer only).
+    ///
+    /// Used as the go-to-definition fallback when no language server is
+    /// available. Returns the definition's byte range, or `None` when
+    /// locals mode is unavailable or the identifier isn't a local (e.g. a
+    /// function or type name, which the locals queries don't track).
+    pub fn find_local_definition(
+        &mut self,
+        buffer: &Buffer,
+        cursor_position: usize,
+    ) -> Option<Range<usize>> {
+        if !self.has_locals() {
+            return None;
+        }
+
+        let parse_start = cursor_position.saturating_sub(Self::MAX_SEARCH_RANGE);
+        let parse_end = (cursor_position + Self::MAX_SEARCH_RANGE).min(buffer.len());
+        let LocalsIndex {
+            scopes,
+            definitions,
+            references,
+        } = self.collect_locals(buffer, parse_start, parse_end)?;
+
+        let contains = |outer: &Range<usize>, inner: &Range<usize>| {
+            outer.start <= inner.start && inner.end <= outer.end
+        };
+
+        // Already on a definition: that's the answer.
+        if let Some((range, _, _)) = definitions
+            .iter()
+            .find(|(range, _, _)| range.start <= cursor_position && cursor_position <= range.end)
+        {
+            return Some(range.clone());
+        }
+
+        let (cursor_range, target_name) = references
+            .iter()
+            .find(|(range, _)| range.start <= cursor_position && cursor_position <= range.end)?;
+
+        // Visible definitions: same name, declared before the reference, in
+        // a scope enclosing it. The innermost scope wins; within one scope
+        // the latest declaration shadows earlier ones.
+        definitions
+            .iter()
+            .filter(|(range, name, scope_id)| {
+                name == target_name
+                    && range.end <= cursor_range.start
+                    && scopes
+                        .get(*scope_id)
+                        .is_none_or(|scope| contains(scope, cursor_range))
+            })
+            .min_by_key(|(range, _, scope_id)| {
+                let scope_len = scopes.get(*scope_id).map_or(usize::MAX, |s| s.len());
+                (scope_len, std::cmp::Reverse(range.start))
+            })
+            .map(|(range, _, _)| range.clone())
+    }
+
     /// Tree-sitter based highlighting that finds identifier nodes
     fn highlight_with_tree_sitter(
         &mut self,
@@ -737,27 +810,21 @@ impl ReferenceHighlighter {
     /// Get the word range at the given position
     ///
     /// Returns None if the cursor is not on a word character.
-    fn get_word_at_position(&self, buffer: &Buffer, position: usize) -> Option<Range<usize>> {
+    pub fn get_word_at_position(&self, buffer: &Buffer, position: usize) -> Option<Range<usize>> {
         let buf_len = buffer.len();
         if position > buf_len {
             return None;
         }
 
+        let byte_at = |pos: usize| buffer.slice_bytes(pos..pos + 1).first().copied();
+
         // Check if cursor is on a word character
         // Need to handle cursor at end of buffer
         let is_on_word = if position < buf_len {
-            let byte_at_pos = buffer.slice_bytes(position..position + 1);
-            byte_at_pos
-                .first()
-                .map(|&b| is_word_char(b))
-                .unwrap_or(false)
+            byte_at(position).is_some_and(|b| self.is_word_byte(b))
         } else if position > 0 {
             // Cursor at end of buffer - check previous character
-            let byte_before = buffer.slice_bytes(position - 1..position);
-            byte_before
-                .first()
-                .map(|&b| is_word_char(b))
-                .unwrap_or(false)
+            byte_at(position - 1).is_some_and(|b| self.is_word_byte(b))
         } else {
             false
         };
@@ -766,20 +833,16 @@ impl ReferenceHighlighter {
             // Check if we're just after a word AND the cursor is at end of buffer
             // or the character before was a word char but current is not
             // This handles cursor positioned right after a word (e.g., at end of "foo|")
-            let byte_before = buffer.slice_bytes(position.saturating_sub(1)..position);
-            let is_after_word = byte_before
-                .first()
-                .map(|&b| is_word_char(b))
-                .unwrap_or(false);
+            let is_after_word = byte_at(position - 1).is_some_and(|b| self.is_word_byte(b));
 
             // Only use "word before cursor" if we're at end of buffer
             // Otherwise, cursor on whitespace/punctuation should not highlight
             if is_after_word && position >= buf_len {
                 // Use the word before cursor
-                let start = find_word_start(buffer, position.saturating_sub(1));
-                let end = position;
-                if start < end {
-                    return Some(start..end);
+                let start = find_word_start(buffer, position - 1);
+                let range = self.extend_over_extra_chars(buffer, start..position);
+                if range.start < range.end {
+                    return Some(range);
                 }
             }
             return None;
@@ -790,16 +853,54 @@ impl ReferenceHighlighter {
         }
 
         // Find word boundaries
-        let start = find_word_start(buffer, position);
-        let end = find_word_end(buffer, position);
+        let range = if position >= buf_len {
+            find_word_start(buffer, position)..buf_len
+        } else if byte_at(position).is_some_and(is_word_char) {
+            find_word_start(buffer, position)..find_word_end(buffer, position)
+        } else {
+            // On one of the extra word characters
+            position..position + 1
+        };
+        let range = self.extend_over_extra_chars(buffer, range);
 
-        if start < end {
-            Some(start..end)
+        if range.start < range.end {
+            Some(range)
         } else {
             None
         }
     }
 
+    /// Whether `byte` is part of a word: alphanumeric, `_`, or one of
+    /// `word_chars_extra`.
+    fn is_word_byte(&self, byte: u8) -> bool {
+        is_word_char(byte) || (byte.is_ascii() && self.word_chars_extra.contains(byte as char))
+    }
+
+    /// Grow `range` across adjacent `word_chars_extra` characters and the
+    /// words they join, so `foo-bar` is one word when `-` is an extra.
+    fn extend_over_extra_chars(&self, buffer: &Buffer, range: Range<usize>) -> Range<usize> {
+        if self.word_chars_extra.is_empty() {
+            return range;
+        }
+        let byte_at = |pos: usize| buffer.slice_bytes(pos..pos + 1).first().copied();
+        let Range { mut start, mut end } = range;
+        while start > 0 {
+            match byte_at(start - 1) {
+                Some(b) if is_word_char(b) => start = find_word_start(buffer, start - 1),
+                Some(b) if self.is_word_byte(b) => start -= 1,
+                _ => break,
+            }
+        }
+        while end < buffer.len() {
+            match byte_at(end) {
+                Some(b) if is_word_char(b) => end = find_word_end(buffer, end),
+                Some(b) if self.is_word_byte(b) => end += 1,
+                _ => break,
+            }
+        }
+        start..end
+    }
+
     /// Maximum search range for semantic highlighting (1MB)
     /// Beyond this, skip highlighting to avoid performance issues with huge single-line files
     const MAX_SEARCH_RANGE: usize = 1024 * 1024;
@@ -839,12 +940,18 @@ impl ReferenceHighlighter {
             // Check if this is a whole word match (not part of a larger word)
             let is_word_start = abs_start == 0 || {
                 let prev_byte = buffer.slice_bytes(abs_start - 1..abs_start);
-                prev_byte.first().map(|&b| !is_word_char(b)).unwrap_or(true)
+                prev_byte
+                    .first()
+                    .map(|&b| !self.is_word_byte(b))
+                    .unwrap_or(true)
             };
 
             let is_word_end = abs_end >= buffer.len() || {
                 let next_byte = buffer.slice_bytes(abs_end..abs_end + 1);
-                next_byte.first().map(|&b| !is_word_char(b)).unwrap_or(true)
+                next_byte
+                    .first()
+                    .map(|&b| !self.is_word_byte(b))
+                    .unwrap_or(true)
             };
 
             if is_word_start && is_word_end {
@@ -910,6 +1017,30 @@ mod tests {
         assert_eq!(occurrences[0], 7..10);
     }
 
+    #[test]
+    fn test_word_chars_extra() {
+        let buffer = Buffer::from_str_test("foo-bar foo foo-bar foo");
+        let mut highlighter = ReferenceHighlighter::new();
+
+        // Without extras `-` splits words: "foo" occurs four times
+        let spans = highlighter.highlight_occurrences(&buffer, 0, 0, buffer.len(), 100_000);
+        assert_eq!(spans.len(), 4);
+
+        highlighter.word_chars_extra = "-".to_string();
+        assert_eq!(highlighter.get_word_at_position(&buffer, 5), Some(0..7));
+        assert_eq!(highlighter.get_word_at_position(&buffer, 3), Some(0..7));
+
+        // "foo-bar" is one word, so the cursor on it matches both copies
+        let spans = highlighter.highlight_occurrences(&buffer, 0, 0, buffer.len(), 100_000);
+        let ranges: Vec<_> = spans.into_iter().map(|s| s.range).collect();
+        assert_eq!(ranges, vec![0..7, 12..19]);
+
+        // ...and a bare "foo" no longer matches inside "foo-bar"
+        let spans = highlighter.highlight_occurrences(&buffer, 8, 0, buffer.len(), 100_000);
+        let ranges: Vec<_> = spans.into_iter().map(|s| s.range).collect();
+        assert_eq!(ranges, vec![8..11, 20..23]);
+    }
+
     #[test]
     fn test_highlight_occurrences() {
         let buffer = Buffer::from_str_test("let foo = 1;\nlet bar = foo;\nlet baz = foo;");
@@ -1124,4 +1255,86 @@ fn greet(name: &str) {
             spans.len()
         );
     }
+
+    /// A reference to a local variable resolves to its `let` binding, and
+    /// the innermost shadowing binding wins.
+    #[test]
+    fn test_find_local_definition_rust() {
+        use crate::primitives::highlighter::Language;
+
+        // The Rust grammar is only present in builds that compile it in;
+        // without it there is no locals mode to exercise.
+        if Language::Rust.ts_language().is_none() {
+            return;
+        }
+
+        let code = r#"
+fn main() {
+    let total = 1;
+    let other = total + 1;
+    {
+        let total = 2;
+        println!("{}", total);
+    }
+    println!("{}", total);
+}
+"#;
+        let buffer = Buffer::from_str_test(code);
+        let mut highlighter = ReferenceHighlighter::new();
+        highlighter.set_language(&Language::Rust);
+        assert!(highlighter.has_locals());
+
+        let outer_def = code.find("let total = 1").unwrap() + 4;
+        let inner_def = code.find("let total = 2").unwrap() + 4;
+
+        let use_outer = code.find("other = total").unwrap() + 8;
+        assert_eq!(
+            highlighter.find_local_definition(&buffer, use_outer),
+            Some(outer_def..outer_def + 5)
+        );
+
+        let use_inner = code.find("\"{}\", total);\n    }").unwrap() + 6;
+        assert_eq!(
+            highlighter.find_local_definition(&buffer, use_inner),
+            Some(inner_def..inner_def + 5)
+        );
+
+        let use_after_block = code.rfind("total").unwrap();
+        assert_eq!(
+            highlighter.find_local_definition(&buffer, use_after_block),
+            Some(outer_def..outer_def + 5)
+        );
+
+        // `main` is a function name, which the locals query doesn't track.
+        let main_pos = code.find("main").unwrap();
+        assert_eq!(highlighter.find_local_definition(&buffer, main_pos), None);
+    }
+
+    /// Same lookup against the bundled Go grammar: a parameter reference
+    /// resolves to the parameter, a `:=` reference to its declaration.
+    #[test]
+    #[cfg(feature = "tree-sitter")]
+    fn test_find_local_definition_go() {
+        use crate::primitives::highlighter::Language;
+
+        let code =
+            "package main\n\nfunc add(count int) int {\n\tsum := count + 1\n\treturn sum\n}\n";
+        let buffer = Buffer::from_str_test(code);
+        let mut highlighter = ReferenceHighlighter::new();
+        highlighter.set_language(&Language::Go);
+
+        let param = code.find("count int").unwrap();
+        let param_use = code.find("count + 1").unwrap() + 2;
+        assert_eq!(
+            highlighter.find_local_definition(&buffer, param_use),
+            Some(param..param + 5)
+        );
+
+        let sum_def = code.find("sum :=").unwrap();
+        let sum_use = code.find("return sum").unwrap() + 7;
+        assert_eq!(
+            highlighter.find_local_definition(&buffer, sum_use),
+            Some(sum_def..sum_def + 3)
+        );
+    }
 }
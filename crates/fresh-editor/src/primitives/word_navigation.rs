@@ -4,13 +4,13 @@ use crate::model::buffer::Buffer;
 use crate::primitives::grapheme::{next_grapheme_boundary, prev_grapheme_boundary};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum CharClass {
+pub(crate) enum CharClass {
     Word,
     Whitespace,
     Punctuation,
 }
 
-fn get_grapheme_class(g: &str) -> CharClass {
+pub(crate) fn get_grapheme_class(g: &str) -> CharClass {
     if g.chars().any(|c| c.is_alphanumeric() || c == '_') {
         CharClass::Word
     } else if g.chars().all(|c| c.is_whitespace()) {
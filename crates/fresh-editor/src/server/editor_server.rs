@@ -886,7 +886,9 @@ impl EditorServer {
         conn.write_data(&setup)?;
 
         // Send cursor style (from editor if running, otherwise from config)
-        conn.write_data(cursor_style.to_escape_sequence())?;
+        if crate::config::CursorStyle::is_supported_by_term(hello.term()) {
+            conn.write_data(cursor_style.to_escape_sequence())?;
+        }
 
         tracing::debug!(
             "Client {} connected: {}x{}, TERM={:?}",
@@ -1261,6 +1263,8 @@ impl EditorServer {
                         .handle_key(key_event.code, key_event.modifiers)
                         .map_err(|e| io::Error::other(e.to_string()))?;
                     Ok(true)
+                } else if key_event.kind == KeyEventKind::Release {
+                    Ok(editor.handle_key_release(key_event))
                 } else {
                     Ok(false)
                 }
@@ -132,6 +132,8 @@ pub enum AsyncMessage {
     /// LSP server crashed or failed
     LspError {
         language: String,
+        /// Name of the server that failed (for per-server status tracking)
+        server_name: String,
         error: String,
         /// Path to the stderr log file for this LSP session
         stderr_log_path: Option<std::path::PathBuf>,
@@ -781,6 +783,7 @@ mod tests {
         sender
             .send(AsyncMessage::LspError {
                 language: "rust".to_string(),
+                server_name: "rust-analyzer".to_string(),
                 error: "Failed to initialize".to_string(),
                 stderr_log_path: None,
             })
@@ -792,10 +795,12 @@ mod tests {
         match &messages[0] {
             AsyncMessage::LspError {
                 language,
+                server_name,
                 error,
                 stderr_log_path,
             } => {
                 assert_eq!(language, "rust");
+                assert_eq!(server_name, "rust-analyzer");
                 assert_eq!(error, "Failed to initialize");
                 assert!(stderr_log_path.is_none());
             }
@@ -36,7 +36,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
 use std::sync::{mpsc as std_mpsc, Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
@@ -104,6 +104,7 @@ fn is_informational_method(method: &str) -> bool {
             | "textDocument/documentSymbol"
             | "textDocument/inlayHint"
             | "textDocument/foldingRange"
+            | "workspace/symbol"
     )
 }
 
@@ -359,12 +360,13 @@ fn create_client_capabilities() -> ClientCapabilities {
         CodeActionClientCapabilities, CodeActionKindLiteralSupport, CodeActionLiteralSupport,
         CompletionClientCapabilities, DiagnosticClientCapabilities, DiagnosticTag,
         DiagnosticWorkspaceClientCapabilities, DocumentFormattingClientCapabilities,
-        DocumentHighlightClientCapabilities, DocumentRangeFormattingClientCapabilities,
-        DocumentSymbolClientCapabilities, DynamicRegistrationClientCapabilities,
-        FoldingRangeCapability, FoldingRangeClientCapabilities, FoldingRangeKind,
-        FoldingRangeKindCapability, GeneralClientCapabilities, GotoCapability,
-        HoverClientCapabilities, InlayHintClientCapabilities, InlayHintWorkspaceClientCapabilities,
-        MarkupKind, PublishDiagnosticsClientCapabilities, RenameClientCapabilities,
+        DocumentHighlightClientCapabilities, DocumentLinkClientCapabilities,
+        DocumentRangeFormattingClientCapabilities, DocumentSymbolClientCapabilities,
+        DynamicRegistrationClientCapabilities, FoldingRangeCapability,
+        FoldingRangeClientCapabilities, FoldingRangeKind, FoldingRangeKindCapability,
+        GeneralClientCapabilities, GotoCapability, HoverClientCapabilities,
+        InlayHintClientCapabilities, InlayHintWorkspaceClientCapabilities, MarkupKind,
+        PublishDiagnosticsClientCapabilities, RenameClientCapabilities,
         SemanticTokensWorkspaceClientCapabilities, SignatureHelpClientCapabilities, TagSupport,
         TextDocumentClientCapabilities, TextDocumentSyncClientCapabilities,
         WorkspaceClientCapabilities, WorkspaceEditClientCapabilities,
@@ -449,13 +451,27 @@ fn create_client_capabilities() -> ClientCapabilities {
             references: Some(DynamicRegistrationClientCapabilities {
                 dynamic_registration: Some(true),
             }),
+            // Consumed by the `call_hierarchy` plugin through
+            // `sendLspRequest`; the editor core only advertises it.
+            call_hierarchy: Some(DynamicRegistrationClientCapabilities {
+                dynamic_registration: Some(true),
+            }),
             document_highlight: Some(DocumentHighlightClientCapabilities {
                 dynamic_registration: Some(true),
             }),
+            // Hierarchical symbols let the outline plugin show nesting
+            // without reconstructing it from flat ranges.
             document_symbol: Some(DocumentSymbolClientCapabilities {
                 dynamic_registration: Some(true),
+                hierarchical_document_symbol_support: Some(true),
                 ..Default::default()
             }),
+            // Consumed by the `document_links` plugin, which paints each
+            // link as an overlay the editor follows on Ctrl+Click.
+            document_link: Some(DocumentLinkClientCapabilities {
+                dynamic_registration: Some(true),
+                tooltip_support: Some(true),
+            }),
             formatting: Some(DocumentFormattingClientCapabilities {
                 dynamic_registration: Some(true),
             }),
@@ -2827,8 +2843,9 @@ struct LspTask {
     next_id: i64,
 
     /// Pending requests waiting for response, keyed by request id. The stored
-    /// `String` is the request method (see `PendingRequests`).
-    pending: HashMap<i64, (String, oneshot::Sender<Result<Value, String>>)>,
+    /// `String` is the request method (see `PendingRequests`). Shared with the
+    /// owning `LspHandle` so the status popup can show the in-flight count.
+    pending: PendingRequests,
 
     /// Server capabilities
     capabilities: Option<ServerCapabilities>,
@@ -2884,6 +2901,8 @@ impl LspTask {
         stderr_log_path: std::path::PathBuf,
         language_id_overrides: HashMap<String, String>,
         document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>>,
+        pending: PendingRequests,
+        pid: Arc<AtomicU32>,
         long_running_spawner: Arc<dyn crate::services::remote::LongRunningSpawner>,
     ) -> Result<Self, String> {
         tracing::info!("Spawning async LSP server: {} {:?}", command, args);
@@ -2914,6 +2933,10 @@ impl LspTask {
             .await
             .map_err(|e| format!("Failed to spawn LSP server '{}': {}", command, e))?;
 
+        // 0 means "unknown" (the child already exited, or the spawner
+        // couldn't report one); `LspHandle::pid` maps it back to `None`.
+        pid.store(stdio_child.id().unwrap_or(0), Ordering::Relaxed);
+
         let stdin = stdio_child
             .take_stdin()
             .ok_or_else(|| "Failed to get stdin".to_string())?;
@@ -2970,7 +2993,7 @@ impl LspTask {
             stdin,
             stdout,
             next_id: 0,
-            pending: HashMap::new(),
+            pending,
             capabilities: None,
             document_versions,
             pending_opens: HashMap::new(),
@@ -3041,6 +3064,7 @@ impl LspTask {
                             });
                             let _ = async_tx.send(AsyncMessage::LspError {
                                 language: language.clone(),
+                                server_name: server_name.clone(),
                                 error: format!("Read error: {}", e),
                                 stderr_log_path: Some(stderr_log_path.clone()),
                             });
@@ -3102,7 +3126,7 @@ impl LspTask {
             language_id_overrides: Arc::new(self.language_id_overrides.clone()),
         };
 
-        let pending = Arc::new(Mutex::new(self.pending));
+        let pending = self.pending;
         let async_tx = state.async_tx.clone();
         let language_clone: String = (*state.language).clone();
         let server_name: String = (*state.server_name).clone();
@@ -4490,6 +4514,14 @@ pub struct LspHandle {
     /// Document version tracking (shared with the async LSP task).
     /// Used to check document versions in workspace/applyEdit.
     document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>>,
+
+    /// Requests awaiting a response (shared with the async LSP task). Only
+    /// read for its length, to surface the in-flight count in the UI.
+    pending: PendingRequests,
+
+    /// OS process id of the server, written by the async task once the
+    /// process is spawned. 0 until then.
+    pid: Arc<AtomicU32>,
 }
 
 // Channel sends and state transitions in LspHandle are best-effort: async_tx.send()
@@ -4544,6 +4576,10 @@ impl LspHandle {
         let document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>> =
             Arc::new(std::sync::Mutex::new(HashMap::new()));
         let document_versions_for_task = document_versions.clone();
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let pending_for_task = pending.clone();
+        let pid = Arc::new(AtomicU32::new(0));
+        let pid_for_task = pid.clone();
 
         let state_clone = state.clone();
         let stderr_log_path_clone = stderr_log_path.clone();
@@ -4559,6 +4595,8 @@ impl LspHandle {
                 stderr_log_path_clone.clone(),
                 language_id_overrides,
                 document_versions_for_task,
+                pending_for_task,
+                pid_for_task,
                 long_running_spawner,
             )
             .await
@@ -4614,6 +4652,7 @@ impl LspHandle {
                     });
                     let _ = async_tx.send(AsyncMessage::LspError {
                         language: language_clone,
+                        server_name: server_name_clone,
                         error: e,
                         stderr_log_path: Some(stderr_log_path_clone),
                     });
@@ -4630,6 +4669,8 @@ impl LspHandle {
             state,
             runtime: runtime.clone(),
             document_versions,
+            pending,
+            pid,
         })
     }
 
@@ -4638,6 +4679,22 @@ impl LspHandle {
         self.id
     }
 
+    /// OS process id of the server, once spawned.
+    ///
+    /// For container authorities this is the `docker exec` wrapper rather
+    /// than the server itself (see `StdioChild::id`).
+    pub fn pid(&self) -> Option<u32> {
+        match self.pid.load(Ordering::Relaxed) {
+            0 => None,
+            pid => Some(pid),
+        }
+    }
+
+    /// Number of requests sent to the server that haven't been answered yet.
+    pub fn pending_request_count(&self) -> usize {
+        self.pending.lock().map(|p| p.len()).unwrap_or(0)
+    }
+
     /// Get the language scope this handle serves.
     pub fn scope(&self) -> &crate::services::lsp::manager::LanguageScope {
         &self.scope
@@ -5444,6 +5501,13 @@ mod tests {
             Some(true),
             "document_symbol must advertise dynamicRegistration"
         );
+        assert_eq!(
+            td.document_symbol
+                .as_ref()
+                .and_then(|c| c.hierarchical_document_symbol_support),
+            Some(true),
+            "document_symbol must advertise hierarchical symbols"
+        );
         assert_eq!(
             caps.workspace
                 .as_ref()
@@ -5627,6 +5691,10 @@ mod tests {
             LSP_ERROR_REQUEST_FAILED,
             "textDocument/completion"
         ));
+        assert!(is_suppressed_response_error(
+            LSP_ERROR_REQUEST_FAILED,
+            "workspace/symbol"
+        ));
 
         // ...but a RequestFailed from a mutating/actionable method is a real
         // problem the user should be able to see.
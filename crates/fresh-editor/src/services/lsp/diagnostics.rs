@@ -4,7 +4,7 @@
 //! Diagnostics are displayed as colored underlines (red for errors, yellow for warnings, etc.)
 use crate::model::buffer::Buffer;
 use crate::state::EditorState;
-use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use crate::view::overlay::{priority, Overlay, OverlayFace, OverlayNamespace};
 use lsp_types::{Diagnostic, DiagnosticSeverity, Position};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
@@ -93,7 +93,8 @@ pub fn invalidate_cache_all() {
 /// This is the recommended entry point that skips redundant work when diagnostics haven't changed.
 /// On a typical keystroke, diagnostics don't change, so this returns immediately.
 ///
-/// Returns `true` if overlays were actually updated (cache miss), `false` if skipped (cache hit).
+/// Returns `true` if overlays were actually updated, `false` if skipped (cache hit, or
+/// a cache miss whose diagnostics still match the existing overlays).
 pub fn apply_diagnostics_to_state_cached(
     state: &mut EditorState,
     diagnostics: &[Diagnostic],
@@ -102,10 +103,7 @@ pub fn apply_diagnostics_to_state_cached(
     // Get cache key from buffer's file path
     let cache_key = match state.buffer.file_path() {
         Some(path) => path.to_string_lossy().to_string(),
-        None => {
-            apply_diagnostics_to_state(state, diagnostics, theme);
-            return true;
-        }
+        None => return apply_diagnostics_to_state(state, diagnostics, theme),
     };
 
     // Compute hash of incoming diagnostics
@@ -134,15 +132,15 @@ pub fn apply_diagnostics_to_state_cached(
         new_hash
     );
 
-    // Diagnostics have changed, do the expensive update
-    apply_diagnostics_to_state(state, diagnostics, theme);
+    // Diagnostics may have changed, diff them against the overlays
+    let updated = apply_diagnostics_to_state(state, diagnostics, theme);
 
     // Update cache for this buffer
     if let Ok(mut cache) = DIAGNOSTIC_CACHE.lock() {
         cache.insert(cache_key, new_hash);
     }
 
-    true
+    updated
 }
 
 /// Convert an LSP diagnostic to an overlay (range, face, priority)
@@ -184,28 +182,28 @@ pub fn diagnostic_to_overlay(
             OverlayFace::Background {
                 color: theme.diagnostic_error_bg,
             },
-            100, // Highest priority
+            priority::DIAGNOSTIC_ERROR,
             "diagnostic.error_bg",
         ),
         Some(DiagnosticSeverity::WARNING) => (
             OverlayFace::Background {
                 color: theme.diagnostic_warning_bg,
             },
-            50, // Medium priority
+            priority::DIAGNOSTIC_WARNING,
             "diagnostic.warning_bg",
         ),
         Some(DiagnosticSeverity::INFORMATION) => (
             OverlayFace::Background {
                 color: theme.diagnostic_info_bg,
             },
-            30, // Lower priority
+            priority::DIAGNOSTIC_INFO,
             "diagnostic.info_bg",
         ),
         Some(DiagnosticSeverity::HINT) | None => (
             OverlayFace::Background {
                 color: theme.diagnostic_hint_bg,
             },
-            10, // Lowest priority
+            priority::DIAGNOSTIC_HINT,
             "diagnostic.hint_bg",
         ),
         _ => return None, // Unknown severity
@@ -216,40 +214,108 @@ pub fn diagnostic_to_overlay(
 
 /// Apply LSP diagnostics to editor state as overlays
 ///
-/// This function:
-/// 1. Clears all existing LSP diagnostic overlays (using namespace)
-/// 2. Adds overlays for all current diagnostics
+/// This function diffs the diagnostics against the existing LSP diagnostic
+/// overlays (using namespace): overlays that still match a diagnostic are
+/// kept, the rest are removed, and only new diagnostics get overlays. A
+/// re-publish of the same set (after an edit shifted everything, both
+/// ends shift the same way) therefore leaves the overlays untouched
+/// instead of clearing and rebuilding them, which is what made the
+/// underlines flicker while typing.
+///
+/// Returns `true` if any overlay was added or removed.
 pub fn apply_diagnostics_to_state(
     state: &mut EditorState,
     diagnostics: &[Diagnostic],
     theme: &crate::view::theme::Theme,
-) {
+) -> bool {
     let ns = lsp_diagnostic_namespace();
 
-    // Clear all existing LSP diagnostic overlays using namespace
-    state.overlays.clear_namespace(&ns, &mut state.marker_list);
-
-    // Add overlays for all current diagnostics
-    let mut added_count = 0;
+    // Overlays wanted for the current diagnostics, keyed by everything
+    // that identifies one on screen except the face (not hashable).
+    type Key = (usize, usize, i32, &'static str, String);
+    let mut wanted: HashMap<Key, Vec<OverlayFace>> = HashMap::new();
     for diagnostic in diagnostics {
         if let Some((range, face, priority, theme_key)) =
             diagnostic_to_overlay(diagnostic, &state.buffer, theme)
         {
-            let message = diagnostic.message.clone();
-
-            let overlay = Overlay::with_namespace(&mut state.marker_list, range, face, ns.clone())
-                .with_priority_value(priority)
-                .with_message(message)
-                .with_theme_key(theme_key);
+            wanted
+                .entry((
+                    range.start,
+                    range.end,
+                    priority,
+                    theme_key,
+                    diagnostic.message.clone(),
+                ))
+                .or_default()
+                .push(face);
+        }
+    }
 
-            state.overlays.add(overlay);
-            added_count += 1;
+    // Keep existing overlays that match a wanted one; the rest are stale.
+    let mut stale = Vec::new();
+    let mut existing = 0;
+    for overlay in state.overlays.all() {
+        if overlay.namespace.as_ref() != Some(&ns) {
+            continue;
         }
+        existing += 1;
+        let range = overlay.range(&state.marker_list);
+        let key = (
+            range.start,
+            range.end,
+            overlay.priority,
+            overlay.theme_key.unwrap_or_default(),
+            overlay.message.clone().unwrap_or_default(),
+        );
+        let kept = wanted.get_mut(&key).and_then(|faces| {
+            let idx = faces.iter().position(|face| *face == overlay.face)?;
+            Some(faces.swap_remove(idx))
+        });
+        if kept.is_none() {
+            stale.push(overlay.handle.clone());
+        }
+    }
+
+    let mut added: Vec<_> = wanted
+        .into_iter()
+        .flat_map(|(key, faces)| faces.into_iter().map(move |face| (key.clone(), face)))
+        .collect();
+    // HashMap order is arbitrary; add in buffer order so equal-priority
+    // overlays stack the same way on every publish.
+    added.sort_by(|(a, _), (b, _)| a.cmp(b));
+    if stale.is_empty() && added.is_empty() {
+        return false;
     }
 
-    if added_count > 0 {
-        tracing::debug!("Applied {} diagnostic overlays", added_count);
+    if stale.len() == existing {
+        // Nothing survived: one bulk clear beats per-handle removal.
+        state.overlays.clear_namespace(&ns, &mut state.marker_list);
+    } else {
+        for handle in &stale {
+            state
+                .overlays
+                .remove_by_handle(handle, &mut state.marker_list);
+        }
+    }
+    for ((start, end, priority, theme_key, message), face) in &added {
+        let overlay = Overlay::with_namespace(
+            &mut state.marker_list,
+            *start..*end,
+            face.clone(),
+            ns.clone(),
+        )
+        .with_priority_value(*priority)
+        .with_message(message.clone())
+        .with_theme_key(theme_key);
+        state.overlays.add(overlay);
     }
+
+    tracing::debug!(
+        "Diagnostic overlays: {} removed, {} added",
+        stale.len(),
+        added.len()
+    );
+    true
 }
 
 /// A diagnostic paired with a byte-range anchor into the buffer it was
@@ -346,6 +412,31 @@ mod tests {
         assert_eq!(buffer.lsp_position_to_byte(10, 0), buffer.len());
     }
 
+    #[test]
+    fn test_lsp_position_to_byte_counts_utf16_code_units() {
+        // "π" and "é" are one UTF-16 unit but two UTF-8 bytes each
+        let buffer = Buffer::from_str_test("let π = \"café\"\n");
+        // Character 10 is the "a" in "café", after the two-byte "π"
+        assert_eq!(buffer.lsp_position_to_byte(0, 10), 11);
+        // Character 13 is the closing quote, after the two-byte "é"
+        assert_eq!(buffer.lsp_position_to_byte(0, 13), 15);
+
+        // "😀" is a surrogate pair: two UTF-16 units, four UTF-8 bytes
+        let buffer = Buffer::from_str_test("x = \"😀!\"");
+        assert_eq!(buffer.lsp_position_to_byte(0, 5), 5);
+        assert_eq!(buffer.lsp_position_to_byte(0, 7), 9);
+    }
+
+    #[test]
+    fn test_lsp_position_to_byte_clamps_to_line_end() {
+        let buffer = Buffer::from_str_test("héllo\r\nworld\nend");
+        // Past the end of a line lands before its terminator, never on
+        // the next line
+        assert_eq!(buffer.lsp_position_to_byte(0, 99), 6);
+        assert_eq!(buffer.lsp_position_to_byte(1, 99), 13);
+        assert_eq!(buffer.lsp_position_to_byte(2, 99), buffer.len());
+    }
+
     #[test]
     fn test_diagnostic_to_overlay_error() {
         let buffer = Buffer::from_str_test("hello world");
@@ -595,4 +686,69 @@ mod tests {
         assert_eq!(now.range.start, Position::new(2, 0));
         assert_eq!(now.range.end, Position::new(2, 3));
     }
+
+    fn diagnostic_handles(state: &EditorState) -> Vec<crate::view::overlay::OverlayHandle> {
+        let ns = lsp_diagnostic_namespace();
+        state
+            .overlays
+            .all()
+            .iter()
+            .filter(|o| o.namespace.as_ref() == Some(&ns))
+            .map(|o| o.handle.clone())
+            .collect()
+    }
+
+    #[test]
+    fn identical_publish_does_not_rebuild_overlays() {
+        let (mut state, _, _) = state_with("aaa\nbbb\nccc");
+        let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let diagnostics = vec![err_at(0, 0, 0, 3), err_at(2, 0, 2, 3)];
+
+        assert!(apply_diagnostics_to_state(&mut state, &diagnostics, &theme));
+        let handles = diagnostic_handles(&state);
+        assert_eq!(handles.len(), 2);
+
+        // The same set again: nothing is removed or re-created.
+        assert!(!apply_diagnostics_to_state(
+            &mut state,
+            &diagnostics,
+            &theme
+        ));
+        assert_eq!(diagnostic_handles(&state), handles);
+    }
+
+    #[test]
+    fn changed_publish_only_touches_changed_overlays() {
+        let (mut state, _, _) = state_with("aaa\nbbb\nccc");
+        let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+        apply_diagnostics_to_state(
+            &mut state,
+            &[err_at(0, 0, 0, 3), err_at(2, 0, 2, 3)],
+            &theme,
+        );
+        let kept = state
+            .overlays
+            .all()
+            .iter()
+            .find(|o| o.range(&state.marker_list) == (0..3))
+            .map(|o| o.handle.clone())
+            .unwrap();
+
+        // Drop the line-2 error and add one on line 1; line 0's overlay survives.
+        assert!(apply_diagnostics_to_state(
+            &mut state,
+            &[err_at(0, 0, 0, 3), err_at(1, 0, 1, 3)],
+            &theme,
+        ));
+        let handles = diagnostic_handles(&state);
+        assert_eq!(handles.len(), 2);
+        assert!(handles.contains(&kept));
+        let ranges: Vec<_> = state
+            .overlays
+            .all()
+            .iter()
+            .map(|o| o.range(&state.marker_list))
+            .collect();
+        assert!(ranges.contains(&(0..3)) && ranges.contains(&(4..7)));
+    }
 }
@@ -461,6 +461,11 @@ pub struct LspManager {
     /// These will not auto-restart until user manually restarts them
     disabled_languages: HashSet<String>,
 
+    /// (language, server name) pairs whose executable was reported missing.
+    /// Auto-start skips these servers so a missing binary is reported once
+    /// rather than on every file open; a manual restart clears the mark.
+    missing_binaries: HashSet<(String, String)>,
+
     /// Master switch mirroring the top-level `lsp_enabled` config field.
     /// When false, `try_spawn` refuses to auto-start any server (per-language
     /// and universal alike). Manual starts (`allow_language` + `force_spawn`)
@@ -494,6 +499,7 @@ impl LspManager {
             pending_restarts: HashMap::new(),
             allowed_languages: HashSet::new(),
             disabled_languages: HashSet::new(),
+            missing_binaries: HashSet::new(),
             globally_enabled: true,
         }
     }
@@ -587,6 +593,15 @@ impl LspManager {
         tracing::info!("LSP language '{}' manually enabled", language);
     }
 
+    /// Record that a language server's executable could not be found.
+    ///
+    /// Returns `true` the first time a server is marked, so callers can
+    /// warn the user once and stay quiet on later failures.
+    pub fn mark_binary_missing(&mut self, language: &str, server_name: &str) -> bool {
+        self.missing_binaries
+            .insert((language.to_string(), server_name.to_string()))
+    }
+
     /// Get the set of manually enabled languages
     pub fn allowed_languages(&self) -> &HashSet<String> {
         &self.allowed_languages
@@ -950,6 +965,7 @@ impl LspManager {
         self.restart_attempts.clear();
         self.restart_cooldown.clear();
         self.pending_restarts.clear();
+        self.missing_binaries.clear();
 
         // Keep allowed_languages and disabled_languages as user preferences
         // Keep config as it's not project-specific
@@ -1286,6 +1302,21 @@ impl LspManager {
             }
 
             let server_name = config.display_name();
+
+            // The executable was already reported missing; don't retry (and
+            // re-report) on every file open until the user restarts manually.
+            if self
+                .missing_binaries
+                .contains(&(language.to_string(), server_name.clone()))
+            {
+                tracing::debug!(
+                    "force_spawn: not spawning {} server '{}': executable was not found",
+                    language,
+                    server_name
+                );
+                continue;
+            }
+
             tracing::info!(
                 "Spawning LSP server '{}' for language: {}",
                 server_name,
@@ -1623,6 +1654,9 @@ impl LspManager {
         // Re-enable the language (remove from disabled set)
         self.disabled_languages.remove(language);
 
+        // The user may have installed the server since it was reported missing
+        self.missing_binaries.retain(|(lang, _)| lang != language);
+
         // Add to allowed languages so it stays active even if auto_start=false
         self.allowed_languages.insert(language.to_string());
 
@@ -1665,6 +1699,8 @@ impl LspManager {
     ) -> (bool, String) {
         self.clear_cooldown(language);
         self.disabled_languages.remove(language);
+        self.missing_binaries
+            .remove(&(language.to_string(), server_name.to_string()));
         self.allowed_languages.insert(language.to_string());
 
         // Find and shut down just the named server
@@ -1829,6 +1865,23 @@ impl LspManager {
             .collect()
     }
 
+    /// Name of the first server for a language that has requests awaiting a
+    /// response, if any. Drives the status-bar activity spinner.
+    pub fn busy_server(&self, language: &str) -> Option<&str> {
+        self.handles
+            .iter()
+            .filter(|sh| sh.handle.scope().accepts(language))
+            .find(|sh| sh.handle.pending_request_count() > 0)
+            .map(|sh| sh.name.as_str())
+    }
+
+    /// Check if any server has requests awaiting a response.
+    pub fn has_requests_in_flight(&self) -> bool {
+        self.handles
+            .iter()
+            .any(|sh| sh.handle.pending_request_count() > 0)
+    }
+
     /// Check if any LSP server for a language is running and ready to serve requests
     pub fn is_server_ready(&self, language: &str) -> bool {
         self.handles
@@ -2308,6 +2361,25 @@ mod tests {
         assert_ne!(manager.try_spawn("rust", None), LspSpawnResult::Disabled);
     }
 
+    #[test]
+    fn test_lsp_manager_missing_binary_reported_once() {
+        let mut manager = LspManager::new(fresh_core::WindowId(1), None);
+
+        assert!(manager.mark_binary_missing("rust", "rust-analyzer"));
+        assert!(!manager.mark_binary_missing("rust", "rust-analyzer"));
+        // Another server for the same language is tracked separately
+        assert!(manager.mark_binary_missing("rust", "bacon-ls"));
+
+        // A manual restart gives the language's servers another chance
+        manager.manual_restart("rust", None);
+        assert!(manager.mark_binary_missing("rust", "rust-analyzer"));
+        assert!(manager.mark_binary_missing("rust", "bacon-ls"));
+
+        manager.manual_restart_server("rust", "bacon-ls", None);
+        assert!(!manager.mark_binary_missing("rust", "rust-analyzer"));
+        assert!(manager.mark_binary_missing("rust", "bacon-ls"));
+    }
+
     #[test]
     fn test_lsp_manager_shutdown_all() {
         let mut manager = LspManager::new(fresh_core::WindowId(1), None);
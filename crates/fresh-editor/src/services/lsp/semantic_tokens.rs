@@ -1,9 +1,9 @@
 use crate::state::{EditorState, SemanticTokenSpan};
-use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use crate::view::overlay::{priority, Overlay, OverlayFace, OverlayNamespace};
 use ratatui::style::Color;
 
 const SEMANTIC_TOKENS_NAMESPACE: &str = "lsp-semantic-token";
-const SEMANTIC_TOKENS_PRIORITY: i32 = 5;
+const SEMANTIC_TOKENS_PRIORITY: i32 = priority::SEMANTIC;
 
 /// Namespace for all LSP semantic token overlays.
 pub fn lsp_semantic_tokens_namespace() -> OverlayNamespace {
@@ -302,6 +302,27 @@ impl PluginManager {
         }
     }
 
+    /// Call a plugin's text-object handler (fire-and-forget). The range
+    /// comes back as a `TextObjectResolved` command for `request_id`.
+    pub fn resolve_text_object(
+        &self,
+        plugin: &str,
+        handler: &str,
+        request_id: u64,
+        args: serde_json::Value,
+    ) {
+        #[cfg(feature = "plugins")]
+        {
+            if let Some(ref manager) = self.inner {
+                manager.resolve_text_object(plugin, handler, request_id, args);
+            }
+        }
+        #[cfg(not(feature = "plugins"))]
+        {
+            let _ = (plugin, handler, request_id, args);
+        }
+    }
+
     /// Deliver a response to a pending async plugin operation.
     pub fn deliver_response(&self, response: super::api::PluginResponse) {
         #[cfg(feature = "plugins")]
@@ -265,7 +265,9 @@ impl TerminalModes {
         }
 
         // Reset cursor style to default
-        let _ = stdout().execute(SetCursorStyle::DefaultUserShape);
+        if crate::config::CursorStyle::is_supported_by_current_terminal() {
+            let _ = stdout().execute(SetCursorStyle::DefaultUserShape);
+        }
 
         // Reset terminal cursor color
         crate::view::theme::Theme::reset_terminal_cursor_color();
@@ -370,7 +372,9 @@ pub fn emergency_cleanup() {
     let _ = stdout().execute(DisableBracketedPaste);
 
     // Reset cursor style to default
-    let _ = stdout().execute(SetCursorStyle::DefaultUserShape);
+    if crate::config::CursorStyle::is_supported_by_current_terminal() {
+        let _ = stdout().execute(SetCursorStyle::DefaultUserShape);
+    }
 
     // Reset terminal cursor color
     crate::view::theme::Theme::reset_terminal_cursor_color();
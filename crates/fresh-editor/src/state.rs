@@ -103,6 +103,11 @@ pub struct BufferSettings {
     /// Set based on language config; can be changed per-buffer by user
     pub tab_size: usize,
 
+    /// Number of columns a tab character occupies on screen, when it
+    /// differs from `tab_size`. Read through [`Self::tab_display_width`].
+    /// Set from `editor.tab_display_width`.
+    pub tab_display_width: Option<usize>,
+
     /// Whether to auto-close brackets, parentheses, and quotes.
     /// Set based on global + language config.
     pub auto_close: bool,
@@ -122,7 +127,8 @@ pub struct BufferSettings {
     pub virtual_space_override: Option<crate::config::VirtualSpaceMode>,
 
     /// Extra characters (beyond alphanumeric + `_`) considered part of
-    /// identifiers for this language. Used by completion providers.
+    /// identifiers for this language. Used by completion providers and
+    /// occurrence highlighting.
     pub word_characters: String,
 
     /// Whether indentation guides may render for this buffer. `false`
@@ -139,6 +145,7 @@ impl Default for BufferSettings {
             whitespace: crate::config::WhitespaceVisibility::default(),
             use_tabs: false,
             tab_size: 4,
+            tab_display_width: None,
             auto_close: true,
             auto_surround: true,
             virtual_space: crate::config::VirtualSpaceMode::default(),
@@ -150,6 +157,13 @@ impl Default for BufferSettings {
 }
 
 impl BufferSettings {
+    /// Columns a tab occupies on screen: the renderer and the layouts
+    /// cursor movement reads visual columns from use this, while
+    /// indentation keeps using `tab_size`.
+    pub fn tab_display_width(&self) -> usize {
+        self.tab_display_width.unwrap_or(self.tab_size)
+    }
+
     /// Stamp a resolved global + per-language configuration onto this
     /// buffer's settings. Together with [`EditorState::apply_buffer_config`]
     /// this is THE single place language-dependent settings land on a buffer
@@ -162,6 +176,7 @@ impl BufferSettings {
     /// keeps following `virtual_space_override` when one is set.
     pub fn apply_config(&mut self, resolved: &crate::config::BufferConfig) {
         self.tab_size = resolved.tab_size;
+        self.tab_display_width = resolved.tab_display_width;
         self.use_tabs = resolved.use_tabs;
         self.auto_close = resolved.auto_close;
         self.auto_surround = resolved.auto_surround;
@@ -374,6 +389,7 @@ impl EditorState {
     pub fn apply_buffer_config(&mut self, config: &crate::config::Config) {
         let resolved = crate::config::BufferConfig::resolve(config, Some(&self.language));
         self.buffer_settings.apply_config(&resolved);
+        self.reference_highlighter.word_chars_extra = resolved.word_characters.clone();
     }
 
     /// Create a new state with a buffer and default (plain text) language.
@@ -45,6 +45,7 @@ pub mod context_keys {
     pub const KEYMAP_EMACS: &str = "keymap_emacs";
     pub const KEYMAP_VSCODE: &str = "keymap_vscode";
     pub const KEYMAP_MACOS_GUI: &str = "keymap_macos_gui";
+    pub const KEYMAP_VIM: &str = "keymap_vim";
 }
 
 /// Configuration for process resource limits
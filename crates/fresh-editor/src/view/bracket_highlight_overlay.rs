@@ -6,6 +6,7 @@
 
 use crate::model::buffer::Buffer;
 use crate::model::marker::MarkerList;
+use crate::primitives::highlighter::{HighlightCategory, HighlightSpan};
 use crate::view::overlay::{Overlay, OverlayFace, OverlayManager, OverlayNamespace};
 use crate::view::theme::Theme;
 use ratatui::style::Color;
@@ -65,7 +66,7 @@ fn opening_for_closing(ch: char) -> Option<char> {
 /// These are the comment/string ranges where brackets are prose/data rather
 /// than structural punctuation, so they are excluded from bracket matching
 /// and rainbow colorization (issue #2405).
-fn pos_in_ranges(ranges: &[Range<usize>], pos: usize) -> bool {
+pub(crate) fn pos_in_ranges(ranges: &[Range<usize>], pos: usize) -> bool {
     ranges
         .binary_search_by(|r| {
             if pos < r.start {
@@ -79,6 +80,88 @@ fn pos_in_ranges(ranges: &[Range<usize>], pos: usize) -> bool {
         .is_ok()
 }
 
+/// Find the matching bracket (bounded to MAX_BRACKET_SEARCH_BYTES).
+///
+/// Brackets inside `skip_ranges` (comments/strings) are ignored so the
+/// match reflects only structural punctuation (issue #2405).
+pub(crate) fn find_matching_bracket(
+    buffer: &Buffer,
+    position: usize,
+    opening: char,
+    closing: char,
+    forward: bool,
+    skip_ranges: &[Range<usize>],
+) -> Option<usize> {
+    let buffer_len = buffer.len();
+    let open = opening as u8;
+    let close = closing as u8;
+    let mut depth: i32 = 1;
+
+    if forward {
+        let search_limit = (position + 1 + MAX_BRACKET_SEARCH_BYTES).min(buffer_len);
+        let mut pos = position + 1;
+        while pos < search_limit {
+            let chunk_end = (pos + BRACKET_SCAN_CHUNK).min(search_limit);
+            let chunk = buffer.slice_bytes(pos..chunk_end);
+            for (i, &b) in chunk.iter().enumerate() {
+                if pos_in_ranges(skip_ranges, pos + i) {
+                    continue;
+                }
+                if b == open {
+                    depth += 1;
+                } else if b == close {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(pos + i);
+                    }
+                }
+            }
+            pos = chunk_end;
+        }
+    } else {
+        let search_limit = position.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
+        let mut pos = position;
+        while pos > search_limit {
+            let chunk_start = pos.saturating_sub(BRACKET_SCAN_CHUNK).max(search_limit);
+            let chunk = buffer.slice_bytes(chunk_start..pos);
+            for (i, &b) in chunk.iter().enumerate().rev() {
+                if pos_in_ranges(skip_ranges, chunk_start + i) {
+                    continue;
+                }
+                if b == close {
+                    depth += 1;
+                } else if b == open {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(chunk_start + i);
+                    }
+                }
+            }
+            pos = chunk_start;
+        }
+    }
+
+    None
+}
+
+/// Comment and string ranges from a set of highlight spans, sorted by start,
+/// for use as the `skip_ranges` of the bracket scanners (issue #2405).
+pub(crate) fn bracket_skip_ranges(spans: &[HighlightSpan]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = spans
+        .iter()
+        .filter(|span| {
+            matches!(
+                span.category,
+                Some(HighlightCategory::Comment) | Some(HighlightCategory::String)
+            )
+        })
+        .map(|span| span.range.clone())
+        .collect();
+    // `pos_in_ranges` binary-searches, so the ranges must be sorted by start.
+    ranges.sort_by_key(|range| range.start);
+    ranges
+}
+
 /// Get the matching bracket pair for a character
 fn get_bracket_pair(ch: char) -> Option<(char, char, bool)> {
     for &(open, close) in BRACKET_PAIRS {
@@ -221,7 +304,7 @@ impl BracketHighlightOverlay {
         };
 
         // Find matching bracket
-        let matching_pos = self.find_matching_bracket(
+        let matching_pos = find_matching_bracket(
             buffer,
             cursor_position,
             opening,
@@ -311,71 +394,6 @@ impl BracketHighlightOverlay {
         }
     }
 
-    /// Find the matching bracket (bounded to MAX_BRACKET_SEARCH_BYTES).
-    ///
-    /// Brackets inside `skip_ranges` (comments/strings) are ignored so the
-    /// match reflects only structural punctuation (issue #2405).
-    fn find_matching_bracket(
-        &self,
-        buffer: &Buffer,
-        position: usize,
-        opening: char,
-        closing: char,
-        forward: bool,
-        skip_ranges: &[Range<usize>],
-    ) -> Option<usize> {
-        let buffer_len = buffer.len();
-        let open = opening as u8;
-        let close = closing as u8;
-        let mut depth: i32 = 1;
-
-        if forward {
-            let search_limit = (position + 1 + MAX_BRACKET_SEARCH_BYTES).min(buffer_len);
-            let mut pos = position + 1;
-            while pos < search_limit {
-                let chunk_end = (pos + BRACKET_SCAN_CHUNK).min(search_limit);
-                let chunk = buffer.slice_bytes(pos..chunk_end);
-                for (i, &b) in chunk.iter().enumerate() {
-                    if pos_in_ranges(skip_ranges, pos + i) {
-                        continue;
-                    }
-                    if b == open {
-                        depth += 1;
-                    } else if b == close {
-                        depth -= 1;
-                        if depth == 0 {
-                            return Some(pos + i);
-                        }
-                    }
-                }
-                pos = chunk_end;
-            }
-        } else {
-            let search_limit = position.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
-            let mut pos = position;
-            while pos > search_limit {
-                let chunk_start = pos.saturating_sub(BRACKET_SCAN_CHUNK).max(search_limit);
-                let chunk = buffer.slice_bytes(chunk_start..pos);
-                for (i, &b) in chunk.iter().enumerate().rev() {
-                    if pos_in_ranges(skip_ranges, chunk_start + i) {
-                        continue;
-                    }
-                    if b == close {
-                        depth += 1;
-                    } else if b == open {
-                        depth -= 1;
-                        if depth == 0 {
-                            return Some(chunk_start + i);
-                        }
-                    }
-                }
-                pos = chunk_start;
-            }
-        }
-
-        None
-    }
-
     /// Force clear all highlights (e.g., when switching buffers)
     pub fn clear(&mut self, overlays: &mut OverlayManager, marker_list: &mut MarkerList) {
         let highlight_ns = bracket_highlight_namespace();
@@ -515,32 +533,26 @@ mod tests {
     #[test]
     fn test_find_matching_bracket_forward() {
         let buffer = Buffer::from_str_test("(hello)");
-        let overlay = BracketHighlightOverlay::new();
-
-        let result = overlay.find_matching_bracket(&buffer, 0, '(', ')', true, &[]);
+        let result = find_matching_bracket(&buffer, 0, '(', ')', true, &[]);
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_find_matching_bracket_backward() {
         let buffer = Buffer::from_str_test("(hello)");
-        let overlay = BracketHighlightOverlay::new();
-
-        let result = overlay.find_matching_bracket(&buffer, 6, '(', ')', false, &[]);
+        let result = find_matching_bracket(&buffer, 6, '(', ')', false, &[]);
         assert_eq!(result, Some(0));
     }
 
     #[test]
     fn test_find_matching_bracket_nested() {
         let buffer = Buffer::from_str_test("((inner))");
-        let overlay = BracketHighlightOverlay::new();
-
         // Outer opening bracket should match outer closing
-        let result = overlay.find_matching_bracket(&buffer, 0, '(', ')', true, &[]);
+        let result = find_matching_bracket(&buffer, 0, '(', ')', true, &[]);
         assert_eq!(result, Some(8));
 
         // Inner opening bracket should match inner closing
-        let result = overlay.find_matching_bracket(&buffer, 1, '(', ')', true, &[]);
+        let result = find_matching_bracket(&buffer, 1, '(', ')', true, &[]);
         assert_eq!(result, Some(7));
     }
 
@@ -590,18 +602,16 @@ mod tests {
         // `( # ) )` — the first `)` sits inside a "comment" range and must be
         // ignored, so the opening `(` matches the second `)`.
         let buffer = Buffer::from_str_test("(a)b)");
-        let overlay = BracketHighlightOverlay::new();
-
         // Without skipping, `(` at 0 matches the `)` at 2.
         assert_eq!(
-            overlay.find_matching_bracket(&buffer, 0, '(', ')', true, &[]),
+            find_matching_bracket(&buffer, 0, '(', ')', true, &[]),
             Some(2)
         );
 
         // Treat byte 2 (the first `)`) as inside a comment: it should be
         // skipped, so the match becomes the `)` at 4.
         assert_eq!(
-            overlay.find_matching_bracket(&buffer, 0, '(', ')', true, &[2..3]),
+            find_matching_bracket(&buffer, 0, '(', ')', true, &[2..3]),
             Some(4)
         );
     }
@@ -178,7 +178,9 @@ impl IgnorePatterns {
             // otherwise a `*` pattern would hide the directory it lives in.
             if path.starts_with(gitignore_dir) && path != gitignore_dir.as_path() {
                 let relative_path = path.strip_prefix(gitignore_dir).unwrap_or(path);
-                let matched = gitignore.matched(relative_path, is_dir);
+                // Entries inside an ignored directory are ignored too, which
+                // matters once gitignored files are shown (they render dimmed).
+                let matched = gitignore.matched_path_or_any_parents(relative_path, is_dir);
 
                 if matched.is_ignore() {
                     return true;
@@ -414,6 +416,26 @@ mod tests {
         assert!(!patterns.is_ignored(&ds_store, false));
     }
 
+    #[test]
+    fn test_gitignored_directory_covers_its_contents() {
+        let root = Path::new("/repo");
+        let mut patterns = IgnorePatterns::new();
+        patterns.load_gitignore_from_bytes(root, b"target/\n", None);
+
+        assert_eq!(
+            patterns.get_status(&root.join("target"), true),
+            IgnoreStatus::GitIgnored
+        );
+        assert_eq!(
+            patterns.get_status(&root.join("target/debug/app"), false),
+            IgnoreStatus::GitIgnored
+        );
+        assert_eq!(
+            patterns.get_status(&root.join("src/target.rs"), false),
+            IgnoreStatus::Visible
+        );
+    }
+
     #[test]
     fn test_multiple_gitignores() {
         let root = Path::new("/repo");
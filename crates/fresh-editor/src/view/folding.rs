@@ -694,3 +694,76 @@ pub mod indent_folding {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::filesystem::StdFileSystem;
+    use lsp_types::{FoldingRange, FoldingRangeKind};
+    use std::sync::Arc;
+
+    fn lsp_range(start_line: u32, end_line: u32, kind: Option<FoldingRangeKind>) -> FoldingRange {
+        FoldingRange {
+            start_line,
+            end_line,
+            start_character: None,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        }
+    }
+
+    #[test]
+    fn lsp_folding_ranges_decode_into_fold_state() {
+        let buffer = Buffer::from_str(
+            "use a;\nuse b;\n// one\n// two\nfn main() {\n    body();\n}\n",
+            1024 * 1024,
+            Arc::new(StdFileSystem),
+        );
+        let mut marker_list = MarkerList::new();
+        let mut ranges = LspFoldRanges::new();
+
+        let mut comment = lsp_range(2, 3, Some(FoldingRangeKind::Comment));
+        comment.collapsed_text = Some("// …".to_string());
+        ranges.set_from_lsp(
+            &buffer,
+            &mut marker_list,
+            vec![
+                lsp_range(0, 1, Some(FoldingRangeKind::Imports)),
+                comment,
+                lsp_range(4, 6, None),
+                // Past EOF: dropped on ingest.
+                lsp_range(40, 50, Some(FoldingRangeKind::Region)),
+                // Single line: tracked, but not foldable once resolved.
+                lsp_range(5, 5, None),
+            ],
+        );
+        assert_eq!(ranges.len(), 4);
+
+        let resolved: Vec<_> = ranges
+            .resolved(&buffer, &marker_list)
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind, r.collapsed_text))
+            .collect();
+        assert_eq!(
+            resolved,
+            vec![
+                (0, 1, Some(FoldingRangeKind::Imports), None),
+                (
+                    2,
+                    3,
+                    Some(FoldingRangeKind::Comment),
+                    Some("// …".to_string())
+                ),
+                (4, 6, None, None),
+            ]
+        );
+
+        // A fresh response replaces (not appends to) the tracked set, and
+        // an empty one clears it so indent-based folding takes over again.
+        ranges.set_from_lsp(&buffer, &mut marker_list, vec![lsp_range(4, 6, None)]);
+        assert_eq!(ranges.len(), 1);
+        ranges.set_from_lsp(&buffer, &mut marker_list, Vec::new());
+        assert!(ranges.is_empty());
+    }
+}
@@ -0,0 +1,656 @@
+//! HTML subset rendering for plugin output panels (`editor.showOutputPanel`)
+//!
+//! Plugins such as a Markdown preview hand over a small, constrained subset
+//! of HTML; this module turns it into panel rows — one `TextPropertyEntry`
+//! per line, styled with inline overlays — which the panel buffer then draws
+//! cell by cell like any other virtual buffer.
+//!
+//! Supported subset:
+//! - blocks: `<h1>`–`<h6>`, `<p>`, `<ul>` / `<ol>` with `<li>` (nestable),
+//!   `<pre>` (optionally wrapping a `<code>`), `<br>` and `<hr>`
+//! - inline: `<b>` / `<strong>`, `<i>` / `<em>`, `<code>`
+//! - entities: `&amp;` `&lt;` `&gt;` `&quot;` `&apos;` `&#39;` `&nbsp;` and
+//!   numeric `&#N;` / `&#xN;`
+//!
+//! Any other tag is dropped but its text is kept, except `<script>` and
+//! `<style>`, whose content is dropped too. Attributes are ignored apart
+//! from a `language-*` / `lang-*` class on a `<pre>`'s `<code>`: when a
+//! [`CodeHighlighter`] is given, that block is syntax highlighted, and
+//! markup inside `<pre>` is otherwise ignored. Outside `<pre>` whitespace
+//! collapses to single spaces as in a browser; top-level blocks are
+//! separated by one blank row. Rows are not wrapped here — the panel
+//! soft-wraps them.
+
+use fresh_core::api::{OverlayColorSpec, OverlayOptions};
+use fresh_core::text_property::{InlineOverlay, TextPropertyEntry};
+use ratatui::style::Color;
+use std::collections::HashMap;
+
+use crate::primitives::highlighter::HighlightSpan;
+
+/// Highlights a fenced code block: `(code, language)` to spans over `code`.
+/// An empty result leaves the block in the plain code style.
+pub type CodeHighlighter<'a> = &'a dyn Fn(&str, &str) -> Vec<HighlightSpan>;
+
+/// Indent of `<pre>` rows and of each nested list level.
+const PRE_INDENT: &str = "    ";
+const LIST_INDENT: &str = "  ";
+
+/// Foreground of a highlighted code token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenFg {
+    Key(&'static str),
+    Rgb(u8, u8, u8),
+}
+
+impl TokenFg {
+    /// The theme key of the span's category, so the panel follows theme
+    /// changes; diff categories (whose key names a background) and
+    /// uncategorised spans keep their resolved colour.
+    fn of(span: &HighlightSpan) -> Option<Self> {
+        match (span.category, span.color) {
+            (Some(category), _) if span.bg.is_none() => Some(Self::Key(category.theme_key())),
+            (_, Color::Rgb(r, g, b)) => Some(Self::Rgb(r, g, b)),
+            _ => None,
+        }
+    }
+}
+
+/// Inline formatting in effect for a run of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct RunStyle {
+    bold: bool,
+    italic: bool,
+    code: bool,
+    heading: Option<u8>,
+    token: Option<TokenFg>,
+}
+
+impl RunStyle {
+    fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn overlay(&self) -> OverlayOptions {
+        let fg = if self.heading.is_some() {
+            Some(TokenFg::Key("syntax.keyword"))
+        } else if self.token.is_some() {
+            self.token
+        } else if self.code {
+            Some(TokenFg::Key("syntax.string"))
+        } else {
+            None
+        };
+        OverlayOptions {
+            fg: fg.map(|fg| match fg {
+                TokenFg::Key(key) => OverlayColorSpec::ThemeKey(key.to_string()),
+                TokenFg::Rgb(r, g, b) => OverlayColorSpec::Rgb(r, g, b),
+            }),
+            bold: self.bold || self.heading.is_some(),
+            italic: self.italic,
+            underline: self.heading == Some(1),
+            ..Default::default()
+        }
+    }
+}
+
+/// Render `html` into panel rows, one entry per line, highlighting code
+/// blocks that name their language with `highlight` when given.
+pub fn render_html_subset(
+    html: &str,
+    highlight: Option<CodeHighlighter<'_>>,
+) -> Vec<TextPropertyEntry> {
+    let mut renderer = Renderer {
+        highlight,
+        ..Default::default()
+    };
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => renderer.text(&decode_entities(text)),
+            Token::Open(name, attrs) => renderer.open(&name, attrs),
+            Token::Close(name) => renderer.close(&name),
+        }
+    }
+    renderer.finish()
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Text(&'a str),
+    /// A start tag and its raw attribute text.
+    Open(String, &'a str),
+    Close(String),
+}
+
+/// Split `html` into text and lowercased tag names. Comments, doctypes and
+/// attributes are skipped; a `<` that doesn't start a tag is kept as text.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while let Some(lt) = rest.find('<') {
+        let after = &rest[lt + 1..];
+        let starts_tag = after
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        if !starts_tag {
+            tokens.push(Token::Text(&rest[..lt + 1]));
+            rest = after;
+            continue;
+        }
+        if lt > 0 {
+            tokens.push(Token::Text(&rest[..lt]));
+        }
+
+        if let Some(comment) = after.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = tag_end(after) else {
+            rest = "";
+            break;
+        };
+        let inner = &after[..end];
+        rest = &after[end + 1..];
+
+        let (closing, inner) = match inner.strip_prefix('/') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+        let name_len = inner
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(inner.len());
+        if name_len == 0 {
+            continue;
+        }
+        let name = inner[..name_len].to_ascii_lowercase();
+        tokens.push(if closing {
+            Token::Close(name)
+        } else {
+            Token::Open(name, &inner[name_len..])
+        });
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+/// The language named by a `class="language-rust"` (or `lang-rust`)
+/// attribute.
+fn code_language(attrs: &str) -> Option<String> {
+    let class = attrs.split_once("class=")?.1;
+    let class = match class.chars().next()? {
+        quote @ ('"' | '\'') => class[1..].split(quote).next()?,
+        _ => class
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()?,
+    };
+    class.split_whitespace().find_map(|name| {
+        name.strip_prefix("language-")
+            .or_else(|| name.strip_prefix("lang-"))
+            .filter(|lang| !lang.is_empty())
+            .map(str::to_string)
+    })
+}
+
+/// Byte index of the `>` closing a tag body, skipping quoted attribute values.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Decode the supported character references. Unknown ones are left as-is.
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|semi| {
+            let name = &rest[1..semi];
+            let ch = match name {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{00A0}'),
+                _ => name.strip_prefix('#').and_then(|num| {
+                    let code = match num.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => num.parse().ok(),
+                    };
+                    code.and_then(char::from_u32)
+                }),
+            };
+            ch.map(|ch| (ch, semi))
+        });
+        match decoded {
+            Some((ch, semi)) => {
+                out.push(ch);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[derive(Default)]
+struct Renderer<'a> {
+    highlight: Option<CodeHighlighter<'a>>,
+    rows: Vec<Vec<(String, RunStyle)>>,
+    line: Vec<(String, RunStyle)>,
+    style: RunStyle,
+    /// Open `<b>`/`<strong>`, `<i>`/`<em>` and `<code>` elements.
+    bold: usize,
+    italic: usize,
+    code: usize,
+    /// Open `<pre>` elements, and whether the newline that may directly
+    /// follow `<pre>` is still to be swallowed. A block's text and language
+    /// are collected until its `</pre>`, then laid out at once.
+    pre: usize,
+    pre_start: bool,
+    pre_source: String,
+    pre_language: Option<String>,
+    /// Open `<script>` / `<style>` elements.
+    skip: usize,
+    /// One entry per open list: `None` for `<ul>`, the next number for `<ol>`.
+    lists: Vec<Option<usize>>,
+    /// The current row has text after any list marker.
+    line_has_text: bool,
+    pending_space: bool,
+}
+
+impl Renderer<'_> {
+    fn open(&mut self, name: &str, attrs: &str) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.break_block();
+                self.style.heading = name[1..].parse().ok();
+            }
+            "p" => self.break_block(),
+            "pre" => {
+                self.break_block();
+                self.pre += 1;
+                self.pre_start = true;
+            }
+            "ul" | "ol" => {
+                if self.lists.is_empty() {
+                    self.break_block();
+                } else {
+                    self.end_line();
+                }
+                self.lists.push((name == "ol").then_some(1));
+            }
+            "li" => {
+                self.end_line();
+                let depth = self.lists.len().max(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        let marker = format!("{}. ", n);
+                        *n += 1;
+                        marker
+                    }
+                    _ => "• ".to_string(),
+                };
+                let indent = LIST_INDENT.repeat(depth - 1);
+                self.line
+                    .push((format!("{indent}{marker}"), RunStyle::default()));
+            }
+            "br" => self.force_line(),
+            "hr" => {
+                self.break_block();
+                self.line.push(("─".repeat(40), RunStyle::default()));
+                self.break_block();
+            }
+            "b" | "strong" => self.bold += 1,
+            "i" | "em" => self.italic += 1,
+            "code" => {
+                self.code += 1;
+                if self.pre > 0 && self.pre_language.is_none() {
+                    self.pre_language = code_language(attrs);
+                }
+            }
+            "script" | "style" => self.skip += 1,
+            _ => {}
+        }
+        self.update_style();
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.style.heading = None;
+                self.break_block();
+            }
+            "p" => self.break_block(),
+            "pre" => {
+                self.pre = self.pre.saturating_sub(1);
+                if self.pre == 0 {
+                    self.flush_pre();
+                }
+                self.break_block();
+            }
+            "ul" | "ol" => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.break_block();
+                } else {
+                    self.end_line();
+                }
+            }
+            "li" => self.end_line(),
+            "b" | "strong" => self.bold = self.bold.saturating_sub(1),
+            "i" | "em" => self.italic = self.italic.saturating_sub(1),
+            "code" => self.code = self.code.saturating_sub(1),
+            "script" | "style" => self.skip = self.skip.saturating_sub(1),
+            _ => {}
+        }
+        self.update_style();
+    }
+
+    fn update_style(&mut self) {
+        self.style.bold = self.bold > 0;
+        self.style.italic = self.italic > 0;
+        self.style.code = self.code > 0 || self.pre > 0;
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip > 0 {
+            return;
+        }
+        if self.pre > 0 {
+            self.pre_text(text);
+            return;
+        }
+        for ch in text.chars() {
+            if ch.is_whitespace() && ch != '\u{00A0}' {
+                self.pending_space = true;
+                continue;
+            }
+            if std::mem::take(&mut self.pending_space) && self.line_has_text {
+                self.push_space();
+            }
+            self.push_char(ch);
+            self.line_has_text = true;
+        }
+    }
+
+    /// Text inside `<pre>`: collected verbatim for [`Self::flush_pre`].
+    fn pre_text(&mut self, text: &str) {
+        let mut text = text;
+        if std::mem::take(&mut self.pre_start) {
+            text = text
+                .strip_prefix("\r\n")
+                .or_else(|| text.strip_prefix('\n'))
+                .unwrap_or(text);
+        }
+        self.pre_source.push_str(text);
+    }
+
+    /// Lay out the collected `<pre>` block, one indented row per source
+    /// line, highlighted when its language is known to the highlighter.
+    fn flush_pre(&mut self) {
+        let source = std::mem::take(&mut self.pre_source).replace(['\t', '\r'], " ");
+        let source = source.strip_suffix('\n').unwrap_or(&source);
+        let language = self.pre_language.take();
+        let spans = match (self.highlight, language) {
+            (Some(highlight), Some(language)) => highlight(source, &language),
+            _ => Vec::new(),
+        };
+        let plain = RunStyle {
+            code: spans.is_empty(),
+            ..Default::default()
+        };
+
+        self.end_line();
+        let mut spans = spans.iter().peekable();
+        let mut offset = 0;
+        for line in source.split('\n') {
+            if !line.is_empty() {
+                self.line
+                    .push((PRE_INDENT.to_string(), RunStyle::default()));
+            }
+            for (i, ch) in line.char_indices() {
+                let at = offset + i;
+                while spans.next_if(|span| span.range.end <= at).is_some() {}
+                let style = match spans.peek() {
+                    Some(span) if span.range.start <= at => RunStyle {
+                        token: TokenFg::of(span),
+                        ..plain
+                    },
+                    _ => plain,
+                };
+                match self.line.last_mut() {
+                    Some((text, last)) if *last == style => text.push(ch),
+                    _ => self.line.push((ch.to_string(), style)),
+                }
+            }
+            self.force_line();
+            offset += line.len() + 1;
+        }
+    }
+
+    /// A collapsed space between words. It joins the previous run when the
+    /// style hasn't changed; otherwise it stays unstyled (apart from the
+    /// heading) so bold or code spans don't start or end with a space.
+    fn push_space(&mut self) {
+        let style = match self.line.last() {
+            Some((_, last)) if *last == self.style => self.style,
+            _ => RunStyle {
+                heading: self.style.heading,
+                ..Default::default()
+            },
+        };
+        match self.line.last_mut() {
+            Some((text, last)) if *last == style => text.push(' '),
+            _ => self.line.push((" ".to_string(), style)),
+        }
+    }
+
+    fn push_char(&mut self, ch: char) {
+        match self.line.last_mut() {
+            Some((text, style)) if *style == self.style => text.push(ch),
+            _ => self.line.push((ch.to_string(), self.style)),
+        }
+    }
+
+    /// End the current row if it has any content.
+    fn end_line(&mut self) {
+        if !self.line.is_empty() {
+            self.force_line();
+        }
+    }
+
+    /// End the current row, emitting an empty one if there is no content.
+    fn force_line(&mut self) {
+        self.rows.push(std::mem::take(&mut self.line));
+        self.line_has_text = false;
+        self.pending_space = false;
+    }
+
+    /// Start or end a block: end the current row and, outside lists, leave
+    /// one blank row before whatever comes next. A list item's own marker
+    /// stays on the row with the item's first block.
+    fn break_block(&mut self) {
+        if !self.lists.is_empty() && !self.line_has_text {
+            return;
+        }
+        self.end_line();
+        if self.lists.is_empty() && self.rows.last().is_some_and(|row| !row.is_empty()) {
+            self.rows.push(Vec::new());
+        }
+    }
+
+    fn finish(mut self) -> Vec<TextPropertyEntry> {
+        self.end_line();
+        while self.rows.last().is_some_and(|row| row.is_empty()) {
+            self.rows.pop();
+        }
+        self.rows.into_iter().map(row_entry).collect()
+    }
+}
+
+/// One panel row: its text plus a newline, with an overlay per styled run.
+fn row_entry(row: Vec<(String, RunStyle)>) -> TextPropertyEntry {
+    let mut entry = TextPropertyEntry::text(String::new());
+    for (text, style) in row {
+        let start = entry.text.len();
+        entry.text.push_str(&text);
+        if !style.is_plain() {
+            entry.inline_overlays.push(InlineOverlay {
+                start,
+                end: entry.text.len(),
+                style: style.overlay(),
+                properties: HashMap::new(),
+                unit: Default::default(),
+            });
+        }
+    }
+    entry.text.push('\n');
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(entries: &[TextPropertyEntry]) -> Vec<&str> {
+        entries
+            .iter()
+            .map(|e| e.text.trim_end_matches('\n'))
+            .collect()
+    }
+
+    /// The text covered by each inline overlay of `entry`.
+    fn styled<'a>(entry: &'a TextPropertyEntry) -> Vec<(&'a str, &'a OverlayOptions)> {
+        entry
+            .inline_overlays
+            .iter()
+            .map(|o| (&entry.text[o.start..o.end], &o.style))
+            .collect()
+    }
+
+    #[test]
+    fn test_heading_and_code_block() {
+        let entries = render_html_subset(
+            "<h1>Usage</h1>\n<pre><code>fn main() {\n    run();\n}\n</code></pre>",
+            None,
+        );
+        assert_eq!(
+            rows(&entries),
+            vec!["Usage", "", "    fn main() {", "        run();", "    }"]
+        );
+
+        let heading = styled(&entries[0]);
+        assert_eq!(heading.len(), 1);
+        assert_eq!(heading[0].0, "Usage");
+        assert!(heading[0].1.bold && heading[0].1.underline);
+
+        // The indent is plain; the code itself is styled as code.
+        let code = styled(&entries[2]);
+        assert_eq!(code.len(), 1);
+        assert_eq!(code[0].0, "fn main() {");
+        assert_eq!(
+            code[0].1.fg,
+            Some(OverlayColorSpec::ThemeKey("syntax.string".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_paragraph_whitespace_and_inline_styles() {
+        let entries = render_html_subset(
+            "<p>Some   <b>bold</b>\n and <em>it&amp;lic</em> <code>x</code></p>",
+            None,
+        );
+        assert_eq!(rows(&entries), vec!["Some bold and it&lic x"]);
+        let spans = styled(&entries[0]);
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].0, "bold");
+        assert!(spans[0].1.bold);
+        assert_eq!(spans[1].0, "it&lic");
+        assert!(spans[1].1.italic);
+        assert_eq!(spans[2].0, "x");
+        assert!(spans[2].1.fg.is_some());
+    }
+
+    #[test]
+    fn test_lists_and_block_spacing() {
+        let entries = render_html_subset(
+            "<p>Steps:</p><ol><li>one</li><li>two<ul><li>nested</li></ul></li></ol><p>Done</p>",
+            None,
+        );
+        assert_eq!(
+            rows(&entries),
+            vec!["Steps:", "", "1. one", "2. two", "  • nested", "", "Done"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_tags_keep_text_and_scripts_are_dropped() {
+        let entries = render_html_subset(
+            "<div class=\"a>b\">kept <span>text</span></div><script>alert(1)</script><!-- gone --> 1 < 2",
+            None,
+        );
+        assert_eq!(rows(&entries), vec!["kept text 1 < 2"]);
+    }
+
+    #[test]
+    fn test_code_block_language_is_highlighted() {
+        use crate::primitives::highlight_types::HighlightCategory;
+
+        // Marks every `fn` as a keyword, and remembers the language asked for.
+        let language = std::cell::RefCell::new(String::new());
+        let highlight = |code: &str, lang: &str| -> Vec<HighlightSpan> {
+            *language.borrow_mut() = lang.to_string();
+            code.match_indices("fn")
+                .map(|(at, _)| HighlightSpan {
+                    range: at..at + 2,
+                    color: Color::Blue,
+                    bg: None,
+                    category: Some(HighlightCategory::Keyword),
+                })
+                .collect()
+        };
+        let entries = render_html_subset(
+            "<pre><code class=\"language-rust\">fn a() {}\nfn b() {}\n</code></pre>",
+            Some(&highlight),
+        );
+        assert_eq!(language.borrow().as_str(), "rust");
+        assert_eq!(rows(&entries), vec!["    fn a() {}", "    fn b() {}"]);
+        for entry in &entries {
+            let spans = styled(entry);
+            assert_eq!(spans.len(), 1);
+            assert_eq!(spans[0].0, "fn");
+            assert_eq!(
+                spans[0].1.fg,
+                Some(OverlayColorSpec::ThemeKey("syntax.keyword".to_string()))
+            );
+        }
+
+        // Without a language the block keeps the plain code style.
+        let entries = render_html_subset("<pre><code>fn a() {}</code></pre>", Some(&highlight));
+        assert_eq!(styled(&entries[0])[0].0, "fn a() {}");
+    }
+
+    #[test]
+    fn test_loose_list_item_keeps_marker_with_paragraph() {
+        let entries =
+            render_html_subset("<ul><li><p>first</p></li><li><p>second</p></li></ul>", None);
+        assert_eq!(rows(&entries), vec!["• first", "• second"]);
+    }
+}
@@ -520,7 +520,7 @@ pub fn compute_line_layout(
     let is_binary = state.buffer.is_binary();
     let line_ending = state.buffer.line_ending();
     let estimated_line_length = state.buffer.estimated_line_length();
-    let tab_size = state.buffer_settings.tab_size;
+    let tab_size = state.buffer_settings.tab_display_width();
 
     // Step 1: build tokens for just this one logical line.
     let mut tokens = build_base_tokens(
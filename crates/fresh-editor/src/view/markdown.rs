@@ -656,6 +656,74 @@ pub fn parse_markdown(
     lines
 }
 
+/// Convert markdown into the HTML subset understood by
+/// [`crate::view::html_panel::render_html_subset`], for previews.
+///
+/// Fenced code blocks keep their language as a `language-*` class so the
+/// panel can highlight them. Links and images are reduced to their text,
+/// and raw HTML passes through for the panel to render what it can.
+pub fn markdown_to_html_subset(text: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut html = String::new();
+    for event in Parser::new_ext(text, options) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph => html.push_str("<p>"),
+                Tag::Heading { level, .. } => html.push_str(&format!("<h{}>", level as u8)),
+                Tag::CodeBlock(kind) => {
+                    html.push_str("<pre><code");
+                    if let pulldown_cmark::CodeBlockKind::Fenced(info) = kind {
+                        // The info string may carry attributes after the language.
+                        if let Some(lang) = info.split_whitespace().next() {
+                            html.push_str(&format!(" class=\"language-{}\"", escape_html(lang)));
+                        }
+                    }
+                    html.push('>');
+                }
+                Tag::List(Some(_)) => html.push_str("<ol>"),
+                Tag::List(None) => html.push_str("<ul>"),
+                Tag::Item => html.push_str("<li>"),
+                Tag::Emphasis => html.push_str("<em>"),
+                Tag::Strong => html.push_str("<strong>"),
+                _ => {}
+            },
+            Event::End(tag_end) => match tag_end {
+                TagEnd::Paragraph => html.push_str("</p>"),
+                TagEnd::Heading(level) => html.push_str(&format!("</h{}>", level as u8)),
+                TagEnd::CodeBlock => html.push_str("</code></pre>"),
+                TagEnd::List(true) => html.push_str("</ol>"),
+                TagEnd::List(false) => html.push_str("</ul>"),
+                TagEnd::Item => html.push_str("</li>"),
+                TagEnd::Emphasis => html.push_str("</em>"),
+                TagEnd::Strong => html.push_str("</strong>"),
+                _ => {}
+            },
+            Event::Text(text) => html.push_str(&escape_html(&text)),
+            Event::Code(code) => {
+                html.push_str("<code>");
+                html.push_str(&escape_html(&code));
+                html.push_str("</code>");
+            }
+            Event::Html(raw) | Event::InlineHtml(raw) => html.push_str(&raw),
+            Event::SoftBreak => html.push('\n'),
+            Event::HardBreak => html.push_str("<br>"),
+            Event::Rule => html.push_str("<hr>"),
+            Event::TaskListMarker(done) => html.push_str(if done { "[x] " } else { "[ ] " }),
+            _ => {}
+        }
+    }
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1424,4 +1492,17 @@ mod tests {
             "Continuation line should have same indent as original"
         );
     }
+
+    #[test]
+    fn test_markdown_to_html_subset() {
+        let html = markdown_to_html_subset(
+            "# Title\n\nSome *em* and `a<b`.\n\n- one\n- two\n\n```rust title=x\nfn main() {}\n```\n",
+        );
+        assert_eq!(
+            html,
+            "<h1>Title</h1><p>Some <em>em</em> and <code>a&lt;b</code>.</p>\
+             <ul><li>one</li><li>two</li></ul>\
+             <pre><code class=\"language-rust\">fn main() {}\n</code></pre>"
+        );
+    }
 }
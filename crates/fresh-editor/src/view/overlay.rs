@@ -126,8 +126,46 @@ pub enum UnderlineStyle {
 
 /// Priority for overlay z-ordering
 /// Higher priority overlays are rendered on top of lower priority ones
+///
+/// Layering rules for a buffer cell, bottom to top:
+///
+/// 1. Base: plugin token style, ANSI escapes or syntax highlighting, then
+///    semantic token colours.
+/// 2. Overlays, in ascending priority (ties keep insertion order).
+/// 3. Current-line background, only where no layer below set a background.
+/// 4. Selection background.
+/// 5. Cursor.
+///
+/// Within the overlay layer each face writes only the attributes it
+/// carries — foreground, background, underline (with its own colour) and
+/// text modifiers — so per attribute the highest-priority overlay that
+/// sets it wins, and faces touching different attributes coexist: an
+/// error underline keeps a search match's background and the syntax
+/// foreground. The selection likewise replaces only the background.
+///
+/// Built-in overlays use the tiers in [`priority`].
 pub type Priority = i32;
 
+/// Priority tiers of the built-in overlays (see [`Priority`]).
+pub mod priority {
+    use super::Priority;
+
+    /// Search scope wash behind a search-in-selection.
+    pub const SEARCH_SCOPE: Priority = 1;
+    /// Semantic-token and reference highlights.
+    pub const SEMANTIC: Priority = 5;
+    /// Search matches and plugin overlays.
+    pub const DEFAULT: Priority = 10;
+    /// LSP hint diagnostics.
+    pub const DIAGNOSTIC_HINT: Priority = 10;
+    /// LSP information diagnostics.
+    pub const DIAGNOSTIC_INFO: Priority = 30;
+    /// LSP warning diagnostics.
+    pub const DIAGNOSTIC_WARNING: Priority = 50;
+    /// LSP error diagnostics; above every other built-in overlay.
+    pub const DIAGNOSTIC_ERROR: Priority = 100;
+}
+
 /// An overlay represents a visual decoration over a range of text
 /// Uses markers for content-anchored positions that automatically adjust with edits
 #[derive(Debug, Clone)]
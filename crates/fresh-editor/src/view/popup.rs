@@ -2,14 +2,17 @@ use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    widgets::{Block, Clear, List, ListItem, Paragraph},
     Frame,
 };
 
 use super::markdown::{parse_markdown, wrap_styled_lines, wrap_text_lines, StyledLine};
 
 pub mod input;
+use super::ui::border::bordered_block;
 use super::ui::scrollbar::{render_scrollbar, ScrollbarColors, ScrollbarState};
+use crate::config::BorderStyle;
+use crate::model::marker::MarkerId;
 use crate::primitives::grammar::GrammarRegistry;
 
 /// Clamp a rectangle to fit within bounds, preventing out-of-bounds rendering panics.
@@ -43,6 +46,12 @@ pub enum PopupPosition {
     AboveCursor,
     /// Fixed screen coordinates (x, y)
     Fixed { x: u16, y: u16 },
+    /// Below a marker in the buffer whose popup stack holds the popup (above
+    /// it when there is no room below). The marker moves with edits, and the
+    /// renderer resolves it to a screen position each frame, so the popup
+    /// follows the text as the view scrolls and is hidden while the anchor
+    /// is out of view.
+    BelowBufferPosition(MarkerId),
     /// Centered on screen
     Centered,
     /// Centered floating overlay sized as a percentage of the frame,
@@ -134,6 +143,23 @@ pub enum PopupResolver {
     SettingsSaveError {
         layer: crate::config_io::ConfigLayer,
     },
+    /// `goto_file_under_cursor` picker shown when the reference matches
+    /// several files. Selected row's `data` is the absolute path; confirm
+    /// opens it at the `:line:col` parsed from the reference.
+    GotoFile {
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    /// Bookmarks panel (`list_bookmarks`). Selected row's `data` is
+    /// `register:buffer_id`; confirm jumps to that bookmark.
+    Bookmarks,
+    /// MRU buffer switcher (`buffer_switcher`). Selected row's `data` is
+    /// the buffer id; confirm (or releasing Ctrl) switches to it.
+    BufferSwitcher,
+    /// Plugin floating popup (`editor.showPopup`). `handle` is what the
+    /// plugin passes to `updatePopup` / `closePopup`; confirm/cancel just
+    /// dismiss it.
+    PluginPopup { handle: u64 },
 }
 
 /// Content of a popup window
@@ -886,7 +912,10 @@ impl Popup {
     /// Calculate the area where this popup should be rendered
     pub fn calculate_area(&self, terminal_area: Rect, cursor_pos: Option<(u16, u16)>) -> Rect {
         match self.position {
-            PopupPosition::AtCursor | PopupPosition::BelowCursor | PopupPosition::AboveCursor => {
+            PopupPosition::AtCursor
+            | PopupPosition::BelowCursor
+            | PopupPosition::AboveCursor
+            | PopupPosition::BelowBufferPosition(_) => {
                 let (cursor_x, cursor_y) = cursor_pos.unwrap_or((
                     terminal_area.x + terminal_area.width / 2,
                     terminal_area.y + terminal_area.height / 2,
@@ -916,7 +945,7 @@ impl Popup {
 
                 let y = match self.position {
                     PopupPosition::AtCursor => cursor_y,
-                    PopupPosition::BelowCursor => {
+                    PopupPosition::BelowCursor | PopupPosition::BelowBufferPosition(_) => {
                         if cursor_y + 1 + height > bottom {
                             // Not enough space below, put above cursor
                             cursor_y.saturating_sub(height)
@@ -1057,16 +1086,18 @@ impl Popup {
 
     /// Render the popup to the frame
     pub fn render(&self, frame: &mut Frame, area: Rect, theme: &crate::view::theme::Theme) {
-        self.render_with_hover(frame, area, theme, None);
+        self.render_with_hover(frame, area, theme, None, BorderStyle::default());
     }
 
-    /// Render the popup to the frame with hover highlighting
+    /// Render the popup to the frame with hover highlighting, framed in
+    /// `border` when the popup is bordered
     pub fn render_with_hover(
         &self,
         frame: &mut Frame,
         area: Rect,
         theme: &crate::view::theme::Theme,
         hover_target: Option<&crate::app::HoverTarget>,
+        border: BorderStyle,
     ) {
         // Defensive bounds checking: clamp area to frame bounds to prevent panic
         let frame_area = frame.area();
@@ -1082,8 +1113,7 @@ impl Popup {
 
         let rendered_title = self.render_title();
         let block = if self.bordered {
-            let mut block = Block::default()
-                .borders(Borders::ALL)
+            let mut block = bordered_block(border)
                 .border_style(self.border_style)
                 .style(self.background_style);
 
@@ -1467,15 +1497,24 @@ impl Popup {
 pub struct PopupManager {
     /// Stack of active popups (top of stack = topmost popup)
     popups: Vec<Popup>,
+    /// Markers anchoring `BelowBufferPosition` popups shown on this stack,
+    /// kept until `take_released_anchors` hands them back for deletion
+    anchors: Vec<MarkerId>,
 }
 
 impl PopupManager {
     pub fn new() -> Self {
-        Self { popups: Vec::new() }
+        Self {
+            popups: Vec::new(),
+            anchors: Vec::new(),
+        }
     }
 
     /// Show a popup (adds to top of stack)
     pub fn show(&mut self, popup: Popup) {
+        if let PopupPosition::BelowBufferPosition(marker) = popup.position {
+            self.anchors.push(marker);
+        }
         self.popups.push(popup);
     }
 
@@ -1495,6 +1534,11 @@ impl PopupManager {
         self.popups.pop()
     }
 
+    /// Remove the popup at `index`, wherever it sits in the stack
+    pub fn remove(&mut self, index: usize) -> Option<Popup> {
+        (index < self.popups.len()).then(|| self.popups.remove(index))
+    }
+
     /// Clear all popups
     pub fn clear(&mut self) {
         self.popups.clear();
@@ -1564,6 +1608,21 @@ impl PopupManager {
             false
         }
     }
+
+    /// Remove and return the anchor markers whose popups have left the
+    /// stack, however they were dismissed. The caller deletes them from the
+    /// buffer's marker list.
+    pub fn take_released_anchors(&mut self) -> Vec<MarkerId> {
+        let (live, released): (Vec<_>, Vec<_>) = std::mem::take(&mut self.anchors)
+            .into_iter()
+            .partition(|&marker| {
+                self.popups
+                    .iter()
+                    .any(|p| p.position == PopupPosition::BelowBufferPosition(marker))
+            });
+        self.anchors = live;
+        released
+    }
 }
 
 impl Default for PopupManager {
@@ -1688,6 +1747,26 @@ mod tests {
         assert_eq!(manager.all().len(), 0);
     }
 
+    #[test]
+    fn test_popup_manager_releases_anchors_of_dismissed_popups() {
+        let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let mut manager = PopupManager::new();
+
+        for id in [1, 2] {
+            let mut popup = Popup::text(vec![format!("anchored {id}")], &theme);
+            popup.position = PopupPosition::BelowBufferPosition(MarkerId(id));
+            manager.show(popup);
+        }
+        assert!(manager.take_released_anchors().is_empty());
+
+        manager.hide();
+        assert_eq!(manager.take_released_anchors(), vec![MarkerId(2)]);
+        assert!(manager.take_released_anchors().is_empty());
+
+        manager.clear();
+        assert_eq!(manager.take_released_anchors(), vec![MarkerId(1)]);
+    }
+
     #[test]
     fn test_popup_area_calculation() {
         let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
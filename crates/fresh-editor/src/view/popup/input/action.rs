@@ -4,12 +4,13 @@
 //! - Escape: dismiss the popup
 //! - Enter: execute the selected action
 //! - Arrow keys: navigate the action list
-//! - Tab/Shift+Tab: navigate the action list
+//! - Tab/Shift+Tab: navigate the action list (also with Ctrl held, so the
+//!   buffer switcher can be cycled without letting go of Ctrl)
 
 use super::base::{try_handle_shared, SharedHandleResult};
 use crate::input::handler::{DeferredAction, InputContext, InputResult};
 use crate::view::popup::Popup;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle input for action popups
 pub fn handle_action_input(
@@ -50,7 +51,7 @@ pub fn handle_action_input(
         }
 
         // Tab navigates forward
-        KeyCode::Tab if event.modifiers.is_empty() => {
+        KeyCode::Tab if event.modifiers.is_empty() || event.modifiers == KeyModifiers::CONTROL => {
             popup.select_next();
             InputResult::Consumed
         }
@@ -156,6 +156,12 @@ pub enum PromptType {
     },
     /// Confirm deleting multiple items from the file explorer
     ConfirmMultiDelete { paths: Vec<std::path::PathBuf> },
+    /// Confirm overwriting the destination of a drag-and-drop move in the
+    /// file explorer
+    ConfirmExplorerMoveOverwrite {
+        src: std::path::PathBuf,
+        dst: std::path::PathBuf,
+    },
     /// Per-conflict prompt for multi-file paste.
     /// `pending[0]` is the conflict currently being shown.
     /// User choices: (o)verwrite this, (O) all, (s)kip this, (S) all, (c)ancel.
@@ -170,6 +176,11 @@ pub enum PromptType {
     ConfirmLargeFileEncoding { path: std::path::PathBuf },
     /// Switch to a tab by name (from the current split's open buffers)
     SwitchToTab,
+    /// Pick a buffer to compare: `first` is `None` while choosing the left
+    /// side and holds it while choosing the right side
+    CompareBuffers {
+        first: Option<crate::model::event::BufferId>,
+    },
     /// Run shell command on buffer/selection
     /// If replace is true, replace the input with the output
     /// If replace is false, output goes to a new buffer
@@ -7,7 +7,7 @@
 use crate::model::buffer::Buffer;
 use crate::model::marker::MarkerList;
 use crate::primitives::reference_highlighter::ReferenceHighlighter;
-use crate::view::overlay::{Overlay, OverlayFace, OverlayManager, OverlayNamespace};
+use crate::view::overlay::{priority, Overlay, OverlayFace, OverlayManager, OverlayNamespace};
 use ratatui::style::Color;
 use std::time::{Duration, Instant};
 
@@ -84,7 +84,7 @@ impl ReferenceHighlightOverlay {
         let now = Instant::now();
 
         // Get the word under cursor
-        let word_under_cursor = get_word_at_position(buffer, cursor_position);
+        let word_under_cursor = get_word_at_position(highlighter, buffer, cursor_position);
 
         // Check if word changed from what we're tracking
         let word_changed = word_under_cursor != self.pending_word;
@@ -160,7 +160,7 @@ impl ReferenceHighlightOverlay {
         for span in spans {
             let face = OverlayFace::Background { color: span.color };
             let overlay = Overlay::with_namespace(marker_list, span.range, face, ns.clone())
-                .with_priority_value(5) // Lower priority than diagnostics
+                .with_priority_value(priority::SEMANTIC)
                 .with_theme_key("ui.semantic_highlight_bg");
 
             overlays.add(overlay);
@@ -205,40 +205,19 @@ impl Default for ReferenceHighlightOverlay {
     }
 }
 
-/// Get the word at the given position in the buffer
-fn get_word_at_position(buffer: &crate::model::buffer::Buffer, position: usize) -> Option<String> {
-    use crate::primitives::word_navigation::{find_word_end, find_word_start, is_word_char};
-
-    let buf_len = buffer.len();
-    if position > buf_len {
+/// Get the word at the given position in the buffer, classified the same
+/// way as `highlighter` (so language `word_characters` count as part of it)
+fn get_word_at_position(
+    highlighter: &ReferenceHighlighter,
+    buffer: &crate::model::buffer::Buffer,
+    position: usize,
+) -> Option<String> {
+    if position >= buffer.len() {
         return None;
     }
-
-    // Check if cursor is on a word character
-    let is_on_word = if position < buf_len {
-        let byte_at_pos = buffer.slice_bytes(position..position + 1);
-        byte_at_pos
-            .first()
-            .map(|&b| is_word_char(b))
-            .unwrap_or(false)
-    } else {
-        false
-    };
-
-    if !is_on_word {
-        return None;
-    }
-
-    // Find word boundaries
-    let start = find_word_start(buffer, position);
-    let end = find_word_end(buffer, position);
-
-    if start < end {
-        let word_bytes = buffer.slice_bytes(start..end);
-        std::str::from_utf8(&word_bytes).ok().map(|s| s.to_string())
-    } else {
-        None
-    }
+    let range = highlighter.get_word_at_position(buffer, position)?;
+    let word_bytes = buffer.slice_bytes(range);
+    std::str::from_utf8(&word_bytes).ok().map(|s| s.to_string())
 }
 
 #[cfg(test)]
@@ -249,17 +228,18 @@ mod tests {
     #[test]
     fn test_get_word_at_position() {
         let buffer = Buffer::from_str_test("hello world test");
+        let highlighter = ReferenceHighlighter::new();
 
         // Middle of "hello"
-        let word = get_word_at_position(&buffer, 2);
+        let word = get_word_at_position(&highlighter, &buffer, 2);
         assert_eq!(word, Some("hello".to_string()));
 
         // On space - no word
-        let word = get_word_at_position(&buffer, 5);
+        let word = get_word_at_position(&highlighter, &buffer, 5);
         assert_eq!(word, None);
 
         // Start of "world"
-        let word = get_word_at_position(&buffer, 6);
+        let word = get_word_at_position(&highlighter, &buffer, 6);
         assert_eq!(word, Some("world".to_string()));
     }
 }
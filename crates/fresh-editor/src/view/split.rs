@@ -622,6 +622,26 @@ impl SplitViewState {
         self.focus_history
             .retain(|t| *t != TabTarget::Group(leaf_id));
     }
+
+    /// Buffer tabs ordered most recently focused first: the active buffer,
+    /// then the focus history newest-first, then tabs never focused in tab
+    /// order. Drives the MRU buffer switcher.
+    pub fn buffer_tabs_by_recent_focus(&self) -> Vec<BufferId> {
+        let mut ordered: Vec<BufferId> = Vec::new();
+        if self.has_buffer(self.active_buffer) {
+            ordered.push(self.active_buffer);
+        }
+        let history = self.focus_history.iter().rev().filter_map(|t| match t {
+            TabTarget::Buffer(id) => Some(*id),
+            TabTarget::Group(_) => None,
+        });
+        for id in history.chain(self.buffer_tab_ids()) {
+            if self.has_buffer(id) && !ordered.contains(&id) {
+                ordered.push(id);
+            }
+        }
+        ordered
+    }
 }
 
 impl SplitNode {
@@ -541,6 +541,10 @@ pub struct EditorColors {
     /// Cursor color in unfocused splits
     #[serde(default = "default_inactive_cursor")]
     pub inactive_cursor: ColorDef,
+    /// Background of the secondary cursors when editing with multiple
+    /// cursors, so the primary cursor stands out
+    #[serde(default = "default_secondary_cursor")]
+    pub secondary_cursor: ColorDef,
     /// Selected text background
     #[serde(default = "default_selection_bg")]
     pub selection_bg: ColorDef,
@@ -661,6 +665,9 @@ fn default_cursor() -> ColorDef {
 fn default_inactive_cursor() -> ColorDef {
     ColorDef::Named("DarkGray".to_string())
 }
+fn default_secondary_cursor() -> ColorDef {
+    ColorDef::Rgb(90, 90, 120)
+}
 fn default_selection_bg() -> ColorDef {
     ColorDef::Rgb(38, 79, 120)
 }
@@ -1404,6 +1411,7 @@ pub struct Theme {
     pub editor_fg: Color,
     pub cursor: Color,
     pub inactive_cursor: Color,
+    pub secondary_cursor: Color,
     pub selection_bg: Color,
     /// SGR text attributes layered onto selected cells. Empty for
     /// traditional themes; native-palette themes set
@@ -1625,6 +1633,7 @@ impl From<ThemeFile> for Theme {
             editor_fg: file.editor.fg.into(),
             cursor: file.editor.cursor.into(),
             inactive_cursor: file.editor.inactive_cursor.into(),
+            secondary_cursor: file.editor.secondary_cursor.into(),
             selection_bg: file.editor.selection_bg.into(),
             selection_modifier: file
                 .editor
@@ -1893,6 +1902,7 @@ impl From<Theme> for ThemeFile {
                 fg: theme.editor_fg.into(),
                 cursor: theme.cursor.into(),
                 inactive_cursor: theme.inactive_cursor.into(),
+                secondary_cursor: theme.secondary_cursor.into(),
                 selection_bg: theme.selection_bg.into(),
                 selection_modifier: if theme.selection_modifier.is_empty() {
                     None
@@ -2380,6 +2390,7 @@ theme_color_keys! {
         "line_number_bg" => color line_number_bg,
         "line_number_fg" => color line_number_fg,
         "ruler_bg" => color ruler_bg,
+        "secondary_cursor" => color secondary_cursor,
         "selection_bg" => color selection_bg,
         "whitespace_indicator_fg" => color whitespace_indicator_fg,
         "bracket_match_fg" => color bracket_match_fg,
@@ -0,0 +1,51 @@
+//! Framed chrome borders (the `border_style` setting)
+//!
+//! Popups, menu dropdowns, context menus and the file explorer build their
+//! frame with [`bordered_block`], so the setting applies to all of them
+//! alike. The frame always takes one cell on each side — `None` just draws
+//! blanks there — so inner areas and mouse hit areas never depend on it.
+
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders};
+
+use crate::config::BorderStyle;
+
+/// A block with all four borders drawn in `style`'s box-drawing characters.
+pub fn bordered_block<'a>(style: BorderStyle) -> Block<'a> {
+    let set = match style {
+        BorderStyle::None => border::EMPTY,
+        BorderStyle::Plain => border::PLAIN,
+        BorderStyle::Rounded => border::ROUNDED,
+        BorderStyle::Double => border::DOUBLE,
+    };
+    Block::default().borders(Borders::ALL).border_set(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    /// Render a 4x3 box in `style` and return its four corners,
+    /// clockwise from the top left.
+    fn corners(style: BorderStyle) -> [String; 4] {
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        bordered_block(style).render(area, &mut buf);
+        [(0, 0), (3, 0), (3, 2), (0, 2)].map(|(x, y)| buf[(x, y)].symbol().to_string())
+    }
+
+    #[test]
+    fn test_rounded_and_double_corners() {
+        assert_eq!(corners(BorderStyle::Rounded), ["╭", "╮", "╯", "╰"]);
+        assert_eq!(corners(BorderStyle::Double), ["╔", "╗", "╝", "╚"]);
+    }
+
+    #[test]
+    fn test_plain_is_the_default_and_none_draws_blanks() {
+        assert_eq!(corners(BorderStyle::default()), ["┌", "┐", "┘", "└"]);
+        assert_eq!(corners(BorderStyle::None), [" ", " ", " ", " "]);
+    }
+}
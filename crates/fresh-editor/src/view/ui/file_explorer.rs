@@ -1,16 +1,18 @@
 use crate::app::types::CellThemeRecorder;
+use crate::config::BorderStyle;
 use crate::input::fuzzy::FuzzyMatch;
 use crate::primitives::display_width::str_width;
 use crate::view::file_tree::{
     ExplorerSlotContext, ExplorerSlotResolution, ExplorerSlotResolver, FileExplorerDecorationCache,
-    FileExplorerSlotOverrideCache, FileTreeView, NodeId,
+    FileExplorerSlotOverrideCache, FileTreeView, IgnoreStatus, NodeId,
 };
 use crate::view::theme::Theme;
+use crate::view::ui::border::bordered_block;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{List, ListItem, ListState},
     Frame,
 };
 
@@ -141,6 +143,7 @@ impl FileExplorerRenderer {
         theme: &Theme,
         close_button_hovered: bool,
         remote_connection: Option<&str>,
+        border_style: BorderStyle,
     ) {
         use ratatui::layout::Alignment;
         use ratatui::widgets::Paragraph;
@@ -156,8 +159,7 @@ impl FileExplorerRenderer {
         let (title_style, border_style) =
             Self::panel_chrome_styles(remote_connection, is_focused, theme);
 
-        let block = Block::default()
-            .borders(Borders::ALL)
+        let block = bordered_block(border_style)
             .title(title)
             .title_style(title_style)
             .border_style(border_style)
@@ -198,6 +200,7 @@ impl FileExplorerRenderer {
         theme: &Theme,
         close_button_hovered: bool,
         remote_connection: Option<&str>,
+        border_style: BorderStyle,
         cut_paths: &[PathBuf],
         config: &crate::config::FileExplorerConfig,
         // The explorer is only ever painted by the TUI path, which always
@@ -313,8 +316,7 @@ impl FileExplorerRenderer {
         // Create the list widget
         let list = List::new(items)
             .block(
-                Block::default()
-                    .borders(Borders::ALL)
+                bordered_block(border_style)
                     .title(title)
                     .title_style(title_style)
                     .border_style(border_style)
@@ -473,12 +475,20 @@ impl FileExplorerRenderer {
         };
 
         let is_pending_cut = cut_paths.iter().any(|cp| cp == &node.entry.path);
-        let neutral_fg = if node
-            .entry
-            .metadata
-            .as_ref()
-            .map(|m| m.is_hidden)
-            .unwrap_or(false)
+        // Hidden and ignored entries (only listed when their filter is
+        // toggled off) are dimmed so they read as secondary.
+        let is_ignored = matches!(
+            view.ignore_patterns()
+                .get_status(&node.entry.path, node.is_dir()),
+            IgnoreStatus::GitIgnored | IgnoreStatus::CustomIgnored
+        );
+        let neutral_fg = if is_ignored
+            || node
+                .entry
+                .metadata
+                .as_ref()
+                .map(|m| m.is_hidden)
+                .unwrap_or(false)
         {
             theme.line_number_fg
         } else if node.entry.is_symlink() {
@@ -857,6 +867,94 @@ mod tests {
         }));
     }
 
+    #[tokio::test]
+    async fn renderer_decorates_modified_and_untracked_files() {
+        let (_temp_dir, view) = create_renderer_view().await;
+        let theme = Theme::load_builtin("dark").unwrap();
+        let root = view.tree().root_path().to_path_buf();
+        let decoration = |path: PathBuf, symbol: &str, key: &str, priority| {
+            crate::view::file_tree::FileExplorerDecoration {
+                path,
+                symbol: symbol.to_string(),
+                color: fresh_core::api::OverlayColorSpec::ThemeKey(key.into()),
+                priority,
+            }
+        };
+        let decorations = FileExplorerDecorationCache::rebuild(
+            vec![
+                decoration(
+                    root.join("README.md"),
+                    "M",
+                    "ui.file_status_modified_fg",
+                    50,
+                ),
+                decoration(
+                    root.join("src/schema.ts"),
+                    "U",
+                    "ui.file_status_untracked_fg",
+                    30,
+                ),
+            ],
+            &root,
+            &HashMap::new(),
+        );
+        let line_for = |path: PathBuf, indent| {
+            let id = view.tree().get_node_by_path(&path).unwrap().id;
+            build_line(
+                &view,
+                id,
+                indent,
+                &decorations,
+                &FileExplorerSlotOverrideCache::default(),
+                &theme,
+            )
+        };
+        let has_badge = |line: &Line<'static>, symbol: &str, fg: Color| {
+            line.spans
+                .iter()
+                .any(|span| span.content.as_ref() == symbol && span.style.fg == Some(fg))
+        };
+
+        let readme = line_for(root.join("README.md"), 1);
+        assert!(has_badge(&readme, "M", theme.file_status_modified_fg));
+
+        let schema = line_for(root.join("src/schema.ts"), 2);
+        assert!(has_badge(&schema, "U", theme.file_status_untracked_fg));
+
+        // The untracked file's status bubbles up to its directory.
+        let src = line_for(root.join("src"), 1);
+        assert!(has_badge(&src, "●", theme.file_status_untracked_fg));
+    }
+
+    #[tokio::test]
+    async fn gitignored_entries_render_dimmed() {
+        let (_temp_dir, mut view) = create_renderer_view().await;
+        let theme = Theme::load_builtin("dark").unwrap();
+        let root = view.tree().root_path().to_path_buf();
+        view.ignore_patterns_mut()
+            .load_gitignore_from_bytes(&root, b"*.md\n", None);
+        view.ignore_patterns_mut().set_show_gitignored(true);
+
+        let name_fg = |name: &str| {
+            let id = view.tree().get_node_by_path(&root.join(name)).unwrap().id;
+            let line = build_line(
+                &view,
+                id,
+                1,
+                &FileExplorerDecorationCache::default(),
+                &FileExplorerSlotOverrideCache::default(),
+                &theme,
+            );
+            line.spans
+                .iter()
+                .find(|span| span.content.contains(name.rsplit('/').next().unwrap()))
+                .and_then(|span| span.style.fg)
+        };
+
+        assert_eq!(name_fg("README.md"), Some(theme.line_number_fg));
+        assert_eq!(name_fg("src/schema.ts"), Some(theme.editor_fg));
+    }
+
     #[tokio::test]
     async fn default_slot_providers_allow_explicit_slot_and_name_color_overrides() {
         let (_temp_dir, view) = create_renderer_view().await;
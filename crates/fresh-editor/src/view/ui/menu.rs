@@ -1,14 +1,15 @@
 //! Menu bar rendering
 
 use crate::app::types::CellThemeRecorder;
-use crate::config::{generate_dynamic_items, Menu, MenuItem, MenuItemExt};
+use crate::config::{generate_dynamic_items, BorderStyle, Menu, MenuItem, MenuItemExt};
 use crate::primitives::display_width::str_width;
 use crate::view::theme::Theme;
+use crate::view::ui::border::bordered_block;
 use crate::view::ui::layout::point_in_rect;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 // Re-export context_keys from the shared types module
@@ -512,6 +513,7 @@ impl MenuRenderer {
     /// * `keybindings` - Keybinding resolver for displaying shortcuts
     /// * `theme` - The active theme for colors
     /// * `hover_target` - The currently hovered UI element (if any)
+    /// * `border_style` - Border drawn around dropdowns
     ///
     /// # Returns
     /// `MenuLayout` containing hit areas for mouse interaction
@@ -528,6 +530,7 @@ impl MenuRenderer {
         theme: &Theme,
         hover_target: Option<&crate::app::HoverTarget>,
         mnemonics_enabled: bool,
+        border_style: BorderStyle,
         mut rec: Option<&mut CellThemeRecorder>,
         // When false, compute + record layout but skip emitting cells (the host
         // renders the menu from the semantic model). See docs/internal/web-ui.md.
@@ -666,6 +669,7 @@ impl MenuRenderer {
                     keybindings,
                     theme,
                     hover_target,
+                    border_style,
                     &mut layout,
                     rec,
                     draw,
@@ -688,6 +692,7 @@ impl MenuRenderer {
         keybindings: &crate::input::keybindings::KeybindingResolver,
         theme: &Theme,
         hover_target: Option<&crate::app::HoverTarget>,
+        border_style: BorderStyle,
         layout: &mut MenuLayout,
         mut rec: Option<&mut CellThemeRecorder>,
         draw: bool,
@@ -742,6 +747,7 @@ impl MenuRenderer {
                 keybindings,
                 theme,
                 hover_target,
+                border_style,
                 &menu_state.context,
                 layout,
                 rec.as_deref_mut(),
@@ -820,6 +826,7 @@ impl MenuRenderer {
         keybindings: &crate::input::keybindings::KeybindingResolver,
         theme: &Theme,
         hover_target: Option<&crate::app::HoverTarget>,
+        border_style: BorderStyle,
         context: &MenuContext,
         layout: &mut MenuLayout,
         mut rec: Option<&mut CellThemeRecorder>,
@@ -917,8 +924,7 @@ impl MenuRenderer {
             ));
         }
 
-        let block = Block::default()
-            .borders(Borders::ALL)
+        let block = bordered_block(border_style)
             .border_style(Style::default().fg(theme.menu_border_fg))
             .style(Style::reset().bg(theme.menu_dropdown_bg));
 
@@ -2,6 +2,7 @@
 //!
 //! This module contains all rendering logic for the editor UI,
 //! separated into focused submodules:
+//! - `border` - Popup, menu and file explorer frames (`border_style`)
 //! - `menu` - Menu bar rendering
 //! - `tabs` - Tab bar rendering for multiple buffers
 //! - `status_bar` - Status bar and prompt/minibuffer display
@@ -13,6 +14,7 @@
 //! - `file_browser` - File open dialog popup
 
 // WASM-compatible modules (pure rendering, no runtime deps)
+pub mod border;
 pub mod focus;
 pub mod layout;
 pub mod scroll_panel;
@@ -164,17 +164,18 @@ pub(super) fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
         }
     }
 
-    // Apply overlay styles — last overlay wins for each attribute
+    // Apply overlay styles — last overlay wins for each attribute. Faces
+    // only write the attributes they carry (see `overlay::Priority`), so an
+    // underline colours the underline and leaves the text colour alone.
     for overlay in ctx.active_overlays {
         match &overlay.face {
             OverlayFace::Underline {
                 color,
                 style: _underline_style,
             } => {
-                style = style.add_modifier(Modifier::UNDERLINED).fg(*color);
-                if let Some(key) = overlay.theme_key {
-                    fg_theme_key = Some(key);
-                }
+                style = style
+                    .add_modifier(Modifier::UNDERLINED)
+                    .underline_color(*color);
             }
             OverlayFace::Background { color } => {
                 style = style.bg(*color);
@@ -261,15 +262,17 @@ pub(super) fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
         region = "Selection";
     }
 
-    // Apply cursor styling.
+    // Apply cursor styling. Secondary cursors get their own theme color so
+    // the primary one stays easy to spot among them.
     let is_secondary_cursor = ctx.is_cursor && ctx.byte_pos != Some(ctx.primary_cursor_position);
     if ctx.is_active {
-        if ctx.is_cursor {
-            if ctx.skip_primary_cursor_reverse {
-                if is_secondary_cursor {
-                    style = style.add_modifier(Modifier::REVERSED);
-                }
-            } else {
+        if is_secondary_cursor {
+            style = style.fg(ctx.theme.editor_fg).bg(ctx.theme.secondary_cursor);
+            fg_theme_key = Some("editor.fg");
+            bg_theme_key = Some("editor.secondary_cursor");
+            region = "Secondary Cursor";
+        } else if ctx.is_cursor {
+            if !ctx.skip_primary_cursor_reverse {
                 style = style.add_modifier(Modifier::REVERSED);
             }
             region = "Cursor";
@@ -294,7 +297,7 @@ pub(super) fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
 mod tests {
     use super::*;
     use crate::model::marker::MarkerList;
-    use crate::view::overlay::Overlay;
+    use crate::view::overlay::{priority, Overlay};
     use crate::view::theme::{Theme, THEME_TERMINAL};
     use ratatui::style::Color;
 
@@ -376,6 +379,98 @@ mod tests {
         assert!(out.style.add_modifier.contains(Modifier::ITALIC));
     }
 
+    #[test]
+    fn selection_and_diagnostic_underline_both_apply() {
+        let theme = Theme::load_builtin(crate::view::theme::THEME_DARK).unwrap();
+        let mut ml = MarkerList::new();
+        ml.set_buffer_size(100);
+        let search = Overlay::with_priority(
+            &mut ml,
+            0..10,
+            OverlayFace::Background {
+                color: Color::Yellow,
+            },
+            priority::DEFAULT,
+        );
+        let error =
+            Overlay::error(&mut ml, 0..10, None).with_priority_value(priority::DIAGNOSTIC_ERROR);
+        let overlays: Vec<&Overlay> = vec![&search, &error];
+
+        let out = compute_char_style(&CharStyleContext {
+            byte_pos: Some(0),
+            token_style: None,
+            ansi_style: Style::default(),
+            is_cursor: false,
+            is_selected: true,
+            theme: &theme,
+            highlight_color: Some(theme.syntax_keyword),
+            highlight_theme_key: Some("syntax.keyword"),
+            highlight_bg: None,
+            highlight_bg_theme_key: None,
+            semantic_token_color: None,
+            active_overlays: &overlays,
+            primary_cursor_position: 50,
+            is_active: true,
+            skip_primary_cursor_reverse: true,
+            is_cursor_line_highlighted: false,
+            current_line_bg: theme.current_line_bg,
+        });
+
+        // Selection owns the background, the error owns the underline and
+        // the syntax colour keeps the text.
+        assert_eq!(out.style.bg, Some(theme.selection_bg));
+        assert!(out.style.add_modifier.contains(Modifier::UNDERLINED));
+        assert_eq!(out.style.underline_color, Some(Color::Red));
+        assert_eq!(out.style.fg, Some(theme.syntax_keyword));
+        assert_eq!(out.fg_theme_key, Some("syntax.keyword"));
+        assert_eq!(out.bg_theme_key, Some("editor.selection_bg"));
+    }
+
+    #[test]
+    fn higher_priority_overlay_wins_each_attribute() {
+        let theme = Theme::load_builtin(crate::view::theme::THEME_DARK).unwrap();
+        let mut ml = MarkerList::new();
+        ml.set_buffer_size(100);
+        let low = Overlay::with_priority(
+            &mut ml,
+            0..10,
+            OverlayFace::Style {
+                style: Style::default().fg(Color::Blue).bg(Color::Green),
+            },
+            priority::SEMANTIC,
+        );
+        let high = Overlay::with_priority(
+            &mut ml,
+            0..10,
+            OverlayFace::Background { color: Color::Red },
+            priority::DIAGNOSTIC_WARNING,
+        );
+        let overlays: Vec<&Overlay> = vec![&low, &high];
+
+        let out = compute_char_style(&CharStyleContext {
+            byte_pos: Some(0),
+            token_style: None,
+            ansi_style: Style::default(),
+            is_cursor: false,
+            is_selected: false,
+            theme: &theme,
+            highlight_color: None,
+            highlight_theme_key: None,
+            highlight_bg: None,
+            highlight_bg_theme_key: None,
+            semantic_token_color: None,
+            active_overlays: &overlays,
+            primary_cursor_position: 50,
+            is_active: true,
+            skip_primary_cursor_reverse: true,
+            is_cursor_line_highlighted: false,
+            current_line_bg: theme.current_line_bg,
+        });
+
+        assert_eq!(out.style.bg, Some(Color::Red));
+        assert_eq!(out.style.fg, Some(Color::Blue));
+    }
+
     #[test]
     fn collision_only_preserves_non_matching_fg() {
         // Red keyword on Green diff-add bg: no collision → keep Red.
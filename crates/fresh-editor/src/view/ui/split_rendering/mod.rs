@@ -63,9 +63,12 @@ pub struct EditorRenderConfig<'a> {
     pub diagnostics_inline_text: bool,
     pub show_tilde: bool,
     pub highlight_current_column: bool,
+    pub show_wrap_guide: bool,
+    pub page_view_focus: bool,
     pub indentation_guide: IndentationGuideMode,
     pub indentation_guide_glyph: &'a str,
     pub rainbow_indentation: bool,
+    pub selection_full_line: bool,
     pub hide_current_line_on_selection: bool,
     pub background_fade: f32,
     pub software_cursor_only: bool,
@@ -93,9 +96,12 @@ impl<'a> EditorRenderConfig<'a> {
             diagnostics_inline_text: editor.diagnostics_inline_text,
             show_tilde: editor.show_tilde,
             highlight_current_column: editor.highlight_current_column,
+            show_wrap_guide: editor.show_wrap_guide,
+            page_view_focus: editor.page_view_focus,
             indentation_guide: editor.indentation_guide,
             indentation_guide_glyph: &editor.indentation_guide_glyph,
             rainbow_indentation: editor.rainbow_indentation,
+            selection_full_line: editor.selection_full_line,
             hide_current_line_on_selection: editor.hide_current_line_on_selection,
             background_fade,
             software_cursor_only,
@@ -540,6 +546,7 @@ mod tests {
             indentation_guide,
             indentation_guide_glyph: &indentation_guide_glyph,
             rainbow_indentation: false,
+            selection_full_line: false,
             cell_theme_map: &mut dummy_theme_map,
             screen_width: 0,
         });
@@ -646,6 +653,7 @@ mod tests {
             indentation_guide: IndentationGuideMode::All,
             indentation_guide_glyph: &glyph,
             rainbow_indentation: false,
+            selection_full_line: false,
             cell_theme_map: &mut dummy_theme_map,
             screen_width: 0,
         })
@@ -3196,6 +3204,7 @@ mod tests {
             indentation_guide: IndentationGuideMode::None,
             indentation_guide_glyph: "▏",
             rainbow_indentation: false,
+            selection_full_line: false,
             cell_theme_map: &mut Vec::new(),
             screen_width: 0,
         })
@@ -145,6 +145,7 @@ pub(crate) fn render_content(
         show_horizontal_scrollbar,
         show_tilde,
         highlight_current_column,
+        show_wrap_guide,
         hide_current_line_on_selection,
         ..
     } = cfg;
@@ -437,15 +438,28 @@ pub(crate) fn render_content(
             // Column rulers are a source-code editing aid; virtual buffers
             // (dashboard, *Diagnostics*, grep results, ...) aren't code, so
             // the config-driven rulers would just paint stripes over plugin
-            // chrome. Suppress them for any virtual buffer.
+            // chrome. Suppress them for any virtual buffer. The wrap guide
+            // joins them when wrapping at a fixed column (never for the
+            // terminal grid, whose wrap column is the full width).
             let is_virtual_buffer = buffer_metadata
                 .get(&buffer_id)
                 .is_some_and(|m| m.is_virtual());
-            let effective_rulers: &[usize] = if is_virtual_buffer {
-                &[]
+            let mut effective_rulers: Vec<usize> = if is_virtual_buffer {
+                Vec::new()
             } else {
-                &view_prefs.rulers
+                view_prefs.rulers.clone()
             };
+            if show_wrap_guide
+                && !is_virtual_buffer
+                && viewport.line_wrap_enabled
+                && !viewport.grid_wrap
+            {
+                if let Some(col) = viewport.wrap_column {
+                    if !effective_rulers.contains(&col) {
+                        effective_rulers.push(col);
+                    }
+                }
+            }
 
             // Indentation guides are a source-code editing aid, like the column
             // rulers above. Resolve the effective mode per buffer: an explicit
@@ -494,7 +508,7 @@ pub(crate) fn render_content(
                 buffer_id,
                 hide_cursor,
                 session_mode,
-                effective_rulers,
+                &effective_rulers,
                 view_prefs.show_line_numbers,
                 effective_highlight_current_line,
                 split_show_tilde,
@@ -1227,6 +1241,7 @@ pub(crate) fn compute_content_layout(
             IndentationGuideMode::None,
             "▏",
             false,
+            false,
             None, // No cell theme map for layout-only computation
         );
 
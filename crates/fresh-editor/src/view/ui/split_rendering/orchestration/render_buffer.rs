@@ -12,7 +12,8 @@ use super::super::layout::{
     calculate_compose_layout, calculate_view_anchor, calculate_viewport_end, ComposeLayout,
 };
 use super::super::post_pass::{
-    apply_background_to_lines, render_column_guides, render_cursor_column_bg, render_ruler_bg,
+    apply_background_to_lines, dim_rows_outside_range, render_column_guides,
+    render_cursor_column_bg, render_ruler_bg,
 };
 use super::super::view_data::build_view_data;
 use super::contexts::SelectionContext;
@@ -20,6 +21,7 @@ use super::overlays::{decoration_context, selection_context};
 use super::render_line::{render_view_lines, LastLineEnd, LineRenderInput, LineRenderOutput};
 use crate::app::types::{CellThemeInfo, ViewLineMapping};
 use crate::config::IndentationGuideMode;
+use crate::model::buffer::Buffer;
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, EventLog};
 use crate::primitives::ansi_background::AnsiBackground;
@@ -32,6 +34,7 @@ use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::Widget;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use std::ops::Range;
 
 /// Output of the pure layout computation phase of buffer rendering.
 ///
@@ -114,6 +117,7 @@ pub(crate) fn compute_buffer_layout(
     indentation_guide: IndentationGuideMode,
     indentation_guide_glyph: &str,
     rainbow_indentation: bool,
+    selection_full_line: bool,
     cell_theme_map: Option<(&mut Vec<CellThemeInfo>, u16)>,
 ) -> BufferLayoutOutput {
     let _span = tracing::trace_span!("compute_buffer_layout").entered();
@@ -396,6 +400,7 @@ pub(crate) fn compute_buffer_layout(
         indentation_guide,
         indentation_guide_glyph,
         rainbow_indentation,
+        selection_full_line,
         cell_theme_map: map_ref,
         screen_width: sw,
     });
@@ -441,6 +446,7 @@ pub(crate) fn draw_buffer_in_split(
     rulers: &[usize],
     compose_column_guides: Option<Vec<u16>>,
     highlight_current_column: bool,
+    focus_range: Option<Range<usize>>,
     pending_hardware_cursor: &mut Option<(u16, u16)>,
 ) {
     let render_area = layout_output.render_area;
@@ -481,6 +487,16 @@ pub(crate) fn draw_buffer_in_split(
         .block(editor_block)
         .render(render_area, buf);
 
+    if let Some(focus) = &focus_range {
+        dim_rows_outside_range(
+            buf,
+            &layout_output.view_line_mappings,
+            focus,
+            render_area,
+            gutter_width,
+        );
+    }
+
     let cursor_from_line_pass = layout_output.render_output.cursor.is_some();
     let cursor = resolve_cursor_fallback(
         layout_output.render_output.cursor,
@@ -659,8 +675,11 @@ pub(crate) fn render_buffer_in_split(
         indentation_guide,
         indentation_guide_glyph,
         rainbow_indentation,
+        selection_full_line,
+        page_view_focus,
         ..
     } = cfg;
+    let focus_in_page_view = page_view_focus && view_mode == ViewMode::PageView && is_active;
     let layout_output = compute_buffer_layout(
         state,
         cursors,
@@ -686,10 +705,21 @@ pub(crate) fn render_buffer_in_split(
         indentation_guide,
         indentation_guide_glyph,
         rainbow_indentation,
+        selection_full_line,
         Some((cell_theme_map, screen_width)),
     );
 
     let view_line_mappings = layout_output.view_line_mappings.clone();
+    let focus_range = if focus_in_page_view {
+        focused_paragraph(
+            &mut state.buffer,
+            cursors.primary().position,
+            &view_line_mappings,
+            estimated_line_length,
+        )
+    } else {
+        None
+    };
 
     draw_buffer_in_split(
         buf,
@@ -707,8 +737,51 @@ pub(crate) fn render_buffer_in_split(
         rulers,
         compose_column_guides,
         highlight_current_column,
+        focus_range,
         pending_hardware_cursor,
     );
 
     view_line_mappings
 }
+
+/// Byte range of the paragraph (run of non-blank lines) holding `cursor`,
+/// for page-view focus mode. The scan stops at the rows on screen, so a
+/// huge paragraph costs no more than the visible text. `None` when nothing
+/// is rendered.
+fn focused_paragraph(
+    buffer: &mut Buffer,
+    cursor: usize,
+    view_line_mappings: &[ViewLineMapping],
+    estimated_line_length: usize,
+) -> Option<Range<usize>> {
+    let visible_bytes = view_line_mappings
+        .iter()
+        .flat_map(|m| m.char_source_bytes.iter().flatten().copied());
+    let (first_visible, last_visible) = visible_bytes.fold(None, |acc, b| match acc {
+        None => Some((b, b)),
+        Some((lo, hi)) => Some((b.min(lo), b.max(hi))),
+    })?;
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    let mut iter = buffer.line_iterator(cursor, estimated_line_length);
+    let mut start = iter.current_position();
+    while start > first_visible {
+        match iter.prev() {
+            Some((line_start, line)) if !is_blank(&line) => start = line_start,
+            _ => break,
+        }
+    }
+
+    let mut iter = buffer.line_iterator(cursor, estimated_line_length);
+    let mut end = match iter.next_line() {
+        Some((line_start, line)) => line_start + line.len(),
+        None => cursor,
+    };
+    while end <= last_visible {
+        match iter.next_line() {
+            Some((line_start, line)) if !is_blank(&line) => end = line_start + line.len(),
+            _ => break,
+        }
+    }
+    Some(start..end.max(cursor + 1))
+}
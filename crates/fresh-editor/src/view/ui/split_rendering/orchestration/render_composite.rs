@@ -403,6 +403,13 @@ fn render_aligned_row(
     let mut x_offset = area_x;
     for (pane_idx, &width) in layout.widths.iter().enumerate() {
         let pane_area = Rect::new(x_offset, row_y, width, 1);
+        // A pane's own background, when set, replaces the theme's.
+        let pane_bg = composite
+            .sources
+            .get(pane_idx)
+            .and_then(|source| source.style.bg)
+            .map(|(r, g, b)| Color::Rgb(r, g, b))
+            .unwrap_or(effective_editor_bg);
         render_row_pane(
             buf,
             pane_area,
@@ -415,7 +422,7 @@ fn render_aligned_row(
             selection_cols,
             is_cursor_row,
             row_bg,
-            effective_editor_bg,
+            pane_bg,
             theme,
         );
         x_offset += width;
@@ -277,7 +277,8 @@ impl CellPass<'_, '_, '_> {
 
         // For primary cursor in active split, terminal hardware cursor provides
         // visual indication, so we can still show selection background.
-        // Only exclude secondary cursors from selection (they use REVERSED styling).
+        // Only exclude secondary cursors from selection (they use the
+        // secondary-cursor background).
         // Bug #614: Previously excluded all cursor positions, causing first char
         // of selection to display with wrong background for bar/underline cursors.
         let is_primary_cursor =
@@ -686,7 +687,11 @@ impl CellPass<'_, '_, '_> {
                     self.line_spans,
                     self.line_view_map,
                     " ".to_string(),
-                    cursor_indicator_style(self.input.theme, self.input.is_active),
+                    cursor_indicator_style(
+                        self.input.theme,
+                        self.input.is_active,
+                        is_secondary_cursor,
+                    ),
                     byte_pos,
                 );
             }
@@ -86,6 +86,9 @@ pub(crate) struct LineRenderInput<'a> {
     /// Color indentation guides by indent level (palette slot picked from the
     /// guide's column, one slot per tab stop).
     pub rainbow_indentation: bool,
+    /// Extend the selection background to the right edge on rows whose
+    /// newline is selected.
+    pub selection_full_line: bool,
     /// Per-cell theme key map for the theme inspector (screen_width used for indexing)
     pub cell_theme_map: &'a mut Vec<crate::app::types::CellThemeInfo>,
     /// Screen width for cell_theme_map indexing
@@ -490,6 +493,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
         indentation_guide,
         indentation_guide_glyph,
         rainbow_indentation,
+        selection_full_line,
         cell_theme_map,
         screen_width,
     } = input;
@@ -965,10 +969,31 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
             }
         }
 
+        // With `selection_full_line`, a row whose newline is selected
+        // carries the selection background to the right edge. It takes
+        // the place of the tail and current-line fills below.
+        let remaining_cols = content_width.saturating_sub(rendered_cols);
+        let selection_fills_tail = selection_full_line
+            && remaining_cols > 0
+            && line_has_newline
+            && line_char_source_bytes
+                .last()
+                .copied()
+                .flatten()
+                .is_some_and(|nl| selection.ranges.iter().any(|r| r.contains(&nl)));
+        if selection_fills_tail {
+            // Added directly (not via push_span_with_map) for the same
+            // reason as the current-line fill below.
+            let mut style = Style::default().bg(theme.selection_bg);
+            if !theme.selection_modifier.is_empty() {
+                style = style.add_modifier(theme.selection_modifier);
+            }
+            line_spans.push(Span::styled(" ".repeat(remaining_cols), style));
+        }
+
         // Paint trailing columns with the overlay-extend bg, or fall
         // back to the virtual-line bg. See `tail_fill` for the policy.
-        let remaining_cols = content_width.saturating_sub(rendered_cols);
-        if remaining_cols > 0 {
+        if remaining_cols > 0 && !selection_fills_tail {
             if let Some(fill) = resolve_tail_fill(TailFillInput {
                 current_view_line,
                 theme,
@@ -990,7 +1015,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
         // Fill remaining width with current_line_bg for cursor line highlighting.
         // Add the span directly (not via push_span_with_map) to avoid extending
         // line_view_map, which would break mouse click byte mapping.
-        if cursor_line_active && remaining_cols > 0 {
+        if cursor_line_active && remaining_cols > 0 && !selection_fills_tail {
             line_spans.push(Span::styled(
                 " ".repeat(remaining_cols),
                 Style::default().bg(theme.current_line_bg),
@@ -1160,8 +1185,12 @@ fn prefill_cell_theme_map(
 }
 
 /// Style for the software cursor indicator cell.
-fn cursor_indicator_style(theme: &Theme, is_active: bool) -> Style {
-    if is_active {
+fn cursor_indicator_style(theme: &Theme, is_active: bool, is_secondary: bool) -> Style {
+    if is_active && is_secondary {
+        Style::default()
+            .fg(theme.editor_fg)
+            .bg(theme.secondary_cursor)
+    } else if is_active {
         Style::default()
             .fg(theme.editor_fg)
             .bg(theme.editor_bg)
@@ -1209,7 +1238,18 @@ fn place_line_end_cursor(
     let last_char_idx = line_len_chars.saturating_sub(1);
     let after_last_char_idx = line_len_chars;
 
-    let last_char_buf_pos = line_char_source_bytes.get(last_char_idx).copied().flatten();
+    // After bidi reordering the rightmost char need not be the logically
+    // last one; the line still ends after the highest source byte.
+    let last_char_buf_pos = line_char_source_bytes
+        .get(last_char_idx)
+        .copied()
+        .flatten()
+        .map(|last| {
+            line_char_source_bytes
+                .iter()
+                .flatten()
+                .fold(last, |a, &b| a.max(b))
+        });
     let after_last_char_buf_pos = line_char_source_bytes
         .get(after_last_char_idx)
         .copied()
@@ -1286,7 +1326,7 @@ fn place_line_end_cursor(
             line_spans,
             line_view_map,
             " ".to_string(),
-            cursor_indicator_style(input.theme, input.is_active),
+            cursor_indicator_style(input.theme, input.is_active, !is_primary_at_end),
             None,
         );
     }
@@ -1420,12 +1460,12 @@ fn build_view_line_mapping(
             .rev()
             .find_map(|m| *m)
             .unwrap_or(prev_line_end_byte)
-    } else if let Some((char_idx, &Some(last_byte_start))) = view_line
+    } else if let Some((char_idx, last_byte_start)) = view_line
         .char_source_bytes
         .iter()
         .enumerate()
-        .rev()
-        .find(|(_, m)| m.is_some())
+        .filter_map(|(i, m)| m.map(|b| (i, b)))
+        .max_by_key(|&(_, b)| b)
     {
         // Position AFTER the last character - find last source byte and add char length
         if let Some(last_char) = view_line.text.chars().nth(char_idx) {
@@ -4,6 +4,7 @@
 //! no dependency on any shared render-time "mega struct".
 
 use super::spans::compress_chars;
+use super::style::dim_color_for_tilde;
 use crate::app::types::ViewLineMapping;
 use crate::primitives::ansi_background::AnsiBackground;
 use crate::view::overlay::Overlay;
@@ -94,6 +95,38 @@ pub(super) fn render_ruler_bg(
     }
 }
 
+/// Dim the text of every row whose source bytes all fall outside `focus`
+/// (page-view focus mode). Rows without source bytes — virtual lines and
+/// blank separators — are dimmed too; the gutter is left alone.
+pub(super) fn dim_rows_outside_range(
+    buf: &mut ratatui::buffer::Buffer,
+    view_line_mappings: &[ViewLineMapping],
+    focus: &Range<usize>,
+    render_area: Rect,
+    gutter_width: usize,
+) {
+    let start_x = render_area.x + gutter_width as u16;
+    let end_x = render_area.x + render_area.width;
+    for (screen_row, mapping) in view_line_mappings.iter().enumerate() {
+        let y = render_area.y + screen_row as u16;
+        if y >= render_area.y + render_area.height {
+            break;
+        }
+        let in_focus = mapping
+            .char_source_bytes
+            .iter()
+            .flatten()
+            .any(|byte| focus.contains(byte));
+        if in_focus {
+            continue;
+        }
+        for x in start_x..end_x {
+            let cell = &mut buf[(x, y)];
+            cell.set_fg(dim_color_for_tilde(cell.fg));
+        }
+    }
+}
+
 /// Post-process the rendered frame to apply OSC 8 hyperlink escape sequences
 /// for any overlays that have a URL set.
 ///
@@ -15,7 +15,7 @@ use crate::view::theme::Theme;
 use crate::view::ui::view_pipeline::ViewLine;
 use crate::view::virtual_text::{VirtualTextNamespace, VirtualTextPosition};
 use fresh_core::api::{ViewTokenStyle, ViewTokenWire, ViewTokenWireKind};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Wrap tokens to fit within `content_width` columns (accounting for a
 /// leading gutter on the first visual line). Emits `Break` tokens where
@@ -1126,9 +1126,9 @@ struct InlineHintCell {
     style: Option<ViewTokenStyle>,
 }
 
-/// Splice inline virtual text (`BeforeChar` / `AfterChar` inlay hints) into
-/// the token stream as styled `source_offset: None` Text cells, **before**
-/// wrapping.
+/// Splice inline virtual text (`BeforeChar` / `AfterChar` inlay hints and
+/// `EndOfLine` notes) into the token stream as styled `source_offset: None`
+/// Text cells, **before** wrapping.
 ///
 /// This is the heart of the canonical layout model: by turning hints into
 /// real cells up front, their display width participates in line wrapping,
@@ -1144,6 +1144,9 @@ struct InlineHintCell {
 ///   - `BeforeChar`: `"{text} "`, or `" {text} "` when anchored on a
 ///     newline (an end-of-line hint).
 ///   - `AfterChar`:  `" {text}"`.
+///   - `EndOfLine`:  `" {text}"`, just before the anchor line's newline (or
+///     at the end of the stream for a last line without one, when the
+///     stream reaches the end of the buffer).
 ///
 /// `theme` is `Some` on the draw path (so hint colours resolve) and `None`
 /// on the wrap-cache / scroll-math path, where only cell *width* matters and
@@ -1168,6 +1171,9 @@ pub fn splice_inline_virtual_text(
     // walking the token stream below.
     let mut before: HashMap<usize, Vec<(String, Option<ViewTokenStyle>)>> = HashMap::new();
     let mut after: HashMap<usize, Vec<InlineHintCell>> = HashMap::new();
+    // End-of-line texts in anchor order; each newline drains the ones
+    // anchored at or before it.
+    let mut eol: VecDeque<(usize, InlineHintCell)> = VecDeque::new();
     for (pos, vtext) in inline {
         let style = theme.map(|t| token_style_from_ratatui(vtext.resolved_style(t)));
         match vtext.position {
@@ -1183,6 +1189,15 @@ pub fn splice_inline_virtual_text(
                     style,
                 });
             }
+            VirtualTextPosition::EndOfLine => {
+                eol.push_back((
+                    pos,
+                    InlineHintCell {
+                        text: format!(" {}", vtext.text),
+                        style,
+                    },
+                ));
+            }
             // Line-level positions are handled by `inject_virtual_lines`.
             _ => {}
         }
@@ -1257,6 +1272,12 @@ pub fn splice_inline_virtual_text(
                         out.push(virt(padded, style.clone()));
                     }
                 }
+                if anchor_is_newline {
+                    while eol.front().is_some_and(|(pos, _)| *pos <= anchor) {
+                        let (_, hint) = eol.pop_front().expect("front checked above");
+                        out.push(virt(hint.text, hint.style));
+                    }
+                }
                 let after_hints = after.get(&anchor);
                 out.push(token);
                 if let Some(hints) = after_hints {
@@ -1271,5 +1292,13 @@ pub fn splice_inline_virtual_text(
         }
     }
 
+    // Whatever is left is anchored on a line whose newline lies past the
+    // stream. Only the buffer's last line (which has none) ends here.
+    if end >= state.buffer.len() {
+        for (_, hint) in eol {
+            out.push(virt(hint.text, hint.style));
+        }
+    }
+
     out
 }
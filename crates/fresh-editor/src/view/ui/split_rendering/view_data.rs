@@ -238,7 +238,7 @@ pub(super) fn build_view_data(
         &tokens,
         is_binary,
         ansi_aware,
-        state.buffer_settings.tab_size,
+        state.buffer_settings.tab_display_width(),
         at_buffer_end,
     )
     .with_fold_skip(&fold_skip)
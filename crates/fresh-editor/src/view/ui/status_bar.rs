@@ -309,6 +309,9 @@ pub struct StatusBarContext<'a> {
     /// `{trust}` indicator (read from the active authority each frame, so it
     /// never goes stale or vanishes — unlike a per-buffer plugin token).
     pub workspace_trust_level: crate::services::workspace_trust::TrustLevel,
+    /// Render the clock as a fixed, non-blinking `00:00` so snapshots of the
+    /// bar do not depend on the time of day.
+    pub deterministic: bool,
 }
 
 /// Layout information returned from status bar rendering for mouse click detection
@@ -1245,6 +1248,12 @@ impl StatusBarRenderer {
                     token_key: None,
                 })
             }
+            StatusBarElement::Clock if ctx.deterministic => Some(RenderedElement {
+                text: "00:00".to_string(),
+                // A plain element: same style as the clock, minus the blink
+                kind: ElementKind::Normal,
+                token_key: None,
+            }),
             StatusBarElement::Clock => {
                 let now = chrono::Local::now();
                 let text = format!("{:02}:{:02}", now.hour(), now.minute());
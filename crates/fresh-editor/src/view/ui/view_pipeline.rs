@@ -21,6 +21,7 @@
 //! not reconstructed from flattened text.
 
 use crate::primitives::ansi::AnsiParser;
+use crate::primitives::bidi;
 use crate::primitives::display_width::str_width;
 use fresh_core::api::{ViewTokenStyle, ViewTokenWire, ViewTokenWireKind};
 use std::collections::HashSet;
@@ -514,9 +515,73 @@ impl LineAccumulator {
         }
     }
 
+    /// Rearrange the accumulated characters into bidi display order
+    /// (see [`crate::primitives::bidi`]). Each character keeps its source
+    /// byte and style; only its position and visual column change. A
+    /// grapheme cluster or tab expansion (a character followed by its
+    /// zero-width continuation characters) moves as one unit.
+    fn reorder_for_bidi(&mut self) {
+        // ANSI escape sequences must stay where the parser expects them.
+        if self.text.contains('\x1b') {
+            return;
+        }
+        let Some(order) = bidi::visual_char_order(&self.text) else {
+            return;
+        };
+        let len = self.char_source_bytes.len();
+        if order.len() != len {
+            return;
+        }
+
+        // Group each character with the zero-width characters after it.
+        let mut widths = vec![0usize; len];
+        for &char_idx in &self.visual_to_char {
+            widths[char_idx] += 1;
+        }
+        let mut cluster_of = vec![0usize; len];
+        let mut clusters: Vec<Range<usize>> = Vec::new();
+        for (i, &width) in widths.iter().enumerate() {
+            match clusters.last_mut() {
+                Some(cluster) if width == 0 => cluster.end = i + 1,
+                _ => clusters.push(i..i + 1),
+            }
+            cluster_of[i] = clusters.len() - 1;
+        }
+
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut placed = vec![false; clusters.len()];
+        let mut reordered = LineAccumulator::new();
+        for logical in order {
+            let cluster = cluster_of[logical];
+            if std::mem::replace(&mut placed[cluster], true) {
+                continue;
+            }
+            let range = clusters[cluster].clone();
+            let start_col = self.char_visual_cols[range.start];
+            for i in range {
+                let new_idx = reordered.char_source_bytes.len();
+                reordered.text.push(chars[i]);
+                reordered.char_source_bytes.push(self.char_source_bytes[i]);
+                reordered.char_styles.push(self.char_styles[i].clone());
+                reordered
+                    .char_visual_cols
+                    .push(reordered.col + self.char_visual_cols[i] - start_col);
+                if self.tab_starts.contains(&i) {
+                    reordered.tab_starts.insert(new_idx);
+                }
+                for _ in 0..widths[i] {
+                    reordered.visual_to_char.push(new_idx);
+                }
+            }
+            reordered.col = reordered.visual_to_char.len();
+        }
+        *self = reordered;
+    }
+
     /// Finalize into a [`ViewLine`] with the given line metadata.
-    fn into_view_line(self, line_start: LineStart, ends_with_newline: bool) -> ViewLine {
+    fn into_view_line(mut self, line_start: LineStart, ends_with_newline: bool) -> ViewLine {
         let source_start_byte = self.char_source_bytes.iter().find_map(|s| *s);
+        self.reorder_for_bidi();
         ViewLine {
             text: self.text,
             source_start_byte,
@@ -892,6 +957,41 @@ mod tests {
         assert!(should_show_line_number(&lines[1]));
     }
 
+    #[test]
+    fn test_mixed_ltr_rtl_line_is_reordered_for_display() {
+        // "say שלום" followed by a newline; each Hebrew letter is 2 bytes
+        let tokens = vec![
+            make_text_token("say שלום", Some(0)),
+            make_newline_token(Some(12)),
+        ];
+
+        let lines: Vec<_> = ViewLineIterator::new(&tokens, false, false, 4, false).collect();
+
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        // The Hebrew word is shown right-to-left; the newline stays last
+        assert_eq!(line.text, "say םולש\n");
+        assert_eq!(line.source_start_byte, Some(0));
+        // Every displayed char still maps to its logical source byte
+        assert_eq!(
+            line.char_source_bytes,
+            vec![
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(10),
+                Some(8),
+                Some(6),
+                Some(4),
+                Some(12)
+            ]
+        );
+        assert_eq!(line.char_visual_cols, (0..9).collect::<Vec<_>>());
+        // Clicking the leftmost Hebrew cell lands on the word's last letter
+        assert_eq!(line.source_byte_at_visual_col(4), Some(10));
+    }
+
     #[test]
     fn test_wrapped_continuation() {
         let tokens = vec![
@@ -615,12 +615,23 @@ impl Viewport {
     /// is passed to the visual-row scroll.
     pub fn center_on_position(&mut self, buffer: &mut Buffer, position: usize) {
         let half = self.visible_line_count() / 2;
+        self.place_position_at_row(buffer, position, half);
+    }
+
+    /// Scroll so the visual row containing `position` is the top row of
+    /// the viewport. Same wrap handling as [`Self::center_on_position`].
+    pub fn top_on_position(&mut self, buffer: &mut Buffer, position: usize) {
+        self.place_position_at_row(buffer, position, 0);
+    }
 
+    /// Scroll so the visual row containing `position` sits `rows_above`
+    /// rows below the top of the viewport.
+    fn place_position_at_row(&mut self, buffer: &mut Buffer, position: usize, rows_above: usize) {
         if !self.line_wrap_enabled {
             // Unwrapped: one visual row per logical line, so walk back
-            // `half` logical lines from the target.
+            // `rows_above` logical lines from the target.
             let mut iter = buffer.line_iterator(position, 80);
-            for _ in 0..half {
+            for _ in 0..rows_above {
                 if iter.prev().is_none() {
                     break;
                 }
@@ -632,7 +643,7 @@ impl Viewport {
 
         // Wrapped: find which visual row inside its logical line the
         // target sits on, anchor the viewport top to that row, then
-        // scroll up `half` real visual rows (which walks back through any
+        // scroll up `rows_above` real visual rows (which walks back through any
         // wrapped lines above).
         let line = buffer.get_line_number(position);
         let line_start = buffer.line_start_offset(line).unwrap_or(position);
@@ -661,7 +672,7 @@ impl Viewport {
 
         self.top_byte = line_start;
         self.top_view_line_offset = match_row_in_line;
-        self.scroll_up(buffer, &[], &[], half);
+        self.scroll_up(buffer, &[], &[], rows_above);
     }
 
     /// Scroll down by N lines (byte-based)
@@ -2631,6 +2642,22 @@ mod tests {
         assert_eq!(vp.top_view_line_offset, 0);
     }
 
+    #[test]
+    fn top_on_position_unwrapped_puts_line_at_top() {
+        let mut content = String::new();
+        for i in 0..50 {
+            content.push_str(&format!("line{i}\n"));
+        }
+        let mut buffer = Buffer::from_str_test(&content);
+        let mut vp = Viewport::new(80, 24);
+
+        let pos = buffer.line_start_offset(29).unwrap() + 2;
+        vp.top_on_position(&mut buffer, pos);
+
+        assert_eq!(buffer.get_line_number(vp.top_byte), 29);
+        assert_eq!(vp.top_view_line_offset, 0);
+    }
+
     #[test]
     fn center_on_position_wrapped_counts_visual_rows() {
         // A long line that wraps into many visual rows sits directly above
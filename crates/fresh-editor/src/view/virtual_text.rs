@@ -5,8 +5,14 @@
 //!
 //! Two types of virtual text are supported:
 //! - **Inline**: Text inserted before/after a character (e.g., `: i32` type hints)
+//!   or at the end of the character's line (e.g., a short note after the code)
 //! - **Line**: Full lines inserted above/below a position (e.g., git blame headers)
 //!
+//! LSP inlay hints (inline) and the git blame plugin (line) are built on this
+//! layer. Inline diagnostics are not: they are right-aligned and truncated to
+//! the space left on the row, which depends on the wrapped layout, so they
+//! are still drawn after wrapping by the line renderer.
+//!
 //! Virtual text is rendered during the render phase by reading from VirtualTextManager.
 //! The buffer content remains unchanged - we just inject extra styled text during rendering.
 //!
@@ -32,6 +38,11 @@ pub enum VirtualTextPosition {
     BeforeChar,
     /// Render after the character (e.g., type hints: `x: i32`)
     AfterChar,
+    /// Render after the last character of the line containing this
+    /// position, separated by a space (e.g., `let x = 1; // note`).
+    /// The anchor can be anywhere on the line, so the text stays at the
+    /// end as the line is edited.
+    EndOfLine,
 
     // ─── Line positions (full lines) ───
     /// Render as a full line ABOVE the line containing this position
@@ -50,9 +61,9 @@ impl VirtualTextPosition {
         matches!(self, Self::LineAbove | Self::LineBelow)
     }
 
-    /// Returns true if this is an inline position (BeforeChar/AfterChar)
+    /// Returns true if this is an inline position (BeforeChar/AfterChar/EndOfLine)
     pub fn is_inline(&self) -> bool {
-        matches!(self, Self::BeforeChar | Self::AfterChar)
+        matches!(self, Self::BeforeChar | Self::AfterChar | Self::EndOfLine)
     }
 }
 
@@ -770,7 +781,7 @@ impl VirtualTextManager {
         results
     }
 
-    /// Query only INLINE virtual texts (BeforeChar/AfterChar) in a byte range
+    /// Query only INLINE virtual texts (BeforeChar/AfterChar/EndOfLine) in a byte range
     ///
     /// Used by the render pipeline to inject inline hints.
     pub fn query_inline_in_range(
@@ -1633,7 +1633,7 @@ fn tick_only(editor: &mut Editor) -> bool {
 /// scene for the frontend/tests).
 fn poll_active(editor: &Editor) -> bool {
     editor.active_window().animations.is_active()
-        || editor.active_window().has_active_lsp_progress()
+        || editor.active_window().has_lsp_activity()
         || editor.next_periodic_redraw_deadline().is_some()
 }
 
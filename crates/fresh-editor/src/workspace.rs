@@ -70,10 +70,16 @@ pub struct Workspace {
     #[serde(default)]
     pub search_options: SearchOptions,
 
-    /// Bookmarks (character key -> file position)
+    /// Bookmarks (character key -> file position): global marks, plus
+    /// local marks saved by older versions
     #[serde(default)]
     pub bookmarks: HashMap<char, SerializedBookmark>,
 
+    /// Local (lowercase) marks. Every file has its own set, so the same
+    /// key can appear once per file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub local_bookmarks: Vec<(char, SerializedBookmark)>,
+
     /// Open terminal workspaces (for restoration)
     #[serde(default)]
     pub terminals: Vec<SerializedTerminalWorkspace>,
@@ -428,8 +434,12 @@ pub struct WorkspaceHistories {
     pub search: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub replace: Vec<String>,
+    /// Command palette usage, most recent first
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub command_palette: Vec<String>,
+    /// How many times each command in `command_palette` was used
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub command_usage: HashMap<String, u32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub goto_line: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -449,13 +459,21 @@ pub struct SearchOptions {
     pub confirm_each: bool,
 }
 
-/// Serialized bookmark (file path + byte offset)
+/// Serialized bookmark (file path + byte offset, plus a content anchor)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SerializedBookmark {
     /// File path (relative to working_dir)
     pub file_path: PathBuf,
     /// Byte offset position in the file
     pub position: usize,
+    /// Hash of the bookmarked line's trimmed text. On restore, if the line
+    /// at `position` no longer matches, the bookmark moves to the nearest
+    /// line that does. Absent for blank lines and older workspaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_hash: Option<String>,
+    /// Byte column of the bookmark within its line
+    #[serde(default)]
+    pub column: usize,
 }
 
 /// Reference to an open tab (file path, terminal index, or unnamed buffer)
@@ -1317,6 +1335,7 @@ impl Workspace {
             histories: WorkspaceHistories::default(),
             search_options: SearchOptions::default(),
             bookmarks: HashMap::new(),
+            local_bookmarks: Vec::new(),
             terminals: Vec::new(),
             external_files: Vec::new(),
             read_only_files: Vec::new(),
@@ -1492,7 +1511,12 @@ mod tests {
             line_wrap: None,
             virtual_space: None,
             plugin_state: HashMap::new(),
-            folds: Vec::new(),
+            folds: vec![SerializedFoldRange {
+                header_line: 3,
+                end_line: 8,
+                placeholder: None,
+                header_text: Some("fn main() {".to_string()),
+            }],
         };
 
         let json = serde_json::to_string(&file_state).unwrap();
@@ -1504,6 +1528,13 @@ mod tests {
         assert_eq!(restored.additional_cursors.len(), 1);
         assert_eq!(restored.scroll.top_byte, 500);
         assert_eq!(restored.scroll.left_column, 10);
+        assert_eq!(restored.folds.len(), 1);
+        assert_eq!(restored.folds[0].header_line, 3);
+        assert_eq!(restored.folds[0].end_line, 8);
+        assert_eq!(
+            restored.folds[0].header_text.as_deref(),
+            Some("fn main() {")
+        );
     }
 
     #[test]
@@ -1514,6 +1545,8 @@ mod tests {
             SerializedBookmark {
                 file_path: PathBuf::from("src/main.rs"),
                 position: 1234,
+                line_hash: None,
+                column: 0,
             },
         );
         bookmarks.insert(
@@ -1521,6 +1554,8 @@ mod tests {
             SerializedBookmark {
                 file_path: PathBuf::from("src/lib.rs"),
                 position: 5678,
+                line_hash: None,
+                column: 0,
             },
         );
 
@@ -1603,6 +1638,8 @@ mod tests {
             SerializedBookmark {
                 file_path: PathBuf::from("src/main.rs"),
                 position: 100,
+                line_hash: None,
+                column: 0,
             },
         );
 
@@ -1647,6 +1684,8 @@ mod tests {
             SerializedBookmark {
                 file_path: PathBuf::from("test.txt"),
                 position: 42,
+                line_hash: None,
+                column: 0,
             },
         );
 
@@ -626,8 +626,6 @@ done
         dir: &std::path::Path,
         diagnostic_count: usize,
     ) -> anyhow::Result<Self> {
-        let (stop_tx, stop_rx) = mpsc::channel();
-
         // Generate JSON for many diagnostics
         let mut diagnostics_json = String::from("[");
         for i in 0..diagnostic_count {
@@ -645,7 +643,21 @@ done
         }
         diagnostics_json.push(']');
 
-        // Create a Bash script that sends many diagnostics on didChange
+        Self::spawn_publishing_diagnostics(dir, &diagnostics_json)
+    }
+
+    /// Spawn a fake LSP server that publishes a fixed diagnostics array
+    ///
+    /// `diagnostics_json` is the raw JSON array sent as the `diagnostics`
+    /// param of `textDocument/publishDiagnostics` on every didOpen/didChange.
+    /// Shares its script path with `spawn_many_diagnostics`.
+    pub fn spawn_publishing_diagnostics(
+        dir: &std::path::Path,
+        diagnostics_json: &str,
+    ) -> anyhow::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel();
+
+        // Create a Bash script that sends the diagnostics on didChange
         let script = format!(
             r#"#!/bin/bash
 
@@ -1704,6 +1704,22 @@ impl EditorTestHarness {
         result
     }
 
+    /// Render a stable text snapshot of the screen for golden-file tests.
+    ///
+    /// Switches the editor to deterministic rendering first (fixed clock,
+    /// no blinking colon, frozen LSP spinner), so the same editor state
+    /// always produces the same snapshot. Rows are read with
+    /// `screen_row_text`, so hyperlinks and trailing blanks are stripped.
+    pub fn render_snapshot(&mut self) -> anyhow::Result<String> {
+        self.editor.set_deterministic_render(true);
+        self.render()?;
+        let height = self.buffer().area.height;
+        Ok((0..height)
+            .map(|row| self.screen_row_text(row))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
     /// Read a screen row as clean text, stripping OSC 8 hyperlink sequences.
     ///
     /// This reads cells directly from the ratatui buffer and strips any
@@ -2376,7 +2392,8 @@ impl EditorTestHarness {
     /// Find all visible cursors on screen
     /// Returns a vec of (x, y, character_at_cursor, is_primary)
     /// Primary cursor is detected at hardware cursor position
-    /// Secondary cursors are detected by REVERSED style modifier or inactive cursor background
+    /// Secondary cursors are detected by REVERSED style modifier, secondary cursor
+    /// background or inactive cursor background
     pub fn find_all_cursors(&mut self) -> Vec<(u16, u16, String, bool)> {
         use ratatui::style::Modifier;
         let mut cursors = Vec::new();
@@ -2386,6 +2403,7 @@ impl EditorTestHarness {
 
         // Get the buffer to read cell content
         let theme_inactive_cursor = self.editor.theme().inactive_cursor;
+        let theme_secondary_cursor = self.editor.theme().secondary_cursor;
         let buffer = self.terminal.backend().buffer();
         let content_start = layout::CONTENT_START_ROW as u16;
         let content_end = buffer
@@ -2403,9 +2421,11 @@ impl EditorTestHarness {
             }
         }
 
-        // Inactive cursor bg is taken from the active theme; any cell matching
-        // that bg in the content area is treated as a secondary cursor.
+        // Secondary and inactive cursor bgs are taken from the active theme;
+        // any cell matching either bg in the content area is treated as a
+        // secondary cursor.
         let inactive_cursor_bg = theme_inactive_cursor;
+        let secondary_cursor_bg = theme_secondary_cursor;
 
         for y in content_start..content_end {
             for x in 0..buffer.area.width {
@@ -2423,8 +2443,9 @@ impl EditorTestHarness {
                 let pos = buffer.index_of(x, y);
                 if let Some(cell) = buffer.content.get(pos) {
                     let is_reversed = cell.modifier.contains(Modifier::REVERSED);
-                    let has_inactive_cursor_bg = cell.bg == inactive_cursor_bg;
-                    if is_reversed || has_inactive_cursor_bg {
+                    let has_cursor_bg =
+                        cell.bg == inactive_cursor_bg || cell.bg == secondary_cursor_bg;
+                    if is_reversed || has_cursor_bg {
                         cursors.push((x, y, cell.symbol().to_string(), false));
                     }
                 }
@@ -2547,6 +2568,8 @@ impl EditorTestHarness {
         let _ = self.editor.process_async_messages();
         // Check debounced completion trigger timer (quick suggestions)
         self.editor.check_completion_trigger_timer();
+        // Apply diagnostic overlays held back by the edit debounce
+        self.editor.check_diagnostic_overlay_timer();
         self.render()?;
         Ok(())
     }
@@ -0,0 +1,60 @@
+//! E2E tests for bookmarks: local (lowercase) and global (uppercase) marks
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+/// An uppercase mark set in one file is reachable from another file, and
+/// reopens its file once the buffer has been closed. A lowercase mark only
+/// exists in the buffer it was set in.
+#[test]
+fn test_global_mark_jumps_across_files() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let first = project_dir.join("first.txt");
+    let second = project_dir.join("second.txt");
+    std::fs::write(&first, "alpha\nbeta\ngamma\ndelta\n").unwrap();
+    std::fs::write(&second, "one\ntwo\n").unwrap();
+
+    // Mark "gamma" in first.txt both locally and globally
+    harness.open_file(&first).unwrap();
+    let first_id = harness.editor().active_buffer();
+    for _ in 0..2 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetBookmark('a'));
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetBookmark('A'));
+
+    harness.open_file(&second).unwrap();
+    harness.render().unwrap();
+
+    // The local mark belongs to first.txt
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::JumpToBookmark('a'));
+    harness.render().unwrap();
+    harness.assert_screen_contains("Bookmark 'a' not set");
+    harness.assert_screen_contains("one");
+
+    // The global mark switches to first.txt
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::JumpToBookmark('A'));
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_buffer(), first_id);
+    harness.assert_screen_contains("Ln 3, Col 1");
+
+    // Close first.txt; the global mark reopens it
+    harness.editor_mut().close_buffer(first_id).unwrap();
+    harness.open_file(&second).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::JumpToBookmark('A'));
+    harness.render().unwrap();
+    harness.assert_screen_contains("gamma");
+    harness.assert_screen_contains("Ln 3, Col 1");
+}
@@ -0,0 +1,60 @@
+//! Ctrl+Tab MRU buffer switcher: tabs are listed most recently focused
+//! first and the previously focused one is preselected.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, ModifierKeyCode};
+use fresh::input::keybindings::Action;
+
+fn active_file_name(harness: &EditorTestHarness) -> String {
+    harness
+        .editor()
+        .active_state()
+        .buffer
+        .file_path()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_buffer_switcher_cycles_most_recently_used_first() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    for name in ["a.txt", "b.txt", "c.txt"] {
+        std::fs::write(project_dir.join(name), format!("{name}\n")).unwrap();
+        harness.open_file(&project_dir.join(name)).unwrap();
+    }
+    // Back to a.txt: focus order is now a, c, b while tab order is a, b, c
+    harness.open_file(&project_dir.join("a.txt")).unwrap();
+    assert_eq!(active_file_name(&harness), "a.txt");
+
+    // A single tap lands on the previously focused buffer, not the next tab
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::BufferSwitcher);
+    harness.render().unwrap();
+    harness.assert_screen_contains("Recently Used Buffers");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(active_file_name(&harness), "c.txt");
+
+    // Holding Ctrl and tapping Tab again moves one further down the list
+    // (c, a, b); releasing Ctrl switches.
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Tab, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(active_file_name(&harness), "c.txt");
+    let released = harness
+        .editor_mut()
+        .handle_key_release(KeyEvent::new_with_kind(
+            KeyCode::Modifier(ModifierKeyCode::LeftControl),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        ));
+    assert!(released);
+    assert_eq!(active_file_name(&harness), "b.txt");
+}
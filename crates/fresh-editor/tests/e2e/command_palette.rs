@@ -1917,3 +1917,38 @@ fn test_command_palette_description_search() {
     harness.assert_screen_contains("Toggle Page View");
     harness.assert_screen_contains("Set Page Width");
 }
+
+/// Run `name` from the command palette.
+fn run_palette_command(harness: &mut EditorTestHarness, name: &str) {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// "Repeat Last Command" re-runs the command the palette last executed,
+/// and says so when there is nothing to repeat yet.
+#[test]
+fn test_command_palette_repeat_last_command() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.render().unwrap();
+
+    run_palette_command(&mut harness, "Repeat Last Command");
+    harness.assert_screen_contains("No command has been run yet");
+    harness.assert_screen_contains("1 │");
+
+    run_palette_command(&mut harness, "Toggle Line Numbers");
+    harness.assert_screen_not_contains("1 │");
+
+    // Repeating toggles them back, and repeating again toggles them off:
+    // the repeat itself never becomes the last command.
+    run_palette_command(&mut harness, "Repeat Last Command");
+    harness.assert_screen_contains("1 │");
+    run_palette_command(&mut harness, "Repeat Last Command");
+    harness.assert_screen_not_contains("1 │");
+}
@@ -0,0 +1,260 @@
+//! E2E tests for comparing buffers side by side
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+/// Open `left.txt` and `right.txt` from the harness project with the given
+/// contents.
+fn open_pair(harness: &mut EditorTestHarness, left: &str, right: &str) {
+    let project_dir = harness.project_dir().unwrap();
+    let left_path = project_dir.join("left.txt");
+    let right_path = project_dir.join("right.txt");
+    std::fs::write(&left_path, left).unwrap();
+    std::fs::write(&right_path, right).unwrap();
+    harness.open_file(&left_path).unwrap();
+    harness.open_file(&right_path).unwrap();
+}
+
+/// Pick `name` in the active compare prompt.
+fn pick(harness: &mut EditorTestHarness, name: &str) {
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// The screen row showing `text`.
+fn row_with(screen: &str, text: &str) -> String {
+    screen
+        .lines()
+        .find(|line| line.contains(text))
+        .unwrap_or_else(|| panic!("No row with '{text}'. Screen:\n{screen}"))
+        .to_string()
+}
+
+/// Unchanged lines sit on the same row in both panes; a line only the
+/// right buffer has gets a blank row on the left.
+#[test]
+fn test_compare_buffers_aligns_unchanged_lines() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 24).unwrap();
+    open_pair(
+        &mut harness,
+        "alpha\nbeta\ngamma\n",
+        "alpha\ninserted\nbeta\ngamma\n",
+    );
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CompareBuffers);
+    pick(&mut harness, "left.txt");
+    pick(&mut harness, "right.txt");
+
+    let screen = harness.screen_to_string();
+    for unchanged in ["alpha", "beta", "gamma"] {
+        assert_eq!(
+            row_with(&screen, unchanged).matches(unchanged).count(),
+            2,
+            "'{unchanged}' should appear in both panes on one row. Screen:\n{screen}"
+        );
+    }
+    let inserted = row_with(&screen, "inserted");
+    for unchanged in ["alpha", "beta", "gamma"] {
+        assert!(
+            !inserted.contains(unchanged),
+            "The inserted line should face padding. Screen:\n{screen}"
+        );
+    }
+    harness.assert_screen_contains("Differing regions: 1");
+}
+
+/// Comparing a buffer with itself leaves the editor where it was.
+#[test]
+fn test_compare_buffer_with_itself_is_a_no_op() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 24).unwrap();
+    open_pair(&mut harness, "same\n", "other\n");
+    let before = harness.editor().active_buffer();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CompareBuffers);
+    pick(&mut harness, "left.txt");
+    pick(&mut harness, "left.txt");
+
+    assert_eq!(harness.editor().active_buffer(), before);
+    harness.assert_screen_contains("A buffer is identical to itself");
+}
+
+/// Editing a line and diffing against disk shows the saved line on the
+/// left facing the edited one on the right.
+#[test]
+fn test_diff_against_disk_shows_unsaved_edit() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 24).unwrap();
+    let path = harness.project_dir().unwrap().join("file.txt");
+    std::fs::write(&path, "first\nsecond\nthird\n").unwrap();
+    harness.open_file(&path).unwrap();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" edited").unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DiffAgainstDisk);
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let edited = row_with(&screen, "second edited");
+    assert_eq!(
+        edited.matches("second").count(),
+        2,
+        "The saved line should face the edited one. Screen:\n{screen}"
+    );
+    for unchanged in ["first", "third"] {
+        assert_eq!(
+            row_with(&screen, unchanged).matches(unchanged).count(),
+            2,
+            "'{unchanged}' should appear in both panes on one row. Screen:\n{screen}"
+        );
+    }
+    harness.assert_screen_contains("Differing regions: 1");
+}
+
+/// A buffer whose file is missing on disk shows every line as added.
+#[test]
+fn test_diff_against_disk_for_deleted_file() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 24).unwrap();
+    let path = harness.project_dir().unwrap().join("gone.txt");
+    std::fs::write(&path, "kept\nlines\n").unwrap();
+    harness.open_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DiffAgainstDisk);
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    for line in ["kept", "lines"] {
+        assert_eq!(
+            row_with(&screen, line).matches(line).count(),
+            1,
+            "'{line}' should only appear in the buffer pane. Screen:\n{screen}"
+        );
+    }
+    harness.assert_screen_contains("Differing regions: 1");
+}
+
+/// Closing a diff against disk frees its hidden on-disk snapshot, so
+/// repeated diffs don't pile up buffers.
+#[test]
+fn test_diff_against_disk_frees_snapshot_on_close() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 24).unwrap();
+    let path = harness.project_dir().unwrap().join("file.txt");
+    std::fs::write(&path, "first\n").unwrap();
+    harness.open_file(&path).unwrap();
+    let buffers = harness.editor().buffer_count_for_tests();
+
+    for _ in 0..2 {
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::DiffAgainstDisk);
+        harness.render().unwrap();
+        assert_eq!(harness.editor().buffer_count_for_tests(), buffers + 1);
+
+        let composite = harness.editor().active_buffer();
+        harness.editor_mut().close_buffer(composite).unwrap();
+        harness.render().unwrap();
+        assert_eq!(harness.editor().buffer_count_for_tests(), buffers);
+        assert!(!harness
+            .editor()
+            .active_window()
+            .is_composite_buffer(composite));
+    }
+}
+
+/// `fresh --diff LEFT RIGHT` opens both files straight into a composite
+/// diff view.
+#[test]
+fn test_diff_files_opens_composite_for_two_files() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let left = project_dir.join("old.txt");
+    let right = project_dir.join("new.txt");
+    std::fs::write(&left, "same\nbefore\n").unwrap();
+    std::fs::write(&right, "same\nafter\n").unwrap();
+
+    harness.editor_mut().diff_files(&left, &right).unwrap();
+    harness.render().unwrap();
+
+    let active = harness.editor().active_buffer();
+    assert!(
+        harness.editor().active_window().is_composite_buffer(active),
+        "The diff should open as a composite buffer"
+    );
+    let screen = harness.screen_to_string();
+    assert_eq!(
+        row_with(&screen, "same").matches("same").count(),
+        2,
+        "The unchanged line should appear in both panes. Screen:\n{screen}"
+    );
+    harness.assert_screen_contains("before");
+    harness.assert_screen_contains("after");
+    harness.assert_screen_contains("Differing regions: 1");
+}
+
+/// A pane's own background replaces the theme's on its unchanged rows, so
+/// the two sides of a composite can carry different tints.
+#[test]
+fn test_composite_panes_use_their_background_override() {
+    use fresh::model::composite_buffer::{CompositeLayout, LineAlignment, PaneStyle, SourcePane};
+    use fresh::primitives::text_property::TextPropertyEntry;
+    use ratatui::style::Color;
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    let editor = harness.editor_mut();
+    let mut pane = |name: &str, text: &str, bg: (u8, u8, u8)| {
+        let id = editor.active_window_mut().create_virtual_buffer(
+            name.to_string(),
+            "text".to_string(),
+            true,
+        );
+        editor
+            .set_virtual_buffer_content(id, vec![TextPropertyEntry::text(text)])
+            .unwrap();
+        SourcePane::new(id, name, false).with_style(PaneStyle {
+            bg: Some(bg),
+            ..Default::default()
+        })
+    };
+    let sources = vec![
+        pane("LEFT", "one\nleftside\n", (60, 20, 20)),
+        pane("RIGHT", "one\nrightside\n", (20, 20, 60)),
+    ];
+    let layout = CompositeLayout::SideBySide {
+        ratios: vec![0.5, 0.5],
+        show_separator: true,
+    };
+    let composite = editor.create_composite_buffer(
+        "tinted".to_string(),
+        "diff-view".to_string(),
+        layout,
+        sources,
+    );
+    editor
+        .active_window_mut()
+        .set_composite_alignment(composite, LineAlignment::from_hunks(&[], 2, 2));
+    editor.switch_buffer(composite);
+    harness.render().unwrap();
+
+    // Row 0 carries the cursor-line highlight; compare the second row.
+    let bg_at = |text: &str| {
+        let (x, y) = harness
+            .find_text_on_screen(text)
+            .unwrap_or_else(|| panic!("'{text}' should be on screen"));
+        harness.get_cell_style(x, y).and_then(|s| s.bg)
+    };
+    assert_eq!(bg_at("leftside"), Some(Color::Rgb(60, 20, 20)));
+    assert_eq!(bg_at("rightside"), Some(Color::Rgb(20, 20, 60)));
+}
@@ -399,3 +399,39 @@ fn test_ctrl_g_cancels_mark_mode() {
         "deselect_on_move should be true (mark mode cancelled)"
     );
 }
+
+// =============================================================================
+// Which-key popup
+// =============================================================================
+
+/// Pausing after C-x lists the keys that can follow it; the next key closes
+/// the popup and still completes the chord.
+#[test]
+fn test_chord_prefix_shows_which_key_popup() {
+    let mut harness = emacs_harness(100, 30);
+    harness.type_text("abc").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Close split");
+
+    harness.advance_time(std::time::Duration::from_millis(600));
+    harness.tick_and_render().unwrap();
+    let screen = harness.screen_to_string();
+    for label in ["Save file", "Close split", "Next split"] {
+        assert!(
+            screen.contains(label),
+            "Popup should list '{label}'. Screen:\n{screen}"
+        );
+    }
+
+    // C-x o completes as next_split rather than inserting 'o'.
+    harness
+        .send_key(KeyCode::Char('o'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Close split");
+    assert_eq!(harness.get_buffer_content().unwrap(), "abc");
+}
@@ -696,6 +696,63 @@ fn test_multi_delete_prompt_names_the_items() {
         .unwrap();
 }
 
+/// Shift-selecting two files and pressing Delete removes both in one
+/// confirmation, leaving unselected siblings alone. With
+/// `file_explorer.delete_to_trash` off the prompt says the delete is
+/// permanent and the files are removed outright.
+#[test]
+fn test_batch_delete_removes_selected_files() {
+    let mut config = Config::default();
+    config.file_explorer.delete_to_trash = false;
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 30, config).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::write(project_root.join("apple.txt"), "a").unwrap();
+    fs::write(project_root.join("banana.txt"), "b").unwrap();
+    fs::write(project_root.join("cherry.txt"), "c").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("cherry.txt").unwrap();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap(); // apple
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap(); // extend to banana
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+
+    let screen = harness.screen_to_string();
+    let prompt_row = screen
+        .lines()
+        .rev()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("");
+    assert!(
+        prompt_row.contains("Permanently delete 2 items")
+            && prompt_row.contains("apple")
+            && prompt_row.contains("banana"),
+        "Batch delete prompt should list the affected files. Prompt:\n{}",
+        prompt_row
+    );
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    assert!(!project_root.join("apple.txt").exists());
+    assert!(!project_root.join("banana.txt").exists());
+    assert!(
+        project_root.join("cherry.txt").exists(),
+        "unselected sibling must survive the batch delete"
+    );
+}
+
 /// The conflict prompt advertises `(c)ancel` but used to accept *any*
 /// unrecognized input as a cancel — including typos. A typo loses the
 /// clipboard and the whole paste queue with no recovery. The prompt
@@ -4353,3 +4353,49 @@ fn test_file_explorer_git_decorations_nested_subrepo_under_repo_root() {
         "inner.py tree row should show a modified (M) decoration from its own nested repo. Line: '{inner_line}'"
     );
 }
+
+/// Dragging a file onto a directory row moves it there; dropping onto a
+/// file does nothing; Ctrl+Z in the explorer moves the last drag back.
+#[test]
+fn test_drag_file_onto_directory_moves_it() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::create_dir(project_root.join("archive")).unwrap();
+    fs::write(project_root.join("notes.txt"), "notes").unwrap();
+    fs::write(project_root.join("other.txt"), "other").unwrap();
+    fs::write(project_root.join("readme.md"), "readme").unwrap();
+
+    harness.editor_mut().toggle_file_explorer();
+    harness.wait_for_file_explorer_item("readme.md").unwrap();
+
+    // Dropping onto a file is a no-op.
+    let (other_row, other_col) = find_explorer_row_cell(&harness, "other.txt");
+    let (readme_row, readme_col) = find_explorer_row_cell(&harness, "readme.md");
+    harness
+        .mouse_drag(other_col, other_row, readme_col, readme_row)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(project_root.join("other.txt").exists());
+
+    // Dropping onto a directory moves the file into it.
+    let (notes_row, notes_col) = find_explorer_row_cell(&harness, "notes.txt");
+    let (archive_row, archive_col) = find_explorer_row_cell(&harness, "archive");
+    harness
+        .mouse_drag(notes_col, notes_row, archive_col, archive_row)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Moved notes.txt to archive"))
+        .unwrap();
+    assert!(project_root.join("archive/notes.txt").exists());
+    assert!(!project_root.join("notes.txt").exists());
+
+    // Undo puts it back.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Moved notes.txt back"))
+        .unwrap();
+    assert!(project_root.join("notes.txt").exists());
+    assert!(!project_root.join("archive/notes.txt").exists());
+}
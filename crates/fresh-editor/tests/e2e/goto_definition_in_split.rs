@@ -0,0 +1,61 @@
+//! `GotoDefinitionInSplit` jumps to the definition in another split, so the
+//! place the jump started from stays on screen.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+/// Without a language server the definition comes from the tree-sitter
+/// locals query (Go is one of the bundled grammars).
+#[test]
+#[cfg(feature = "tree-sitter")]
+fn test_goto_definition_in_split_opens_target_in_new_split() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let source = "package main\n\nfunc main() {\n\ttotal := 1\n\tprintln(total)\n}\n";
+    let file = project_dir.join("main.go");
+    std::fs::write(&file, source).unwrap();
+    harness.open_file(&file).unwrap();
+
+    // Cursor onto `total` in `println(total)`
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    let use_position = harness.cursor_position();
+    let original_split = harness.editor().get_active_split();
+    assert_eq!(harness.editor().get_split_count(), 1);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoDefinitionInSplit);
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().get_split_count(), 2);
+    assert_ne!(harness.editor().get_active_split(), original_split);
+    assert_eq!(
+        harness
+            .editor()
+            .active_state()
+            .buffer
+            .file_path()
+            .and_then(|p| p.file_name()),
+        file.file_name()
+    );
+    assert_eq!(harness.cursor_position(), source.find("total").unwrap());
+
+    // The split the jump started from still has its cursor on the use
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::NextSplit);
+    assert_eq!(harness.editor().get_active_split(), original_split);
+    assert_eq!(harness.cursor_position(), use_position);
+
+    // A second jump reuses the existing split instead of adding another
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoDefinitionInSplit);
+    assert_eq!(harness.editor().get_split_count(), 2);
+}
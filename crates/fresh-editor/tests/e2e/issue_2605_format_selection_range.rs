@@ -15,6 +15,10 @@
 //! VS Code's "Format Selection". Without a selection, the existing whole-file
 //! behavior is preserved.
 //!
+//! The dedicated Format Selection command (`format_selection`) shares the same
+//! range-formatting path and falls back to whole-buffer formatting when there
+//! is no selection.
+//!
 //! These tests use a bash fake LSP, so they are skipped on Windows.
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
@@ -300,3 +304,81 @@ fn test_no_selection_uses_whole_file_external_formatter() -> anyhow::Result<()>
 
     Ok(())
 }
+
+fn run_format_selection(harness: &mut EditorTestHarness) -> anyhow::Result<()> {
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.render()?;
+    harness.type_text("Format Selection")?;
+    harness.wait_for_screen_contains("Format Selection")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+    Ok(())
+}
+
+/// The dedicated Format Selection command range-formats the selection, and the
+/// server's edits land as a single undo step.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_format_selection_command_formats_only_selection() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("range_log_cmd.txt");
+    let (mut harness, _f) = setup(&temp_dir, &log_file, /*with_external_formatter=*/ true)?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::SHIFT)?;
+    harness.render()?;
+    assert!(
+        harness.has_selection(),
+        "precondition: a selection should be active"
+    );
+
+    run_format_selection(&mut harness)?;
+
+    harness.wait_until(|h| {
+        h.get_buffer_content().as_deref() == Some("local a = 1\nlocal b=2\nlocal c=3\n")
+    })?;
+
+    let log = std::fs::read_to_string(&log_file).unwrap_or_default();
+    assert!(
+        log.contains("METHOD:textDocument/rangeFormatting"),
+        "Format Selection must send a range-formatting request; log:\n{log}"
+    );
+    assert!(
+        !log.contains("METHOD:textDocument/formatting"),
+        "Format Selection must not request whole-document formatting; log:\n{log}"
+    );
+
+    // One undo reverts the whole formatting change.
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.render()?;
+    assert_eq!(
+        harness.get_buffer_content().as_deref(),
+        Some("local a=1\nlocal b=2\nlocal c=3\n")
+    );
+
+    Ok(())
+}
+
+/// Without a selection there is no range to format, so Format Selection falls
+/// back to formatting the whole buffer.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_format_selection_without_selection_formats_whole_buffer() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("range_log_cmd_nosel.txt");
+    let (mut harness, _f) = setup(&temp_dir, &log_file, /*with_external_formatter=*/ true)?;
+
+    assert!(
+        !harness.has_selection(),
+        "precondition: no selection should be active"
+    );
+
+    run_format_selection(&mut harness)?;
+
+    harness.wait_until(|h| {
+        h.get_buffer_content()
+            .unwrap_or_default()
+            .contains("EXTERNAL_FORMATTER_RAN")
+    })?;
+
+    Ok(())
+}
@@ -1150,6 +1150,78 @@ fn test_save_changes_with_ctrl_s() {
     harness.assert_screen_not_contains("Keybinding Editor");
 }
 
+/// Rebinding a keymap action moves it to the new key: the saved user config
+/// gains the new binding plus a `noop` override that frees the old key.
+#[test]
+fn test_rebind_keymap_action_updates_config() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let config_path = harness
+        .project_dir()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("config")
+        .join("config.json");
+    open_keybinding_editor(&mut harness);
+
+    // goto_line is bound to Ctrl+G in the default keymap.
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    for ch in "goto_line".chars() {
+        harness
+            .send_key(KeyCode::Char(ch), KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    select_first_binding(&mut harness);
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Edit Keybinding");
+
+    // Press the new key, then move to Save and confirm.
+    harness
+        .send_key(
+            KeyCode::Char('g'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Keybinding Editor");
+
+    let bound = |bindings: &[fresh::config::Keybinding], action: &str, modifiers: &[&str]| {
+        bindings
+            .iter()
+            .any(|kb| kb.action == action && kb.key == "g" && kb.modifiers == modifiers)
+    };
+    let keybindings = &harness.editor().config().keybindings;
+    assert!(
+        bound(keybindings, "goto_line", &["ctrl", "alt"]),
+        "{keybindings:?}"
+    );
+    assert!(bound(keybindings, "noop", &["ctrl"]), "{keybindings:?}");
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&config_path).unwrap()).unwrap();
+    let saved: Vec<fresh::config::Keybinding> =
+        serde_json::from_value(saved["keybindings"].clone()).unwrap();
+    assert!(bound(&saved, "goto_line", &["ctrl", "alt"]), "{saved:?}");
+    assert!(bound(&saved, "noop", &["ctrl"]), "{saved:?}");
+}
+
 // ========================
 // Edit dialog field editing
 // ========================
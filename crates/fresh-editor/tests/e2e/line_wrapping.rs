@@ -2477,3 +2477,188 @@ fn test_page_view_default_width_80() {
         screen
     );
 }
+
+/// Test wrap_column with wrap_indent: a long indented line breaks between
+/// words at the configured column, continuation rows repeat the line's
+/// indent, and Up/Down step between the visual rows of the one logical line.
+#[test]
+fn test_wrap_column_word_boundary_with_continuation_indent() {
+    let config = Config {
+        editor: fresh::config::EditorConfig {
+            line_wrap: true,
+            wrap_indent: true,
+            wrap_column: Some(30),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+
+    let text = "    alpha beta gamma delta epsilon zeta eta theta iota kappa";
+    harness.type_text(text).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let gutter_width = harness.editor().active_state().margins.left_total_width();
+    let (first_row, _) = harness.content_area_rows();
+    let content_of = |row: usize| -> String {
+        harness
+            .get_row_text(row as u16)
+            .chars()
+            .skip(gutter_width)
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    };
+    let rows: Vec<String> = (first_row..first_row + 3).map(content_of).collect();
+
+    // Every row stays within the wrap column and no word is split.
+    for row in &rows {
+        assert!(
+            row.chars().count() <= 30,
+            "row wider than wrap column: {row:?}"
+        );
+    }
+    let rejoined: Vec<&str> = rows.iter().flat_map(|r| r.split_whitespace()).collect();
+    let words: Vec<&str> = text.split_whitespace().collect();
+    assert_eq!(
+        rejoined, words,
+        "wrapping must break between words: {rows:?}"
+    );
+
+    // Continuation rows carry the source line's 4-space indent.
+    for row in &rows[1..] {
+        assert!(
+            row.starts_with("    ") && !row.starts_with("     "),
+            "continuation row should be indented by exactly 4 spaces: {row:?}"
+        );
+    }
+
+    // Up/Down move between visual rows of the same logical line.
+    for _ in 0..10 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    let (start_x, start_y) = harness.screen_cursor_position();
+    assert_eq!(harness.cursor_position(), 10);
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    let (down_x, down_y) = harness.screen_cursor_position();
+    let down_pos = harness.cursor_position();
+    assert_eq!(
+        down_y,
+        start_y + 1,
+        "Down should reach the continuation row"
+    );
+    assert_eq!(down_x, start_x, "Down should keep the visual column");
+    let second_row_start = text.find(rows[1].trim_start()).unwrap();
+    assert!(
+        down_pos > second_row_start && down_pos < text.len(),
+        "cursor {down_pos} should be inside the continuation row starting at {second_row_start}"
+    );
+
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.screen_cursor_position(), (start_x, start_y));
+    assert_eq!(harness.cursor_position(), 10);
+}
+
+/// Toggle page view from the command palette.
+fn toggle_page_view(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Page View").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test that page view centers a column of exactly `page_width` cells,
+/// with equal margins on either side.
+#[test]
+fn test_page_view_centers_text_column_at_page_width() {
+    let mut config = Config::default();
+    config.editor.page_width = Some(60);
+    let mut harness = EditorTestHarness::with_config(160, 24, config).unwrap();
+
+    // A line exactly as wide as the page fills the text column edge to edge.
+    let content = "y".repeat(60);
+    let fixture = crate::common::fixtures::TestFixture::new("test_centered.txt", &content).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    toggle_page_view(&mut harness);
+
+    let row = (0..24u16)
+        .map(|y| harness.get_row_text(y))
+        .find(|row| row.contains("yyyy"))
+        .expect("page view should show the content row");
+    let chars: Vec<char> = row.chars().collect();
+    let left = chars.iter().position(|&c| c == 'y').unwrap();
+    let right = chars.iter().rposition(|&c| c == 'y').unwrap();
+    assert_eq!(
+        right - left + 1,
+        60,
+        "text column should be 60 wide: {row:?}"
+    );
+
+    // Margins balance, allowing for the odd leftover column and the
+    // scrollbar on the right edge.
+    let right_margin = chars.len() - right - 1;
+    assert!(
+        left.abs_diff(right_margin) <= 2,
+        "text column should be centered: left margin {left}, right margin {right_margin}"
+    );
+}
+
+/// Test that page-view focus mode dims paragraphs other than the one
+/// holding the cursor, and follows the cursor into the next paragraph.
+#[test]
+fn test_page_view_focus_dims_other_paragraphs() {
+    let mut config = Config::default();
+    config.editor.page_width = Some(60);
+    config.editor.page_view_focus = true;
+    let mut harness = EditorTestHarness::with_config(120, 24, config).unwrap();
+
+    let content = "First paragraph here\nstill the first\n\nSecond paragraph here";
+    let fixture = crate::common::fixtures::TestFixture::new("test_focus.txt", content).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    toggle_page_view(&mut harness);
+
+    let fg_of = |harness: &EditorTestHarness, needle: &str| {
+        let (x, y) = harness
+            .find_text_on_screen(needle)
+            .unwrap_or_else(|| panic!("{needle:?} should be on screen"));
+        harness.get_cell_style(x, y).and_then(|s| s.fg)
+    };
+
+    let first = fg_of(&harness, "First");
+    assert_eq!(fg_of(&harness, "still"), first);
+    assert_ne!(
+        fg_of(&harness, "Second"),
+        first,
+        "the paragraph without the cursor should be dimmed"
+    );
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    let second = fg_of(&harness, "Second");
+    assert_ne!(
+        fg_of(&harness, "First"),
+        second,
+        "focus should follow the cursor into the second paragraph"
+    );
+    assert_eq!(
+        second, first,
+        "the focused paragraph keeps its normal color"
+    );
+}
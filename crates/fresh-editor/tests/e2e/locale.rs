@@ -538,3 +538,30 @@ fn test_locale_switch_affects_command_palette_commands() {
     // Should confirm that we see the translated command
     harness.assert_screen_contains("Abrir archivo");
 }
+
+#[test]
+fn test_locale_from_config_translates_menu_and_shortcut_labels() {
+    let _lock = lock_locale();
+    let config = Config {
+        locale: LocaleName(Some("es".to_string())),
+        ..Default::default()
+    };
+
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.render().unwrap();
+
+    // Menu labels come from the locale, not the English defaults
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Nuevo archivo");
+    harness.assert_screen_not_contains("New File");
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    // So do the cheat sheet's title and category headings
+    harness.editor_mut().open_keyboard_shortcuts();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Atajos de teclado");
+    harness.assert_screen_contains("── Movimiento ──");
+}
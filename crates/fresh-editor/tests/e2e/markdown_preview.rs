@@ -0,0 +1,98 @@
+//! E2E tests for the Markdown preview panel (`ToggleMarkdownPreview`).
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use fresh::input::keybindings::Action;
+use ratatui::style::Color;
+use std::time::Duration;
+
+const WIDTH: u16 = 120;
+
+fn create_harness() -> EditorTestHarness {
+    EditorTestHarness::create(
+        WIDTH,
+        40,
+        HarnessOptions::new()
+            .with_project_root()
+            .with_full_grammar_registry(),
+    )
+    .unwrap()
+}
+
+/// Open `content` as a Markdown file and toggle the preview on.
+fn open_preview(harness: &mut EditorTestHarness, content: &str) {
+    let path = harness.project_dir().unwrap().join("notes.md");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, content).unwrap();
+    harness.open_file(&path).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleMarkdownPreview);
+    harness.render().unwrap();
+}
+
+/// Position of `text` in the right half of the screen, where the preview
+/// panel sits beside the source.
+fn find_in_preview(harness: &EditorTestHarness, text: &str) -> Option<(u16, u16)> {
+    let needle: Vec<char> = text.chars().collect();
+    (0..40).find_map(|row| {
+        let line: Vec<char> = harness.screen_row_text(row).chars().collect();
+        (WIDTH as usize / 2..line.len())
+            .find(|&col| line[col..].starts_with(&needle))
+            .map(|col| (col as u16, row))
+    })
+}
+
+fn fg(harness: &EditorTestHarness, (col, row): (u16, u16)) -> Option<Color> {
+    harness.get_cell_style(col, row).and_then(|style| style.fg)
+}
+
+/// A fenced Rust block is highlighted in the preview with the same
+/// colours the source buffer uses for it.
+#[test]
+fn test_markdown_preview_highlights_fenced_code() {
+    let mut harness = create_harness();
+    open_preview(
+        &mut harness,
+        "# Notes\n\n```rust\nfn answer() -> u32 {\n    42\n}\n```\n",
+    );
+
+    let source_keyword = harness.find_text_on_screen("fn answer").unwrap();
+    let keyword = find_in_preview(&harness, "fn answer").expect("preview shows the code");
+    let number = find_in_preview(&harness, "42").unwrap();
+    assert!(keyword.0 > source_keyword.0);
+
+    assert!(fg(&harness, keyword).is_some());
+    assert_eq!(fg(&harness, keyword), fg(&harness, source_keyword));
+    assert_ne!(
+        fg(&harness, keyword),
+        fg(&harness, number),
+        "keyword and number must differ — a uniform colour means the block wasn't highlighted"
+    );
+    // The fence markers themselves are not part of the preview.
+    assert!(find_in_preview(&harness, "```").is_none());
+}
+
+/// Edits to the source re-render the preview once typing pauses, and the
+/// cursor stays in the source meanwhile.
+#[test]
+fn test_markdown_preview_follows_edits_after_debounce() {
+    let mut harness = create_harness();
+    open_preview(&mut harness, "Hello preview\n");
+    assert!(find_in_preview(&harness, "Hello preview").is_some());
+
+    harness.type_text("Draft ").unwrap();
+    harness.tick_and_render().unwrap();
+    assert!(find_in_preview(&harness, "Draft Hello preview").is_none());
+
+    harness.advance_time(Duration::from_millis(500));
+    harness.tick_and_render().unwrap();
+    assert!(find_in_preview(&harness, "Draft Hello preview").is_some());
+
+    // Toggling again closes the panel.
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleMarkdownPreview);
+    harness.render().unwrap();
+    assert!(find_in_preview(&harness, "Draft Hello preview").is_none());
+    harness.assert_screen_contains("Draft Hello preview");
+}
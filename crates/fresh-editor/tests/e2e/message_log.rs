@@ -0,0 +1,71 @@
+//! The message log keeps every status message (core and plugin) with the
+//! time it was shown, and `ShowMessageLog` lists them oldest first.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh_core::api::PluginCommand;
+
+/// True when `line` starts with an `HH:MM:SS` timestamp followed by two
+/// spaces.
+fn has_timestamp_prefix(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    bytes.len() > 10
+        && bytes[..8].iter().enumerate().all(|(i, b)| match i {
+            2 | 5 => *b == b':',
+            _ => b.is_ascii_digit(),
+        })
+        && &line[8..10] == "  "
+}
+
+#[test]
+fn test_message_log_lists_status_messages_in_order_with_timestamps() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+
+    harness
+        .editor_mut()
+        .set_status_message("first-message".to_string());
+    let _ = harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::SetStatus {
+            message: "second-from-plugin".to_string(),
+        });
+    harness
+        .editor_mut()
+        .set_status_message("third-message".to_string());
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Show Message Log").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    let position = |marker: &str| {
+        lines
+            .iter()
+            .position(|line| line.ends_with(marker))
+            .unwrap_or_else(|| panic!("{marker} missing from message log:\n{content}"))
+    };
+    let first = position("first-message");
+    let second = position("second-from-plugin");
+    let third = position("third-message");
+    assert!(
+        first < second && second < third,
+        "messages should be listed oldest first:\n{content}"
+    );
+    for index in [first, second, third] {
+        assert!(
+            has_timestamp_prefix(lines[index]),
+            "each entry should start with its time: {:?}",
+            lines[index]
+        );
+    }
+
+    // The log is read-only.
+    harness.type_text("x").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), content);
+}
@@ -10,13 +10,16 @@ pub mod basic;
 pub mod binary_file;
 pub mod block_selection;
 pub mod blog_showcases;
+pub mod bookmarks;
 pub mod buffer_groups;
 #[cfg(feature = "plugins")]
 pub mod buffer_lifecycle;
 pub mod buffer_settings_commands;
+pub mod buffer_switcher;
 pub mod capslock_shortcuts;
 pub mod cargo_config_editing;
 pub mod command_palette;
+pub mod compare_buffers;
 pub mod config_language_selector;
 pub mod copy_buffer_path;
 pub mod crash_repro;
@@ -46,6 +49,7 @@ pub mod flash;
 pub mod floating_modal_frame_chrome;
 pub mod folding;
 pub mod glob_language_detection;
+pub mod goto_definition_in_split;
 #[cfg(feature = "gui")]
 pub mod gui;
 pub mod hot_exit_flows;
@@ -152,17 +156,21 @@ pub mod markdown_compose_diagnostics;
 pub mod markdown_compose_scroll_reach;
 pub mod markdown_compose_table_border;
 pub mod markdown_fenced_code_highlighting;
+#[cfg(feature = "plugins")]
+pub mod markdown_preview;
 pub mod memory_scroll_leak;
 pub mod menu_bar;
 pub mod menu_cursor_bleed;
 pub mod menu_render_golden;
 pub mod menu_tab_color_bleed;
 pub mod merge_conflict;
+pub mod message_log;
 pub mod mouse;
 pub mod movement;
 pub mod multi_file_opening;
 pub mod multibyte_characters;
 pub mod multicursor;
+pub mod navigation_acceleration;
 pub mod occurrence_highlight;
 pub mod on_save_actions;
 pub mod open_folder;
@@ -176,6 +184,12 @@ pub mod paste;
 pub mod per_buffer_view_settings;
 pub mod per_session_authority;
 #[cfg(feature = "plugins")]
+pub mod plugin_gutter_icons;
+#[cfg(feature = "plugins")]
+pub mod plugin_output_panel;
+#[cfg(feature = "plugins")]
+pub mod plugin_popup;
+#[cfg(feature = "plugins")]
 pub mod plugins;
 pub mod popup_selection;
 pub mod position_history;
@@ -193,21 +207,26 @@ pub mod remote_fs_test;
 pub mod remote_indicator_popup;
 pub mod remote_indicator_status;
 pub mod remote_reconnect_terminal;
+pub mod render_snapshot;
 pub mod rendering;
 pub mod restored_agent_terminal;
 pub mod restored_terminal_dock_activation;
 pub mod restored_terminal_focus;
 pub mod save_as_language_detection;
 pub mod save_nonexistent_directory;
+pub mod scratch_buffer;
 pub mod scroll_clearing;
+pub mod scroll_on_jump;
 pub mod scrolling;
 pub mod search;
 pub mod search_center_on_scroll;
 pub mod search_down_stall_after_wrap;
 pub mod search_navigation_after_move;
 pub mod search_replace;
+pub mod search_scope;
 pub mod search_selection_on_punctuation;
 pub mod search_viewport_stall_after_wrap;
+pub mod search_wrap;
 pub mod selection;
 pub mod server_session_lifecycle;
 pub mod session_hot_exit;
@@ -280,8 +299,11 @@ pub mod vertical_rulers;
 pub mod vi_mode;
 #[cfg(feature = "plugins")]
 pub mod vi_mode_bugs;
+pub mod vim_keymap;
 pub mod virtual_space;
+pub mod virtual_text_placement;
 pub mod visual_regression;
+pub mod wait_mode;
 pub mod warning_indicators;
 #[cfg(feature = "plugins")]
 pub mod widget_panel_ownership;
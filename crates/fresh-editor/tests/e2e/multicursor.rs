@@ -671,16 +671,19 @@ fn test_all_cursors_visible_in_viewport() {
     // Let's check multiple x positions to find the cursor
 
     let line_y_positions = vec![1, 2, 3]; // y positions of the three lines
+    let secondary_cursor_bg = harness.editor().theme().secondary_cursor;
 
     let mut cursor_indicators_found = 0;
 
     for y in line_y_positions {
         // Check multiple x positions (accounting for line numbers/gutter)
-        // Try x=0 through x=10 to find reversed characters
+        // Try x=0 through x=10 to find cursor-styled characters
         for x in 0..10 {
             if let Some(style) = harness.get_cell_style(x, y) {
-                // Cursor should have REVERSED modifier
-                if style.add_modifier.contains(Modifier::REVERSED) {
+                // Cursor should have REVERSED modifier or the secondary cursor bg
+                if style.add_modifier.contains(Modifier::REVERSED)
+                    || style.bg == Some(secondary_cursor_bg)
+                {
                     cursor_indicators_found += 1;
                     break; // Found cursor on this line, move to next line
                 }
@@ -694,6 +697,41 @@ fn test_all_cursors_visible_in_viewport() {
     );
 }
 
+/// Secondary cursors are drawn with the theme's `secondary_cursor` background
+/// so the primary cursor can be told apart from them
+#[test]
+fn test_secondary_cursor_styled_differently_from_primary() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new_no_wrap(80, 24).unwrap();
+
+    harness.type_text("abc\nabc").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_count(), 2);
+
+    let cursors = harness.find_all_cursors();
+    let &(px, py, _, _) = cursors
+        .iter()
+        .find(|c| c.3)
+        .expect("primary cursor should be visible");
+    let &(sx, sy, _, _) = cursors
+        .iter()
+        .find(|c| !c.3)
+        .expect("secondary cursor should be visible");
+    assert_ne!(py, sy, "cursors should be on different lines");
+
+    let primary_style = harness.get_cell_style(px, py).unwrap();
+    let secondary_style = harness.get_cell_style(sx, sy).unwrap();
+    let secondary_cursor_bg = harness.editor().theme().secondary_cursor;
+
+    assert_eq!(secondary_style.bg, Some(secondary_cursor_bg));
+    assert_ne!(primary_style.bg, Some(secondary_cursor_bg));
+    assert_ne!(primary_style, secondary_style);
+}
+
 /// Test comprehensive multi-cursor editing with multiple 'abc' lines
 /// This test uses the exact same pattern as test_multi_cursor_typing but with 'abc' content
 #[test]
@@ -0,0 +1,122 @@
+//! E2E tests for `navigation_acceleration`: a held Up/Down key moves more
+//! lines per repeat the longer it is held, up to the configured cap.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::time::Duration;
+
+/// Bytes per line of the test file ("0000\n").
+const LINE_LEN: usize = 5;
+
+/// Interval between auto-repeated keys, like a terminal's key repeat.
+const REPEAT_INTERVAL: Duration = Duration::from_millis(30);
+
+/// Open a 500-line file with `config`.
+fn open_lines(config: Config) -> (tempfile::TempDir, EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    let content: String = (0..500).map(|i| format!("{:04}\n", i)).collect();
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    (temp_dir, harness)
+}
+
+/// Press Down `repeats` times at key-repeat rate, returning how many lines
+/// each press moved the cursor.
+fn hold_down(harness: &mut EditorTestHarness, repeats: usize) -> Vec<usize> {
+    let mut line = harness.cursor_position() / LINE_LEN;
+    let mut steps = Vec::new();
+    for _ in 0..repeats {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        let next = harness.cursor_position() / LINE_LEN;
+        steps.push(next - line);
+        line = next;
+        harness.advance_time(REPEAT_INTERVAL);
+    }
+    steps
+}
+
+fn accelerating_config() -> Config {
+    let mut config = Config::default();
+    config.editor.navigation_acceleration = true;
+    config.editor.navigation_acceleration_ramp_ms = 100;
+    config.editor.navigation_acceleration_max_step = 4;
+    config
+}
+
+#[test]
+fn test_held_down_key_accelerates_up_to_the_cap() {
+    let (_temp_dir, mut harness) = open_lines(accelerating_config());
+    let steps = hold_down(&mut harness, 20);
+
+    assert_eq!(steps[0], 1, "the first press moves one line: {:?}", steps);
+    assert!(
+        steps.windows(2).all(|w| w[0] <= w[1]),
+        "the step never shrinks while the key is held: {:?}",
+        steps
+    );
+    assert!(
+        steps.iter().any(|&s| s > 1 && s < 4),
+        "the step ramps up: {:?}",
+        steps
+    );
+    assert_eq!(*steps.last().unwrap(), 4, "the step is capped: {:?}", steps);
+}
+
+#[test]
+fn test_pause_resets_acceleration() {
+    let (_temp_dir, mut harness) = open_lines(accelerating_config());
+    hold_down(&mut harness, 20);
+
+    harness.advance_time(Duration::from_millis(500));
+    assert_eq!(hold_down(&mut harness, 1), vec![1]);
+}
+
+#[test]
+fn test_acceleration_is_off_by_default() {
+    let (_temp_dir, mut harness) = open_lines(Config::default());
+    let steps = hold_down(&mut harness, 20);
+    assert!(steps.iter().all(|&s| s == 1), "{:?}", steps);
+}
+
+#[test]
+fn test_macro_replays_every_accelerated_step() {
+    let (_temp_dir, mut harness) = open_lines(accelerating_config());
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Record Macro").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("0").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Recording");
+
+    let steps = hold_down(&mut harness, 20);
+    assert!(steps.iter().any(|&s| s > 1), "{:?}", steps);
+    let moved: usize = steps.iter().sum();
+    assert_eq!(harness.cursor_position() / LINE_LEN, moved);
+
+    // Stop recording (F5), return to the top, then replay (F4).
+    harness.send_key(KeyCode::F(5), KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+    harness.send_key(KeyCode::F(4), KeyModifiers::NONE).unwrap();
+
+    assert_eq!(
+        harness.cursor_position() / LINE_LEN,
+        moved,
+        "playback moves as far as the accelerated recording did"
+    );
+}
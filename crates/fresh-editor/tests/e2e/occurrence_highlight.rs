@@ -1,5 +1,6 @@
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::view::reference_highlight_overlay::reference_highlight_namespace;
 
 #[test]
 fn test_occurrence_highlight_toggle() {
@@ -95,3 +96,36 @@ fn test_occurrence_highlight_toggle() {
         })
         .expect("Background should be highlighted again");
 }
+
+/// With the cursor on an identifier that appears three times, each
+/// occurrence (and nothing that merely contains it) gets a highlight.
+#[test]
+fn test_occurrence_highlight_marks_every_whole_word_occurrence() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .type_text("count = count + recount;\nprint(count)")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let highlighted = |h: &EditorTestHarness| {
+        let ns = reference_highlight_namespace();
+        let state = h.editor().active_state();
+        let mut ranges: Vec<_> = state
+            .overlays
+            .all()
+            .iter()
+            .filter(|o| o.namespace.as_ref() == Some(&ns))
+            .map(|o| o.range(&state.marker_list))
+            .collect();
+        ranges.sort_by_key(|r| r.start);
+        ranges
+    };
+
+    harness
+        .wait_until(|h| !highlighted(h).is_empty())
+        .expect("occurrences of the word under the cursor should be highlighted");
+    assert_eq!(highlighted(&harness), vec![0..5, 8..13, 31..36]);
+}
@@ -0,0 +1,107 @@
+//! E2E tests for plugin gutter icons (`editor.setGutterIcon`).
+
+use crate::common::harness::EditorTestHarness;
+use fresh::services::plugins::api::{OverlayColorSpec, PluginCommand};
+use ratatui::style::Color;
+
+fn set_gutter_icon(
+    harness: &mut EditorTestHarness,
+    line: usize,
+    namespace: &str,
+    glyph: &str,
+    color: OverlayColorSpec,
+    priority: i32,
+) {
+    let buffer_id = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::SetGutterIcon {
+            buffer_id,
+            line,
+            namespace: namespace.to_string(),
+            glyph: glyph.to_string(),
+            color,
+            priority,
+        })
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// An icon renders in the gutter of its line, in its theme colour.
+#[test]
+fn test_gutter_icon_renders_on_its_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("first line\nsecond line\nthird line\n")
+        .unwrap();
+    set_gutter_icon(
+        &mut harness,
+        1,
+        "breakpoints",
+        "◆",
+        OverlayColorSpec::ThemeKey("diagnostic.error_fg".to_string()),
+        0,
+    );
+
+    let (icon_x, icon_y) = harness.find_text_on_screen("◆").expect("icon drawn");
+    let (text_x, text_y) = harness.find_text_on_screen("second line").unwrap();
+    assert_eq!(icon_y, text_y, "icon sits on the line it was set for");
+    assert!(icon_x < text_x, "icon is drawn in the gutter");
+
+    let expected = harness
+        .editor()
+        .theme()
+        .resolve_theme_key("diagnostic.error_fg");
+    assert_eq!(
+        harness.get_cell_style(icon_x, icon_y).and_then(|s| s.fg),
+        expected
+    );
+}
+
+/// When namespaces put icons on the same line the higher priority shows,
+/// and clearing its namespace reveals the other one.
+#[test]
+fn test_gutter_icons_resolve_by_priority() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("only line\n").unwrap();
+    set_gutter_icon(
+        &mut harness,
+        0,
+        "git",
+        "│",
+        OverlayColorSpec::Rgb(0, 200, 0),
+        5,
+    );
+    set_gutter_icon(
+        &mut harness,
+        0,
+        "breakpoints",
+        "●",
+        OverlayColorSpec::Rgb(200, 0, 0),
+        20,
+    );
+
+    let (x, y) = harness
+        .find_text_on_screen("●")
+        .expect("higher priority shows");
+    assert_eq!(
+        harness.get_cell_style(x, y).and_then(|s| s.fg),
+        Some(Color::Rgb(200, 0, 0))
+    );
+
+    let buffer_id = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ClearLineIndicators {
+            buffer_id,
+            namespace: "breakpoints".to_string(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("●");
+    assert_eq!(harness.get_cell(x, y).as_deref(), Some("│"));
+    assert_eq!(
+        harness.get_cell_style(x, y).and_then(|s| s.fg),
+        Some(Color::Rgb(0, 200, 0))
+    );
+}
@@ -0,0 +1,53 @@
+//! E2E tests for plugin output panels (`editor.showOutputPanel`).
+
+use crate::common::harness::EditorTestHarness;
+use fresh::services::plugins::api::PluginCommand;
+use ratatui::style::Modifier;
+
+fn show_output_panel(harness: &mut EditorTestHarness, html: &str) {
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ShowOutputPanel {
+            id: "preview".to_string(),
+            html: html.to_string(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A heading renders bold and a code block keeps its lines and
+/// indentation, one screen row per source line.
+#[test]
+fn test_output_panel_renders_heading_and_code_block() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    show_output_panel(
+        &mut harness,
+        "<h2>Build</h2><pre><code>cargo build\n  --release</code></pre>",
+    );
+
+    harness.assert_screen_contains("*preview*");
+    let (x, y) = harness.find_text_on_screen("Build").unwrap();
+    for dx in 0..5 {
+        let style = harness.get_cell_style(x + dx, y).unwrap();
+        assert!(style.add_modifier.contains(Modifier::BOLD));
+    }
+
+    let (code_x, code_y) = harness.find_text_on_screen("cargo build").unwrap();
+    assert_eq!(code_y, y + 2, "a blank row separates heading and code");
+    assert_eq!(code_x, x + 4, "code blocks are indented");
+    let (flag_x, flag_y) = harness.find_text_on_screen("--release").unwrap();
+    assert_eq!((flag_x, flag_y), (code_x + 2, code_y + 1));
+}
+
+/// Showing the same id again refreshes the open panel instead of
+/// opening a second one.
+#[test]
+fn test_output_panel_same_id_replaces_content() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    show_output_panel(&mut harness, "<p>first version</p>");
+    show_output_panel(&mut harness, "<p>second version</p>");
+
+    harness.assert_screen_contains("second version");
+    // A second panel would leave the first one on screen
+    harness.assert_screen_not_contains("first version");
+}
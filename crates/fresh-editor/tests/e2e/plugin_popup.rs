@@ -0,0 +1,134 @@
+//! E2E tests for plugin floating popups (`editor.showPopup`,
+//! `updatePopup`, `closePopup`).
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::services::plugins::api::{PluginCommand, PopupAnchor, PopupOptions};
+
+fn show_popup(harness: &mut EditorTestHarness, anchor: PopupAnchor) {
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ShowPopup {
+            handle: 1,
+            lines: vec!["first line".to_string(), "second line".to_string()],
+            anchor,
+            options: PopupOptions::default(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Index of the first screen row containing `text`.
+fn row_of(harness: &EditorTestHarness, text: &str) -> usize {
+    harness
+        .screen_to_string()
+        .lines()
+        .position(|line| line.contains(text))
+        .unwrap_or_else(|| panic!("{:?} not on screen", text))
+}
+
+/// A buffer-anchored popup draws its lines in a bordered box just below
+/// the anchor's line.
+#[test]
+fn test_show_popup_renders_bordered_lines_below_anchor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("anchor text").unwrap();
+    show_popup(&mut harness, PopupAnchor::Buffer { position: 0 });
+
+    let screen = harness.screen_to_string();
+    let lines: Vec<&str> = screen.lines().collect();
+    let anchor_row = row_of(&harness, "anchor text");
+    let first = row_of(&harness, "first line");
+    let second = row_of(&harness, "second line");
+
+    assert_eq!(first, anchor_row + 2, "popup should open below the anchor");
+    assert_eq!(second, first + 1);
+    assert!(lines[first - 1].contains('┌') && lines[first - 1].contains('┐'));
+    assert!(lines[first].contains('│') && lines[second].contains('│'));
+    assert!(lines[second + 1].contains('└') && lines[second + 1].contains('┘'));
+}
+
+/// The anchor is a marker: text inserted before it pushes the popup down
+/// with the anchored line.
+#[test]
+fn test_buffer_anchored_popup_follows_edits() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("top line").unwrap();
+    show_popup(&mut harness, PopupAnchor::Buffer { position: 0 });
+    assert_eq!(
+        row_of(&harness, "first line"),
+        row_of(&harness, "top line") + 2
+    );
+
+    let buffer_id = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::InsertText {
+            buffer_id,
+            position: 0,
+            text: "inserted\n".to_string(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        row_of(&harness, "top line"),
+        row_of(&harness, "inserted") + 1
+    );
+    assert_eq!(
+        row_of(&harness, "first line"),
+        row_of(&harness, "top line") + 2
+    );
+}
+
+/// Scrolling the anchor out of view hides the popup instead of pinning it
+/// to the top row; scrolling back shows it again.
+#[test]
+fn test_buffer_anchored_popup_hidden_while_anchor_off_screen() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let content: String = (0..100).map(|i| format!("line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    show_popup(&mut harness, PopupAnchor::Buffer { position: 0 });
+    harness.assert_screen_contains("first line");
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("line 99");
+    harness.assert_screen_not_contains("first line");
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        row_of(&harness, "first line"),
+        row_of(&harness, "line 0") + 2
+    );
+}
+
+#[test]
+fn test_update_and_close_popup() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    show_popup(&mut harness, PopupAnchor::Screen { x: 10, y: 5 });
+    assert_eq!(row_of(&harness, "first line"), 6);
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::UpdatePopup {
+            handle: 1,
+            lines: vec!["updated".to_string()],
+        })
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("updated");
+    harness.assert_screen_not_contains("first line");
+
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ClosePopup { handle: 1 })
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("updated");
+}
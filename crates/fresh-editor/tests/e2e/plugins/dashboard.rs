@@ -25,6 +25,7 @@ fn config_with_dashboard_autoopen() -> Config {
             enabled: true,
             path: None,
             settings: serde_json::json!({ "autoOpen": true }),
+            permissions: Default::default(),
         },
     );
     config
@@ -0,0 +1,121 @@
+//! E2E tests for the ordering of entries in the diagnostics panel.
+//!
+//! Within a file the panel lists diagnostics by severity (errors first),
+//! then by position, so the most important problems are at the top.
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crate::common::tracing::init_tracing_from_env;
+use fresh::input::keybindings::Action;
+use std::fs;
+
+/// Build one `publishDiagnostics` entry for the fake server.
+fn diagnostic_json(line: u32, character: u32, severity: u8, message: &str) -> String {
+    format!(
+        r#"{{"range":{{"start":{{"line":{line},"character":{character}}},"end":{{"line":{line},"character":{}}}}},"severity":{severity},"message":"{message}"}}"#,
+        character + 3
+    )
+}
+
+/// `Action::ShowDiagnosticsPanel` opens the panel with entries sorted by
+/// severity first and position second, regardless of publish order.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_show_diagnostics_panel_sorts_by_severity_then_position() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().to_path_buf();
+
+    // Published deliberately out of order.
+    let diagnostics = [
+        diagnostic_json(4, 0, 2, "warning_late"),
+        diagnostic_json(6, 0, 1, "error_late"),
+        diagnostic_json(1, 0, 3, "info_early"),
+        diagnostic_json(2, 8, 1, "error_mid"),
+        diagnostic_json(2, 2, 1, "error_first"),
+    ];
+    let diagnostics_json = format!("[{}]", diagnostics.join(","));
+    let _fake_server =
+        FakeLspServer::spawn_publishing_diagnostics(temp_dir.path(), &diagnostics_json).unwrap();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let mut content = String::new();
+    for i in 0..20 {
+        content.push_str(&format!("line {:02} content here\n", i));
+    }
+    let test_file = project_root.join("test.rs");
+    fs::write(&test_file, &content).unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::many_diagnostics_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: Some(vec![]),
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 40, config, project_root).unwrap();
+    harness.open_file(&test_file).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .get_stored_diagnostics()
+                .values()
+                .any(|diags| diags.len() == 5)
+        })
+        .unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShowDiagnosticsPanel);
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("Diagnostics (") && screen.contains("info_early")
+        })
+        .unwrap();
+
+    let screen = harness.screen_to_string();
+    let expected = [
+        "3:3 error_first",
+        "3:9 error_mid",
+        "7:1 error_late",
+        "5:1 warning_late",
+        "2:1 info_early",
+    ];
+    let positions: Vec<usize> = expected
+        .iter()
+        .map(|label| {
+            screen
+                .find(label)
+                .unwrap_or_else(|| panic!("Expected '{}' in panel.\nScreen:\n{}", label, screen))
+        })
+        .collect();
+    assert!(
+        positions.windows(2).all(|w| w[0] < w[1]),
+        "Diagnostics should be sorted by severity then position: {:?}.\nScreen:\n{}",
+        expected,
+        screen
+    );
+}
@@ -0,0 +1,136 @@
+//! E2E tests for workspace-wide diagnostics.
+//!
+//! Published diagnostics are kept per file URI for every file the server
+//! reports on, not just open buffers, so the diagnostics panel can list
+//! problems across the workspace and open files that aren't loaded yet.
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crate::common::tracing::init_tracing_from_env;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use std::fs;
+
+/// Diagnostics for two files both land in the URI-keyed store, survive the
+/// second file being closed, and picking the closed file's entry in the
+/// panel reopens it.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_diagnostics_aggregated_across_files() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().to_path_buf();
+
+    let diagnostics_json = r#"[{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":4}},"severity":1,"message":"workspace_problem"}]"#;
+    let _fake_server =
+        FakeLspServer::spawn_publishing_diagnostics(temp_dir.path(), diagnostics_json).unwrap();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin(&plugins_dir, "diagnostics_panel");
+    copy_plugin_lib(&plugins_dir);
+
+    let file_a = project_root.join("alpha.rs");
+    let file_b = project_root.join("beta.rs");
+    fs::write(&file_a, "fn alpha() {}\nbad line\n").unwrap();
+    fs::write(&file_b, "fn beta() {}\nbad line\n").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::many_diagnostics_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: Some(vec![]),
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 40, config, project_root).unwrap();
+
+    let uri_a = fresh_core::file_uri::path_to_lsp_uri(&file_a).unwrap();
+    let uri_b = fresh_core::file_uri::path_to_lsp_uri(&file_b).unwrap();
+
+    harness.open_file(&file_a).unwrap();
+    harness.open_file(&file_b).unwrap();
+    harness
+        .wait_until(|h| {
+            let store = h.editor().get_stored_diagnostics();
+            store.contains_key(uri_a.as_str()) && store.contains_key(uri_b.as_str())
+        })
+        .unwrap();
+
+    // Closing the buffer doesn't drop the file from the workspace view.
+    let beta_id = harness.editor().active_buffer();
+    harness.editor_mut().close_buffer(beta_id).unwrap();
+    harness.render().unwrap();
+    let store = harness.editor().get_stored_diagnostics();
+    assert!(
+        store.contains_key(uri_a.as_str()) && store.contains_key(uri_b.as_str()),
+        "Both files should stay in the diagnostics store, got {:?}",
+        store.keys().collect::<Vec<_>>()
+    );
+
+    // List every file in the panel and pick beta's entry.
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ShowDiagnosticsPanel);
+    harness
+        .wait_until(|h| h.screen_to_string().contains("workspace_problem"))
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("All Files") && screen.contains("beta.rs")
+        })
+        .unwrap();
+
+    let beta_header_row = harness
+        .screen_to_string()
+        .lines()
+        .position(|line| line.contains("beta.rs"))
+        .unwrap();
+    for _ in 0..10 {
+        let (_, row) = harness.screen_cursor_position();
+        let row = row as usize;
+        let on_beta_entry = row > beta_header_row
+            && harness
+                .screen_to_string()
+                .lines()
+                .nth(row)
+                .is_some_and(|line| line.contains("workspace_problem"));
+        if on_beta_entry {
+            break;
+        }
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .active_state()
+                .buffer
+                .file_path()
+                .is_some_and(|path| path.ends_with("beta.rs"))
+        })
+        .unwrap();
+}
@@ -0,0 +1,63 @@
+//! `editor.executeAction` runs any action `Action::from_str` parses, with
+//! the payload args of actions that carry one.
+
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+use tempfile::TempDir;
+
+/// Install the tiny `test_execute_action` plugin into the project.
+fn setup_plugin(project_root: &std::path::Path) {
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).expect("create plugins dir");
+    copy_plugin_lib(&plugins_dir);
+    const SRC: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/plugins/test_execute_action.ts"
+    ));
+    fs::write(plugins_dir.join("test_execute_action.ts"), SRC).expect("write test plugin");
+}
+
+#[test]
+fn plugin_execute_action_moves_cursor_and_passes_args() {
+    let tmp = TempDir::new().unwrap();
+    let project = tmp.path().to_path_buf();
+    setup_plugin(&project);
+    let file = project.join("lines.txt");
+    fs::write(&file, "one\ntwo\nthree\nfour\nfive\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, Config::default(), project)
+            .unwrap();
+    harness.open_file(&file).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("TestActions: Move Down Three").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("EXEC-DONE"))
+        .unwrap();
+
+    // Three `move_down`s land on line 4; `insert_char` types there and
+    // leaves the cursor after the inserted character.
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "one\ntwo\nthree\nxfour\nfive\n"
+    );
+    assert_eq!(harness.cursor_position(), "one\ntwo\nthree\nx".len());
+    assert!(
+        harness
+            .screen_to_string()
+            .contains("EXEC-DONE rejected=true"),
+        "non-object args should be rejected"
+    );
+}
@@ -862,3 +862,39 @@ fn test_quick_open_files_action() {
 
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
 }
+
+/// Ctrl+F in the file explorer opens Quick Open on files, which
+/// fuzzy-finds a file anywhere in the tree (including collapsed
+/// directories); Enter opens it.
+#[test]
+fn test_find_file_from_file_explorer() {
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 30, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    fs::create_dir_all(project_root.join("src/handlers")).unwrap();
+    fs::write(
+        project_root.join("src/handlers/request_handler.rs"),
+        "fn handle_request() {}\n",
+    )
+    .unwrap();
+    fs::write(project_root.join("README.md"), "Readme\n").unwrap();
+
+    harness.editor_mut().toggle_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("reqhan").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("request_handler.rs"))
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("fn handle_request"))
+        .unwrap();
+}
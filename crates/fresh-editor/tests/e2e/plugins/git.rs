@@ -2571,6 +2571,70 @@ fn test_git_blame_scroll_with_many_virtual_lines() {
     );
 }
 
+/// Toggling blame annotations prefixes each committed line of the open
+/// file with its commit and author; toggling again removes them.
+// TODO: Fix git blame tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_blame_annotations_toggle() {
+    let repo = GitTestRepo::new();
+    repo.create_file("annotated.txt", "first line\nsecond line\n");
+    repo.git_add(&["annotated.txt"]);
+    repo.git_commit("Add annotated file");
+    repo.setup_git_blame_plugin();
+
+    let head = crate::common::git_test_helper::git_command(&repo.path)
+        .args(["rev-parse", "--short=7", "HEAD"])
+        .output()
+        .unwrap();
+    let short_hash = String::from_utf8_lossy(&head.stdout).trim().to_string();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    harness.open_file(&repo.path.join("annotated.txt")).unwrap();
+    harness.wait_for_screen_contains("first line").unwrap();
+
+    let toggle = |harness: &mut EditorTestHarness| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text("Git Blame: Toggle Annotations").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    toggle(&mut harness);
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            ["first line", "second line"].iter().all(|text| {
+                screen
+                    .lines()
+                    .find(|line| line.contains(text))
+                    .is_some_and(|line| line.contains(&short_hash) && line.contains("Test User"))
+            })
+        })
+        .unwrap();
+
+    toggle(&mut harness);
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("first line") && !screen.contains(&short_hash)
+        })
+        .unwrap();
+}
+
 // =============================================================================
 // View Transform Tests - Minimal reproduction of byte 0 header bug
 // =============================================================================
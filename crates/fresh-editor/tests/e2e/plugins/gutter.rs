@@ -529,6 +529,165 @@ fn test_git_gutter_untracked_file() {
     );
 }
 
+/// Unsaved edits are diffed against HEAD: changing line 2 and adding a
+/// line after it marks both, with nothing saved
+// TODO: Fix git gutter tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_updates_on_unsaved_edit() {
+    let repo = GitTestRepo::new();
+    repo.setup_typical_project();
+    repo.setup_git_gutter_plugin();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    open_file(&mut harness, &repo.path, "src/main.rs");
+    assert_eq!(
+        count_gutter_indicators(&harness.screen_to_string(), "│"),
+        0,
+        "A clean file should have no change markers"
+    );
+
+    // Modify line 2 and add a new line 3
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" // edited").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("let added = 1;").unwrap();
+
+    harness
+        .wait_until(|h| get_indicator_lines(&h.screen_to_string(), "│") == vec![1, 2])
+        .unwrap();
+}
+
+/// Run a command from the command palette by name
+fn run_palette_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Main.rs with `println!` on line 2 and `start_server` in the last
+/// function both changed from the committed version
+const MAIN_RS_TWO_HUNKS: &str = r#"fn main() {
+    println!("Changed greeting");
+    let config = load_config();
+    start_server(config);
+}
+
+fn load_config() -> Config {
+    Config::default()
+}
+
+fn start_server(config: Config) {
+    println!("Changed server message");
+}
+"#;
+
+/// Reverting the hunk under the cursor restores its HEAD lines in the
+/// buffer and leaves the other hunk alone
+// TODO: Fix git gutter tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_revert_hunk() {
+    let repo = GitTestRepo::new();
+    repo.setup_typical_project();
+    repo.setup_git_gutter_plugin();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    repo.modify_file("src/main.rs", MAIN_RS_TWO_HUNKS);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    open_file(&mut harness, &repo.path, "src/main.rs");
+    wait_for_indicator_on_line(&mut harness, "│", 1);
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    run_palette_command(&mut harness, "Git Gutter: Revert Hunk");
+
+    harness
+        .wait_until(|h| {
+            let content = h.get_buffer_content().unwrap();
+            content.contains("println!(\"Hello, world!\");")
+                && !content.contains("Changed greeting")
+        })
+        .unwrap();
+    let content = harness.get_buffer_content().unwrap();
+    assert!(
+        content.contains("Changed server message"),
+        "Only the hunk under the cursor should be reverted:\n{content}"
+    );
+}
+
+/// Staging the hunk under the cursor adds only that hunk to the index
+// TODO: Fix git gutter tests on Windows - they fail due to git command output differences
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_git_gutter_stage_hunk() {
+    let repo = GitTestRepo::new();
+    repo.setup_typical_project();
+    repo.setup_git_gutter_plugin();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    repo.modify_file("src/main.rs", MAIN_RS_TWO_HUNKS);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    open_file(&mut harness, &repo.path, "src/main.rs");
+    wait_for_indicator_on_line(&mut harness, "│", 1);
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    run_palette_command(&mut harness, "Git Gutter: Stage Hunk");
+    harness
+        .wait_for_screen_contains("Staged hunk at line 2")
+        .unwrap();
+
+    let staged = crate::common::git_test_helper::git_command(&repo.path)
+        .args(["diff", "--cached", "--", "src/main.rs"])
+        .output()
+        .unwrap();
+    let staged = String::from_utf8_lossy(&staged.stdout);
+    assert!(
+        staged.contains("+    println!(\"Changed greeting\");"),
+        "The hunk under the cursor should be staged:\n{staged}"
+    );
+    assert!(
+        !staged.contains("Changed server message"),
+        "The other hunk should stay unstaged:\n{staged}"
+    );
+}
+
 // =============================================================================
 // Buffer Modified Tests
 // =============================================================================
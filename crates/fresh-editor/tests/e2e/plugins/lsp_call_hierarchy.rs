@@ -0,0 +1,192 @@
+//! E2E tests for the call_hierarchy plugin
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+/// Fake server answering `prepareCallHierarchy` with `helper` and
+/// `incomingCalls` with two callers. `__URI__` is replaced with the test
+/// file's URI so the decoded nodes point back into the open buffer.
+const FAKE_LSP_SCRIPT: &str = r#"#!/bin/bash
+read_message() {
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}
+send_message() {
+    local message="$1"
+    local length=${#message}
+    echo -en "Content-Length: $length\r\n\r\n$message"
+}
+item() {
+    echo '{"name":"'$1'","kind":12,"uri":"__URI__","range":{"start":{"line":'$2',"character":0},"end":{"line":'$2',"character":1}},"selectionRange":{"start":{"line":'$2',"character":3},"end":{"line":'$2',"character":9}}}'
+}
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then
+        break
+    fi
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+    case "$method" in
+        "initialize")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"callHierarchyProvider":true,"textDocumentSync":1}}}'
+            ;;
+        "initialized") ;;
+        "textDocument/didOpen"|"textDocument/didChange"|"textDocument/didSave") ;;
+        "textDocument/prepareCallHierarchy")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":['"$(item helper 0)"']}'
+            ;;
+        "callHierarchy/incomingCalls")
+            r='{"start":{"line":3,"character":4},"end":{"line":3,"character":10}}'
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"from":'"$(item caller_one 2)"',"fromRanges":['$r']},{"from":'"$(item caller_two 6)"',"fromRanges":['$r','$r']}]}'
+            ;;
+        "shutdown")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            break
+            ;;
+    esac
+done
+"#;
+
+const TEST_FILE_CONTENT: &str = "fn helper() {}
+
+fn caller_one() {
+    helper();
+}
+
+fn caller_two() {
+    helper();
+    helper();
+}
+";
+
+fn setup_call_hierarchy_test() -> anyhow::Result<(EditorTestHarness, tempfile::TempDir)> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().to_path_buf();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir)?;
+    copy_plugin(&plugins_dir, "call_hierarchy");
+    copy_plugin_lib(&plugins_dir);
+
+    let test_file = project_root.join("test.rs");
+    fs::write(&test_file, TEST_FILE_CONTENT)?;
+
+    let uri = format!("file://{}", test_file.to_string_lossy());
+    let script_path = project_root.join("fake_lsp.sh");
+    fs::write(&script_path, FAKE_LSP_SCRIPT.replace("__URI__", &uri))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms)?;
+    }
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: Some(vec![]),
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+
+    harness.open_file(&test_file)?;
+    harness.process_async_and_render()?;
+    harness.wait_until(|h| h.screen_to_string().contains("LSP (on)"))?;
+
+    Ok((harness, temp_dir))
+}
+
+fn show_incoming_calls(harness: &mut EditorTestHarness) -> anyhow::Result<()> {
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.process_async_and_render()?;
+    harness.type_text("Show Incoming Calls")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| h.screen_to_string().contains("← [fn] caller_two"))?;
+    Ok(())
+}
+
+/// The `incomingCalls` response is decoded into a tree: the prepared item
+/// is the root and each `from` caller is an indented child, with repeated
+/// call sites collapsed into a count.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_call_hierarchy_decodes_incoming_calls_into_tree() -> anyhow::Result<()> {
+    let (mut harness, _temp_dir) = setup_call_hierarchy_test()?;
+    show_incoming_calls(&mut harness)?;
+
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("Incoming calls of 'helper'"),
+        "Panel title should name the prepared item. Screen:\n{screen}"
+    );
+    assert!(
+        screen.contains("▾ [fn] helper"),
+        "Root node should be the prepared item. Screen:\n{screen}"
+    );
+    assert!(
+        screen.contains("← [fn] caller_one") && !screen.contains("caller_one ("),
+        "Single-site caller should be a child without a count. Screen:\n{screen}"
+    );
+    assert!(
+        screen.contains("← [fn] caller_two (2×)"),
+        "Two-site caller should be a child with its call-site count. Screen:\n{screen}"
+    );
+
+    // Tree order: root first, then callers in response order.
+    let root = screen.find("▾ [fn] helper").unwrap();
+    let one = screen.find("← [fn] caller_one").unwrap();
+    let two = screen.find("← [fn] caller_two").unwrap();
+    assert!(
+        root < one && one < two,
+        "Nodes out of order. Screen:\n{screen}"
+    );
+
+    Ok(())
+}
+
+/// Selecting a child node jumps to the caller's name in the source.
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_call_hierarchy_select_jumps_to_node() -> anyhow::Result<()> {
+    let (mut harness, _temp_dir) = setup_call_hierarchy_test()?;
+    show_incoming_calls(&mut harness)?;
+
+    // Panel cursor starts on the root; step down to `caller_two`.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+
+    // `caller_two`'s selectionRange starts at line 6, character 3.
+    harness.wait_until(|h| h.screen_to_string().contains("Ln 7, Col 4"))?;
+
+    Ok(())
+}
@@ -36,13 +36,16 @@ while true; do
     msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
     case "$method" in
         "initialize")
-            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"documentSymbolProvider":true,"textDocumentSync":1}}}'
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"documentSymbolProvider":true,"workspaceSymbolProvider":true,"textDocumentSync":1}}}'
             ;;
         "initialized") ;;
         "textDocument/didOpen"|"textDocument/didChange"|"textDocument/didSave") ;;
         "textDocument/documentSymbol")
             send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"name":"MyClass","kind":5,"location":{"uri":"file://test.ts","range":{"start":{"line":0,"character":0},"end":{"line":8,"character":1}}}},{"name":"constructor","kind":9,"location":{"uri":"file://test.ts","range":{"start":{"line":1,"character":2},"end":{"line":3,"character":3}}}},{"name":"myMethod","kind":6,"location":{"uri":"file://test.ts","range":{"start":{"line":5,"character":2},"end":{"line":7,"character":3}}}}]}'
             ;;
+        "workspace/symbol")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"name":"MyClass","kind":5,"location":{"uri":"file://__ROOT__/test.ts","range":{"start":{"line":0,"character":6},"end":{"line":0,"character":13}}}},{"name":"helperFunction","kind":12,"containerName":"other","location":{"uri":"file://__ROOT__/other.ts","range":{"start":{"line":2,"character":9},"end":{"line":2,"character":23}}}},{"name":"HelperWidget","kind":5,"location":{"uri":"file://__ROOT__/other.ts","range":{"start":{"line":6,"character":6},"end":{"line":6,"character":18}}}}]}'
+            ;;
         "shutdown")
             send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
             break
@@ -62,6 +65,16 @@ const TEST_FILE_CONTENT: &str = r#"class MyClass {
 }
 "#;
 
+/// Second project file, only reachable through workspace symbols
+const OTHER_FILE_CONTENT: &str = r#"// Helpers
+
+function helperFunction(): number {
+  return 1;
+}
+
+class HelperWidget {}
+"#;
+
 fn setup_lsp_test() -> anyhow::Result<(EditorTestHarness, tempfile::TempDir)> {
     let temp_dir = tempfile::TempDir::new()?;
     let project_root = temp_dir.path().to_path_buf();
@@ -72,7 +85,10 @@ fn setup_lsp_test() -> anyhow::Result<(EditorTestHarness, tempfile::TempDir)> {
     copy_plugin_lib(&plugins_dir);
 
     let script_path = project_root.join("fake_lsp.sh");
-    fs::write(&script_path, FAKE_LSP_SCRIPT)?;
+    fs::write(
+        &script_path,
+        FAKE_LSP_SCRIPT.replace("__ROOT__", &project_root.to_string_lossy()),
+    )?;
 
     #[cfg(unix)]
     {
@@ -84,6 +100,7 @@ fn setup_lsp_test() -> anyhow::Result<(EditorTestHarness, tempfile::TempDir)> {
 
     let test_file = project_root.join("test.ts");
     fs::write(&test_file, TEST_FILE_CONTENT)?;
+    fs::write(project_root.join("other.ts"), OTHER_FILE_CONTENT)?;
 
     let mut config = fresh::config::Config::default();
     config.lsp.insert(
@@ -396,3 +413,38 @@ fn selected_suggestion_text(harness: &EditorTestHarness) -> Option<String> {
     }
     None
 }
+
+/// The `workspace/symbol` response is decoded and fuzzy-filtered by name,
+/// and confirming a result opens the file containing it at the symbol
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_lsp_workspace_symbol_filters_and_opens_file() -> anyhow::Result<()> {
+    let (mut harness, _temp_dir) = setup_lsp_test()?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.process_async_and_render()?;
+    harness.type_text("Go to Workspace Symbol")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_for_prompt()?;
+
+    harness.type_text("helpfn")?;
+    harness.wait_until(|h| h.screen_to_string().contains("[fn] helperFunction"))?;
+
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("other.ts:3"),
+        "Result should name its file and line. Screen:\n{screen}"
+    );
+    for filtered_out in ["[class] HelperWidget", "[class] MyClass"] {
+        assert!(
+            !screen.contains(filtered_out),
+            "'{filtered_out}' doesn't match the query. Screen:\n{screen}"
+        );
+    }
+
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| h.screen_to_string().contains("Ln 3, Col 10"))?;
+    harness.assert_buffer_content(OTHER_FILE_CONTENT);
+
+    Ok(())
+}
@@ -0,0 +1,238 @@
+//! E2E tests for the outline plugin
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+/// Fake server answering `documentSymbol` with a hierarchical tree for
+/// `TEST_FILE_CONTENT`: `mod outer` holding `struct Inner` and an impl
+/// with `method`, plus a top-level `top_level` function.
+const FAKE_LSP_SCRIPT: &str = r#"#!/bin/bash
+read_message() {
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}
+send_message() {
+    local message="$1"
+    local length=${#message}
+    echo -en "Content-Length: $length\r\n\r\n$message"
+}
+# sym NAME KIND START_LINE END_LINE NAME_LINE NAME_CHAR CHILDREN
+sym() {
+    echo '{"name":"'$1'","kind":'$2',"range":{"start":{"line":'$3',"character":0},"end":{"line":'$4',"character":1}},"selectionRange":{"start":{"line":'$5',"character":'$6'},"end":{"line":'$5',"character":'$(($6 + ${#1}))'}},"children":['$7']}'
+}
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then
+        break
+    fi
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+    case "$method" in
+        "initialize")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"documentSymbolProvider":true,"textDocumentSync":1}}}'
+            ;;
+        "initialized") ;;
+        "textDocument/didOpen"|"textDocument/didChange"|"textDocument/didSave") ;;
+        "textDocument/documentSymbol")
+            method_sym=$(sym method 6 4 6 4 11 '')
+            impl_sym=$(sym Inner 19 3 7 3 9 "$method_sym")
+            struct_sym=$(sym Inner 23 1 1 1 15 '')
+            outer=$(sym outer 2 0 8 0 4 "$struct_sym,$impl_sym")
+            top=$(sym top_level 12 10 10 10 3 '')
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":['"$outer,$top"']}'
+            ;;
+        "shutdown")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            break
+            ;;
+    esac
+done
+"#;
+
+const TEST_FILE_CONTENT: &str = "mod outer {
+    pub struct Inner;
+
+    impl Inner {
+        pub fn method(&self) {
+            let x = 1;
+        }
+    }
+}
+
+fn top_level() {}
+";
+
+fn setup_outline_test() -> anyhow::Result<(EditorTestHarness, tempfile::TempDir)> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_root = temp_dir.path().to_path_buf();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir)?;
+    copy_plugin(&plugins_dir, "outline");
+    copy_plugin_lib(&plugins_dir);
+
+    let test_file = project_root.join("test.rs");
+    fs::write(&test_file, TEST_FILE_CONTENT)?;
+
+    let script_path = project_root.join("fake_lsp.sh");
+    fs::write(&script_path, FAKE_LSP_SCRIPT)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&script_path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms)?;
+    }
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: Some(vec![]),
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, config, project_root)?;
+
+    harness.open_file(&test_file)?;
+    harness.process_async_and_render()?;
+    harness.wait_until(|h| h.screen_to_string().contains("LSP (on)"))?;
+
+    Ok((harness, temp_dir))
+}
+
+fn toggle_outline(harness: &mut EditorTestHarness) -> anyhow::Result<()> {
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.process_async_and_render()?;
+    harness.type_text("Toggle Outline")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_until(|h| h.screen_to_string().contains("[fn] top_level"))?;
+    Ok(())
+}
+
+/// The screen line showing the outline row `label`.
+fn outline_row(screen: &str, label: &str) -> String {
+    screen
+        .lines()
+        .find(|line| line.contains(label))
+        .unwrap_or_else(|| panic!("No outline row '{label}'. Screen:\n{screen}"))
+        .to_string()
+}
+
+/// The nested `documentSymbol` response becomes an indented tree, and
+/// moving the cursor into `method` highlights that node — not its
+/// enclosing impl or module.
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_outline_builds_tree_and_follows_cursor() -> anyhow::Result<()> {
+    let (mut harness, _temp_dir) = setup_outline_test()?;
+    toggle_outline(&mut harness)?;
+
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("Outline: test.rs"),
+        "Sidebar title should name the file. Screen:\n{screen}"
+    );
+
+    // Tree order and nesting: children follow their parent, one level
+    // deeper.
+    let rows = [
+        "▾ [mod] outer",
+        "  [struct] Inner",
+        "▾ [obj] Inner",
+        "  [method] method",
+        "  [fn] top_level",
+    ];
+    let mut last = 0;
+    for label in rows {
+        let pos = screen
+            .find(label)
+            .unwrap_or_else(|| panic!("Missing row '{label}'. Screen:\n{screen}"));
+        assert!(pos > last, "Row '{label}' out of order. Screen:\n{screen}");
+        last = pos;
+    }
+    // The sidebar starts at the same column on every line, so the kind
+    // tag's column measures the row's indent.
+    let indent = |label: &str| {
+        outline_row(&screen, label)
+            .chars()
+            .position(|c| c == '[')
+            .unwrap()
+    };
+    assert!(
+        indent("[mod] outer") < indent("[obj] Inner")
+            && indent("[obj] Inner") < indent("[method] method"),
+        "Nested rows should be indented deeper. Screen:\n{screen}"
+    );
+
+    // The cursor starts on line 1, inside `mod outer`.
+    assert!(
+        outline_row(&screen, "[mod] outer").contains('›'),
+        "Enclosing module should be highlighted. Screen:\n{screen}"
+    );
+
+    // Move into the body of `method` (line 6).
+    for _ in 0..5 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    }
+    harness.wait_until(|h| outline_row(&h.screen_to_string(), "[method] method").contains('›'))?;
+
+    let screen = harness.screen_to_string();
+    for label in [
+        "[mod] outer",
+        "[obj] Inner",
+        "[struct] Inner",
+        "[fn] top_level",
+    ] {
+        assert!(
+            !outline_row(&screen, label).contains('›'),
+            "Only the innermost symbol should be highlighted, not '{label}'. Screen:\n{screen}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Clicking a row jumps to the symbol's name in the source.
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_outline_click_jumps_to_symbol() -> anyhow::Result<()> {
+    let (mut harness, _temp_dir) = setup_outline_test()?;
+    toggle_outline(&mut harness)?;
+
+    let (col, row) = harness
+        .find_text_on_screen("[fn] top_level")
+        .expect("top_level row should be visible");
+    harness.mouse_click(col, row)?;
+
+    // `top_level`'s selectionRange starts at line 10, character 3.
+    harness.wait_until(|h| h.screen_to_string().contains("Ln 11, Col 4"))?;
+
+    Ok(())
+}
@@ -29,8 +29,11 @@ pub mod devcontainer_spec_repros;
 pub mod devcontainer_usability_repros;
 pub mod diagnostics_panel_bugs;
 pub mod diagnostics_panel_jump;
+pub mod diagnostics_panel_order;
+pub mod diagnostics_panel_workspace;
 pub mod diff_cursor;
 pub mod env_manager;
+pub mod execute_action;
 pub mod file_explorer_slots;
 pub mod find_file;
 pub mod git;
@@ -44,8 +47,10 @@ pub mod init_script;
 pub mod language_pack;
 pub mod live_diff;
 pub mod load_from_buffer;
+pub mod lsp_call_hierarchy;
 pub mod lsp_find_references;
 pub mod lsp_navigation;
+pub mod lsp_outline;
 pub mod markdown_source;
 pub mod orchestrator_attach_worktree;
 pub mod orchestrator_new_dialog;
@@ -54,7 +59,11 @@ pub mod orchestrator_open_cross_project;
 pub mod package_manager;
 pub mod plugin;
 pub mod plugin_config_registration;
+pub mod plugin_delay;
 pub mod plugin_keybinding_execution;
+pub mod plugin_rendered_region;
+pub mod plugin_spawn_process;
+pub mod plugin_timers;
 pub mod plugins_dir_in_working_dir;
 pub mod review_diff_hunk_parity;
 pub mod review_diff_line_staging;
@@ -62,6 +71,7 @@ pub mod review_diff_ux_bugs;
 pub mod set_split_ratio_leaf;
 pub mod tab_actions;
 pub mod terminal_hooks;
+pub mod text_objects;
 pub mod theme_editor;
 pub mod trust_lockdown;
 pub mod unified_keybindings;
@@ -9,6 +9,7 @@ use fresh::config::Config;
 use fresh::services::lsp::LspServerConfig;
 use fresh::services::process_limits::ProcessLimits;
 use fresh::types::LspLanguageConfig;
+use ratatui::style::Color;
 use std::fs;
 use std::time::Duration;
 
@@ -194,8 +195,17 @@ editor.setStatus("Overlay test plugin loaded");
     let overlays = harness.editor().active_state().overlays.all();
     assert!(!overlays.is_empty(), "Expected at least one overlay");
 
-    let screen = harness.screen_to_string();
-    println!("Screen after overlay:\n{}", screen);
+    // The overlay covers "TEST" and is drawn in red; the text after it is not
+    harness.render().unwrap();
+    let (x, y) = harness
+        .find_text_on_screen("TEST content")
+        .expect("test file content should be visible");
+    for dx in 0..4 {
+        let fg = harness.get_cell_style(x + dx, y).and_then(|s| s.fg);
+        assert_eq!(fg, Some(Color::Rgb(255, 0, 0)), "cell {} of TEST", dx);
+    }
+    let after = harness.get_cell_style(x + 5, y).and_then(|s| s.fg);
+    assert_ne!(after, Some(Color::Rgb(255, 0, 0)));
 }
 
 /// Test plugin render-line hook receives correct arguments
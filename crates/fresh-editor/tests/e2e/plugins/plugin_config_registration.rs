@@ -279,6 +279,7 @@ fn plugin_config_category_hidden_when_plugin_disabled() {
             enabled: false,
             path: None,
             settings: serde_json::Value::Null,
+            permissions: Default::default(),
         },
     );
 
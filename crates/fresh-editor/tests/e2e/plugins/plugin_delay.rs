@@ -0,0 +1,52 @@
+//! E2E tests for `editor.delay(ms)`: overlapping delays resolve in deadline
+//! order, and the editor keeps handling input and rendering while one is
+//! pending.
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use std::fs;
+
+#[test]
+fn test_overlapping_delays_resolve_in_deadline_order() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    // The longer delay is started first, so a delay that blocked (or
+    // resolved in request order) would report "slow,fast".
+    let test_plugin = r#"
+const editor = getEditor();
+const resolved: string[] = [];
+
+async function after(ms: number, label: string): Promise<void> {
+    await editor.delay(ms);
+    resolved.push(label);
+    editor.setStatus(`delays resolved: ${resolved.join(",")}`);
+}
+
+after(1000, "slow");
+after(50, "fast");
+"#;
+    fs::write(plugins_dir.join("test_delay.ts"), test_plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    harness
+        .wait_for_screen_contains("delays resolved: fast")
+        .unwrap();
+
+    // Input is handled and drawn while the slow delay may still be pending.
+    harness.type_text("typed while waiting").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("typed while waiting");
+
+    harness
+        .wait_for_screen_contains("delays resolved: fast,slow")
+        .unwrap();
+}
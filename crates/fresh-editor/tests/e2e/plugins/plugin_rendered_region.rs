@@ -0,0 +1,51 @@
+//! E2E test for `editor.getRenderedRegion()`: a plugin reads back the
+//! rendered screen and finds buffer text where the terminal shows it.
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use std::fs;
+
+#[test]
+fn test_plugin_reads_rendered_region() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    // Ask for more than the screen so the reply is clipped to 80x24, and
+    // poll until the buffer text has been drawn.
+    let test_plugin = r#"
+const editor = getEditor();
+
+async function probe(): Promise<void> {
+    for (;;) {
+        const region = await editor.getRenderedRegion(0, 0, 200, 100);
+        for (let row = 0; row < region.cells.length; row++) {
+            const cells = region.cells[row];
+            const col = cells.findIndex((_, x) =>
+                cells.slice(x, x + 5).map((cell) => cell.symbol).join("") === "hello");
+            if (col >= 0) {
+                editor.setStatus(
+                    `found hello at ${col},${row} in ${region.cells[0].length}x${region.lines.length}`);
+                return;
+            }
+        }
+        await editor.delay(20);
+    }
+}
+
+probe();
+"#;
+    fs::write(plugins_dir.join("test_rendered_region.ts"), test_plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "hello region\n").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    harness.wait_for_screen_contains("found hello at").unwrap();
+    let (x, y) = harness.find_text_on_screen("hello region").unwrap();
+    harness.assert_screen_contains(&format!("found hello at {},{} in 80x24", x, y));
+}
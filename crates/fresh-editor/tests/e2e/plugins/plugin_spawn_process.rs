@@ -0,0 +1,71 @@
+//! E2E tests for `editor.spawnProcess`: output is captured in full, a
+//! failing command still resolves with its exit code, and `kill()` stops a
+//! running child.
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use std::fs;
+
+/// Harness with `plugin` installed and a file open so plugins are loaded.
+fn harness_with_plugin(plugin: &str) -> (EditorTestHarness, tempfile::TempDir, TestFixture) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    fs::write(plugins_dir.join("test_spawn.ts"), plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    (harness, temp_dir, fixture)
+}
+
+#[cfg(unix)]
+#[test]
+fn test_spawn_process_captures_large_output_and_nonzero_exit() {
+    // ~590 KB on stdout, well past any pipe buffer, then a failing exit.
+    let plugin = r#"
+const editor = getEditor();
+
+(async () => {
+    const result = await editor.spawnProcess("sh", [
+        "-c",
+        "seq 1 100000; echo oops >&2; exit 3",
+    ]);
+    const lines = result.stdout.trim().split("\n");
+    editor.setStatus(
+        `spawned: lines=${lines.length} last=${lines[lines.length - 1]} ` +
+            `stderr=${result.stderr.trim()} exit=${result.exit_code}`,
+    );
+})().catch((e) => editor.setStatus(`spawn rejected: ${e}`));
+"#;
+    let (mut harness, _temp_dir, _fixture) = harness_with_plugin(plugin);
+
+    harness
+        .wait_for_screen_contains("spawned: lines=100000 last=100000 stderr=oops exit=3")
+        .unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_spawn_process_kill_stops_the_child() {
+    let plugin = r#"
+const editor = getEditor();
+
+(async () => {
+    const handle = editor.spawnProcess("sleep", ["30"]);
+    await editor.delay(100);
+    handle.kill();
+    const result = await handle;
+    editor.setStatus(`killed: exit_nonzero=${result.exit_code !== 0}`);
+})().catch((e) => editor.setStatus(`spawn rejected: ${e}`));
+"#;
+    let (mut harness, _temp_dir, _fixture) = harness_with_plugin(plugin);
+
+    harness
+        .wait_for_screen_contains("killed: exit_nonzero=true")
+        .unwrap();
+}
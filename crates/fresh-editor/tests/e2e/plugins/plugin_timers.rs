@@ -0,0 +1,39 @@
+//! E2E tests for plugin `setTimeout` / `setInterval`: callbacks run from the
+//! plugin event loop without any other editor activity to drive them.
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use std::fs;
+
+#[test]
+fn test_set_timeout_and_interval_fire_while_idle() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+let ticks = 0;
+
+const interval = setInterval(() => {
+    ticks += 1;
+    if (ticks === 3) {
+        clearInterval(interval);
+        setTimeout(() => editor.setStatus(`timers done: ticks=${ticks}`), 50);
+    }
+}, 20);
+"#;
+    fs::write(plugins_dir.join("test_timers.ts"), test_plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+
+    harness
+        .wait_for_screen_contains("timers done: ticks=3")
+        .unwrap();
+}
@@ -0,0 +1,69 @@
+//! E2E tests for plugin-defined text objects (`editor.registerTextObject`).
+
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness, HarnessOptions};
+use crate::common::tracing::init_tracing_from_env;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+
+/// A plugin text object resolves through its (async) handler and is usable
+/// after an operator like the built-in ones: `din` deletes the number.
+#[test]
+fn test_plugin_text_object_with_delete_operator() {
+    init_tracing_from_env();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+// `n`: the run of digits under the cursor.
+globalThis.number_text_object = async function(ctx: { bufferId: number; position: number; around: boolean }) {
+    const length = editor.getBufferLength(ctx.bufferId);
+    const text = await editor.getBufferText(ctx.bufferId, 0, length);
+    const isDigit = (i: number) => i >= 0 && i < text.length && text[i] >= "0" && text[i] <= "9";
+    if (!isDigit(ctx.position)) {
+        return null;
+    }
+    let start = ctx.position;
+    while (isDigit(start - 1)) start--;
+    let end = ctx.position;
+    while (isDigit(end)) end++;
+    return { start, end };
+};
+
+editor.registerTextObject("n", "number_text_object");
+editor.setStatus("numobj ready");
+"#;
+    fs::write(plugins_dir.join("number_object.ts"), test_plugin).unwrap();
+
+    let config = Config {
+        active_keybinding_map: "vim".into(),
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_root)
+            .without_empty_plugins_dir()
+            .with_preserved_keybinding_map(),
+    )
+    .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("numobj ready"))
+        .unwrap();
+
+    harness.type_text("i").unwrap();
+    harness.type_text("let x = 1234 + 5;").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    harness.type_text("010ldin").unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("let x =  + 5;"))
+        .unwrap();
+}
@@ -1,9 +1,9 @@
 //! Security regression: a plugin must not be able to *set* the workspace
 //! trust level. Plugins may request the trust prompt (the user decides), but
 //! the level-setting actions (`workspace_trust_trust` / `_restrict` / `_block`)
-//! are denied when dispatched through the generic `executeActions` channel —
+//! are denied when dispatched through the generic `executeAction(s)` channels —
 //! matching the VS Code / JetBrains / Zed model where extensions can ask but
-//! never grant. See `handle_execute_actions` in `plugin_dispatch.rs`.
+//! never grant. See `is_plugin_forbidden_action` in `plugin_dispatch.rs`.
 
 use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -39,6 +39,7 @@ fn build_harness(auto_start: bool) -> (EditorTestHarness, tempfile::TempDir) {
             enabled: true,
             path: None,
             settings: serde_json::json!({ "autoStart": auto_start }),
+            permissions: Default::default(),
         },
     );
 
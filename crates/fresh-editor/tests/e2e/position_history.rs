@@ -1,5 +1,6 @@
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
 
 /// Test basic back/forward navigation within a single buffer
 #[test]
@@ -250,3 +251,46 @@ fn test_position_history_with_next_prev_buffer() {
     harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
     harness.assert_buffer_content("Third");
 }
+
+/// GotoLastEdit follows edits rather than navigation: it returns to the
+/// most recent edit in another buffer, then walks back to the older one.
+#[test]
+fn test_goto_last_edit_across_buffers() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    // Buffer 1: edit, then move away from the edit
+    harness.type_text("First buffer edit").unwrap();
+    let buffer1_edit = harness.cursor_position();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+
+    // Buffer 2: edit, then go back to buffer 1 by plain navigation
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Second").unwrap();
+    let buffer2_edit = harness.cursor_position();
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("First buffer edit");
+
+    // Most recent edit: buffer 2
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoLastEdit);
+    harness.assert_buffer_content("Second");
+    assert_eq!(harness.cursor_position(), buffer2_edit);
+
+    // Repeating walks back to the edit in buffer 1
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoLastEdit);
+    harness.assert_buffer_content("First buffer edit");
+    assert_eq!(harness.cursor_position(), buffer1_edit);
+
+    // ...and wraps around to the newest again
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoLastEdit);
+    harness.assert_buffer_content("Second");
+}
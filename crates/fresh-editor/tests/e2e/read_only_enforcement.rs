@@ -111,7 +111,9 @@ fn test_sort_lines_on_readonly_buffer_does_not_modify() {
 
     // Select all so SortLines has a range to operate on, then invoke it.
     harness.api_mut().dispatch(Action::SelectAll);
-    harness.api_mut().dispatch(Action::SortLines);
+    harness
+        .api_mut()
+        .dispatch(Action::SortLines(Default::default()));
     harness.render().unwrap();
 
     assert_eq!(
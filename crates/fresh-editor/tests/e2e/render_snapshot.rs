@@ -0,0 +1,44 @@
+//! `render_snapshot` renders with deterministic chrome, so golden-file
+//! comparisons don't depend on the time of day or spinner phase.
+
+use crate::common::harness::EditorTestHarness;
+use fresh::config::{Config, StatusBarConfig, StatusBarElement};
+use ratatui::style::Modifier;
+
+fn config_with_clock() -> Config {
+    let mut config = Config::default();
+    config.editor.status_bar = StatusBarConfig {
+        left: vec![StatusBarElement::Filename, StatusBarElement::Cursor],
+        right: vec![StatusBarElement::Clock],
+        ..StatusBarConfig::default()
+    };
+    config
+}
+
+#[test]
+fn test_render_snapshot_is_stable() {
+    let mut harness = EditorTestHarness::with_config(80, 12, config_with_clock()).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("fn main() {\n    println!(\"hi\");\n}\n")
+        .unwrap();
+
+    let first = harness.render_snapshot().unwrap();
+    let second = harness.render_snapshot().unwrap();
+
+    assert_eq!(first, second);
+    assert!(first.contains("println!(\"hi\");"), "snapshot:\n{first}");
+}
+
+#[test]
+fn test_render_snapshot_freezes_the_clock() {
+    let mut harness = EditorTestHarness::with_config(80, 12, config_with_clock()).unwrap();
+    let snapshot = harness.render_snapshot().unwrap();
+
+    let status = harness.get_status_bar();
+    assert!(status.contains("00:00"), "status bar: {status}");
+    assert!(snapshot.contains("00:00"));
+
+    let (x, y) = harness.find_text_on_screen("00:00").unwrap();
+    let colon = harness.get_cell_style(x + 2, y).unwrap();
+    assert!(!colon.add_modifier.contains(Modifier::SLOW_BLINK));
+}
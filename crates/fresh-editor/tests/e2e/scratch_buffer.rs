@@ -0,0 +1,42 @@
+//! `OpenScratchBuffer` opens numbered, file-less `*scratch*` buffers for
+//! quick notes, optionally highlighted as a chosen language.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use fresh::input::keybindings::Action;
+
+#[test]
+fn test_scratch_buffers_are_numbered_and_take_a_language() {
+    let mut harness = EditorTestHarness::create(
+        100,
+        24,
+        HarnessOptions::new()
+            .with_project_root()
+            .with_full_grammar_registry(),
+    )
+    .unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::OpenScratchBuffer(String::new()));
+    harness.render().unwrap();
+    harness.assert_screen_contains("*scratch*");
+    assert_eq!(harness.editor().active_state().language, "text");
+
+    // A second scratch buffer gets the next number and the requested syntax
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::OpenScratchBuffer("Rust".to_string()));
+    harness.type_text("fn main() {}").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("*scratch 2*");
+    assert_eq!(harness.editor().active_state().language, "rust");
+    assert!(harness.editor().active_state().buffer.file_path().is_none());
+
+    // Closing a scratch buffer with content asks first instead of dropping it
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Close);
+    harness.render().unwrap();
+    assert!(harness.editor().active_window().prompt.is_some());
+    harness.assert_buffer_content("fn main() {}");
+}
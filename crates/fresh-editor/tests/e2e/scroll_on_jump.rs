@@ -0,0 +1,138 @@
+//! E2E tests for the `scroll_on_jump` setting: where an off-screen jump
+//! target lands in the viewport (centered, at the top, or scrolled into
+//! view minimally), and for `Editor::reveal_range`, which the jump paths
+//! share.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, ScrollOnJump};
+
+/// Line (0-indexed) of the match the search jumps to.
+const TARGET_LINE: usize = 60;
+
+/// Open a 100-line file with `scroll_on_jump` set to `alignment`, search
+/// for a needle on line 2 and `TARGET_LINE`, then Find Next to the
+/// off-screen match and return the harness after the jump.
+fn jump_with(alignment: ScrollOnJump) -> EditorTestHarness {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+
+    let mut content = String::new();
+    for i in 0..100 {
+        if i == 2 || i == TARGET_LINE {
+            content.push_str(&format!("line {} NEEDLE here\n", i));
+        } else {
+            content.push_str(&format!("line {} filler text\n", i));
+        }
+    }
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.scroll_on_jump = alignment;
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text("NEEDLE").unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+    assert_eq!(harness.top_line_number(), 0, "first match is on screen");
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.process_async_and_render().unwrap();
+    harness
+}
+
+#[test]
+fn test_scroll_on_jump_center() {
+    let mut harness = jump_with(ScrollOnJump::Center);
+    let viewport_height = harness.viewport_height();
+    assert_eq!(
+        harness.top_line_number(),
+        TARGET_LINE - viewport_height / 2,
+        "center should put the match mid-viewport"
+    );
+}
+
+#[test]
+fn test_scroll_on_jump_top() {
+    let mut harness = jump_with(ScrollOnJump::Top);
+    assert_eq!(
+        harness.top_line_number(),
+        TARGET_LINE,
+        "top should put the match on the first row"
+    );
+}
+
+#[test]
+fn test_scroll_on_jump_minimal() {
+    let mut harness = jump_with(ScrollOnJump::Minimal);
+    let viewport_height = harness.viewport_height();
+    let scroll_offset = Config::default().editor.scroll_offset;
+    // Just far enough that the match sits `scroll_offset` rows above the
+    // bottom edge, like moving the cursor down to it.
+    assert_eq!(
+        harness.top_line_number(),
+        TARGET_LINE + scroll_offset + 1 - viewport_height,
+        "minimal should scroll only until the match is in view"
+    );
+}
+
+/// A range far below the viewport and deep inside a long line needs both a
+/// vertical and a horizontal scroll; both of its ends must end up on screen.
+#[test]
+fn test_reveal_range_scrolls_vertically_and_horizontally() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+
+    let mut content = String::new();
+    for i in 0..100 {
+        if i == TARGET_LINE {
+            content.push_str(&format!("{}TARGET_RANGE_END\n", "x".repeat(200)));
+        } else {
+            content.push_str(&format!("line {} filler text\n", i));
+        }
+    }
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let line_start = content
+        .lines()
+        .take(TARGET_LINE)
+        .map(|line| line.len() + 1)
+        .sum::<usize>();
+    let range = line_start + 190..line_start + 216;
+    let buffer_id = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .reveal_range(buffer_id, range, ScrollOnJump::Center);
+    harness.render().unwrap();
+
+    let viewport_height = harness.viewport_height();
+    let top_line = harness.top_line_number();
+    assert!(
+        top_line <= TARGET_LINE && TARGET_LINE < top_line + viewport_height,
+        "range line should be in view; top_line={top_line}"
+    );
+    assert!(
+        harness.editor().active_viewport().left_column > 0,
+        "range should have scrolled horizontally"
+    );
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("xxxTARGET_RANGE_END"),
+        "both ends of the range should be visible; screen was:\n{screen}"
+    );
+}
@@ -0,0 +1,98 @@
+//! `SetSearchScope` confines find and replace to the selection it was set
+//! from until `ClearSearchScope` lifts it again.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+const CONTENT: &str = "0 NEEDLE\n1 NEEDLE\n2 NEEDLE\n3 NEEDLE\n4 NEEDLE\n";
+
+fn nth_match(n: usize) -> usize {
+    CONTENT.match_indices("NEEDLE").nth(n).unwrap().0
+}
+
+/// Open CONTENT and make lines 1-2 the search scope.
+fn open_with_scope_on_lines_1_and_2(harness: &mut EditorTestHarness) -> tempfile::TempDir {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, CONTENT).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetSearchScope);
+    harness.render().unwrap();
+    harness.assert_screen_contains("Search scope set");
+    temp_dir
+}
+
+fn search(harness: &mut EditorTestHarness, query: &str) {
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(query).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+}
+
+#[test]
+fn test_find_next_skips_matches_outside_search_scope() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = open_with_scope_on_lines_1_and_2(&mut harness);
+
+    search(&mut harness, "NEEDLE");
+    harness.assert_screen_contains("Found 2 matches for 'NEEDLE' in selection");
+    assert_eq!(harness.cursor_position(), nth_match(1));
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(2));
+
+    // Lines 3 and 4 are outside the scope: the search stops at line 2
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(2));
+    harness.assert_screen_contains("No more matches.");
+
+    // Clearing the scope re-runs the search over the whole buffer
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ClearSearchScope);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(3));
+}
+
+#[test]
+fn test_replace_all_stays_inside_search_scope() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _temp_dir = open_with_scope_on_lines_1_and_2(&mut harness);
+
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("NEEDLE").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("pin").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "0 NEEDLE\n1 pin\n2 pin\n3 NEEDLE\n4 NEEDLE\n"
+    );
+}
@@ -0,0 +1,90 @@
+//! Find Next at the last match wraps to the first one only while
+//! `editor.search_wrap` is on; with it off the search stops there.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::input::keybindings::Action;
+
+const CONTENT: &str = "one NEEDLE\ntwo NEEDLE\nthree NEEDLE\n";
+
+fn nth_match(n: usize) -> usize {
+    CONTENT.match_indices("NEEDLE").nth(n).unwrap().0
+}
+
+/// Search for NEEDLE and step with F3 to the last of its three matches.
+fn search_to_last_match(harness: &mut EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, CONTENT).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("NEEDLE").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(0));
+
+    for _ in 0..2 {
+        harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(2));
+    harness.assert_screen_contains("Match 3 of 3");
+}
+
+#[test]
+fn test_find_next_wraps_to_first_match_by_default() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    search_to_last_match(&mut harness);
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.cursor_position(), nth_match(0));
+    harness.assert_screen_contains("Search wrapped to top: match 1 of 3");
+}
+
+#[test]
+fn test_find_next_stops_at_last_match_without_wrap() {
+    let mut config = Config::default();
+    config.editor.search_wrap = false;
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    search_to_last_match(&mut harness);
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.cursor_position(), nth_match(2));
+    harness.assert_screen_contains("No more matches.");
+}
+
+/// Toggling wrap applies to the search that is already active.
+#[test]
+fn test_toggle_search_wrap_applies_to_active_search() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    search_to_last_match(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleSearchWrap);
+    harness.render().unwrap();
+    harness.assert_screen_contains("Wrap-around search disabled");
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(2));
+    harness.assert_screen_contains("No more matches.");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleSearchWrap);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), nth_match(0));
+}
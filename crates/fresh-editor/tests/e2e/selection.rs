@@ -2347,3 +2347,55 @@ fn test_select_word_with_accented_characters() {
         "Ctrl+W should select the entire word including accented characters"
     );
 }
+
+/// Select the first two lines of a three-line buffer and return the
+/// background of a cell well past the end of each line's text.
+fn trailing_bg_of_multi_line_selection(
+    selection_full_line: bool,
+) -> (Vec<Option<ratatui::style::Color>>, ratatui::style::Color) {
+    let mut config = fresh::config::Config::default();
+    config.editor.selection_full_line = selection_full_line;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text("one\ntwo\nthree\n").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let trailing_bgs = (0..3)
+        .map(|row| {
+            harness
+                .get_cell_style(40, content_first_row as u16 + row)
+                .and_then(|style| style.bg)
+        })
+        .collect();
+    let selection_bg = harness.editor().theme().selection_bg;
+    (trailing_bgs, selection_bg)
+}
+
+/// `editor.selection_full_line` carries the selection background to the
+/// right edge on lines whose newline is selected; by default it stops at
+/// the text.
+#[test]
+fn test_selection_full_line_fills_trailing_cells() {
+    let (bgs, selection_bg) = trailing_bg_of_multi_line_selection(false);
+    assert!(
+        bgs.iter().all(|bg| *bg != Some(selection_bg)),
+        "selection should stop at the line content by default, got {bgs:?}"
+    );
+
+    let (bgs, selection_bg) = trailing_bg_of_multi_line_selection(true);
+    assert_eq!(bgs[0], Some(selection_bg));
+    assert_eq!(bgs[1], Some(selection_bg));
+    assert_ne!(
+        bgs[2],
+        Some(selection_bg),
+        "the cursor line's newline is not selected"
+    );
+}
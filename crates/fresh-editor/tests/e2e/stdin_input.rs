@@ -255,3 +255,62 @@ fn test_stdin_large_file_lazy_loading() {
     harness.render().unwrap();
     harness.assert_screen_contains("[stdin]");
 }
+
+/// Saving the stdin buffer keeps its edited bytes for stdout instead of
+/// prompting for a file name.
+#[test]
+fn test_save_stdin_buffer_goes_to_stdout() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    let temp_file = create_stdin_temp_file("piped line\n");
+    harness
+        .editor_mut()
+        .open_stdin_buffer(temp_file.path(), None)
+        .unwrap();
+    harness.assert_buffer_content("piped line\n");
+
+    harness.type_text("edited ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(
+        harness.editor().active_window().prompt.is_none(),
+        "Saving stdin should not ask for a file name"
+    );
+    harness.assert_screen_contains("written to stdout on exit");
+    assert_eq!(
+        harness.editor_mut().take_stdout_output(),
+        Some(b"edited piped line\n".to_vec())
+    );
+}
+
+/// With `--output PATH`, saving the stdin buffer writes that file and
+/// nothing is left for stdout.
+#[test]
+fn test_save_stdin_buffer_to_output_path() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("out.txt");
+    harness.editor_mut().set_stdin_output_path(out_path.clone());
+    let temp_file = create_stdin_temp_file("piped line\n");
+    harness
+        .editor_mut()
+        .open_stdin_buffer(temp_file.path(), None)
+        .unwrap();
+
+    harness.type_text("edited ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&out_path).unwrap(),
+        "edited piped line\n"
+    );
+    assert_eq!(harness.editor_mut().take_stdout_output(), None);
+}
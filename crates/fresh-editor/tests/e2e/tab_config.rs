@@ -1,6 +1,7 @@
 //! Tests for per-language tab configuration options:
 //! - `show_whitespace_tabs`: Whether to display tab indicators (→) in the editor
 //! - `use_tabs`: Whether pressing Tab inserts a tab character or spaces
+//! - `tab_display_width`: How many columns a tab character is drawn as
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -73,6 +74,44 @@ fn test_issue_1997_adjacent_tabs_no_doubled_arrow() {
     );
 }
 
+/// `tab_display_width` sets how wide a tab is drawn independently of
+/// `tab_size`: a leading tab pushes the next character (and the cursor
+/// after it) to column 8 even though the indent unit stays 4.
+#[test]
+fn test_tab_display_width_independent_of_tab_size() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "\tX").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 4;
+    config.editor.tab_display_width = Some(8);
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let line_with_x = screen
+        .lines()
+        .find(|line| line.contains('X'))
+        .expect("the marker 'X' must appear on screen");
+    let chars: Vec<char> = line_with_x.chars().collect();
+    let tab_col = chars.iter().position(|&c| c == '→').unwrap();
+    let x_col = chars.iter().position(|&c| c == 'X').unwrap();
+    assert_eq!(
+        x_col - tab_col,
+        8,
+        "character after a leading tab should sit at display column 8, got line: {line_with_x:?}"
+    );
+
+    // The cursor column follows the same width.
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    let (cursor_x, _) = harness.screen_cursor_position();
+    assert_eq!(cursor_x as usize, x_col + 1);
+}
+
 /// Test that tab characters in Go files do NOT show → indicator
 /// (Go convention is to use tabs for indentation, so we hide the indicators)
 #[test]
@@ -786,3 +786,64 @@ fn test_switch_keybinding_map_via_action_persists() {
         "Switching the keybinding style via the menu action must persist (issue #474)"
     );
 }
+
+/// Run a command by name from the command palette.
+fn run_palette_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Zen mode hides the menu bar, file explorer, tab bar and status bar,
+/// and toggling it off restores exactly the panels that were showing.
+#[test]
+fn test_toggle_zen_mode_hides_and_restores_panels() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.editor_mut().toggle_file_explorer();
+    harness.editor_mut().active_window_mut().focus_editor();
+    harness.render().unwrap();
+
+    assert!(harness.get_menu_bar().contains("File"));
+    harness.assert_screen_contains("[No Name]");
+    assert!(harness
+        .get_screen_row(layout::status_bar_row(24))
+        .contains("Ln"));
+    assert!(harness.editor().active_window().file_explorer_visible);
+
+    run_palette_command(&mut harness, "Toggle Zen Mode");
+
+    let window = harness.editor().active_window();
+    assert!(!window.menu_bar_visible);
+    assert!(!window.tab_bar_visible);
+    assert!(!window.status_bar_visible);
+    assert!(!window.file_explorer_visible);
+    assert!(
+        !harness.get_screen_row(0).contains("File"),
+        "menu bar should be hidden in zen mode"
+    );
+    harness.assert_screen_not_contains("[No Name]");
+    let status_bar_row = harness.get_screen_row(layout::status_bar_row(24));
+    assert!(
+        !status_bar_row.contains("Ln"),
+        "status bar should be hidden in zen mode. Got: {status_bar_row}"
+    );
+
+    run_palette_command(&mut harness, "Toggle Zen Mode");
+
+    let window = harness.editor().active_window();
+    assert!(window.menu_bar_visible);
+    assert!(window.tab_bar_visible);
+    assert!(window.status_bar_visible);
+    assert!(window.file_explorer_visible);
+    assert!(window.zen_mode_saved.is_none());
+    assert!(harness.get_menu_bar().contains("File"));
+    harness.assert_screen_contains("[No Name]");
+    assert!(harness
+        .get_screen_row(layout::status_bar_row(24))
+        .contains("Ln"));
+}
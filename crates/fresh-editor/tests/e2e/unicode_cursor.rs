@@ -1127,3 +1127,63 @@ fn test_left_arrow_at_long_position_file_loaded() {
          If this is 51, the bug is present: left arrow fell back to code point movement."
     );
 }
+
+/// Test that Left/Right step over a flag emoji (two regional indicators)
+/// and a combining-accent sequence in one press each
+#[test]
+fn test_arrows_step_over_flag_and_combining_accent() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    // "a" + 🇯🇵 (2 × 4 bytes) + "e" + U+0301 (1 + 2 bytes) + "b"
+    harness.type_text("a\u{1F1EF}\u{1F1F5}e\u{301}b").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+
+    let mut positions = vec![harness.cursor_position()];
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+        positions.push(harness.cursor_position());
+    }
+    assert_eq!(positions, vec![0, 1, 9, 12, 13]);
+
+    let mut positions = Vec::new();
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+        positions.push(harness.cursor_position());
+    }
+    assert_eq!(positions, vec![12, 9, 1, 0]);
+}
+
+/// Test that backspace removes a whole flag or skin-tone emoji, unlike
+/// combining marks which it still removes one at a time
+#[test]
+fn test_backspace_deletes_emoji_sequence_as_unit() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness
+        .type_text("x\u{1F1EF}\u{1F1F5}\u{1F44D}\u{1F3FD}e\u{301}")
+        .unwrap();
+
+    // Combining accent: one layer at a time
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "x\u{1F1EF}\u{1F1F5}\u{1F44D}\u{1F3FD}e"
+    );
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    // Thumbs-up with skin tone, then the flag, each in one press
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "x\u{1F1EF}\u{1F1F5}");
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "x");
+}
@@ -1,7 +1,7 @@
 //! E2E tests for vertical rulers feature.
 //!
 //! Tests: rendering at correct columns, per-buffer/per-view independence,
-//! horizontal scroll behavior, and command palette add/remove.
+//! horizontal scroll behavior, command palette add/remove, and the wrap guide.
 //!
 //! Rulers are rendered as a background color tint (not a character), so tests
 //! check `get_cell_style().bg` rather than `get_cell()` for character content.
@@ -186,6 +186,46 @@ fn test_no_rulers_by_default() {
     }
 }
 
+/// `show_wrap_guide` draws a ruler at the wrap column while wrapping at a
+/// fixed column, and nothing when lines wrap at the viewport edge.
+#[test]
+fn test_wrap_guide_at_wrap_column() {
+    let mut config = Config::default();
+    config.editor.line_wrap = true;
+    config.editor.wrap_column = Some(30);
+    config.editor.show_wrap_guide = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text("short line").unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let row = content_first_row as u16;
+    assert!(
+        has_ruler_bg(&harness, gutter_width(&harness) + 30, row),
+        "Wrap guide should appear at the wrap column"
+    );
+    assert!(
+        !has_ruler_bg(&harness, gutter_width(&harness) + 20, row),
+        "Only the wrap column should be tinted"
+    );
+
+    let mut config = Config::default();
+    config.editor.line_wrap = true;
+    config.editor.show_wrap_guide = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let _fixture = harness.load_buffer_from_text("short line").unwrap();
+    harness.render().unwrap();
+
+    for col_offset in [10u16, 20, 30, 40] {
+        assert!(
+            !has_ruler_bg(&harness, gutter_width(&harness) + col_offset, row),
+            "No wrap guide without a wrap column, found one at {col_offset}"
+        );
+    }
+}
+
 /// Regression: virtual buffers (Dashboard, *Diagnostics*, grep results, ...)
 /// must not paint the config-driven column rulers. They aren't source code,
 /// and the ruler stripes would otherwise overlay plugin chrome.
@@ -44,6 +44,7 @@ fn vi_mode_harness_with_settings(
             enabled: true,
             path: None,
             settings,
+            permissions: Default::default(),
         },
     );
 
@@ -0,0 +1,256 @@
+//! End-to-end tests for the built-in modal `vim` keybinding map.
+//!
+//! Unlike the `vi_mode` plugin tests, these exercise the keymap shipped in
+//! `keymaps/vim.json`, which needs no plugin runtime.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+fn vim_harness() -> EditorTestHarness {
+    let config = Config {
+        active_keybinding_map: "vim".into(),
+        ..Default::default()
+    };
+    EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_preserved_keybinding_map(),
+    )
+    .unwrap()
+}
+
+/// Insert `text` from normal mode and return to normal mode.
+fn insert(harness: &mut EditorTestHarness, text: &str) {
+    harness.type_text("i").unwrap();
+    harness.type_text(text).unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
+#[test]
+fn test_vim_keymap_starts_in_normal_mode() {
+    let mut harness = vim_harness();
+
+    // Unbound letters are swallowed in normal mode rather than inserted.
+    harness.type_text("qz").unwrap();
+    harness.assert_buffer_content("");
+
+    insert(&mut harness, "hello");
+    harness.assert_buffer_content("hello");
+    harness.render().unwrap();
+    harness.assert_screen_contains("-- NORMAL --");
+
+    // Leaving insert mode steps back onto the last inserted character.
+    assert_eq!(harness.cursor_position(), 4);
+}
+
+#[test]
+fn test_vim_keymap_insert_mode_status() {
+    let mut harness = vim_harness();
+    harness.type_text("i").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("-- INSERT --");
+}
+
+#[test]
+fn test_vim_keymap_delete_word() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "foo bar");
+
+    harness.type_text("0dw").unwrap();
+    harness.assert_buffer_content("bar");
+}
+
+#[test]
+fn test_vim_keymap_count_delete_lines() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "one\ntwo\nthree");
+
+    harness.type_text("gg2dd").unwrap();
+    harness.assert_buffer_content("three");
+}
+
+#[test]
+fn test_vim_keymap_append_and_open_line() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "ab");
+
+    // `A` appends at end of line, `o` opens a new line below.
+    harness.type_text("0A").unwrap();
+    harness.type_text("c").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.type_text("o").unwrap();
+    harness.type_text("d").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    harness.assert_buffer_content("abc\nd");
+}
+
+#[test]
+fn test_vim_keymap_change_word() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "foo bar");
+
+    harness.type_text("0cw").unwrap();
+    harness.type_text("baz").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    harness.assert_buffer_content("baz bar");
+}
+
+#[test]
+fn test_vim_keymap_visual_delete() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "foo bar");
+
+    harness.type_text("0vw").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("-- VISUAL --");
+
+    harness.type_text("d").unwrap();
+    harness.assert_buffer_content("bar");
+
+    // Back in normal mode: `x` deletes instead of inserting.
+    harness.type_text("x").unwrap();
+    harness.assert_buffer_content("ar");
+}
+
+#[test]
+fn test_vim_keymap_operator_counts_multiply() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "a b c d e f g");
+
+    // Counts before the operator and before the motion compose: d2w, 2dw
+    // and 2d2w delete two, two and four words respectively.
+    harness.type_text("0d2w").unwrap();
+    harness.assert_buffer_content("c d e f g");
+    harness.type_text("2dw").unwrap();
+    harness.assert_buffer_content("e f g");
+    harness.type_text("u0").unwrap();
+    harness.type_text("2d2w").unwrap();
+    harness.assert_buffer_content("g");
+}
+
+#[test]
+fn test_vim_keymap_delete_linewise_motions() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "one\ntwo\nthree\nfour");
+
+    // `dj` takes the cursor's line and the one below it.
+    harness.type_text("ggdj").unwrap();
+    harness.assert_buffer_content("three\nfour");
+
+    // `dd` on the last line also removes the line break before it.
+    harness.type_text("Gdd").unwrap();
+    harness.assert_buffer_content("three");
+}
+
+#[test]
+fn test_vim_keymap_delete_paragraph_motion() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "one\ntwo\n\nthree");
+
+    // `d}` deletes up to the blank line that ends the paragraph.
+    harness.type_text("ggd}").unwrap();
+    harness.assert_buffer_content("\nthree");
+}
+
+#[test]
+fn test_vim_keymap_yank_and_change_motions() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "foo bar");
+
+    // `yw` copies without moving the text or leaving a selection.
+    harness.type_text("0yw").unwrap();
+    harness.assert_buffer_content("foo bar");
+    harness.type_text("$p").unwrap();
+    harness.assert_buffer_content("foo barfoo ");
+
+    // `c$` deletes to the end of the line and starts inserting.
+    harness.type_text("0wc$").unwrap();
+    harness.type_text("baz").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("foo baz");
+}
+
+#[test]
+fn test_vim_keymap_operator_cancelled_by_other_key() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "foo bar");
+
+    // Esc drops the pending `d`, so the following `w` is a plain motion.
+    harness.type_text("0d").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.type_text("w").unwrap();
+    harness.assert_buffer_content("foo bar");
+    assert_eq!(harness.cursor_position(), 4);
+}
+
+#[test]
+fn test_vim_keymap_text_objects_with_operators() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "call(a, \"old\") word");
+
+    // `ci"` replaces the string's contents.
+    harness.type_text("010l").unwrap();
+    harness.type_text("ci\"").unwrap();
+    harness.type_text("new").unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("call(a, \"new\") word");
+
+    // `da(` removes the whole argument list, parens included.
+    harness.type_text("di(").unwrap();
+    harness.assert_buffer_content("call() word");
+    harness.type_text("u06l").unwrap();
+    harness.type_text("da(").unwrap();
+    harness.assert_buffer_content("call word");
+
+    // `daw` takes the word and the space before it at end of line.
+    harness.type_text("$daw").unwrap();
+    harness.assert_buffer_content("call");
+}
+
+#[test]
+fn test_vim_keymap_visual_text_object() {
+    let mut harness = vim_harness();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    insert(&mut harness, "if x { a { b } c }");
+
+    // `vi{` on the inner block's content selects it; `d` deletes it.
+    harness.type_text("011lvi{d").unwrap();
+    harness.assert_buffer_content("if x { a {} c }");
+}
+
+#[test]
+fn test_vim_keymap_surround_add_and_change() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "foo bar");
+
+    // `viwS(` wraps the word under the cursor in parens.
+    harness.type_text("0wviwS(").unwrap();
+    harness.assert_buffer_content("foo (bar)");
+    harness.render().unwrap();
+    harness.assert_screen_contains("-- NORMAL --");
+
+    // `cs[` swaps the innermost surrounding pair for brackets.
+    harness.type_text("lcs[").unwrap();
+    harness.assert_buffer_content("foo [bar]");
+}
+
+#[test]
+fn test_vim_keymap_surround_delete_quotes() {
+    let mut harness = vim_harness();
+    insert(&mut harness, "say \"hi there\" now");
+
+    harness.type_text("06lds\"").unwrap();
+    harness.assert_buffer_content("say hi there now");
+}
@@ -0,0 +1,64 @@
+//! Virtual text placement: `BeforeChar` text sits just before its anchor
+//! character, `EndOfLine` text after the last character of its line.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::view::virtual_text::VirtualTextPosition;
+use ratatui::style::Style;
+
+fn add_virtual_text(
+    harness: &mut EditorTestHarness,
+    position: usize,
+    text: &str,
+    placement: VirtualTextPosition,
+) {
+    let state = harness.editor_mut().active_state_mut();
+    state.virtual_texts.add(
+        &mut state.marker_list,
+        position,
+        text.to_string(),
+        Style::default(),
+        placement,
+        0,
+    );
+}
+
+#[test]
+fn test_end_of_line_and_inline_before_placement() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("let x = 1;\nsecond\n")
+        .unwrap();
+
+    // Inline hint before `x`; end-of-line note anchored at the line start.
+    add_virtual_text(&mut harness, 4, "T:", VirtualTextPosition::BeforeChar);
+    add_virtual_text(&mut harness, 0, "END", VirtualTextPosition::EndOfLine);
+    harness.render().unwrap();
+
+    let (first_row, _) = harness.content_area_rows();
+    let row = harness.get_row_text(first_row as u16);
+    let expected = "let T: x = 1; END ";
+    assert!(
+        row.contains(expected),
+        "expected {:?} on the first row, got {:?}",
+        expected,
+        row
+    );
+
+    let next = harness.get_row_text(first_row as u16 + 1);
+    assert!(next.contains("second") && !next.contains("END"));
+}
+
+/// The end-of-line text follows the end of the line as it grows.
+#[test]
+fn test_end_of_line_text_follows_typing() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("abc\n").unwrap();
+    add_virtual_text(&mut harness, 0, "note", VirtualTextPosition::EndOfLine);
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text("def").unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("abcdef note");
+}
@@ -0,0 +1,93 @@
+//! `fresh --wait FILE` (for `$EDITOR` / `GIT_EDITOR`) keeps running until
+//! FILE is closed, then quits on its own.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::app::QUIT_WAIT_ID;
+use fresh::input::keybindings::Action;
+
+/// Editing and saving the waited-on file keeps the editor open; closing
+/// another buffer does too. Closing the waited-on file quits.
+#[test]
+fn test_wait_mode_quits_when_file_is_closed() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let message = project_dir.join("COMMIT_EDITMSG");
+    std::fs::write(&message, "\n# Please enter the commit message\n").unwrap();
+
+    // What `main` queues for `fresh --wait COMMIT_EDITMSG`
+    harness.editor_mut().queue_file_open(
+        message.clone(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(QUIT_WAIT_ID),
+    );
+    harness.editor_mut().process_pending_file_opens();
+    harness.render().unwrap();
+
+    harness.type_text("Fix the bug").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(
+        !harness.editor().should_quit(),
+        "Saving should not end a --wait session"
+    );
+
+    let notes = project_dir.join("notes.txt");
+    std::fs::write(&notes, "scratch\n").unwrap();
+    harness.open_file(&notes).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Close);
+    assert!(
+        !harness.editor().should_quit(),
+        "Closing another buffer should not end a --wait session"
+    );
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Close);
+    assert!(
+        harness.editor().should_quit(),
+        "Closing the waited-on file should quit the editor"
+    );
+    assert!(std::fs::read_to_string(&message)
+        .unwrap()
+        .starts_with("Fix the bug\n"));
+}
+
+/// Closing the waited-on file quits like any other quit: unsaved edits in
+/// another buffer get the save/discard prompt rather than being dropped.
+#[test]
+fn test_wait_mode_prompts_for_other_unsaved_buffers() {
+    let mut config = fresh::config::Config::default();
+    config.editor.hot_exit = false;
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let notes = project_dir.join("notes.txt");
+    std::fs::write(&notes, "scratch\n").unwrap();
+    harness.open_file(&notes).unwrap();
+    harness.type_text("unsaved ").unwrap();
+
+    let message = project_dir.join("COMMIT_EDITMSG");
+    std::fs::write(&message, "\n").unwrap();
+    harness
+        .editor_mut()
+        .queue_file_open(message, None, None, None, None, None, Some(QUIT_WAIT_ID));
+    harness.editor_mut().process_pending_file_opens();
+    harness.render().unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Close);
+    harness.render().unwrap();
+    assert!(
+        !harness.editor().should_quit(),
+        "unsaved edits elsewhere must not be dropped silently"
+    );
+    harness.assert_screen_contains("unsaved changes");
+}
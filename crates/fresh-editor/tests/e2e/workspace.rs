@@ -7,6 +7,7 @@ use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::Config;
 use fresh::config_io::DirectoryContext;
+use fresh::input::keybindings::Action;
 use fresh::workspace::get_workspace_path;
 use tempfile::TempDir;
 
@@ -128,6 +129,75 @@ fn test_session_restores_cursor_line() {
     }
 }
 
+/// Bookmarks are saved with their line's content, so a bookmark restored
+/// into a file that gained lines above it follows its line. The bookmarks
+/// panel lists it at the new line and Enter jumps there.
+#[test]
+fn test_session_restores_bookmark_on_shifted_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file = project_dir.join("numbered.txt");
+    std::fs::write(&file, "Line 01\nLine 02\nLine 03\nLine 04\nLine 05\n").unwrap();
+
+    // First session: bookmark Line 04
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+
+        harness.open_file(&file).unwrap();
+        for _ in 0..3 {
+            harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        }
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::SetBookmark('a'));
+        harness.editor_mut().save_workspace().unwrap();
+    }
+
+    // Two lines are added above the bookmark while the editor is closed
+    std::fs::write(
+        &file,
+        "New A\nNew B\nLine 01\nLine 02\nLine 03\nLine 04\nLine 05\n",
+    )
+    .unwrap();
+
+    // Second session: the bookmark now sits on line 6
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+
+        harness.editor_mut().try_restore_workspace().unwrap();
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::ListBookmarks);
+        harness.render().unwrap();
+
+        let screen = harness.screen_to_string();
+        assert!(
+            screen.contains("'a'  numbered.txt:6  Line 04"),
+            "Bookmarks panel should list the shifted line. Screen:\n{screen}"
+        );
+
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+        harness.assert_screen_contains("Ln 6, Col 1");
+    }
+}
+
 /// Test that session handles missing files gracefully
 #[test]
 fn test_session_handles_missing_files() {
@@ -2573,3 +2643,69 @@ fn test_restore_orphaned_active_unnamed_tab_renders_surviving_tab() {
         );
     }
 }
+
+/// Command palette ranking is saved with the project's workspace: a
+/// command used in one session ranks above an equally matching, never-used
+/// one after restore.
+#[test]
+fn test_session_restores_command_palette_ranking() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+
+    // First session: toggle line wrap on and off again, then save
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            100,
+            30,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+        run_command(&mut harness, "Toggle Line Wrap");
+        run_command(&mut harness, "Toggle Line Wrap");
+        harness.editor_mut().save_workspace().unwrap();
+    }
+
+    // Second session: after restore, line wrap ranks first
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            100,
+            30,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+        let restored = harness.editor_mut().try_restore_workspace().unwrap();
+        assert!(restored, "Session should have been restored");
+
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text("toggle line").unwrap();
+        harness.render().unwrap();
+
+        let row_of = |name: &str| {
+            harness
+                .find_text_on_screen(name)
+                .unwrap_or_else(|| panic!("'{name}' not listed"))
+                .1
+        };
+        assert!(
+            row_of("Toggle Line Wrap") < row_of("Toggle Line Numbers"),
+            "Used command should rank first. Screen:\n{}",
+            harness.screen_to_string()
+        );
+    }
+}
@@ -0,0 +1,26 @@
+/// <reference path="./lib/fresh.d.ts" />
+//
+// Tiny test plugin: drives the editor through `editor.executeAction`, one
+// action per call — three plain motions, then a payload action carrying its
+// argument — and reports the cursor position to the status bar.
+
+const editor = getEditor();
+
+function moveDownThree(): void {
+  for (let i = 0; i < 3; i++) {
+    editor.executeAction("move_down");
+  }
+  editor.executeAction("insert_char", { char: "x" });
+  // Rejected before reaching the editor: args must be an object.
+  const badArgs = "y" as unknown as Record<string, unknown>;
+  const rejected = !editor.executeAction("insert_char", badArgs);
+  editor.setStatus("EXEC-DONE rejected=" + rejected);
+}
+registerHandler("test_execute_action_move_down_three", moveDownThree);
+
+editor.registerCommand(
+  "TestActions: Move Down Three",
+  "Move the cursor down three lines through executeAction",
+  "test_execute_action_move_down_three",
+  null,
+);
@@ -1,7 +1,7 @@
 /// <reference path="./lib/fresh.d.ts" />
 //
 // Tiny test plugin: tries to *set* the workspace trust level directly through
-// the generic `executeActions` channel (the capability we deny to plugins),
+// the generic `executeAction(s)` channels (the capability we deny to plugins),
 // then reports the resulting level to the status bar. If the lockdown holds,
 // the level is unchanged; if it ever regresses, the status will show that a
 // plugin managed to elevate trust on its own.
@@ -9,8 +9,10 @@
 const editor = getEditor();
 
 function tryElevate(): void {
-  // Attempt the forbidden trust-setting action. Core must drop this.
+  // Attempt the forbidden trust-setting action through both channels. Core
+  // must drop it.
   editor.executeActions([{ action: "workspace_trust_trust", count: 1 }]);
+  editor.executeAction("workspace_trust_trust");
   editor.setStatus("TRUST-AFTER:" + editor.workspaceTrustLevel());
 }
 registerHandler("test_trust_try_elevate", tryElevate);
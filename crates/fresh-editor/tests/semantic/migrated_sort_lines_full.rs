@@ -33,7 +33,7 @@ fn migrated_sort_lines_basic() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines on 3 unsorted lines yields alphabetical order".into(),
         initial_text: "cherry\nbanana\napple".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nbanana\ncherry".into(),
         expected_primary: CursorExpect::at(19),
         ..Default::default()
@@ -47,7 +47,7 @@ fn migrated_sort_lines_single_line_no_change() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines on single-line buffer preserves SelectAll anchor".into(),
         initial_text: "only line".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "only line".into(),
         expected_primary: CursorExpect::range(0, 9),
         ..Default::default()
@@ -60,7 +60,7 @@ fn migrated_sort_lines_with_numbers_uses_lexicographic_order() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines uses lexicographic, not numeric, ordering".into(),
         initial_text: "10 items\n2 items\n1 item".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "1 item\n10 items\n2 items".into(),
         expected_primary: CursorExpect::at(23),
         ..Default::default()
@@ -73,7 +73,7 @@ fn migrated_sort_lines_preserves_trailing_newline() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines preserves trailing newline".into(),
         initial_text: "zebra\napple\nmango\n".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nmango\nzebra\n".into(),
         expected_primary: CursorExpect::at(18),
         ..Default::default()
@@ -86,7 +86,7 @@ fn migrated_sort_lines_undo_restores_original_order() {
     assert_trace_scenario(TraceScenario {
         description: "SortLines + Undo restores original ordering".into(),
         initial_text: "cherry\napple\nbanana".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nbanana\ncherry".into(),
         undo_count: 1,
     });
@@ -99,7 +99,7 @@ fn migrated_sort_lines_already_sorted_is_noop() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines is idempotent on already-sorted input; anchor preserved".into(),
         initial_text: "apple\nbanana\ncherry".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nbanana\ncherry".into(),
         expected_primary: CursorExpect::range(0, 19),
         ..Default::default()
@@ -114,7 +114,7 @@ fn migrated_sort_lines_case_sensitive_ascii_ordering() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines uses case-sensitive ASCII order: uppercase first".into(),
         initial_text: "Banana\napple\nCherry".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "Banana\nCherry\napple".into(),
         expected_primary: CursorExpect::at(19),
         ..Default::default()
@@ -128,7 +128,7 @@ fn migrated_sort_lines_with_empty_lines() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines puts empty lines first".into(),
         initial_text: "cherry\n\napple\n\nbanana".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "\n\napple\nbanana\ncherry".into(),
         expected_primary: CursorExpect::at(21),
         ..Default::default()
@@ -162,7 +162,7 @@ fn migrated_sort_lines_partial_selection() {
             Action::SelectDown,
         ]
         .into_iter()
-        .chain(std::iter::once(Action::SortLines))
+        .chain(std::iter::once(Action::SortLines(Default::default())))
         .collect(),
         expected_text: "first\napple\nmango\nzebra\nlast".into(),
         // After sort, the selection collapses (anchor cleared, matching
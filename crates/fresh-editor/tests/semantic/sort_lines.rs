@@ -26,7 +26,7 @@ fn theorem_sort_lines_basic_alphabetical() {
     assert_buffer_scenario(BufferScenario {
         description: "SelectAll + SortLines orders three lines alphabetically".into(),
         initial_text: "cherry\napple\nbanana".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nbanana\ncherry".into(),
         expected_primary: CursorExpect::at(19),
         expected_extra_cursors: vec![],
@@ -43,7 +43,7 @@ fn theorem_sort_lines_already_sorted_is_idempotent() {
     assert_buffer_scenario(BufferScenario {
         description: "SortLines on sorted input is idempotent and preserves the selection".into(),
         initial_text: "apple\nbanana\ncherry".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nbanana\ncherry".into(),
         expected_primary: CursorExpect::range(0, 19),
         expected_extra_cursors: vec![],
@@ -61,7 +61,7 @@ fn theorem_sort_lines_undo_restores_original_order() {
     assert_trace_scenario(TraceScenario {
         description: "SortLines is a single undo unit — one Undo restores the input".into(),
         initial_text: "cherry\napple\nbanana".into(),
-        actions: vec![Action::SelectAll, Action::SortLines],
+        actions: vec![Action::SelectAll, Action::SortLines(Default::default())],
         expected_text: "apple\nbanana\ncherry".into(),
         undo_count: 1,
     });
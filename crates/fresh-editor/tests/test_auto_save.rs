@@ -2,6 +2,7 @@ mod common;
 
 use common::harness::EditorTestHarness;
 use fresh::config::Config;
+use fresh::workspace::Workspace;
 use std::fs;
 use std::time::Duration;
 
@@ -129,3 +130,63 @@ fn test_auto_recovery_save_throttled_before_interval() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_workspace_auto_save_after_interval_and_skipped_when_idle() -> anyhow::Result<()> {
+    let mut config = Config::default();
+    config.editor.workspace_auto_save_interval_secs = 5;
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let working_dir = harness.editor().working_dir().to_path_buf();
+    let file_a = working_dir.join("a.txt");
+    let file_b = working_dir.join("b.txt");
+    fs::write(&file_a, "A")?;
+    fs::write(&file_b, "B")?;
+    harness.open_file(&file_a)?;
+
+    // Before the interval: nothing is written
+    harness.advance_time(Duration::from_millis(3000));
+    assert!(!harness.editor_mut().auto_save_workspace()?);
+    assert!(Workspace::load(&working_dir)?.is_none());
+
+    // After the interval the session is written
+    harness.advance_time(Duration::from_millis(2100));
+    assert!(harness.editor_mut().auto_save_workspace()?);
+    assert!(Workspace::load(&working_dir)?.is_some());
+
+    // Nothing changed since: the next interval does not rewrite it
+    Workspace::delete(&working_dir)?;
+    harness.advance_time(Duration::from_millis(5100));
+    assert!(!harness.editor_mut().auto_save_workspace()?);
+    assert!(Workspace::load(&working_dir)?.is_none());
+
+    // Opening another file changes the layout, so it is saved again
+    harness.open_file(&file_b)?;
+    harness.advance_time(Duration::from_millis(5100));
+    assert!(harness.editor_mut().auto_save_workspace()?);
+    let workspace = Workspace::load(&working_dir)?.expect("workspace autosaved");
+    assert!(serde_json::to_string(&workspace)?.contains("b.txt"));
+
+    Workspace::delete(&working_dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_workspace_auto_save_skipped_without_sessions() -> anyhow::Result<()> {
+    let mut config = Config::default();
+    config.editor.workspace_auto_save_interval_secs = 5;
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    // As `--no-session` does
+    harness.editor_mut().set_workspace_enabled(false);
+    let working_dir = harness.editor().working_dir().to_path_buf();
+    let file_a = working_dir.join("a.txt");
+    fs::write(&file_a, "A")?;
+    harness.open_file(&file_a)?;
+
+    harness.advance_time(Duration::from_millis(5100));
+    assert!(!harness.editor_mut().auto_save_workspace()?);
+    assert!(Workspace::load(&working_dir)?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Build script for `fresh-parser-js`.
+//!
+//! Emits `FRESH_OXC_VERSION` — the `oxc_parser` version Cargo actually
+//! resolved, read from the workspace `Cargo.lock`. The workspace manifest
+//! only states a semver requirement, so the lock file is the one place that
+//! knows which oxc release does the transpiling. Falls back to "unknown",
+//! with a build warning, when no lock file is found (e.g. building as a
+//! dependency from a registry), in which case caches still key on the Fresh
+//! version.
+
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let lock = Path::new(&manifest_dir)
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.exists());
+
+    let version = lock
+        .as_deref()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| locked_version(&contents, "oxc_parser"))
+        .unwrap_or_else(|| {
+            println!(
+                "cargo::warning=could not read the oxc_parser version from Cargo.lock; \
+                 OXC_VERSION is \"unknown\""
+            );
+            "unknown".to_string()
+        });
+    println!("cargo::rustc-env=FRESH_OXC_VERSION={version}");
+
+    if let Some(path) = lock {
+        println!("cargo::rerun-if-changed={}", path.display());
+    }
+}
+
+/// The `version` of the `[[package]]` entry named `name` in a Cargo.lock.
+fn locked_version(lock: &str, name: &str) -> Option<String> {
+    let name_line = format!("name = \"{name}\"");
+    let mut lines = lock.lines();
+    lines.find(|line| *line == name_line)?;
+    let version = lines.next()?.strip_prefix("version = \"")?;
+    Some(version.trim_end_matches('"').to_string())
+}
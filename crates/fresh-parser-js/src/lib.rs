@@ -15,6 +15,11 @@ use oxc_transformer::{TransformOptions, Transformer};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+/// Version of the oxc crates doing the transpiling, as resolved in
+/// `Cargo.lock` (see build.rs). Transpiled output can change between oxc
+/// releases, so anything caching it keys on this.
+pub const OXC_VERSION: &str = env!("FRESH_OXC_VERSION");
+
 /// Transpile TypeScript source code to JavaScript
 pub fn transpile_typescript(source: &str, filename: &str) -> Result<String> {
     let allocator = Allocator::default();
@@ -349,6 +354,47 @@ pub fn bundle_module(entry_path: &Path) -> Result<String> {
     Ok(output)
 }
 
+/// Read the source of a module and of every local module it imports,
+/// dependencies first — the same closure and order `bundle_module` emits.
+///
+/// Only parses each file for its imports (no transpiling), so it is cheap
+/// enough to fingerprint a bundle before deciding whether to rebuild it.
+pub fn module_closure_sources(entry_path: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let mut visited = HashSet::new();
+    let mut sources = Vec::new();
+    collect_module_sources(entry_path, &mut visited, &mut sources)?;
+    Ok(sources)
+}
+
+fn collect_module_sources(
+    path: &Path,
+    visited: &mut HashSet<PathBuf>,
+    sources: &mut Vec<(PathBuf, String)>,
+) -> Result<()> {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if !visited.insert(canonical.clone()) {
+        return Ok(()); // Already processed (circular import protection)
+    }
+
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+
+    let (imports, _, reexports) = extract_module_bindings(&source);
+    let parent_dir = path.parent().unwrap_or(Path::new("."));
+    let local_deps = imports
+        .iter()
+        .map(|import| &import.source_path)
+        .chain(reexports.iter().map(|reexport| &reexport.source_path))
+        .filter(|source_path| source_path.starts_with("./") || source_path.starts_with("../"));
+    for source_path in local_deps {
+        let resolved = resolve_import(source_path, parent_dir)?;
+        collect_module_sources(&resolved, visited, sources)?;
+    }
+
+    sources.push((canonical, source));
+    Ok(())
+}
+
 /// Collect all modules in dependency order (dependencies first)
 fn collect_modules(
     path: &Path,
@@ -850,6 +896,38 @@ fn declaration_to_statement(decl: Declaration<'_>) -> Statement<'_> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn oxc_version_is_resolved_from_the_lock_file() {
+        let parts: Vec<&str> = OXC_VERSION.split('.').collect();
+        assert!(
+            parts.len() == 3 && parts.iter().all(|part| part.parse::<u64>().is_ok()),
+            "OXC_VERSION should be the locked oxc_parser version, got {OXC_VERSION:?}"
+        );
+    }
+
+    #[test]
+    fn module_closure_sources_lists_dependencies_first() {
+        let dir = std::env::temp_dir().join(format!("fresh_closure_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("lib")).unwrap();
+        std::fs::write(dir.join("lib/util.ts"), "export const x = 1;\n").unwrap();
+        std::fs::write(dir.join("lib/index.ts"), "export { x } from \"./util\";\n").unwrap();
+        std::fs::write(
+            dir.join("main.ts"),
+            "import { x } from \"./lib/index\";\nimport { x as y } from \"./lib/util\";\n",
+        )
+        .unwrap();
+
+        let sources = module_closure_sources(&dir.join("main.ts")).unwrap();
+        let names: Vec<_> = sources
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["util.ts", "index.ts", "main.ts"]);
+        assert_eq!(sources[0].1, "export const x = 1;\n");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn emit_isolated_declarations_script_hides_internals() {
         // Script-style plugin: no `import`, no `export`. Before we forced
@@ -1052,6 +1130,56 @@ const x = foo() + bar();"#;
         assert!(stripped.contains("const x = foo() + bar();"));
     }
 
+    #[test]
+    fn test_strip_multiline_imports_and_export_lists() {
+        let source = r#"import greet, {
+    a,
+    b as renamed,
+} from "./lib";
+import type {
+    Shape,
+} from "./types";
+const local = greet(a + renamed);
+export {
+    local,
+    renamed as alias,
+};"#;
+
+        let stripped = strip_imports_and_exports(source);
+        assert!(!stripped.contains("import"), "{stripped}");
+        assert!(!stripped.contains("export"), "{stripped}");
+        assert!(!stripped.contains("from"), "{stripped}");
+        assert!(stripped.contains("const local = greet(a + renamed);"));
+    }
+
+    #[test]
+    fn bundle_module_handles_multiline_default_and_named_imports() {
+        let dir = std::env::temp_dir().join(format!("fresh_multiline_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("lib.ts"),
+            "export default function greet(n: number) { return n; }\n\
+             export const a = 1;\n\
+             export const b = 2;\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("main.ts"),
+            "import greet, {\n  a,\n  b as renamed,\n} from \"./lib\";\n\
+             console.log(greet(a + renamed));\n",
+        )
+        .unwrap();
+
+        let bundled = bundle_module(&dir.join("main.ts")).unwrap();
+        assert!(!bundled.contains("import"), "{bundled}");
+        assert!(bundled.contains("const greet = "), "{bundled}");
+        assert!(bundled.contains(".default;"), "{bundled}");
+        assert!(bundled.contains("const {b: renamed} = "), "{bundled}");
+        assert!(bundled.contains("console.log(greet(a + renamed))"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_extract_plugin_dependencies_basic() {
         let source = r#"
@@ -47,6 +47,10 @@
 //! | `async_thenable` | Returns `ProcessHandle<T>` (cancellable) | `#[plugin_api(async_thenable)]` |
 //! | `ts_type = "..."` | Custom TypeScript type for parameter | `#[plugin_api(ts_type = "BufferInfo")]` |
 //! | `ts_return = "..."` | Custom TypeScript return type | `#[plugin_api(ts_return = "string")]` |
+//! | `ts_enum = "A \| B"` | Rust enum as a string-literal union alias | `#[plugin_api(ts_enum = "Normal \| Insert")]` |
+//!
+//! A method marked with the standard `#[deprecated]` (optionally with a
+//! `note`) gets a JSDoc `@deprecated` tag after its description.
 //!
 //! ## Type Mapping
 //!
@@ -58,6 +62,7 @@
 //! | `()` | `void` | |
 //! | `Option<T>` | `T \| null` | |
 //! | `Vec<T>` | `T[]` | |
+//! | `rquickjs::TypedArray<'js, u8>` | `Uint8Array` | `Vec<u8>` and `&[u8]` marshal as plain arrays (`number[]`) |
 //! | `rquickjs::Ctx<'js>` | *(skipped)* | Runtime context |
 //! | `rquickjs::function::Opt<T>` | `T?` | Optional parameter |
 //! | `rquickjs::function::Rest<T>` | `...T[]` | Variadic parameter |
@@ -148,6 +153,21 @@ struct ApiMethod {
     /// Raw TypeScript signature override (from `ts_raw = "..."`)
     /// When set, replaces the entire auto-generated signature line.
     ts_raw: Option<String>,
+    /// String-literal unions declared with `ts_enum = "..."` on the method
+    /// (for its return type) or its parameters
+    type_aliases: Vec<TsTypeAlias>,
+    /// Deprecation note from `#[deprecated]` (empty when it has no note),
+    /// emitted as a JSDoc `@deprecated` tag
+    deprecated: Option<String>,
+}
+
+/// A `type Name = "A" | "B";` alias for a Rust enum, from `ts_enum`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TsTypeAlias {
+    /// The Rust enum's name, reused as the TypeScript alias name
+    name: String,
+    /// The union of string literals, e.g. `"Normal" | "Insert"`
+    definition: String,
 }
 
 /// Parsed parameter information
@@ -229,69 +249,92 @@ fn extract_doc_comment(attrs: &[Attribute]) -> String {
         .join("\n")
 }
 
-/// Parse a string value from attribute tokens like `key = "value"`
-fn parse_attr_string_value(tokens: &str, key: &str) -> Option<String> {
-    let start = tokens.find(key)?;
-    let rest = &tokens[start..];
-    let eq_pos = rest.find('=')?;
-    let after_eq = rest[eq_pos + 1..].trim();
-
-    if !after_eq.starts_with('"') {
-        return None;
+/// The entries of a `#[name(...)]` attribute as `(key, value)` pairs, in
+/// order: `key` for bare flags (value `None`) and `key = <expr>` for
+/// key-value pairs. Nested lists such as `key(...)` are recorded as flags.
+///
+/// Each key is matched as a whole identifier, so a flag never matches
+/// inside another key or a string value. An attribute that does not parse
+/// yields no entries.
+fn attr_entries(attr: &Attribute) -> Vec<(String, Option<syn::Expr>)> {
+    let mut entries = Vec::new();
+    let parsed = attr.parse_nested_meta(|meta| {
+        let key = meta
+            .path
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .unwrap_or_default();
+        if meta.input.peek(syn::Token![=]) {
+            entries.push((key, Some(meta.value()?.parse::<syn::Expr>()?)));
+        } else {
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            entries.push((key, None));
+        }
+        Ok(())
+    });
+    if parsed.is_err() {
+        return Vec::new();
     }
+    entries
+}
+
+/// The string literal assigned to `key` in `#[attr_name(key = "value")]`
+fn get_attr_string_value(attrs: &[Attribute], attr_name: &str, key: &str) -> Option<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(attr_name))
+        .flat_map(attr_entries)
+        .find_map(|(k, value)| match value {
+            Some(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            })) if k == key => Some(lit.value()),
+            _ => None,
+        })
+}
 
-    let end_quote = after_eq[1..].find('"')?;
-    Some(after_eq[1..end_quote + 1].to_string())
+/// The note of a standard `#[deprecated]` attribute: `Some("")` for a bare
+/// `#[deprecated]`, `None` when the item is not deprecated
+fn get_deprecation(attrs: &[Attribute]) -> Option<String> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("deprecated"))?;
+    let note = match &attr.meta {
+        Meta::NameValue(meta) => match &meta.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => Some(lit.value()),
+            _ => None,
+        },
+        Meta::List(_) => get_attr_string_value(attrs, "deprecated", "note"),
+        Meta::Path(_) => None,
+    };
+    Some(note.unwrap_or_default())
 }
 
 /// Check if `#[plugin_api(...)]` contains a specific flag
 fn has_plugin_api_flag(attrs: &[Attribute], flag: &str) -> bool {
-    attrs.iter().any(|attr| {
-        if !attr.path().is_ident("plugin_api") {
-            return false;
-        }
-        if let Meta::List(meta_list) = &attr.meta {
-            meta_list.tokens.to_string().contains(flag)
-        } else {
-            false
-        }
-    })
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("plugin_api"))
+        .flat_map(attr_entries)
+        .any(|(key, value)| key == flag && value.is_none())
 }
 
 /// Get a string value from `#[plugin_api(key = "value")]`
 fn get_plugin_api_value(attrs: &[Attribute], key: &str) -> Option<String> {
-    for attr in attrs {
-        if !attr.path().is_ident("plugin_api") {
-            continue;
-        }
-        if let Meta::List(meta_list) = &attr.meta {
-            if let Some(value) = parse_attr_string_value(&meta_list.tokens.to_string(), key) {
-                return Some(value);
-            }
-        }
-    }
-    None
+    get_attr_string_value(attrs, "plugin_api", key)
 }
 
 /// Get custom JS name from `#[qjs(rename = "...")]` or `#[plugin_api(js_name = "...")]`
 fn get_js_name(attrs: &[Attribute]) -> Option<String> {
-    // First check plugin_api attribute (takes precedence)
-    if let Some(name) = get_plugin_api_value(attrs, "js_name") {
-        return Some(name);
-    }
-
-    // Then check qjs attribute
-    for attr in attrs {
-        if !attr.path().is_ident("qjs") {
-            continue;
-        }
-        if let Meta::List(meta_list) = &attr.meta {
-            if let Some(name) = parse_attr_string_value(&meta_list.tokens.to_string(), "rename") {
-                return Some(name);
-            }
-        }
-    }
-    None
+    // plugin_api takes precedence over qjs
+    get_plugin_api_value(attrs, "js_name").or_else(|| get_attr_string_value(attrs, "qjs", "rename"))
 }
 
 // ============================================================================
@@ -300,16 +343,45 @@ fn get_js_name(attrs: &[Attribute]) -> Option<String> {
 
 /// Extract inner type from generic wrapper like `Option<T>`, `Vec<T>`
 fn extract_inner_type(ty: &Type) -> Option<Type> {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                if let Some(GenericArgument::Type(inner)) = args.args.first() {
-                    return Some(inner.clone());
-                }
-            }
-        }
+    generic_type_args(ty).into_iter().next()
+}
+
+/// Extract the second generic argument, e.g. `V` from `HashMap<K, V>`
+fn extract_second_inner_type(ty: &Type) -> Option<Type> {
+    generic_type_args(ty).into_iter().nth(1)
+}
+
+/// The type arguments of the last path segment, skipping lifetimes (so `T`
+/// is the first argument of `TypedArray<'js, T>`)
+fn generic_type_args(ty: &Type) -> Vec<Type> {
+    let Type::Path(type_path) = ty else {
+        return Vec::new();
+    };
+    let Some(PathArguments::AngleBracketed(args)) =
+        type_path.path.segments.last().map(|s| &s.arguments)
+    else {
+        return Vec::new();
+    };
+    args.args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(inner) => Some(inner.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check if type is `u8`
+fn is_u8_type(ty: &Type) -> bool {
+    get_type_name(ty).is_some_and(|n| n == "u8")
+}
+
+/// Check if type is `String`, `str` or a reference to either
+fn is_string_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_string_type(&reference.elem),
+        _ => get_type_name(ty).is_some_and(|n| n == "String" || n == "str"),
     }
-    None
 }
 
 /// Get the final segment name from a type path (e.g., "Opt" from "rquickjs::function::Opt")
@@ -354,6 +426,47 @@ fn is_rest_type(ty: &Type) -> bool {
     get_type_name(ty).is_some_and(|n| n == "Rest")
 }
 
+/// The alias declared by `#[plugin_api(ts_enum = "A | B")]`
+///
+/// The alias is named after the Rust enum — the innermost type under any
+/// `Opt`/`Option`/`Vec`/`Rest`/`Result` wrappers, which is also the name
+/// `rust_to_typescript` emits for it — and each variant becomes a string
+/// literal. Variants that are already quoted are kept as written.
+fn ts_enum_alias(ty: &Type, attrs: &[Attribute]) -> Option<TsTypeAlias> {
+    let variants = get_plugin_api_value(attrs, "ts_enum")?;
+    let mut ty = ty.clone();
+    loop {
+        ty = match &ty {
+            Type::Reference(reference) => (*reference.elem).clone(),
+            _ if matches!(
+                get_type_name(&ty).as_deref(),
+                Some("Opt" | "Option" | "Vec" | "Rest" | "Result")
+            ) =>
+            {
+                extract_inner_type(&ty)?
+            }
+            _ => break,
+        };
+    }
+    let definition = variants
+        .split('|')
+        .map(str::trim)
+        .filter(|variant| !variant.is_empty())
+        .map(|variant| {
+            if variant.starts_with('"') {
+                variant.to_string()
+            } else {
+                format!("\"{}\"", variant)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ");
+    Some(TsTypeAlias {
+        name: get_type_name(&ty)?,
+        definition,
+    })
+}
+
 // ============================================================================
 // Rust to TypeScript Type Conversion
 // ============================================================================
@@ -402,7 +515,8 @@ fn rust_to_typescript(ty: &Type, attrs: &[Attribute]) -> String {
                     format!("{} | null", inner)
                 }
 
-                // Vec<T> -> T[]
+                // Vec<T> -> T[] (including Vec<u8>, which rquickjs marshals
+                // as a plain array)
                 "Vec" => {
                     let inner = extract_inner_type(ty)
                         .map(|t| rust_to_typescript(&t, &[]))
@@ -410,6 +524,11 @@ fn rust_to_typescript(ty: &Type, attrs: &[Attribute]) -> String {
                     format!("{}[]", inner)
                 }
 
+                // rquickjs::TypedArray<'js, u8> -> Uint8Array
+                "TypedArray" if extract_inner_type(ty).is_some_and(|t| is_u8_type(&t)) => {
+                    "Uint8Array".to_string()
+                }
+
                 // Opt<T> -> extract inner (optionality handled at param level)
                 "Opt" => extract_inner_type(ty)
                     .map(|t| rust_to_typescript(&t, &[]))
@@ -429,8 +548,20 @@ fn rust_to_typescript(ty: &Type, attrs: &[Attribute]) -> String {
                 "Value" => "unknown".to_string(),
                 "Object" => "Record<string, unknown>".to_string(),
 
-                // Rust collections
-                "HashMap" | "BTreeMap" => "Record<string, unknown>".to_string(),
+                // HashMap<K, V> -> Record<string, V>. JS object keys are
+                // always strings, so a non-string Rust key is noted in a
+                // trailing comment rather than changing the key type.
+                "HashMap" | "BTreeMap" => {
+                    let value = extract_second_inner_type(ty)
+                        .map(|t| rust_to_typescript(&t, &[]))
+                        .unwrap_or_else(|| "unknown".to_string());
+                    match extract_inner_type(ty) {
+                        Some(key) if !is_string_type(&key) => {
+                            format!("Record<string, {}> /* keys are {} */", value, quote!(#key))
+                        }
+                        _ => format!("Record<string, {}>", value),
+                    }
+                }
 
                 // Known API types - pass through unchanged
                 "BufferInfo"
@@ -478,6 +609,8 @@ fn rust_to_typescript(ty: &Type, attrs: &[Attribute]) -> String {
         }
         Type::Tuple(tuple) if tuple.elems.is_empty() => "void".to_string(),
         Type::Reference(reference) => rust_to_typescript(&reference.elem, attrs),
+        // [T] -> T[]
+        Type::Slice(slice) => format!("{}[]", rust_to_typescript(&slice.elem, &[])),
         _ => "unknown".to_string(),
     }
 }
@@ -497,6 +630,7 @@ fn parse_method(method: &ImplItemFn) -> Option<ApiMethod> {
 
     let rust_name = method.sig.ident.to_string();
     let doc = extract_doc_comment(&method.attrs);
+    let mut type_aliases = Vec::new();
 
     // Determine method kind from explicit attributes only (no heuristics)
     let kind = if has_plugin_api_flag(&method.attrs, "async_thenable") {
@@ -545,6 +679,8 @@ fn parse_method(method: &ImplItemFn) -> Option<ApiMethod> {
                 return None;
             }
 
+            type_aliases.extend(ts_enum_alias(ty, &pat_type.attrs));
+
             Some(ParamInfo {
                 name: to_camel_case(param_name),
                 ts_type: rust_to_typescript(ty, &pat_type.attrs),
@@ -558,6 +694,7 @@ fn parse_method(method: &ImplItemFn) -> Option<ApiMethod> {
     let return_type = match &method.sig.output {
         ReturnType::Default => "void".to_string(),
         ReturnType::Type(_, ty) => {
+            type_aliases.extend(ts_enum_alias(ty, &method.attrs));
             // Check for explicit ts_return override
             get_plugin_api_value(&method.attrs, "ts_return")
                 .unwrap_or_else(|| rust_to_typescript(ty, &method.attrs))
@@ -574,6 +711,8 @@ fn parse_method(method: &ImplItemFn) -> Option<ApiMethod> {
         return_type,
         doc,
         ts_raw,
+        type_aliases,
+        deprecated: get_deprecation(&method.attrs),
     })
 }
 
@@ -585,12 +724,15 @@ fn parse_method(method: &ImplItemFn) -> Option<ApiMethod> {
 fn generate_ts_method(method: &ApiMethod) -> String {
     let mut lines = Vec::new();
 
-    // JSDoc comment
-    if !method.doc.is_empty() {
+    // JSDoc comment, with any @deprecated tag after the description
+    if !method.doc.is_empty() || method.deprecated.is_some() {
         lines.push("  /**".to_string());
         for line in method.doc.lines() {
             lines.push(format!("   * {}", line));
         }
+        if let Some(note) = &method.deprecated {
+            lines.push(format!("   * @deprecated {}", note).trim_end().to_string());
+        }
         lines.push("   */".to_string());
     }
 
@@ -657,6 +799,38 @@ declare function getEditor(): EditorAPI;
  */
 declare function registerHandler(name: string, fn: Function): void;
 
+/** Encodes strings as UTF-8 bytes (the standard `TextEncoder`) */
+declare class TextEncoder {
+  readonly encoding: "utf-8";
+  encode(input?: string): Uint8Array;
+}
+
+/**
+ * Decodes bytes into a string (the standard `TextDecoder`).
+ * Only the UTF-8 encoding is supported; other labels throw a `RangeError`.
+ * With `fatal: true`, invalid input throws a `TypeError` instead of
+ * decoding to U+FFFD.
+ */
+declare class TextDecoder {
+  constructor(label?: string, options?: { fatal?: boolean; ignoreBOM?: boolean });
+  readonly encoding: string;
+  readonly fatal: boolean;
+  readonly ignoreBOM: boolean;
+  decode(input?: Uint8Array | ArrayBuffer): string;
+}
+
+/**
+ * Run `callback` once after `ms` milliseconds. `callback` may also be the
+ * name of a global handler. Returns an id for `clearTimeout`.
+ */
+declare function setTimeout(callback: Function | string, ms?: number, ...args: unknown[]): number;
+/** Cancel a `setTimeout`; ids that already fired are ignored */
+declare function clearTimeout(id?: number): void;
+/** Run `callback` every `ms` milliseconds until `clearInterval` */
+declare function setInterval(callback: Function | string, ms?: number, ...args: unknown[]): number;
+/** Cancel a `setInterval` */
+declare function clearInterval(id?: number): void;
+
 /** Handle for a cancellable async operation */
 interface ProcessHandle<T> extends PromiseLike<T> {
   /** Promise that resolves to the result when complete */
@@ -731,17 +905,42 @@ interface FreshPluginRegistry {}
 "#
 }
 
-/// Generate the EditorAPI interface (methods only)
-/// Types are provided separately via ts-rs
-fn generate_editor_api_interface(methods: &[ApiMethod]) -> String {
+/// Generate the EditorAPI interface (methods only), preceded by the
+/// `ts_enum` type aliases it references
+/// Other types are provided separately via ts-rs
+fn generate_editor_api_interface(methods: &[ApiMethod], aliases: &[TsTypeAlias]) -> String {
     let method_sigs: Vec<String> = methods.iter().map(generate_ts_method).collect();
+    let alias_decls: String = aliases
+        .iter()
+        .map(|alias| format!("type {} = {};\n", alias.name, alias.definition))
+        .collect();
 
     format!(
-        "/**\n * Main editor API interface\n */\ninterface EditorAPI {{\n{}\n}}\n",
+        "{}/**\n * Main editor API interface\n */\ninterface EditorAPI {{\n{}\n}}\n",
+        alias_decls,
         method_sigs.join("\n\n")
     )
 }
 
+/// Collect the `ts_enum` aliases of all methods, once per enum, in order of
+/// first use. Two different unions declared for the same enum are an error.
+fn collect_type_aliases(methods: &[ApiMethod]) -> Result<Vec<TsTypeAlias>, String> {
+    let mut aliases: Vec<TsTypeAlias> = Vec::new();
+    for alias in methods.iter().flat_map(|m| &m.type_aliases) {
+        match aliases.iter().find(|a| a.name == alias.name) {
+            None => aliases.push(alias.clone()),
+            Some(existing) if existing.definition == alias.definition => {}
+            Some(existing) => {
+                return Err(format!(
+                    "conflicting ts_enum for `{}`: `{}` vs `{}`",
+                    alias.name, existing.definition, alias.definition
+                ));
+            }
+        }
+    }
+    Ok(aliases)
+}
+
 /// Built-in TypeScript types that don't need to be collected
 const BUILTIN_TS_TYPES: &[&str] = &[
     "number",
@@ -756,6 +955,7 @@ const BUILTIN_TS_TYPES: &[&str] = &[
     "Promise",
     "ProcessHandle",
     "PromiseLike",
+    "Uint8Array",
     "BufferId",
     "SplitId", // Defined in preamble
 ];
@@ -767,6 +967,7 @@ const BUILTIN_TS_TYPES: &[&str] = &[
 /// - Generics: "ProcessHandle<SpawnResult>" -> ["SpawnResult"]
 /// - Union: "string | null" -> []
 /// - Arrays: "BufferInfo[]" -> ["BufferInfo"]
+/// - Records: "Record<string, BufferInfo>" -> ["BufferInfo"]
 fn extract_type_references(ts_type: &str) -> Vec<String> {
     let mut types = Vec::new();
 
@@ -791,8 +992,8 @@ fn extract_type_references(ts_type: &str) -> Vec<String> {
         }
     }
 
-    // Handle union types (split by |)
-    for part in current.split('|') {
+    // Handle union types (split by |) and generic arguments (split by ,)
+    for part in current.split(['|', ',']) {
         let part = part.trim();
 
         // Skip built-in types
@@ -840,6 +1041,11 @@ fn collect_referenced_types(methods: &[ApiMethod]) -> Vec<String> {
         }
     }
 
+    // `ts_enum` aliases are declared alongside EditorAPI, not by ts-rs
+    for alias in methods.iter().flat_map(|m| &m.type_aliases) {
+        types.remove(&alias.name);
+    }
+
     let mut sorted: Vec<String> = types.into_iter().collect();
     sorted.sort();
     sorted
@@ -918,8 +1124,12 @@ pub fn plugin_api_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
         .collect();
 
     // Generate TypeScript parts
+    let aliases = match collect_type_aliases(&methods) {
+        Ok(aliases) => aliases,
+        Err(message) => return compile_error(input.self_ty.span(), &message).into(),
+    };
     let preamble = generate_ts_preamble();
-    let editor_api = generate_editor_api_interface(&methods);
+    let editor_api = generate_editor_api_interface(&methods, &aliases);
 
     // Collect JS method names
     let js_names: Vec<&str> = methods.iter().map(|m| m.js_name.as_str()).collect();
@@ -985,6 +1195,8 @@ pub fn plugin_api_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `async_thenable` - Method returns `ProcessHandle<T>` (cancellable)
 /// - `ts_type = "..."` - Custom TypeScript type for a parameter
 /// - `ts_return = "..."` - Custom TypeScript return type
+/// - `ts_enum = "A | B"` - The variants of a Rust enum parameter (or, on the
+///   method, return type), emitted once as `type Enum = "A" | "B";`
 /// - `ts_raw = "..."` - Raw TypeScript signature (replaces auto-generated signature)
 ///
 /// # Examples
@@ -1031,17 +1243,59 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_attr_string_value() {
+    fn test_plugin_api_values_resolve_independently() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(
+            #[plugin_api(ts_return = "X", ts_type = "Y")]
+        )];
         assert_eq!(
-            parse_attr_string_value(r#"js_name = "myMethod""#, "js_name"),
-            Some("myMethod".to_string())
+            get_plugin_api_value(&attrs, "ts_return"),
+            Some("X".to_string())
         );
         assert_eq!(
-            parse_attr_string_value(r#"skip, js_name = "foo""#, "js_name"),
-            Some("foo".to_string())
+            get_plugin_api_value(&attrs, "ts_type"),
+            Some("Y".to_string())
         );
-        assert_eq!(parse_attr_string_value(r#"skip"#, "js_name"), None);
-        assert_eq!(parse_attr_string_value(r#"js_name = 123"#, "js_name"), None);
+        assert_eq!(get_plugin_api_value(&attrs, "ts"), None);
+
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[plugin_api(js_name = 123)])];
+        assert_eq!(get_plugin_api_value(&attrs, "js_name"), None);
+    }
+
+    #[test]
+    fn test_keys_are_not_matched_inside_values() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(
+            #[plugin_api(ts_type = "Record<string, rename> | skip")]
+        )];
+        assert_eq!(
+            get_plugin_api_value(&attrs, "ts_type"),
+            Some("Record<string, rename> | skip".to_string())
+        );
+        assert!(!has_plugin_api_flag(&attrs, "skip"));
+        assert_eq!(get_js_name(&attrs), None);
+    }
+
+    #[test]
+    fn test_plugin_api_flags() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(
+            #[plugin_api(async_thenable, js_name = "spawn")]
+        )];
+        assert!(has_plugin_api_flag(&attrs, "async_thenable"));
+        assert!(!has_plugin_api_flag(&attrs, "async_promise"));
+        assert!(!has_plugin_api_flag(&attrs, "async"));
+        // A key with a value is not a flag
+        assert!(!has_plugin_api_flag(&attrs, "js_name"));
+    }
+
+    #[test]
+    fn test_js_name_prefers_plugin_api_over_qjs() {
+        let qjs_only: Vec<Attribute> = vec![syn::parse_quote!(#[qjs(get, rename = "fromQjs")])];
+        assert_eq!(get_js_name(&qjs_only), Some("fromQjs".to_string()));
+
+        let both: Vec<Attribute> = vec![
+            syn::parse_quote!(#[qjs(rename = "fromQjs")]),
+            syn::parse_quote!(#[plugin_api(js_name = "fromPluginApi")]),
+        ];
+        assert_eq!(get_js_name(&both), Some("fromPluginApi".to_string()));
     }
 
     #[test]
@@ -1132,6 +1386,57 @@ mod tests {
         assert!(extract_type_references("Promise<void>").is_empty());
     }
 
+    #[test]
+    fn test_hash_map_value_types() {
+        assert_eq!(
+            rust_to_typescript(&parse_type("HashMap<String, u32>"), &[]),
+            "Record<string, number>"
+        );
+        assert_eq!(
+            rust_to_typescript(&parse_type("HashMap<String, Vec<String>>"), &[]),
+            "Record<string, string[]>"
+        );
+        assert_eq!(
+            rust_to_typescript(&parse_type("Option<BTreeMap<&str, BufferInfo>>"), &[]),
+            "Record<string, BufferInfo> | null"
+        );
+        assert_eq!(
+            rust_to_typescript(&parse_type("HashMap<u32, String>"), &[]),
+            "Record<string, string> /* keys are u32 */"
+        );
+        assert_eq!(
+            extract_type_references("Record<string, BufferInfo> | null"),
+            vec!["BufferInfo"]
+        );
+    }
+
+    #[test]
+    fn test_typed_bytes_map_to_uint8_array() {
+        assert_eq!(
+            rust_to_typescript(&parse_type("Option<rquickjs::TypedArray<'js, u8>>"), &[]),
+            "Uint8Array | null"
+        );
+        // Only a TypedArray reaches JS as a Uint8Array; rquickjs turns byte
+        // vectors and slices into plain arrays.
+        assert_eq!(rust_to_typescript(&parse_type("Vec<u8>"), &[]), "number[]");
+        assert_eq!(rust_to_typescript(&parse_type("&[u8]"), &[]), "number[]");
+
+        let method: ImplItemFn = syn::parse_quote! {
+            pub fn read_file_bytes<'js>(
+                &self,
+                ctx: rquickjs::Ctx<'js>,
+                path: String,
+            ) -> rquickjs::Result<rquickjs::TypedArray<'js, u8>> {}
+        };
+        let ts = generate_ts_method(&parse_method(&method).unwrap());
+        assert!(
+            ts.contains("readFileBytes(path: string): Uint8Array;"),
+            "{}",
+            ts
+        );
+        assert!(extract_type_references("Uint8Array | null").is_empty());
+    }
+
     #[test]
     fn test_collect_referenced_types() {
         let methods = vec![
@@ -1142,6 +1447,8 @@ mod tests {
                 return_type: "SpawnResult".to_string(),
                 doc: "".to_string(),
                 ts_raw: None,
+                type_aliases: vec![],
+                deprecated: None,
             },
             ApiMethod {
                 js_name: "listBuffers".to_string(),
@@ -1150,6 +1457,8 @@ mod tests {
                 return_type: "BufferInfo[]".to_string(),
                 doc: "".to_string(),
                 ts_raw: None,
+                type_aliases: vec![],
+                deprecated: None,
             },
         ];
 
@@ -1158,6 +1467,90 @@ mod tests {
         assert!(types.contains(&"BufferInfo".to_string()));
     }
 
+    #[test]
+    fn test_ts_enum_alias_is_declared_once() {
+        let methods: Vec<ApiMethod> = [
+            syn::parse_quote! {
+                pub fn set_mode(&self, #[plugin_api(ts_enum = "Normal | Insert | Visual")] mode: EditorMode) {}
+            },
+            syn::parse_quote! {
+                pub fn set_default_mode(
+                    &self,
+                    #[plugin_api(ts_enum = "Normal | Insert | Visual")] mode: Opt<EditorMode>,
+                ) {}
+            },
+            syn::parse_quote! {
+                #[plugin_api(ts_enum = "Normal | Insert | Visual")]
+                pub fn get_mode(&self) -> Option<EditorMode> {}
+            },
+        ]
+        .iter()
+        .filter_map(parse_method)
+        .collect();
+
+        let aliases = collect_type_aliases(&methods).unwrap();
+        let ts = generate_editor_api_interface(&methods, &aliases);
+        let alias = r#"type EditorMode = "Normal" | "Insert" | "Visual";"#;
+        assert_eq!(ts.matches(alias).count(), 1, "{}", ts);
+        assert!(ts.find(alias) < ts.find("interface EditorAPI"));
+        assert!(ts.contains("setMode(mode: EditorMode): void;"));
+        assert!(ts.contains("setDefaultMode(mode?: EditorMode): void;"));
+        assert!(ts.contains("getMode(): EditorMode | null;"));
+
+        // The alias is not left for ts-rs to resolve
+        assert!(!collect_referenced_types(&methods).contains(&"EditorMode".to_string()));
+    }
+
+    #[test]
+    fn test_conflicting_ts_enum_is_an_error() {
+        let methods: Vec<ApiMethod> = [
+            syn::parse_quote! {
+                pub fn a(&self, #[plugin_api(ts_enum = "On | Off")] s: Switch) {}
+            },
+            syn::parse_quote! {
+                pub fn b(&self, #[plugin_api(ts_enum = "\"on\" | \"off\"")] s: Switch) {}
+            },
+        ]
+        .iter()
+        .filter_map(parse_method)
+        .collect();
+
+        let err = collect_type_aliases(&methods).unwrap_err();
+        assert!(err.contains("Switch"), "{}", err);
+    }
+
+    #[test]
+    fn test_deprecated_methods_get_a_jsdoc_tag() {
+        let with_note: ImplItemFn = syn::parse_quote! {
+            /// Get the old thing
+            #[deprecated(note = "use getNewThing instead")]
+            pub fn get_old_thing(&self) -> u32 {}
+        };
+        let ts = generate_ts_method(&parse_method(&with_note).unwrap());
+        let description = ts.find("Get the old thing").unwrap();
+        let tag = ts.find("@deprecated use getNewThing instead").unwrap();
+        assert!(description < tag, "{}", ts);
+
+        let bare: ImplItemFn = syn::parse_quote! {
+            #[deprecated]
+            pub fn get_older_thing(&self) -> u32 {}
+        };
+        let ts = generate_ts_method(&parse_method(&bare).unwrap());
+        assert!(ts.contains("   * @deprecated\n   */"), "{}", ts);
+
+        let name_value: ImplItemFn = syn::parse_quote! {
+            #[deprecated = "gone soon"]
+            pub fn get_oldest_thing(&self) -> u32 {}
+        };
+        let method = parse_method(&name_value).unwrap();
+        assert_eq!(method.deprecated.as_deref(), Some("gone soon"));
+
+        let current: ImplItemFn = syn::parse_quote! {
+            pub fn get_thing(&self) -> u32 {}
+        };
+        assert!(!generate_ts_method(&parse_method(&current).unwrap()).contains("@deprecated"));
+    }
+
     #[test]
     fn test_generate_ts_method_sync() {
         let method = ApiMethod {
@@ -1167,6 +1560,8 @@ mod tests {
             return_type: "number".to_string(),
             doc: "Get the active buffer ID".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
 
         let ts = generate_ts_method(&method);
@@ -1188,6 +1583,8 @@ mod tests {
             return_type: "void".to_string(),
             doc: "".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
 
         let ts = generate_ts_method(&method);
@@ -1216,6 +1613,8 @@ mod tests {
             return_type: "SpawnResult".to_string(),
             doc: "Spawn a process".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
 
         let ts = generate_ts_method(&method);
@@ -1342,6 +1741,8 @@ mod tests {
             return_type: "boolean".to_string(),
             doc: "Update alignment hunks".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
 
         let ts = generate_ts_method(&method);
@@ -1359,6 +1760,8 @@ mod tests {
             return_type: "CursorInfo | null".to_string(),
             doc: "Get primary cursor".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
         let ts = generate_ts_method(&method);
         assert!(ts.contains("getPrimaryCursor(): CursorInfo | null;"));
@@ -1370,6 +1773,8 @@ mod tests {
             return_type: "CursorInfo[]".to_string(),
             doc: "Get all cursors".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
         let ts = generate_ts_method(&method);
         assert!(ts.contains("getAllCursors(): CursorInfo[];"));
@@ -1381,6 +1786,8 @@ mod tests {
             return_type: "number[]".to_string(),
             doc: "Get all cursor positions".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
         let ts = generate_ts_method(&method);
         assert!(ts.contains("getAllCursorPositions(): number[];"));
@@ -1400,6 +1807,8 @@ mod tests {
             return_type: "TerminalResult".to_string(),
             doc: "Create a terminal".to_string(),
             ts_raw: None,
+            type_aliases: vec![],
+            deprecated: None,
         };
 
         let ts = generate_ts_method(&method);
@@ -1423,6 +1832,8 @@ mod tests {
                 return_type: "boolean".to_string(),
                 doc: "".to_string(),
                 ts_raw: None,
+                type_aliases: vec![],
+                deprecated: None,
             },
             ApiMethod {
                 js_name: "setSuggestions".to_string(),
@@ -1436,6 +1847,8 @@ mod tests {
                 return_type: "boolean".to_string(),
                 doc: "".to_string(),
                 ts_raw: None,
+                type_aliases: vec![],
+                deprecated: None,
             },
             ApiMethod {
                 js_name: "getPrimaryCursor".to_string(),
@@ -1444,6 +1857,8 @@ mod tests {
                 return_type: "CursorInfo | null".to_string(),
                 doc: "".to_string(),
                 ts_raw: None,
+                type_aliases: vec![],
+                deprecated: None,
             },
             ApiMethod {
                 js_name: "createTerminal".to_string(),
@@ -1457,6 +1872,8 @@ mod tests {
                 return_type: "TerminalResult".to_string(),
                 doc: "".to_string(),
                 ts_raw: None,
+                type_aliases: vec![],
+                deprecated: None,
             },
         ];
 
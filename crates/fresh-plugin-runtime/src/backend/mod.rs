@@ -4,6 +4,8 @@
 //! Currently implements QuickJS with oxc transpilation.
 
 pub mod quickjs_backend;
+mod timers;
+mod transpile_cache;
 
 pub use quickjs_backend::{
     has_fatal_js_error, set_panic_on_js_errors, take_fatal_js_error, PendingResponses,
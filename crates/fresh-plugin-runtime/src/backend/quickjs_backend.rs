@@ -86,6 +86,8 @@
 //!
 //! This validates TypeScript syntax and writes `plugins/lib/fresh.d.ts`.
 
+use crate::backend::timers::TimerQueue;
+use crate::backend::transpile_cache::TranspileCache;
 use anyhow::{anyhow, Result};
 use fresh_core::api::{
     ActionSpec, BufferInfo, CompositeHunk, CreateCompositeBufferOptions, EditorStateSnapshot,
@@ -94,13 +96,11 @@ use fresh_core::api::{
     SearchTakeResult,
 };
 use fresh_core::command::Command;
+use fresh_core::config::{PluginCapability, PluginPermissions};
 use fresh_core::overlay::OverlayNamespace;
 use fresh_core::text_property::TextPropertyEntry;
 use fresh_core::{BufferId, SplitId};
-use fresh_parser_js::{
-    bundle_module, has_es_imports, has_es_module_syntax, strip_imports_and_exports,
-    transpile_typescript,
-};
+use fresh_parser_js::{has_es_imports, has_es_module_syntax, strip_imports_and_exports};
 use fresh_plugin_api_macros::{plugin_api, plugin_api_impl};
 use rquickjs::{Context, Function, Object, Runtime, Value};
 use std::cell::RefCell;
@@ -333,57 +333,137 @@ fn get_text_properties_at_cursor_typed(
     TextPropertiesAtCursor(result)
 }
 
-/// Convert a JavaScript value to a string representation for console output
-fn js_value_to_string(ctx: &rquickjs::Ctx<'_>, val: &Value<'_>) -> String {
+/// Convert a JavaScript value to a string representation for console output.
+///
+/// Formats like a browser console: strings print verbatim, objects and
+/// arrays on one line as `{ a: 1, b: [2, 3] }`, functions as
+/// `[Function: name]`. Nesting deeper than [`CONSOLE_MAX_DEPTH`] collapses
+/// to `[Object]` / `[Array]`, and a reference back to an enclosing object
+/// prints `[Circular]`, so logging a cyclic structure cannot hang.
+fn js_value_to_string(val: &Value<'_>) -> String {
+    if let Some(s) = val.as_string() {
+        return s.to_string().unwrap_or_default();
+    }
+    if let Some(error) = format_error_object(val) {
+        return error;
+    }
+    let mut out = String::new();
+    format_console_value(val, &mut Vec::new(), &mut out);
+    out
+}
+
+/// How many levels of objects and arrays `console.log` expands.
+const CONSOLE_MAX_DEPTH: usize = 4;
+
+/// `name: message` plus the stack for `Error` instances (including
+/// subclasses). Plain objects with a `message` field format as objects.
+fn format_error_object(val: &Value<'_>) -> Option<String> {
+    if !val.is_error() {
+        return None;
+    }
+    let obj = val.as_object()?;
+    let message: String = obj.get("message").unwrap_or_default();
+    let name: String = obj.get("name").unwrap_or_else(|_| "Error".to_string());
+    Some(match obj.get::<_, String>("stack") {
+        Ok(stack) => format!("{}: {}\n{}", name, message, stack),
+        Err(_) => format!("{}: {}", name, message),
+    })
+}
+
+/// Append `val` to `out`. `ancestors` holds the objects currently being
+/// expanded, both for cycle detection and as the depth counter.
+fn format_console_value<'js>(val: &Value<'js>, ancestors: &mut Vec<Value<'js>>, out: &mut String) {
     use rquickjs::Type;
     match val.type_of() {
-        Type::Null => "null".to_string(),
-        Type::Undefined => "undefined".to_string(),
-        Type::Bool => val.as_bool().map(|b| b.to_string()).unwrap_or_default(),
-        Type::Int => val.as_int().map(|n| n.to_string()).unwrap_or_default(),
-        Type::Float => val.as_float().map(|f| f.to_string()).unwrap_or_default(),
-        Type::String => val
-            .as_string()
-            .and_then(|s| s.to_string().ok())
-            .unwrap_or_default(),
-        Type::Object | Type::Exception => {
-            // Check if this is an Error object (has message/stack properties)
-            if let Some(obj) = val.as_object() {
-                // Try to get error properties
-                let name: Option<String> = obj.get("name").ok();
-                let message: Option<String> = obj.get("message").ok();
-                let stack: Option<String> = obj.get("stack").ok();
-
-                if message.is_some() || name.is_some() {
-                    // This looks like an Error object
-                    let name = name.unwrap_or_else(|| "Error".to_string());
-                    let message = message.unwrap_or_default();
-                    if let Some(stack) = stack {
-                        return format!("{}: {}\n{}", name, message, stack);
-                    } else {
-                        return format!("{}: {}", name, message);
+        Type::String => {
+            let s = val
+                .as_string()
+                .and_then(|s| s.to_string().ok())
+                .unwrap_or_default();
+            out.push_str(&serde_json::Value::String(s).to_string());
+        }
+        Type::Function | Type::Constructor => {
+            let name = val
+                .as_object()
+                .and_then(|f| f.get::<_, String>("name").ok())
+                .filter(|name| !name.is_empty());
+            match name {
+                Some(name) => out.push_str(&format!("[Function: {}]", name)),
+                None => out.push_str("[Function (anonymous)]"),
+            }
+        }
+        Type::Array | Type::Object | Type::Exception => {
+            let is_array = val.is_array();
+            if ancestors.contains(val) {
+                out.push_str("[Circular]");
+                return;
+            }
+            if ancestors.len() >= CONSOLE_MAX_DEPTH {
+                out.push_str(if is_array { "[Array]" } else { "[Object]" });
+                return;
+            }
+            ancestors.push(val.clone());
+            if let Some(arr) = val.as_array().filter(|_| is_array) {
+                out.push('[');
+                for (i, item) in arr.iter::<Value>().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    match item {
+                        Ok(item) => format_console_value(&item, ancestors, out),
+                        Err(_) => out.push_str("undefined"),
                     }
                 }
-
-                // Regular object - convert to JSON
-                let json = js_to_json(ctx, val.clone());
-                serde_json::to_string(&json).unwrap_or_else(|_| "[object]".to_string())
-            } else {
-                "[object]".to_string()
+                out.push(']');
+            } else if let Some(obj) = val.as_object() {
+                let keys: Vec<String> = obj.keys::<String>().flatten().collect();
+                if keys.is_empty() {
+                    out.push_str("{}");
+                } else {
+                    out.push_str("{ ");
+                    for (i, key) in keys.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(", ");
+                        }
+                        push_object_key(key, out);
+                        out.push_str(": ");
+                        match obj.get::<_, Value>(key.as_str()) {
+                            Ok(v) => format_console_value(&v, ancestors, out),
+                            Err(_) => out.push_str("undefined"),
+                        }
+                    }
+                    out.push_str(" }");
+                }
             }
-        }
-        Type::Array => {
-            let json = js_to_json(ctx, val.clone());
-            serde_json::to_string(&json).unwrap_or_else(|_| "[array]".to_string())
-        }
-        Type::Function | Type::Constructor => "[function]".to_string(),
-        Type::Symbol => "[symbol]".to_string(),
-        Type::BigInt => val
-            .as_big_int()
-            .and_then(|b| b.clone().to_i64().ok())
-            .map(|n| n.to_string())
-            .unwrap_or_else(|| "[bigint]".to_string()),
-        _ => format!("[{}]", val.type_name()),
+            ancestors.pop();
+        }
+        Type::Symbol => out.push_str("[symbol]"),
+        Type::BigInt => out.push_str(
+            &val.as_big_int()
+                .and_then(|b| b.clone().to_i64().ok())
+                .map(|n| format!("{}n", n))
+                .unwrap_or_else(|| "[bigint]".to_string()),
+        ),
+        Type::Null => out.push_str("null"),
+        Type::Undefined | Type::Uninitialized => out.push_str("undefined"),
+        Type::Bool => out.push_str(&val.as_bool().unwrap_or_default().to_string()),
+        Type::Int => out.push_str(&val.as_int().unwrap_or_default().to_string()),
+        Type::Float => out.push_str(&val.as_float().unwrap_or_default().to_string()),
+        _ => out.push_str(&format!("[{}]", val.type_name())),
+    }
+}
+
+/// Object keys print bare when they are identifiers and quoted otherwise.
+fn push_object_key(key: &str, out: &mut String) {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        out.push_str(key);
+    } else {
+        out.push_str(&serde_json::Value::String(key.to_string()).to_string());
     }
 }
 
@@ -647,6 +727,8 @@ pub struct TsPluginInfo {
     /// init.ts / downstream plugins. `None` means isolated-
     /// declarations emit failed (plugin still runs).
     pub declarations: Option<String>,
+    /// Capabilities granted to this plugin by its config.
+    pub permissions: PluginPermissions,
 }
 
 /// Handler information for events and actions
@@ -808,6 +890,10 @@ pub struct JsEditorApi {
     /// side drains via `_searchHandleTake`.
     #[qjs(skip_trace)]
     search_handles: SearchHandleRegistry,
+    /// Capabilities granted per plugin, checked by the filesystem, process
+    /// and network bindings. Plugins without an entry get everything.
+    #[qjs(skip_trace)]
+    plugin_permissions: Rc<RefCell<HashMap<String, PluginPermissions>>>,
     pub plugin_name: String,
 }
 
@@ -994,6 +1080,49 @@ fn check_range<'js>(
 // Internal helpers used by the macro-processed `impl JsEditorApi` below.
 // Kept in a plain impl block so they don't get exported as JS methods.
 impl JsEditorApi {
+    /// Whether this plugin was granted `capability`. A denial is logged
+    /// with the refusing `api`, since the caller only sees `false`/`null`.
+    fn permits(&self, capability: PluginCapability, api: &str) -> bool {
+        let granted = self
+            .plugin_permissions
+            .borrow()
+            .get(&self.plugin_name)
+            .copied()
+            .unwrap_or_default()
+            .allows(capability);
+        if !granted {
+            tracing::warn!(
+                "{} refused: plugin '{}' lacks the '{}' permission",
+                api,
+                self.plugin_name,
+                capability.as_str()
+            );
+        }
+        granted
+    }
+
+    /// [`Self::permits`] for the async bindings, which throw on denial so
+    /// the call rejects instead of handing back a request id.
+    fn require(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+        capability: PluginCapability,
+        api: &str,
+    ) -> rquickjs::Result<()> {
+        if self.permits(capability, api) {
+            return Ok(());
+        }
+        Err(throw_js(
+            ctx,
+            &format!(
+                "{}: plugin '{}' lacks the '{}' permission",
+                api,
+                self.plugin_name,
+                capability.as_str()
+            ),
+        ))
+    }
+
     /// The filesystem a plugin path resolves against: the local editor host for
     /// a `LocalPath`, or a window's authority (a specific window, or the active
     /// one for a bare string) otherwise.
@@ -1449,6 +1578,58 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Register a text object named by the single character `key`
+    ///
+    /// When the user triggers it (`d i {key}` in the vim keymap, or the
+    /// `select_inside` / `select_around` actions), the `globalThis` function
+    /// `handlerName` is called with `{ bufferId, position, around }` and
+    /// returns (or resolves to) a `{ start, end }` byte range, or `null`
+    /// if there is no such object at the cursor.
+    pub fn register_text_object<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        key: String,
+        handler_name: String,
+    ) -> rquickjs::Result<bool> {
+        let mut chars = key.chars();
+        let (Some(key), None) = (chars.next(), chars.next()) else {
+            let msg = format!("registerTextObject: key must be one character, got {key:?}");
+            return Err(ctx.throw(rquickjs::String::from_str(ctx.clone(), &msg)?.into_value()));
+        };
+        Ok(self
+            .command_sender
+            .send(PluginCommand::RegisterTextObject {
+                plugin_name: self.plugin_name.clone(),
+                key,
+                handler_name,
+            })
+            .is_ok())
+    }
+
+    /// Report a text-object handler's result back to the editor. Called by
+    /// the wrapper `resolve_text_object` evaluates, not by plugins.
+    #[qjs(rename = "_resolveTextObject")]
+    pub fn resolve_text_object_result<'js>(
+        &self,
+        request_id: u64,
+        range: rquickjs::Value<'js>,
+    ) -> bool {
+        let offset = |obj: &rquickjs::Object<'js>, field: &str| {
+            obj.get::<_, f64>(field)
+                .ok()
+                .filter(|n| n.is_finite() && *n >= 0.0)
+                .map(|n| n as usize)
+        };
+        let range = range.as_object().and_then(|obj| {
+            let start = offset(obj, "start")?;
+            let end = offset(obj, "end")?;
+            (start <= end).then_some(start..end)
+        });
+        self.command_sender
+            .send(PluginCommand::TextObjectResolved { request_id, range })
+            .is_ok()
+    }
+
     /// Set a context (for keybinding conditions)
     pub fn set_context(&self, name: String, active: bool) -> bool {
         // Track context name for cleanup on unload
@@ -1466,9 +1647,39 @@ impl JsEditorApi {
     }
 
     /// Execute a built-in action
-    pub fn execute_action(&self, action_name: String) -> bool {
+    ///
+    /// `args` carries the payload of actions that take one, e.g.
+    /// `{ char: "x" }` for `insert_char`. Returns false without sending
+    /// anything when the name is empty or `args` is not an object.
+    pub fn execute_action<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        action_name: String,
+        #[plugin_api(ts_type = "Record<string, unknown> | null")] args: rquickjs::function::Opt<
+            rquickjs::Value<'js>,
+        >,
+    ) -> bool {
+        if action_name.trim().is_empty() {
+            tracing::warn!("executeAction: empty action name");
+            return false;
+        }
+        let args = match args.0 {
+            None => HashMap::new(),
+            Some(v) if v.is_null() || v.is_undefined() => HashMap::new(),
+            Some(v) => match js_to_json(&ctx, v) {
+                serde_json::Value::Object(map) => map.into_iter().collect(),
+                other => {
+                    tracing::warn!(
+                        "executeAction('{}'): args must be an object, got {}",
+                        action_name,
+                        other
+                    );
+                    return false;
+                }
+            },
+        };
         self.command_sender
-            .send(PluginCommand::ExecuteAction { action_name })
+            .send(PluginCommand::ExecuteAction { action_name, args })
             .is_ok()
     }
 
@@ -1586,6 +1797,9 @@ impl JsEditorApi {
     /// Save a buffer to a specific file path
     /// Used by :w filename to save unnamed buffers or save-as
     pub fn save_buffer_to_path(&self, buffer_id: u32, path: String) -> bool {
+        if !self.permits(PluginCapability::FsWrite, "saveBufferToPath") {
+            return false;
+        }
         self.command_sender
             .send(PluginCommand::SaveBufferToPath {
                 buffer_id: BufferId(buffer_id as usize),
@@ -1682,6 +1896,36 @@ impl JsEditorApi {
             .map_err(|e| rquickjs::Error::new_from_js_message("serialize", "", &e.to_string()))
     }
 
+    /// Text and colours of a screen region as of the next render, for
+    /// plugin self-tests. Clipped to the screen.
+    ///
+    /// Only available in debug builds or when `FRESH_PLUGIN_DEBUG_API=1`
+    /// is set; otherwise the promise rejects.
+    #[plugin_api(
+        async_promise,
+        js_name = "getRenderedRegion",
+        ts_return = "RenderedRegion"
+    )]
+    #[qjs(rename = "_getRenderedRegionStart")]
+    pub fn get_rendered_region_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> u64 {
+        let id = self.alloc_request_id();
+        let _ = self.command_sender.send(PluginCommand::GetRenderedRegion {
+            x,
+            y,
+            width,
+            height,
+            callback_id: JsCallbackId::new(id),
+        });
+        id
+    }
+
     /// List every split with its active buffer and viewport.
     ///
     /// Plugins that need to operate on every visible buffer
@@ -2066,12 +2310,12 @@ impl JsEditorApi {
         id
     }
 
-    /// Allocate a fresh animation id from the shared request-id counter.
-    /// Not exposed to JS — used internally by `animateArea` /
-    /// `animateVirtualBuffer`.
+    /// Allocate a fresh handle from the shared request-id counter, with no
+    /// callback attached. Not exposed to JS — used internally by
+    /// `animateArea` / `animateVirtualBuffer` and `showPopup`.
     #[plugin_api(skip)]
     #[qjs(skip)]
-    fn alloc_animation_id(&self) -> u64 {
+    fn alloc_handle_id(&self) -> u64 {
         let mut id_ref = self.next_request_id.borrow_mut();
         let id = *id_ref;
         *id_ref += 1;
@@ -2087,7 +2331,7 @@ impl JsEditorApi {
     ) -> rquickjs::Result<u64> {
         let rect = parse_animation_rect(&rect)?;
         let kind = parse_animation_kind(&kind)?;
-        let id = self.alloc_animation_id();
+        let id = self.alloc_handle_id();
         let _ = self
             .command_sender
             .send(PluginCommand::StartAnimationArea { id, rect, kind });
@@ -2102,7 +2346,7 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "PluginAnimationKind")] kind: rquickjs::Object<'js>,
     ) -> rquickjs::Result<u64> {
         let kind = parse_animation_kind(&kind)?;
-        let id = self.alloc_animation_id();
+        let id = self.alloc_handle_id();
         let _ = self
             .command_sender
             .send(PluginCommand::StartAnimationVirtualBuffer {
@@ -2357,11 +2601,31 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         path: fresh_core::api::PluginPath,
     ) -> Option<String> {
+        if !self.permits(PluginCapability::FsRead, "readFile") {
+            return None;
+        }
         self.fs_for(&path)
             .read_file(Path::new(path.as_str()))
             .and_then(|bytes| String::from_utf8(bytes).ok())
     }
 
+    /// Read raw file bytes from the path's filesystem, for binary files that
+    /// `readFile` cannot decode as UTF-8.
+    pub fn read_file_bytes<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
+        path: fresh_core::api::PluginPath,
+    ) -> rquickjs::Result<Option<rquickjs::TypedArray<'js, u8>>> {
+        if !self.permits(PluginCapability::FsRead, "readFileBytes") {
+            return Ok(None);
+        }
+        self.fs_for(&path)
+            .read_file(Path::new(path.as_str()))
+            .map(|bytes| rquickjs::TypedArray::new(ctx, bytes))
+            .transpose()
+    }
+
     /// Write file contents to the path's filesystem. Parent directories are
     /// created as needed.
     pub fn write_file(
@@ -2370,6 +2634,9 @@ impl JsEditorApi {
         path: fresh_core::api::PluginPath,
         content: String,
     ) -> bool {
+        if !self.permits(PluginCapability::FsWrite, "writeFile") {
+            return false;
+        }
         self.fs_for(&path)
             .write_file(Path::new(path.as_str()), content.as_bytes())
     }
@@ -2382,7 +2649,11 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         path: fresh_core::api::PluginPath,
     ) -> rquickjs::Result<Value<'js>> {
-        let entries = self.fs_for(&path).read_dir(Path::new(path.as_str()));
+        let entries = if self.permits(PluginCapability::FsRead, "readDir") {
+            self.fs_for(&path).read_dir(Path::new(path.as_str()))
+        } else {
+            Vec::new()
+        };
         rquickjs_serde::to_value(ctx, &entries)
             .map_err(|e| rquickjs::Error::new_from_js_message("serialize", "", &e.to_string()))
     }
@@ -2395,6 +2666,9 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         path: fresh_core::api::PluginPath,
     ) -> bool {
+        if !self.permits(PluginCapability::FsWrite, "createDir") {
+            return false;
+        }
         self.fs_for(&path).create_dir_all(Path::new(path.as_str()))
     }
 
@@ -2406,6 +2680,9 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         path: fresh_core::api::PluginPath,
     ) -> bool {
+        if !self.permits(PluginCapability::FsWrite, "removePath") {
+            return false;
+        }
         let fs = self.fs_for(&path);
         let target = match fs.canonicalize(Path::new(path.as_str())) {
             Some(p) => p,
@@ -2454,7 +2731,8 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         to: fresh_core::api::PluginPath,
     ) -> bool {
-        if !Self::same_backend(&from, &to) {
+        if !Self::same_backend(&from, &to) || !self.permits(PluginCapability::FsWrite, "renamePath")
+        {
             return false;
         }
         self.fs_for(&from)
@@ -2470,7 +2748,7 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         to: fresh_core::api::PluginPath,
     ) -> bool {
-        if !Self::same_backend(&from, &to) {
+        if !Self::same_backend(&from, &to) || !self.permits(PluginCapability::FsWrite, "copyPath") {
             return false;
         }
         self.fs_for(&from)
@@ -3330,7 +3608,13 @@ impl JsEditorApi {
     }
 
     /// Save a theme file to the user themes directory, returns the saved path
-    pub fn save_theme_file(&self, name: String, content: String) -> rquickjs::Result<String> {
+    pub fn save_theme_file(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: String,
+        content: String,
+    ) -> rquickjs::Result<String> {
+        self.require(&ctx, PluginCapability::FsWrite, "saveThemeFile")?;
         self.services
             .save_theme_file(&name, &content)
             .map_err(|e| rquickjs::Error::new_from_js_message("io", "", &e))
@@ -3350,6 +3634,9 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | LocalPath | WindowPath | AuthorityPath")]
         path: fresh_core::api::PluginPath,
     ) -> rquickjs::Result<Value<'js>> {
+        if !self.permits(PluginCapability::FsRead, "fileStat") {
+            return Ok(Value::new_null(ctx));
+        }
         let stat = self.fs_for(&path).stat(Path::new(path.as_str())).map(|s| {
             serde_json::json!({
                 "isFile": s.is_file,
@@ -5080,6 +5367,61 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Set a gutter icon on a line. `color` is an RGB array or a theme key;
+    /// when icons share a line the highest `priority` (default 0) shows
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_gutter_icon<'js>(
+        &self,
+        buffer_id: u32,
+        line: u32,
+        glyph: String,
+        #[plugin_api(ts_type = "OverlayColorSpec")] color: rquickjs::Value<'js>,
+        namespace: String,
+        priority: rquickjs::function::Opt<i32>,
+    ) -> rquickjs::Result<bool> {
+        let color = if color.is_string() {
+            fresh_core::api::OverlayColorSpec::ThemeKey(color.get()?)
+        } else {
+            let arr: Vec<u8> = color.get()?;
+            if arr.len() < 3 {
+                return Err(rquickjs::Error::FromJs {
+                    from: "array",
+                    to: "color",
+                    message: Some(format!(
+                        "color array must have at least 3 elements, got {}",
+                        arr.len()
+                    )),
+                });
+            }
+            fresh_core::api::OverlayColorSpec::Rgb(arr[0], arr[1], arr[2])
+        };
+
+        // Track namespace for cleanup on unload
+        self.plugin_tracked_state
+            .borrow_mut()
+            .entry(self.plugin_name.clone())
+            .or_default()
+            .line_indicator_namespaces
+            .push((BufferId(buffer_id as usize), namespace.clone()));
+
+        Ok(self
+            .command_sender
+            .send(PluginCommand::SetGutterIcon {
+                buffer_id: BufferId(buffer_id as usize),
+                line: line as usize,
+                namespace,
+                glyph,
+                color,
+                priority: priority.0.unwrap_or(0),
+            })
+            .is_ok())
+    }
+
+    /// Clear the gutter icons in a namespace
+    pub fn clear_gutter_icons(&self, buffer_id: u32, namespace: String) -> bool {
+        self.clear_line_indicators(buffer_id, namespace)
+    }
+
     /// Clear line indicators in a namespace
     pub fn clear_line_indicators(&self, buffer_id: u32, namespace: String) -> bool {
         self.command_sender
@@ -5567,6 +5909,51 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Show a bordered floating popup with `lines` at `anchor`: a buffer
+    /// position (`{position}`) or a screen cell (`{x, y}`). Returns a handle
+    /// for `updatePopup` / `closePopup`. The user can dismiss the popup at
+    /// any time; updating or closing it after that is a no-op.
+    pub fn show_popup(
+        &self,
+        lines: Vec<String>,
+        anchor: fresh_core::api::PopupAnchor,
+        options: rquickjs::function::Opt<fresh_core::api::PopupOptions>,
+    ) -> u64 {
+        let handle = self.alloc_handle_id();
+        let _ = self.command_sender.send(PluginCommand::ShowPopup {
+            handle,
+            lines,
+            anchor,
+            options: options.0.unwrap_or_default(),
+        });
+        handle
+    }
+
+    /// Replace the lines of a popup opened with `showPopup`
+    pub fn update_popup(&self, handle: u64, lines: Vec<String>) -> bool {
+        self.command_sender
+            .send(PluginCommand::UpdatePopup { handle, lines })
+            .is_ok()
+    }
+
+    /// Close a popup opened with `showPopup`
+    pub fn close_popup(&self, handle: u64) -> bool {
+        self.command_sender
+            .send(PluginCommand::ClosePopup { handle })
+            .is_ok()
+    }
+
+    /// Show the read-only output panel `id` in a split with `html` rendered
+    /// into it. Supports a constrained subset: `<h1>`-`<h6>`, `<p>`,
+    /// `<ul>`/`<ol>` with `<li>`, `<pre>`/`<code>`, `<b>`/`<strong>`,
+    /// `<i>`/`<em>`, `<br>` and `<hr>`; other tags are dropped but their
+    /// text is kept. Showing an `id` that is already open replaces its content.
+    pub fn show_output_panel(&self, id: String, html: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::ShowOutputPanel { id, html })
+            .is_ok()
+    }
+
     /// Contribute (or replace, or clear) menu rows for the LSP-Servers
     /// popup. Pass an empty `items` to clear this plugin's slice for
     /// the given language. See `PluginCommand::SetLspMenuContributions`.
@@ -5949,16 +6336,31 @@ impl JsEditorApi {
 
     /// Set virtual buffer content (takes array of entry objects)
     ///
-    /// Note: entries should be TextPropertyEntry[] - uses manual parsing for HashMap support
+    /// Note: entries should be TextPropertyEntry[] - uses manual parsing for HashMap support.
+    /// Entries without a string `text` are skipped with a warning; an empty
+    /// array clears the buffer.
     pub fn set_virtual_buffer_content<'js>(
         &self,
         ctx: rquickjs::Ctx<'js>,
         buffer_id: u32,
-        entries_arr: Vec<rquickjs::Object<'js>>,
+        #[plugin_api(ts_type = "TextPropertyEntry[]")] entries_arr: Vec<rquickjs::Value<'js>>,
     ) -> rquickjs::Result<bool> {
         let entries: Vec<TextPropertyEntry> = entries_arr
             .iter()
-            .filter_map(|obj| parse_text_property_entry(&ctx, obj))
+            .enumerate()
+            .filter_map(|(index, value)| {
+                let entry = value
+                    .as_object()
+                    .and_then(|obj| parse_text_property_entry(&ctx, obj));
+                if entry.is_none() {
+                    tracing::warn!(
+                        "setVirtualBufferContent: skipping malformed entry {} for buffer {}",
+                        index,
+                        buffer_id
+                    );
+                }
+                entry
+            })
             .collect();
         Ok(self
             .command_sender
@@ -6231,12 +6633,13 @@ impl JsEditorApi {
     #[qjs(rename = "_spawnProcessStart")]
     pub fn spawn_process_start(
         &self,
-        _ctx: rquickjs::Ctx<'_>,
+        ctx: rquickjs::Ctx<'_>,
         command: String,
         args: Vec<String>,
         cwd: rquickjs::function::Opt<String>,
         stdout_to: rquickjs::function::Opt<String>,
-    ) -> u64 {
+    ) -> rquickjs::Result<u64> {
+        self.require(&ctx, PluginCapability::ProcessSpawn, "spawnProcess")?;
         let id = self.alloc_request_id();
         // Use provided cwd, or fall back to snapshot's working_dir.
         // An explicit empty string is treated the same as omitting the
@@ -6269,7 +6672,7 @@ impl JsEditorApi {
             cwd: effective_cwd,
             stdout_to: stdout_to_path,
         });
-        id
+        Ok(id)
     }
 
     /// Spawn a process on the host regardless of the active authority.
@@ -6286,11 +6689,12 @@ impl JsEditorApi {
     #[qjs(rename = "_spawnHostProcessStart")]
     pub fn spawn_host_process_start(
         &self,
-        _ctx: rquickjs::Ctx<'_>,
+        ctx: rquickjs::Ctx<'_>,
         command: String,
         args: Vec<String>,
         cwd: rquickjs::function::Opt<String>,
-    ) -> u64 {
+    ) -> rquickjs::Result<u64> {
+        self.require(&ctx, PluginCapability::ProcessSpawn, "spawnHostProcess")?;
         let id = self.alloc_request_id();
         let effective_cwd = cwd.0.or_else(|| {
             self.state_snapshot
@@ -6304,7 +6708,7 @@ impl JsEditorApi {
             args,
             cwd: effective_cwd,
         });
-        id
+        Ok(id)
     }
 
     /// Cancel a host-side process started via `spawnHostProcess`.
@@ -6460,10 +6864,11 @@ impl JsEditorApi {
     #[qjs(rename = "_httpFetchStart")]
     pub fn http_fetch_start(
         &self,
-        _ctx: rquickjs::Ctx<'_>,
+        ctx: rquickjs::Ctx<'_>,
         url: String,
         target_path: String,
-    ) -> u64 {
+    ) -> rquickjs::Result<u64> {
+        self.require(&ctx, PluginCapability::Network, "httpFetch")?;
         let id = self.alloc_request_id();
         tracing::info!(
             "http_fetch_start: plugin='{}', url='{}', target='{}', callback_id={}",
@@ -6477,7 +6882,7 @@ impl JsEditorApi {
             target_path: std::path::PathBuf::from(target_path),
             callback_id: JsCallbackId::new(id),
         });
-        id
+        Ok(id)
     }
 
     /// Wait for a process to complete and get its result (async)
@@ -6727,11 +7132,16 @@ impl JsEditorApi {
     #[qjs(rename = "_spawnBackgroundProcessStart")]
     pub fn spawn_background_process_start(
         &self,
-        _ctx: rquickjs::Ctx<'_>,
+        ctx: rquickjs::Ctx<'_>,
         command: String,
         args: Vec<String>,
         cwd: rquickjs::function::Opt<String>,
-    ) -> u64 {
+    ) -> rquickjs::Result<u64> {
+        self.require(
+            &ctx,
+            PluginCapability::ProcessSpawn,
+            "spawnBackgroundProcess",
+        )?;
         let id = self.alloc_request_id();
         // Use id as process_id for simplicity
         let process_id = id;
@@ -6752,7 +7162,7 @@ impl JsEditorApi {
                 cwd: cwd.0.filter(|s| !s.is_empty()),
                 callback_id: JsCallbackId::new(id),
             });
-        id
+        Ok(id)
     }
 
     /// Kill a background process
@@ -6773,9 +7183,11 @@ impl JsEditorApi {
     #[qjs(rename = "_createTerminalStart")]
     pub fn create_terminal_start(
         &self,
-        _ctx: rquickjs::Ctx<'_>,
+        ctx: rquickjs::Ctx<'_>,
         opts: rquickjs::function::Opt<fresh_core::api::CreateTerminalOptions>,
     ) -> rquickjs::Result<u64> {
+        // A terminal always spawns a process: `command`, or the user's shell.
+        self.require(&ctx, PluginCapability::ProcessSpawn, "createTerminal")?;
         let id = self.alloc_request_id();
 
         let opts = opts.0.unwrap_or(fresh_core::api::CreateTerminalOptions {
@@ -6827,9 +7239,14 @@ impl JsEditorApi {
     #[qjs(rename = "_createWindowWithTerminalStart")]
     pub fn create_window_with_terminal_start(
         &self,
-        _ctx: rquickjs::Ctx<'_>,
+        ctx: rquickjs::Ctx<'_>,
         opts: fresh_core::api::CreateWindowWithTerminalOptions,
     ) -> rquickjs::Result<u64> {
+        self.require(
+            &ctx,
+            PluginCapability::ProcessSpawn,
+            "createWindowWithTerminal",
+        )?;
         let id = self.alloc_request_id();
         if let Ok(mut owners) = self.async_resource_owners.lock() {
             owners.insert(id, self.plugin_name.clone());
@@ -7166,6 +7583,10 @@ pub struct QuickJsBackend {
     plugin_api_exports: PluginApiExports,
     /// Streaming-search handle registry shared with the editor thread.
     search_handles: SearchHandleRegistry,
+    /// `setTimeout` / `setInterval` timers of every plugin context.
+    timers: Rc<RefCell<TimerQueue>>,
+    /// Capabilities granted per plugin, from `plugins.<name>.permissions`.
+    plugin_permissions: Rc<RefCell<HashMap<String, PluginPermissions>>>,
 }
 
 impl Drop for QuickJsBackend {
@@ -7423,6 +7844,7 @@ const EDITOR_PROMISE_BOOTSTRAP: &str = r#"
                 editor.getNextKey = _wrapAsync("_getNextKeyStart", "getNextKey");
                 editor.getLineStartPosition = _wrapAsync("_getLineStartPositionStart", "getLineStartPosition");
                 editor.getLineEndPosition = _wrapAsync("_getLineEndPositionStart", "getLineEndPosition");
+                editor.getRenderedRegion = _wrapAsync("_getRenderedRegionStart", "getRenderedRegion");
                 editor.createTerminal = _wrapAsync("_createTerminalStart", "createTerminal");
                 editor.createWindowWithTerminal = _wrapAsync("_createWindowWithTerminalStart", "createWindowWithTerminal");
                 editor.reloadGrammars = _wrapAsync("_reloadGrammarsStart", "reloadGrammars");
@@ -7478,9 +7900,8 @@ fn install_console<'js>(
         "log",
         Function::new(
             ctx.clone(),
-            |ctx: rquickjs::Ctx, args: rquickjs::function::Rest<rquickjs::Value>| {
-                let parts: Vec<String> =
-                    args.0.iter().map(|v| js_value_to_string(&ctx, v)).collect();
+            |args: rquickjs::function::Rest<rquickjs::Value>| {
+                let parts: Vec<String> = args.0.iter().map(js_value_to_string).collect();
                 tracing::info!("console.log: {}", parts.join(" "));
             },
         )?,
@@ -7489,9 +7910,8 @@ fn install_console<'js>(
         "warn",
         Function::new(
             ctx.clone(),
-            |ctx: rquickjs::Ctx, args: rquickjs::function::Rest<rquickjs::Value>| {
-                let parts: Vec<String> =
-                    args.0.iter().map(|v| js_value_to_string(&ctx, v)).collect();
+            |args: rquickjs::function::Rest<rquickjs::Value>| {
+                let parts: Vec<String> = args.0.iter().map(js_value_to_string).collect();
                 tracing::warn!("console.warn: {}", parts.join(" "));
             },
         )?,
@@ -7500,9 +7920,8 @@ fn install_console<'js>(
         "error",
         Function::new(
             ctx.clone(),
-            |ctx: rquickjs::Ctx, args: rquickjs::function::Rest<rquickjs::Value>| {
-                let parts: Vec<String> =
-                    args.0.iter().map(|v| js_value_to_string(&ctx, v)).collect();
+            |args: rquickjs::function::Rest<rquickjs::Value>| {
+                let parts: Vec<String> = args.0.iter().map(js_value_to_string).collect();
                 tracing::error!("console.error: {}", parts.join(" "));
             },
         )?,
@@ -7511,26 +7930,218 @@ fn install_console<'js>(
     Ok(())
 }
 
-impl QuickJsBackend {
-    /// Create a new QuickJS backend (standalone, for testing)
-    pub fn new() -> Result<Self> {
-        let (tx, _rx) = mpsc::channel();
-        let state_snapshot = Arc::new(RwLock::new(EditorStateSnapshot::new()));
-        let services = Arc::new(fresh_core::services::NoopServiceBridge);
-        Self::with_state(state_snapshot, tx, services)
-    }
+/// Timer globals over the native `__timerStart` / `__timerClear` helpers
+/// installed by `install_timers`, which this shim removes from the global
+/// scope. Keeps each timer's callback until `__fireTimer` runs it.
+const TIMER_SHIM: &str = r#"
+                (function() {
+                    const start = globalThis.__timerStart;
+                    const clear = globalThis.__timerClear;
+                    delete globalThis.__timerStart;
+                    delete globalThis.__timerClear;
+                    // id -> { callback, args, repeat }. The callback may be a
+                    // function or the name of a global handler.
+                    const timers = new Map();
+                    function schedule(callback, ms, args, repeat) {
+                        if (typeof callback !== "function" && typeof callback !== "string") {
+                            throw new TypeError("Timer callback must be a function or a handler name");
+                        }
+                        const id = start(Math.max(0, Number(ms) || 0), repeat);
+                        timers.set(id, { callback, args, repeat });
+                        return id;
+                    }
+                    function cancel(id) {
+                        if (timers.delete(id)) clear(id);
+                    }
+                    globalThis.setTimeout = (callback, ms, ...args) => schedule(callback, ms, args, false);
+                    globalThis.setInterval = (callback, ms, ...args) => schedule(callback, ms, args, true);
+                    globalThis.clearTimeout = cancel;
+                    globalThis.clearInterval = cancel;
+                    globalThis.__fireTimer = function(id) {
+                        const timer = timers.get(id);
+                        if (!timer) return;
+                        if (!timer.repeat) timers.delete(id);
+                        const fn = typeof timer.callback === "string"
+                            ? globalThis[timer.callback]
+                            : timer.callback;
+                        if (typeof fn !== "function") {
+                            console.error(`Timer handler ${timer.callback} is not defined as a global function`);
+                            return;
+                        }
+                        fn(...timer.args);
+                    };
+                })();
+                "#;
 
-    /// Create a new QuickJS backend with editor state
-    pub fn with_state(
-        state_snapshot: Arc<RwLock<EditorStateSnapshot>>,
-        command_sender: mpsc::Sender<PluginCommand>,
-        services: Arc<dyn fresh_core::services::PluginServiceBridge>,
-    ) -> Result<Self> {
-        let pending_responses: PendingResponses = Arc::new(std::sync::Mutex::new(HashMap::new()));
-        Self::with_state_and_responses(state_snapshot, command_sender, pending_responses, services)
+/// Install `setTimeout` / `setInterval` and their `clear*` counterparts.
+/// Due times live in the backend's shared [`TimerQueue`]; `TIMER_SHIM`
+/// keeps the callbacks and runs them from `QuickJsBackend::fire_due_timers`.
+fn install_timers<'js>(
+    ctx: &rquickjs::Ctx<'js>,
+    globals: &rquickjs::Object<'js>,
+    plugin_name: &str,
+    timers: &Rc<RefCell<TimerQueue>>,
+) -> rquickjs::Result<()> {
+    let (queue, plugin) = (Rc::clone(timers), plugin_name.to_string());
+    globals.set(
+        "__timerStart",
+        Function::new(ctx.clone(), move |ms: f64, repeat: bool| -> u64 {
+            // Like browsers, cap delays at i32::MAX milliseconds.
+            let delay = std::time::Duration::from_millis(ms.clamp(0.0, i32::MAX as f64) as u64);
+            queue
+                .borrow_mut()
+                .start(&plugin, delay, repeat, std::time::Instant::now())
+        })?,
+    )?;
+    let (queue, plugin) = (Rc::clone(timers), plugin_name.to_string());
+    globals.set(
+        "__timerClear",
+        Function::new(ctx.clone(), move |id: u64| {
+            queue.borrow_mut().clear(&plugin, id);
+        })?,
+    )?;
+    ctx.eval::<(), _>(TIMER_SHIM)
+}
+
+/// `TextEncoder` / `TextDecoder` classes over the native helpers installed
+/// by `install_text_codecs`, which this shim removes from the global scope.
+const TEXT_CODEC_SHIM: &str = r#"
+                (function() {
+                    const encode = globalThis.__textEncode;
+                    const decode = globalThis.__textDecode;
+                    const encodingFor = globalThis.__textEncodingFor;
+                    delete globalThis.__textEncode;
+                    delete globalThis.__textDecode;
+                    delete globalThis.__textEncodingFor;
+                    globalThis.TextEncoder = class TextEncoder {
+                        get encoding() { return "utf-8"; }
+                        encode(input = "") { return encode(String(input)); }
+                    };
+                    globalThis.TextDecoder = class TextDecoder {
+                        constructor(label = "utf-8", options) {
+                            const opts = options || {};
+                            this.encoding = encodingFor(String(label));
+                            this.fatal = !!opts.fatal;
+                            this.ignoreBOM = !!opts.ignoreBOM;
+                        }
+                        decode(input) {
+                            return decode(input, this.fatal, this.ignoreBOM);
+                        }
+                    };
+                })();
+                "#;
+
+/// The canonical name for a `TextDecoder` encoding label, or `None` when
+/// the encoding is unsupported. Only UTF-8 is implemented.
+fn text_encoding_for_label(label: &str) -> Option<&'static str> {
+    match label.trim().to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" | "unicode-1-1-utf-8" => Some("utf-8"),
+        _ => None,
     }
+}
 
-    /// Create a new QuickJS backend with editor state and shared pending responses
+/// UTF-8 decode as `TextDecoder.decode` does: a leading BOM is dropped
+/// unless `ignore_bom`, and invalid sequences become U+FFFD unless
+/// `fatal`, in which case they yield `None`.
+fn decode_utf8(bytes: &[u8], fatal: bool, ignore_bom: bool) -> Option<String> {
+    let bytes = if ignore_bom {
+        bytes
+    } else {
+        bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes)
+    };
+    if fatal {
+        std::str::from_utf8(bytes).ok().map(str::to_string)
+    } else {
+        Some(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+/// Install the `TextEncoder` / `TextDecoder` globals. The codecs run in
+/// Rust; `TEXT_CODEC_SHIM` only wraps them in the standard classes.
+fn install_text_codecs<'js>(
+    ctx: &rquickjs::Ctx<'js>,
+    globals: &rquickjs::Object<'js>,
+) -> rquickjs::Result<()> {
+    globals.set(
+        "__textEncode",
+        Function::new(
+            ctx.clone(),
+            |ctx: rquickjs::Ctx<'js>,
+             input: String|
+             -> rquickjs::Result<rquickjs::TypedArray<'js, u8>> {
+                rquickjs::TypedArray::new(ctx, input.into_bytes())
+            },
+        )?,
+    )?;
+    globals.set(
+        "__textEncodingFor",
+        Function::new(
+            ctx.clone(),
+            |ctx: rquickjs::Ctx<'js>, label: String| -> rquickjs::Result<String> {
+                text_encoding_for_label(&label)
+                    .map(str::to_string)
+                    .ok_or_else(|| {
+                        rquickjs::Exception::throw_range(
+                            &ctx,
+                            &format!("The \"{}\" encoding is not supported", label),
+                        )
+                    })
+            },
+        )?,
+    )?;
+    globals.set(
+        "__textDecode",
+        Function::new(
+            ctx.clone(),
+            |ctx: rquickjs::Ctx<'js>,
+             input: Value<'js>,
+             fatal: bool,
+             ignore_bom: bool|
+             -> rquickjs::Result<String> {
+                let bytes = if input.is_undefined() {
+                    Vec::new()
+                } else if let Ok(array) = input.get::<rquickjs::TypedArray<'js, u8>>() {
+                    array.as_bytes().unwrap_or_default().to_vec()
+                } else if let Ok(buffer) = input.get::<rquickjs::ArrayBuffer<'js>>() {
+                    buffer.as_bytes().unwrap_or_default().to_vec()
+                } else {
+                    return Err(rquickjs::Exception::throw_type(
+                        &ctx,
+                        "TextDecoder.decode: input must be a Uint8Array or ArrayBuffer",
+                    ));
+                };
+                decode_utf8(&bytes, fatal, ignore_bom).ok_or_else(|| {
+                    rquickjs::Exception::throw_type(
+                        &ctx,
+                        "The encoded data was not valid for encoding utf-8",
+                    )
+                })
+            },
+        )?,
+    )?;
+    ctx.eval::<(), _>(TEXT_CODEC_SHIM)
+}
+
+impl QuickJsBackend {
+    /// Create a new QuickJS backend (standalone, for testing)
+    pub fn new() -> Result<Self> {
+        let (tx, _rx) = mpsc::channel();
+        let state_snapshot = Arc::new(RwLock::new(EditorStateSnapshot::new()));
+        let services = Arc::new(fresh_core::services::NoopServiceBridge);
+        Self::with_state(state_snapshot, tx, services)
+    }
+
+    /// Create a new QuickJS backend with editor state
+    pub fn with_state(
+        state_snapshot: Arc<RwLock<EditorStateSnapshot>>,
+        command_sender: mpsc::Sender<PluginCommand>,
+        services: Arc<dyn fresh_core::services::PluginServiceBridge>,
+    ) -> Result<Self> {
+        let pending_responses: PendingResponses = Arc::new(std::sync::Mutex::new(HashMap::new()));
+        Self::with_state_and_responses(state_snapshot, command_sender, pending_responses, services)
+    }
+
+    /// Create a new QuickJS backend with editor state and shared pending responses
     pub fn with_state_and_responses(
         state_snapshot: Arc<RwLock<EditorStateSnapshot>>,
         command_sender: mpsc::Sender<PluginCommand>,
@@ -7630,6 +8241,8 @@ impl QuickJsBackend {
             registered_lsp_servers,
             plugin_api_exports,
             search_handles,
+            timers: Rc::new(RefCell::new(TimerQueue::default())),
+            plugin_permissions: Rc::new(RefCell::new(HashMap::new())),
         };
 
         // Initialize main context (for internal utilities if needed)
@@ -7660,6 +8273,7 @@ impl QuickJsBackend {
             registered_lsp_servers: Rc::clone(&self.registered_lsp_servers),
             plugin_api_exports: Rc::clone(&self.plugin_api_exports),
             search_handles: Arc::clone(&self.search_handles),
+            plugin_permissions: Rc::clone(&self.plugin_permissions),
             plugin_name: plugin_name.to_string(),
         }
     }
@@ -7683,11 +8297,13 @@ impl QuickJsBackend {
 
                 // Bootstrap, in order: the getEditor()/registerHandler()
                 // globals, the closure-friendly editor.on/off shim, a console
-                // that forwards to tracing, then the Promise/async-wrapper
-                // infrastructure.
+                // that forwards to tracing, TextEncoder/TextDecoder, timers,
+                // then the Promise/async-wrapper infrastructure.
                 ctx.eval::<(), _>(EDITOR_GLOBALS_BOOTSTRAP)?;
                 ctx.eval::<(), _>(EDITOR_ON_OFF_SHIM)?;
                 install_console(&ctx, &globals)?;
+                install_text_codecs(&ctx, &globals)?;
+                install_timers(&ctx, &globals, plugin_name, &self.timers)?;
                 ctx.eval::<(), _>(EDITOR_PROMISE_BOOTSTRAP.as_bytes())?;
 
                 Ok::<_, rquickjs::Error>(())
@@ -7711,11 +8327,12 @@ impl QuickJsBackend {
             .file_name()
             .and_then(|s| s.to_str())
             .unwrap_or("plugin.ts");
+        let cache = TranspileCache::in_config_dir(&self.services.config_dir());
 
         // Check for ES imports - these need bundling to resolve dependencies
         if has_es_imports(&source) {
             // Try to bundle (this also strips imports and exports)
-            match bundle_module(&path_buf) {
+            match cache.bundle(&path_buf) {
                 Ok(bundled) => {
                     self.execute_js(&bundled, path)?;
                 }
@@ -7732,7 +8349,7 @@ impl QuickJsBackend {
             // Has exports but no imports - strip exports and transpile
             let stripped = strip_imports_and_exports(&source);
             let js_code = if filename.ends_with(".ts") {
-                transpile_typescript(&stripped, filename)?
+                cache.transpile(&stripped, filename)?
             } else {
                 stripped
             };
@@ -7740,7 +8357,7 @@ impl QuickJsBackend {
         } else {
             // Plain code - just transpile if TypeScript
             let js_code = if filename.ends_with(".ts") {
-                transpile_typescript(&source, filename)?
+                cache.transpile(&source, filename)?
             } else {
                 source
             };
@@ -7854,6 +8471,23 @@ impl QuickJsBackend {
         self.execute_js(&js_code, &source_name)
     }
 
+    /// Grant `plugin_name` the given capabilities. Applies to calls made
+    /// from then on, so set it before the plugin's code runs.
+    pub fn set_plugin_permissions(&self, plugin_name: &str, permissions: PluginPermissions) {
+        self.plugin_permissions
+            .borrow_mut()
+            .insert(plugin_name.to_string(), permissions);
+    }
+
+    /// Capabilities granted to `plugin_name` (all of them if never set).
+    pub fn plugin_permissions(&self, plugin_name: &str) -> PluginPermissions {
+        self.plugin_permissions
+            .borrow()
+            .get(plugin_name)
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Clean up all runtime state owned by a plugin.
     ///
     /// This removes the plugin's JS context, event handlers, registered actions,
@@ -7883,10 +8517,11 @@ impl QuickJsBackend {
             .borrow_mut()
             .retain(|_, h| h.plugin_name != plugin_name);
 
-        // 4. Remove callback contexts for this plugin
+        // 4. Remove callback contexts and timers for this plugin
         self.callback_contexts
             .borrow_mut()
             .retain(|_, pname| pname != plugin_name);
+        self.timers.borrow_mut().clear_plugin(plugin_name);
 
         // 5. Send compensating commands for editor-side state
         if let Some(tracked) = self.plugin_tracked_state.borrow_mut().remove(plugin_name) {
@@ -8284,6 +8919,73 @@ impl QuickJsBackend {
         Ok(())
     }
 
+    /// Call a text-object handler registered with `registerTextObject` and
+    /// send its range back to the editor as `TextObjectResolved`. The
+    /// handler may be async; the result is reported once it settles, and a
+    /// missing or throwing handler reports `null`.
+    pub fn resolve_text_object(
+        &mut self,
+        plugin_name: &str,
+        handler_name: &str,
+        request_id: u64,
+        args: &serde_json::Value,
+    ) {
+        let plugin_contexts = self.plugin_contexts.borrow();
+        let context = plugin_contexts
+            .get(plugin_name)
+            .unwrap_or(&self.main_context);
+
+        let code = format!(
+            r#"
+            (async function() {{
+                let range = null;
+                try {{
+                    if (typeof globalThis.{fn} === 'function') {{
+                        range = await globalThis.{fn}({args});
+                    }} else {{
+                        console.error('Text object handler {fn} is not defined as a global function');
+                    }}
+                }} catch (e) {{
+                    console.error('Text object handler {fn} error:', e);
+                }}
+                editor._resolveTextObject({request_id}, range ?? null);
+            }})();
+            "#,
+            fn = handler_name,
+        );
+
+        context.with(|ctx| {
+            if let Err(e) = ctx.eval::<rquickjs::Value, _>(code.as_bytes()) {
+                log_js_error(&ctx, e, &format!("text object {}", handler_name));
+            }
+            run_pending_jobs_checked(&ctx, &format!("text object {}", handler_name));
+        });
+    }
+
+    /// When the earliest pending `setTimeout` / `setInterval` timer is due.
+    pub fn next_timer_deadline(&self) -> Option<std::time::Instant> {
+        self.timers.borrow_mut().next_deadline()
+    }
+
+    /// Run the callbacks of every timer that is due. Returns whether any
+    /// fired.
+    pub fn fire_due_timers(&mut self) -> bool {
+        let due = self.timers.borrow_mut().take_due(std::time::Instant::now());
+        for (id, plugin_name) in &due {
+            let Some(context) = self.plugin_contexts.borrow().get(plugin_name).cloned() else {
+                continue;
+            };
+            context.with(|ctx| {
+                let code = format!("globalThis.__fireTimer({});", id);
+                if let Err(e) = ctx.eval::<(), _>(code.as_bytes()) {
+                    log_js_error(&ctx, e, &format!("timer callback in {}", plugin_name));
+                }
+                run_pending_jobs_checked(&ctx, &format!("timer {}", plugin_name));
+            });
+        }
+        !due.is_empty()
+    }
+
     /// Poll the event loop once to run any pending microtasks
     pub fn poll_event_loop_once(&mut self) -> bool {
         let mut had_work = false;
@@ -8717,6 +9419,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_register_and_resolve_text_object() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.wordAt = function(ctx) {
+                return ctx.around ? null : { start: ctx.position, end: ctx.position + 3 };
+            };
+            editor.registerTextObject("f", "wordAt");
+        "#,
+                "test_plugin.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::RegisterTextObject {
+                plugin_name,
+                key,
+                handler_name,
+            } => {
+                assert_eq!(plugin_name, "test_plugin");
+                assert_eq!(key, 'f');
+                assert_eq!(handler_name, "wordAt");
+            }
+            cmd => panic!("Expected RegisterTextObject, got {:?}", cmd),
+        }
+
+        let args = serde_json::json!({ "bufferId": 1, "position": 4, "around": false });
+        backend.resolve_text_object("test_plugin", "wordAt", 7, &args);
+        match rx.try_recv().unwrap() {
+            PluginCommand::TextObjectResolved { request_id, range } => {
+                assert_eq!(request_id, 7);
+                assert_eq!(range, Some(4..7));
+            }
+            cmd => panic!("Expected TextObjectResolved, got {:?}", cmd),
+        }
+
+        let args = serde_json::json!({ "bufferId": 1, "position": 4, "around": true });
+        backend.resolve_text_object("test_plugin", "wordAt", 8, &args);
+        match rx.try_recv().unwrap() {
+            PluginCommand::TextObjectResolved { request_id, range } => {
+                assert_eq!(request_id, 8);
+                assert_eq!(range, None);
+            }
+            cmd => panic!("Expected TextObjectResolved, got {:?}", cmd),
+        }
+    }
+
     #[test]
     fn test_api_define_mode() {
         let (mut backend, rx) = create_test_backend();
@@ -9098,6 +9851,45 @@ mod tests {
         assert_eq!(got, vec!["alpha got 7", "beta got 7"]);
     }
 
+    #[tokio::test]
+    async fn test_emit_event_passes_nested_objects_to_closure_handlers() {
+        // Handlers receive the payload as a live object graph (no
+        // `JSON.parse` round-trip), whether registered by name or as a
+        // closure.
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.on("nestedEvent", (data) => {
+                const first = data.ranges[0];
+                editor.setStatus(
+                    `${data.meta.path} ${first.start}-${first.end} ` +
+                        `${Array.isArray(data.ranges)} ${data.meta.tags.join("+")} ` +
+                        `${data.meta.missing === null}`,
+                );
+            });
+        "#,
+                "test.js",
+            )
+            .unwrap();
+        while rx.try_recv().is_ok() {}
+
+        let event_data: serde_json::Value = serde_json::json!({
+            "meta": { "path": "/a \"quoted\".rs", "tags": ["x", "y"], "missing": null },
+            "ranges": [{ "start": 3, "end": 9 }],
+        });
+        backend.emit("nestedEvent", &event_data).await.unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetStatus { message } => {
+                assert_eq!(message, r#"/a "quoted".rs 3-9 true x+y true"#);
+            }
+            cmd => panic!("Expected SetStatus from event handler, got {:?}", cmd),
+        }
+    }
+
     #[tokio::test]
     async fn test_emit_event_preserves_integers_beyond_i32() {
         // Hook payloads carry u64s (timestamps, byte offsets, ids). The
@@ -9305,6 +10097,58 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_api_get_text_properties_at_cursor_returns_covering_span() {
+        let (tx, _rx) = mpsc::channel();
+        let state_snapshot = Arc::new(RwLock::new(EditorStateSnapshot::new()));
+
+        // Two entries of a virtual buffer: "alpha\n" (0..6) and "beta\n" (6..11)
+        {
+            let mut state = state_snapshot.write().unwrap();
+            let buffer = BufferId(7);
+            state.buffer_text_properties.insert(
+                buffer,
+                vec![
+                    fresh_core::text_property::TextProperty::new(0, 6)
+                        .with_property("name", serde_json::Value::from("alpha")),
+                    fresh_core::text_property::TextProperty::new(6, 11)
+                        .with_property("name", serde_json::Value::from("beta")),
+                ],
+            );
+            state.buffer_cursor_positions.insert(buffer, 8);
+            state.buffer_cursor_positions.insert(BufferId(8), 3);
+        }
+
+        let services = Arc::new(fresh_core::services::NoopServiceBridge);
+        let mut backend = QuickJsBackend::with_state(state_snapshot, tx, services).unwrap();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis._inSpan = JSON.stringify(editor.getTextPropertiesAtCursor(7));
+            globalThis._noProps = JSON.stringify(editor.getTextPropertiesAtCursor(8));
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                let in_span: String = global.get("_inSpan").unwrap();
+                assert_eq!(in_span, r#"[{"name":"beta"}]"#);
+                // A buffer without text properties yields an empty array, not null.
+                let no_props: String = global.get("_noProps").unwrap();
+                assert_eq!(no_props, "[]");
+            });
+    }
+
     /// Ad-hoc plugin probe for the cursor-line API (issue #2076).
     ///
     /// Exercises the real JS plugin surface (`getPrimaryCursor().line`,
@@ -9893,6 +10737,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_set_gutter_icon_accepts_theme_key_and_default_priority() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.setGutterIcon(1, 3, "●", "diagnostic.error_fg", "breakpoints");
+            editor.setGutterIcon(1, 4, "│", [0, 200, 0], "git", 5);
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetGutterIcon {
+                line,
+                namespace,
+                glyph,
+                color,
+                priority,
+                ..
+            } => {
+                assert_eq!(line, 3);
+                assert_eq!(namespace, "breakpoints");
+                assert_eq!(glyph, "●");
+                assert_eq!(
+                    color,
+                    fresh_core::api::OverlayColorSpec::ThemeKey("diagnostic.error_fg".into())
+                );
+                assert_eq!(priority, 0);
+            }
+            cmd => panic!("Expected SetGutterIcon, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetGutterIcon {
+                color, priority, ..
+            } => {
+                assert_eq!(color, fresh_core::api::OverlayColorSpec::Rgb(0, 200, 0));
+                assert_eq!(priority, 5);
+            }
+            cmd => panic!("Expected SetGutterIcon, got {:?}", cmd),
+        }
+    }
+
     // ==================== Virtual Buffer Tests ====================
 
     #[test]
@@ -9945,6 +10835,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_create_virtual_buffer_in_split_resolves_ids() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis._created = null;
+            editor.createVirtualBufferInSplit({
+                name: "*Diff*",
+                mode: "diff-view",
+                direction: "vertical",
+                entries: [{ text: "hunk\n" }]
+            }).then(result => {
+                globalThis._created = result;
+            });
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        let request_id = match rx.try_recv().unwrap() {
+            PluginCommand::CreateVirtualBufferInSplit {
+                name,
+                direction,
+                entries,
+                request_id,
+                ..
+            } => {
+                assert_eq!(name, "*Diff*");
+                assert_eq!(direction.as_deref(), Some("vertical"));
+                assert_eq!(entries.len(), 1);
+                request_id.expect("the split is created with a pending request")
+            }
+            cmd => panic!("Expected CreateVirtualBufferInSplit, got {:?}", cmd),
+        };
+        assert!(request_id > 0);
+
+        backend.resolve_callback(
+            JsCallbackId::from(request_id),
+            r#"{"bufferId":7,"splitId":3}"#,
+        );
+        let context = backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone();
+        context.with(|ctx| {
+            run_pending_jobs_checked(&ctx, "test createVirtualBufferInSplit");
+            let created: rquickjs::Object = ctx.globals().get("_created").unwrap();
+            assert_eq!(created.get::<_, u64>("bufferId").unwrap(), 7);
+            assert_eq!(created.get::<_, u64>("splitId").unwrap(), 3);
+        });
+    }
+
     #[test]
     fn test_api_set_virtual_buffer_content() {
         let (mut backend, rx) = create_test_backend();
@@ -9972,27 +10919,64 @@ mod tests {
         }
     }
 
-    // ==================== Overlay Tests ====================
-
     #[test]
-    fn test_api_add_overlay() {
+    fn test_api_set_virtual_buffer_content_skips_malformed_entries() {
         let (mut backend, rx) = create_test_backend();
 
         backend
             .execute_js(
                 r#"
             const editor = getEditor();
-            editor.addOverlay(1, "highlight", 10, 20, {
-                fg: [255, 128, 0],
-                bg: [50, 50, 50],
-                bold: true,
-            });
+            editor.setVirtualBufferContent(5, [
+                { text: "kept\n", properties: { file: "a.rs" } },
+                "not an entry",
+                { properties: { type: "no text" } },
+            ]);
+            editor.setVirtualBufferContent(5, []);
         "#,
                 "test.js",
             )
             .unwrap();
 
-        let cmd = rx.try_recv().unwrap();
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetVirtualBufferContent { entries, .. } => {
+                assert_eq!(entries.len(), 1);
+                assert_eq!(entries[0].text, "kept\n");
+                assert_eq!(entries[0].properties["file"], "a.rs");
+            }
+            cmd => panic!("Expected SetVirtualBufferContent, got {:?}", cmd),
+        }
+        // An empty array still reaches the editor, clearing the buffer.
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetVirtualBufferContent { buffer_id, entries } => {
+                assert_eq!(buffer_id.0, 5);
+                assert!(entries.is_empty());
+            }
+            cmd => panic!("Expected SetVirtualBufferContent, got {:?}", cmd),
+        }
+    }
+
+    // ==================== Overlay Tests ====================
+
+    #[test]
+    fn test_api_add_overlay() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.addOverlay(1, "highlight", 10, 20, {
+                fg: [255, 128, 0],
+                bg: [50, 50, 50],
+                bold: true,
+            });
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        let cmd = rx.try_recv().unwrap();
         match cmd {
             PluginCommand::AddOverlay {
                 buffer_id,
@@ -11178,6 +12162,257 @@ mod tests {
         std::fs::remove_file(&local_file).ok();
     }
 
+    #[test]
+    fn test_api_read_file_bytes_returns_uint8_array() {
+        let (mut backend, _rx) = create_test_backend();
+        let file =
+            std::env::temp_dir().join(format!("fresh_read_bytes_{}.bin", std::process::id()));
+        std::fs::write(&file, [0u8, 255, 16]).unwrap();
+        let file_js = file.to_string_lossy().replace('\\', "\\\\");
+
+        let js = format!(
+            r#"
+            const editor = getEditor();
+            const bytes = editor.readFileBytes("{file}");
+            globalThis._isUint8Array = bytes instanceof Uint8Array;
+            globalThis._bytes = Array.from(bytes).join(",");
+            globalThis._missing = editor.readFileBytes("{file}.missing") == null;
+        "#,
+            file = file_js,
+        );
+        backend.execute_js(&js, "test.js").unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                assert!(global.get::<_, bool>("_isUint8Array").unwrap());
+                assert_eq!(global.get::<_, String>("_bytes").unwrap(), "0,255,16");
+                assert!(global.get::<_, bool>("_missing").unwrap());
+            });
+
+        std::fs::remove_file(&file).ok();
+    }
+
+    #[test]
+    fn test_api_write_file_denied_without_fs_write() {
+        let (mut backend, _rx) = create_test_backend();
+        backend.set_plugin_permissions(
+            "test",
+            PluginPermissions {
+                fs_write: false,
+                ..Default::default()
+            },
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("out.txt");
+        let file_js = file.to_string_lossy().replace('\\', "\\\\");
+
+        let js = format!(
+            r#"
+            const editor = getEditor();
+            globalThis._written = editor.writeFile("{file}", "data");
+            globalThis._created = editor.createDir("{file}.d");
+        "#,
+            file = file_js,
+        );
+        backend.execute_js(&js, "test.js").unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                assert!(!global.get::<_, bool>("_written").unwrap());
+                assert!(!global.get::<_, bool>("_created").unwrap());
+            });
+        assert!(!file.exists());
+    }
+
+    #[test]
+    fn test_api_read_file_denied_without_fs_read() {
+        let (mut backend, _rx) = create_test_backend();
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("in.txt");
+        std::fs::write(&file, "secret").unwrap();
+        let file_js = file.to_string_lossy().replace('\\', "\\\\");
+
+        // Another plugin's grant doesn't leak into this one.
+        backend.set_plugin_permissions(
+            "other",
+            PluginPermissions {
+                fs_read: false,
+                ..Default::default()
+            },
+        );
+        let js = format!(
+            r#"
+            const editor = getEditor();
+            globalThis._allowed = editor.readFile("{file}");
+        "#,
+            file = file_js,
+        );
+        backend.execute_js(&js, "test.js").unwrap();
+
+        backend.set_plugin_permissions(
+            "test",
+            PluginPermissions {
+                fs_read: false,
+                ..Default::default()
+            },
+        );
+        let js = format!(
+            r#"
+            globalThis._denied = editor.readFile("{file}") === null;
+            globalThis._deniedBytes = editor.readFileBytes("{file}") === null;
+            globalThis._deniedStat = editor.fileStat("{file}") === null;
+            globalThis._deniedDir = editor.readDir("{dir}").length === 0;
+        "#,
+            file = file_js,
+            dir = dir.path().to_string_lossy().replace('\\', "\\\\"),
+        );
+        backend.execute_js(&js, "test.js").unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                assert_eq!(global.get::<_, String>("_allowed").unwrap(), "secret");
+                assert!(global.get::<_, bool>("_denied").unwrap());
+                assert!(global.get::<_, bool>("_deniedBytes").unwrap());
+                assert!(global.get::<_, bool>("_deniedStat").unwrap());
+                assert!(global.get::<_, bool>("_deniedDir").unwrap());
+            });
+    }
+
+    #[test]
+    fn test_api_spawn_process_denied_without_process_spawn() {
+        let (mut backend, rx) = create_test_backend();
+        backend.set_plugin_permissions(
+            "test",
+            PluginPermissions {
+                process_spawn: false,
+                network: false,
+                ..Default::default()
+            },
+        );
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            const threw = (f) => { try { f(); return false; } catch (e) { return true; } };
+            globalThis._spawn = threw(() => editor.spawnProcess("echo", ["hi"]));
+            globalThis._background = threw(() => editor.spawnBackgroundProcess("echo", []));
+            globalThis._fetch = threw(() => editor.httpFetch("https://example.com", "/tmp/x"));
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                assert!(global.get::<_, bool>("_spawn").unwrap());
+                assert!(global.get::<_, bool>("_background").unwrap());
+                assert!(global.get::<_, bool>("_fetch").unwrap());
+            });
+        assert!(rx.try_recv().is_err(), "nothing reaches the editor");
+    }
+
+    #[test]
+    fn test_api_terminal_denied_without_process_spawn() {
+        let (mut backend, rx) = create_test_backend();
+        backend.set_plugin_permissions(
+            "test",
+            PluginPermissions {
+                process_spawn: false,
+                ..Default::default()
+            },
+        );
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            const denied = (f) => {
+                try { f(); return false; } catch (e) { return String(e).includes("process_spawn"); }
+            };
+            globalThis._terminal = denied(() => editor.createTerminal({ command: ["sh", "-c", "echo hi"] }));
+            globalThis._window = denied(() =>
+                editor.createWindowWithTerminal({ root: "/tmp", label: "agent", command: ["sh"] }));
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                assert!(global.get::<_, bool>("_terminal").unwrap());
+                assert!(global.get::<_, bool>("_window").unwrap());
+            });
+        assert!(rx.try_recv().is_err(), "nothing reaches the editor");
+    }
+
+    #[test]
+    fn test_api_save_denied_without_fs_write() {
+        let (mut backend, rx) = create_test_backend();
+        backend.set_plugin_permissions(
+            "test",
+            PluginPermissions {
+                fs_write: false,
+                ..Default::default()
+            },
+        );
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis._saved = editor.saveBufferToPath(1, "/tmp/fresh-denied.txt");
+            try { editor.saveThemeFile("denied", "{}"); globalThis._theme = false; }
+            catch (e) { globalThis._theme = true; }
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let global = ctx.globals();
+                assert!(!global.get::<_, bool>("_saved").unwrap());
+                assert!(global.get::<_, bool>("_theme").unwrap());
+            });
+        assert!(rx.try_recv().is_err(), "nothing reaches the editor");
+    }
+
     #[test]
     fn test_api_read_dir() {
         let (mut backend, _rx) = create_test_backend();
@@ -11229,13 +12464,41 @@ mod tests {
 
         let cmd = rx.try_recv().unwrap();
         match cmd {
-            PluginCommand::ExecuteAction { action_name } => {
+            PluginCommand::ExecuteAction { action_name, args } => {
                 assert_eq!(action_name, "move_cursor_up");
+                assert!(args.is_empty());
             }
             _ => panic!("Expected ExecuteAction, got {:?}", cmd),
         }
     }
 
+    #[test]
+    fn test_api_execute_action_with_args() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.executeAction("insert_char", { char: "x" });
+            editor.executeAction("insert_char", "x");
+            editor.executeAction("");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::ExecuteAction { action_name, args } => {
+                assert_eq!(action_name, "insert_char");
+                assert_eq!(args.get("char"), Some(&serde_json::json!("x")));
+            }
+            cmd => panic!("Expected ExecuteAction, got {:?}", cmd),
+        }
+        // Non-object args and empty names are rejected before sending.
+        assert!(rx.try_recv().is_err());
+    }
+
     // ==================== Debug Test ====================
 
     #[test]
@@ -11455,6 +12718,213 @@ mod tests {
             });
     }
 
+    /// Format the global `name` the way `console.log` would.
+    fn console_format(backend: &QuickJsBackend, name: &str) -> String {
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| js_value_to_string(&ctx.globals().get::<_, Value>(name).unwrap()))
+    }
+
+    #[test]
+    fn test_console_formats_objects_readably() {
+        let (mut backend, _rx) = create_test_backend();
+        backend
+            .execute_js(
+                r#"
+            globalThis._obj = { a: 1, b: [2, 3], "two words": "x", f: function greet() {} };
+            globalThis._str = "plain text";
+            globalThis._nested = { l1: { l2: { l3: { l4: { l5: 1 } } } } };
+            globalThis._message = { message: "not an error" };
+            globalThis._error = new TypeError("bad value");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        assert_eq!(
+            console_format(&backend, "_obj"),
+            r#"{ a: 1, b: [2, 3], "two words": "x", f: [Function: greet] }"#
+        );
+        assert_eq!(console_format(&backend, "_str"), "plain text");
+        assert_eq!(
+            console_format(&backend, "_nested"),
+            "{ l1: { l2: { l3: { l4: [Object] } } } }"
+        );
+        assert_eq!(
+            console_format(&backend, "_message"),
+            r#"{ message: "not an error" }"#
+        );
+        assert!(console_format(&backend, "_error").starts_with("TypeError: bad value"));
+    }
+
+    #[test]
+    fn test_text_encoder_decoder_round_trip() {
+        let (mut backend, _rx) = create_test_backend();
+        backend
+            .execute_js(
+                r#"
+            const bytes = new TextEncoder().encode("héllo");
+            globalThis._byteLength = bytes.length;
+            globalThis._roundTrip = new TextDecoder("UTF-8").decode(bytes);
+            globalThis._lossy = new TextDecoder().decode(new Uint8Array([0x61, 0xff]));
+            try {
+                new TextDecoder("utf-8", { fatal: true }).decode(new Uint8Array([0x61, 0xff]));
+                globalThis._fatalError = "no error";
+            } catch (e) {
+                globalThis._fatalError = e instanceof TypeError ? "TypeError" : String(e);
+            }
+            try {
+                new TextDecoder("latin1");
+                globalThis._labelError = "no error";
+            } catch (e) {
+                globalThis._labelError = e instanceof RangeError ? "RangeError" : String(e);
+            }
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| {
+                let globals = ctx.globals();
+                assert_eq!(globals.get::<_, u32>("_byteLength").unwrap(), 6);
+                assert_eq!(globals.get::<_, String>("_roundTrip").unwrap(), "héllo");
+                assert_eq!(globals.get::<_, String>("_lossy").unwrap(), "a\u{FFFD}");
+                assert_eq!(
+                    globals.get::<_, String>("_fatalError").unwrap(),
+                    "TypeError"
+                );
+                assert_eq!(
+                    globals.get::<_, String>("_labelError").unwrap(),
+                    "RangeError"
+                );
+            });
+    }
+
+    #[test]
+    fn test_decode_utf8_strips_bom_unless_ignored() {
+        let with_bom = b"\xEF\xBB\xBFhi";
+        assert_eq!(decode_utf8(with_bom, false, false).as_deref(), Some("hi"));
+        assert_eq!(
+            decode_utf8(with_bom, true, true).as_deref(),
+            Some("\u{FEFF}hi")
+        );
+        assert_eq!(decode_utf8(b"\xff", true, false), None);
+    }
+
+    #[test]
+    fn test_console_marks_circular_references() {
+        let (mut backend, _rx) = create_test_backend();
+        backend
+            .execute_js(
+                r#"
+            const node = { name: "root", children: [] };
+            node.self = node;
+            node.children.push(node);
+            globalThis._cyclic = node;
+            console.log("cyclic:", node);
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        assert_eq!(
+            console_format(&backend, "_cyclic"),
+            r#"{ name: "root", children: [[Circular]], self: [Circular] }"#
+        );
+    }
+
+    #[test]
+    fn test_set_timeout_fires_on_next_tick() {
+        let (mut backend, _rx) = create_test_backend();
+        backend
+            .execute_js(
+                r#"
+            globalThis._fired = [];
+            globalThis._fired_named = (label) => globalThis._fired.push(label);
+            globalThis._first = setTimeout(() => globalThis._fired.push("first"), 0);
+            setTimeout("_fired_named", 0, "named");
+            const cancelled = setTimeout(() => globalThis._fired.push("cancelled"), 0);
+            clearTimeout(cancelled);
+            setTimeout(() => globalThis._fired.push("later"), 60000);
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        let fired = |backend: &QuickJsBackend| {
+            backend
+                .plugin_contexts
+                .borrow()
+                .get("test")
+                .unwrap()
+                .clone()
+                .with(|ctx| {
+                    ctx.eval::<String, _>("globalThis._fired.join(',')")
+                        .unwrap()
+                })
+        };
+
+        // Nothing runs until the loop fires due timers.
+        assert_eq!(fired(&backend), "");
+        assert!(backend.fire_due_timers());
+        assert_eq!(fired(&backend), "first,named");
+
+        // The 60s timer is still pending; clearing a fired id is a no-op.
+        assert!(backend.next_timer_deadline().is_some());
+        backend
+            .execute_js("clearTimeout(globalThis._first);", "test.js")
+            .unwrap();
+        assert!(!backend.fire_due_timers());
+        assert_eq!(fired(&backend), "first,named");
+    }
+
+    #[test]
+    fn test_set_interval_repeats_until_cleared() {
+        let (mut backend, _rx) = create_test_backend();
+        backend
+            .execute_js(
+                r#"
+            globalThis._ticks = 0;
+            globalThis._interval = setInterval(() => {
+                globalThis._ticks += 1;
+                if (globalThis._ticks === 3) clearInterval(globalThis._interval);
+            }, 0);
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        // A 0ms interval is clamped, so wait out each period.
+        for _ in 0..5 {
+            while let Some(due) = backend.next_timer_deadline() {
+                let now = std::time::Instant::now();
+                if due <= now {
+                    break;
+                }
+                std::thread::sleep(due - now);
+            }
+            backend.fire_due_timers();
+        }
+        let ticks = backend
+            .plugin_contexts
+            .borrow()
+            .get("test")
+            .unwrap()
+            .clone()
+            .with(|ctx| ctx.globals().get::<_, u32>("_ticks").unwrap());
+        assert_eq!(ticks, 3);
+    }
+
     #[test]
     fn test_api_unload_plugin_rejects_on_error() {
         let (mut backend, rx) = create_test_backend();
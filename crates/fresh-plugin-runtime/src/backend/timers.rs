@@ -0,0 +1,190 @@
+//! `setTimeout` / `setInterval` scheduling for plugin contexts.
+//!
+//! The queue only tracks ids and due times; the callbacks themselves stay
+//! in the owning plugin's context (see `TIMER_SHIM`). The plugin thread
+//! sleeps until [`TimerQueue::next_deadline`] and then fires whatever
+//! [`TimerQueue::take_due`] returns. Clearing a timer drops its entry; the
+//! stale heap slot it leaves behind is skipped when it comes due, or pruned
+//! as soon as it reaches the top of the heap.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::time::{Duration, Instant};
+
+struct Timer {
+    plugin: String,
+    due: Instant,
+    interval: Option<Duration>,
+}
+
+/// Shortest period an interval repeats at, as in browsers: a `0` ms
+/// `setInterval` would otherwise keep the plugin thread busy.
+const MIN_INTERVAL: Duration = Duration::from_millis(4);
+
+#[derive(Default)]
+pub(crate) struct TimerQueue {
+    next_id: u64,
+    heap: BinaryHeap<Reverse<(Instant, u64)>>,
+    timers: HashMap<u64, Timer>,
+}
+
+impl TimerQueue {
+    /// Schedule a timer for `plugin` firing `delay` after `now`, and every
+    /// `delay` after that when `repeat` is set. Intervals are clamped to
+    /// [`MIN_INTERVAL`]. Returns its id.
+    pub(crate) fn start(
+        &mut self,
+        plugin: &str,
+        delay: Duration,
+        repeat: bool,
+        now: Instant,
+    ) -> u64 {
+        self.next_id += 1;
+        let id = self.next_id;
+        let delay = if repeat {
+            delay.max(MIN_INTERVAL)
+        } else {
+            delay
+        };
+        let due = now + delay;
+        self.timers.insert(
+            id,
+            Timer {
+                plugin: plugin.to_string(),
+                due,
+                interval: repeat.then_some(delay),
+            },
+        );
+        self.heap.push(Reverse((due, id)));
+        id
+    }
+
+    /// Cancel `plugin`'s timer `id`. Unknown ids, ids that already fired
+    /// and other plugins' timers are left alone.
+    pub(crate) fn clear(&mut self, plugin: &str, id: u64) {
+        if self.timers.get(&id).is_some_and(|t| t.plugin == plugin) {
+            self.timers.remove(&id);
+        }
+    }
+
+    /// Cancel every timer `plugin` owns (on unload).
+    pub(crate) fn clear_plugin(&mut self, plugin: &str) {
+        self.timers.retain(|_, t| t.plugin != plugin);
+    }
+
+    /// When the earliest timer is due, if any is scheduled. Stale slots of
+    /// cleared or rescheduled timers are dropped on the way.
+    pub(crate) fn next_deadline(&mut self) -> Option<Instant> {
+        while let Some(&Reverse((due, id))) = self.heap.peek() {
+            if self.timers.get(&id).is_some_and(|t| t.due == due) {
+                return Some(due);
+            }
+            self.heap.pop();
+        }
+        None
+    }
+
+    /// Remove the timers due at `now` and return `(id, plugin)` for each,
+    /// in due order. Intervals are rescheduled relative to `now`.
+    pub(crate) fn take_due(&mut self, now: Instant) -> Vec<(u64, String)> {
+        let mut fired = Vec::new();
+        while let Some(&Reverse((due, id))) = self.heap.peek() {
+            if due > now {
+                break;
+            }
+            self.heap.pop();
+            // A cleared or rescheduled timer leaves a stale slot behind.
+            if self.timers.get(&id).is_some_and(|t| t.due == due) {
+                fired.push(id);
+            }
+        }
+
+        fired
+            .into_iter()
+            .filter_map(|id| {
+                let timer = self.timers.get_mut(&id)?;
+                let plugin = timer.plugin.clone();
+                match timer.interval {
+                    Some(interval) => {
+                        timer.due = now + interval;
+                        self.heap.push(Reverse((timer.due, id)));
+                    }
+                    None => {
+                        self.timers.remove(&id);
+                    }
+                }
+                Some((id, plugin))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn timers_fire_in_due_order_once() {
+        let now = Instant::now();
+        let mut queue = TimerQueue::default();
+        let late = queue.start("p", 20 * MS, false, now);
+        let early = queue.start("p", 10 * MS, false, now);
+        assert_eq!(queue.next_deadline(), Some(now + 10 * MS));
+
+        assert!(queue.take_due(now + 5 * MS).is_empty());
+        let fired: Vec<u64> = queue
+            .take_due(now + 20 * MS)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(fired, vec![early, late]);
+        assert!(queue.take_due(now + 100 * MS).is_empty());
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn cleared_and_fired_timers_are_skipped() {
+        let now = Instant::now();
+        let mut queue = TimerQueue::default();
+        let cleared = queue.start("p", MS, false, now);
+        let other_plugin = queue.start("q", MS, false, now);
+        queue.clear("p", cleared);
+        queue.clear("p", other_plugin);
+
+        assert_eq!(
+            queue.take_due(now + MS),
+            vec![(other_plugin, "q".to_string())]
+        );
+        // Clearing an id that already fired is a no-op.
+        queue.clear("q", other_plugin);
+    }
+
+    #[test]
+    fn cleared_timers_do_not_hold_the_next_deadline() {
+        let now = Instant::now();
+        let mut queue = TimerQueue::default();
+        let cleared = queue.start("p", MS, false, now);
+        queue.start("p", 10 * MS, false, now);
+        queue.clear("p", cleared);
+
+        assert_eq!(queue.next_deadline(), Some(now + 10 * MS));
+        queue.clear_plugin("p");
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn zero_ms_interval_is_clamped_until_cleared() {
+        let now = Instant::now();
+        let mut queue = TimerQueue::default();
+        let id = queue.start("p", Duration::ZERO, true, now);
+
+        assert!(queue.take_due(now).is_empty());
+        assert_eq!(queue.take_due(now + MIN_INTERVAL).len(), 1);
+        assert_eq!(queue.next_deadline(), Some(now + 2 * MIN_INTERVAL));
+        queue.clear_plugin("p");
+        assert!(queue.take_due(now + 3 * MIN_INTERVAL).is_empty());
+        queue.clear("p", id);
+    }
+}
@@ -0,0 +1,258 @@
+//! On-disk cache of transpiled plugin code.
+//!
+//! Transpiling (and, for plugins with ES imports, bundling) every plugin on
+//! startup is noticeable once many plugins are installed. Results are stored
+//! under `<config_dir>/cache/transpiled/<sha256>.js`, keyed on a hash of
+//! everything that shapes the output: the oxc version, the input file name
+//! and the source. For bundles the key covers every module in the import
+//! closure, so editing an imported file misses the cache too.
+//!
+//! Each entry starts with a header line carrying the SHA-256 of the code
+//! below it. An entry whose code does not match (a partial write, a corrupt
+//! file) is deleted and regenerated rather than executed.
+//!
+//! Entries for old plugin sources or old Fresh releases are never looked up
+//! again, so reads refresh an entry's mtime and entries unused for
+//! [`MAX_ENTRY_AGE`] are swept once per run.
+
+use anyhow::Result;
+use fresh_parser_js::{bundle_module, module_closure_sources, transpile_typescript, OXC_VERSION};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::{Duration, SystemTime};
+
+const HEADER_PREFIX: &str = "// fresh-transpile-cache sha256=";
+
+/// How long an entry may go unused before it is pruned.
+const MAX_ENTRY_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+pub(crate) struct TranspileCache {
+    dir: PathBuf,
+}
+
+impl TranspileCache {
+    /// The cache under `<config_dir>/cache/transpiled`. The first cache
+    /// opened in a run prunes stale entries.
+    pub(crate) fn in_config_dir(config_dir: &Path) -> Self {
+        static PRUNE: Once = Once::new();
+        let cache = Self {
+            dir: config_dir.join("cache").join("transpiled"),
+        };
+        PRUNE.call_once(|| cache.prune(SystemTime::now()));
+        cache
+    }
+
+    /// Delete entries (and leftover temporary files) last used more than
+    /// [`MAX_ENTRY_AGE`] before `now`.
+    fn prune(&self, now: SystemTime) {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let stale = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .is_ok_and(|modified| {
+                    now.duration_since(modified)
+                        .is_ok_and(|age| age > MAX_ENTRY_AGE)
+                });
+            if stale {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    /// `transpile_typescript`, served from the cache when `source` has been
+    /// transpiled under the same file name before.
+    pub(crate) fn transpile(&self, source: &str, filename: &str) -> Result<String> {
+        let key = cache_key("transpile", &[filename.as_bytes(), source.as_bytes()]);
+        self.get_or_insert_with(&key, || transpile_typescript(source, filename))
+    }
+
+    /// `bundle_module`, served from the cache when no module in the entry's
+    /// import closure has changed since it was last bundled.
+    pub(crate) fn bundle(&self, entry_path: &Path) -> Result<String> {
+        let sources = module_closure_sources(entry_path)?;
+        let mut fields: Vec<&[u8]> = Vec::with_capacity(sources.len() * 2);
+        for (path, source) in &sources {
+            fields.push(path.as_os_str().as_encoded_bytes());
+            fields.push(source.as_bytes());
+        }
+        let key = cache_key("bundle", &fields);
+        self.get_or_insert_with(&key, || bundle_module(entry_path))
+    }
+
+    fn get_or_insert_with(
+        &self,
+        key: &str,
+        build: impl FnOnce() -> Result<String>,
+    ) -> Result<String> {
+        if let Some(code) = self.read(key) {
+            return Ok(code);
+        }
+        let code = build()?;
+        if let Err(e) = self.write(key, &code) {
+            tracing::debug!("Failed to write transpile cache entry {}: {}", key, e);
+        }
+        Ok(code)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.js", key))
+    }
+
+    fn read(&self, key: &str) -> Option<String> {
+        let path = self.entry_path(key);
+        let bytes = std::fs::read(&path).ok()?;
+        match std::str::from_utf8(&bytes).ok().and_then(parse_entry) {
+            Some(code) => {
+                // Mark the entry as used so pruning keeps it.
+                let _ = std::fs::File::options()
+                    .write(true)
+                    .open(&path)
+                    .and_then(|file| file.set_modified(SystemTime::now()));
+                Some(code.to_string())
+            }
+            None => {
+                tracing::warn!(
+                    "Discarding corrupt transpile cache entry {}",
+                    path.display()
+                );
+                let _ = std::fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Write through a temporary file and rename it into place, so a
+    /// concurrent reader never sees a half-written entry.
+    fn write(&self, key: &str, code: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let tmp = self
+            .dir
+            .join(format!("{}.js.{}.tmp", key, std::process::id()));
+        let entry = format!("{}{}\n{}", HEADER_PREFIX, sha256_hex(code.as_bytes()), code);
+        std::fs::write(&tmp, entry)?;
+        std::fs::rename(&tmp, self.entry_path(key)).inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })
+    }
+}
+
+/// Hash `kind`, the oxc and runtime versions and each field into a key.
+/// Fields are length-prefixed so different splits of the same bytes never
+/// collide.
+fn cache_key(kind: &str, fields: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for field in [
+        OXC_VERSION.as_bytes(),
+        env!("CARGO_PKG_VERSION").as_bytes(),
+        kind.as_bytes(),
+    ]
+    .iter()
+    .chain(fields)
+    {
+        hasher.update((field.len() as u64).to_le_bytes());
+        hasher.update(field);
+    }
+    to_hex(&hasher.finalize())
+}
+
+/// The code of a cache entry, or `None` when its header is missing or
+/// does not match the code.
+fn parse_entry(contents: &str) -> Option<&str> {
+    let (header, code) = contents.split_once('\n')?;
+    let digest = header.strip_prefix(HEADER_PREFIX)?;
+    (sha256_hex(code.as_bytes()) == digest).then_some(code)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    to_hex(&Sha256::digest(bytes))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(dir: &Path) -> Vec<PathBuf> {
+        std::fs::read_dir(dir)
+            .map(|rd| rd.map(|e| e.unwrap().path()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn transpile_is_cached_per_source() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = TranspileCache::in_config_dir(temp.path());
+        let dir = temp.path().join("cache").join("transpiled");
+
+        let first = cache.transpile("const a: number = 1;", "p.ts").unwrap();
+        assert!(first.contains("const a = 1"));
+        assert_eq!(entries(&dir).len(), 1);
+
+        let again = cache.transpile("const a: number = 1;", "p.ts").unwrap();
+        assert_eq!(again, first);
+        assert_eq!(entries(&dir).len(), 1);
+
+        cache.transpile("const a: number = 2;", "p.ts").unwrap();
+        assert_eq!(entries(&dir).len(), 2);
+    }
+
+    #[test]
+    fn corrupt_entry_is_regenerated() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = TranspileCache::in_config_dir(temp.path());
+        let dir = temp.path().join("cache").join("transpiled");
+
+        let good = cache.transpile("const a: number = 1;", "p.ts").unwrap();
+        let entry = entries(&dir).pop().unwrap();
+        let original = std::fs::read_to_string(&entry).unwrap();
+        std::fs::write(&entry, &original[..original.len() - 3]).unwrap();
+
+        let code = cache.transpile("const a: number = 1;", "p.ts").unwrap();
+        assert_eq!(code, good);
+        assert_eq!(std::fs::read_to_string(&entry).unwrap(), original);
+    }
+
+    #[test]
+    fn unused_entries_are_pruned() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = TranspileCache::in_config_dir(temp.path());
+        let dir = temp.path().join("cache").join("transpiled");
+
+        cache.transpile("const a: number = 1;", "p.ts").unwrap();
+        cache.prune(SystemTime::now());
+        assert_eq!(entries(&dir).len(), 1);
+
+        cache.prune(SystemTime::now() + MAX_ENTRY_AGE + Duration::from_secs(60));
+        assert!(entries(&dir).is_empty());
+    }
+
+    #[test]
+    fn editing_an_imported_module_busts_the_bundle() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = TranspileCache::in_config_dir(temp.path().join("config").as_path());
+        let src = temp.path().join("plugin");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("lib.ts"), "export const greeting = \"hello\";\n").unwrap();
+        std::fs::write(
+            src.join("main.ts"),
+            "import { greeting } from \"./lib\";\nconsole.log(greeting);\n",
+        )
+        .unwrap();
+
+        let first = cache.bundle(&src.join("main.ts")).unwrap();
+        assert!(first.contains("hello"));
+        assert_eq!(cache.bundle(&src.join("main.ts")).unwrap(), first);
+
+        std::fs::write(src.join("lib.ts"), "export const greeting = \"bye\";\n").unwrap();
+        let edited = cache.bundle(&src.join("main.ts")).unwrap();
+        assert!(edited.contains("bye"));
+        assert!(!edited.contains("hello"));
+    }
+}
@@ -111,6 +111,15 @@ pub enum PluginRequest {
         target: Option<String>,
     },
 
+    /// Call a plugin's text-object handler (fire-and-forget). The result
+    /// comes back as a `TextObjectResolved` command.
+    ResolveTextObject {
+        plugin_name: String,
+        handler_name: String,
+        request_id: u64,
+        args: serde_json::Value,
+    },
+
     /// Check if any handlers are registered for a hook
     HasHookHandlers {
         hook_name: String,
@@ -699,6 +708,25 @@ impl PluginThreadHandle {
         }
     }
 
+    /// Resolve a plugin text object (non-blocking). The handler's range
+    /// arrives later as `PluginCommand::TextObjectResolved { request_id, .. }`.
+    pub fn resolve_text_object(
+        &self,
+        plugin_name: &str,
+        handler_name: &str,
+        request_id: u64,
+        args: serde_json::Value,
+    ) {
+        if let Some(sender) = self.request_sender.as_ref() {
+            fire_and_forget(sender.send(PluginRequest::ResolveTextObject {
+                plugin_name: plugin_name.to_string(),
+                handler_name: handler_name.to_string(),
+                request_id,
+                args,
+            }));
+        }
+    }
+
     /// Check if any handlers are registered for a hook (blocking)
     pub fn has_hook_handlers(&self, hook_name: &str) -> bool {
         let (tx, rx) = oneshot::channel();
@@ -1019,6 +1047,7 @@ mod plugin_thread_tests {
 /// Uses `tokio::select!` to interleave request handling with periodic event loop
 /// polling. This allows long-running promises (like process spawns) to make progress
 /// even when no requests are coming in, preventing the UI from getting stuck.
+/// Plugin `setTimeout` / `setInterval` timers fire from here too.
 async fn plugin_thread_loop(
     runtime: Rc<RefCell<QuickJsBackend>>,
     plugins: &mut HashMap<String, TsPluginInfo>,
@@ -1044,6 +1073,13 @@ async fn plugin_thread_loop(
             }
         }
 
+        // Wake for the earliest plugin timer; re-read every iteration since
+        // requests and fired callbacks may schedule new ones.
+        let next_timer = runtime.borrow().next_timer_deadline();
+        let timer_due = tokio::time::sleep_until(
+            next_timer.map_or_else(tokio::time::Instant::now, tokio::time::Instant::from_std),
+        );
+
         tokio::select! {
             biased; // Prefer handling requests over polling
 
@@ -1076,6 +1112,14 @@ async fn plugin_thread_loop(
                 }
             }
 
+            // Poll straight after firing: the biased select would otherwise
+            // keep picking a busy timer over the poll arm below.
+            _ = timer_due, if next_timer.is_some() => {
+                if runtime.borrow_mut().fire_due_timers() {
+                    has_pending_work = runtime.borrow_mut().poll_event_loop_once();
+                }
+            }
+
             // Poll the JS event loop periodically to make progress on pending promises
             _ = tokio::time::sleep(poll_interval), if has_pending_work => {
                 has_pending_work = runtime.borrow_mut().poll_event_loop_once();
@@ -1237,6 +1281,20 @@ async fn handle_request(
             }
         }
 
+        PluginRequest::ResolveTextObject {
+            plugin_name,
+            handler_name,
+            request_id,
+            args,
+        } => {
+            runtime.borrow_mut().resolve_text_object(
+                &plugin_name,
+                &handler_name,
+                request_id,
+                &args,
+            );
+        }
+
         PluginRequest::HasHookHandlers {
             hook_name,
             response,
@@ -1398,15 +1456,7 @@ fn prepare_plugin(path: &Path) -> Result<PreparedPlugin> {
         source
     };
 
-    // Load accompanying .i18n.json file
-    let i18n_path = path.with_extension("i18n.json");
-    let i18n = if i18n_path.exists() {
-        std::fs::read_to_string(&i18n_path)
-            .ok()
-            .and_then(|content| serde_json::from_str(&content).ok())
-    } else {
-        None
-    };
+    let i18n = load_plugin_i18n(path);
 
     Ok(PreparedPlugin {
         name: plugin_name,
@@ -1418,6 +1468,31 @@ fn prepare_plugin(path: &Path) -> Result<PreparedPlugin> {
     })
 }
 
+/// Read the locale bundle shipped next to a plugin file: `foo.ts` looks
+/// for `foo.i18n.json`, shaped `{ "en": { "key": "text" }, "de": { ... } }`.
+/// A missing bundle is normal; an unreadable or malformed one is logged
+/// and ignored so the plugin still loads (its `t()` calls return keys).
+fn load_plugin_i18n(path: &Path) -> Option<HashMap<String, HashMap<String, String>>> {
+    let i18n_path = path.with_extension("i18n.json");
+    if !i18n_path.exists() {
+        return None;
+    }
+    let parsed = std::fs::read_to_string(&i18n_path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()));
+    match parsed {
+        Ok(strings) => Some(strings),
+        Err(e) => {
+            tracing::warn!(
+                "Ignoring plugin locale bundle {}: {}",
+                i18n_path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
 /// Execute a pre-prepared plugin in QuickJS. This is the serial phase —
 /// must run on the plugin thread.
 fn execute_prepared_plugin(
@@ -1458,6 +1533,7 @@ fn execute_prepared_plugin(
             path: prepared.path.clone(),
             enabled: true,
             declarations: prepared.declarations.clone(),
+            permissions: runtime.borrow().plugin_permissions(&prepared.name),
         },
     );
 
@@ -1487,21 +1563,12 @@ async fn load_plugin_internal(
         .to_str()
         .ok_or_else(|| anyhow!("Invalid path encoding"))?;
 
-    // Try to load accompanying .i18n.json file
-    let i18n_path = path.with_extension("i18n.json");
-    if i18n_path.exists() {
-        if let Ok(content) = std::fs::read_to_string(&i18n_path) {
-            if let Ok(strings) = serde_json::from_str::<
-                std::collections::HashMap<String, std::collections::HashMap<String, String>>,
-            >(&content)
-            {
-                runtime
-                    .borrow_mut()
-                    .services
-                    .register_plugin_strings(&plugin_name, strings);
-                tracing::debug!("Loaded i18n strings for plugin '{}'", plugin_name);
-            }
-        }
+    if let Some(strings) = load_plugin_i18n(path) {
+        runtime
+            .borrow_mut()
+            .services
+            .register_plugin_strings(&plugin_name, strings);
+        tracing::debug!("Loaded i18n strings for plugin '{}'", plugin_name);
     }
 
     let load_start = std::time::Instant::now();
@@ -1528,6 +1595,7 @@ async fn load_plugin_internal(
             // file, no prepare-then-execute split). Skip the emit
             // here; the full directory scan picks it up next time.
             declarations: None,
+            permissions: runtime.borrow().plugin_permissions(&plugin_name),
         },
     );
 
@@ -1654,6 +1722,7 @@ async fn load_plugins_from_dir_with_config_internal(
                 enabled: existing_config.enabled,
                 path: Some(path.clone()),
                 settings: existing_config.settings.clone(),
+                permissions: existing_config.permissions,
             }
         } else {
             // Create new config with default enabled = true
@@ -1664,6 +1733,9 @@ async fn load_plugins_from_dir_with_config_internal(
         discovered_plugins.insert(plugin_name.clone(), config.clone());
 
         if config.enabled {
+            runtime
+                .borrow()
+                .set_plugin_permissions(&plugin_name, config.permissions);
             enabled_plugins.push((plugin_name, path));
         } else {
             tracing::info!(
@@ -1821,6 +1893,7 @@ fn load_plugin_from_source_internal(
             // declarations emit. Users editing real plugin files
             // still get types on the next full scan.
             declarations: None,
+            permissions: runtime.borrow().plugin_permissions(name),
         },
     );
 
@@ -1910,4 +1983,26 @@ mod tests {
         assert_eq!(json["prompt_type"], "search");
         assert_eq!(json["input"], "test");
     }
+
+    #[test]
+    fn test_load_plugin_i18n_reads_bundle_next_to_plugin() {
+        let dir = std::env::temp_dir().join(format!("fresh_plugin_i18n_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let plugin = dir.join("greeter.ts");
+        std::fs::write(
+            dir.join("greeter.i18n.json"),
+            r#"{ "en": { "hello": "Hello, {name}" }, "de": { "hello": "Hallo, {name}" } }"#,
+        )
+        .unwrap();
+
+        let strings = load_plugin_i18n(&plugin).expect("bundle should load");
+        assert_eq!(strings["de"]["hello"], "Hallo, {name}");
+
+        // Malformed and missing bundles are skipped, not fatal
+        std::fs::write(dir.join("broken.i18n.json"), "{ not json").unwrap();
+        assert!(load_plugin_i18n(&dir.join("broken.ts")).is_none());
+        assert!(load_plugin_i18n(&dir.join("missing.ts")).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -23,9 +23,10 @@ use fresh_core::api::{
     GrepMatch, JsDiagnostic, JsPosition, JsRange, JsTextPropertyEntry, KeyEventPayload,
     LanguagePackConfig, LayoutHints, LspServerPackConfig, OverlayColorSpec, OverlayOptions,
     PluginAnimationEdge, PluginAnimationKind, ProcessLimitsPackConfig, RemoteBackendInfo,
-    ReplaceResult, ScreenSize, SearchTakeResult, SpawnResult, SplitSnapshot, TerminalResult,
-    TextPropertiesAtCursor, TokenColor, TsHighlightSpan, ViewTokenStyle, ViewTokenWire,
-    ViewTokenWireKind, ViewportInfo, VirtualBufferResult, WindowInfo,
+    RenderedCell, RenderedRegion, ReplaceResult, ScreenSize, SearchTakeResult, SpawnResult,
+    SplitSnapshot, TerminalResult, TextPropertiesAtCursor, TokenColor, TsHighlightSpan,
+    ViewTokenStyle, ViewTokenWire, ViewTokenWireKind, ViewportInfo, VirtualBufferResult,
+    WindowInfo,
 };
 use fresh_core::command::Suggestion;
 use fresh_core::file_explorer::{
@@ -55,6 +56,8 @@ fn get_type_decl(type_name: &str) -> Option<String> {
         "CursorInfo" => Some(CursorInfo::decl(&cfg)),
         "ViewportInfo" => Some(ViewportInfo::decl(&cfg)),
         "ScreenSize" => Some(ScreenSize::decl(&cfg)),
+        "RenderedRegion" => Some(RenderedRegion::decl(&cfg)),
+        "RenderedCell" => Some(RenderedCell::decl(&cfg)),
         "KeyEventPayload" => Some(KeyEventPayload::decl(&cfg)),
         "SplitSnapshot" => Some(SplitSnapshot::decl(&cfg)),
         "ActionSpec" => Some(ActionSpec::decl(&cfg)),
@@ -107,6 +110,8 @@ fn get_type_decl(type_name: &str) -> Option<String> {
         // UI types (ts-rs renames these with Ts prefix)
         "TsActionPopupAction" | "ActionPopupAction" => Some(ActionPopupAction::decl(&cfg)),
         "ActionPopupOptions" => Some(ActionPopupOptions::decl(&cfg)),
+        "PopupAnchor" => Some(fresh_core::api::PopupAnchor::decl(&cfg)),
+        "PopupOptions" => Some(fresh_core::api::PopupOptions::decl(&cfg)),
         "TsLspMenuItem" | "LspMenuItem" => Some(fresh_core::api::LspMenuItem::decl(&cfg)),
         "TsHighlightSpan" => Some(TsHighlightSpan::decl(&cfg)),
         "FileExplorerDecoration" => Some(FileExplorerDecoration::decl(&cfg)),
@@ -330,6 +335,7 @@ const DEPENDENCY_TYPES: &[&str] = &[
     "TsCreateCompositeBufferOptions",  // Options for createCompositeBuffer
     "ViewportInfo",                    // Used by plugins for viewport queries
     "ScreenSize",                      // Used by editor.getScreenSize()
+    "RenderedCell",                    // Used in RenderedRegion.cells
     "KeyEventPayload",                 // Used by editor.getNextKey()
     "SplitSnapshot",                   // Used by editor.listSplits()
     "LayoutHints",                     // Used by plugins for view transforms
@@ -348,6 +354,8 @@ const DEPENDENCY_TYPES: &[&str] = &[
     "ActionSpec",                      // Used by executeActions
     "TsActionPopupAction",             // Used by ActionPopupOptions.actions
     "ActionPopupOptions",              // Used by showActionPopup
+    "PopupAnchor",                     // Used by showPopup
+    "PopupOptions",                    // Used by showPopup
     "TsLspMenuItem",                   // Used by setLspMenuContributions
     "FileExplorerDecoration",          // Used by setFileExplorerDecorations
     "FileExplorerSlotEntry",           // Used by setFileExplorerSlots
@@ -692,7 +700,27 @@ interface HookEventMap {
   // so a plugin can attribute output to a session: output from ANY terminal
   // in the window counts, and it fires on every PTY read (in-place redraws
   // and carriage-return progress bars register, not just newlines).
-  terminal_output: { terminal_id: number; window_id: number; last_line: string };
+  terminal_output: {
+    terminal_id: number;
+    window_id: number;
+    last_line: string;
+    /**
+     * The terminal's current tab title — the combined foreground-process
+     * + OSC-title string shown on its tab. Empty when the terminal has no
+     * meaningful title yet (the auto-numbered `*Terminal N*` default). Lets
+     * a plugin name a workspace after whatever the terminal is running.
+     */
+    terminal_title: string;
+    /**
+     * The program's most recent out-of-band activity signal, sniffed from
+     * the raw PTY stream: `true` while a command/task is running (OSC 133
+     * command markers, OSC 9;4 progress), `false` when it has finished,
+     * `null` when the program never emitted such a marker. Lets a plugin
+     * drive a workspace's working/idle indicator off an explicit signal
+     * instead of output timing.
+     */
+    osc_activity: boolean | null;
+  };
   terminal_exit: { terminal_id: number; window_id: number; exit_code: number | null };
 
   // ── filesystem watching (watchPath plugin API) ────────────────────────────
@@ -890,6 +918,8 @@ mod tests {
             "CursorInfo",
             "ViewportInfo",
             "ScreenSize",
+            "RenderedRegion",
+            "RenderedCell",
             "KeyEventPayload",
             "SplitSnapshot",
             "ActionSpec",
@@ -912,6 +942,8 @@ mod tests {
             "ViewTokenWire",
             "TsActionPopupAction",
             "ActionPopupOptions",
+            "PopupAnchor",
+            "PopupOptions",
             "TsHighlightSpan",
             "FileExplorerDecoration",
             "TextPropertyEntry",
@@ -1287,6 +1319,7 @@ mod tests {
             "setClipboard",
             "registerCommand",
             "unregisterCommand",
+            "registerTextObject",
             "setContext",
             "executeAction",
             "cancelPrompt",
@@ -1301,6 +1334,7 @@ mod tests {
             "getAllCursorPositions",
             "getViewport",
             "getScreenSize",
+            "getRenderedRegion",
             "getCursorLine",
             "getLineStartPosition",
             "getLineEndPosition",
@@ -1330,6 +1364,7 @@ mod tests {
             "utf8ByteLength",
             "fileExists",
             "readFile",
+            "readFileBytes",
             "writeFile",
             "readDir",
             "createDir",
@@ -1429,6 +1464,8 @@ mod tests {
             "setBufferCursor",
             "setLineIndicator",
             "clearLineIndicators",
+            "setGutterIcon",
+            "clearGutterIcons",
             "setLineNumbers",
             "setIndentationGuide",
             "setViewMode",
@@ -1447,6 +1484,10 @@ mod tests {
             "removeScrollSyncGroup",
             "executeActions",
             "showActionPopup",
+            "showPopup",
+            "updatePopup",
+            "closePopup",
+            "showOutputPanel",
             "setLspMenuContributions",
             "disableLspForLanguage",
             "setLspRootUri",